@@ -22,6 +22,10 @@
     APIBackend
     tweezer_devices
     emulator_devices
+    tomography
+    ghz_parity
+    xeb
+    noise_fitting
 
 """
 
@@ -30,5 +34,9 @@ from .api_devices import *  # type: ignore
 from .pragma_operations import *  # type: ignore
 from .qryd_devices import *  # type: ignore
 from .tweezer_devices import *  # type: ignore
+from .tomography import *  # type: ignore
+from .ghz_parity import *  # type: ignore
+from .xeb import *  # type: ignore
+from .noise_fitting import *  # type: ignore
 
 devices = qryd_devices  # type: ignore
@@ -0,0 +1,119 @@
+# This is an auto generated file containing only the documentation.
+# You can find the full implementation on this page:
+# https://github.com/HQSquantumsimulations/qoqo_qryd
+
+"""
+GHZ parity oscillation experiment for QRyd devices.
+
+.. autosummary::
+   :toctree: generated/
+
+   GHZParityExperiment
+
+"""
+
+from typing import List, Any, Dict
+from qoqo import Circuit
+
+class GHZParityExperiment:
+    """
+    Experiment builder for GHZ state parity oscillations.
+
+    The GHZ state is prepared along the given line of qubits, consecutive qubits are entangled
+    with a `ControlledPauliZ` gate and therefore need to be connected on the device.
+    The parity is measured after a `RotateXY(pi/2, phi)` rotation on every qubit for
+    `number_phases` equidistant phases `phi` in `[0, 2 pi)`. The GHZ fidelity is estimated as
+    `F = (P_0...0 + P_1...1) / 2 + C / 2`, where `C` is the parity oscillation amplitude.
+
+    Args:
+        qubits (List[int]): The line of qubits the GHZ state is prepared on.
+        number_phases (int): The number of analysis phases, needs to be larger than twice the number of qubits.
+        number_measurements (int): The number of measurements performed for each circuit.
+
+    Raises:
+        ValueError: Less than two qubits, duplicated qubits, too few phases or no measurements were given.
+    """
+
+    def __init__(self, qubits: List[int], number_phases: int, number_measurements: int):
+        return
+
+    def qubits(self) -> List[int]:
+        """
+        Returns the line of qubits the GHZ state is prepared on.
+
+        Returns:
+            List[int]: The qubits.
+        """
+
+    def number_phases(self) -> int:
+        """
+        Returns the number of analysis phases.
+
+        Returns:
+            int: The number of analysis phases.
+        """
+
+    def number_measurements(self) -> int:
+        """
+        Returns the number of measurements performed for each circuit.
+
+        Returns:
+            int: The number of measurements.
+        """
+
+    def phases(self) -> List[float]:
+        """
+        Returns the analysis phases of the parity circuits.
+
+        Returns:
+            List[float]: The analysis phases.
+        """
+
+    def preparation_circuit(self) -> Circuit:
+        """
+        Returns the circuit preparing the GHZ state on the line of qubits.
+
+        Returns:
+            Circuit: The preparation circuit.
+        """
+
+    def circuits(self) -> List[Circuit]:
+        """
+        Returns the measurement circuits of the experiment.
+
+        The first circuit measures the populations in the computational basis, followed by one
+        parity circuit per analysis phase. The i-th qubit of the line is measured into the
+        i-th bit of the `ro_ghz` register.
+
+        Returns:
+            List[Circuit]: The measurement circuits.
+        """
+
+    def analyse(self, registers: List[List[List[bool]]]) -> Dict[str, Any]:
+        """
+        Analyses the measured bit registers.
+
+        Args:
+            registers (List[List[List[bool]]]): The measured bit registers, one per circuit.
+
+        Returns:
+            Dict[str, Any]: The populations, parity_amplitude, parity_phase_offset, fidelity and parities.
+
+        Raises:
+            ValueError: The registers do not match the circuits of the experiment.
+        """
+
+    def run(self, backend: Any) -> Dict[str, Any]:
+        """
+        Runs the experiment on a backend and analyses the results.
+
+        Args:
+            backend (Union[SimulatorBackend, APIBackend]): The backend the circuits are run on.
+
+        Returns:
+            Dict[str, Any]: The populations, parity_amplitude, parity_phase_offset, fidelity and parities.
+
+        Raises:
+            RuntimeError: Running a circuit failed.
+            ValueError: The analysis failed.
+        """
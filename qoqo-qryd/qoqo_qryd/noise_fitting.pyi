@@ -0,0 +1,109 @@
+# This is an auto generated file containing only the documentation.
+# You can find the full implementation on this page:
+# https://github.com/HQSquantumsimulations/qoqo_qryd
+
+"""
+Fitting of simulator noise parameters from experimental results.
+
+.. autosummary::
+   :toctree: generated/
+
+   fit_decay_time
+   fit_t1
+   fit_t2
+   fit_depolarising_rate
+
+"""
+
+from typing import List, Any, Dict, Union
+from .tweezer_devices import TweezerDevice, TweezerMutableDevice
+from .emulator_devices import EmulatorDevice
+from .xeb import XEBExperiment
+
+def fit_decay_time(times: List[float], signal: List[float]) -> float:
+    """
+    Fits the decay time `T` of `signal(t) = A * exp(-t / T)`.
+
+    The fit is a log-linear least squares fit, points with a non-positive signal are ignored.
+
+    Args:
+        times (List[float]): The times at which the signal was measured.
+        signal (List[float]): The measured signal.
+
+    Returns:
+        float: The fitted decay time.
+
+    Raises:
+        ValueError: The data does not have matching lengths, contains fewer than two usable points or does not decay.
+    """
+
+def fit_t1(
+    device: Union[TweezerDevice, TweezerMutableDevice, EmulatorDevice],
+    qubit: int,
+    times: List[float],
+    excited_populations: List[float],
+) -> float:
+    """
+    Fits the relaxation time T1 of a qubit and writes it into the device.
+
+    Args:
+        device (Union[TweezerDevice, TweezerMutableDevice, EmulatorDevice]): The device whose noise parameters are updated.
+        qubit (int): The measured qubit.
+        times (List[float]): The waiting times after the excitation of the qubit.
+        excited_populations (List[float]): The measured population of the excited state.
+
+    Returns:
+        float: The fitted relaxation time.
+
+    Raises:
+        TypeError: The device is not a TweezerDevice, TweezerMutableDevice or EmulatorDevice.
+        ValueError: The fit failed or the qubit is not mapped to a tweezer.
+    """
+
+def fit_t2(
+    device: Union[TweezerDevice, TweezerMutableDevice, EmulatorDevice],
+    qubit: int,
+    times: List[float],
+    coherences: List[float],
+) -> float:
+    """
+    Fits the dephasing time T2 of a qubit and writes it into the device.
+
+    Args:
+        device (Union[TweezerDevice, TweezerMutableDevice, EmulatorDevice]): The device whose noise parameters are updated.
+        qubit (int): The measured qubit.
+        times (List[float]): The waiting times of the Ramsey or echo sequence.
+        coherences (List[float]): The measured contrast of the coherence, e.g. the fringe amplitude.
+
+    Returns:
+        float: The fitted dephasing time.
+
+    Raises:
+        TypeError: The device is not a TweezerDevice, TweezerMutableDevice or EmulatorDevice.
+        ValueError: The fit failed or the qubit is not mapped to a tweezer.
+    """
+
+def fit_depolarising_rate(
+    device: Union[TweezerDevice, TweezerMutableDevice, EmulatorDevice],
+    experiment: XEBExperiment,
+    result: Dict[str, Any],
+) -> float:
+    """
+    Fits the depolarising rate of the benchmarked qubits from an XEB result and writes it into the device.
+
+    The fidelity per cycle is modelled as `f = exp(-r T)`, where `T` is the gate time per cycle
+    summed over all qubits the gates act on. The same rate is assigned to the tweezers of all
+    benchmarked qubits.
+
+    Args:
+        device (Union[TweezerDevice, TweezerMutableDevice, EmulatorDevice]): The device the experiment was run on, whose noise parameters are updated.
+        experiment (XEBExperiment): The benchmarking experiment.
+        result (Dict[str, Any]): The result returned by `XEBExperiment.run` or `XEBExperiment.analyse`.
+
+    Returns:
+        float: The fitted depolarising rate.
+
+    Raises:
+        TypeError: The device is not a TweezerDevice, TweezerMutableDevice or EmulatorDevice.
+        ValueError: No valid fidelity per cycle was fitted, a gate time is missing in the device or a qubit is not mapped to a tweezer.
+    """
@@ -13,9 +13,15 @@ QRyd specific PragmaOperations that support changing the QRyd device during a ci
    PragmaDeactivateQRydQubit
    PragmaShiftQubitsTweezers
    PragmaSwitchDeviceLayout
+   PragmaSwapTweezers
+   PragmaGlobalRotateXY
+   PragmaMoveToZone
+   PragmaSetPhaseRelation
 """
 
-from typing import List, Tuple, Dict, Set
+from typing import List, Tuple, Dict, Set, Optional, Union
+from qoqo_calculator_pyo3 import CalculatorFloat
+from .qryd_devices import FirstDevice  # type: ignore
 
 class PragmaChangeQRydLayout:
     """
@@ -196,6 +202,67 @@ class PragmaShiftQRydQubit:
             PragmaChangeDevice
         """
 
+    @staticmethod
+    def from_device(device: FirstDevice) -> PragmaShiftQRydQubit:
+        """
+        Create a PragmaShiftQRydQubit keeping all qubits of a FirstDevice at their current positions.
+
+        A FirstDevice expects the new positions of all its qubits. Starting from the current
+        positions, only the moved qubits need to be changed with `move_qubit` and `shift_row`.
+
+        Args:
+            device (FirstDevice): The device the current qubit positions are taken from.
+
+        Returns:
+            PragmaShiftQRydQubit: The pragma leaving all qubits at their positions.
+
+        Raises:
+            TypeError: Input is not a FirstDevice.
+        """
+
+    def move_qubit(self, qubit: int, row: int, column: int) -> PragmaShiftQRydQubit:
+        """
+        Set the new position of a qubit.
+
+        Args:
+            qubit (int): The qubit to move.
+            row (int): The row of the new position.
+            column (int): The column of the new position.
+
+        Returns:
+            PragmaShiftQRydQubit: The pragma with the new position of the qubit.
+        """
+
+    def shift_row(self, row: int, offset: int) -> PragmaShiftQRydQubit:
+        """
+        Shift the new positions of all qubits in a row by a number of columns.
+
+        Args:
+            row (int): The row whose qubits are shifted.
+            offset (int): The number of columns to shift the qubits by, negative to shift them to the left.
+
+        Returns:
+            PragmaShiftQRydQubit: The pragma with the shifted positions.
+
+        Raises:
+            ValueError: A qubit would be shifted to a negative column.
+        """
+
+    def validate(self, device: FirstDevice):
+        """
+        Check that a FirstDevice can change its qubit positions to the new positions.
+
+        The new positions need to contain every qubit of the device and no other qubits. The
+        qubits need to stay in their rows and every column needs to exist in its row.
+
+        Args:
+            device (FirstDevice): The device to validate the new positions against.
+
+        Raises:
+            TypeError: Input is not a FirstDevice.
+            ValueError: The new positions are not valid on the device.
+        """
+
     def involved_qubits(self) -> Set[int]:
         """
         List all involved qubits (here, all).
@@ -423,13 +490,21 @@ class PragmaShiftQubitsTweezers:
     This PRAGMA Operation lists the shift operations to be executed in a QRyd Tweezer device.
 
     Each tuple contains first the starting tweezer identifier and second the ending tweezer identifier.
-    Multiple instances indicate parallel operations.
+    Sequential shifts are executed in the order of the list, each shift sees the qubits moved by
+    the previous ones. Parallel shifts are executed at the same time, their paths may not cross.
 
     Args:
-        shifts (list((int, int))): The list of shifts that can run in parallel.
+        shifts (list((int, int))): The list of shifts.
+        durations (Optional[list[float]]): The duration of each shift, None to use the transport times of the device.
+        parallel (Optional[bool]): Whether the shifts are executed in parallel, defaults to False.
     """
 
-    def __init__(self, shifts: int, int):
+    def __init__(
+        self,
+        shifts: List[Tuple[int, int]],
+        durations: Optional[List[float]] = None,
+        parallel: Optional[bool] = None,
+    ):
         return
 
     def shifts(self) -> List[Tuple[int, int]]:
@@ -440,6 +515,22 @@ class PragmaShiftQubitsTweezers:
             list[Tuple[int, int]]: The shifts involved in the Operation.
         """
 
+    def durations(self) -> Optional[List[float]]:
+        """
+        Return the durations of the shifts.
+
+        Returns:
+            Optional[list[float]]: The duration of each shift, None if the transport times of the device are used.
+        """
+
+    def parallel(self) -> bool:
+        """
+        Return whether the shifts are executed in parallel.
+
+        Returns:
+            bool: True if the shifts are executed in parallel, False if one after the other.
+        """
+
     def to_pragma_change_device(self):
         """
         Wrap PragmaShiftQubitsTweezers in PragmaChangeDevice operation
@@ -684,3 +775,570 @@ class PragmaSwitchDeviceLayout:
         Raises:
             RuntimeError: The qubit remapping failed.
         """
+
+class PragmaSwapTweezers:
+    """
+    This PRAGMA Operation exchanges the qubits of two occupied tweezers in a QRyd Tweezer device.
+
+    The qubits trapped in the two tweezers swap places, the qubit -> tweezer mapping of the
+    device is updated in one step.
+
+    Args:
+        tweezer_0 (int): The first tweezer of the exchange.
+        tweezer_1 (int): The second tweezer of the exchange.
+    """
+
+    def __init__(self, tweezer_0: int, tweezer_1: int):
+        return
+
+    def tweezer_0(self) -> int:
+        """
+        Return the first tweezer of the exchange.
+
+        Returns:
+            int: The first tweezer of the exchange.
+        """
+
+    def tweezer_1(self) -> int:
+        """
+        Return the second tweezer of the exchange.
+
+        Returns:
+            int: The second tweezer of the exchange.
+        """
+
+    def to_pragma_change_device(self):
+        """
+        Wrap PragmaSwapTweezers in PragmaChangeDevice operation
+
+        PragmaSwapTweezers is device specific and can not be directly added to a Circuit.
+        Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+        to the circuit.
+
+        Example
+        -------
+
+        >>> from qoqo import Circuit
+        ... from qoqo_qryd.pragma_operations import PragmaSwapTweezers
+        ... circuit = Circuit()
+        ... circuit += PragmaSwapTweezers(tweezer_0=0, tweezer_1=1).to_pragma_change_device()
+
+        Returns:
+            PragmaChangeDevice
+        """
+
+    def involved_qubits(self) -> Set[int]:
+        """
+        List all involved qubits (here, all).
+
+        Returns:
+            set[int]: The involved qubits of the PRAGMA operation.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Return tags classifying the type of the operation.
+
+        Used for the type based dispatch in ffi interfaces.
+
+        Returns:
+            list[str]: The tags of the operation.
+        """
+
+    def hqslang(self) -> str:
+        """
+        Return hqslang name of the operation.
+
+        Returns:
+            str: The hqslang name of the operation.
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Return true when the operation has symbolic parameters.
+
+        Returns:
+            bool: True if the operation contains symbolic parameters, False if it does not.
+        """
+
+    def to_bincode(self) -> bytearray:
+        """
+        Return the bincode representation of the PragmaSwapTweezers using the bincode crate.
+
+        Returns:
+            ByteArray: The serialized PragmaSwapTweezers (in bincode form).
+
+        Raises:
+            ValueError: Cannot serialize PragmaSwapTweezers to bytes.
+        """
+
+    def from_bincode(self, input: bytearray) -> PragmaSwapTweezers:
+        """
+        Convert the bincode representation of the PragmaSwapTweezers to a PragmaSwapTweezers using the bincode crate.
+
+        Args:
+            input (ByteArray): The serialized PragmaSwapTweezers (in bincode form).
+
+        Returns:
+            PragmaSwapTweezers: The deserialized PragmaSwapTweezers.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized to PragmaSwapTweezers.
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> PragmaSwapTweezers:
+        """
+        Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+
+        Args:
+            substitution_parameters (dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+
+        Returns:
+            self: The PRAGMA operation operation with the parameters substituted.
+
+        Raises:
+            RuntimeError: The parameter substitution failed.
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> PragmaSwapTweezers:
+        """
+        Remap qubits in a clone of the PRAGMA operation.
+
+        Args:
+            mapping (dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+
+        Returns:
+            self: The PRAGMA operation with the qubits remapped.
+
+        Raises:
+            RuntimeError: The qubit remapping failed.
+        """
+
+class PragmaGlobalRotateXY:
+    """
+    This PRAGMA Operation applies a global single-qubit rotation to all qubits of a QRyd Tweezer device.
+
+    Rydberg platforms drive global Raman pulses addressing all atoms simultaneously. The
+    operation acts as a RotateXY gate with the same angles on every active qubit of the device
+    and takes the global rotation time of the current Layout.
+
+    Args:
+        theta (CalculatorFloat): The angle theta of the rotation, in the interval from 0 to 2 * 2pi.
+        phi (CalculatorFloat): The rotation axis, in spherical coordinates phi gives the angle in the x-y plane.
+    """
+
+    def __init__(self, theta: Union[float, str], phi: Union[float, str]):
+        return
+
+    def theta(self) -> CalculatorFloat:
+        """
+        Return the angle theta of the rotation.
+
+        Returns:
+            CalculatorFloat: The angle theta of the rotation.
+        """
+
+    def phi(self) -> CalculatorFloat:
+        """
+        Return the angle phi of the rotation axis.
+
+        Returns:
+            CalculatorFloat: The angle phi of the rotation axis.
+        """
+
+    def to_pragma_change_device(self):
+        """
+        Wrap PragmaGlobalRotateXY in PragmaChangeDevice operation
+
+        PragmaGlobalRotateXY is device specific and can not be directly added to a Circuit.
+        Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+        to the circuit.
+
+        Example
+        -------
+
+        >>> from qoqo import Circuit
+        ... from qoqo_qryd.pragma_operations import PragmaGlobalRotateXY
+        ... circuit = Circuit()
+        ... circuit += PragmaGlobalRotateXY(theta=1.0, phi=0.0).to_pragma_change_device()
+
+        Returns:
+            PragmaChangeDevice
+        """
+
+    def involved_qubits(self) -> Set[int]:
+        """
+        List all involved qubits (here, all).
+
+        Returns:
+            set[int]: The involved qubits of the PRAGMA operation.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Return tags classifying the type of the operation.
+
+        Used for the type based dispatch in ffi interfaces.
+
+        Returns:
+            list[str]: The tags of the operation.
+        """
+
+    def hqslang(self) -> str:
+        """
+        Return hqslang name of the operation.
+
+        Returns:
+            str: The hqslang name of the operation.
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Return true when the operation has symbolic parameters.
+
+        Returns:
+            bool: True if the operation contains symbolic parameters, False if it does not.
+        """
+
+    def to_bincode(self) -> bytearray:
+        """
+        Return the bincode representation of the PragmaGlobalRotateXY using the bincode crate.
+
+        Returns:
+            ByteArray: The serialized PragmaGlobalRotateXY (in bincode form).
+
+        Raises:
+            ValueError: Cannot serialize PragmaGlobalRotateXY to bytes.
+        """
+
+    def from_bincode(self, input: bytearray) -> PragmaGlobalRotateXY:
+        """
+        Convert the bincode representation of the PragmaGlobalRotateXY to a PragmaGlobalRotateXY using the bincode crate.
+
+        Args:
+            input (ByteArray): The serialized PragmaGlobalRotateXY (in bincode form).
+
+        Returns:
+            PragmaGlobalRotateXY: The deserialized PragmaGlobalRotateXY.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized to PragmaGlobalRotateXY.
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> PragmaGlobalRotateXY:
+        """
+        Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+
+        Args:
+            substitution_parameters (dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+
+        Returns:
+            self: The PRAGMA operation operation with the parameters substituted.
+
+        Raises:
+            RuntimeError: The parameter substitution failed.
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> PragmaGlobalRotateXY:
+        """
+        Remap qubits in a clone of the PRAGMA operation.
+
+        Args:
+            mapping (dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+
+        Returns:
+            self: The PRAGMA operation with the qubits remapped.
+
+        Raises:
+            RuntimeError: The qubit remapping failed.
+        """
+
+class PragmaMoveToZone:
+    """
+    This PRAGMA Operation moves qubits into a zone of a QRyd Tweezer device.
+
+    The device resolves the operation into shifts of the qubits into the nearest free tweezers
+    labelled with the zone, so that transfers between e.g. the storage and the interaction zone
+    can be expressed without giving the tweezers.
+
+    Args:
+        qubits (List[int]): The qubits to move, in the order they are moved.
+        zone (str): The label of the zone the qubits are moved into.
+    """
+
+    def __init__(self, qubits: List[int], zone: str):
+        return
+
+    def qubits(self) -> List[int]:
+        """
+        Return the qubits to move.
+
+        Returns:
+            List[int]: The qubits to move, in the order they are moved.
+        """
+
+    def zone(self) -> str:
+        """
+        Return the label of the zone the qubits are moved into.
+
+        Returns:
+            str: The label of the zone.
+        """
+
+    def to_pragma_change_device(self):
+        """
+        Wrap PragmaMoveToZone in PragmaChangeDevice operation
+
+        PragmaMoveToZone is device specific and can not be directly added to a Circuit.
+        Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+        to the circuit.
+
+        Example
+        -------
+
+        >>> from qoqo import Circuit
+        ... from qoqo_qryd.pragma_operations import PragmaMoveToZone
+        ... circuit = Circuit()
+        ... circuit += PragmaMoveToZone(qubits=[0, 1], zone="interaction").to_pragma_change_device()
+
+        Returns:
+            PragmaChangeDevice
+        """
+
+    def involved_qubits(self) -> Set[int]:
+        """
+        List all involved qubits (here, all).
+
+        Returns:
+            set[int]: The involved qubits of the PRAGMA operation.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Return tags classifying the type of the operation.
+
+        Used for the type based dispatch in ffi interfaces.
+
+        Returns:
+            list[str]: The tags of the operation.
+        """
+
+    def hqslang(self) -> str:
+        """
+        Return hqslang name of the operation.
+
+        Returns:
+            str: The hqslang name of the operation.
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Return true when the operation has symbolic parameters.
+
+        Returns:
+            bool: True if the operation contains symbolic parameters, False if it does not.
+        """
+
+    def to_bincode(self) -> bytearray:
+        """
+        Return the bincode representation of the PragmaMoveToZone using the bincode crate.
+
+        Returns:
+            ByteArray: The serialized PragmaMoveToZone (in bincode form).
+
+        Raises:
+            ValueError: Cannot serialize PragmaMoveToZone to bytes.
+        """
+
+    def from_bincode(self, input: bytearray) -> PragmaMoveToZone:
+        """
+        Convert the bincode representation of the PragmaMoveToZone to a PragmaMoveToZone using the bincode crate.
+
+        Args:
+            input (ByteArray): The serialized PragmaMoveToZone (in bincode form).
+
+        Returns:
+            PragmaMoveToZone: The deserialized PragmaMoveToZone.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized to PragmaMoveToZone.
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> PragmaMoveToZone:
+        """
+        Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+
+        Args:
+            substitution_parameters (dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+
+        Returns:
+            self: The PRAGMA operation operation with the parameters substituted.
+
+        Raises:
+            RuntimeError: The parameter substitution failed.
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> PragmaMoveToZone:
+        """
+        Remap qubits in a clone of the PRAGMA operation.
+
+        Args:
+            mapping (dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+
+        Returns:
+            self: The PRAGMA operation with the qubits remapped.
+
+        Raises:
+            RuntimeError: The qubit remapping failed.
+        """
+
+class PragmaSetPhaseRelation:
+    """
+    This PRAGMA Operation sets the phase relations of a QRyd Tweezer device.
+
+    The phase relations give the phase of the PhaseShiftedControlledZ and
+    PhaseShiftedControlledPhase gates. Changing them mid-circuit expresses circuits calibrated
+    for different interaction strengths in a single program.
+
+    Args:
+        cz_relation (str): The relation to use for the PhaseShiftedControlledZ gate.
+        cp_relation (str): The relation to use for the PhaseShiftedControlledPhase gate.
+    """
+
+    def __init__(self, cz_relation: str, cp_relation: str):
+        return
+
+    def cz_relation(self) -> str:
+        """
+        Return the relation to use for the PhaseShiftedControlledZ gate.
+
+        Returns:
+            str: The PhaseShiftedControlledZ phase relation.
+        """
+
+    def cp_relation(self) -> str:
+        """
+        Return the relation to use for the PhaseShiftedControlledPhase gate.
+
+        Returns:
+            str: The PhaseShiftedControlledPhase phase relation.
+        """
+
+    def to_pragma_change_device(self):
+        """
+        Wrap PragmaSetPhaseRelation in PragmaChangeDevice operation
+
+        PragmaSetPhaseRelation is device specific and can not be directly added to a Circuit.
+        Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+        to the circuit.
+
+        Example
+        -------
+
+        >>> from qoqo import Circuit
+        ... from qoqo_qryd.pragma_operations import PragmaSetPhaseRelation
+        ... circuit = Circuit()
+        ... circuit += PragmaSetPhaseRelation(cz_relation="DefaultRelation", cp_relation="0.5").to_pragma_change_device()
+
+        Returns:
+            PragmaChangeDevice
+        """
+
+    def involved_qubits(self) -> Set[int]:
+        """
+        List all involved qubits (here, all).
+
+        Returns:
+            set[int]: The involved qubits of the PRAGMA operation.
+        """
+
+    def tags(self) -> List[str]:
+        """
+        Return tags classifying the type of the operation.
+
+        Used for the type based dispatch in ffi interfaces.
+
+        Returns:
+            list[str]: The tags of the operation.
+        """
+
+    def hqslang(self) -> str:
+        """
+        Return hqslang name of the operation.
+
+        Returns:
+            str: The hqslang name of the operation.
+        """
+
+    def is_parametrized(self) -> bool:
+        """
+        Return true when the operation has symbolic parameters.
+
+        Returns:
+            bool: True if the operation contains symbolic parameters, False if it does not.
+        """
+
+    def to_bincode(self) -> bytearray:
+        """
+        Return the bincode representation of the PragmaSetPhaseRelation using the bincode crate.
+
+        Returns:
+            ByteArray: The serialized PragmaSetPhaseRelation (in bincode form).
+
+        Raises:
+            ValueError: Cannot serialize PragmaSetPhaseRelation to bytes.
+        """
+
+    def from_bincode(self, input: bytearray) -> PragmaSetPhaseRelation:
+        """
+        Convert the bincode representation of the PragmaSetPhaseRelation to a PragmaSetPhaseRelation using the bincode crate.
+
+        Args:
+            input (ByteArray): The serialized PragmaSetPhaseRelation (in bincode form).
+
+        Returns:
+            PragmaSetPhaseRelation: The deserialized PragmaSetPhaseRelation.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized to PragmaSetPhaseRelation.
+        """
+
+    def substitute_parameters(
+        self, substitution_parameters: Dict[str, float]
+    ) -> PragmaSetPhaseRelation:
+        """
+        Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+
+        Args:
+            substitution_parameters (dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+
+        Returns:
+            self: The PRAGMA operation operation with the parameters substituted.
+
+        Raises:
+            RuntimeError: The parameter substitution failed.
+        """
+
+    def remap_qubits(self, mapping: Dict[int, int]) -> PragmaSetPhaseRelation:
+        """
+        Remap qubits in a clone of the PRAGMA operation.
+
+        Args:
+            mapping (dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+
+        Returns:
+            self: The PRAGMA operation with the qubits remapped.
+
+        Raises:
+            RuntimeError: The qubit remapping failed.
+        """
@@ -19,8 +19,9 @@ qoqo is the HQS python package to represent quantum circuits.
 
 """
 
-from typing import Optional, List, Tuple, Dict, Union
-from qoqo import Circuit
+import numpy as np
+from typing import Any, Optional, List, Tuple, Dict, Union, Callable
+from qoqo import Circuit, QuantumProgram
 from qoqo.measurements import (
     ClassicalRegister,
     Cheated,
@@ -48,14 +49,29 @@ class SimulatorBackend:
 
     Args:
         device (Union[QRydDevice,TweezerDevice]): The device providing information about the available operations.
+        number_qubits (Optional[int]): The number of qubits the simulator should use. Defaults to `device.number_qubits()`.
+        density_matrix (Optional[bool]): Whether the simulator always uses a density matrix instead of a state vector. Defaults to False.
 
     Raises:
         TypeError: Device Parameter is not QRydDevice or TweezerDevice
     """
 
-    def __init__(self, device: Union[QRydDevice, TweezerDevice]):
+    def __init__(
+        self,
+        device: Union[QRydDevice, TweezerDevice],
+        number_qubits: Optional[int] = None,
+        density_matrix: Optional[bool] = None,
+    ):
         return
 
+    def is_density_matrix(self) -> bool:
+        """
+        Return whether the simulator always uses a density matrix instead of a state vector.
+
+        Returns:
+            bool: Whether the simulator uses a density matrix.
+        """
+
     def to_bincode(self) -> bytearray:
         """
         Return the bincode representation of the SimulatorBackend using the bincode crate.
@@ -107,6 +123,152 @@ class SimulatorBackend:
             ValueError: Input cannot be deserialized to SimulatorBackend.
         """
 
+    def set_noisy(self, noisy: bool):
+        """
+        Set whether the decoherence of the qubits during the gates is simulated.
+
+        After every native gate, each active qubit in a tweezer with noise parameters is subject
+        to amplitude damping with rate 1/T1, dephasing with rate (1/T2 - 1/(2 T1)) / 2 and the
+        depolarising rate of the tweezer, for the gate time of the gate on the device.
+        The noise turns the simulation into a density matrix simulation.
+
+        Args:
+            noisy (bool): Whether to apply the noise of the tweezers after every gate.
+        """
+
+    def is_noisy(self) -> bool:
+        """
+        Return whether the decoherence of the qubits during the gates is simulated.
+
+        Returns:
+            bool: Whether the noise of the tweezers is applied after every gate.
+        """
+
+    def set_number_threads(self, number_threads: int):
+        """
+        Set the number of threads the shots of a circuit are simulated on.
+
+        With more than one thread, the shots of a circuit are split evenly between the threads
+        and every thread runs its own QuEST simulation. The output registers of the threads are
+        concatenated, so registers that are written once per simulation, for example by a
+        PragmaGetStateVector, contain one entry per thread.
+
+        Args:
+            number_threads (int): The number of threads, 1 simulates all shots sequentially and 0 uses one thread per core.
+        """
+
+    def number_threads(self) -> int:
+        """
+        Return the number of threads the shots of a circuit are simulated on.
+
+        Returns:
+            int: The number of threads, 0 uses one thread per core.
+        """
+
+    def set_sample_terminal_measurements(self, sample_terminal_measurements: bool):
+        """
+        Set whether the shots of circuits with terminal measurements are sampled from a single simulation.
+
+        A circuit has terminal measurements if it has no classical control, no float or complex
+        output registers and no operation acts on a qubit after the qubit has been measured. The
+        shots of such a circuit are sampled from its final state instead of simulating the circuit
+        once per shot. Enabled by default.
+
+        Args:
+            sample_terminal_measurements (bool): Whether to sample the shots of circuits with terminal measurements.
+        """
+
+    def sample_terminal_measurements(self) -> bool:
+        """
+        Return whether the shots of circuits with terminal measurements are sampled from a single simulation.
+
+        Returns:
+            bool: Whether the shots of circuits with terminal measurements are sampled.
+        """
+
+    def set_memory_limit(self, memory_limit: Optional[int]):
+        """
+        Set the memory limit for the quantum register of a simulation.
+
+        Simulations whose register does not fit into the limit are rejected before QuEST
+        allocates the register.
+
+        Args:
+            memory_limit (Optional[int]): The memory limit in bytes, None for no limit.
+        """
+
+    def memory_limit(self) -> Optional[int]:
+        """
+        Return the memory limit for the quantum register of a simulation.
+
+        Returns:
+            Optional[int]: The memory limit in bytes, None for no limit.
+        """
+
+    @staticmethod
+    def uses_gpu() -> bool:
+        """
+        Return whether the simulations run on a GPU.
+
+        QuEST selects the CPU or the GPU when it is compiled. Builds with the `simulator-gpu`
+        feature simulate on a CUDA GPU and can not fall back to the CPU at runtime.
+
+        Returns:
+            bool: Whether the simulations run on a GPU.
+        """
+
+    def required_memory(self, density_matrix: bool) -> Optional[int]:
+        """
+        Return the memory of the quantum register of a simulation.
+
+        QuEST stores every amplitude of the state vector, or every entry of the density matrix, as
+        two 8 byte floating point numbers.
+
+        Args:
+            density_matrix (bool): Whether the simulation uses a density matrix instead of a state vector.
+
+        Returns:
+            Optional[int]: The memory of the register in bytes, None if it exceeds the addressable memory.
+        """
+
+    def set_transport_noise(self, transport_noise: bool):
+        """
+        Set whether the decoherence of the qubits during shifts and layout switches is simulated.
+
+        The active qubits are subject to the noise of their tweezers for the transport time of each
+        shift and layout switch, independently of `set_noisy`.
+
+        Args:
+            transport_noise (bool): Whether to apply the noise of the tweezers during shifts and layout switches.
+        """
+
+    def transport_noise(self) -> bool:
+        """
+        Return whether the decoherence of the qubits during shifts and layout switches is simulated.
+
+        Returns:
+            bool: Whether the noise of the tweezers is applied during shifts and layout switches.
+        """
+
+    def transport_time(self, circuit: Circuit) -> float:
+        """
+        Return the accumulated transport time of the shifts and layout switches of a circuit.
+
+        A PragmaShiftQubitsTweezers takes its duration on the device at its position in the
+        circuit and a PragmaSwitchDeviceLayout the switch time of the new Layout. Shifts and layout
+        switches without a configured time do not contribute.
+
+        Args:
+            circuit (Circuit): The circuit to accumulate the transport time of.
+
+        Returns:
+            float: The accumulated transport time.
+
+        Raises:
+            TypeError: Circuit argument cannot be converted to qoqo Circuit
+            ValueError: A device-changing operation of the circuit is rejected by the device.
+        """
+
     def run_circuit(self, circuit: Circuit) -> Tuple[
         Dict[str, List[List[bool]]],
         Dict[str, List[List[float]]],
@@ -185,6 +347,28 @@ class SimulatorBackend:
             RuntimeError: Internal error measurement.evaluation returned unknown type
         """
 
+    def run_template(
+        self, quantumprogram, parameter_grid: List[List[float]]
+    ) -> List[Optional[Dict[str, float]]]:
+        """
+        Runs a parametric QuantumProgram for every set of parameter values of a grid.
+
+        The circuits of the measurement are compiled once, for every set of parameter values
+        only the operations with symbolic parameters are substituted. The values are assigned
+        to the input parameter names of the program.
+
+        Args:
+            quantumprogram (qoqo.QuantumProgram): The PauliZProduct, CheatedPauliZProduct or Cheated QuantumProgram to run.
+            parameter_grid (List[List[float]]): The values of the input parameters, one list per evaluation.
+
+        Returns:
+            List[Optional[Dict[str, float]]]: The expectation values for each set of parameter values.
+
+        Raises:
+            TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+            RuntimeError: The number of parameters does not match, a parameter could not be substituted or the simulation failed.
+        """
+
 class APIBackend:
     """
     Qoqo backend interfacing QRydDemo WebAPI.
@@ -218,7 +402,128 @@ class APIBackend:
             str: URL of the location of the job.
         """
 
-    def get_job_status(self, job_location: str) -> Dict[str, str]:
+    def post_job_with_parameters(self, quantumprogram, parameters: List[float]) -> str:
+        """
+        Substitute the symbolic parameters of a program and post it as a job.
+
+        The WebAPI does not support symbolic parameters. The values are assigned to the
+        input parameter names of the program and substituted into all circuits,
+        including the constant circuit, before the job is serialized.
+
+        Args:
+            quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be executed.
+            parameters (List[float]): The values of the input parameters of the program.
+
+        Returns:
+            str: URL of the location of the job.
+
+        Raises:
+            TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+            RuntimeError: The number of parameters does not match, a circuit remains parametrized or posting the job failed.
+        """
+
+    def post_job_with_seeds(self, quantumprogram, seeds: List[int]) -> Dict[int, str]:
+        """
+        Post a job once per simulator seed and return the locations of the jobs.
+
+        The same quantum program is executed once for every seed, overriding the seed
+        of the device.
+
+        Args:
+            quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be executed.
+            seeds (List[int]): The simulator seeds, one job is posted for each seed.
+
+        Returns:
+            Dict[int, str]: URL of the location of the job posted for each seed.
+
+        Raises:
+            TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+            RuntimeError: The seeds are empty or contain duplicates, or posting a job failed.
+        """
+
+    def post_measurement_jobs(self, quantumprogram) -> List[str]:
+        """
+        Post the jobs of an expectation value measurement and return their locations.
+
+        A PauliZProduct measurement is converted into one ClassicalRegister job per circuit,
+        the expectation values are computed locally with `evaluate_measurement_jobs`.
+        ClassicalRegister programs are posted unchanged.
+
+        Args:
+            quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be executed.
+
+        Returns:
+            List[str]: URL of the location of the job posted for each circuit of the measurement.
+
+        Raises:
+            TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+            RuntimeError: The measurement is not supported by the WebAPI or posting a job failed.
+        """
+
+    def evaluate_measurement_jobs(
+        self, quantumprogram, job_locations: List[str]
+    ) -> Optional[Dict[str, float]]:
+        """
+        Wait for the jobs of an expectation value measurement and evaluate the expectation values.
+
+        Args:
+            quantumprogram (qoqo.QuantumProgram): The PauliZProduct QuantumProgram the jobs were posted for.
+            job_locations (List[str]): The locations returned by `post_measurement_jobs` or `post_job`.
+
+        Returns:
+            Optional[Dict[str, float]]: The expectation values of the measurement.
+
+        Raises:
+            TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+            RuntimeError: The program is not a PauliZProduct measurement, the number of job locations does not match the circuits or a job failed.
+        """
+
+    def estimate_resources(self, quantumprogram) -> Dict[str, Any]:
+        """
+        Estimate the resources of a program before posting it.
+
+        The number of qubits, native gates and shots and the execution time are computed
+        locally from the gate times of the device of the backend. Gates not provided by the
+        device are counted with a standard decomposition into native gates.
+
+        Args:
+            quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be estimated.
+
+        Returns:
+            Dict[str, Any]: The number_qubits, number_circuits, native_gate_counts, number_shots,
+                estimated_time and the gates_without_time whose time is not provided by the device.
+
+        Raises:
+            TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+            ValueError: The program is neither a ClassicalRegister nor a PauliZProduct program.
+        """
+
+    def compare_api_versions(
+        self, candidate: APIBackend, quantumprogram, tolerance: float
+    ) -> Dict[str, Any]:
+        """
+        Post a program to this backend and a candidate backend and compare the statuses and results.
+
+        This backend serves as reference, e.g. with api_version "v1_1", while the candidate
+        uses the WebAPI version to be validated, e.g. with the dev flag set.
+        Differing job statuses, result metadata and numbers of shots are reported as discrepancies,
+        as is a total variation distance between the measured distributions above `tolerance`.
+
+        Args:
+            candidate (APIBackend): The backend using the WebAPI version to be validated.
+            quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be executed on both backends.
+            tolerance (float): The maximal total variation distance that is not reported as a discrepancy.
+
+        Returns:
+            Dict[str, Any]: The API versions, statuses and counts of both runs, the total variation
+                distance (None if a job did not complete) and the list of discrepancies.
+
+        Raises:
+            TypeError: candidate is not an APIBackend or quantumprogram is not a qoqo.QuantumProgram.
+            RuntimeError: The tolerance is negative or posting or polling a job failed.
+        """
+
+    def get_job_status(self, job_location: str) -> Dict[str, Union[str, int, float]]:
         """
         Get status of a posted WebAPI job.
 
@@ -226,7 +531,8 @@ class APIBackend:
             job_location (str): location (url) of the job one is interested in.
 
         Returns:
-            Dict[str, str]: status and message of the job.
+            Dict[str, Union[str, int, float]]: status and message of the job. The queue_position,
+                estimated_start_time and progress of the job are included when provided by the WebAPI.
 
         """
 
@@ -238,8 +544,62 @@ class APIBackend:
             job_location (str): location (url) of the job one is interested in.
 
         Returns
-            dict: Result of the job.
+            dict: Result of the job. For jobs run with `dev=True`, "dev_diagnostics" holds the
+                diagnostic fields returned by the develop version of the WebAPI, None otherwise.
+
+        """
+
+    def get_job_result_raw(self, job_location: str) -> dict:
+        """
+        Get the result of a completed WebAPI job as untyped JSON.
+
+        Contrary to `get_job_result`, all fields returned by the WebAPI are kept,
+        including fields added in newer WebAPI versions.
+
+        Args:
+            job_location (str): location (url) of the job one is interested in.
+
+        Returns:
+            dict: The JSON body returned by the WebAPI.
+
+        Raises:
+            RuntimeError: Error retrieving the job result.
+        """
+
+    def get_job_result_array(self, job_location: str, number_qubits: int) -> np.ndarray:
+        """
+        Get the measured bits of a completed WebAPI job as a numpy array.
+
+        The bits of all shots are stored in a single boolean array,
+        which keeps the memory footprint low for large numbers of shots.
+
+        Args:
+            job_location (str): location (url) of the job one is interested in.
+            number_qubits (int): The number of measured qubits.
+
+        Returns:
+            np.ndarray: The measured bits with shape (number of shots, number_qubits).
+
+        Raises:
+            RuntimeError: Error retrieving the job result or parsing the measured bits.
+        """
 
+    @staticmethod
+    def counts_to_array(counts: Dict[str, int], number_qubits: int) -> np.ndarray:
+        """
+        Convert measured bitstring counts to a numpy array of the measured bits.
+
+        Row `i` of the array holds the measured bits of shot `i`, column `j` the bit of qubit `j`.
+
+        Args:
+            counts (Dict[str, int]): The counts returned by the WebAPI, keyed by hexadecimal bitstrings.
+            number_qubits (int): The number of measured qubits.
+
+        Returns:
+            np.ndarray: The measured bits with shape (number of shots, number_qubits).
+
+        Raises:
+            ValueError: A measurement result could not be parsed.
         """
 
     def delete_job(self, job_location: str):
@@ -335,6 +695,32 @@ class APIBackend:
             RuntimeError: Running Circuit failed
         """
 
+    def run_circuit_with_seeds(self, circuit: Circuit, seeds: List[int]) -> Dict[
+        int,
+        Tuple[
+            Dict[str, List[List[bool]]],
+            Dict[str, List[List[float]]],
+            Dict[str, List[List[complex]]],
+        ],
+    ]:
+        """
+        Run a circuit once per simulator seed with the QRyd APIBackend.
+
+        All jobs are posted before the results are polled, so the runs can be
+        executed concurrently on the WebAPI.
+
+        Args:
+            circuit (Circuit): The circuit that is run on the APIBackend.
+            seeds (List[int]): The simulator seeds, the circuit is executed once for each seed.
+
+        Returns:
+            Dict[int, Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]]: The output registers of the run with each seed.
+
+        Raises:
+            TypeError: Circuit argument cannot be converted to qoqo Circuit
+            RuntimeError: The seeds are empty or contain duplicates, or running the Circuit failed
+        """
+
     def run_measurement_registers(
         self,
         measurement: Union[Cheated, ClassicalRegister, CheatedPauliZProduct, PauliZProduct],
@@ -394,6 +780,159 @@ class APIBackend:
 
         """
 
+    def capabilities(self) -> dict:
+        """
+        Query the capabilities of the used WebAPI version and record them in the backend.
+
+        Once recorded, `post_job` rejects programs containing operations the WebAPI version
+        does not support and omits job fields it does not understand.
+
+        Returns:
+            dict: The API version, supported operations and supported job fields.
+
+        Raises:
+            RuntimeError: Could not retrieve the capabilities.
+        """
+
+    def allow_operation(self, hqslang: str):
+        """
+        Adds an operation to the operations accepted by `post_job`.
+
+        Allows exercising new capabilities of the WebAPI before they are supported by a release
+        of qoqo-qryd. Operations not accepted by default can only be added with the dev option set
+        and are only accepted as long as it is set.
+
+        Args:
+            hqslang (str): The hqslang name of the operation.
+
+        Raises:
+            ValueError: The operation is not accepted by default and the dev option is not set.
+        """
+
+    def disallow_operation(self, hqslang: str):
+        """
+        Removes an operation from the operations accepted by `post_job`.
+
+        Args:
+            hqslang (str): The hqslang name of the operation.
+        """
+
+    def reset_supported_operations(self):
+        """
+        Resets the operations accepted by `post_job` to the default operations.
+        """
+
+    def supported_operations(self) -> List[str]:
+        """
+        Returns the names of the operations currently accepted by `post_job`.
+
+        Returns:
+            List[str]: The sorted hqslang names of the accepted operations.
+        """
+
+    def set_compress_payload(self, compress_payload: bool):
+        """
+        Setter for the gzip compression of posted job payloads.
+
+        When enabled, the serialized job is sent gzip compressed with a
+        `Content-Encoding: gzip` header, reducing the upload size of large circuits.
+
+        Args:
+            compress_payload (bool): Whether to compress the body of posted jobs.
+
+        """
+
+    def compress_payload(self) -> bool:
+        """
+        Returns whether the body of posted jobs is gzip compressed.
+
+        Returns:
+            bool: Whether the body of posted jobs is compressed.
+        """
+
+    def set_client_config(
+        self,
+        pool_max_idle_per_host: Optional[int] = None,
+        pool_idle_timeout: Optional[float] = None,
+        tcp_keepalive: Optional[float] = None,
+        request_timeout: Optional[float] = None,
+        connect_timeout: Optional[float] = None,
+    ):
+        """
+        Setter for the configuration of the HTTP client used for the requests to the WebAPI.
+
+        The client is built on the first request and reused by all following requests of the
+        backend, keeping the connections to the WebAPI open between polls. Options that are
+        not set keep the defaults of the HTTP client.
+
+        Args:
+            pool_max_idle_per_host (Optional[int]): The maximal number of idle connections kept open.
+            pool_idle_timeout (Optional[float]): The time in seconds after which idle connections are closed.
+            tcp_keepalive (Optional[float]): The interval in seconds of the TCP keep-alive probes.
+            request_timeout (Optional[float]): The timeout of a request in seconds.
+            connect_timeout (Optional[float]): The timeout for establishing a connection in seconds.
+
+        Raises:
+            ValueError: A time is negative or not finite.
+        """
+
+    def client_config(self) -> Dict[str, Optional[Union[int, float]]]:
+        """
+        Returns the configuration of the HTTP client used for the requests to the WebAPI.
+
+        Returns:
+            Dict[str, Optional[Union[int, float]]]: The pool size and the keep-alive and timeouts in seconds.
+        """
+
+    def set_fixture_mode(self, mode: str, file: str):
+        """
+        Sets the backend to record or replay the requests to the WebAPI.
+
+        In "record" mode, every request is sent to the WebAPI (or the mock server) as usual and
+        the request method and path are written to the fixture file together with the response.
+        In "replay" mode, the responses are served from the fixture file without accessing the
+        network, in the order they were recorded for each method and path.
+
+        Args:
+            mode (str): Either "record" or "replay".
+            file (str): The JSON file the fixtures are written to or read from.
+
+        Raises:
+            ValueError: Unknown mode or the fixture file could not be read in replay mode.
+        """
+
+    def fixture_mode(self) -> Optional[str]:
+        """
+        Returns the fixture mode of the backend.
+
+        Returns:
+            Optional[str]: "record", "replay" or None if the requests are neither recorded nor replayed.
+        """
+
+    def clear_fixture_mode(self):
+        """
+        Stops recording or replaying the requests to the WebAPI.
+        """
+
+    def set_progress_callback(
+        self, callback: Optional[Callable[[Dict[str, Union[str, int, float]]], None]]
+    ):
+        """
+        Sets a callback that is called with the job progress on each poll of `run_circuit`,
+        `run_measurement` and `run_measurement_registers`, e.g. to update a progress bar.
+
+        The callback receives the dictionary returned by `get_job_status`, extended by the
+        `job_location`, the `elapsed` time in seconds since posting the job, the `attempt`
+        number of the poll and the `max_attempts` before the run times out.
+        Passing None removes the callback.
+
+        Args:
+            callback (Optional[Callable[[Dict[str, Union[str, int, float]]], None]]): The function called with the job progress.
+
+        Raises:
+            TypeError: The callback is not callable.
+        """
+
 class qryd_devices:
     """
     Prototype qoqo devices for Rydberg hardware
@@ -564,6 +1103,10 @@ class pragma_operations:
        PragmaDeactivateQRydQubit
        PragmaShiftQubitsTweezers
        PragmaSwitchDeviceLayout
+       PragmaSwapTweezers
+       PragmaGlobalRotateXY
+       PragmaMoveToZone
+       PragmaSetPhaseRelation
     """
 
     def __init__(self):
@@ -628,3 +1171,188 @@ class pragma_operations:
         Args:
             new_layout (str): The name of the new layout.
         """
+
+    def PragmaSwapTweezers(self, tweezer_0: int, tweezer_1: int):
+        """
+        This PRAGMA Operation exchanges the qubits of two occupied tweezers in a QRyd Tweezer device.
+
+        The qubits trapped in the two tweezers swap places, the qubit -> tweezer mapping of the
+        device is updated in one step.
+
+        Args:
+            tweezer_0 (int): The first tweezer of the exchange.
+            tweezer_1 (int): The second tweezer of the exchange.
+        """
+
+    def PragmaGlobalRotateXY(self, theta: float, phi: float):
+        """
+        This PRAGMA Operation applies a global single-qubit rotation to all qubits of a QRyd Tweezer device.
+
+        Rydberg platforms drive global Raman pulses addressing all atoms simultaneously. The
+        operation acts as a RotateXY gate with the same angles on every active qubit of the device
+        and takes the global rotation time of the current Layout.
+
+        Args:
+            theta (CalculatorFloat): The angle theta of the rotation, in the interval from 0 to 2 * 2pi.
+            phi (CalculatorFloat): The rotation axis, in spherical coordinates phi gives the angle in the x-y plane.
+        """
+
+    def PragmaMoveToZone(self, qubits: List[int], zone: str):
+        """
+        This PRAGMA Operation moves qubits into a zone of a QRyd Tweezer device.
+
+        The device resolves the operation into shifts of the qubits into the nearest free tweezers
+        labelled with the zone, so that transfers between e.g. the storage and the interaction zone
+        can be expressed without giving the tweezers.
+
+        Args:
+            qubits (List[int]): The qubits to move, in the order they are moved.
+            zone (str): The label of the zone the qubits are moved into.
+        """
+
+    def PragmaSetPhaseRelation(self, cz_relation: str, cp_relation: str):
+        """
+        This PRAGMA Operation sets the phase relations of a QRyd Tweezer device.
+
+        The phase relations give the phase of the PhaseShiftedControlledZ and
+        PhaseShiftedControlledPhase gates. Changing them mid-circuit expresses circuits calibrated
+        for different interaction strengths in a single program.
+
+        Args:
+            cz_relation (str): The relation to use for the PhaseShiftedControlledZ gate.
+            cp_relation (str): The relation to use for the PhaseShiftedControlledPhase gate.
+        """
+
+def counts_histogram(counts: Dict[str, int]) -> List[Tuple[str, int]]:
+    """
+    Returns the histogram data of measured bitstring counts, sorted by bitstring.
+
+    The returned data can be used directly for plotting, e.g. with matplotlib.
+
+    Args:
+        counts (Dict[str, int]): The number of times each bitstring was measured.
+
+    Returns:
+        List[Tuple[str, int]]: The sorted (bitstring, count) pairs.
+    """
+
+def plot_counts(
+    counts: Dict[str, int],
+    pixel_per_point: Optional[float] = None,
+    file_save_path: Optional[str] = None,
+):
+    """
+    Creates a bar chart of measured bitstring counts.
+
+    Args:
+        counts (Dict[str, int]): The number of times each bitstring was measured.
+        pixel_per_point (Optional[float]): The quality of the image.
+        file_save_path (Optional[str]): Path to save the image to. Default: output the image with the display method.
+
+    Raises:
+        PyValueError - if the counts are empty, an error occurred during the compilation or and invalid path was provided.
+    """
+
+def sanitize_for_api(circuit: Circuit) -> Tuple[Circuit, Dict[str, Union[List[str], int]]]:
+    """
+    Strips the simulation-only pragmas from a circuit so it can be submitted to the QRyd WebAPI.
+
+    Readout, state initialisation, noise and compiler hint pragmas are removed,
+    PragmaLoop operations with a numeric number of repetitions are unrolled.
+
+    Args:
+        circuit (Circuit): The circuit to sanitize.
+
+    Returns:
+        Tuple[Circuit, Dict[str, Union[List[str], int]]]: The sanitized circuit and a report with the
+            hqslang names of the removed operations ("removed") and the number of unrolled loops ("unrolled_loops").
+
+    Raises:
+        TypeError: Circuit argument cannot be converted to qoqo Circuit.
+    """
+
+def substitute_circuit_parameters(
+    circuit: Circuit, substitution_parameters: Dict[str, float]
+) -> Circuit:
+    """
+    Substitutes the symbolic parameters of a circuit, including the parameters of the QRyd pragmas.
+
+    The QRyd pragmas are added to circuits wrapped in PragmaChangeDevice operations, whose own
+    parameter substitution leaves the wrapped pragma unchanged.
+
+    Args:
+        circuit (Circuit): The circuit to substitute the parameters of.
+        substitution_parameters (Dict[str, float]): The values of the symbolic parameters.
+
+    Returns:
+        Circuit: The circuit with the substituted parameters.
+
+    Raises:
+        TypeError: Circuit argument cannot be converted to qoqo Circuit.
+        RuntimeError: A symbolic parameter could not be substituted.
+    """
+
+def substitute_program_parameters(
+    quantumprogram: QuantumProgram, parameters: List[float]
+) -> QuantumProgram:
+    """
+    Substitutes the input parameters of a QuantumProgram, including the parameters of the QRyd pragmas.
+
+    The values are assigned to the input parameter names of the program, as in QuantumProgram.run.
+    The returned program has no input parameters and can be run with an empty list of parameters.
+
+    Args:
+        quantumprogram (QuantumProgram): The QuantumProgram to substitute the parameters of.
+        parameters (List[float]): The values of the input parameters of the program.
+
+    Returns:
+        QuantumProgram: The program with the substituted parameters.
+
+    Raises:
+        TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+        RuntimeError: The number of parameters does not match or a symbolic parameter could not be substituted.
+    """
+
+def remap_circuit_qubits(circuit: Circuit, mapping: Dict[int, int]) -> Circuit:
+    """
+    Remaps the qubits of a circuit, including the qubits of the QRyd pragmas.
+
+    The QRyd pragmas are added to circuits wrapped in PragmaChangeDevice operations, whose own
+    qubit remapping leaves the wrapped pragma unchanged. Pragmas referring to Layouts or tweezers
+    are not changed by the remapping.
+
+    Args:
+        circuit (Circuit): The circuit to remap the qubits of.
+        mapping (Dict[int, int]): The mapping from the old to the new qubit indices.
+
+    Returns:
+        Circuit: The circuit with the remapped qubits.
+
+    Raises:
+        TypeError: Circuit argument cannot be converted to qoqo Circuit.
+        RuntimeError: The qubits of an operation could not be remapped.
+    """
+
+def check_circuit_compatibility(
+    circuit: Circuit, device: TweezerDevice
+) -> List[Dict[str, Union[int, str, List[int], Optional[str]]]]:
+    """
+    Checks every operation of a circuit against a TweezerDevice.
+
+    The device-changing pragmas are applied to a copy of the device, so every gate is checked
+    against the Layout, qubit positions and deactivated qubits at its position in the circuit.
+    A device-changing pragma the device rejects is reported and leaves the device unchanged.
+
+    Args:
+        circuit (Circuit): The circuit to check.
+        device (TweezerDevice): The device the circuit is run on.
+
+    Returns:
+        List[Dict[str, Union[int, str, List[int], Optional[str]]]]: One report per incompatible operation
+            with the index of the operation in the circuit ("index"), its hqslang name ("hqslang"), the qubits
+            it acts on ("qubits"), the current Layout at its position ("layout") and the reason ("reason").
+            The list is empty if the circuit is compatible with the device.
+
+    Raises:
+        TypeError: Circuit argument cannot be converted to qoqo Circuit or device argument is not a TweezerDevice.
+    """
@@ -57,6 +57,36 @@ class FirstDevice(QRydDevice):
     ):
         return
 
+    @staticmethod
+    def ragged(
+        qubits_per_row: List[int],
+        initial_positions: List[List[(float, float)]],
+        controlled_z_phase_relation: Optional[Union[str, float]],
+        controlled_phase_phase_relation: Optional[Union[str, float]],
+        allow_ccz_gate: Optional[bool],
+        allow_ccp_gate: Optional[bool],
+    ) -> FirstDevice:
+        """
+        Create new `First` QRyd device with a different number of tweezers in each row.
+
+        The tweezers do not need to form a regular grid, the (x, y) position of every tweezer is given explicitly.
+        After creation the device will be in the initial layout with layout number 0.
+
+        Args:
+            qubits_per_row (List[int]): Fixed number of occupied tweezer positions in each row.
+            initial_positions (List[List[(float, float)]]): The (x, y) position of every tweezer, given row by row.
+            controlled_z_phase_relation (Optional[Union[str, float]]): The relation to use for the PhaseShiftedControlledZ gate.
+            controlled_phase_phase_relation (Optional[Union[str, float]]): The relation to use for the PhaseShiftedControlledPhase gate.
+            allow_ccz_gate (Optional[bool]): Whether to allow ControlledControlledPauliZ operations in the device.
+            allow_ccp_gate (Optional[bool]): Whether to allow ControlledControlledPhaseShift operations in the device.
+
+        Returns:
+            FirstDevice: The new device.
+
+        Raises:
+            ValueError: More qubits than tweezers are specified for a row.
+        """
+
     def single_qubit_gate_time(self) -> float:
         """
         Returns the gate time of a single qubit operation on this device.
@@ -216,6 +246,30 @@ class FirstDevice(QRydDevice):
 
         """
 
+    def columns_per_row(self) -> List[int]:
+        """
+        Return the number of tweezer positions in each row.
+
+        Returns:
+            List[int]: The number of tweezers in each row.
+
+        """
+
+    def tweezer_position(self, row: int, column: int) -> (float, float):
+        """
+        Return the physical (x, y) position of a tweezer in the current layout.
+
+        Args:
+            row (int): The row of the tweezer.
+            column (int): The column of the tweezer.
+
+        Returns:
+            (float, float): The position of the tweezer.
+
+        Raises:
+            ValueError: The tweezer does not exist in the device.
+        """
+
     def qubit_positions(self) -> Dict[int, (int, int)]:
         """
         Return the position of each qubit in the row-column grid of tweezer positions.
@@ -264,6 +318,25 @@ class FirstDevice(QRydDevice):
             PyValueError: layout number is already in use
         """
 
+    def add_irregular_layout(
+        self, layout_number: int, positions: List[List[(float, float)]]
+    ) -> FirstDevice:
+        """
+        Add a new layout with explicit tweezer positions to the device.
+
+        The number of positions in each row has to match the number of tweezers in the row.
+
+        Args:
+            layout_number (int): The number index that is assigned to the new layout
+            positions (List[List[(float, float)]]): The (x, y) position of every tweezer, given row by row
+
+        Returns:
+            FirstDevice: A copy of the device with the new layout added.
+
+        Raises:
+            PyValueError: layout number is already in use or the positions do not fit the tweezers per row
+        """
+
     def set_cutoff(self, cutoff: float):
         """
         Set distance cutoff for two-qubit gate operations.
@@ -278,6 +351,162 @@ class FirstDevice(QRydDevice):
             cutoff (float): The new cutoff for interaction distance
         """
 
+    def cutoff(self) -> float:
+        """
+        Return the distance cutoff for two-qubit gate operations.
+
+        Returns:
+            float: The cutoff for interaction distance
+        """
+
+    def set_power_law_gate_model(
+        self, prefactor: float, exponent: float, fidelity: Optional[float] = None
+    ):
+        """
+        Set a power-law model for the distance dependence of the two-qubit gates.
+
+        The gate time is `prefactor * distance^exponent`, the fidelity is the same for all distances.
+        Gate times are calculated from the current qubit positions, so they follow every
+        PragmaShiftQRydQubit and layout change.
+
+        Args:
+            prefactor (float): The gate time at distance 1.0.
+            exponent (float): The exponent of the distance.
+            fidelity (Optional[float]): The fidelity of the gate, defaults to 1.0.
+
+        Raises:
+            ValueError: A parameter of the model is not valid.
+        """
+
+    def set_van_der_waals_gate_model(
+        self, c6: float, phase: float, decay_rate: Optional[float] = None
+    ):
+        """
+        Set a van der Waals model for the distance dependence of the two-qubit gates.
+
+        The interaction strength is `c6 / distance^6`, the gate time is the time to accumulate
+        the interaction phase, `phase * distance^6 / c6`, and the fidelity is
+        `exp(-decay_rate * gate_time)`.
+        Gate times are calculated from the current qubit positions, so they follow every
+        PragmaShiftQRydQubit and layout change.
+
+        Args:
+            c6 (float): The C6 interaction coefficient.
+            phase (float): The interaction phase accumulated during the gate.
+            decay_rate (Optional[float]): The decay rate of the fidelity during the gate, defaults to 0.0.
+
+        Raises:
+            ValueError: A parameter of the model is not valid.
+        """
+
+    def reset_distance_gate_model(self):
+        """
+        Go back to the default model of the two-qubit gates with a gate time of `2e-6 * distance^2`.
+        """
+
+    def distance_gate_model(self) -> Optional[str]:
+        """
+        Return the json representation of the distance model of the two-qubit gates.
+
+        Returns:
+            Optional[str]: The serialized model, None for the default model.
+
+        Raises:
+            ValueError: Cannot serialize the model to json.
+        """
+
+    def two_qubit_gate_fidelity(self, hqslang: str, control: int, target: int) -> float:
+        """
+        Returns the fidelity of a two qubit operation on this device.
+
+        Args:
+            hqslang (str): The name of the two-qubit gate.
+            control (int): The control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
+
+        Returns:
+            float: The gate fidelity.
+
+        Raises:
+            ValueError: The gate is not available in the device.
+        """
+
+    def set_qubit_position(self, qubit: int, column: int):
+        """
+        Move a single qubit to an unoccupied tweezer position in its row.
+
+        Args:
+            qubit (int): The qubit that is moved
+            column (int): The new column of the qubit
+
+        Raises:
+            ValueError: The qubit is not in the device or the new position does not exist or is occupied
+        """
+
+    def set_tweezer_position(self, row: int, column: int, new_position: (float, float)):
+        """
+        Set the physical (x, y) position of a tweezer in the current layout.
+
+        Args:
+            row (int): The row of the tweezer
+            column (int): The column of the tweezer
+            new_position ((float, float)): The new (x, y) position of the tweezer
+
+        Raises:
+            ValueError: The tweezer does not exist in the device
+        """
+
+    def allow_ccz_gate(self) -> bool:
+        """
+        Return whether the device allows ControlledControlledPauliZ operations.
+
+        Returns:
+            bool: Whether ControlledControlledPauliZ operations are allowed.
+        """
+
+    def set_allow_ccz_gate(self, allow_ccz_gate: bool):
+        """
+        Set whether the device allows ControlledControlledPauliZ operations.
+
+        Args:
+            allow_ccz_gate (bool): Whether to allow ControlledControlledPauliZ operations.
+        """
+
+    def allow_ccp_gate(self) -> bool:
+        """
+        Return whether the device allows ControlledControlledPhaseShift operations.
+
+        Returns:
+            bool: Whether ControlledControlledPhaseShift operations are allowed.
+        """
+
+    def set_allow_ccp_gate(self, allow_ccp_gate: bool):
+        """
+        Set whether the device allows ControlledControlledPhaseShift operations.
+
+        Args:
+            allow_ccp_gate (bool): Whether to allow ControlledControlledPhaseShift operations.
+        """
+
+    def available_three_qubit_gates(self) -> List[str]:
+        """
+        Return the three-qubit gates allowed in the device.
+
+        Returns:
+            List[str]: The names of the allowed three-qubit gates.
+        """
+
+    def three_qubit_gate_triples(self, hqslang: str) -> List[(int, int, int)]:
+        """
+        Return all qubit triples a three-qubit gate is available on.
+
+        Args:
+            hqslang (str): The name of the three-qubit gate.
+
+        Returns:
+            List[(int, int, int)]: The (control_0, control_1, target) triples, sorted.
+        """
+
     def _enum_to_bincode(self) -> bytearray:
         """
         Return the bincode representation of the Enum variant of the Device.
@@ -0,0 +1,114 @@
+# This is an auto generated file containing only the documentation.
+# You can find the full implementation on this page:
+# https://github.com/HQSquantumsimulations/qoqo_qryd
+
+"""
+State tomography experiments for QRyd devices.
+
+.. autosummary::
+   :toctree: generated/
+
+   StateTomography
+
+"""
+
+from typing import Optional, List, Any
+import numpy as np
+from qoqo import Circuit
+
+class StateTomography:
+    """
+    Experiment builder for quantum state tomography.
+
+    For `n` target qubits, `3^n` measurement settings are generated, measuring every
+    target qubit in the X, Y or Z basis. The basis rotations only use the native
+    `RotateX` and `RotateXY` gates.
+
+    Args:
+        qubits (List[int]): The qubits whose state is reconstructed.
+        number_measurements (int): The number of measurements performed for each measurement setting.
+
+    Raises:
+        ValueError: No qubits, duplicated qubits or no measurements were given.
+    """
+
+    def __init__(self, qubits: List[int], number_measurements: int):
+        return
+
+    def qubits(self) -> List[int]:
+        """
+        Returns the qubits whose state is reconstructed.
+
+        Returns:
+            List[int]: The target qubits.
+        """
+
+    def number_measurements(self) -> int:
+        """
+        Returns the number of measurements performed for each measurement setting.
+
+        Returns:
+            int: The number of measurements.
+        """
+
+    def measurement_settings(self) -> List[List[str]]:
+        """
+        Returns the measurement settings of the experiment.
+
+        The i-th entry of each setting is the basis ("X", "Y" or "Z") the i-th target qubit is measured in.
+
+        Returns:
+            List[List[str]]: The measurement settings.
+        """
+
+    def measurement_circuits(self, preparation: Circuit) -> List[Circuit]:
+        """
+        Returns the measurement circuits of the experiment.
+
+        Each circuit consists of the preparation circuit followed by the basis rotations and
+        the measurement of the target qubits into the `ro_tomography` register.
+
+        Args:
+            preparation (Circuit): The circuit preparing the state to reconstruct.
+
+        Returns:
+            List[Circuit]: The measurement circuits, one per measurement setting.
+
+        Raises:
+            TypeError: Preparation cannot be converted to a qoqo Circuit.
+        """
+
+    def reconstruct(
+        self, registers: List[List[List[bool]]], method: Optional[str] = None
+    ) -> np.ndarray:
+        """
+        Reconstructs the density matrix from the measured bit registers.
+
+        Args:
+            registers (List[List[List[bool]]]): The measured bit registers, one per measurement setting.
+            method (Optional[str]): "linear_inversion" (default) or "maximum_likelihood".
+
+        Returns:
+            np.ndarray: The reconstructed density matrix.
+
+        Raises:
+            ValueError: Unknown method or the registers do not match the measurement settings.
+        """
+
+    def run(self, backend: Any, preparation: Circuit, method: Optional[str] = None) -> np.ndarray:
+        """
+        Runs the experiment on a backend and reconstructs the density matrix.
+
+        Args:
+            backend (Union[SimulatorBackend, APIBackend]): The backend the measurement circuits are run on.
+            preparation (Circuit): The circuit preparing the state to reconstruct.
+            method (Optional[str]): "linear_inversion" (default) or "maximum_likelihood".
+
+        Returns:
+            np.ndarray: The reconstructed density matrix.
+
+        Raises:
+            TypeError: Preparation cannot be converted to a qoqo Circuit.
+            RuntimeError: Running a measurement circuit failed.
+            ValueError: Unknown method or the reconstruction failed.
+        """
@@ -13,8 +13,12 @@ Tweezer devices for the QRyd platform.
 
 """
 
-from typing import Optional, List, Dict, Union, Sequence
+from typing import Optional, List, Dict, Union, Sequence, Tuple, Callable
+from qoqo import Circuit  # type: ignore
 from qoqo.devices import GenericDevice
+from qoqo.operations import Operation  # type: ignore
+from .pragma_operations import PragmaMoveToZone, PragmaShiftQubitsTweezers  # type: ignore
+from .qryd_devices import FirstDevice  # type: ignore
 
 class TweezerDevice:
     """
@@ -48,6 +52,54 @@ class TweezerDevice:
             TweezerDevice: The new TweezerDevice instance.
         """
 
+    @staticmethod
+    def from_calibration_file(path: str) -> TweezerDevice:
+        """
+        Creates a new TweezerDevice from a calibration file.
+
+        The calibration file is a JSON document, or a YAML document if the file extension is
+        `.yaml` or `.yml`, containing the gate times, gate fidelities, allowed shifts, tweezer
+        coordinates and tweezers per row of each Layout. Every Layout of the file is added to
+        the device, the default Layout is set as the current Layout.
+
+        Args:
+            path (str): The path of the calibration file.
+
+        Returns:
+            TweezerDevice: The device described by the calibration file.
+
+        Raises:
+            ValueError: The file could not be read or parsed, or a field is invalid.
+        """
+
+    @staticmethod
+    def from_file(path: str, format: Optional[str]) -> TweezerDevice:
+        """
+        Loads a TweezerDevice from a file written by `to_file`.
+
+        Args:
+            path (str): The path of the file.
+            format (Optional[str]): The format of the file, "json", "bincode" or "yaml". Default: inferred from the extension, `.json`, `.bin`, `.bincode`, `.yaml` or `.yml`.
+
+        Returns:
+            TweezerDevice: The device stored in the file.
+
+        Raises:
+            ValueError: The format is unknown or the file could not be read or deserialized.
+        """
+
+    def to_file(self, path: str, format: Optional[str]):
+        """
+        Saves the TweezerDevice to a file.
+
+        Args:
+            path (str): The path of the file.
+            format (Optional[str]): The format of the file, "json", "bincode" or "yaml". Default: inferred from the extension, `.json`, `.bin`, `.bincode`, `.yaml` or `.yml`.
+
+        Raises:
+            ValueError: The format is unknown or the device could not be serialized or written.
+        """
+
     @staticmethod
     def from_api(
         device_name: Optional[str],
@@ -87,7 +139,12 @@ class TweezerDevice:
             str: The name of the current layout.
         """
 
-    def switch_layout(self, layout_number: str, with_trivial_map: bool):
+    def switch_layout(
+        self,
+        layout_number: str,
+        with_trivial_map: Optional[bool] = None,
+        mapping_policy: Optional[str] = None,
+    ):
         """
         Switch to a different pre-defined Layout.
 
@@ -98,11 +155,28 @@ class TweezerDevice:
         Args:
             layout_number (str): The number index of the new Layout.
             with_trivial_map (bool): Whether the qubit -> tweezer mapping should be trivially populated. Defaults to true.
+            mapping_policy (Optional[str]): The policy populating the qubit -> tweezer mapping, one of
+                `all-tweezers`, `usable-tweezers`, `occupied-first` or `none`. Overrides `with_trivial_map`.
 
         Raises:
             PyValueError
         """
 
+    def switch_layout_with_mapping(self, name: str, qubit_to_tweezer: Dict[int, int]):
+        """
+        Switch to a different pre-defined Layout with an explicit qubit -> tweezer mapping.
+
+        The mapping replaces the current qubit -> tweezer mapping. It is validated against the
+        new Layout before switching, the device is left unchanged if the validation fails.
+
+        Args:
+            name (str): The name of the new Layout.
+            qubit_to_tweezer (Dict[int, int]): The qubit -> tweezer mapping to use in the new Layout.
+
+        Raises:
+            ValueError: The Layout is not set, a tweezer of the mapping is not present in the Layout or several qubits are mapped to the same tweezer.
+        """
+
     def available_layouts(self) -> List[str]:
         """
         Returns a list of all available Layout names.
@@ -111,454 +185,472 @@ class TweezerDevice:
             List[str]: The list of all available Layout names.
         """
 
-    def add_qubit_tweezer_mapping(self, qubit: int, tweezer: int) -> Dict[int, int]:
+    def capability_manifest(self) -> str:
         """
-        Modifies the qubit -> tweezer mapping of the device.
+        Returns the capability manifest of the device as a compact JSON document.
 
-        If a qubit -> tweezer mapping is already present, it is overwritten.
-
-        Args:
-            qubit (int): The index of the qubit.
-            tweezer (int): The index of the tweezer.
+        The manifest summarizes the gates, connectivity and limits of the current Layout, the
+        phase relations and the noise parameters. The schema is versioned by the
+        `schema_version` field.
 
         Returns:
-            dict[int, int]: The updated qubit -> tweezer mapping.
+            str: The JSON capability manifest.
 
         Raises:
-            ValueError: The tweezer is not present in the device.
+            ValueError: The manifest could not be created.
         """
 
-    def get_qubit_to_tweezer_mapping(self) -> Dict[int, int]:
+    def apply_update(self, json_patch: str):
         """
-        Get the qubit -> tweezer mapping of the device.
+        Applies a partial update of the device, e.g. a calibration delta served by the WebAPI.
 
-        Returns:
-            dict[int, int]: The qubit -> tweezer mapping.
-            None: The mapping is empty.
+        The update is a JSON patch (RFC 6902) against the JSON serialization of the device
+        returned by `to_json`. The device is only changed if the whole patch could be applied.
+
+        Args:
+            json_patch (str): The JSON patch, an array of operations.
+
+        Raises:
+            ValueError: The patch is invalid, could not be applied or results in an invalid device.
         """
 
-    def get_available_gates_names(self, layout_name: Optional[str]) -> List[str]:
+    def tweezer_coordinates(self, layout_name: Optional[str]) -> Dict[int, Tuple[float, float]]:
         """
-        Get the names of the available gates in the given layout.
+        Returns the (x, y) coordinates of the tweezers of a given Layout.
 
         Args:
-            layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
 
         Returns:
-            list[str]: List of the names of the available gates in the given layout.
+            dict[int, (float, float)]: The coordinates of the tweezers that have coordinates.
 
         Raises:
-            ValueError: No layout name provided and no current layout set.
-        """
-
-    def get_allow_reset(self) -> bool:
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
         """
-        Get whether the device allows PragmaActiveReset operations or not.
 
-        Returns:
-            bool: Whether the device allows PragmaActiveReset operations or not.
+    def infer_tweezers_per_row(self, layout_name: Optional[str]) -> Optional[List[int]]:
         """
+        Infers the tweezers per row of a given Layout.
 
-    def deactivate_qubit(self, qubit: int) -> Dict[int, int]:
-        """
-        Deactivate the given qubit in the device.
+        If all tweezers of the Layout have coordinates, the rows are the tweezers with the same
+        y coordinate, ordered by increasing y. Otherwise, if every tweezer can be shifted or
+        shifted into, the rows are the groups of tweezers connected by allowed shifts, ordered
+        by their smallest tweezer. Dynamic layout switching uses the inferred tweezers per row
+        of Layouts without tweezers per row set.
 
         Args:
-            qubit (int): The input qubit identifier.
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
 
         Returns:
-            dict[int, int]: The updated qubit -> tweezer mapping.
+            Optional[List[int]]: The inferred number of tweezers per row, None if the Layout has neither coordinates nor shifts for all of its tweezers.
 
         Raises:
-            PyValueError: If the given qubit identifier is not present in the mapping.
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
         """
 
-    def single_qubit_gate_time(self) -> float:
+    def tweezer_zones(self, layout_name: Optional[str]) -> Dict[int, str]:
         """
-        Returns the gate time of a single qubit operation on this device.
+        Returns the zone labels of the tweezers of a given Layout.
+
+        Args:
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
 
         Returns:
-            float: The gate time.
+            dict[int, str]: The zone labels of the tweezers that have a zone.
 
         Raises:
-            ValueError: The gate is not available in the device.
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
         """
 
-    def two_qubit_gate_time(self) -> float:
+    def tweezer_zone(self, tweezer: int, layout_name: Optional[str]) -> Optional[str]:
         """
-        Returns the gate time of a two qubit operation on this device.
+        Returns the zone label of a tweezer in a given Layout.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
 
         Returns:
-            float: The gate time.
+            Optional[str]: The zone label of the tweezer, None if the tweezer has no zone.
 
         Raises:
-            ValueError: The gate is not available in the device.
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
         """
 
-    def three_qubit_gate_time(self) -> float:
+    def check_zone_constraints(self, layout_name: Optional[str]):
         """
-        Returns the gate time of a three qubit operation on this device.
+        Checks that the two-qubit gates of a given Layout are only defined within the interaction zone.
 
-        Returns:
-            float: The gate time.
+        Layouts without zone labels always pass the check. In a zoned Layout both tweezers of
+        every two-qubit gate need to be labelled "interaction".
+
+        Args:
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
 
         Raises:
-            ValueError: The gate is not available in the device.
+            ValueError: A two-qubit gate is defined outside of the interaction zone, no layout name provided and no current layout set or the Layout does not exist.
         """
 
-    def multi_qubit_gate_time(self) -> float:
+    def validate(self) -> List[str]:
         """
-        Returns the gate time of a multi qubit operation on this device.
+        Checks the device for inconsistencies.
 
-        Returns:
-            float: The gate time.
+        All Layouts are checked for invalid gate times, two-qubit gates defined for only one order
+        of a tweezer pair, shifts starting from or leading into tweezers that are not present and
+        tweezers per row differing from those of the current Layout. The current and the default
+        Layout need to be present and the qubits need to be mapped to tweezers of the current Layout.
 
-        Raises:
-            ValueError: The gate is not available in the device.
+        Returns:
+            list[str]: The descriptions of all issues found, empty if the device is consistent.
         """
 
-    def phase_shift_controlled_z(self) -> float:
+    def are_tweezers_blockaded(
+        self, tweezer0: int, tweezer1: int, layout_name: Optional[str]
+    ) -> bool:
         """
-        Returns the PhaseShiftedControlledZ phase shift according to the device's relation.
+        Returns whether two tweezers of a given Layout can not be driven simultaneously.
+
+        Args:
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
 
         Returns:
-            float: The PhaseShiftedControlledZ phase shift.
+            bool: Whether the tweezers are the same, have been added as a blockaded pair or are within the blockade radius.
 
         Raises:
-            ValueError: Error in relation selection.
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
         """
 
-    def phase_shift_controlled_phase(self) -> float:
+    def can_execute_in_parallel(self, operations: List[Operation]) -> bool:
         """
-        Returns the PhaseShiftedControlledPhase phase shift according to the device's relation.
+        Returns whether operations can be executed simultaneously in the current Layout.
+
+        The operations can be executed in parallel if no tweezer of an operation is blockaded
+        with a tweezer of another operation, in particular if no two operations act on the same qubit.
+
+        Args:
+            operations (list[Operation]): The operations to execute in parallel.
 
         Returns:
-            float: The PhaseShiftedControlledPhase phase shift.
+            bool: Whether the operations can be executed in parallel.
 
         Raises:
-            ValueError: Error in relation selection.
+            TypeError: An input is not an Operation.
+            ValueError: No current Layout is set or a qubit is not mapped to a tweezer.
         """
 
-    def gate_time_controlled_z(self, control: int, target: int, phi: float) -> float:
+    def shift_time(self, start: int, end: int) -> Optional[float]:
         """
-        Returns the gate time of a PhaseShiftedControlledZ operation with the given qubits and phi angle.
+        Returns the transport time of shifting a qubit between two tweezers in the current Layout.
 
         Args:
-            control (int): The control qubit the gate acts on
-            target (int): The target qubit the gate acts on
-            phi (float): The phi angle to be checked.
+            start (int): The tweezer the qubit is shifted out of.
+            end (int): The tweezer the qubit is shifted into.
 
         Returns:
-            float: The gate time.
-
-        Raises:
-            ValueError: The gate is not available on the device.
+            Optional[float]: The transport time set for the shift, or the number of tweezers passed times the time per site.
+                None if the shift is not allowed or has no transport time.
         """
 
-    def gate_time_controlled_phase(
-        self, control: int, target: int, phi: float, theta: float
-    ) -> float:
+    def shift_duration(self, pragma: PragmaShiftQubitsTweezers) -> Optional[float]:
         """
-        Returns the gate time of a PhaseShiftedControlledPhase operation with the given qubits and phi and theta angles.
+        Returns the duration of a PragmaShiftQubitsTweezers operation in the current Layout.
+
+        A shift takes the duration given in the operation or else the transport time of the device.
+        Sequential shifts take the sum of the durations of the shifts, parallel shifts the longest duration.
 
         Args:
-            control (int): The control qubit the gate acts on
-            target (int): The target qubit the gate acts on
-            phi (float): The phi angle to be checked.
-            theta (float): The theta angle to be checked.
+            pragma (PragmaShiftQubitsTweezers): The operation.
 
         Returns:
-            float: The gate time.
-
-        Raises:
-            ValueError: The gate is not available on the device.
+            Optional[float]: The duration of the operation.
+                None if a shift has neither a duration in the operation nor a transport time in the device.
         """
 
-    def generic_device(self) -> GenericDevice:
+    def resolve_zone_move(self, pragma: PragmaMoveToZone) -> PragmaShiftQubitsTweezers:
         """
-        Turns Device into GenericDevice
+        Resolves a PragmaMoveToZone operation into the shifts applied by the device.
 
-        Can be used as a generic interface for devices when a boxed dyn trait object cannot be used
-        (for example when the interface needs to be serialized)
+        The qubits are moved in the given order. A qubit already in the zone is not moved, any
+        other qubit is shifted into the nearest free tweezer of the zone it can reach with one
+        allowed shift. The distance is taken from the tweezer coordinates of the current Layout if
+        both tweezers have coordinates, otherwise it is the number of tweezers passed.
+
+        Args:
+            pragma (PragmaMoveToZone): The operation.
 
         Returns:
-            GenericDevice: The device in generic representation
+            PragmaShiftQubitsTweezers: The sequential shifts moving the qubits into the zone.
 
-        Note:
-            GenericDevice uses nested HashMaps to represent the most general device connectivity.
-            The memory usage will be inefficient for devices with large qubit numbers.
+        Raises:
+            ValueError: A qubit is not mapped or listed twice, the zone is not present in the current Layout or no free tweezer of the zone can be reached.
         """
 
-    def to_bincode(self) -> bytearray:
+    def global_rotation_time(self) -> Optional[float]:
         """
-        Return the bincode representation of the TweezerDevice using the bincode crate.
+        Returns the gate time of the global rotation PragmaGlobalRotateXY in the current Layout.
 
         Returns:
-            ByteArray: The serialized TweezerDevice (in bincode form).
-
-        Raises:
-            ValueError: Cannot serialize TweezerDevice to bytes.
+            Optional[float]: The global rotation time of the current Layout.
+                None if no global rotation time is set or no current Layout is set.
         """
 
-    @staticmethod
-    def from_bincode(input: bytearray) -> TweezerDevice:
+    def layout_switch_time(self) -> Optional[float]:
         """
-        Convert the bincode representation of the TweezerDevice to a TweezerDevice using the bincode crate.
-
-        Args:
-            input (ByteArray): The serialized TweezerDevice (in bincode form).
+        Returns the time to switch the device into the current Layout.
 
         Returns:
-            TweezerDevice: The deserialized TweezerDevice.
-
-        Raises:
-            TypeError: Input cannot be converted to byte array.
-            ValueError: Input cannot be deserialized to TweezerDevice.
+            Optional[float]: The layout switch time of the current Layout.
+                None if no layout switch time is set or no current Layout is set.
         """
 
-    def to_json(self) -> str:
+    def validate_pragma(self, pragma: PragmaShiftQubitsTweezers):
         """
-        Return the json representation of the TweezerDevice.
+        Checks whether a PragmaShiftQubitsTweezers operation can be applied to the device.
 
-        Additionally, a gate set check is performed.
+        Sequential shifts are checked in order, each shift sees the qubits moved by the previous ones.
+        Parallel shifts all see the qubits before the operation and their paths may not cross.
+        The durations, if given, must be one non-negative duration per shift.
+        The device is not changed.
 
-        Returns:
-            str: The serialized form of TweezerDevice.
+        Args:
+            pragma (PragmaShiftQubitsTweezers): The operation to check.
 
         Raises:
-            ValueError: Cannot serialize TweezerDevice to json or
-                the device does not have valid QRyd gates available.
+            ValueError: Describes the first shift that is not valid and why.
         """
 
-    @staticmethod
-    def from_json(input: str) -> TweezerDevice:
+    def single_qubit_gate_error(self, hqslang: str, qubit: int) -> Optional[float]:
         """
-        Convert the json representation of a TweezerDevice to a TweezerDevice.
-
-        If a default_layout is found in the input, a layout switch is executed.
-        Additionally, a gate set check is performed.
+        Returns the error rate of a single-qubit gate on a qubit in the current Layout.
 
         Args:
-            input (str): The serialized TweezerDevice in json form.
+            hqslang (str): The hqslang name of the gate.
+            qubit (int): The qubit the gate acts on.
 
         Returns:
-            TweezerDevice: The deserialized TweezerDevice.
-
-        Raises:
-            ValueError: Input cannot be deserialized to TweezerDevice  or
-                the device does not have valid QRyd gates available.
+            Optional[float]: The error rate of the gate, None if no error rate is set.
         """
 
-    def number_qubits(self) -> int:
+    def two_qubit_gate_error(self, hqslang: str, control: int, target: int) -> Optional[float]:
         """
-        Return number of qubits in device.
+        Returns the error rate of a two-qubit gate on two qubits in the current Layout.
+
+        Args:
+            hqslang (str): The hqslang name of the gate.
+            control (int): The control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
 
         Returns:
-            int: The number of qubits.
+            Optional[float]: The error rate of the gate, None if no error rate is set.
         """
 
-    def number_tweezer_positions(self, layout_name: Optional[str]) -> int:
+    def three_qubit_gate_error(
+        self, hqslang: str, control_0: int, control_1: int, target: int
+    ) -> Optional[float]:
         """
-        Returns the number of total tweezer positions in the device.
+        Returns the error rate of a three-qubit gate on three qubits in the current Layout.
 
         Args:
-            layout_name (Optional[str]): The name of the layout to reference. Defaults to the current layout.
+            hqslang (str): The hqslang name of the gate.
+            control_0 (int): The first control qubit the gate acts on.
+            control_1 (int): The second control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
 
         Returns:
-            int: The number of tweezer positions in the device.
+            Optional[float]: The error rate of the gate, None if no error rate is set.
         """
 
-    def two_qubit_edges(self) -> Sequence[(int, int)]:
+    def add_qubit_tweezer_mapping(self, qubit: int, tweezer: int) -> Dict[int, int]:
         """
-        Return the list of pairs of qubits linked by a native two-qubit-gate in the device.
+        Modifies the qubit -> tweezer mapping of the device.
 
-        A pair of qubits is considered linked by a native two-qubit-gate if the device
-        can implement a two-qubit-gate between the two qubits without decomposing it
-        into a sequence of gates that involves a third qubit of the device.
-        The two-qubit-gate also has to form a universal set together with the available
-        single qubit gates.
+        If a qubit -> tweezer mapping is already present, it is overwritten.
 
-        The returned vectors is a simple, graph-library independent, representation of
-        the undirected connectivity graph of the device.
-        It can be used to construct the connectivity graph in a graph library of the user's
-        choice from a list of edges and can be used for applications like routing in quantum algorithms.
+        Args:
+            qubit (int): The index of the qubit.
+            tweezer (int): The index of the tweezer.
 
         Returns:
-            Sequence[(int, int)]: List of two qubit edges in the undirected connectivity graph
-        """
+            dict[int, int]: The updated qubit -> tweezer mapping.
 
-    def two_tweezer_edges(self) -> Sequence[(int, int)]:
+        Raises:
+            ValueError: The tweezer is not present in the device.
         """
-        Returns the two tweezer edges of the device.
 
-        And edge between two tweezer is valid only if the
-        PhaseShiftedControlledPhase gate can be performed.
+    def get_qubit_to_tweezer_mapping(self) -> Dict[int, int]:
+        """
+        Get the qubit -> tweezer mapping of the device.
 
         Returns:
-            Sequence[(int, int)]: List of two tweezer edges
+            dict[int, int]: The qubit -> tweezer mapping.
+            None: The mapping is empty.
         """
 
-    def qrydbackend(self):
+    def is_tweezer_occupied(self, tweezer: int) -> bool:
         """
-        Returns the backend associated with the device.
+        Returns whether a qubit is mapped to the given tweezer.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+
+        Returns:
+            bool: Whether the tweezer holds a qubit according to the qubit -> tweezer mapping.
         """
 
-    def seed(self):
+    def occupied_tweezers(self) -> List[int]:
         """
-        Returns the seed usized for the API.
+        Returns the tweezers holding a qubit according to the qubit -> tweezer mapping.
+
+        Returns:
+            List[int]: The occupied tweezers, in ascending order.
         """
 
-    def _enum_to_bincode(self) -> bytearray:
+    def free_tweezers(self, layout_name: Optional[str]) -> List[int]:
         """
-        Return the bincode representation of the Enum variant of the Device.
+        Returns the tweezers of a Layout that do not hold a qubit.
 
-        Only used for internal interfacing.
+        Args:
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
 
         Returns:
-            ByteArray: The serialized TweezerDevice (in [bincode] form).
+            List[int]: The free tweezers of the Layout, in ascending order.
 
         Raises:
-            ValueError: Cannot serialize Device to bytes.
+            ValueError: No layout name provided and no current layout set, or the layout is not present.
         """
 
-    def draw(
-        self,
-        draw_shifts: Optional[bool],
-        pixel_per_point: Optional[float],
-        file_save_path: Optional[str],
-    ):
+    def get_available_gates_names(self, layout_name: Optional[str]) -> List[str]:
         """
-        Creates a graph representing a TweezerDevice.
+        Get the names of the available gates in the given layout.
 
         Args:
-            draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
-            pixel_per_point (Optional[float]): The quality of the image.
-            file_save_path (Optional[str]): Path to save the image to. Default: output the image with the display method.
+            layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
 
-        Raises:
-            ValueError: if there is no layout, an error occurred during the compilation or and invalid path was provided.
+        Returns:
+            list[str]: List of the names of the available gates in the given layout.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set.
         """
 
-class TweezerMutableDevice:
-    """
-    Tweezer Mutable Device
+    def get_available_gates_for_tweezer(
+        self, tweezer: int, layout_name: Optional[str]
+    ) -> List[str]:
+        """
+        Get the names of the gates available on a tweezer in the given layout.
 
-    This interface allows setting any piece of information about the device
-    tweezer.
+        Returns the single-qubit gates defined on the tweezer and the two-, three- and multi-qubit
+        gates defined on at least one group of tweezers including it.
 
-    Args:
-        controlled_z_phase_relation ((Optional[Union[str, float]])): The relation to use for the PhaseShiftedControlledZ gate.
-                                      It can be hardcoded to a specific value if a float is passed in as String.
-        controlled_phase_phase_relation ((Optional[Union[str, float]])): The relation to use for the PhaseShiftedControlledPhase gate.
-    """
+        Args:
+            tweezer (int): The index of the tweezer.
+            layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
 
-    def __init__(
-        self,
-        controlled_z_phase_relation: Optional[Union[str, float]],
-        controlled_phase_phase_relation: Optional[Union[str, float]],
-    ):
-        return
+        Returns:
+            list[str]: The sorted names of the gates available on the tweezer.
 
-    def current_layout(self) -> str:
+        Raises:
+            ValueError: No layout name provided and no current layout set, or the layout is not present.
         """
-        Get the name of the current layout.
 
-        Returns:
-            str: The name of the current layout.
+    def get_allow_reset(self) -> bool:
         """
+        Get whether the device allows PragmaActiveReset operations or not.
 
-    def add_layout(self, name: str):
+        Returns:
+            bool: Whether the device allows PragmaActiveReset operations or not.
         """
-        Add a new layout to the device.
 
-        Args:
-            name (str): The name that is assigned to the new Layout.
+    def get_symmetric_two_qubit_gate_times(self) -> bool:
         """
+        Get whether setting a two-qubit gate time for a tweezer pair also sets it for the reversed pair.
 
-    def switch_layout(self, layout_number: str, with_trivial_map: bool):
+        Returns:
+            bool: Whether the two-qubit gate times are set symmetrically.
         """
-        Switch to a different pre-defined Layout.
-
-        It is updated only if the given Layout name is present in the device's
-        Layout register. If the qubit -> tweezer mapping is empty, it is
-        trivially populated by default.
 
-        Args:
-            layout_number (str): The number index of the new Layout.
-            with_trivial_map (bool): Whether the qubit -> tweezer mapping should be trivially populated. Defaults to true.
+    def get_native_gates(self) -> Dict[str, List[str]]:
+        """
+        Get the native gates gate times and error rates can be set for.
 
-        Raises:
-            PyValueError
+        Returns:
+            Dict[str, List[str]]: The hqslang names of the native gates under the keys "single_qubit",
+                "two_qubit", "three_qubit" and "multi_qubit".
         """
 
-    def available_layouts(self) -> List[str]:
+    def max_qubits(self) -> Optional[int]:
         """
-        Returns a list of all available Layout names.
+        Get the maximal number of qubits the hardware supports.
 
         Returns:
-            List[str]: The list of all available Layout names.
+            Optional[int]: The maximal number of qubits, None if the capacity is not limited.
         """
 
-    def add_qubit_tweezer_mapping(self, qubit: int, tweezer: int) -> Dict[int, int]:
+    def deactivate_qubit(self, qubit: int) -> Dict[int, int]:
         """
-        Modifies the qubit -> tweezer mapping of the device.
-
-        If a qubit -> tweezer mapping is already present, it is overwritten.
+        Deactivate the given qubit in the device.
 
         Args:
-            qubit (int): The index of the qubit.
-            tweezer (int): The index of the tweezer.
+            qubit (int): The input qubit identifier.
 
         Returns:
             dict[int, int]: The updated qubit -> tweezer mapping.
 
         Raises:
-            ValueError: The tweezer is not present in the device.
+            PyValueError: If the given qubit identifier is not present in the mapping.
         """
 
-    def get_qubit_to_tweezer_mapping(self) -> Dict[int, int]:
+    def swap_tweezers(self, tweezer_0: int, tweezer_1: int):
         """
-        Get the qubit -> tweezer mapping of the device.
+        Exchange the qubits of two occupied tweezers of the current Layout.
 
-        Returns:
-            dict[int, int]: The qubit -> tweezer mapping.
-            None: The mapping is empty.
+        Args:
+            tweezer_0 (int): The first tweezer of the exchange.
+            tweezer_1 (int): The second tweezer of the exchange.
+
+        Raises:
+            ValueError: The tweezers are the same, not present in the current Layout or do not hold a qubit.
         """
 
-    def get_available_gates_names(self, layout_name: Optional[str]) -> List[str]:
+    def get_tweezer_noise(self, tweezer: int) -> Dict[str, Optional[float]]:
         """
-        Get the names of the available gates in the given layout.
+        Get the noise parameters of the qubit in the given tweezer.
 
         Args:
-            layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
+            tweezer (int): The index of the tweezer.
 
         Returns:
-            list[str]: List of the names of the available gates in the given layout.
-
-        Raises:
-            ValueError: No layout name provided and no current layout set.
+            dict[str, Optional[float]]: The relaxation time `t1`, the dephasing time `t2` and
+                the `depolarising_rate`, None if not set.
         """
 
-    def get_allow_reset(self) -> bool:
+    def loading_probability(self, tweezer: int) -> float:
         """
-        Get whether the device allows PragmaActiveReset operations or not.
+        Get the probability that the given tweezer is loaded with an atom at the start of a shot.
+
+        Args:
+            tweezer (int): The index of the tweezer.
 
         Returns:
-            bool: Whether the device allows PragmaActiveReset operations or not.
+            float: The loading probability, 1.0 if not set.
         """
 
-    def deactivate_qubit(self, qubit: int) -> Dict[int, int]:
+    def shift_loss_probability(self) -> float:
         """
-        Deactivate the given qubit in the device.
-
-        Args:
-            qubit (int): The input qubit identifier.
+        Get the probability that the atom of a qubit is lost when its tweezer is shifted.
 
         Returns:
-            dict[int, int]: The updated qubit -> tweezer mapping.
+            float: The loss probability per shifted qubit.
+        """
 
-        Raises:
-            PyValueError: If the given qubit identifier is not present in the mapping.
+    def measurement_loss_probability(self) -> float:
+        """
+        Get the probability that the atom of a qubit is lost when the qubit is measured.
+
+        Returns:
+            float: The loss probability per measured qubit.
         """
 
     def single_qubit_gate_time(self) -> float:
@@ -627,6 +719,18 @@ class TweezerMutableDevice:
             ValueError: Error in relation selection.
         """
 
+    def phi_theta_relation(self, relation_name: str, theta: float) -> Optional[float]:
+        """
+        Returns phi for the given theta according to a custom or builtin phi-theta relation.
+
+        Args:
+            relation_name (str): The name of the relation.
+            theta (float): The theta angle.
+
+        Returns:
+            Optional[float]: The phi angle, None if the relation does not exist or is not defined for theta.
+        """
+
     def gate_time_controlled_z(self, control: int, target: int, phi: float) -> float:
         """
         Returns the gate time of a PhaseShiftedControlledZ operation with the given qubits and phi angle.
@@ -669,71 +773,91 @@ class TweezerMutableDevice:
         Can be used as a generic interface for devices when a boxed dyn trait object cannot be used
         (for example when the interface needs to be serialized)
 
+        The gate times of the current Layout are translated through the qubit -> tweezer mapping,
+        gates on tweezers not holding a qubit are skipped and three-qubit gates are approximated
+        by multi-qubit gates on the same qubits.
+
         Returns:
             GenericDevice: The device in generic representation
 
+        Raises:
+            ValueError: No current Layout is set.
+
         Note:
             GenericDevice uses nested HashMaps to represent the most general device connectivity.
             The memory usage will be inefficient for devices with large qubit numbers.
         """
 
+    def generic_device_checked(self) -> GenericDevice:
+        """
+        Turns Device into GenericDevice, failing if gate times of the current Layout would be lost.
+
+        Unlike `generic_device` no gate is skipped or approximated.
+
+        Returns:
+            GenericDevice: The device in generic representation
+
+        Raises:
+            ValueError: No current Layout is set, a gate is defined on a tweezer not holding a qubit or a three-qubit gate is defined.
+        """
+
     def to_bincode(self) -> bytearray:
         """
-        Return the bincode representation of the TweezerMutableDevice using the bincode crate.
+        Return the bincode representation of the TweezerDevice using the bincode crate.
 
         Returns:
-            ByteArray: The serialized TweezerMutableDevice (in bincode form).
+            ByteArray: The serialized TweezerDevice (in bincode form).
 
         Raises:
-            ValueError: Cannot serialize TweezerMutableDevice to bytes.
+            ValueError: Cannot serialize TweezerDevice to bytes.
         """
 
     @staticmethod
-    def from_bincode(input: bytearray) -> TweezerMutableDevice:
+    def from_bincode(input: bytearray) -> TweezerDevice:
         """
-        Convert the bincode representation of the TweezerMutableDevice to an
-        TweezerMutableDevice using the bincode crate.
+        Convert the bincode representation of the TweezerDevice to a TweezerDevice using the bincode crate.
 
         Args:
-            input (ByteArray): The serialized TweezerMutableDevice (in bincode form).
+            input (ByteArray): The serialized TweezerDevice (in bincode form).
 
         Returns:
-            TweezerMutableDevice: The deserialized TweezerMutableDevice.
+            TweezerDevice: The deserialized TweezerDevice.
 
         Raises:
             TypeError: Input cannot be converted to byte array.
-            ValueError: Input cannot be deserialized to TweezerMutableDevice.
+            ValueError: Input cannot be deserialized to TweezerDevice.
         """
 
     def to_json(self) -> str:
         """
-        Return the json representation of the TweezerMutableDevice.
+        Return the json representation of the TweezerDevice.
 
         Additionally, a gate set check is performed.
 
         Returns:
-            str: The serialized form of TweezerMutableDevice.
+            str: The serialized form of TweezerDevice.
 
         Raises:
-            ValueError: Cannot serialize TweezerMutableDevice to json or
+            ValueError: Cannot serialize TweezerDevice to json or
                 the device does not have valid QRyd gates available.
         """
 
     @staticmethod
-    def from_json(input: str) -> TweezerMutableDevice:
+    def from_json(input: str) -> TweezerDevice:
         """
-        Convert the json representation of a TweezerMutableDevice to an TweezerMutableDevice.
+        Convert the json representation of a TweezerDevice to a TweezerDevice.
 
+        If a default_layout is found in the input, a layout switch is executed.
         Additionally, a gate set check is performed.
 
         Args:
-            input (str): The serialized TweezerMutableDevice in json form.
+            input (str): The serialized TweezerDevice in json form.
 
         Returns:
-            TweezerMutableDevice: The deserialized TweezerMutableDevice.
+            TweezerDevice: The deserialized TweezerDevice.
 
         Raises:
-            ValueError: Input cannot be deserialized to TweezerMutableDevice or
+            ValueError: Input cannot be deserialized to TweezerDevice  or
                 the device does not have valid QRyd gates available.
         """
 
@@ -743,7 +867,6 @@ class TweezerMutableDevice:
 
         Returns:
             int: The number of qubits.
-
         """
 
     def number_tweezer_positions(self, layout_name: Optional[str]) -> int:
@@ -804,105 +927,1531 @@ class TweezerMutableDevice:
         Only used for internal interfacing.
 
         Returns:
-            ByteArray: The serialized TweezerMutableDevice (in [bincode] form).
+            ByteArray: The serialized TweezerDevice (in [bincode] form).
 
         Raises:
             ValueError: Cannot serialize Device to bytes.
         """
 
-    def set_tweezer_single_qubit_gate_time(
-        self, hqslang: str, tweezer: int, gate_time: float, layout_name: Optional[str]
+    def draw(
+        self,
+        draw_shifts: Optional[bool],
+        pixel_per_point: Optional[float],
+        file_save_path: Optional[str],
+        show_tweezer_labels: Optional[bool],
+        show_qubit_labels: Optional[bool],
+        show_legend: Optional[bool],
+        dpi: Optional[float],
     ):
         """
-        Set the time of a single-qubit gate for a tweezer in a given Layout.
+        Creates a graph representing a TweezerDevice.
 
         Args:
-            hqslang (str): The hqslang name of a single-qubit gate.
-            tweezer (int): The index of the tweezer.
-            gate_time (float): The the gate time for the given gate.
-            layout_name (Optional[str]): The name of the Layout to apply the gate time in.
-                Defaults to the current Layout.
+            draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+            pixel_per_point (Optional[float]): The quality of the image.
+            file_save_path (Optional[str]): Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics. Default: output the image with the display method.
+            show_tweezer_labels (Optional[bool]): Whether to label the tweezers with their index. Default: true
+            show_qubit_labels (Optional[bool]): Whether to label the tweezers with the qubit they hold. Default: true
+            show_legend (Optional[bool]): Whether to add a legend explaining the labels and edges. Default: false
+            dpi (Optional[float]): The resolution of the image in dots per inch, overrides pixel_per_point.
 
         Raises:
-            ValueError: No layout name provided and no current layout set.
+            ValueError: if there is no layout, an error occurred during the compilation or and invalid path was provided.
         """
 
-    def set_tweezer_two_qubit_gate_time(
+    def draw_layouts(
         self,
-        hqslang: str,
-        tweezer0: int,
-        tweezer1: int,
-        gate_time: float,
-        layout_name: Optional[str],
+        layout_names: List[str],
+        draw_shifts: Optional[bool],
+        pixel_per_point: Optional[float],
+        file_save_path: Optional[str],
+        show_legend: Optional[bool],
     ):
         """
-        Set the time of a two-qubit gate for a tweezer couple in a given Layout.
+        Creates a graph showing several Layouts of the TweezerDevice next to each other.
+
+        Tweezers whose available gates differ between the Layouts are highlighted.
 
         Args:
-            hqslang (str): The hqslang name of a single-qubit gate.
-            tweezer0 (int): The index of the first tweezer.
-            tweezer1 (int): The index of the second tweezer.
-            gate_time (float): The the gate time for the given gate.
-            layout_name (Optional[str]): The name of the Layout to apply the gate time in.
-                Defaults to the current Layout.
+            layout_names (List[str]): The names of the Layouts to draw, from left to right.
+            draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+            pixel_per_point (Optional[float]): The quality of the image.
+            file_save_path (Optional[str]): Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics. Default: output the image with the display method.
+            show_legend (Optional[bool]): Whether to add a legend explaining the labels, edges and highlighted tweezers. Default: false
 
         Raises:
-            ValueError: No layout name provided and no current layout set.
+            ValueError: if no Layout is given, a Layout is not present, an error occurred during the compilation or an invalid path was provided.
         """
 
-    def set_tweezer_three_qubit_gate_time(
+    def draw_circuit_shifts(
         self,
-        hqslang: str,
-        tweezer0: int,
-        tweezer1: int,
-        tweezer2: int,
-        gate_time: float,
-        layout_name: Optional[str],
+        circuit: Circuit,
+        file_save_path: str,
+        draw_shifts: Optional[bool],
+        pixel_per_point: Optional[float],
+        frame_delay_ms: Optional[int],
     ):
         """
-        Set the time of a three-qubit gate for a tweezer trio in a given Layout.
+        Creates one drawing of the TweezerDevice per device-changing step of a circuit.
+
+        The first frame shows the device before the circuit. The PragmaChangeDevice operations of
+        the circuit, e.g. Layout switches, shifts and deactivations, are applied in order to a copy
+        of the device and each adds a frame. The device itself is not changed.
 
         Args:
-            hqslang (str): The hqslang name of a three-qubit gate.
-            tweezer0 (int): The index of the first tweezer.
-            tweezer1 (int): The index of the second tweezer.
-            tweezer2 (int): The index of the third tweezer.
-            gate_time (float): The the gate time for the given gate.
-            layout_name (Optional[str]): The name of the Layout to apply the gate time in.
-                Defaults to the current Layout.
+            circuit (Circuit): The circuit whose device-changing operations are drawn.
+            file_save_path (str): Path to save the drawings to. A `.gif` file is saved as an animated GIF, otherwise every frame is saved with its index appended to the file name.
+            draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+            pixel_per_point (Optional[float]): The quality of the images.
+            frame_delay_ms (Optional[int]): The time each frame of the animated GIF is shown in milliseconds. Default: 500
 
         Raises:
-            ValueError: No layout name provided and no current layout set.
+            TypeError: Circuit argument cannot be converted to qoqo Circuit.
+            ValueError: if there is no layout, a device-changing operation is not valid on the device, an error occurred during the compilation or an invalid path was provided.
         """
 
-    def set_tweezer_multi_qubit_gate_time(
-        self,
-        hqslang: str,
-        tweezers: List[int],
-        gate_time: float,
-        layout_name: Optional[str],
-    ):
+    def upgrade_circuit(self, circuit: Circuit) -> Circuit:
         """
-        Set the time of a multi-qubit gate for a list of tweezers in a given Layout.
+        Rewrites the legacy QRyd pragmas of a circuit into the tweezer pragmas.
+
+        A PragmaChangeQRydLayout to the layout number `n` becomes a PragmaSwitchDeviceLayout to the
+        Layout named `n`. A PragmaShiftQRydQubit becomes a parallel PragmaShiftQubitsTweezers moving
+        every qubit to the tweezer of its new position, with the tweezers numbered row by row.
+        Legacy pragmas that can not be converted are left unchanged. The device itself is not changed.
 
         Args:
-            hqslang (str): The hqslang name of a multi-qubit gate.
-            tweezers (List[int]): The list of tweezer indexes.
-            gate_time (float): The the gate time for the given gate.
-            layout_name (Optional[str]): The name of the Layout to apply the gate time in.
-                Defaults to the current Layout.
+            circuit (Circuit): The circuit to convert.
+
+        Returns:
+            Circuit: The circuit with the convertible legacy pragmas replaced.
 
         Raises:
-            ValueError: No layout name provided and no current layout set.
+            TypeError: Circuit argument cannot be converted to qoqo Circuit.
         """
 
-    def set_allowed_tweezer_shifts(
-        self, tweezer: int, allowed_shifts: list[list[int]], layout_name: Optional[str]
+class TweezerMutableDevice:
+    """
+    Tweezer Mutable Device
+
+    This interface allows setting any piece of information about the device
+    tweezer.
+
+    Args:
+        controlled_z_phase_relation ((Optional[Union[str, float]])): The relation to use for the PhaseShiftedControlledZ gate.
+                                      It can be hardcoded to a specific value if a float is passed in as String.
+        controlled_phase_phase_relation ((Optional[Union[str, float]])): The relation to use for the PhaseShiftedControlledPhase gate.
+    """
+
+    def __init__(
+        self,
+        controlled_z_phase_relation: Optional[Union[str, float]],
+        controlled_phase_phase_relation: Optional[Union[str, float]],
     ):
+        return
+
+    @staticmethod
+    def square_lattice(rows: int, columns: int, gate_times: Dict[str, float]) -> TweezerMutableDevice:
         """
-        Set the allowed Tweezer shifts of a specified Tweezer.
+        Creates a new TweezerMutableDevice with a square lattice of tweezers.
 
-        The tweezer give the tweezer a qubit can be shifted out of. The values are lists
+        The device contains the Layout "default", which is set as the current and the default
+        Layout. The tweezers are numbered row by row and placed at the coordinates (column, row).
+        Single-qubit gates are available on all tweezers, two-qubit gates between horizontally and
+        vertically neighbouring tweezers. Qubits can be shifted along the rows.
+
+        Args:
+            rows (int): The number of rows of the lattice.
+            columns (int): The number of tweezers per row.
+            gate_times (dict[str, float]): The hqslang names of the single- and two-qubit gates and their gate times.
+
+        Returns:
+            TweezerMutableDevice: The device with the lattice Layout.
+
+        Raises:
+            ValueError: The lattice is empty, no single-qubit gate is given or a gate is not supported.
+        """
+
+    @staticmethod
+    def triangular_lattice(rows: int, columns: int, gate_times: Dict[str, float]) -> TweezerMutableDevice:
+        """
+        Creates a new TweezerMutableDevice with a triangular lattice of tweezers.
+
+        The device contains the Layout "default", which is set as the current and the default
+        Layout. The tweezers are numbered row by row, every second row is offset by half a
+        tweezer spacing so that each tweezer has up to six neighbours at the same distance.
+        Single-qubit gates are available on all tweezers, two-qubit gates between neighbouring
+        tweezers. Qubits can be shifted along the rows.
+
+        Args:
+            rows (int): The number of rows of the lattice.
+            columns (int): The number of tweezers per row.
+            gate_times (dict[str, float]): The hqslang names of the single- and two-qubit gates and their gate times.
+
+        Returns:
+            TweezerMutableDevice: The device with the lattice Layout.
+
+        Raises:
+            ValueError: The lattice is empty, no single-qubit gate is given or a gate is not supported.
+        """
+
+    @staticmethod
+    def from_first_device(device: FirstDevice) -> TweezerMutableDevice:
+        """
+        Creates a new TweezerMutableDevice from a FirstDevice of the deprecated `qryd_devices` module.
+
+        The current layout of the FirstDevice becomes the Layout "default", which is set as the
+        current and the default Layout. The tweezers are numbered row by row and placed at the
+        positions of the FirstDevice. The gate times are the ones of the FirstDevice, qubits can be
+        shifted along the rows and are mapped to the tweezers of their positions in the FirstDevice.
+
+        Args:
+            device (FirstDevice): The FirstDevice to convert.
+
+        Returns:
+            TweezerMutableDevice: The converted device.
+
+        Raises:
+            TypeError: The input is not a FirstDevice.
+            ValueError: The FirstDevice has no tweezers.
+        """
+
+    @staticmethod
+    def from_calibration_file(path: str) -> TweezerMutableDevice:
+        """
+        Creates a new TweezerMutableDevice from a calibration file.
+
+        The calibration file is a JSON document, or a YAML document if the file extension is
+        `.yaml` or `.yml`, containing the gate times, gate fidelities, allowed shifts, tweezer
+        coordinates and tweezers per row of each Layout. Every Layout of the file is added to
+        the device, the default Layout is set as the current Layout.
+
+        Args:
+            path (str): The path of the calibration file.
+
+        Returns:
+            TweezerMutableDevice: The device described by the calibration file.
+
+        Raises:
+            ValueError: The file could not be read or parsed, or a field is invalid.
+        """
+
+    @staticmethod
+    def from_file(path: str, format: Optional[str]) -> TweezerMutableDevice:
+        """
+        Loads a TweezerMutableDevice from a file written by `to_file`.
+
+        Args:
+            path (str): The path of the file.
+            format (Optional[str]): The format of the file, "json", "bincode" or "yaml". Default: inferred from the extension, `.json`, `.bin`, `.bincode`, `.yaml` or `.yml`.
+
+        Returns:
+            TweezerMutableDevice: The device stored in the file.
+
+        Raises:
+            ValueError: The format is unknown or the file could not be read or deserialized.
+        """
+
+    def to_file(self, path: str, format: Optional[str]):
+        """
+        Saves the TweezerMutableDevice to a file.
+
+        Args:
+            path (str): The path of the file.
+            format (Optional[str]): The format of the file, "json", "bincode" or "yaml". Default: inferred from the extension, `.json`, `.bin`, `.bincode`, `.yaml` or `.yml`.
+
+        Raises:
+            ValueError: The format is unknown or the device could not be serialized or written.
+        """
+
+    def current_layout(self) -> str:
+        """
+        Get the name of the current layout.
+
+        Returns:
+            str: The name of the current layout.
+        """
+
+    def add_layout(self, name: str):
+        """
+        Add a new layout to the device.
+
+        Args:
+            name (str): The name that is assigned to the new Layout.
+        """
+
+    def remove_layout(self, name: str):
+        """
+        Remove a layout from the device.
+
+        The current and the default Layout can not be removed.
+
+        Args:
+            name (str): The name of the Layout to remove.
+
+        Raises:
+            ValueError: The Layout is not set or is the current or the default Layout.
+        """
+
+    def rename_layout(self, old_name: str, new_name: str):
+        """
+        Rename a layout of the device.
+
+        The current and the default Layout are updated if they refer to the renamed Layout.
+
+        Args:
+            old_name (str): The name of the Layout to rename.
+            new_name (str): The new name of the Layout.
+
+        Raises:
+            ValueError: The Layout is not set or the new name is already in use.
+        """
+
+    def duplicate_layout(self, source: str, new_name: str):
+        """
+        Add a deep copy of a layout to the device under a new name.
+
+        The copy can be changed without affecting the source Layout, e.g. to derive a
+        recalibrated variant of a Layout by updating some of its gate times.
+
+        Args:
+            source (str): The name of the Layout to copy.
+            new_name (str): The name of the new Layout.
+
+        Raises:
+            ValueError: The source Layout is not set, could not be deserialized or the new name is already in use.
+        """
+
+    def switch_layout(
+        self,
+        layout_number: str,
+        with_trivial_map: Optional[bool] = None,
+        mapping_policy: Optional[str] = None,
+    ):
+        """
+        Switch to a different pre-defined Layout.
+
+        It is updated only if the given Layout name is present in the device's
+        Layout register. If the qubit -> tweezer mapping is empty, it is
+        trivially populated by default.
+
+        Args:
+            layout_number (str): The number index of the new Layout.
+            with_trivial_map (bool): Whether the qubit -> tweezer mapping should be trivially populated. Defaults to true.
+            mapping_policy (Optional[str]): The policy populating the qubit -> tweezer mapping, one of
+                `all-tweezers`, `usable-tweezers`, `occupied-first` or `none`. Overrides `with_trivial_map`.
+
+        Raises:
+            PyValueError
+        """
+
+    def switch_layout_with_mapping(self, name: str, qubit_to_tweezer: Dict[int, int]):
+        """
+        Switch to a different pre-defined Layout with an explicit qubit -> tweezer mapping.
+
+        The mapping replaces the current qubit -> tweezer mapping. It is validated against the
+        new Layout before switching, the device is left unchanged if the validation fails.
+
+        Args:
+            name (str): The name of the new Layout.
+            qubit_to_tweezer (Dict[int, int]): The qubit -> tweezer mapping to use in the new Layout.
+
+        Raises:
+            ValueError: The Layout is not set, a tweezer of the mapping is not present in the Layout or several qubits are mapped to the same tweezer.
+        """
+
+    def available_layouts(self) -> List[str]:
+        """
+        Returns a list of all available Layout names.
+
+        Returns:
+            List[str]: The list of all available Layout names.
+        """
+
+    def capability_manifest(self) -> str:
+        """
+        Returns the capability manifest of the device as a compact JSON document.
+
+        The manifest summarizes the gates, connectivity and limits of the current Layout, the
+        phase relations and the noise parameters. The schema is versioned by the
+        `schema_version` field.
+
+        Returns:
+            str: The JSON capability manifest.
+
+        Raises:
+            ValueError: The manifest could not be created.
+        """
+
+    def apply_update(self, json_patch: str):
+        """
+        Applies a partial update of the device, e.g. a calibration delta served by the WebAPI.
+
+        The update is a JSON patch (RFC 6902) against the JSON serialization of the device
+        returned by `to_json`. The device is only changed if the whole patch could be applied.
+
+        Args:
+            json_patch (str): The JSON patch, an array of operations.
+
+        Raises:
+            ValueError: The patch is invalid, could not be applied or results in an invalid device.
+        """
+
+    def tweezer_coordinates(self, layout_name: Optional[str]) -> Dict[int, Tuple[float, float]]:
+        """
+        Returns the (x, y) coordinates of the tweezers of a given Layout.
+
+        Args:
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+
+        Returns:
+            dict[int, (float, float)]: The coordinates of the tweezers that have coordinates.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def infer_tweezers_per_row(self, layout_name: Optional[str]) -> Optional[List[int]]:
+        """
+        Infers the tweezers per row of a given Layout.
+
+        If all tweezers of the Layout have coordinates, the rows are the tweezers with the same
+        y coordinate, ordered by increasing y. Otherwise, if every tweezer can be shifted or
+        shifted into, the rows are the groups of tweezers connected by allowed shifts, ordered
+        by their smallest tweezer. Dynamic layout switching uses the inferred tweezers per row
+        of Layouts without tweezers per row set.
+
+        Args:
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+
+        Returns:
+            Optional[List[int]]: The inferred number of tweezers per row, None if the Layout has neither coordinates nor shifts for all of its tweezers.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def tweezer_zones(self, layout_name: Optional[str]) -> Dict[int, str]:
+        """
+        Returns the zone labels of the tweezers of a given Layout.
+
+        Args:
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+
+        Returns:
+            dict[int, str]: The zone labels of the tweezers that have a zone.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def tweezer_zone(self, tweezer: int, layout_name: Optional[str]) -> Optional[str]:
+        """
+        Returns the zone label of a tweezer in a given Layout.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+
+        Returns:
+            Optional[str]: The zone label of the tweezer, None if the tweezer has no zone.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def check_zone_constraints(self, layout_name: Optional[str]):
+        """
+        Checks that the two-qubit gates of a given Layout are only defined within the interaction zone.
+
+        Layouts without zone labels always pass the check. In a zoned Layout both tweezers of
+        every two-qubit gate need to be labelled "interaction".
+
+        Args:
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+
+        Raises:
+            ValueError: A two-qubit gate is defined outside of the interaction zone, no layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def validate(self) -> List[str]:
+        """
+        Checks the device for inconsistencies.
+
+        All Layouts are checked for invalid gate times, two-qubit gates defined for only one order
+        of a tweezer pair, shifts starting from or leading into tweezers that are not present and
+        tweezers per row differing from those of the current Layout. The current and the default
+        Layout need to be present and the qubits need to be mapped to tweezers of the current Layout.
+
+        Returns:
+            list[str]: The descriptions of all issues found, empty if the device is consistent.
+        """
+
+    def are_tweezers_blockaded(
+        self, tweezer0: int, tweezer1: int, layout_name: Optional[str]
+    ) -> bool:
+        """
+        Returns whether two tweezers of a given Layout can not be driven simultaneously.
+
+        Args:
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+
+        Returns:
+            bool: Whether the tweezers are the same, have been added as a blockaded pair or are within the blockade radius.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def can_execute_in_parallel(self, operations: List[Operation]) -> bool:
+        """
+        Returns whether operations can be executed simultaneously in the current Layout.
+
+        The operations can be executed in parallel if no tweezer of an operation is blockaded
+        with a tweezer of another operation, in particular if no two operations act on the same qubit.
+
+        Args:
+            operations (list[Operation]): The operations to execute in parallel.
+
+        Returns:
+            bool: Whether the operations can be executed in parallel.
+
+        Raises:
+            TypeError: An input is not an Operation.
+            ValueError: No current Layout is set or a qubit is not mapped to a tweezer.
+        """
+
+    def shift_time(self, start: int, end: int) -> Optional[float]:
+        """
+        Returns the transport time of shifting a qubit between two tweezers in the current Layout.
+
+        Args:
+            start (int): The tweezer the qubit is shifted out of.
+            end (int): The tweezer the qubit is shifted into.
+
+        Returns:
+            Optional[float]: The transport time set for the shift, or the number of tweezers passed times the time per site.
+                None if the shift is not allowed or has no transport time.
+        """
+
+    def shift_duration(self, pragma: PragmaShiftQubitsTweezers) -> Optional[float]:
+        """
+        Returns the duration of a PragmaShiftQubitsTweezers operation in the current Layout.
+
+        A shift takes the duration given in the operation or else the transport time of the device.
+        Sequential shifts take the sum of the durations of the shifts, parallel shifts the longest duration.
+
+        Args:
+            pragma (PragmaShiftQubitsTweezers): The operation.
+
+        Returns:
+            Optional[float]: The duration of the operation.
+                None if a shift has neither a duration in the operation nor a transport time in the device.
+        """
+
+    def resolve_zone_move(self, pragma: PragmaMoveToZone) -> PragmaShiftQubitsTweezers:
+        """
+        Resolves a PragmaMoveToZone operation into the shifts applied by the device.
+
+        The qubits are moved in the given order. A qubit already in the zone is not moved, any
+        other qubit is shifted into the nearest free tweezer of the zone it can reach with one
+        allowed shift. The distance is taken from the tweezer coordinates of the current Layout if
+        both tweezers have coordinates, otherwise it is the number of tweezers passed.
+
+        Args:
+            pragma (PragmaMoveToZone): The operation.
+
+        Returns:
+            PragmaShiftQubitsTweezers: The sequential shifts moving the qubits into the zone.
+
+        Raises:
+            ValueError: A qubit is not mapped or listed twice, the zone is not present in the current Layout or no free tweezer of the zone can be reached.
+        """
+
+    def global_rotation_time(self) -> Optional[float]:
+        """
+        Returns the gate time of the global rotation PragmaGlobalRotateXY in the current Layout.
+
+        Returns:
+            Optional[float]: The global rotation time of the current Layout.
+                None if no global rotation time is set or no current Layout is set.
+        """
+
+    def layout_switch_time(self) -> Optional[float]:
+        """
+        Returns the time to switch the device into the current Layout.
+
+        Returns:
+            Optional[float]: The layout switch time of the current Layout.
+                None if no layout switch time is set or no current Layout is set.
+        """
+
+    def validate_pragma(self, pragma: PragmaShiftQubitsTweezers):
+        """
+        Checks whether a PragmaShiftQubitsTweezers operation can be applied to the device.
+
+        Sequential shifts are checked in order, each shift sees the qubits moved by the previous ones.
+        Parallel shifts all see the qubits before the operation and their paths may not cross.
+        The durations, if given, must be one non-negative duration per shift.
+        The device is not changed.
+
+        Args:
+            pragma (PragmaShiftQubitsTweezers): The operation to check.
+
+        Raises:
+            ValueError: Describes the first shift that is not valid and why.
+        """
+
+    def single_qubit_gate_error(self, hqslang: str, qubit: int) -> Optional[float]:
+        """
+        Returns the error rate of a single-qubit gate on a qubit in the current Layout.
+
+        Args:
+            hqslang (str): The hqslang name of the gate.
+            qubit (int): The qubit the gate acts on.
+
+        Returns:
+            Optional[float]: The error rate of the gate, None if no error rate is set.
+        """
+
+    def two_qubit_gate_error(self, hqslang: str, control: int, target: int) -> Optional[float]:
+        """
+        Returns the error rate of a two-qubit gate on two qubits in the current Layout.
+
+        Args:
+            hqslang (str): The hqslang name of the gate.
+            control (int): The control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
+
+        Returns:
+            Optional[float]: The error rate of the gate, None if no error rate is set.
+        """
+
+    def three_qubit_gate_error(
+        self, hqslang: str, control_0: int, control_1: int, target: int
+    ) -> Optional[float]:
+        """
+        Returns the error rate of a three-qubit gate on three qubits in the current Layout.
+
+        Args:
+            hqslang (str): The hqslang name of the gate.
+            control_0 (int): The first control qubit the gate acts on.
+            control_1 (int): The second control qubit the gate acts on.
+            target (int): The target qubit the gate acts on.
+
+        Returns:
+            Optional[float]: The error rate of the gate, None if no error rate is set.
+        """
+
+    def add_qubit_tweezer_mapping(self, qubit: int, tweezer: int) -> Dict[int, int]:
+        """
+        Modifies the qubit -> tweezer mapping of the device.
+
+        If a qubit -> tweezer mapping is already present, it is overwritten.
+
+        Args:
+            qubit (int): The index of the qubit.
+            tweezer (int): The index of the tweezer.
+
+        Returns:
+            dict[int, int]: The updated qubit -> tweezer mapping.
+
+        Raises:
+            ValueError: The tweezer is not present in the device.
+        """
+
+    def get_qubit_to_tweezer_mapping(self) -> Dict[int, int]:
+        """
+        Get the qubit -> tweezer mapping of the device.
+
+        Returns:
+            dict[int, int]: The qubit -> tweezer mapping.
+            None: The mapping is empty.
+        """
+
+    def is_tweezer_occupied(self, tweezer: int) -> bool:
+        """
+        Returns whether a qubit is mapped to the given tweezer.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+
+        Returns:
+            bool: Whether the tweezer holds a qubit according to the qubit -> tweezer mapping.
+        """
+
+    def occupied_tweezers(self) -> List[int]:
+        """
+        Returns the tweezers holding a qubit according to the qubit -> tweezer mapping.
+
+        Returns:
+            List[int]: The occupied tweezers, in ascending order.
+        """
+
+    def free_tweezers(self, layout_name: Optional[str]) -> List[int]:
+        """
+        Returns the tweezers of a Layout that do not hold a qubit.
+
+        Args:
+            layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+
+        Returns:
+            List[int]: The free tweezers of the Layout, in ascending order.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set, or the layout is not present.
+        """
+
+    def get_available_gates_names(self, layout_name: Optional[str]) -> List[str]:
+        """
+        Get the names of the available gates in the given layout.
+
+        Args:
+            layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
+
+        Returns:
+            list[str]: List of the names of the available gates in the given layout.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set.
+        """
+
+    def get_available_gates_for_tweezer(
+        self, tweezer: int, layout_name: Optional[str]
+    ) -> List[str]:
+        """
+        Get the names of the gates available on a tweezer in the given layout.
+
+        Returns the single-qubit gates defined on the tweezer and the two-, three- and multi-qubit
+        gates defined on at least one group of tweezers including it.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+            layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
+
+        Returns:
+            list[str]: The sorted names of the gates available on the tweezer.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set, or the layout is not present.
+        """
+
+    def get_allow_reset(self) -> bool:
+        """
+        Get whether the device allows PragmaActiveReset operations or not.
+
+        Returns:
+            bool: Whether the device allows PragmaActiveReset operations or not.
+        """
+
+    def get_symmetric_two_qubit_gate_times(self) -> bool:
+        """
+        Get whether setting a two-qubit gate time for a tweezer pair also sets it for the reversed pair.
+
+        Returns:
+            bool: Whether the two-qubit gate times are set symmetrically.
+        """
+
+    def get_native_gates(self) -> Dict[str, List[str]]:
+        """
+        Get the native gates gate times and error rates can be set for.
+
+        Returns:
+            Dict[str, List[str]]: The hqslang names of the native gates under the keys "single_qubit",
+                "two_qubit", "three_qubit" and "multi_qubit".
+        """
+
+    def max_qubits(self) -> Optional[int]:
+        """
+        Get the maximal number of qubits the hardware supports.
+
+        Returns:
+            Optional[int]: The maximal number of qubits, None if the capacity is not limited.
+        """
+
+    def deactivate_qubit(self, qubit: int) -> Dict[int, int]:
+        """
+        Deactivate the given qubit in the device.
+
+        Args:
+            qubit (int): The input qubit identifier.
+
+        Returns:
+            dict[int, int]: The updated qubit -> tweezer mapping.
+
+        Raises:
+            PyValueError: If the given qubit identifier is not present in the mapping.
+        """
+
+    def swap_tweezers(self, tweezer_0: int, tweezer_1: int):
+        """
+        Exchange the qubits of two occupied tweezers of the current Layout.
+
+        Args:
+            tweezer_0 (int): The first tweezer of the exchange.
+            tweezer_1 (int): The second tweezer of the exchange.
+
+        Raises:
+            ValueError: The tweezers are the same, not present in the current Layout or do not hold a qubit.
+        """
+
+    def get_tweezer_noise(self, tweezer: int) -> Dict[str, Optional[float]]:
+        """
+        Get the noise parameters of the qubit in the given tweezer.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+
+        Returns:
+            dict[str, Optional[float]]: The relaxation time `t1`, the dephasing time `t2` and
+                the `depolarising_rate`, None if not set.
+        """
+
+    def loading_probability(self, tweezer: int) -> float:
+        """
+        Get the probability that the given tweezer is loaded with an atom at the start of a shot.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+
+        Returns:
+            float: The loading probability, 1.0 if not set.
+        """
+
+    def shift_loss_probability(self) -> float:
+        """
+        Get the probability that the atom of a qubit is lost when its tweezer is shifted.
+
+        Returns:
+            float: The loss probability per shifted qubit.
+        """
+
+    def measurement_loss_probability(self) -> float:
+        """
+        Get the probability that the atom of a qubit is lost when the qubit is measured.
+
+        Returns:
+            float: The loss probability per measured qubit.
+        """
+
+    def set_tweezer_t1(self, tweezer: int, t1: float):
+        """
+        Set the relaxation time T1 of the qubit in the given tweezer.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+            t1 (float): The relaxation time.
+
+        Raises:
+            ValueError: The relaxation time is not positive.
+        """
+
+    def set_tweezer_t2(self, tweezer: int, t2: float):
+        """
+        Set the dephasing time T2 of the qubit in the given tweezer.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+            t2 (float): The dephasing time.
+
+        Raises:
+            ValueError: The dephasing time is not positive.
+        """
+
+    def set_tweezer_depolarising_rate(self, tweezer: int, rate: float):
+        """
+        Set the depolarising rate of the qubit in the given tweezer.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+            rate (float): The depolarising rate.
+
+        Raises:
+            ValueError: The depolarising rate is negative.
+        """
+
+    def clear_noise(self):
+        """
+        Remove all noise parameters from the device.
+        """
+
+    def set_loading_probability(self, tweezer: int, probability: float):
+        """
+        Set the probability that the given tweezer is loaded with an atom at the start of a shot.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+            probability (float): The loading probability.
+
+        Raises:
+            ValueError: The loading probability is not between 0 and 1.
+        """
+
+    def set_rearrangement(self, rearrangement: bool):
+        """
+        Set whether the loaded atoms are rearranged before each shot.
+
+        With rearrangement the atoms loaded into any tweezer of the current Layout fill the
+        tweezers of the qubits of a circuit in ascending qubit order.
+
+        Args:
+            rearrangement (bool): Whether the loaded atoms are rearranged.
+        """
+
+    def set_shift_loss_probability(self, probability: float):
+        """
+        Set the probability that the atom of a qubit is lost when its tweezer is shifted.
+
+        A lost qubit is reset, the subsequent operations acting on it are skipped by the simulator
+        and the loss is reported as an atom loss event of the shot.
+
+        Args:
+            probability (float): The loss probability per shifted qubit.
+
+        Raises:
+            ValueError: The loss probability is not between 0 and 1.
+        """
+
+    def set_measurement_loss_probability(self, probability: float):
+        """
+        Set the probability that the atom of a qubit is lost when the qubit is measured.
+
+        A lost qubit is reset, the subsequent operations acting on it are skipped by the simulator
+        and the loss is reported as an atom loss event of the shot.
+
+        Args:
+            probability (float): The loss probability per measured qubit.
+
+        Raises:
+            ValueError: The loss probability is not between 0 and 1.
+        """
+
+    def add_polynomial_phi_theta_relation(self, name: str, coefficients: List[float]):
+        """
+        Registers a custom polynomial phi-theta relation.
+
+        The relation gives phi = sum_k coefficients[k] * theta^k and can be used as phase relation
+        of the device by its name. A relation registered under the name of an existing custom
+        relation replaces it.
+
+        Args:
+            name (str): The name of the relation.
+            coefficients (List[float]): The coefficients, starting with the constant one.
+
+        Raises:
+            ValueError: The name is empty, a number or the name of a builtin relation.
+        """
+
+    def add_logarithmic_phi_theta_relation(
+        self,
+        name: str,
+        offset: float,
+        scale: float,
+        quadratic: float,
+        exponent: float,
+        linear: float,
+        constant: float,
+    ):
+        """
+        Registers a custom logarithmic phi-theta relation.
+
+        The relation gives phi = offset + scale * ln(quadratic * theta^2 * exp(exponent * theta) + linear * theta + constant),
+        the form of the "DefaultRelation", and can be used as phase relation of the device by its name.
+        A relation registered under the name of an existing custom relation replaces it.
+
+        Args:
+            name (str): The name of the relation.
+            offset (float): The offset of phi.
+            scale (float): The factor of the logarithm.
+            quadratic (float): The factor of the quadratic term.
+            exponent (float): The factor of theta in the exponential of the quadratic term.
+            linear (float): The factor of the linear term.
+            constant (float): The constant term.
+
+        Raises:
+            ValueError: The name is empty, a number or the name of a builtin relation.
+        """
+
+    def remove_phi_theta_relation(self, name: str) -> bool:
+        """
+        Removes a custom phi-theta relation.
+
+        Args:
+            name (str): The name of the relation.
+
+        Returns:
+            bool: Whether a custom relation with the name was registered.
+        """
+
+    def single_qubit_gate_time(self) -> float:
+        """
+        Returns the gate time of a single qubit operation on this device.
+
+        Returns:
+            float: The gate time.
+
+        Raises:
+            ValueError: The gate is not available in the device.
+        """
+
+    def two_qubit_gate_time(self) -> float:
+        """
+        Returns the gate time of a two qubit operation on this device.
+
+        Returns:
+            float: The gate time.
+
+        Raises:
+            ValueError: The gate is not available in the device.
+        """
+
+    def three_qubit_gate_time(self) -> float:
+        """
+        Returns the gate time of a three qubit operation on this device.
+
+        Returns:
+            float: The gate time.
+
+        Raises:
+            ValueError: The gate is not available in the device.
+        """
+
+    def multi_qubit_gate_time(self) -> float:
+        """
+        Returns the gate time of a multi qubit operation on this device.
+
+        Returns:
+            float: The gate time.
+
+        Raises:
+            ValueError: The gate is not available in the device.
+        """
+
+    def phase_shift_controlled_z(self) -> float:
+        """
+        Returns the PhaseShiftedControlledZ phase shift according to the device's relation.
+
+        Returns:
+            float: The PhaseShiftedControlledZ phase shift.
+
+        Raises:
+            ValueError: Error in relation selection.
+        """
+
+    def phase_shift_controlled_phase(self) -> float:
+        """
+        Returns the PhaseShiftedControlledPhase phase shift according to the device's relation.
+
+        Returns:
+            float: The PhaseShiftedControlledPhase phase shift.
+
+        Raises:
+            ValueError: Error in relation selection.
+        """
+
+    def phi_theta_relation(self, relation_name: str, theta: float) -> Optional[float]:
+        """
+        Returns phi for the given theta according to a custom or builtin phi-theta relation.
+
+        Args:
+            relation_name (str): The name of the relation.
+            theta (float): The theta angle.
+
+        Returns:
+            Optional[float]: The phi angle, None if the relation does not exist or is not defined for theta.
+        """
+
+    def gate_time_controlled_z(self, control: int, target: int, phi: float) -> float:
+        """
+        Returns the gate time of a PhaseShiftedControlledZ operation with the given qubits and phi angle.
+
+        Args:
+            control (int): The control qubit the gate acts on
+            target (int): The target qubit the gate acts on
+            phi (float): The phi angle to be checked.
+
+        Returns:
+            float: The gate time.
+
+        Raises:
+            ValueError: The gate is not available on the device.
+        """
+
+    def gate_time_controlled_phase(
+        self, control: int, target: int, phi: float, theta: float
+    ) -> float:
+        """
+        Returns the gate time of a PhaseShiftedControlledPhase operation with the given qubits and phi and theta angles.
+
+        Args:
+            control (int): The control qubit the gate acts on
+            target (int): The target qubit the gate acts on
+            phi (float): The phi angle to be checked.
+            theta (float): The theta angle to be checked.
+
+        Returns:
+            float: The gate time.
+
+        Raises:
+            ValueError: The gate is not available on the device.
+        """
+
+    def generic_device(self) -> GenericDevice:
+        """
+        Turns Device into GenericDevice
+
+        Can be used as a generic interface for devices when a boxed dyn trait object cannot be used
+        (for example when the interface needs to be serialized)
+
+        The gate times of the current Layout are translated through the qubit -> tweezer mapping,
+        gates on tweezers not holding a qubit are skipped and three-qubit gates are approximated
+        by multi-qubit gates on the same qubits.
+
+        Returns:
+            GenericDevice: The device in generic representation
+
+        Raises:
+            ValueError: No current Layout is set.
+
+        Note:
+            GenericDevice uses nested HashMaps to represent the most general device connectivity.
+            The memory usage will be inefficient for devices with large qubit numbers.
+        """
+
+    def generic_device_checked(self) -> GenericDevice:
+        """
+        Turns Device into GenericDevice, failing if gate times of the current Layout would be lost.
+
+        Unlike `generic_device` no gate is skipped or approximated.
+
+        Returns:
+            GenericDevice: The device in generic representation
+
+        Raises:
+            ValueError: No current Layout is set, a gate is defined on a tweezer not holding a qubit or a three-qubit gate is defined.
+        """
+
+    def to_bincode(self) -> bytearray:
+        """
+        Return the bincode representation of the TweezerMutableDevice using the bincode crate.
+
+        Returns:
+            ByteArray: The serialized TweezerMutableDevice (in bincode form).
+
+        Raises:
+            ValueError: Cannot serialize TweezerMutableDevice to bytes.
+        """
+
+    @staticmethod
+    def from_bincode(input: bytearray) -> TweezerMutableDevice:
+        """
+        Convert the bincode representation of the TweezerMutableDevice to an
+        TweezerMutableDevice using the bincode crate.
+
+        Args:
+            input (ByteArray): The serialized TweezerMutableDevice (in bincode form).
+
+        Returns:
+            TweezerMutableDevice: The deserialized TweezerMutableDevice.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized to TweezerMutableDevice.
+        """
+
+    def to_json(self) -> str:
+        """
+        Return the json representation of the TweezerMutableDevice.
+
+        Additionally, a gate set check is performed.
+
+        Returns:
+            str: The serialized form of TweezerMutableDevice.
+
+        Raises:
+            ValueError: Cannot serialize TweezerMutableDevice to json or
+                the device does not have valid QRyd gates available.
+        """
+
+    @staticmethod
+    def from_json(input: str) -> TweezerMutableDevice:
+        """
+        Convert the json representation of a TweezerMutableDevice to an TweezerMutableDevice.
+
+        Additionally, a gate set check is performed.
+
+        Args:
+            input (str): The serialized TweezerMutableDevice in json form.
+
+        Returns:
+            TweezerMutableDevice: The deserialized TweezerMutableDevice.
+
+        Raises:
+            ValueError: Input cannot be deserialized to TweezerMutableDevice or
+                the device does not have valid QRyd gates available.
+        """
+
+    def number_qubits(self) -> int:
+        """
+        Return number of qubits in device.
+
+        Returns:
+            int: The number of qubits.
+
+        """
+
+    def number_tweezer_positions(self, layout_name: Optional[str]) -> int:
+        """
+        Returns the number of total tweezer positions in the device.
+
+        Args:
+            layout_name (Optional[str]): The name of the layout to reference. Defaults to the current layout.
+
+        Returns:
+            int: The number of tweezer positions in the device.
+        """
+
+    def two_qubit_edges(self) -> Sequence[(int, int)]:
+        """
+        Return the list of pairs of qubits linked by a native two-qubit-gate in the device.
+
+        A pair of qubits is considered linked by a native two-qubit-gate if the device
+        can implement a two-qubit-gate between the two qubits without decomposing it
+        into a sequence of gates that involves a third qubit of the device.
+        The two-qubit-gate also has to form a universal set together with the available
+        single qubit gates.
+
+        The returned vectors is a simple, graph-library independent, representation of
+        the undirected connectivity graph of the device.
+        It can be used to construct the connectivity graph in a graph library of the user's
+        choice from a list of edges and can be used for applications like routing in quantum algorithms.
+
+        Returns:
+            Sequence[(int, int)]: List of two qubit edges in the undirected connectivity graph
+        """
+
+    def two_tweezer_edges(self) -> Sequence[(int, int)]:
+        """
+        Returns the two tweezer edges of the device.
+
+        And edge between two tweezer is valid only if the
+        PhaseShiftedControlledPhase gate can be performed.
+
+        Returns:
+            Sequence[(int, int)]: List of two tweezer edges
+        """
+
+    def qrydbackend(self):
+        """
+        Returns the backend associated with the device.
+        """
+
+    def seed(self):
+        """
+        Returns the seed usized for the API.
+        """
+
+    def _enum_to_bincode(self) -> bytearray:
+        """
+        Return the bincode representation of the Enum variant of the Device.
+
+        Only used for internal interfacing.
+
+        Returns:
+            ByteArray: The serialized TweezerMutableDevice (in [bincode] form).
+
+        Raises:
+            ValueError: Cannot serialize Device to bytes.
+        """
+
+    def set_tweezer_single_qubit_gate_time(
+        self, hqslang: str, tweezer: int, gate_time: float, layout_name: Optional[str]
+    ):
+        """
+        Set the time of a single-qubit gate for a tweezer in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a single-qubit gate.
+            tweezer (int): The index of the tweezer.
+            gate_time (float): The the gate time for the given gate.
+            layout_name (Optional[str]): The name of the Layout to apply the gate time in.
+                Defaults to the current Layout.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set.
+        """
+
+    def set_tweezer_two_qubit_gate_time(
+        self,
+        hqslang: str,
+        tweezer0: int,
+        tweezer1: int,
+        gate_time: float,
+        layout_name: Optional[str],
+    ):
+        """
+        Set the time of a two-qubit gate for a tweezer couple in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a single-qubit gate.
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            gate_time (float): The the gate time for the given gate.
+            layout_name (Optional[str]): The name of the Layout to apply the gate time in.
+                Defaults to the current Layout.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set.
+        """
+
+    def set_controlled_phase_time_curve(
+        self,
+        tweezer0: int,
+        tweezer1: int,
+        samples: List[Tuple[float, float]],
+        layout_name: Optional[str],
+    ):
+        """
+        Set the theta-dependent gate time curve of the PhaseShiftedControlledPhase gate for a tweezer pair in a given Layout.
+
+        The gate time of a PhaseShiftedControlledPhase gate with the rotation angle theta is
+        interpolated linearly between the samples of the curve. Theta is wrapped into [-pi, pi]
+        and only its absolute value is used, so the samples are given for theta in [0, pi].
+
+        Args:
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            samples (List[Tuple[float, float]]): The (theta, gate time) samples of the curve.
+            layout_name (Optional[str]): The name of the Layout to set the curve in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The samples are invalid, no layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def set_tweezer_three_qubit_gate_time(
+        self,
+        hqslang: str,
+        tweezer0: int,
+        tweezer1: int,
+        tweezer2: int,
+        gate_time: float,
+        layout_name: Optional[str],
+    ):
+        """
+        Set the time of a three-qubit gate for a tweezer trio in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a three-qubit gate.
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            tweezer2 (int): The index of the third tweezer.
+            gate_time (float): The the gate time for the given gate.
+            layout_name (Optional[str]): The name of the Layout to apply the gate time in.
+                Defaults to the current Layout.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set.
+        """
+
+    def set_tweezer_multi_qubit_gate_time(
+        self,
+        hqslang: str,
+        tweezers: List[int],
+        gate_time: float,
+        layout_name: Optional[str],
+    ):
+        """
+        Set the time of a multi-qubit gate for a list of tweezers in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a multi-qubit gate.
+            tweezers (List[int]): The list of tweezer indexes.
+            gate_time (float): The the gate time for the given gate.
+            layout_name (Optional[str]): The name of the Layout to apply the gate time in.
+                Defaults to the current Layout.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set.
+        """
+
+    def set_tweezer_single_qubit_gate_times(
+        self,
+        hqslang: str,
+        gate_times: List[Tuple[int, float]],
+        layout_name: Optional[str],
+    ):
+        """
+        Set the times of a single-qubit gate for many tweezers in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a single-qubit gate.
+            gate_times (List[Tuple[int, float]]): The (tweezer, gate time) entries.
+            layout_name (Optional[str]): The name of the Layout to apply the gate times in.
+                Defaults to the current Layout.
+
+        Raises:
+            ValueError: The gate is not supported, no layout name provided and no current layout set, or the layout is not present.
+        """
+
+    def set_tweezer_two_qubit_gate_times(
+        self,
+        hqslang: str,
+        gate_times: List[Tuple[int, int, float]],
+        layout_name: Optional[str],
+    ):
+        """
+        Set the times of a two-qubit gate for many tweezer couples in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a two-qubit gate.
+            gate_times (List[Tuple[int, int, float]]): The (tweezer0, tweezer1, gate time) entries.
+            layout_name (Optional[str]): The name of the Layout to apply the gate times in.
+                Defaults to the current Layout.
+
+        Raises:
+            ValueError: The gate is not supported, no layout name provided and no current layout set, or the layout is not present.
+        """
+
+    def set_tweezer_three_qubit_gate_times(
+        self,
+        hqslang: str,
+        gate_times: List[Tuple[int, int, int, float]],
+        layout_name: Optional[str],
+    ):
+        """
+        Set the times of a three-qubit gate for many tweezer trios in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a three-qubit gate.
+            gate_times (List[Tuple[int, int, int, float]]): The (tweezer0, tweezer1, tweezer2, gate time) entries.
+            layout_name (Optional[str]): The name of the Layout to apply the gate times in.
+                Defaults to the current Layout.
+
+        Raises:
+            ValueError: The gate is not supported, no layout name provided and no current layout set, or the layout is not present.
+        """
+
+    def unset_tweezer_single_qubit_gate_time(
+        self,
+        hqslang: str,
+        tweezer: int,
+        layout_name: Optional[str],
+    ) -> Optional[float]:
+        """
+        Remove the time of a single-qubit gate for a tweezer in a given Layout.
+
+        The error rate of the gate is removed as well.
+
+        Args:
+            hqslang (str): The hqslang name of a single-qubit gate.
+            tweezer (int): The index of the tweezer.
+            layout_name (Optional[str]): The name of the Layout to remove the gate time from.
+                Defaults to the current Layout.
+
+        Returns:
+            Optional[float]: The removed gate time, None if it was not set.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set, or the layout is not present.
+        """
+
+    def unset_tweezer_two_qubit_gate_time(
+        self,
+        hqslang: str,
+        tweezer0: int,
+        tweezer1: int,
+        layout_name: Optional[str],
+    ) -> Optional[float]:
+        """
+        Remove the time of a two-qubit gate for a tweezer couple in a given Layout.
+
+        The error rate of the gate is removed as well.
+
+        Args:
+            hqslang (str): The hqslang name of a two-qubit gate.
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            layout_name (Optional[str]): The name of the Layout to remove the gate time from.
+                Defaults to the current Layout.
+
+        Returns:
+            Optional[float]: The removed gate time, None if it was not set.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set, or the layout is not present.
+        """
+
+    def unset_tweezer_three_qubit_gate_time(
+        self,
+        hqslang: str,
+        tweezer0: int,
+        tweezer1: int,
+        tweezer2: int,
+        layout_name: Optional[str],
+    ) -> Optional[float]:
+        """
+        Remove the time of a three-qubit gate for a tweezer trio in a given Layout.
+
+        The error rate of the gate is removed as well.
+
+        Args:
+            hqslang (str): The hqslang name of a three-qubit gate.
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            tweezer2 (int): The index of the third tweezer.
+            layout_name (Optional[str]): The name of the Layout to remove the gate time from.
+                Defaults to the current Layout.
+
+        Returns:
+            Optional[float]: The removed gate time, None if it was not set.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set, or the layout is not present.
+        """
+
+    def unset_tweezer_multi_qubit_gate_time(
+        self,
+        hqslang: str,
+        tweezers: List[int],
+        layout_name: Optional[str],
+    ) -> Optional[float]:
+        """
+        Remove the time of a multi-qubit gate for a list of tweezers in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a multi-qubit gate.
+            tweezers (List[int]): The list of tweezer indexes.
+            layout_name (Optional[str]): The name of the Layout to remove the gate time from.
+                Defaults to the current Layout.
+
+        Returns:
+            Optional[float]: The removed gate time, None if it was not set.
+
+        Raises:
+            ValueError: No layout name provided and no current layout set, or the layout is not present.
+        """
+
+    def clear_layout(self, name: str):
+        """
+        Remove all gate times, error rates, shifts, coordinates and tweezers per row of a Layout.
+
+        The Layout stays in the register as an empty Layout.
+
+        Args:
+            name (str): The name of the Layout to clear.
+
+        Raises:
+            ValueError: The layout is not present.
+        """
+
+    def set_allowed_tweezer_shifts(
+        self, tweezer: int, allowed_shifts: list[list[int]], layout_name: Optional[str]
+    ):
+        """
+        Set the allowed Tweezer shifts of a specified Tweezer.
+
+        The tweezer give the tweezer a qubit can be shifted out of. The values are lists
         over the directions the qubit in the tweezer can be shifted into.
         The items in the list give the allowed tweezers the qubit can be shifted into in order.
         For a list 1,2,3 the qubit can be shifted into tweezer 1, into tweezer 2 if tweezer 1 is not occupied,
@@ -938,6 +2487,65 @@ class TweezerMutableDevice:
             ValueError: The involved tweezers are not present in the device.
         """
 
+    def set_tweezer_shift_time(
+        self, start: int, end: int, shift_time: float, layout_name: Optional[str]
+    ):
+        """
+        Set the transport time of an allowed shift in a given Layout.
+
+        The transport time takes precedence over the time per site set with `set_shift_time_per_site`.
+
+        Args:
+            start (int): The tweezer the qubit is shifted out of.
+            end (int): The tweezer the qubit is shifted into.
+            shift_time (float): The transport time of the shift.
+            layout_name (Optional[str]): The name of the Layout to set the transport time in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The transport time is negative or not finite, the shift is not allowed, no layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def set_shift_time_per_site(self, time_per_site: float, layout_name: Optional[str]):
+        """
+        Set the transport time per tweezer passed during a shift in a given Layout.
+
+        Shifting a qubit into the n-th tweezer of an allowed shift direction takes n times the
+        time per site, unless a transport time is set for the shift with `set_tweezer_shift_time`.
+
+        Args:
+            time_per_site (float): The transport time per tweezer passed.
+            layout_name (Optional[str]): The name of the Layout to set the time in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The time is negative or not finite, no layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def set_global_rotation_time(self, gate_time: float, layout_name: Optional[str]):
+        """
+        Set the gate time of the global rotation PragmaGlobalRotateXY in a given Layout.
+
+        The global rotation is only available in the Layouts with a global rotation time.
+
+        Args:
+            gate_time (float): The gate time of the global rotation.
+            layout_name (Optional[str]): The name of the Layout to set the time in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The time is negative or not finite, no layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def set_layout_switch_time(self, switch_time: float, layout_name: Optional[str]):
+        """
+        Set the time to switch the device into a given Layout with a PragmaSwitchDeviceLayout.
+
+        Args:
+            switch_time (float): The time to switch into the Layout.
+            layout_name (Optional[str]): The name of the Layout to set the time in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The time is negative or not finite, no layout name provided and no current layout set or the Layout does not exist.
+        """
+
     def set_tweezers_per_row(tweezers_per_row: List[int], layout_name: Optional[str], self):
         """
         Set the tweezer per row value for a given Layout.
@@ -953,6 +2561,158 @@ class TweezerMutableDevice:
             ValueError: No layout name provided and no current layout set.
         """
 
+    def set_tweezer_coordinates(self, tweezer: int, x: float, y: float, layout_name: Optional[str]):
+        """
+        Set the (x, y) coordinates of a tweezer in a given Layout.
+
+        The coordinates are used by `set_two_qubit_gate_times_from_distance` and by `draw`,
+        which places the tweezers at their true positions when all tweezers have coordinates.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+            x (float): The x coordinate of the tweezer.
+            y (float): The y coordinate of the tweezer.
+            layout_name (Optional[str]): The name of the Layout to set the coordinates in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The coordinates are not finite or no layout name provided and no current layout set.
+        """
+
+    def set_tweezer_zone(self, tweezer: int, zone: str, layout_name: Optional[str]):
+        """
+        Set the zone label of a tweezer in a given Layout.
+
+        Zone labels describe the region of a zoned trap a tweezer is placed in, e.g. "storage",
+        "interaction" or "readout". In a Layout with zone labels two-qubit gates are only allowed
+        between tweezers labelled "interaction", see `check_zone_constraints`.
+
+        Args:
+            tweezer (int): The index of the tweezer.
+            zone (str): The label of the zone of the tweezer.
+            layout_name (Optional[str]): The name of the Layout to set the zone in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The zone label is empty or no layout name provided and no current layout set.
+        """
+
+    def add_blockaded_tweezer_pair(
+        self, tweezer0: int, tweezer1: int, layout_name: Optional[str]
+    ):
+        """
+        Add a pair of tweezers that can not be driven simultaneously in a given Layout.
+
+        The constraint is symmetric, e.g. due to the Rydberg blockade or crosstalk between the
+        tweezers. See `can_execute_in_parallel`.
+
+        Args:
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            layout_name (Optional[str]): The name of the Layout to add the pair to. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The tweezers are the same, no layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def set_blockade_radius(self, radius: float, layout_name: Optional[str]):
+        """
+        Set the blockade radius of a given Layout.
+
+        Tweezers with coordinates at most the blockade radius apart can not be driven simultaneously.
+
+        Args:
+            radius (float): The blockade radius, in the units of the tweezer coordinates.
+            layout_name (Optional[str]): The name of the Layout to set the radius in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The radius is negative or not finite, no layout name provided and no current layout set or the Layout does not exist.
+        """
+
+    def set_tweezer_single_qubit_gate_error(
+        self, hqslang: str, tweezer: int, error: float, layout_name: Optional[str]
+    ):
+        """
+        Set the error rate of a single-qubit gate for a tweezer in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a single-qubit gate.
+            tweezer (int): The index of the tweezer.
+            error (float): The error rate of the gate, between 0 and 1.
+            layout_name (Optional[str]): The name of the Layout to set the error rate in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The gate is not supported, the error rate is invalid or the Layout does not exist.
+        """
+
+    def set_tweezer_two_qubit_gate_error(
+        self,
+        hqslang: str,
+        tweezer0: int,
+        tweezer1: int,
+        error: float,
+        layout_name: Optional[str],
+    ):
+        """
+        Set the error rate of a two-qubit gate for a tweezer couple in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a two-qubit gate.
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            error (float): The error rate of the gate, between 0 and 1.
+            layout_name (Optional[str]): The name of the Layout to set the error rate in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The gate is not supported, the error rate is invalid or the Layout does not exist.
+        """
+
+    def set_tweezer_three_qubit_gate_error(
+        self,
+        hqslang: str,
+        tweezer0: int,
+        tweezer1: int,
+        tweezer2: int,
+        error: float,
+        layout_name: Optional[str],
+    ):
+        """
+        Set the error rate of a three-qubit gate for a tweezer trio in a given Layout.
+
+        Args:
+            hqslang (str): The hqslang name of a three-qubit gate.
+            tweezer0 (int): The index of the first tweezer.
+            tweezer1 (int): The index of the second tweezer.
+            tweezer2 (int): The index of the third tweezer.
+            error (float): The error rate of the gate, between 0 and 1.
+            layout_name (Optional[str]): The name of the Layout to set the error rate in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The gate is not supported, the error rate is invalid or the Layout does not exist.
+        """
+
+    def set_two_qubit_gate_times_from_distance(
+        self,
+        hqslang: str,
+        max_distance: float,
+        time_fn: Callable[[float], float],
+        layout_name: Optional[str],
+    ):
+        """
+        Set the time of a two-qubit gate for all tweezer pairs within a distance in a given Layout.
+
+        For every pair of tweezers with coordinates at most `max_distance` apart, the gate time
+        `time_fn(distance)` is set for both orders of the tweezers.
+
+        Args:
+            hqslang (str): The hqslang name of a two-qubit gate.
+            max_distance (float): The maximal distance between two tweezers supporting the gate.
+            time_fn (Callable[[float], float]): Returns the gate time for the distance between two tweezers.
+            layout_name (Optional[str]): The name of the Layout to set the gate times in. Defaults to the current Layout.
+
+        Raises:
+            ValueError: The gate is not supported, the maximal distance is negative, time_fn failed or
+                returned an invalid gate time or the Layout does not exist.
+        """
+
     def set_allow_reset(self, allow_reset: bool):
         """
         Set whether the device allows PragmaActiveReset operations or not.
@@ -964,15 +2724,65 @@ class TweezerMutableDevice:
             ValueError: The device isn't compatible with PragmaActiveReset.
         """
 
-    def set_default_layout(self, layout: str):
+    def set_symmetric_two_qubit_gate_times(self, symmetric: bool):
+        """
+        Set whether setting a two-qubit gate time for a tweezer pair also sets it for the reversed pair.
+
+        Applies to `set_tweezer_two_qubit_gate_time`, `set_tweezer_two_qubit_gate_times` and
+        `unset_tweezer_two_qubit_gate_time`. Gate times set before are not changed.
+
+        Args:
+            symmetric (bool): Whether the two-qubit gate times are set symmetrically.
+        """
+
+    def set_native_gates(
+        self,
+        single_qubit_gates: List[str],
+        two_qubit_gates: List[str],
+        three_qubit_gates: List[str],
+        multi_qubit_gates: List[str],
+    ):
+        """
+        Set the native gates gate times and error rates can be set for.
+
+        Allows modeling devices supporting other native gates than the QRyd backend, e.g.
+        `SqrtPauliX`. The gates with gate times in a Layout of the device need to stay native.
+
+        Args:
+            single_qubit_gates (List[str]): The hqslang names of the native single-qubit gates.
+            two_qubit_gates (List[str]): The hqslang names of the native two-qubit gates.
+            three_qubit_gates (List[str]): The hqslang names of the native three-qubit gates.
+            multi_qubit_gates (List[str]): The hqslang names of the native multi-qubit gates.
+
+        Raises:
+            ValueError: A gate with gate times in a Layout is not native anymore.
+        """
+
+    def set_max_qubits(self, max_qubits: Optional[int]):
+        """
+        Set the maximal number of qubits the hardware supports.
+
+        Qubits with an index of at least the capacity can not be mapped to tweezers, and circuits
+        acting on them are rejected by the backends before they are run or posted.
+
+        Args:
+            max_qubits (Optional[int]): The maximal number of qubits, None if the capacity is not limited.
+
+        Raises:
+            ValueError: A qubit of the qubit -> tweezer mapping exceeds the capacity.
+        """
+
+    def set_default_layout(self, layout: str, mapping_policy: Optional[str] = None):
         """
         Set the name of the default layout to use and switch to it.
 
         Args:
             layout (str): The name of the layout to use.
+            mapping_policy (Optional[str]): The policy populating the qubit -> tweezer mapping, one of
+                `all-tweezers`, `usable-tweezers`, `occupied-first` or `none`. Defaults to `all-tweezers`.
 
         Raises:
-            ValueError: The given layout name is not present in the layout register.
+            ValueError: The given layout name is not present in the layout register or the policy is unknown.
         """
 
     def draw(
@@ -980,6 +2790,10 @@ class TweezerMutableDevice:
         draw_shifts: Optional[bool],
         pixel_per_point: Optional[float],
         file_save_path: Optional[str],
+        show_tweezer_labels: Optional[bool],
+        show_qubit_labels: Optional[bool],
+        show_legend: Optional[bool],
+        dpi: Optional[float],
     ):
         """
         Creates a graph representing a TweezerDevice.
@@ -987,8 +2801,82 @@ class TweezerMutableDevice:
         Args:
             draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
             pixel_per_point (Optional[float]): The quality of the image.
-            file_save_path (Optional[str]): Path to save the image to. Default: output the image with the display method.
+            file_save_path (Optional[str]): Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics. Default: output the image with the display method.
+            show_tweezer_labels (Optional[bool]): Whether to label the tweezers with their index. Default: true
+            show_qubit_labels (Optional[bool]): Whether to label the tweezers with the qubit they hold. Default: true
+            show_legend (Optional[bool]): Whether to add a legend explaining the labels and edges. Default: false
+            dpi (Optional[float]): The resolution of the image in dots per inch, overrides pixel_per_point.
 
         Raises:
             ValueError: if there is no layout, an error occurred during the compilation or and invalid path was provided.
         """
+
+    def draw_layouts(
+        self,
+        layout_names: List[str],
+        draw_shifts: Optional[bool],
+        pixel_per_point: Optional[float],
+        file_save_path: Optional[str],
+        show_legend: Optional[bool],
+    ):
+        """
+        Creates a graph showing several Layouts of the TweezerDevice next to each other.
+
+        Tweezers whose available gates differ between the Layouts are highlighted.
+
+        Args:
+            layout_names (List[str]): The names of the Layouts to draw, from left to right.
+            draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+            pixel_per_point (Optional[float]): The quality of the image.
+            file_save_path (Optional[str]): Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics. Default: output the image with the display method.
+            show_legend (Optional[bool]): Whether to add a legend explaining the labels, edges and highlighted tweezers. Default: false
+
+        Raises:
+            ValueError: if no Layout is given, a Layout is not present, an error occurred during the compilation or an invalid path was provided.
+        """
+
+    def draw_circuit_shifts(
+        self,
+        circuit: Circuit,
+        file_save_path: str,
+        draw_shifts: Optional[bool],
+        pixel_per_point: Optional[float],
+        frame_delay_ms: Optional[int],
+    ):
+        """
+        Creates one drawing of the TweezerDevice per device-changing step of a circuit.
+
+        The first frame shows the device before the circuit. The PragmaChangeDevice operations of
+        the circuit, e.g. Layout switches, shifts and deactivations, are applied in order to a copy
+        of the device and each adds a frame. The device itself is not changed.
+
+        Args:
+            circuit (Circuit): The circuit whose device-changing operations are drawn.
+            file_save_path (str): Path to save the drawings to. A `.gif` file is saved as an animated GIF, otherwise every frame is saved with its index appended to the file name.
+            draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+            pixel_per_point (Optional[float]): The quality of the images.
+            frame_delay_ms (Optional[int]): The time each frame of the animated GIF is shown in milliseconds. Default: 500
+
+        Raises:
+            TypeError: Circuit argument cannot be converted to qoqo Circuit.
+            ValueError: if there is no layout, a device-changing operation is not valid on the device, an error occurred during the compilation or an invalid path was provided.
+        """
+
+    def upgrade_circuit(self, circuit: Circuit) -> Circuit:
+        """
+        Rewrites the legacy QRyd pragmas of a circuit into the tweezer pragmas.
+
+        A PragmaChangeQRydLayout to the layout number `n` becomes a PragmaSwitchDeviceLayout to the
+        Layout named `n`. A PragmaShiftQRydQubit becomes a parallel PragmaShiftQubitsTweezers moving
+        every qubit to the tweezer of its new position, with the tweezers numbered row by row.
+        Legacy pragmas that can not be converted are left unchanged. The device itself is not changed.
+
+        Args:
+            circuit (Circuit): The circuit to convert.
+
+        Returns:
+            Circuit: The circuit with the convertible legacy pragmas replaced.
+
+        Raises:
+            TypeError: Circuit argument cannot be converted to qoqo Circuit.
+        """
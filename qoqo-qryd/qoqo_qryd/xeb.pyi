@@ -0,0 +1,138 @@
+# This is an auto generated file containing only the documentation.
+# You can find the full implementation on this page:
+# https://github.com/HQSquantumsimulations/qoqo_qryd
+
+"""
+Cross-entropy benchmarking for QRyd devices.
+
+.. autosummary::
+   :toctree: generated/
+
+   XEBExperiment
+   linear_xeb_fidelity
+
+"""
+
+from typing import Optional, List, Any, Dict
+from qoqo import Circuit
+
+class XEBExperiment:
+    """
+    Experiment builder for cross-entropy benchmarking.
+
+    Each cycle consists of a layer of random `RotateXY(pi/2, phi)` rotations on all qubits,
+    with `phi` a multiple of `pi/4`, followed by `ControlledPauliZ` gates on a set of
+    non-overlapping qubit pairs connected on the device. A final rotation layer precedes
+    the measurement. The same experiment can be run on the SimulatorBackend and the APIBackend.
+
+    Args:
+        device (Union[TweezerDevice, EmulatorDevice]): The device the circuits are generated for.
+        qubits (List[int]): The benchmarked qubits.
+        depths (List[int]): The positive numbers of cycles to benchmark.
+        number_circuits (int): The number of random circuits for each depth.
+        number_measurements (int): The number of measurements performed for each circuit.
+        seed (Optional[int]): The seed of the random circuit generation.
+
+    Raises:
+        TypeError: The device is not a TweezerDevice or EmulatorDevice.
+        ValueError: Invalid arguments or the device does not support `RotateXY` on all qubits.
+    """
+
+    def __init__(
+        self,
+        device: Any,
+        qubits: List[int],
+        depths: List[int],
+        number_circuits: int,
+        number_measurements: int,
+        seed: Optional[int] = None,
+    ):
+        return
+
+    def qubits(self) -> List[int]:
+        """
+        Returns the benchmarked qubits.
+
+        Returns:
+            List[int]: The benchmarked qubits.
+        """
+
+    def number_measurements(self) -> int:
+        """
+        Returns the number of measurements performed for each circuit.
+
+        Returns:
+            int: The number of measurements.
+        """
+
+    def depths(self) -> List[int]:
+        """
+        Returns the number of cycles of each circuit, in the order of `circuits`.
+
+        Returns:
+            List[int]: The number of cycles of each circuit.
+        """
+
+    def circuits(self) -> List[Circuit]:
+        """
+        Returns the measurement circuits of the experiment.
+
+        The i-th benchmarked qubit is measured into the i-th bit of the `ro_xeb` register.
+
+        Returns:
+            List[Circuit]: The measurement circuits.
+        """
+
+    def ideal_probabilities(self) -> List[List[float]]:
+        """
+        Returns the ideal output probabilities of each circuit.
+
+        Bit `i` of the basis state index corresponds to the i-th benchmarked qubit.
+
+        Returns:
+            List[List[float]]: The ideal output probabilities.
+        """
+
+    def analyse(self, registers: List[List[List[bool]]]) -> Dict[str, Any]:
+        """
+        Estimates the fidelities from the measured bit registers.
+
+        Args:
+            registers (List[List[List[bool]]]): The measured bit registers, one per circuit.
+
+        Returns:
+            Dict[str, Any]: The depths, the normalized linear XEB fidelities per depth and the fitted fidelity_per_cycle.
+
+        Raises:
+            ValueError: The registers do not match the circuits of the experiment.
+        """
+
+    def run(self, backend: Any) -> Dict[str, Any]:
+        """
+        Runs the experiment on a backend and estimates the fidelities.
+
+        Args:
+            backend (Union[SimulatorBackend, APIBackend]): The backend the circuits are run on.
+
+        Returns:
+            Dict[str, Any]: The depths, the normalized linear XEB fidelities per depth and the fitted fidelity_per_cycle.
+
+        Raises:
+            RuntimeError: Running a circuit failed.
+            ValueError: The analysis failed.
+        """
+
+def linear_xeb_fidelity(ideal_probabilities: List[float], register: List[List[bool]]) -> float:
+    """
+    Returns the linear XEB fidelity `2^n * <p(x)> - 1` of a measured bit register.
+
+    Args:
+        ideal_probabilities (List[float]): The ideal output probabilities of the circuit.
+        register (List[List[bool]]): The measured bit register, one entry per shot.
+
+    Returns:
+        float: The linear XEB fidelity.
+
+    Raises:
+        ValueError: The register is empty or does not match the probabilities.
+    """
@@ -14,6 +14,7 @@
 
 use crate::api_devices::convert_into_device;
 use bincode::{deserialize, serialize};
+use numpy::{PyArray2, ToPyArray};
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::{PyByteArray, PyDict};
@@ -24,7 +25,9 @@ use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputReg
 use roqoqo::Circuit;
 use roqoqo_qryd::APIBackend;
 use roqoqo_qryd::QRydAPIDevice;
+use roqoqo_qryd::{APIClientConfig, APIFixtureMode, JobProgress, QRydJobStatus, ResultCounts};
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Qoqo backend interfacing QRydDemo WebAPI.
 ///
@@ -116,23 +119,242 @@ impl APIBackendWrapper {
         Ok(job_location)
     }
 
+    /// Substitute the symbolic parameters of a program and post it as a job.
+    ///
+    /// The WebAPI does not support symbolic parameters. The values are assigned to the
+    /// input parameter names of the program and substituted into all circuits,
+    /// including the constant circuit, before the job is serialized.
+    ///
+    /// Args:
+    ///     quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be executed.
+    ///     parameters (List[float]): The values of the input parameters of the program.
+    ///
+    /// Returns:
+    ///     str: URL of the location of the job.
+    ///
+    /// Raises:
+    ///     TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+    ///     RuntimeError: The number of parameters does not match, a circuit remains parametrized or posting the job failed.
+    #[pyo3(text_signature = "($self, quantumprogram, parameters, /)")]
+    pub fn post_job_with_parameters(
+        &self,
+        quantumprogram: &Bound<PyAny>,
+        parameters: Vec<f64>,
+    ) -> PyResult<String> {
+        let program = convert_into_quantum_program(quantumprogram).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "quantumprogram is not of type qoqo.QuantumProgram {}",
+                err
+            ))
+        })?;
+        self.internal
+            .post_job_with_parameters(program, &parameters)
+            .map_err(|err| PyRuntimeError::new_err(format!("Error posting job: {}", err)))
+    }
+
+    /// Post a job once per simulator seed and return the locations of the jobs.
+    ///
+    /// The same quantum program is executed once for every seed, overriding the seed
+    /// of the device.
+    ///
+    /// Args:
+    ///     quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be executed.
+    ///     seeds (List[int]): The simulator seeds, one job is posted for each seed.
+    ///
+    /// Returns:
+    ///     Dict[int, str]: URL of the location of the job posted for each seed.
+    ///
+    /// Raises:
+    ///     TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+    ///     RuntimeError: The seeds are empty or contain duplicates, or posting a job failed.
+    #[pyo3(text_signature = "($self, quantumprogram, seeds, /)")]
+    pub fn post_job_with_seeds(
+        &self,
+        quantumprogram: &Bound<PyAny>,
+        seeds: Vec<usize>,
+    ) -> PyResult<HashMap<usize, String>> {
+        let program = convert_into_quantum_program(quantumprogram).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "quantumprogram is not of type qoqo.QuantumProgram {}",
+                err
+            ))
+        })?;
+        self.internal
+            .post_job_with_seeds(program, &seeds)
+            .map_err(|err| PyRuntimeError::new_err(format!("Error posting job: {}", err)))
+    }
+
+    /// Post the jobs of an expectation value measurement and return their locations.
+    ///
+    /// A PauliZProduct measurement is converted into one ClassicalRegister job per circuit,
+    /// the expectation values are computed locally with `evaluate_measurement_jobs`.
+    /// ClassicalRegister programs are posted unchanged.
+    ///
+    /// Args:
+    ///     quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be executed.
+    ///
+    /// Returns:
+    ///     List[str]: URL of the location of the job posted for each circuit of the measurement.
+    ///
+    /// Raises:
+    ///     TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+    ///     RuntimeError: The measurement is not supported by the WebAPI or posting a job failed.
+    #[pyo3(text_signature = "($self, quantumprogram, /)")]
+    pub fn post_measurement_jobs(&self, quantumprogram: &Bound<PyAny>) -> PyResult<Vec<String>> {
+        let program = convert_into_quantum_program(quantumprogram).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "quantumprogram is not of type qoqo.QuantumProgram {}",
+                err
+            ))
+        })?;
+        self.internal
+            .post_measurement_jobs(program)
+            .map_err(|err| PyRuntimeError::new_err(format!("Error posting job: {}", err)))
+    }
+
+    /// Wait for the jobs of an expectation value measurement and evaluate the expectation values.
+    ///
+    /// Args:
+    ///     quantumprogram (qoqo.QuantumProgram): The PauliZProduct QuantumProgram the jobs were posted for.
+    ///     job_locations (List[str]): The locations returned by `post_measurement_jobs` or `post_job`.
+    ///
+    /// Returns:
+    ///     Optional[Dict[str, float]]: The expectation values of the measurement.
+    ///
+    /// Raises:
+    ///     TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+    ///     RuntimeError: The program is not a PauliZProduct measurement, the number of job locations does not match the circuits or a job failed.
+    #[pyo3(text_signature = "($self, quantumprogram, job_locations, /)")]
+    pub fn evaluate_measurement_jobs(
+        &self,
+        quantumprogram: &Bound<PyAny>,
+        job_locations: Vec<String>,
+    ) -> PyResult<Option<HashMap<String, f64>>> {
+        let program = convert_into_quantum_program(quantumprogram).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "quantumprogram is not of type qoqo.QuantumProgram {}",
+                err
+            ))
+        })?;
+        self.internal
+            .evaluate_measurement_jobs(&program, &job_locations)
+            .map_err(|err| {
+                PyRuntimeError::new_err(format!("Error evaluating measurement: {}", err))
+            })
+    }
+
+    /// Post a program to this backend and a candidate backend and compare the statuses and results.
+    ///
+    /// This backend serves as reference, e.g. with api_version "v1_1", while the candidate
+    /// uses the WebAPI version to be validated, e.g. with the dev flag set.
+    /// Differing job statuses, result metadata and numbers of shots are reported as discrepancies,
+    /// as is a total variation distance between the measured distributions above `tolerance`.
+    ///
+    /// Args:
+    ///     candidate (APIBackend): The backend using the WebAPI version to be validated.
+    ///     quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be executed on both backends.
+    ///     tolerance (float): The maximal total variation distance that is not reported as a discrepancy.
+    ///
+    /// Returns:
+    ///     Dict[str, Any]: The API versions, statuses and counts of both runs, the total variation
+    ///         distance (None if a job did not complete) and the list of discrepancies.
+    ///
+    /// Raises:
+    ///     TypeError: candidate is not an APIBackend or quantumprogram is not a qoqo.QuantumProgram.
+    ///     RuntimeError: The tolerance is negative or posting or polling a job failed.
+    #[pyo3(text_signature = "($self, candidate, quantumprogram, tolerance, /)")]
+    pub fn compare_api_versions(
+        &self,
+        candidate: &Bound<PyAny>,
+        quantumprogram: &Bound<PyAny>,
+        tolerance: f64,
+    ) -> PyResult<Py<PyDict>> {
+        let candidate = convert_into_backend(candidate).map_err(|err| {
+            PyTypeError::new_err(format!("candidate is not of type APIBackend {}", err))
+        })?;
+        let program = convert_into_quantum_program(quantumprogram).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "quantumprogram is not of type qoqo.QuantumProgram {}",
+                err
+            ))
+        })?;
+        let comparison =
+            roqoqo_qryd::compare_api_versions(&self.internal, &candidate, program, tolerance)
+                .map_err(|err| {
+                    PyRuntimeError::new_err(format!("Error comparing API versions: {}", err))
+                })?;
+        Python::with_gil(|py| {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("reference_version", comparison.reference_version)?;
+            dict.set_item("candidate_version", comparison.candidate_version)?;
+            dict.set_item("reference_status", comparison.reference_status)?;
+            dict.set_item("candidate_status", comparison.candidate_status)?;
+            dict.set_item("reference_counts", comparison.reference_counts)?;
+            dict.set_item("candidate_counts", comparison.candidate_counts)?;
+            dict.set_item(
+                "total_variation_distance",
+                comparison.total_variation_distance,
+            )?;
+            dict.set_item("discrepancies", comparison.discrepancies)?;
+            Ok(dict.unbind())
+        })
+    }
+
+    /// Estimate the resources of a program before posting it.
+    ///
+    /// The number of qubits, native gates and shots and the execution time are computed
+    /// locally from the gate times of the device of the backend. Gates not provided by the
+    /// device are counted with a standard decomposition into native gates.
+    ///
+    /// Args:
+    ///     quantumprogram (qoqo.QuantumProgram): qoqo QuantumProgram to be estimated.
+    ///
+    /// Returns:
+    ///     Dict[str, Any]: The number_qubits, number_circuits, native_gate_counts, number_shots,
+    ///         estimated_time and the gates_without_time whose time is not provided by the device.
+    ///
+    /// Raises:
+    ///     TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+    ///     ValueError: The program is neither a ClassicalRegister nor a PauliZProduct program.
+    #[pyo3(text_signature = "($self, quantumprogram, /)")]
+    pub fn estimate_resources(&self, quantumprogram: &Bound<PyAny>) -> PyResult<Py<PyDict>> {
+        let program = convert_into_quantum_program(quantumprogram).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "quantumprogram is not of type qoqo.QuantumProgram {}",
+                err
+            ))
+        })?;
+        let estimate = self
+            .internal
+            .estimate_resources(&program)
+            .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+        Python::with_gil(|py| {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("number_qubits", estimate.number_qubits)?;
+            dict.set_item("number_circuits", estimate.number_circuits)?;
+            dict.set_item("native_gate_counts", estimate.native_gate_counts)?;
+            dict.set_item("number_shots", estimate.number_shots)?;
+            dict.set_item("estimated_time", estimate.estimated_time)?;
+            dict.set_item("gates_without_time", estimate.gates_without_time)?;
+            Ok(dict.unbind())
+        })
+    }
+
     /// Get status of a posted WebAPI job.
     ///
     /// Args:
     ///     job_location (str): location (url) of the job one is interested in.
     ///
     /// Returns:
-    ///     Dict[str, str]: status and message of the job.
+    ///     Dict[str, Union[str, int, float]]: status and message of the job. The queue_position,
+    ///         estimated_start_time and progress of the job are included when provided by the WebAPI.
     ///
     #[pyo3(text_signature = "($self, job_location, /)")]
-    pub fn get_job_status(&self, job_location: String) -> PyResult<HashMap<&'static str, String>> {
+    pub fn get_job_status(&self, job_location: String) -> PyResult<Py<PyDict>> {
         let status = self.internal.get_job_status(job_location).map_err(|err| {
             PyRuntimeError::new_err(format!("Error retrieving job status: {}", err))
         })?;
-        let mut result = HashMap::new();
-        result.insert("status", status.status);
-        result.insert("msg", status.msg);
-        Ok(result)
+        Python::with_gil(|py| job_status_to_dict(py, &status).map(|dict| dict.unbind()))
     }
 
     /// Get status of a completed WebAPI job.
@@ -141,7 +363,8 @@ impl APIBackendWrapper {
     ///     job_location (str): location (url) of the job one is interested in.
     ///
     /// Returns
-    ///     dict: Result of the job.
+    ///     dict: Result of the job. For jobs run with `dev=True`, "dev_diagnostics" holds the
+    ///         diagnostic fields returned by the develop version of the WebAPI, None otherwise.
     ///
     #[pyo3(text_signature = "($self, job_location, /)")]
     pub fn get_job_result(&self, job_location: String) -> PyResult<PyObject> {
@@ -170,6 +393,126 @@ impl APIBackendWrapper {
                 "executed_two_qubit_gates",
                 job_result.executed_two_qubit_gates,
             )?;
+            match job_result.dev_diagnostics {
+                Some(dev_diagnostics) => {
+                    let json = PyModule::import_bound(py, "json")?;
+                    let fields = serde_json::to_string(&dev_diagnostics.fields).map_err(|err| {
+                        PyRuntimeError::new_err(format!(
+                            "Error serializing dev diagnostics: {}",
+                            err
+                        ))
+                    })?;
+                    result.set_item("dev_diagnostics", json.call_method1("loads", (fields,))?)?;
+                }
+                None => result.set_item("dev_diagnostics", py.None())?,
+            }
+            Ok(result.to_object(py))
+        })
+    }
+
+    /// Get the result of a completed WebAPI job as untyped JSON.
+    ///
+    /// Contrary to `get_job_result`, all fields returned by the WebAPI are kept,
+    /// including fields added in newer WebAPI versions.
+    ///
+    /// Args:
+    ///     job_location (str): location (url) of the job one is interested in.
+    ///
+    /// Returns:
+    ///     dict: The JSON body returned by the WebAPI.
+    ///
+    /// Raises:
+    ///     RuntimeError: Error retrieving the job result.
+    #[pyo3(text_signature = "($self, job_location, /)")]
+    pub fn get_job_result_raw(&self, job_location: String) -> PyResult<PyObject> {
+        let raw_result = self
+            .internal
+            .get_job_result_raw(job_location)
+            .map_err(|err| {
+                PyRuntimeError::new_err(format!("Error retrieving job result: {}", err))
+            })?;
+        Python::with_gil(|py| -> PyResult<PyObject> {
+            let json = PyModule::import_bound(py, "json")?;
+            Ok(json
+                .call_method1("loads", (raw_result.to_string(),))?
+                .unbind())
+        })
+    }
+
+    /// Get the measured bits of a completed WebAPI job as a numpy array.
+    ///
+    /// The bits of all shots are stored in a single boolean array,
+    /// which keeps the memory footprint low for large numbers of shots.
+    ///
+    /// Args:
+    ///     job_location (str): location (url) of the job one is interested in.
+    ///     number_qubits (int): The number of measured qubits.
+    ///
+    /// Returns:
+    ///     np.ndarray: The measured bits with shape (number of shots, number_qubits).
+    ///
+    /// Raises:
+    ///     RuntimeError: Error retrieving the job result or parsing the measured bits.
+    #[pyo3(text_signature = "($self, job_location, number_qubits, /)")]
+    pub fn get_job_result_array(
+        &self,
+        job_location: String,
+        number_qubits: usize,
+    ) -> PyResult<Py<PyArray2<bool>>> {
+        let job_result = self.internal.get_job_result(job_location).map_err(|err| {
+            PyRuntimeError::new_err(format!("Error retrieving job result: {}", err))
+        })?;
+        let bits = APIBackend::counts_to_array(&job_result.data, number_qubits).map_err(|err| {
+            PyRuntimeError::new_err(format!("Error retrieving job result: {}", err))
+        })?;
+        Python::with_gil(|py| Ok(bits.to_pyarray_bound(py).unbind()))
+    }
+
+    /// Convert measured bitstring counts to a numpy array of the measured bits.
+    ///
+    /// Row `i` of the array holds the measured bits of shot `i`, column `j` the bit of qubit `j`.
+    ///
+    /// Args:
+    ///     counts (Dict[str, int]): The counts returned by the WebAPI, keyed by hexadecimal bitstrings.
+    ///     number_qubits (int): The number of measured qubits.
+    ///
+    /// Returns:
+    ///     np.ndarray: The measured bits with shape (number of shots, number_qubits).
+    ///
+    /// Raises:
+    ///     ValueError: A measurement result could not be parsed.
+    #[staticmethod]
+    #[pyo3(text_signature = "(counts, number_qubits, /)")]
+    pub fn counts_to_array(
+        counts: HashMap<String, u64>,
+        number_qubits: usize,
+    ) -> PyResult<Py<PyArray2<bool>>> {
+        let bits = APIBackend::counts_to_array(&ResultCounts { counts }, number_qubits)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))?;
+        Python::with_gil(|py| Ok(bits.to_pyarray_bound(py).unbind()))
+    }
+
+    /// Query the capabilities of the used WebAPI version and record them in the backend.
+    ///
+    /// Once recorded, `post_job` rejects programs containing operations the WebAPI version
+    /// does not support and omits job fields it does not understand.
+    ///
+    /// Returns:
+    ///     dict: The API version, supported operations and supported job fields.
+    ///
+    /// Raises:
+    ///     RuntimeError: Could not retrieve the capabilities.
+    ///
+    #[pyo3(text_signature = "($self, /)")]
+    pub fn capabilities(&mut self) -> PyResult<PyObject> {
+        let capabilities = self.internal.capabilities().map_err(|err| {
+            PyRuntimeError::new_err(format!("Error retrieving capabilities: {}", err))
+        })?;
+        Python::with_gil(|py| -> PyResult<PyObject> {
+            let result = PyDict::new_bound(py);
+            result.set_item("api_version", capabilities.api_version)?;
+            result.set_item("supported_operations", capabilities.supported_operations)?;
+            result.set_item("supported_fields", capabilities.supported_fields)?;
             Ok(result.to_object(py))
         })
     }
@@ -312,6 +655,38 @@ impl APIBackendWrapper {
             .map_err(|err| PyRuntimeError::new_err(format!("Running Circuit failed {:?}", err)))
     }
 
+    /// Run a circuit once per simulator seed with the QRyd APIBackend.
+    ///
+    /// All jobs are posted before the results are polled, so the runs can be
+    /// executed concurrently on the WebAPI.
+    ///
+    /// Args:
+    ///     circuit (Circuit): The circuit that is run on the APIBackend.
+    ///     seeds (List[int]): The simulator seeds, the circuit is executed once for each seed.
+    ///
+    /// Returns:
+    ///     Dict[int, Tuple[Dict[str, List[List[bool]]], Dict[str, List[List[float]]], Dict[str, List[List[complex]]]]]: The output registers of the run with each seed.
+    ///
+    /// Raises:
+    ///     TypeError: Circuit argument cannot be converted to qoqo Circuit
+    ///     RuntimeError: The seeds are empty or contain duplicates, or running the Circuit failed
+    #[pyo3(text_signature = "($self, circuit, seeds, /)")]
+    pub fn run_circuit_with_seeds(
+        &self,
+        circuit: &Bound<PyAny>,
+        seeds: Vec<usize>,
+    ) -> PyResult<HashMap<usize, Registers>> {
+        let circuit = convert_into_circuit(circuit).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "Circuit argument cannot be converted to qoqo Circuit {:?}",
+                err
+            ))
+        })?;
+        self.internal
+            .run_circuit_with_seeds(&circuit, &seeds)
+            .map_err(|err| PyRuntimeError::new_err(format!("Running Circuit failed {:?}", err)))
+    }
+
     /// Run all circuits corresponding to one measurement with the QRyd APIBackend.
     ///
     /// An expectation value measurement in general involves several circuits.
@@ -471,6 +846,262 @@ impl APIBackendWrapper {
     pub fn set_dev(&mut self, dev: bool) {
         self.internal.set_dev(dev);
     }
+
+    /// Adds an operation to the operations accepted by `post_job`.
+    ///
+    /// Allows exercising new capabilities of the WebAPI before they are supported by a release
+    /// of qoqo-qryd. Operations not accepted by default can only be added with the dev option set
+    /// and are only accepted as long as it is set.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of the operation.
+    ///
+    /// Raises:
+    ///     ValueError: The operation is not accepted by default and the dev option is not set.
+    #[pyo3(text_signature = "($self, hqslang, /)")]
+    pub fn allow_operation(&mut self, hqslang: &str) -> PyResult<()> {
+        self.internal
+            .allow_operation(hqslang)
+            .map_err(|err| PyValueError::new_err(format!("{}", err)))
+    }
+
+    /// Removes an operation from the operations accepted by `post_job`.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of the operation.
+    ///
+    #[pyo3(text_signature = "($self, hqslang, /)")]
+    pub fn disallow_operation(&mut self, hqslang: &str) {
+        self.internal.disallow_operation(hqslang);
+    }
+
+    /// Resets the operations accepted by `post_job` to the default operations.
+    pub fn reset_supported_operations(&mut self) {
+        self.internal.reset_supported_operations();
+    }
+
+    /// Returns the names of the operations currently accepted by `post_job`.
+    ///
+    /// Returns:
+    ///     List[str]: The sorted hqslang names of the accepted operations.
+    pub fn supported_operations(&self) -> Vec<String> {
+        self.internal.supported_operations()
+    }
+
+    /// Setter for the gzip compression of posted job payloads.
+    ///
+    /// When enabled, the serialized job is sent gzip compressed with a
+    /// `Content-Encoding: gzip` header, reducing the upload size of large circuits.
+    ///
+    /// Args:
+    ///     compress_payload (bool): Whether to compress the body of posted jobs.
+    ///
+    #[pyo3(text_signature = "($self, compress_payload, /)")]
+    pub fn set_compress_payload(&mut self, compress_payload: bool) {
+        self.internal.set_compress_payload(compress_payload);
+    }
+
+    /// Returns whether the body of posted jobs is gzip compressed.
+    ///
+    /// Returns:
+    ///     bool: Whether the body of posted jobs is compressed.
+    pub fn compress_payload(&self) -> bool {
+        self.internal.compress_payload()
+    }
+
+    /// Setter for the configuration of the HTTP client used for the requests to the WebAPI.
+    ///
+    /// The client is built on the first request and reused by all following requests of the
+    /// backend, keeping the connections to the WebAPI open between polls. Options that are
+    /// not set keep the defaults of the HTTP client.
+    ///
+    /// Args:
+    ///     pool_max_idle_per_host (Optional[int]): The maximal number of idle connections kept open.
+    ///     pool_idle_timeout (Optional[float]): The time in seconds after which idle connections are closed.
+    ///     tcp_keepalive (Optional[float]): The interval in seconds of the TCP keep-alive probes.
+    ///     request_timeout (Optional[float]): The timeout of a request in seconds.
+    ///     connect_timeout (Optional[float]): The timeout for establishing a connection in seconds.
+    ///
+    /// Raises:
+    ///     ValueError: A time is negative or not finite.
+    #[pyo3(
+        text_signature = "($self, pool_max_idle_per_host, pool_idle_timeout, tcp_keepalive, request_timeout, connect_timeout, /)"
+    )]
+    pub fn set_client_config(
+        &mut self,
+        pool_max_idle_per_host: Option<usize>,
+        pool_idle_timeout: Option<f64>,
+        tcp_keepalive: Option<f64>,
+        request_timeout: Option<f64>,
+        connect_timeout: Option<f64>,
+    ) -> PyResult<()> {
+        let duration = |name: &str, seconds: Option<f64>| -> PyResult<Option<Duration>> {
+            seconds
+                .map(|seconds| {
+                    Duration::try_from_secs_f64(seconds).map_err(|err| {
+                        PyValueError::new_err(format!("Invalid {} {}: {}", name, seconds, err))
+                    })
+                })
+                .transpose()
+        };
+        self.internal.set_client_config(APIClientConfig {
+            pool_max_idle_per_host,
+            pool_idle_timeout: duration("pool_idle_timeout", pool_idle_timeout)?,
+            tcp_keepalive: duration("tcp_keepalive", tcp_keepalive)?,
+            request_timeout: duration("request_timeout", request_timeout)?,
+            connect_timeout: duration("connect_timeout", connect_timeout)?,
+        });
+        Ok(())
+    }
+
+    /// Returns the configuration of the HTTP client used for the requests to the WebAPI.
+    ///
+    /// Returns:
+    ///     Dict[str, Optional[Union[int, float]]]: The pool size and the keep-alive and timeouts in seconds.
+    pub fn client_config(&self) -> PyResult<Py<PyDict>> {
+        let config = self.internal.client_config();
+        Python::with_gil(|py| -> PyResult<Py<PyDict>> {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("pool_max_idle_per_host", config.pool_max_idle_per_host)?;
+            dict.set_item(
+                "pool_idle_timeout",
+                config.pool_idle_timeout.map(|d| d.as_secs_f64()),
+            )?;
+            dict.set_item(
+                "tcp_keepalive",
+                config.tcp_keepalive.map(|d| d.as_secs_f64()),
+            )?;
+            dict.set_item(
+                "request_timeout",
+                config.request_timeout.map(|d| d.as_secs_f64()),
+            )?;
+            dict.set_item(
+                "connect_timeout",
+                config.connect_timeout.map(|d| d.as_secs_f64()),
+            )?;
+            Ok(dict.unbind())
+        })
+    }
+
+    /// Sets the backend to record or replay the requests to the WebAPI.
+    ///
+    /// In "record" mode, every request is sent to the WebAPI (or the mock server) as usual and
+    /// the request method and path are written to the fixture file together with the response.
+    /// In "replay" mode, the responses are served from the fixture file without accessing the
+    /// network, in the order they were recorded for each method and path.
+    ///
+    /// Args:
+    ///     mode (str): Either "record" or "replay".
+    ///     file (str): The JSON file the fixtures are written to or read from.
+    ///
+    /// Raises:
+    ///     ValueError: Unknown mode or the fixture file could not be read in replay mode.
+    #[pyo3(text_signature = "($self, mode, file, /)")]
+    pub fn set_fixture_mode(&mut self, mode: &str, file: &str) -> PyResult<()> {
+        let mode = match mode {
+            "record" => APIFixtureMode::Record,
+            "replay" => APIFixtureMode::Replay,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown fixture mode {}, expected \"record\" or \"replay\".",
+                    mode
+                )))
+            }
+        };
+        self.internal
+            .set_fixture_mode(mode, file)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns the fixture mode of the backend.
+    ///
+    /// Returns:
+    ///     Optional[str]: "record", "replay" or None if the requests are neither recorded nor replayed.
+    pub fn fixture_mode(&self) -> Option<String> {
+        self.internal.fixture_mode().map(|mode| match mode {
+            APIFixtureMode::Record => "record".to_string(),
+            APIFixtureMode::Replay => "replay".to_string(),
+        })
+    }
+
+    /// Stops recording or replaying the requests to the WebAPI.
+    pub fn clear_fixture_mode(&mut self) {
+        self.internal.clear_fixture_mode()
+    }
+
+    /// Sets a callback that is called with the job progress on each poll of `run_circuit`,
+    /// `run_measurement` and `run_measurement_registers`, e.g. to update a progress bar.
+    ///
+    /// The callback receives the dictionary returned by `get_job_status`, extended by the
+    /// `job_location`, the `elapsed` time in seconds since posting the job, the `attempt`
+    /// number of the poll and the `max_attempts` before the run times out.
+    /// Passing None removes the callback.
+    ///
+    /// Args:
+    ///     callback (Optional[Callable[[Dict[str, Union[str, int, float]]], None]]): The function called with the job progress.
+    ///
+    /// Raises:
+    ///     TypeError: The callback is not callable.
+    #[pyo3(text_signature = "($self, callback, /)")]
+    pub fn set_progress_callback(&mut self, callback: Option<PyObject>) -> PyResult<()> {
+        match callback {
+            None => self.internal.clear_progress_callback(),
+            Some(callback) => {
+                Python::with_gil(|py| {
+                    if callback.bind(py).is_callable() {
+                        Ok(())
+                    } else {
+                        Err(PyTypeError::new_err(
+                            "The progress callback is not callable.",
+                        ))
+                    }
+                })?;
+                self.internal.set_progress_callback(move |progress| {
+                    Python::with_gil(|py| {
+                        let result = job_progress_to_dict(py, &progress)
+                            .and_then(|dict| callback.call1(py, (dict,)));
+                        if let Err(err) = result {
+                            err.print(py);
+                        }
+                    })
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Converts a job status into a Python dictionary, omitting fields not provided by the WebAPI.
+fn job_status_to_dict<'py>(
+    py: Python<'py>,
+    status: &QRydJobStatus,
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new_bound(py);
+    dict.set_item("status", status.status.clone())?;
+    dict.set_item("msg", status.msg.clone())?;
+    if let Some(queue_position) = status.queue_position {
+        dict.set_item("queue_position", queue_position)?;
+    }
+    if let Some(estimated_start_time) = status.estimated_start_time.as_ref() {
+        dict.set_item("estimated_start_time", estimated_start_time)?;
+    }
+    if let Some(progress) = status.progress {
+        dict.set_item("progress", progress)?;
+    }
+    Ok(dict)
+}
+
+/// Converts a job progress into a Python dictionary.
+fn job_progress_to_dict<'py>(
+    py: Python<'py>,
+    progress: &JobProgress,
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = job_status_to_dict(py, &progress.status)?;
+    dict.set_item("job_location", progress.job_location.clone())?;
+    dict.set_item("elapsed", progress.elapsed.as_secs_f64())?;
+    dict.set_item("attempt", progress.attempt)?;
+    dict.set_item("max_attempts", progress.max_attempts)?;
+    Ok(dict)
 }
 
 /// Convert generic python object to [roqoqo_qryd::APIBackend].
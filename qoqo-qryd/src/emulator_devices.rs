@@ -18,7 +18,11 @@ use pyo3::types::{IntoPyDict, PyByteArray};
 use qoqo::{devices::GenericDeviceWrapper, QoqoBackendError};
 use qoqo_calculator_pyo3::convert_into_calculator_float;
 use roqoqo::devices::Device;
-use roqoqo_qryd::{EmulatorDevice, TweezerDevice};
+use roqoqo_qryd::{
+    DrawOptions, EmulatorDevice, EmulatorLayoutSpec, EmulatorNoiseParameters, TweezerDevice,
+};
+
+use crate::tweezer_devices::{display_drawing, TweezerMutableDeviceWrapper};
 
 /// Emulator Device
 ///
@@ -123,6 +127,52 @@ impl EmulatorDeviceWrapper {
         Ok(EmulatorDeviceWrapper { internal })
     }
 
+    /// Converts the EmulatorDevice into a TweezerMutableDevice with the given geometry.
+    ///
+    /// The device contains the Layout "default" of the lattice, which is set as the current and
+    /// the default Layout. The available gates become the native gates of the device and keep the
+    /// gate time of the emulator: single-qubit gates on every tweezer, two-qubit gates between
+    /// neighbouring tweezers and three-qubit gates on tweezer trios that are pairwise neighbours.
+    /// The qubit -> tweezer mapping, the qubit limit, the noise parameters and the phase
+    /// relations are kept.
+    ///
+    /// Args:
+    ///     geometry (str): The geometry of the lattice, either "square" or "triangular".
+    ///     rows (int): The number of rows of the lattice.
+    ///     columns (int): The number of tweezers per row.
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: The device with the lattice Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The geometry is unknown, the lattice is empty, no single-qubit gate is
+    ///         available or a qubit is mapped to a tweezer outside of the lattice.
+    #[pyo3(text_signature = "(geometry, rows, columns, /)")]
+    pub fn to_tweezer_device(
+        &self,
+        geometry: &str,
+        rows: usize,
+        columns: usize,
+    ) -> PyResult<TweezerMutableDeviceWrapper> {
+        let layout_spec = match geometry {
+            "square" => EmulatorLayoutSpec::Square { rows, columns },
+            "triangular" => EmulatorLayoutSpec::Triangular { rows, columns },
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown geometry {}, use one of square or triangular.",
+                    geometry
+                )))
+            }
+        };
+        Ok(TweezerMutableDeviceWrapper {
+            internal: self
+                .internal
+                .clone()
+                .into_tweezer_device(layout_spec)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
     /// Returns a list of all available Layout names.
     ///
     /// Implemented for compatibility reasons, as the returning vector
@@ -161,7 +211,7 @@ impl EmulatorDeviceWrapper {
         })
     }
 
-    /// Modifies the qubit -> tweezer mapping of the device.
+    /// Adds a gate to the available list.
     ///
     /// Args:
     ///     hqslang (str): The hqslang name of the gate.
@@ -174,6 +224,33 @@ impl EmulatorDeviceWrapper {
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
+    /// Removes a gate from the available list.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of the gate.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not available in the device.
+    pub fn remove_available_gate(&mut self, hqslang: &str) -> PyResult<()> {
+        self.internal
+            .remove_available_gate(hqslang)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Replaces the available list by the given gates.
+    ///
+    /// Args:
+    ///     hqslang_names (list[str]): The hqslang names of the gates.
+    ///
+    /// Raises:
+    ///     ValueError: One of the gates does not exist.
+    pub fn set_available_gates(&mut self, hqslang_names: Vec<String>) -> PyResult<()> {
+        let names: Vec<&str> = hqslang_names.iter().map(|name| name.as_str()).collect();
+        self.internal
+            .set_available_gates(&names)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
     /// Get the qubit -> tweezer mapping of the device.
     ///
     /// Returns:
@@ -202,6 +279,38 @@ impl EmulatorDeviceWrapper {
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
+    /// Get the names of the available single-qubit gates in the device.
+    ///
+    /// Returns:
+    ///     list[str]: List of the names of the available single-qubit gates.
+    pub fn get_available_single_qubit_gates(&self) -> Vec<&str> {
+        self.internal.get_available_single_qubit_gates()
+    }
+
+    /// Get the names of the available two-qubit gates in the device.
+    ///
+    /// Returns:
+    ///     list[str]: List of the names of the available two-qubit gates.
+    pub fn get_available_two_qubit_gates(&self) -> Vec<&str> {
+        self.internal.get_available_two_qubit_gates()
+    }
+
+    /// Get the names of the available three-qubit gates in the device.
+    ///
+    /// Returns:
+    ///     list[str]: List of the names of the available three-qubit gates.
+    pub fn get_available_three_qubit_gates(&self) -> Vec<&str> {
+        self.internal.get_available_three_qubit_gates()
+    }
+
+    /// Get the names of the available multi-qubit gates in the device.
+    ///
+    /// Returns:
+    ///     list[str]: List of the names of the available multi-qubit gates.
+    pub fn get_available_multi_qubit_gates(&self) -> Vec<&str> {
+        self.internal.get_available_multi_qubit_gates()
+    }
+
     /// Set whether the device allows PragmaActiveReset operations or not.
     ///
     /// Args:
@@ -224,6 +333,30 @@ impl EmulatorDeviceWrapper {
         self.internal.internal.allow_reset
     }
 
+    /// Restricts the two-qubit connectivity of the device to the given edges.
+    ///
+    /// The edges are undirected. Passing None restores the all-to-all connectivity.
+    ///
+    /// Args:
+    ///     edges (Optional[list[tuple[int, int]]]): The allowed edges between qubits.
+    ///
+    /// Raises:
+    ///     ValueError: An edge connects a qubit to itself.
+    #[pyo3(text_signature = "(edges, /)")]
+    pub fn set_allowed_edges(&mut self, edges: Option<Vec<(usize, usize)>>) -> PyResult<()> {
+        self.internal
+            .set_allowed_edges(edges)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Get the allowed two-qubit edges of the device.
+    ///
+    /// Returns:
+    ///     Optional[list[tuple[int, int]]]: The allowed edges, None for all-to-all connectivity.
+    pub fn allowed_edges(&self) -> Option<Vec<(usize, usize)>> {
+        self.internal.allowed_edges().map(|edges| edges.to_vec())
+    }
+
     /// Deactivate the given qubit in the device.
     ///
     /// Args:
@@ -501,7 +634,7 @@ impl EmulatorDeviceWrapper {
     fn from_json(input: &str) -> PyResult<EmulatorDeviceWrapper> {
         let tw: TweezerDevice = serde_json::from_str(input)
             .map_err(|_| PyValueError::new_err("Input cannot be deserialized to EmulatorDevice"))?;
-        if tw.available_gates.is_none() || tw.layout_register.is_some() {
+        if tw.available_gates.is_none() || !matches!(tw.layouts(), Ok(None)) {
             return Err(PyValueError::new_err(
                 "Trying to deserialize an incorrectly setup device into EmulatorDevice",
             ));
@@ -510,6 +643,127 @@ impl EmulatorDeviceWrapper {
         Ok(EmulatorDeviceWrapper { internal })
     }
 
+    /// Set the global noise parameters of the device.
+    ///
+    /// If no parameter is given, the device is an ideal emulator.
+    ///
+    /// Args:
+    ///     damping (Optional[float]): The damping rate of the qubits.
+    ///     dephasing (Optional[float]): The dephasing rate of the qubits.
+    ///     spam_error (Optional[float]): The probability of a state preparation and measurement error.
+    ///
+    /// Raises:
+    ///     ValueError: A rate is negative or not finite, or the SPAM error is not a probability.
+    #[pyo3(text_signature = "(damping, dephasing, spam_error, /)")]
+    pub fn set_noise_parameters(
+        &mut self,
+        damping: Option<f64>,
+        dephasing: Option<f64>,
+        spam_error: Option<f64>,
+    ) -> PyResult<()> {
+        let noise = (damping.is_some() || dephasing.is_some() || spam_error.is_some()).then_some(
+            EmulatorNoiseParameters {
+                damping,
+                dephasing,
+                spam_error,
+            },
+        );
+        self.internal
+            .set_noise_parameters(noise)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns the damping rate of the qubits.
+    ///
+    /// Returns:
+    ///     Optional[float]: The damping rate, None if damping is not modeled.
+    pub fn damping(&self) -> Option<f64> {
+        self.internal.damping()
+    }
+
+    /// Returns the dephasing rate of the qubits.
+    ///
+    /// Returns:
+    ///     Optional[float]: The dephasing rate, None if dephasing is not modeled.
+    pub fn dephasing(&self) -> Option<f64> {
+        self.internal.dephasing()
+    }
+
+    /// Returns the probability of a state preparation and measurement error.
+    ///
+    /// Returns:
+    ///     Optional[float]: The SPAM error, None if SPAM errors are not modeled.
+    pub fn spam_error(&self) -> Option<f64> {
+        self.internal.spam_error()
+    }
+
+    /// Returns whether the device is a noisy emulator.
+    ///
+    /// Returns:
+    ///     bool: Whether any noise parameter is set.
+    pub fn is_noisy(&self) -> bool {
+        self.internal.is_noisy()
+    }
+
+    /// Limits the number of qubits of the device.
+    ///
+    /// The limit is reported by `number_qubits()` and enforced by the backends.
+    ///
+    /// Args:
+    ///     number_qubits (Optional[int]): The maximal number of qubits, None to remove the limit.
+    ///
+    /// Raises:
+    ///     ValueError: A mapped qubit exceeds the new limit.
+    #[pyo3(text_signature = "(number_qubits, /)")]
+    pub fn set_number_qubits(&mut self, number_qubits: Option<usize>) -> PyResult<()> {
+        self.internal
+            .set_number_qubits(number_qubits)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Creates a graph representing the EmulatorDevice.
+    ///
+    /// The qubits are drawn as nodes on a circle, the edges connect the qubits a two-qubit gate
+    /// is available between.
+    ///
+    /// Args:
+    ///     pixel_per_point (Optional[float]): The quality of the image.
+    ///     file_save_path (Optional[str]): Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics. Default: output the image with the display method.
+    ///     show_qubit_labels (Optional[bool]): Whether to label the nodes with their qubit. Default: true
+    ///     show_legend (Optional[bool]): Whether to add a legend explaining the labels and edges. Default: false
+    ///     dpi (Optional[float]): The resolution of the image in dots per inch, overrides pixel_per_point.
+    ///
+    /// Raises:
+    ///     PyValueError - if the device has no qubits, an error occurred during the compilation or and invalid path was provided.
+    #[pyo3(
+        text_signature = "(pixel_per_point, file_save_path, show_qubit_labels, show_legend, dpi, /)"
+    )]
+    pub fn draw(
+        &self,
+        pixel_per_point: Option<f32>,
+        file_save_path: Option<String>,
+        show_qubit_labels: Option<bool>,
+        show_legend: Option<bool>,
+        dpi: Option<f32>,
+    ) -> PyResult<()> {
+        let options = DrawOptions {
+            show_tweezer_labels: false,
+            show_qubit_labels: show_qubit_labels.unwrap_or(true),
+            show_legend: show_legend.unwrap_or(false),
+            dpi,
+        };
+        let display_image = file_save_path.is_none();
+        let image = self
+            .internal
+            .draw_with_options(pixel_per_point, &file_save_path, &options)
+            .map_err(|x| PyValueError::new_err(format!("Error during Circuit drawing: {x:?}")))?;
+
+        if display_image {
+            display_drawing(&image)?;
+        }
+        Ok(())
+    }
+
     /// Return number of qubits in device.
     ///
     /// Returns:
@@ -0,0 +1,204 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GHZ parity oscillation experiment.
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use qoqo::CircuitWrapper;
+use roqoqo::registers::BitOutputRegister;
+use roqoqo_qryd::ghz_parity::{GHZFidelityResult, GHZParityExperiment, GHZ_READOUT};
+use std::collections::HashMap;
+
+/// Experiment builder for GHZ state parity oscillations.
+///
+/// The GHZ state is prepared along the given line of qubits, consecutive qubits are entangled
+/// with a `ControlledPauliZ` gate and therefore need to be connected on the device.
+/// The parity is measured after a `RotateXY(pi/2, phi)` rotation on every qubit for
+/// `number_phases` equidistant phases `phi` in `[0, 2 pi)`. The GHZ fidelity is estimated as
+/// `F = (P_0...0 + P_1...1) / 2 + C / 2`, where `C` is the parity oscillation amplitude.
+///
+/// Args:
+///     qubits (List[int]): The line of qubits the GHZ state is prepared on.
+///     number_phases (int): The number of analysis phases, needs to be larger than twice the number of qubits.
+///     number_measurements (int): The number of measurements performed for each circuit.
+///
+/// Raises:
+///     ValueError: Less than two qubits, duplicated qubits, too few phases or no measurements were given.
+#[pyclass(name = "GHZParityExperiment", module = "qoqo_qryd")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GHZParityExperimentWrapper {
+    /// Internal storage of [roqoqo_qryd::ghz_parity::GHZParityExperiment]
+    pub internal: GHZParityExperiment,
+}
+
+#[pymethods]
+impl GHZParityExperimentWrapper {
+    /// Creates a new GHZParityExperiment.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The line of qubits the GHZ state is prepared on.
+    ///     number_phases (int): The number of analysis phases, needs to be larger than twice the number of qubits.
+    ///     number_measurements (int): The number of measurements performed for each circuit.
+    ///
+    /// Returns:
+    ///     GHZParityExperiment: The new GHZParityExperiment.
+    ///
+    /// Raises:
+    ///     ValueError: Less than two qubits, duplicated qubits, too few phases or no measurements were given.
+    #[new]
+    #[pyo3(text_signature = "(qubits, number_phases, number_measurements, /)")]
+    pub fn new(
+        qubits: Vec<usize>,
+        number_phases: usize,
+        number_measurements: usize,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            internal: GHZParityExperiment::new(qubits, number_phases, number_measurements)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
+    /// Returns the line of qubits the GHZ state is prepared on.
+    ///
+    /// Returns:
+    ///     List[int]: The qubits.
+    pub fn qubits(&self) -> Vec<usize> {
+        self.internal.qubits().to_vec()
+    }
+
+    /// Returns the number of analysis phases.
+    ///
+    /// Returns:
+    ///     int: The number of analysis phases.
+    pub fn number_phases(&self) -> usize {
+        self.internal.number_phases()
+    }
+
+    /// Returns the number of measurements performed for each circuit.
+    ///
+    /// Returns:
+    ///     int: The number of measurements.
+    pub fn number_measurements(&self) -> usize {
+        self.internal.number_measurements()
+    }
+
+    /// Returns the analysis phases of the parity circuits.
+    ///
+    /// Returns:
+    ///     List[float]: The analysis phases.
+    pub fn phases(&self) -> Vec<f64> {
+        self.internal.phases()
+    }
+
+    /// Returns the circuit preparing the GHZ state on the line of qubits.
+    ///
+    /// Returns:
+    ///     Circuit: The preparation circuit.
+    pub fn preparation_circuit(&self) -> CircuitWrapper {
+        CircuitWrapper {
+            internal: self.internal.preparation_circuit(),
+        }
+    }
+
+    /// Returns the measurement circuits of the experiment.
+    ///
+    /// The first circuit measures the populations in the computational basis, followed by one
+    /// parity circuit per analysis phase. The i-th qubit of the line is measured into the
+    /// i-th bit of the `ro_ghz` register.
+    ///
+    /// Returns:
+    ///     List[Circuit]: The measurement circuits.
+    pub fn circuits(&self) -> Vec<CircuitWrapper> {
+        self.internal
+            .circuits()
+            .into_iter()
+            .map(|internal| CircuitWrapper { internal })
+            .collect()
+    }
+
+    /// Analyses the measured bit registers.
+    ///
+    /// Args:
+    ///     registers (List[List[List[bool]]]): The measured bit registers, one per circuit.
+    ///
+    /// Returns:
+    ///     Dict[str, Any]: The populations, parity_amplitude, parity_phase_offset, fidelity and parities.
+    ///
+    /// Raises:
+    ///     ValueError: The registers do not match the circuits of the experiment.
+    #[pyo3(text_signature = "($self, registers, /)")]
+    pub fn analyse(&self, registers: Vec<BitOutputRegister>) -> PyResult<Py<PyDict>> {
+        let result = self
+            .internal
+            .analyse(&registers)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))?;
+        result_to_dict(result)
+    }
+
+    /// Runs the experiment on a backend and analyses the results.
+    ///
+    /// Args:
+    ///     backend (Union[SimulatorBackend, APIBackend]): The backend the circuits are run on.
+    ///
+    /// Returns:
+    ///     Dict[str, Any]: The populations, parity_amplitude, parity_phase_offset, fidelity and parities.
+    ///
+    /// Raises:
+    ///     RuntimeError: Running a circuit failed.
+    ///     ValueError: The analysis failed.
+    #[pyo3(text_signature = "($self, backend, /)")]
+    pub fn run(&self, backend: &Bound<PyAny>) -> PyResult<Py<PyDict>> {
+        let mut registers: Vec<BitOutputRegister> = Vec::new();
+        for circuit in self.circuits() {
+            let result = backend.call_method1("run_circuit", (circuit,))?;
+            let bit_registers: HashMap<String, BitOutputRegister> =
+                result.get_item(0)?.extract().map_err(|_| {
+                    PyRuntimeError::new_err("Backend did not return bit registers.")
+                })?;
+            let register = bit_registers.get(GHZ_READOUT).ok_or_else(|| {
+                PyRuntimeError::new_err(format!(
+                    "Backend did not return the {} register.",
+                    GHZ_READOUT
+                ))
+            })?;
+            registers.push(register.clone());
+        }
+        self.analyse(registers)
+    }
+}
+
+fn result_to_dict(result: GHZFidelityResult) -> PyResult<Py<PyDict>> {
+    Python::with_gil(|py| {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("populations", result.populations)?;
+        dict.set_item("parity_amplitude", result.parity_amplitude)?;
+        dict.set_item("parity_phase_offset", result.parity_phase_offset)?;
+        dict.set_item("fidelity", result.fidelity)?;
+        dict.set_item("parities", result.parities)?;
+        Ok(dict.unbind())
+    })
+}
+
+/// GHZ parity oscillation experiment for QRyd devices.
+///
+/// .. autosummary::
+///    :toctree: generated/
+///
+///    GHZParityExperiment
+///
+#[pymodule]
+pub fn ghz_parity(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_class::<GHZParityExperimentWrapper>()?;
+    Ok(())
+}
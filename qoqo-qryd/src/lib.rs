@@ -25,11 +25,16 @@
 //! * operations: roqoqo Pragma operations specific to QRyd devices that can change the topology of QRyd devices
 //! * simulator (optional): A QuEST based simulator for QRyd devices that checks the availability of the quantum operations on a chosen device during simulation
 
-#[cfg(feature = "web-api")]
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use pyo3::wrap_pymodule;
+use qoqo::{
+    convert_into_circuit, convert_into_quantum_program, CircuitWrapper, QuantumProgramWrapper,
+};
+use qoqo_calculator::Calculator;
+use std::collections::HashMap;
+use std::io::Cursor;
 
 /// qoqo utilities for QRyd quantum computers.
 ///
@@ -79,6 +84,26 @@ pub mod api_backend;
 #[cfg(feature = "web-api")]
 pub use api_backend::APIBackendWrapper;
 
+/// State tomography experiments.
+///
+pub mod tomography;
+pub use tomography::*;
+
+/// GHZ parity oscillation experiment.
+///
+pub mod ghz_parity;
+pub use ghz_parity::*;
+
+/// Cross-entropy benchmarking.
+///
+pub mod xeb;
+pub use xeb::*;
+
+/// Fitting of simulator noise parameters from experimental results.
+///
+pub mod noise_fitting;
+pub use noise_fitting::*;
+
 /// Collection of all QRyd devices for WebAPI.
 ///
 /// At the moment only contains a square and a triangular device.
@@ -133,6 +158,255 @@ pub fn device_from_api(
     })
 }
 
+/// Returns the histogram data of measured bitstring counts, sorted by bitstring.
+///
+/// The returned data can be used directly for plotting, e.g. with matplotlib.
+///
+/// Args:
+///     counts (Dict[str, int]): The number of times each bitstring was measured.
+///
+/// Returns:
+///     List[Tuple[str, int]]: The sorted (bitstring, count) pairs.
+#[pyfunction]
+pub fn counts_histogram(counts: HashMap<String, u64>) -> Vec<(String, u64)> {
+    roqoqo_qryd::counts_histogram(&counts)
+}
+
+/// Creates a bar chart of measured bitstring counts.
+///
+/// Args:
+///     counts (Dict[str, int]): The number of times each bitstring was measured.
+///     pixel_per_point (Optional[float]): The quality of the image.
+///     file_save_path (Optional[str]): Path to save the image to. Default: output the image with the display method.
+///
+/// Raises:
+///     PyValueError - if the counts are empty, an error occurred during the compilation or and invalid path was provided.
+///
+#[pyfunction]
+pub fn plot_counts(
+    counts: HashMap<String, u64>,
+    pixel_per_point: Option<f32>,
+    file_save_path: Option<String>,
+) -> PyResult<()> {
+    let display_image = file_save_path.is_none();
+    let image = roqoqo_qryd::plot_counts(&counts, pixel_per_point, &file_save_path)
+        .map_err(|x| PyValueError::new_err(format!("Error during counts plotting: {x:?}")))?;
+
+    if display_image {
+        let mut buffer = Cursor::new(Vec::new());
+        image
+            .write_to(&mut buffer, image::ImageFormat::Png)
+            .map_err(|x| {
+                PyValueError::new_err(format!(
+                    "Error during the generation of the Png file: {x:?}"
+                ))
+            })?;
+        Python::with_gil(|py| -> PyResult<()> {
+            let pil = PyModule::import_bound(py, "PIL.Image")?;
+            let io = PyModule::import_bound(py, "io")?;
+            let display = PyModule::import_bound(py, "IPython.display")?;
+            let builtins = PyModule::import_bound(py, "builtins")?;
+
+            let bytes_image_data = builtins.call_method1("bytes", (buffer.into_inner(),))?;
+            let bytes_io = io.call_method1("BytesIO", (bytes_image_data,))?;
+            let image = pil.call_method1("open", (bytes_io,))?;
+
+            display.call_method1("display", (image,))?;
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/// Strips the simulation-only pragmas from a circuit so it can be submitted to the QRyd WebAPI.
+///
+/// Readout, state initialisation, noise and compiler hint pragmas are removed,
+/// PragmaLoop operations with a numeric number of repetitions are unrolled.
+///
+/// Args:
+///     circuit (Circuit): The circuit to sanitize.
+///
+/// Returns:
+///     Tuple[Circuit, Dict[str, Union[List[str], int]]]: The sanitized circuit and a report with the
+///         hqslang names of the removed operations ("removed") and the number of unrolled loops ("unrolled_loops").
+///
+/// Raises:
+///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+#[pyfunction]
+pub fn sanitize_for_api(circuit: &Bound<PyAny>) -> PyResult<(CircuitWrapper, Py<PyDict>)> {
+    let circuit = convert_into_circuit(circuit).map_err(|err| {
+        PyTypeError::new_err(format!(
+            "Circuit argument cannot be converted to qoqo Circuit {:?}",
+            err
+        ))
+    })?;
+    let (sanitized, report) = roqoqo_qryd::sanitize_for_api(&circuit);
+    Python::with_gil(|py| {
+        let report_dict = PyDict::new_bound(py);
+        report_dict.set_item("removed", report.removed)?;
+        report_dict.set_item("unrolled_loops", report.unrolled_loops)?;
+        Ok((
+            CircuitWrapper {
+                internal: sanitized,
+            },
+            report_dict.unbind(),
+        ))
+    })
+}
+
+/// Substitutes the symbolic parameters of a circuit, including the parameters of the QRyd pragmas.
+///
+/// The QRyd pragmas are added to circuits wrapped in PragmaChangeDevice operations, whose own
+/// parameter substitution leaves the wrapped pragma unchanged.
+///
+/// Args:
+///     circuit (Circuit): The circuit to substitute the parameters of.
+///     substitution_parameters (Dict[str, float]): The values of the symbolic parameters.
+///
+/// Returns:
+///     Circuit: The circuit with the substituted parameters.
+///
+/// Raises:
+///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+///     RuntimeError: A symbolic parameter could not be substituted.
+#[pyfunction]
+pub fn substitute_circuit_parameters(
+    circuit: &Bound<PyAny>,
+    substitution_parameters: HashMap<String, f64>,
+) -> PyResult<CircuitWrapper> {
+    let circuit = convert_into_circuit(circuit).map_err(|err| {
+        PyTypeError::new_err(format!(
+            "Circuit argument cannot be converted to qoqo Circuit {:?}",
+            err
+        ))
+    })?;
+    let mut calculator = Calculator::new();
+    for (name, value) in substitution_parameters.iter() {
+        calculator.set_variable(name, *value);
+    }
+    Ok(CircuitWrapper {
+        internal: roqoqo_qryd::substitute_circuit_parameters(&circuit, &calculator)
+            .map_err(|err| PyRuntimeError::new_err(format!("{:}", err)))?,
+    })
+}
+
+/// Substitutes the input parameters of a QuantumProgram, including the parameters of the QRyd pragmas.
+///
+/// The values are assigned to the input parameter names of the program, as in QuantumProgram.run.
+/// The returned program has no input parameters and can be run with an empty list of parameters.
+///
+/// Args:
+///     quantumprogram (QuantumProgram): The QuantumProgram to substitute the parameters of.
+///     parameters (List[float]): The values of the input parameters of the program.
+///
+/// Returns:
+///     QuantumProgram: The program with the substituted parameters.
+///
+/// Raises:
+///     TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+///     RuntimeError: The number of parameters does not match or a symbolic parameter could not be substituted.
+#[pyfunction]
+pub fn substitute_program_parameters(
+    quantumprogram: &Bound<PyAny>,
+    parameters: Vec<f64>,
+) -> PyResult<QuantumProgramWrapper> {
+    let program = convert_into_quantum_program(quantumprogram).map_err(|err| {
+        PyTypeError::new_err(format!(
+            "quantumprogram is not of type qoqo.QuantumProgram {}",
+            err
+        ))
+    })?;
+    Ok(QuantumProgramWrapper {
+        internal: roqoqo_qryd::substitute_program_parameters(program, &parameters)
+            .map_err(|err| PyRuntimeError::new_err(format!("{:}", err)))?,
+    })
+}
+
+/// Remaps the qubits of a circuit, including the qubits of the QRyd pragmas.
+///
+/// The QRyd pragmas are added to circuits wrapped in PragmaChangeDevice operations, whose own
+/// qubit remapping leaves the wrapped pragma unchanged. Pragmas referring to Layouts or tweezers
+/// are not changed by the remapping.
+///
+/// Args:
+///     circuit (Circuit): The circuit to remap the qubits of.
+///     mapping (Dict[int, int]): The mapping from the old to the new qubit indices.
+///
+/// Returns:
+///     Circuit: The circuit with the remapped qubits.
+///
+/// Raises:
+///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+///     RuntimeError: The qubits of an operation could not be remapped.
+#[pyfunction]
+pub fn remap_circuit_qubits(
+    circuit: &Bound<PyAny>,
+    mapping: HashMap<usize, usize>,
+) -> PyResult<CircuitWrapper> {
+    let circuit = convert_into_circuit(circuit).map_err(|err| {
+        PyTypeError::new_err(format!(
+            "Circuit argument cannot be converted to qoqo Circuit {:?}",
+            err
+        ))
+    })?;
+    Ok(CircuitWrapper {
+        internal: roqoqo_qryd::remap_circuit_qubits(&circuit, &mapping)
+            .map_err(|err| PyRuntimeError::new_err(format!("{:}", err)))?,
+    })
+}
+
+/// Checks every operation of a circuit against a TweezerDevice.
+///
+/// The device-changing pragmas are applied to a copy of the device, so every gate is checked
+/// against the Layout, qubit positions and deactivated qubits at its position in the circuit.
+/// A device-changing pragma the device rejects is reported and leaves the device unchanged.
+///
+/// Args:
+///     circuit (Circuit): The circuit to check.
+///     device (TweezerDevice): The device the circuit is run on.
+///
+/// Returns:
+///     List[Dict[str, Union[int, str, List[int], Optional[str]]]]: One report per incompatible operation
+///         with the index of the operation in the circuit ("index"), its hqslang name ("hqslang"), the qubits
+///         it acts on ("qubits"), the current Layout at its position ("layout") and the reason ("reason").
+///         The list is empty if the circuit is compatible with the device.
+///
+/// Raises:
+///     TypeError: Circuit argument cannot be converted to qoqo Circuit or device argument is not a TweezerDevice.
+#[pyfunction]
+pub fn check_circuit_compatibility(
+    circuit: &Bound<PyAny>,
+    device: &Bound<PyAny>,
+) -> PyResult<Vec<Py<PyDict>>> {
+    let circuit = convert_into_circuit(circuit).map_err(|err| {
+        PyTypeError::new_err(format!(
+            "Circuit argument cannot be converted to qoqo Circuit {:?}",
+            err
+        ))
+    })?;
+    let device = tweezer_devices::convert_into_device(device).map_err(|err| {
+        PyTypeError::new_err(format!("Device Parameter is not TweezerDevice {:?}", err))
+    })?;
+    let reports = match roqoqo_qryd::check_circuit_compatibility(&circuit, &device) {
+        Ok(()) => Vec::new(),
+        Err(reports) => reports,
+    };
+    Python::with_gil(|py| {
+        reports
+            .into_iter()
+            .map(|report| {
+                let report_dict = PyDict::new_bound(py);
+                report_dict.set_item("index", report.index)?;
+                report_dict.set_item("hqslang", report.hqslang)?;
+                report_dict.set_item("qubits", report.qubits)?;
+                report_dict.set_item("layout", report.layout)?;
+                report_dict.set_item("reason", report.reason)?;
+                Ok(report_dict.unbind())
+            })
+            .collect()
+    })
+}
+
 /// QRyd utilities for qoqo quantum computation toolkit.
 ///
 /// qoqo is the HQS python package to represent quantum circuits.
@@ -146,7 +420,18 @@ pub fn device_from_api(
 ///     qryd_devices
 ///     tweezer_devices
 ///     emulator_devices
+///     tomography
+///     ghz_parity
+///     xeb
+///     noise_fitting
 ///     device_from_api
+///     counts_histogram
+///     plot_counts
+///     sanitize_for_api
+///     substitute_circuit_parameters
+///     substitute_program_parameters
+///     remap_circuit_qubits
+///     check_circuit_compatibility
 ///
 ///
 #[pymodule]
@@ -157,6 +442,13 @@ fn qoqo_qryd(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_class::<APIBackendWrapper>()?;
     #[cfg(feature = "web-api")]
     module.add_function(wrap_pyfunction!(device_from_api, module)?)?;
+    module.add_function(wrap_pyfunction!(counts_histogram, module)?)?;
+    module.add_function(wrap_pyfunction!(plot_counts, module)?)?;
+    module.add_function(wrap_pyfunction!(sanitize_for_api, module)?)?;
+    module.add_function(wrap_pyfunction!(substitute_circuit_parameters, module)?)?;
+    module.add_function(wrap_pyfunction!(substitute_program_parameters, module)?)?;
+    module.add_function(wrap_pyfunction!(remap_circuit_qubits, module)?)?;
+    module.add_function(wrap_pyfunction!(check_circuit_compatibility, module)?)?;
     let wrapper = wrap_pymodule!(qryd_devices::qryd_devices);
     module.add_wrapped(wrapper)?;
     let wrapper = wrap_pymodule!(api_devices::api_devices);
@@ -168,6 +460,14 @@ fn qoqo_qryd(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     module.add_wrapped(wrapper)?;
     let wrapper = wrap_pymodule!(emulator_devices::emulator_devices);
     module.add_wrapped(wrapper)?;
+    let wrapper = wrap_pymodule!(tomography::tomography);
+    module.add_wrapped(wrapper)?;
+    let wrapper = wrap_pymodule!(ghz_parity::ghz_parity);
+    module.add_wrapped(wrapper)?;
+    let wrapper = wrap_pymodule!(xeb::xeb);
+    module.add_wrapped(wrapper)?;
+    let wrapper = wrap_pymodule!(noise_fitting::noise_fitting);
+    module.add_wrapped(wrapper)?;
     // Adding nice imports corresponding to maturin example
     let system = PyModule::import_bound(_py, "sys")?;
     let binding = system.getattr("modules")?;
@@ -186,5 +486,9 @@ fn qoqo_qryd(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
         "qoqo_qryd.emulator_devices",
         module.getattr("emulator_devices")?,
     )?;
+    system_modules.set_item("qoqo_qryd.tomography", module.getattr("tomography")?)?;
+    system_modules.set_item("qoqo_qryd.ghz_parity", module.getattr("ghz_parity")?)?;
+    system_modules.set_item("qoqo_qryd.xeb", module.getattr("xeb")?)?;
+    system_modules.set_item("qoqo_qryd.noise_fitting", module.getattr("noise_fitting")?)?;
     Ok(())
 }
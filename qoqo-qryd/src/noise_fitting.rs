@@ -0,0 +1,195 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fitting of device noise parameters from experimental results.
+
+use crate::emulator_devices::EmulatorDeviceWrapper;
+use crate::tweezer_devices::{TweezerDeviceWrapper, TweezerMutableDeviceWrapper};
+use crate::xeb::XEBExperimentWrapper;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use roqoqo::RoqoqoBackendError;
+use roqoqo_qryd::xeb::XEBResult;
+use roqoqo_qryd::TweezerDevice;
+
+/// Fits the decay time `T` of `signal(t) = A * exp(-t / T)`.
+///
+/// The fit is a log-linear least squares fit, points with a non-positive signal are ignored.
+///
+/// Args:
+///     times (List[float]): The times at which the signal was measured.
+///     signal (List[float]): The measured signal.
+///
+/// Returns:
+///     float: The fitted decay time.
+///
+/// Raises:
+///     ValueError: The data does not have matching lengths, contains fewer than two usable points or does not decay.
+#[pyfunction]
+pub fn fit_decay_time(times: Vec<f64>, signal: Vec<f64>) -> PyResult<f64> {
+    roqoqo_qryd::noise_fitting::fit_decay_time(&times, &signal)
+        .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+}
+
+/// Fits the relaxation time T1 of a qubit and writes it into the device.
+///
+/// Args:
+///     device (Union[TweezerDevice, TweezerMutableDevice, EmulatorDevice]): The device whose noise parameters are updated.
+///     qubit (int): The measured qubit.
+///     times (List[float]): The waiting times after the excitation of the qubit.
+///     excited_populations (List[float]): The measured population of the excited state.
+///
+/// Returns:
+///     float: The fitted relaxation time.
+///
+/// Raises:
+///     TypeError: The device is not a TweezerDevice, TweezerMutableDevice or EmulatorDevice.
+///     ValueError: The fit failed or the qubit is not mapped to a tweezer.
+#[pyfunction]
+pub fn fit_t1(
+    device: &Bound<PyAny>,
+    qubit: usize,
+    times: Vec<f64>,
+    excited_populations: Vec<f64>,
+) -> PyResult<f64> {
+    with_device_mut(device, |internal| {
+        roqoqo_qryd::noise_fitting::fit_t1(internal, qubit, &times, &excited_populations)
+    })
+}
+
+/// Fits the dephasing time T2 of a qubit and writes it into the device.
+///
+/// Args:
+///     device (Union[TweezerDevice, TweezerMutableDevice, EmulatorDevice]): The device whose noise parameters are updated.
+///     qubit (int): The measured qubit.
+///     times (List[float]): The waiting times of the Ramsey or echo sequence.
+///     coherences (List[float]): The measured contrast of the coherence, e.g. the fringe amplitude.
+///
+/// Returns:
+///     float: The fitted dephasing time.
+///
+/// Raises:
+///     TypeError: The device is not a TweezerDevice, TweezerMutableDevice or EmulatorDevice.
+///     ValueError: The fit failed or the qubit is not mapped to a tweezer.
+#[pyfunction]
+pub fn fit_t2(
+    device: &Bound<PyAny>,
+    qubit: usize,
+    times: Vec<f64>,
+    coherences: Vec<f64>,
+) -> PyResult<f64> {
+    with_device_mut(device, |internal| {
+        roqoqo_qryd::noise_fitting::fit_t2(internal, qubit, &times, &coherences)
+    })
+}
+
+/// Fits the depolarising rate of the benchmarked qubits from an XEB result and writes it into the device.
+///
+/// The fidelity per cycle is modelled as `f = exp(-r T)`, where `T` is the gate time per cycle
+/// summed over all qubits the gates act on. The same rate is assigned to the tweezers of all
+/// benchmarked qubits.
+///
+/// Args:
+///     device (Union[TweezerDevice, TweezerMutableDevice, EmulatorDevice]): The device the experiment was run on, whose noise parameters are updated.
+///     experiment (XEBExperiment): The benchmarking experiment.
+///     result (Dict[str, Any]): The result returned by `XEBExperiment.run` or `XEBExperiment.analyse`.
+///
+/// Returns:
+///     float: The fitted depolarising rate.
+///
+/// Raises:
+///     TypeError: The device is not a TweezerDevice, TweezerMutableDevice or EmulatorDevice.
+///     ValueError: No valid fidelity per cycle was fitted, a gate time is missing in the device or a qubit is not mapped to a tweezer.
+#[pyfunction]
+pub fn fit_depolarising_rate(
+    device: &Bound<PyAny>,
+    experiment: &XEBExperimentWrapper,
+    result: &Bound<PyDict>,
+) -> PyResult<f64> {
+    let fidelity_per_cycle: Option<f64> = match result.get_item("fidelity_per_cycle")? {
+        Some(value) => value.extract()?,
+        None => None,
+    };
+    let result = XEBResult {
+        depths: result
+            .get_item("depths")?
+            .map(|value| value.extract())
+            .transpose()?
+            .unwrap_or_default(),
+        fidelities: result
+            .get_item("fidelities")?
+            .map(|value| value.extract())
+            .transpose()?
+            .unwrap_or_default(),
+        fidelity_per_cycle,
+    };
+    let fit_error = |err: RoqoqoBackendError| PyValueError::new_err(format!("{:}", err));
+    let qubits = experiment.internal.qubits();
+    if let Ok(emulator) = device.downcast::<EmulatorDeviceWrapper>() {
+        let mut emulator = emulator.borrow_mut();
+        let rate = roqoqo_qryd::noise_fitting::fit_depolarising_rate(
+            &emulator.internal,
+            &experiment.internal,
+            &result,
+        )
+        .map_err(fit_error)?;
+        roqoqo_qryd::noise_fitting::apply_depolarising_rate(
+            &mut emulator.internal.internal,
+            qubits,
+            rate,
+        )
+        .map_err(fit_error)?;
+        return Ok(rate);
+    }
+    with_device_mut(device, |internal| {
+        roqoqo_qryd::noise_fitting::fit_xeb_noise(internal, &experiment.internal, &result)
+    })
+}
+
+/// Applies a fitting function to the internal TweezerDevice of a python device.
+fn with_device_mut<F>(device: &Bound<PyAny>, fit: F) -> PyResult<f64>
+where
+    F: FnOnce(&mut TweezerDevice) -> Result<f64, RoqoqoBackendError>,
+{
+    let fitted = if let Ok(wrapper) = device.downcast::<TweezerMutableDeviceWrapper>() {
+        fit(&mut wrapper.borrow_mut().internal)
+    } else if let Ok(wrapper) = device.downcast::<TweezerDeviceWrapper>() {
+        fit(&mut wrapper.borrow_mut().internal)
+    } else if let Ok(wrapper) = device.downcast::<EmulatorDeviceWrapper>() {
+        fit(&mut wrapper.borrow_mut().internal.internal)
+    } else {
+        return Err(PyTypeError::new_err(
+            "Device is not a TweezerDevice, TweezerMutableDevice or EmulatorDevice.",
+        ));
+    };
+    fitted.map_err(|err| PyValueError::new_err(format!("{:}", err)))
+}
+
+/// Fitting of simulator noise parameters from experimental results.
+///
+/// .. autosummary::
+///    :toctree: generated/
+///
+///    fit_decay_time
+///    fit_t1
+///    fit_t2
+///    fit_depolarising_rate
+///
+#[pymodule]
+pub fn noise_fitting(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(fit_decay_time, m)?)?;
+    m.add_function(wrap_pyfunction!(fit_t1, m)?)?;
+    m.add_function(wrap_pyfunction!(fit_t2, m)?)?;
+    m.add_function(wrap_pyfunction!(fit_depolarising_rate, m)?)?;
+    Ok(())
+}
@@ -14,6 +14,7 @@
 //!
 //! Quantum programs are represented by linear sequences of quantum operations
 
+use crate::qryd_devices::convert_into_device;
 use bincode::{deserialize, serialize};
 use pyo3::exceptions::PyRuntimeError;
 use pyo3::exceptions::{PyTypeError, PyValueError};
@@ -21,10 +22,12 @@ use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use pyo3::types::PySet;
 use qoqo::operations::PragmaChangeDeviceWrapper;
+use qoqo_calculator_pyo3::{convert_into_calculator_float, CalculatorFloatWrapper};
 use roqoqo::prelude::*;
 use roqoqo_qryd::{
-    PragmaChangeQRydLayout, PragmaDeactivateQRydQubit, PragmaShiftQRydQubit,
-    PragmaShiftQubitsTweezers, PragmaSwitchDeviceLayout,
+    PragmaChangeQRydLayout, PragmaDeactivateQRydQubit, PragmaGlobalRotateXY, PragmaMoveToZone,
+    PragmaSetPhaseRelation, PragmaShiftQRydQubit, PragmaShiftQubitsTweezers, PragmaSwapTweezers,
+    PragmaSwitchDeviceLayout, QRydDevice,
 };
 use std::collections::HashMap;
 
@@ -351,6 +354,87 @@ impl PragmaShiftQRydQubitWrapper {
         })
     }
 
+    /// Create a PragmaShiftQRydQubit keeping all qubits of a FirstDevice at their current positions.
+    ///
+    /// A FirstDevice expects the new positions of all its qubits. Starting from the current
+    /// positions, only the moved qubits need to be changed with `move_qubit` and `shift_row`.
+    ///
+    /// Args:
+    ///     device (FirstDevice): The device the current qubit positions are taken from.
+    ///
+    /// Returns:
+    ///     PragmaShiftQRydQubit: The pragma leaving all qubits at their positions.
+    ///
+    /// Raises:
+    ///     TypeError: Input is not a FirstDevice.
+    #[staticmethod]
+    #[pyo3(text_signature = "(device, /)")]
+    fn from_device(device: &Bound<PyAny>) -> PyResult<Self> {
+        let QRydDevice::FirstDevice(first_device) = convert_into_device(device)
+            .map_err(|_| PyTypeError::new_err("Input is not a FirstDevice"))?;
+        Ok(Self {
+            internal: PragmaShiftQRydQubit::from_device(&first_device),
+        })
+    }
+
+    /// Set the new position of a qubit.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit to move.
+    ///     row (int): The row of the new position.
+    ///     column (int): The column of the new position.
+    ///
+    /// Returns:
+    ///     PragmaShiftQRydQubit: The pragma with the new position of the qubit.
+    #[pyo3(text_signature = "(qubit, row, column, /)")]
+    fn move_qubit(&self, qubit: usize, row: usize, column: usize) -> Self {
+        Self {
+            internal: self.internal.clone().move_qubit(qubit, row, column),
+        }
+    }
+
+    /// Shift the new positions of all qubits in a row by a number of columns.
+    ///
+    /// Args:
+    ///     row (int): The row whose qubits are shifted.
+    ///     offset (int): The number of columns to shift the qubits by, negative to shift them to the left.
+    ///
+    /// Returns:
+    ///     PragmaShiftQRydQubit: The pragma with the shifted positions.
+    ///
+    /// Raises:
+    ///     ValueError: A qubit would be shifted to a negative column.
+    #[pyo3(text_signature = "(row, offset, /)")]
+    fn shift_row(&self, row: usize, offset: isize) -> PyResult<Self> {
+        Ok(Self {
+            internal: self
+                .internal
+                .clone()
+                .shift_row(row, offset)
+                .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?,
+        })
+    }
+
+    /// Check that a FirstDevice can change its qubit positions to the new positions.
+    ///
+    /// The new positions need to contain every qubit of the device and no other qubits. The
+    /// qubits need to stay in their rows and every column needs to exist in its row.
+    ///
+    /// Args:
+    ///     device (FirstDevice): The device to validate the new positions against.
+    ///
+    /// Raises:
+    ///     TypeError: Input is not a FirstDevice.
+    ///     ValueError: The new positions are not valid on the device.
+    #[pyo3(text_signature = "(device, /)")]
+    fn validate(&self, device: &Bound<PyAny>) -> PyResult<()> {
+        let QRydDevice::FirstDevice(first_device) = convert_into_device(device)
+            .map_err(|_| PyTypeError::new_err("Input is not a FirstDevice"))?;
+        self.internal
+            .validate(&first_device)
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))
+    }
+
     /// List all involved qubits (here, all).
     ///
     /// Returns:
@@ -796,10 +880,13 @@ impl PragmaDeactivateQRydQubitWrapper {
 /// This PRAGMA Operation lists the shift operations to be executed in a QRyd Tweezer device.
 ///
 /// Each tuple contains first the starting tweezer identifier and second the ending tweezer identifier.
-/// Multiple instances indicate parallel operations.
+/// Sequential shifts are executed in the order of the list, each shift sees the qubits moved by
+/// the previous ones. Parallel shifts are executed at the same time, their paths may not cross.
 ///
 /// Args:
-///     shifts (list((int, int))): The list of shifts that can run in parallel.
+///     shifts (list((int, int))): The list of shifts.
+///     durations (Optional[list[float]]): The duration of each shift, None to use the transport times of the device.
+///     parallel (Optional[bool]): Whether the shifts are executed in parallel, defaults to False.
 pub struct PragmaShiftQubitsTweezersWrapper {
     /// PragmaShiftQubitsTweezers to be wrapped and converted to Python.
     pub internal: PragmaShiftQubitsTweezers,
@@ -810,16 +897,28 @@ impl PragmaShiftQubitsTweezersWrapper {
     /// Create a PragmaShiftQubitsTweezers.
     ///
     /// Args:
-    ///     shifts (list((int, int))): The list of shifts that can run in parallel.
+    ///     shifts (list((int, int))): The list of shifts.
+    ///     durations (Optional[list[float]]): The duration of each shift, None to use the transport times of the device.
+    ///     parallel (Optional[bool]): Whether the shifts are executed in parallel, defaults to False.
     ///
     /// Returns:
     ///     self: The new PragmaShiftQubitsTweezers.
     #[new]
-    #[pyo3(text_signature = "(shifts, /)")]
-    fn new(shifts: Vec<(usize, usize)>) -> Self {
-        Self {
-            internal: PragmaShiftQubitsTweezers::new(shifts),
-        }
+    #[pyo3(text_signature = "(shifts, durations, parallel, /)")]
+    fn new(
+        shifts: Vec<(usize, usize)>,
+        durations: Option<Vec<f64>>,
+        parallel: Option<bool>,
+    ) -> Self {
+        let parallel = parallel.unwrap_or(false);
+        let internal = match durations {
+            Some(durations) => {
+                PragmaShiftQubitsTweezers::with_durations(shifts, durations, parallel)
+            }
+            None if parallel => PragmaShiftQubitsTweezers::new_parallel(shifts),
+            None => PragmaShiftQubitsTweezers::new(shifts),
+        };
+        Self { internal }
     }
 
     /// Return the shifts involved in the Operation.
@@ -830,6 +929,22 @@ impl PragmaShiftQubitsTweezersWrapper {
         self.internal.shifts.clone()
     }
 
+    /// Return the durations of the shifts.
+    ///
+    /// Returns:
+    ///     Optional[list[float]]: The duration of each shift, None if the transport times of the device are used.
+    fn durations(&self) -> Option<Vec<f64>> {
+        self.internal.durations.clone()
+    }
+
+    /// Return whether the shifts are executed in parallel.
+    ///
+    /// Returns:
+    ///     bool: True if the shifts are executed in parallel, False if one after the other.
+    fn parallel(&self) -> bool {
+        self.internal.parallel
+    }
+
     /// Wrap PragmaShiftQubitsTweezers in PragmaChangeDevice operation
     ///
     /// PragmaShiftQubitsTweezers is device specific and can not be directly added to a Circuit.
@@ -926,7 +1041,7 @@ impl PragmaShiftQubitsTweezersWrapper {
             .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
 
         Ok(PragmaShiftQubitsTweezersWrapper {
-            internal: deserialize(&bytes[..]).map_err(|_| {
+            internal: PragmaShiftQubitsTweezers::from_bincode(&bytes[..]).map_err(|_| {
                 PyValueError::new_err("Input cannot be deserialized to PragmaShiftQubitsTweezers")
             })?,
         })
@@ -1297,6 +1412,1066 @@ impl PragmaSwitchDeviceLayoutWrapper {
     }
 }
 
+#[pyclass(name = "PragmaSwapTweezers", module = "qoqo_qryd.pragma_operations")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// This PRAGMA Operation exchanges the qubits of two occupied tweezers in a QRyd Tweezer device.
+///
+/// The qubits trapped in the two tweezers swap places, the qubit -> tweezer mapping of the
+/// device is updated in one step.
+///
+/// Args:
+///     tweezer_0 (int): The first tweezer of the exchange.
+///     tweezer_1 (int): The second tweezer of the exchange.
+pub struct PragmaSwapTweezersWrapper {
+    /// PragmaSwapTweezers to be wrapped and converted to Python.
+    pub internal: PragmaSwapTweezers,
+}
+
+#[pymethods]
+impl PragmaSwapTweezersWrapper {
+    /// Create a PragmaSwapTweezers.
+    ///
+    /// Args:
+    ///     tweezer_0 (int): The first tweezer of the exchange.
+    ///     tweezer_1 (int): The second tweezer of the exchange.
+    ///
+    /// Returns:
+    ///     self: The new PragmaSwapTweezers.
+    #[new]
+    #[pyo3(text_signature = "(tweezer_0, tweezer_1, /)")]
+    fn new(tweezer_0: usize, tweezer_1: usize) -> Self {
+        Self {
+            internal: PragmaSwapTweezers::new(tweezer_0, tweezer_1),
+        }
+    }
+
+    /// Return the first tweezer of the exchange.
+    ///
+    /// Returns:
+    ///     int: The first tweezer of the exchange.
+    fn tweezer_0(&self) -> usize {
+        self.internal.tweezer_0
+    }
+
+    /// Return the second tweezer of the exchange.
+    ///
+    /// Returns:
+    ///     int: The second tweezer of the exchange.
+    fn tweezer_1(&self) -> usize {
+        self.internal.tweezer_1
+    }
+
+    /// Wrap PragmaSwapTweezers in PragmaChangeDevice operation
+    ///
+    /// PragmaSwapTweezers is device specific and can not be directly added to a Circuit.
+    /// Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+    /// to the circuit.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// >>> from qoqo import Circuit
+    /// ... from qoqo_qryd.pragma_operations import PragmaSwapTweezers
+    /// ... circuit = Circuit()
+    /// ... circuit += PragmaSwapTweezers(tweezer_0=0, tweezer_1=1).to_pragma_change_device()
+    ///
+    /// Returns:
+    ///     PragmaChangeDevice
+    pub fn to_pragma_change_device(&self) -> PyResult<PragmaChangeDeviceWrapper> {
+        Ok(PragmaChangeDeviceWrapper {
+            internal: self.internal.to_pragma_change_device().map_err(|err| {
+                PyRuntimeError::new_err(format!(
+                    "Error occured during serialisation of PragmaSwapTweezers {:?}",
+                    err
+                ))
+            })?,
+        })
+    }
+
+    /// List all involved qubits (here, all).
+    ///
+    /// Returns:
+    ///     set[int]: The involved qubits of the PRAGMA operation.
+    fn involved_qubits(&self) -> PyObject {
+        Python::with_gil(|py| -> PyObject { PySet::new_bound(py, &["All"]).unwrap().to_object(py) })
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     list[str]: The tags of the operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Return the bincode representation of the PragmaSwapTweezers using the bincode crate.
+    ///
+    /// Returns:
+    ///     ByteArray: The serialized PragmaSwapTweezers (in bincode form).
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize PragmaSwapTweezers to bytes.
+    pub fn to_bincode(&self) -> PyResult<Py<PyByteArray>> {
+        let serialized = serialize(&self.internal)
+            .map_err(|_| PyValueError::new_err("Cannot serialize PragmaSwapTweezers to bytes"))?;
+        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+            PyByteArray::new_bound(py, &serialized[..]).into()
+        });
+        Ok(b)
+    }
+
+    /// Convert the bincode representation of the PragmaSwapTweezers to a PragmaSwapTweezers using the bincode crate.
+    ///
+    /// Args:
+    ///     input (ByteArray): The serialized PragmaSwapTweezers (in bincode form).
+    ///
+    /// Returns:
+    ///     PragmaSwapTweezers: The deserialized PragmaSwapTweezers.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to byte array.
+    ///     ValueError: Input cannot be deserialized to PragmaSwapTweezers.
+    #[pyo3(text_signature = "(input, /)")]
+    pub fn from_bincode(&self, input: &Bound<PyAny>) -> PyResult<PragmaSwapTweezersWrapper> {
+        let bytes = input
+            .extract::<Vec<u8>>()
+            .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
+        Ok(PragmaSwapTweezersWrapper {
+            internal: deserialize(&bytes[..]).map_err(|_| {
+                PyValueError::new_err("Input cannot be deserialized to PragmaSwapTweezers")
+            })?,
+        })
+    }
+
+    /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+    ///
+    /// Args:
+    ///     substitution_parameters (dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    #[pyo3(text_signature = "(substitution_parameters, /)")]
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "Parameter Substitution failed: {:?}",
+                        x
+                    ))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the PRAGMA operation.
+    ///
+    /// Args:
+    ///     mapping (dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    #[pyo3(text_signature = "(mapping, /)")]
+    fn remap_qubits(&self, mapping: std::collections::HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the PRAGMA operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     PragmaSwapTweezers: A deep copy of self.
+    fn __copy__(&self) -> PragmaSwapTweezersWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     PragmaSwapTweezers: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: Py<PyAny>) -> PragmaSwapTweezersWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on PragmaSwapTweezers.
+    ///
+    /// Args:
+    ///     self: The PragmaSwapTweezers object.
+    ///     other: The object to compare self to.
+    ///     op: Whether they should be equal or not.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(&self, other: Py<PyAny>, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        let other: PragmaSwapTweezersWrapper =
+            Python::with_gil(|py| -> PyResult<PragmaSwapTweezersWrapper> {
+                let other_extracted: PyResult<PragmaSwapTweezersWrapper> = other.extract(py);
+                other_extracted
+            })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => Ok(self.internal == other.internal),
+            pyo3::class::basic::CompareOp::Ne => Ok(self.internal != other.internal),
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+}
+
+#[pyclass(name = "PragmaMoveToZone", module = "qoqo_qryd.pragma_operations")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// This PRAGMA Operation moves qubits into a zone of a QRyd Tweezer device.
+///
+/// The device resolves the operation into shifts of the qubits into the nearest free tweezers
+/// labelled with the zone, so that transfers between e.g. the storage and the interaction zone
+/// can be expressed without giving the tweezers.
+///
+/// Args:
+///     qubits (List[int]): The qubits to move, in the order they are moved.
+///     zone (str): The label of the zone the qubits are moved into.
+pub struct PragmaMoveToZoneWrapper {
+    /// PragmaMoveToZone to be wrapped and converted to Python.
+    pub internal: PragmaMoveToZone,
+}
+
+#[pymethods]
+impl PragmaMoveToZoneWrapper {
+    /// Create a PragmaMoveToZone.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The qubits to move, in the order they are moved.
+    ///     zone (str): The label of the zone the qubits are moved into.
+    ///
+    /// Returns:
+    ///     self: The new PragmaMoveToZone.
+    #[new]
+    #[pyo3(text_signature = "(qubits, zone, /)")]
+    fn new(qubits: Vec<usize>, zone: String) -> Self {
+        Self {
+            internal: PragmaMoveToZone::new(qubits, zone),
+        }
+    }
+
+    /// Return the qubits to move.
+    ///
+    /// Returns:
+    ///     List[int]: The qubits to move, in the order they are moved.
+    fn qubits(&self) -> Vec<usize> {
+        self.internal.qubits.clone()
+    }
+
+    /// Return the label of the zone the qubits are moved into.
+    ///
+    /// Returns:
+    ///     str: The label of the zone.
+    fn zone(&self) -> String {
+        self.internal.zone.clone()
+    }
+
+    /// Wrap PragmaMoveToZone in PragmaChangeDevice operation
+    ///
+    /// PragmaMoveToZone is device specific and can not be directly added to a Circuit.
+    /// Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+    /// to the circuit.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// >>> from qoqo import Circuit
+    /// ... from qoqo_qryd.pragma_operations import PragmaMoveToZone
+    /// ... circuit = Circuit()
+    /// ... circuit += PragmaMoveToZone(qubits=[0, 1], zone="interaction").to_pragma_change_device()
+    ///
+    /// Returns:
+    ///     PragmaChangeDevice
+    pub fn to_pragma_change_device(&self) -> PyResult<PragmaChangeDeviceWrapper> {
+        Ok(PragmaChangeDeviceWrapper {
+            internal: self.internal.to_pragma_change_device().map_err(|err| {
+                PyRuntimeError::new_err(format!(
+                    "Error occured during serialisation of PragmaMoveToZone {:?}",
+                    err
+                ))
+            })?,
+        })
+    }
+
+    /// List all involved qubits (here, all).
+    ///
+    /// Returns:
+    ///     set[int]: The involved qubits of the PRAGMA operation.
+    fn involved_qubits(&self) -> PyObject {
+        Python::with_gil(|py| -> PyObject { PySet::new_bound(py, &["All"]).unwrap().to_object(py) })
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     list[str]: The tags of the operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Return the bincode representation of the PragmaMoveToZone using the bincode crate.
+    ///
+    /// Returns:
+    ///     ByteArray: The serialized PragmaMoveToZone (in bincode form).
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize PragmaMoveToZone to bytes.
+    pub fn to_bincode(&self) -> PyResult<Py<PyByteArray>> {
+        let serialized = serialize(&self.internal)
+            .map_err(|_| PyValueError::new_err("Cannot serialize PragmaMoveToZone to bytes"))?;
+        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+            PyByteArray::new_bound(py, &serialized[..]).into()
+        });
+        Ok(b)
+    }
+
+    /// Convert the bincode representation of the PragmaMoveToZone to a PragmaMoveToZone using the bincode crate.
+    ///
+    /// Args:
+    ///     input (ByteArray): The serialized PragmaMoveToZone (in bincode form).
+    ///
+    /// Returns:
+    ///     PragmaMoveToZone: The deserialized PragmaMoveToZone.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to byte array.
+    ///     ValueError: Input cannot be deserialized to PragmaMoveToZone.
+    #[pyo3(text_signature = "(input, /)")]
+    pub fn from_bincode(&self, input: &Bound<PyAny>) -> PyResult<PragmaMoveToZoneWrapper> {
+        let bytes = input
+            .extract::<Vec<u8>>()
+            .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
+        Ok(PragmaMoveToZoneWrapper {
+            internal: deserialize(&bytes[..]).map_err(|_| {
+                PyValueError::new_err("Input cannot be deserialized to PragmaMoveToZone")
+            })?,
+        })
+    }
+
+    /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+    ///
+    /// Args:
+    ///     substitution_parameters (dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    #[pyo3(text_signature = "(substitution_parameters, /)")]
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "Parameter Substitution failed: {:?}",
+                        x
+                    ))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the PRAGMA operation.
+    ///
+    /// Args:
+    ///     mapping (dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    #[pyo3(text_signature = "(mapping, /)")]
+    fn remap_qubits(&self, mapping: std::collections::HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the PRAGMA operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     PragmaMoveToZone: A deep copy of self.
+    fn __copy__(&self) -> PragmaMoveToZoneWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     PragmaMoveToZone: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: Py<PyAny>) -> PragmaMoveToZoneWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on PragmaMoveToZone.
+    ///
+    /// Args:
+    ///     self: The PragmaMoveToZone object.
+    ///     other: The object to compare self to.
+    ///     op: Whether they should be equal or not.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(&self, other: Py<PyAny>, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        let other: PragmaMoveToZoneWrapper =
+            Python::with_gil(|py| -> PyResult<PragmaMoveToZoneWrapper> {
+                let other_extracted: PyResult<PragmaMoveToZoneWrapper> = other.extract(py);
+                other_extracted
+            })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => Ok(self.internal == other.internal),
+            pyo3::class::basic::CompareOp::Ne => Ok(self.internal != other.internal),
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+}
+
+#[pyclass(name = "PragmaGlobalRotateXY", module = "qoqo_qryd.pragma_operations")]
+#[derive(Clone, Debug, PartialEq)]
+/// This PRAGMA Operation applies a global single-qubit rotation to all qubits of a QRyd Tweezer device.
+///
+/// Rydberg platforms drive global Raman pulses addressing all atoms simultaneously. The
+/// operation acts as a RotateXY gate with the same angles on every active qubit of the device
+/// and takes the global rotation time of the current Layout.
+///
+/// Args:
+///     theta (CalculatorFloat): The angle theta of the rotation, in the interval from 0 to 2 * 2pi.
+///     phi (CalculatorFloat): The rotation axis, in spherical coordinates phi gives the angle in the x-y plane.
+pub struct PragmaGlobalRotateXYWrapper {
+    /// PragmaGlobalRotateXY to be wrapped and converted to Python.
+    pub internal: PragmaGlobalRotateXY,
+}
+
+#[pymethods]
+impl PragmaGlobalRotateXYWrapper {
+    /// Create a PragmaGlobalRotateXY.
+    ///
+    /// Args:
+    ///     theta (CalculatorFloat): The angle theta of the rotation, in the interval from 0 to 2 * 2pi.
+    ///     phi (CalculatorFloat): The rotation axis, in spherical coordinates phi gives the angle in the x-y plane.
+    ///
+    /// Returns:
+    ///     self: The new PragmaGlobalRotateXY.
+    ///
+    /// Raises:
+    ///     TypeError: The angles can not be converted to CalculatorFloat.
+    #[new]
+    #[pyo3(text_signature = "(theta, phi, /)")]
+    fn new(theta: &Bound<PyAny>, phi: &Bound<PyAny>) -> PyResult<Self> {
+        let theta = convert_into_calculator_float(theta).map_err(|_| {
+            PyTypeError::new_err("Argument theta cannot be converted to CalculatorFloat")
+        })?;
+        let phi = convert_into_calculator_float(phi).map_err(|_| {
+            PyTypeError::new_err("Argument phi cannot be converted to CalculatorFloat")
+        })?;
+        Ok(Self {
+            internal: PragmaGlobalRotateXY::new(theta, phi),
+        })
+    }
+
+    /// Return the angle theta of the rotation.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The angle theta of the rotation.
+    fn theta(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.theta().clone(),
+        }
+    }
+
+    /// Return the angle phi of the rotation axis.
+    ///
+    /// Returns:
+    ///     CalculatorFloat: The angle phi of the rotation axis.
+    fn phi(&self) -> CalculatorFloatWrapper {
+        CalculatorFloatWrapper {
+            internal: self.internal.phi().clone(),
+        }
+    }
+
+    /// Wrap PragmaGlobalRotateXY in PragmaChangeDevice operation
+    ///
+    /// PragmaGlobalRotateXY is device specific and can not be directly added to a Circuit.
+    /// Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+    /// to the circuit.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// >>> from qoqo import Circuit
+    /// ... from qoqo_qryd.pragma_operations import PragmaGlobalRotateXY
+    /// ... circuit = Circuit()
+    /// ... circuit += PragmaGlobalRotateXY(theta=1.0, phi=0.0).to_pragma_change_device()
+    ///
+    /// Returns:
+    ///     PragmaChangeDevice
+    pub fn to_pragma_change_device(&self) -> PyResult<PragmaChangeDeviceWrapper> {
+        Ok(PragmaChangeDeviceWrapper {
+            internal: self.internal.to_pragma_change_device().map_err(|err| {
+                PyRuntimeError::new_err(format!(
+                    "Error occured during serialisation of PragmaGlobalRotateXY {:?}",
+                    err
+                ))
+            })?,
+        })
+    }
+
+    /// List all involved qubits (here, all).
+    ///
+    /// Returns:
+    ///     set[int]: The involved qubits of the PRAGMA operation.
+    fn involved_qubits(&self) -> PyObject {
+        Python::with_gil(|py| -> PyObject { PySet::new_bound(py, &["All"]).unwrap().to_object(py) })
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     list[str]: The tags of the operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Return the bincode representation of the PragmaGlobalRotateXY using the bincode crate.
+    ///
+    /// Returns:
+    ///     ByteArray: The serialized PragmaGlobalRotateXY (in bincode form).
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize PragmaGlobalRotateXY to bytes.
+    pub fn to_bincode(&self) -> PyResult<Py<PyByteArray>> {
+        let serialized = serialize(&self.internal)
+            .map_err(|_| PyValueError::new_err("Cannot serialize PragmaGlobalRotateXY to bytes"))?;
+        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+            PyByteArray::new_bound(py, &serialized[..]).into()
+        });
+        Ok(b)
+    }
+
+    /// Convert the bincode representation of the PragmaGlobalRotateXY to a PragmaGlobalRotateXY using the bincode crate.
+    ///
+    /// Args:
+    ///     input (ByteArray): The serialized PragmaGlobalRotateXY (in bincode form).
+    ///
+    /// Returns:
+    ///     PragmaGlobalRotateXY: The deserialized PragmaGlobalRotateXY.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to byte array.
+    ///     ValueError: Input cannot be deserialized to PragmaGlobalRotateXY.
+    #[pyo3(text_signature = "(input, /)")]
+    pub fn from_bincode(&self, input: &Bound<PyAny>) -> PyResult<PragmaGlobalRotateXYWrapper> {
+        let bytes = input
+            .extract::<Vec<u8>>()
+            .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
+        Ok(PragmaGlobalRotateXYWrapper {
+            internal: deserialize(&bytes[..]).map_err(|_| {
+                PyValueError::new_err("Input cannot be deserialized to PragmaGlobalRotateXY")
+            })?,
+        })
+    }
+
+    /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+    ///
+    /// Args:
+    ///     substitution_parameters (dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    #[pyo3(text_signature = "(substitution_parameters, /)")]
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "Parameter Substitution failed: {:?}",
+                        x
+                    ))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the PRAGMA operation.
+    ///
+    /// Args:
+    ///     mapping (dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    #[pyo3(text_signature = "(mapping, /)")]
+    fn remap_qubits(&self, mapping: std::collections::HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the PRAGMA operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     PragmaGlobalRotateXY: A deep copy of self.
+    fn __copy__(&self) -> PragmaGlobalRotateXYWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     PragmaGlobalRotateXY: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: Py<PyAny>) -> PragmaGlobalRotateXYWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on PragmaGlobalRotateXY.
+    ///
+    /// Args:
+    ///     self: The PragmaGlobalRotateXY object.
+    ///     other: The object to compare self to.
+    ///     op: Whether they should be equal or not.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(&self, other: Py<PyAny>, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        let other: PragmaGlobalRotateXYWrapper =
+            Python::with_gil(|py| -> PyResult<PragmaGlobalRotateXYWrapper> {
+                let other_extracted: PyResult<PragmaGlobalRotateXYWrapper> = other.extract(py);
+                other_extracted
+            })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => Ok(self.internal == other.internal),
+            pyo3::class::basic::CompareOp::Ne => Ok(self.internal != other.internal),
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+}
+
+#[pyclass(
+    name = "PragmaSetPhaseRelation",
+    module = "qoqo_qryd.pragma_operations"
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// This PRAGMA Operation sets the phase relations of a QRyd Tweezer device.
+///
+/// The phase relations give the phase of the PhaseShiftedControlledZ and
+/// PhaseShiftedControlledPhase gates. Changing them mid-circuit expresses circuits calibrated
+/// for different interaction strengths in a single program.
+///
+/// Args:
+///     cz_relation (str): The relation to use for the PhaseShiftedControlledZ gate.
+///     cp_relation (str): The relation to use for the PhaseShiftedControlledPhase gate.
+pub struct PragmaSetPhaseRelationWrapper {
+    /// PragmaSetPhaseRelation to be wrapped and converted to Python.
+    pub internal: PragmaSetPhaseRelation,
+}
+
+#[pymethods]
+impl PragmaSetPhaseRelationWrapper {
+    /// Create a PragmaSetPhaseRelation.
+    ///
+    /// Args:
+    ///     cz_relation (str): The relation to use for the PhaseShiftedControlledZ gate.
+    ///     cp_relation (str): The relation to use for the PhaseShiftedControlledPhase gate.
+    ///
+    /// Returns:
+    ///     self: The new PragmaSetPhaseRelation.
+    #[new]
+    #[pyo3(text_signature = "(cz_relation, cp_relation, /)")]
+    fn new(cz_relation: String, cp_relation: String) -> Self {
+        Self {
+            internal: PragmaSetPhaseRelation::new(cz_relation, cp_relation),
+        }
+    }
+
+    /// Return the relation to use for the PhaseShiftedControlledZ gate.
+    ///
+    /// Returns:
+    ///     str: The PhaseShiftedControlledZ phase relation.
+    fn cz_relation(&self) -> String {
+        self.internal.cz_relation.clone()
+    }
+
+    /// Return the relation to use for the PhaseShiftedControlledPhase gate.
+    ///
+    /// Returns:
+    ///     str: The PhaseShiftedControlledPhase phase relation.
+    fn cp_relation(&self) -> String {
+        self.internal.cp_relation.clone()
+    }
+
+    /// Wrap PragmaSetPhaseRelation in PragmaChangeDevice operation
+    ///
+    /// PragmaSetPhaseRelation is device specific and can not be directly added to a Circuit.
+    /// Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+    /// to the circuit.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// >>> from qoqo import Circuit
+    /// ... from qoqo_qryd.pragma_operations import PragmaSetPhaseRelation
+    /// ... circuit = Circuit()
+    /// ... circuit += PragmaSetPhaseRelation(cz_relation="DefaultRelation", cp_relation="0.5").to_pragma_change_device()
+    ///
+    /// Returns:
+    ///     PragmaChangeDevice
+    pub fn to_pragma_change_device(&self) -> PyResult<PragmaChangeDeviceWrapper> {
+        Ok(PragmaChangeDeviceWrapper {
+            internal: self.internal.to_pragma_change_device().map_err(|err| {
+                PyRuntimeError::new_err(format!(
+                    "Error occured during serialisation of PragmaSetPhaseRelation {:?}",
+                    err
+                ))
+            })?,
+        })
+    }
+
+    /// List all involved qubits (here, all).
+    ///
+    /// Returns:
+    ///     set[int]: The involved qubits of the PRAGMA operation.
+    fn involved_qubits(&self) -> PyObject {
+        Python::with_gil(|py| -> PyObject { PySet::new_bound(py, &["All"]).unwrap().to_object(py) })
+    }
+
+    /// Return tags classifying the type of the operation.
+    ///
+    /// Used for the type based dispatch in ffi interfaces.
+    ///
+    /// Returns:
+    ///     list[str]: The tags of the operation.
+    fn tags(&self) -> Vec<String> {
+        self.internal.tags().iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Return hqslang name of the operation.
+    ///
+    /// Returns:
+    ///     str: The hqslang name of the operation.
+    fn hqslang(&self) -> &'static str {
+        self.internal.hqslang()
+    }
+
+    /// Return true when the operation has symbolic parameters.
+    ///
+    /// Returns:
+    ///     bool: True if the operation contains symbolic parameters, False if it does not.
+    fn is_parametrized(&self) -> bool {
+        self.internal.is_parametrized()
+    }
+
+    /// Return the bincode representation of the PragmaSetPhaseRelation using the bincode crate.
+    ///
+    /// Returns:
+    ///     ByteArray: The serialized PragmaSetPhaseRelation (in bincode form).
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize PragmaSetPhaseRelation to bytes.
+    pub fn to_bincode(&self) -> PyResult<Py<PyByteArray>> {
+        let serialized = serialize(&self.internal).map_err(|_| {
+            PyValueError::new_err("Cannot serialize PragmaSetPhaseRelation to bytes")
+        })?;
+        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+            PyByteArray::new_bound(py, &serialized[..]).into()
+        });
+        Ok(b)
+    }
+
+    /// Convert the bincode representation of the PragmaSetPhaseRelation to a PragmaSetPhaseRelation using the bincode crate.
+    ///
+    /// Args:
+    ///     input (ByteArray): The serialized PragmaSetPhaseRelation (in bincode form).
+    ///
+    /// Returns:
+    ///     PragmaSetPhaseRelation: The deserialized PragmaSetPhaseRelation.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to byte array.
+    ///     ValueError: Input cannot be deserialized to PragmaSetPhaseRelation.
+    #[pyo3(text_signature = "(input, /)")]
+    pub fn from_bincode(&self, input: &Bound<PyAny>) -> PyResult<PragmaSetPhaseRelationWrapper> {
+        let bytes = input
+            .extract::<Vec<u8>>()
+            .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
+        Ok(PragmaSetPhaseRelationWrapper {
+            internal: deserialize(&bytes[..]).map_err(|_| {
+                PyValueError::new_err("Input cannot be deserialized to PragmaSetPhaseRelation")
+            })?,
+        })
+    }
+
+    /// Substitute the symbolic parameters in a clone of the PRAGMA operation according to the substitution_parameters input.
+    ///
+    /// Args:
+    ///     substitution_parameters (dict[str, float]): The dictionary containing the substitutions to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation operation with the parameters substituted.
+    ///
+    /// Raises:
+    ///     RuntimeError: The parameter substitution failed.
+    #[pyo3(text_signature = "(substitution_parameters, /)")]
+    fn substitute_parameters(
+        &self,
+        substitution_parameters: std::collections::HashMap<String, f64>,
+    ) -> PyResult<Self> {
+        let mut calculator = qoqo_calculator::Calculator::new();
+        for (key, val) in substitution_parameters.iter() {
+            calculator.set_variable(key, *val);
+        }
+        Ok(Self {
+            internal: self
+                .internal
+                .substitute_parameters(&calculator)
+                .map_err(|x| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "Parameter Substitution failed: {:?}",
+                        x
+                    ))
+                })?,
+        })
+    }
+
+    /// Remap qubits in a clone of the PRAGMA operation.
+    ///
+    /// Args:
+    ///     mapping (dict[int, int]): The dictionary containing the {qubit: qubit} mapping to use in the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     self: The PRAGMA operation with the qubits remapped.
+    ///
+    /// Raises:
+    ///     RuntimeError: The qubit remapping failed.
+    #[pyo3(text_signature = "(mapping, /)")]
+    fn remap_qubits(&self, mapping: std::collections::HashMap<usize, usize>) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .remap_qubits(&mapping)
+            .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("Qubit remapping failed: "))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
+    /// Return a copy of the PRAGMA operation (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     PragmaSetPhaseRelation: A deep copy of self.
+    fn __copy__(&self) -> PragmaSetPhaseRelationWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     PragmaSetPhaseRelation: A deep copy of self.
+    fn __deepcopy__(&self, _memodict: Py<PyAny>) -> PragmaSetPhaseRelationWrapper {
+        self.clone()
+    }
+
+    /// Return a string containing a formatted (string) representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The string representation of the operation.
+    fn __format__(&self, _format_spec: &str) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return a string containing a printable representation of the PRAGMA operation.
+    ///
+    /// Returns:
+    ///     str: The printable string representation of the operation.
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self.internal))
+    }
+
+    /// Return the __richcmp__ magic method to perform rich comparison operations on PragmaSetPhaseRelation.
+    ///
+    /// Args:
+    ///     self: The PragmaSetPhaseRelation object.
+    ///     other: The object to compare self to.
+    ///     op: Whether they should be equal or not.
+    ///
+    /// Returns:
+    ///     bool: Whether the two operations compared evaluated to True or False.
+    fn __richcmp__(&self, other: Py<PyAny>, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        let other: PragmaSetPhaseRelationWrapper =
+            Python::with_gil(|py| -> PyResult<PragmaSetPhaseRelationWrapper> {
+                let other_extracted: PyResult<PragmaSetPhaseRelationWrapper> = other.extract(py);
+                other_extracted
+            })?;
+        match op {
+            pyo3::class::basic::CompareOp::Eq => Ok(self.internal == other.internal),
+            pyo3::class::basic::CompareOp::Ne => Ok(self.internal != other.internal),
+            _ => Err(pyo3::exceptions::PyNotImplementedError::new_err(
+                "Other comparison not implemented.",
+            )),
+        }
+    }
+}
+
 /// QRyd specific PragmaOperations that support changing the QRyd device during a circuit evaluation
 ///
 /// .. autosummary::
@@ -1307,6 +2482,10 @@ impl PragmaSwitchDeviceLayoutWrapper {
 ///    PragmaDeactivateQRydQubit
 ///    PragmaShiftQubitsTweezers
 ///    PragmaSwitchDeviceLayout
+///    PragmaSwapTweezers
+///    PragmaGlobalRotateXY
+///    PragmaMoveToZone
+///    PragmaSetPhaseRelation
 #[pymodule]
 pub fn pragma_operations(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PragmaChangeQRydLayoutWrapper>()?;
@@ -1314,5 +2493,9 @@ pub fn pragma_operations(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PragmaDeactivateQRydQubitWrapper>()?;
     m.add_class::<PragmaShiftQubitsTweezersWrapper>()?;
     m.add_class::<PragmaSwitchDeviceLayoutWrapper>()?;
+    m.add_class::<PragmaSwapTweezersWrapper>()?;
+    m.add_class::<PragmaGlobalRotateXYWrapper>()?;
+    m.add_class::<PragmaMoveToZoneWrapper>()?;
+    m.add_class::<PragmaSetPhaseRelationWrapper>()?;
     Ok(())
 }
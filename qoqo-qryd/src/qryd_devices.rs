@@ -23,7 +23,7 @@ use qoqo::QoqoBackendError;
 use qoqo_calculator_pyo3::convert_into_calculator_float;
 use roqoqo::devices::Device;
 
-use roqoqo_qryd::qryd_devices::{FirstDevice, QRydDevice};
+use roqoqo_qryd::qryd_devices::{DistanceGateModel, FirstDevice, QRydDevice};
 
 /// First example of a QRyd quantum device.
 ///
@@ -87,34 +87,8 @@ impl FirstDeviceWrapper {
         allow_ccz_gate: Option<bool>,
         allow_ccp_gate: Option<bool>,
     ) -> PyResult<Self> {
-        let czpr = if let Some(value) = controlled_z_phase_relation {
-            if convert_into_calculator_float(value).is_ok() {
-                Some(convert_into_calculator_float(value).unwrap().to_string())
-            } else {
-                Some(
-                    controlled_z_phase_relation
-                        .unwrap()
-                        .extract::<String>()
-                        .unwrap(),
-                )
-            }
-        } else {
-            None
-        };
-        let cppr = if let Some(value) = controlled_phase_phase_relation {
-            if convert_into_calculator_float(value).is_ok() {
-                Some(convert_into_calculator_float(value).unwrap().to_string())
-            } else {
-                Some(
-                    controlled_phase_phase_relation
-                        .unwrap()
-                        .extract::<String>()
-                        .unwrap(),
-                )
-            }
-        } else {
-            None
-        };
+        let czpr = convert_phase_relation(controlled_z_phase_relation)?;
+        let cppr = convert_phase_relation(controlled_phase_phase_relation)?;
         Ok(Self {
             internal: FirstDevice::new(
                 number_rows,
@@ -131,6 +105,51 @@ impl FirstDeviceWrapper {
         })
     }
 
+    /// Create new `First` QRyd device with a different number of tweezers in each row.
+    ///
+    /// The tweezers do not need to form a regular grid, the (x, y) position of every tweezer is given explicitly.
+    /// After creation the device will be in the initial layout with layout number 0.
+    ///
+    /// Args:
+    ///     qubits_per_row (List[int]): Fixed number of occupied tweezer positions in each row.
+    ///     initial_positions (List[List[(float, float)]]): The (x, y) position of every tweezer, given row by row.
+    ///     controlled_z_phase_relation (Optional[Union[str, float]]): The relation to use for the PhaseShiftedControlledZ gate.
+    ///     controlled_phase_phase_relation (Optional[Union[str, float]]): The relation to use for the PhaseShiftedControlledPhase gate.
+    ///     allow_ccz_gate (Optional[bool]): Whether to allow ControlledControlledPauliZ operations in the device.
+    ///     allow_ccp_gate (Optional[bool]): Whether to allow ControlledControlledPhaseShift operations in the device.
+    ///
+    /// Returns:
+    ///     FirstDevice: The new device.
+    ///
+    /// Raises:
+    ///     ValueError: More qubits than tweezers are specified for a row.
+    #[staticmethod]
+    #[pyo3(
+        text_signature = "(qubits_per_row, initial_positions, controlled_z_phase_relation, controlled_phase_phase_relation, allow_ccz_gate, allow_ccp_gate, /)"
+    )]
+    pub fn ragged(
+        qubits_per_row: Vec<usize>,
+        initial_positions: Vec<Vec<(f64, f64)>>,
+        controlled_z_phase_relation: Option<&Bound<PyAny>>,
+        controlled_phase_phase_relation: Option<&Bound<PyAny>>,
+        allow_ccz_gate: Option<bool>,
+        allow_ccp_gate: Option<bool>,
+    ) -> PyResult<Self> {
+        let czpr = convert_phase_relation(controlled_z_phase_relation)?;
+        let cppr = convert_phase_relation(controlled_phase_phase_relation)?;
+        Ok(Self {
+            internal: FirstDevice::new_ragged(
+                &qubits_per_row,
+                initial_positions,
+                czpr,
+                cppr,
+                allow_ccz_gate,
+                allow_ccp_gate,
+            )
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
     /// Returns the gate time of a single qubit operation on this device.
     ///
     /// Returns:
@@ -362,6 +381,33 @@ impl FirstDeviceWrapper {
         self.internal.number_columns()
     }
 
+    /// Return the number of tweezer positions in each row.
+    ///
+    /// Returns:
+    ///     List[int]: The number of tweezers in each row.
+    ///
+    pub fn columns_per_row(&self) -> Vec<usize> {
+        self.internal.columns_per_row()
+    }
+
+    /// Return the physical (x, y) position of a tweezer in the current layout.
+    ///
+    /// Args:
+    ///     row (int): The row of the tweezer.
+    ///     column (int): The column of the tweezer.
+    ///
+    /// Returns:
+    ///     (float, float): The position of the tweezer.
+    ///
+    /// Raises:
+    ///     ValueError: The tweezer does not exist in the device.
+    #[pyo3(text_signature = "(row, column, /)")]
+    pub fn tweezer_position(&self, row: usize, column: usize) -> PyResult<(f64, f64)> {
+        self.internal
+            .tweezer_position(&(row, column))
+            .ok_or_else(|| PyValueError::new_err("The tweezer does not exist in the device."))
+    }
+
     /// Return the position of each qubit in the row-column grid of tweezer positions.
     ///
     /// Returns:
@@ -430,6 +476,34 @@ impl FirstDeviceWrapper {
         })
     }
 
+    /// Add a new layout with explicit tweezer positions to the device.
+    ///
+    /// The number of positions in each row has to match the number of tweezers in the row.
+    ///
+    /// Args:
+    ///     layout_number (int): The number index that is assigned to the new layout
+    ///     positions (List[List[(float, float)]]): The (x, y) position of every tweezer, given row by row
+    ///
+    /// Returns:
+    ///     FirstDevice: A copy of the device with the new layout added.
+    ///
+    /// Raises:
+    ///     PyValueError: layout number is already in use or the positions do not fit the tweezers per row
+    #[pyo3(text_signature = "(layout_number, positions, /)")]
+    pub fn add_irregular_layout(
+        &self,
+        layout_number: usize,
+        positions: Vec<Vec<(f64, f64)>>,
+    ) -> PyResult<Self> {
+        let new_internal = self
+            .internal
+            .add_irregular_layout(layout_number, positions)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))?;
+        Ok(Self {
+            internal: new_internal,
+        })
+    }
+
     /// Set distance cutoff for two-qubit gate operations.
     ///
     /// In the FirstQryd device the availability of two-qubit operations
@@ -446,6 +520,213 @@ impl FirstDeviceWrapper {
         Ok(())
     }
 
+    /// Return the distance cutoff for two-qubit gate operations.
+    ///
+    /// Returns:
+    ///     float: The cutoff for interaction distance
+    pub fn cutoff(&self) -> f64 {
+        self.internal.cutoff()
+    }
+
+    /// Set a power-law model for the distance dependence of the two-qubit gates.
+    ///
+    /// The gate time is `prefactor * distance^exponent`, the fidelity is the same for all distances.
+    /// Gate times are calculated from the current qubit positions, so they follow every
+    /// PragmaShiftQRydQubit and layout change.
+    ///
+    /// Args:
+    ///     prefactor (float): The gate time at distance 1.0.
+    ///     exponent (float): The exponent of the distance.
+    ///     fidelity (Optional[float]): The fidelity of the gate, defaults to 1.0.
+    ///
+    /// Raises:
+    ///     ValueError: A parameter of the model is not valid.
+    #[pyo3(text_signature = "(prefactor, exponent, fidelity, /)")]
+    pub fn set_power_law_gate_model(
+        &mut self,
+        prefactor: f64,
+        exponent: f64,
+        fidelity: Option<f64>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_distance_gate_model(Some(DistanceGateModel::PowerLaw {
+                prefactor,
+                exponent,
+                fidelity: fidelity.unwrap_or(1.0),
+            }))
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set a van der Waals model for the distance dependence of the two-qubit gates.
+    ///
+    /// The interaction strength is `c6 / distance^6`, the gate time is the time to accumulate
+    /// the interaction phase, `phase * distance^6 / c6`, and the fidelity is
+    /// `exp(-decay_rate * gate_time)`.
+    /// Gate times are calculated from the current qubit positions, so they follow every
+    /// PragmaShiftQRydQubit and layout change.
+    ///
+    /// Args:
+    ///     c6 (float): The C6 interaction coefficient.
+    ///     phase (float): The interaction phase accumulated during the gate.
+    ///     decay_rate (Optional[float]): The decay rate of the fidelity during the gate, defaults to 0.0.
+    ///
+    /// Raises:
+    ///     ValueError: A parameter of the model is not valid.
+    #[pyo3(text_signature = "(c6, phase, decay_rate, /)")]
+    pub fn set_van_der_waals_gate_model(
+        &mut self,
+        c6: f64,
+        phase: f64,
+        decay_rate: Option<f64>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_distance_gate_model(Some(DistanceGateModel::VanDerWaals {
+                c6,
+                phase,
+                decay_rate: decay_rate.unwrap_or(0.0),
+            }))
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Go back to the default model of the two-qubit gates with a gate time of `2e-6 * distance^2`.
+    pub fn reset_distance_gate_model(&mut self) -> PyResult<()> {
+        self.internal
+            .set_distance_gate_model(None)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Return the json representation of the distance model of the two-qubit gates.
+    ///
+    /// Returns:
+    ///     Optional[str]: The serialized model, None for the default model.
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize the model to json.
+    pub fn distance_gate_model(&self) -> PyResult<Option<String>> {
+        self.internal
+            .distance_gate_model()
+            .map(|model| {
+                serde_json::to_string(model).map_err(|_| {
+                    PyValueError::new_err("Cannot serialize the distance gate model to json")
+                })
+            })
+            .transpose()
+    }
+
+    /// Returns the fidelity of a two qubit operation on this device.
+    ///
+    /// Args:
+    ///     hqslang (str): The name of the two-qubit gate.
+    ///     control (int): The control qubit the gate acts on.
+    ///     target (int): The target qubit the gate acts on.
+    ///
+    /// Returns:
+    ///     float: The gate fidelity.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not available in the device.
+    #[pyo3(text_signature = "(hqslang, control, target, /)")]
+    pub fn two_qubit_gate_fidelity(
+        &self,
+        hqslang: &str,
+        control: usize,
+        target: usize,
+    ) -> PyResult<f64> {
+        self.internal
+            .two_qubit_gate_fidelity(hqslang, &control, &target)
+            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+    }
+
+    /// Move a single qubit to an unoccupied tweezer position in its row.
+    ///
+    /// Args:
+    ///     qubit (int): The qubit that is moved
+    ///     column (int): The new column of the qubit
+    ///
+    /// Raises:
+    ///     ValueError: The qubit is not in the device or the new position does not exist or is occupied
+    #[pyo3(text_signature = "(qubit, column, /)")]
+    pub fn set_qubit_position(&mut self, qubit: usize, column: usize) -> PyResult<()> {
+        self.internal
+            .set_qubit_position(qubit, column)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the physical (x, y) position of a tweezer in the current layout.
+    ///
+    /// Args:
+    ///     row (int): The row of the tweezer
+    ///     column (int): The column of the tweezer
+    ///     new_position ((float, float)): The new (x, y) position of the tweezer
+    ///
+    /// Raises:
+    ///     ValueError: The tweezer does not exist in the device
+    #[pyo3(text_signature = "(row, column, new_position, /)")]
+    pub fn set_tweezer_position(
+        &mut self,
+        row: usize,
+        column: usize,
+        new_position: (f64, f64),
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_position(&(row, column), new_position)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Return whether the device allows ControlledControlledPauliZ operations.
+    ///
+    /// Returns:
+    ///     bool: Whether ControlledControlledPauliZ operations are allowed.
+    pub fn allow_ccz_gate(&self) -> bool {
+        self.internal.allow_ccz_gate()
+    }
+
+    /// Set whether the device allows ControlledControlledPauliZ operations.
+    ///
+    /// Args:
+    ///     allow_ccz_gate (bool): Whether to allow ControlledControlledPauliZ operations.
+    #[pyo3(text_signature = "(allow_ccz_gate, /)")]
+    pub fn set_allow_ccz_gate(&mut self, allow_ccz_gate: bool) {
+        self.internal.set_allow_ccz_gate(allow_ccz_gate);
+    }
+
+    /// Return whether the device allows ControlledControlledPhaseShift operations.
+    ///
+    /// Returns:
+    ///     bool: Whether ControlledControlledPhaseShift operations are allowed.
+    pub fn allow_ccp_gate(&self) -> bool {
+        self.internal.allow_ccp_gate()
+    }
+
+    /// Set whether the device allows ControlledControlledPhaseShift operations.
+    ///
+    /// Args:
+    ///     allow_ccp_gate (bool): Whether to allow ControlledControlledPhaseShift operations.
+    #[pyo3(text_signature = "(allow_ccp_gate, /)")]
+    pub fn set_allow_ccp_gate(&mut self, allow_ccp_gate: bool) {
+        self.internal.set_allow_ccp_gate(allow_ccp_gate);
+    }
+
+    /// Return the three-qubit gates allowed in the device.
+    ///
+    /// Returns:
+    ///     List[str]: The names of the allowed three-qubit gates.
+    pub fn available_three_qubit_gates(&self) -> Vec<&'static str> {
+        self.internal.available_three_qubit_gates()
+    }
+
+    /// Return all qubit triples a three-qubit gate is available on.
+    ///
+    /// Args:
+    ///     hqslang (str): The name of the three-qubit gate.
+    ///
+    /// Returns:
+    ///     List[(int, int, int)]: The (control_0, control_1, target) triples, sorted.
+    #[pyo3(text_signature = "(hqslang, /)")]
+    pub fn three_qubit_gate_triples(&self, hqslang: &str) -> Vec<(usize, usize, usize)> {
+        self.internal.three_qubit_gate_triples(hqslang)
+    }
+
     /// Return the bincode representation of the Enum variant of the Device.
     ///
     /// Only used for internal interfacing.
@@ -499,6 +780,17 @@ impl FirstDeviceWrapper {
     }
 }
 
+/// Convert an optional phase relation given as a string or a float to the String used by [FirstDevice].
+fn convert_phase_relation(relation: Option<&Bound<PyAny>>) -> PyResult<Option<String>> {
+    match relation {
+        Some(value) => match convert_into_calculator_float(value) {
+            Ok(float_value) => Ok(Some(float_value.to_string())),
+            Err(_) => value.extract::<String>().map(Some),
+        },
+        None => Ok(None),
+    }
+}
+
 /// Convert generic python object to [roqoqo_qryd::QrydDevice].
 ///
 /// Fallible conversion of generic python object to [roqoqo::FirstDevice].
@@ -17,8 +17,8 @@ use bincode::{deserialize, serialize};
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::{PyByteArray, PyType};
-use qoqo::convert_into_circuit;
 use qoqo::QoqoBackendError;
+use qoqo::{convert_into_circuit, convert_into_quantum_program};
 use roqoqo::prelude::*;
 use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister};
 use roqoqo::Circuit;
@@ -41,6 +41,8 @@ use std::collections::HashMap;
 ///
 /// Args:
 ///     device (Union[QRydDevice,TweezerDevice]): The device providing information about the available operations.
+///     number_qubits (Optional[int]): The number of qubits the simulator should use. Defaults to `device.number_qubits()`.
+///     density_matrix (Optional[bool]): Whether the simulator always uses a density matrix instead of a state vector. Defaults to False.
 ///
 /// Raises:
 ///     TypeError: Device Parameter is not QRydDevice or TweezerDevice
@@ -65,18 +67,34 @@ impl SimulatorBackendWrapper {
     /// Args:
     ///     device (TweezerDevice): The device providing information about the available operations.
     ///     number_qubits (int, optional): The number of qubits the simulator should use. Defaults to `device.number_qubits()`.
+    ///     density_matrix (bool, optional): Whether the simulator always uses a density matrix instead of a state vector. Defaults to False.
     ///
     /// Raises:
     ///     TypeError: Device Parameter is not QrydDevice or TweezerDevice
     #[new]
-    #[pyo3(text_signature = "(device, number_qubits, /)")]
-    pub fn new(device: &Bound<PyAny>, number_qubits: Option<usize>) -> PyResult<Self> {
+    #[pyo3(text_signature = "(device, number_qubits, density_matrix, /)")]
+    pub fn new(
+        device: &Bound<PyAny>,
+        number_qubits: Option<usize>,
+        density_matrix: Option<bool>,
+    ) -> PyResult<Self> {
         let device = convert_into_device(device).map_err(|err| {
             PyTypeError::new_err(format!("Device Parameter is not TweezerDevice {:?}", err))
         })?;
-        Ok(Self {
-            internal: SimulatorBackend::new(device, number_qubits),
-        })
+        let internal = if density_matrix.unwrap_or(false) {
+            SimulatorBackend::new_density_matrix(device, number_qubits)
+        } else {
+            SimulatorBackend::new(device, number_qubits)
+        };
+        Ok(Self { internal })
+    }
+
+    /// Return whether the simulator always uses a density matrix instead of a state vector.
+    ///
+    /// Returns:
+    ///     bool: Whether the simulator uses a density matrix.
+    pub fn is_density_matrix(&self) -> bool {
+        self.internal.density_matrix
     }
 
     /// Return a copy of the SimulatorBackend.
@@ -174,6 +192,169 @@ impl SimulatorBackendWrapper {
         })
     }
 
+    /// Set whether the decoherence of the qubits during the gates is simulated.
+    ///
+    /// After every native gate, each active qubit in a tweezer with noise parameters is subject
+    /// to amplitude damping with rate 1/T1, dephasing with rate (1/T2 - 1/(2 T1)) / 2 and the
+    /// depolarising rate of the tweezer, for the gate time of the gate on the device.
+    /// The noise turns the simulation into a density matrix simulation.
+    ///
+    /// Args:
+    ///     noisy (bool): Whether to apply the noise of the tweezers after every gate.
+    #[pyo3(text_signature = "(noisy, /)")]
+    pub fn set_noisy(&mut self, noisy: bool) {
+        self.internal.set_noisy(noisy);
+    }
+
+    /// Return whether the decoherence of the qubits during the gates is simulated.
+    ///
+    /// Returns:
+    ///     bool: Whether the noise of the tweezers is applied after every gate.
+    pub fn is_noisy(&self) -> bool {
+        self.internal.noisy
+    }
+
+    /// Set the number of threads the shots of a circuit are simulated on.
+    ///
+    /// With more than one thread, the shots of a circuit are split evenly between the threads
+    /// and every thread runs its own QuEST simulation. The output registers of the threads are
+    /// concatenated, so registers that are written once per simulation, for example by a
+    /// PragmaGetStateVector, contain one entry per thread.
+    ///
+    /// Args:
+    ///     number_threads (int): The number of threads, 1 simulates all shots sequentially and 0 uses one thread per core.
+    #[pyo3(text_signature = "(number_threads, /)")]
+    pub fn set_number_threads(&mut self, number_threads: usize) {
+        self.internal.set_number_threads(number_threads);
+    }
+
+    /// Return the number of threads the shots of a circuit are simulated on.
+    ///
+    /// Returns:
+    ///     int: The number of threads, 0 uses one thread per core.
+    pub fn number_threads(&self) -> usize {
+        self.internal.number_threads
+    }
+
+    /// Set whether the shots of circuits with terminal measurements are sampled from a single simulation.
+    ///
+    /// A circuit has terminal measurements if it has no classical control, no float or complex
+    /// output registers and no operation acts on a qubit after the qubit has been measured. The
+    /// shots of such a circuit are sampled from its final state instead of simulating the circuit
+    /// once per shot. Enabled by default.
+    ///
+    /// Args:
+    ///     sample_terminal_measurements (bool): Whether to sample the shots of circuits with terminal measurements.
+    #[pyo3(text_signature = "(sample_terminal_measurements, /)")]
+    pub fn set_sample_terminal_measurements(&mut self, sample_terminal_measurements: bool) {
+        self.internal
+            .set_sample_terminal_measurements(sample_terminal_measurements);
+    }
+
+    /// Return whether the shots of circuits with terminal measurements are sampled from a single simulation.
+    ///
+    /// Returns:
+    ///     bool: Whether the shots of circuits with terminal measurements are sampled.
+    pub fn sample_terminal_measurements(&self) -> bool {
+        self.internal.sample_terminal_measurements
+    }
+
+    /// Set the memory limit for the quantum register of a simulation.
+    ///
+    /// Simulations whose register does not fit into the limit are rejected before QuEST
+    /// allocates the register. The memory of the GPU is not queried: with the `simulator-gpu`
+    /// feature the limit has to be set to the memory of the GPU.
+    ///
+    /// Args:
+    ///     memory_limit (Optional[int]): The memory limit in bytes, None for no limit.
+    #[pyo3(text_signature = "(memory_limit, /)")]
+    pub fn set_memory_limit(&mut self, memory_limit: Option<usize>) {
+        self.internal.set_memory_limit(memory_limit);
+    }
+
+    /// Return the memory limit for the quantum register of a simulation.
+    ///
+    /// Returns:
+    ///     Optional[int]: The memory limit in bytes, None for no limit.
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.internal.memory_limit
+    }
+
+    /// Return whether the simulations run on a GPU.
+    ///
+    /// QuEST selects the CPU or the GPU when it is compiled. Builds with the `simulator-gpu`
+    /// feature simulate on a CUDA GPU and can not fall back to the CPU at runtime.
+    ///
+    /// Returns:
+    ///     bool: Whether the simulations run on a GPU.
+    #[staticmethod]
+    pub fn uses_gpu() -> bool {
+        SimulatorBackend::uses_gpu()
+    }
+
+    /// Return the memory of the quantum register of a simulation.
+    ///
+    /// QuEST stores every amplitude of the state vector, or every entry of the density matrix, as
+    /// two 8 byte floating point numbers.
+    ///
+    /// Args:
+    ///     density_matrix (bool): Whether the simulation uses a density matrix instead of a state vector.
+    ///
+    /// Returns:
+    ///     Optional[int]: The memory of the register in bytes, None if it exceeds the addressable memory.
+    #[pyo3(text_signature = "(density_matrix, /)")]
+    pub fn required_memory(&self, density_matrix: bool) -> Option<usize> {
+        self.internal.required_memory(density_matrix)
+    }
+
+    /// Set whether the decoherence of the qubits during shifts and layout switches is simulated.
+    ///
+    /// The active qubits are subject to the noise of their tweezers for the transport time of each
+    /// shift and layout switch, independently of `set_noisy`.
+    ///
+    /// Args:
+    ///     transport_noise (bool): Whether to apply the noise of the tweezers during shifts and layout switches.
+    #[pyo3(text_signature = "(transport_noise, /)")]
+    pub fn set_transport_noise(&mut self, transport_noise: bool) {
+        self.internal.set_transport_noise(transport_noise);
+    }
+
+    /// Return whether the decoherence of the qubits during shifts and layout switches is simulated.
+    ///
+    /// Returns:
+    ///     bool: Whether the noise of the tweezers is applied during shifts and layout switches.
+    pub fn transport_noise(&self) -> bool {
+        self.internal.transport_noise
+    }
+
+    /// Return the accumulated transport time of the shifts and layout switches of a circuit.
+    ///
+    /// A PragmaShiftQubitsTweezers takes its duration on the device at its position in the
+    /// circuit and a PragmaSwitchDeviceLayout the switch time of the new Layout. Shifts and layout
+    /// switches without a configured time do not contribute.
+    ///
+    /// Args:
+    ///     circuit (Circuit): The circuit to accumulate the transport time of.
+    ///
+    /// Returns:
+    ///     float: The accumulated transport time.
+    ///
+    /// Raises:
+    ///     TypeError: Circuit argument cannot be converted to qoqo Circuit
+    ///     ValueError: A device-changing operation of the circuit is rejected by the device.
+    #[pyo3(text_signature = "(circuit, /)")]
+    pub fn transport_time(&self, circuit: &Bound<PyAny>) -> PyResult<f64> {
+        let circuit = convert_into_circuit(circuit).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "Circuit argument cannot be converted to qoqo Circuit {:?}",
+                err
+            ))
+        })?;
+        self.internal
+            .transport_time(&circuit)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
     /// Run a circuit with the QRyd backend.
     ///
     /// A circuit is passed to the backend and executed.
@@ -355,6 +536,39 @@ impl SimulatorBackendWrapper {
                 )
             })
     }
+
+    /// Runs a parametric QuantumProgram for every set of parameter values of a grid.
+    ///
+    /// The circuits of the measurement are compiled once, for every set of parameter values
+    /// only the operations with symbolic parameters are substituted. The values are assigned
+    /// to the input parameter names of the program.
+    ///
+    /// Args:
+    ///     quantumprogram (qoqo.QuantumProgram): The PauliZProduct, CheatedPauliZProduct or Cheated QuantumProgram to run.
+    ///     parameter_grid (List[List[float]]): The values of the input parameters, one list per evaluation.
+    ///
+    /// Returns:
+    ///     List[Optional[Dict[str, float]]]: The expectation values for each set of parameter values.
+    ///
+    /// Raises:
+    ///     TypeError: quantumprogram is not of type qoqo.QuantumProgram.
+    ///     RuntimeError: The number of parameters does not match, a parameter could not be substituted or the simulation failed.
+    #[pyo3(text_signature = "(quantumprogram, parameter_grid, /)")]
+    pub fn run_template(
+        &self,
+        quantumprogram: &Bound<PyAny>,
+        parameter_grid: Vec<Vec<f64>>,
+    ) -> PyResult<Vec<Option<HashMap<String, f64>>>> {
+        let program = convert_into_quantum_program(quantumprogram).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "quantumprogram is not of type qoqo.QuantumProgram {}",
+                err
+            ))
+        })?;
+        self.internal
+            .run_template(&program, &parameter_grid)
+            .map_err(|err| PyRuntimeError::new_err(format!("Running template failed {}", err)))
+    }
 }
 
 /// Convert generic python object to [roqoqo_qryd::SimulatorBackend].
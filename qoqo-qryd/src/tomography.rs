@@ -0,0 +1,228 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! State tomography experiments.
+
+use numpy::{Complex64, PyArray2, ToPyArray};
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use qoqo::{convert_into_circuit, CircuitWrapper};
+use roqoqo::registers::BitOutputRegister;
+use roqoqo_qryd::tomography::{
+    ReconstructionMethod, StateTomography, TomographyBasis, TOMOGRAPHY_READOUT,
+};
+use std::collections::HashMap;
+
+/// Experiment builder for quantum state tomography.
+///
+/// For `n` target qubits, `3^n` measurement settings are generated, measuring every
+/// target qubit in the X, Y or Z basis. The basis rotations only use the native
+/// `RotateX` and `RotateXY` gates.
+///
+/// Args:
+///     qubits (List[int]): The qubits whose state is reconstructed.
+///     number_measurements (int): The number of measurements performed for each measurement setting.
+///
+/// Raises:
+///     ValueError: No qubits, duplicated qubits or no measurements were given.
+#[pyclass(name = "StateTomography", module = "qoqo_qryd")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateTomographyWrapper {
+    /// Internal storage of [roqoqo_qryd::tomography::StateTomography]
+    pub internal: StateTomography,
+}
+
+#[pymethods]
+impl StateTomographyWrapper {
+    /// Creates a new StateTomography experiment.
+    ///
+    /// Args:
+    ///     qubits (List[int]): The qubits whose state is reconstructed.
+    ///     number_measurements (int): The number of measurements performed for each measurement setting.
+    ///
+    /// Returns:
+    ///     StateTomography: The new StateTomography experiment.
+    ///
+    /// Raises:
+    ///     ValueError: No qubits, duplicated qubits or no measurements were given.
+    #[new]
+    #[pyo3(text_signature = "(qubits, number_measurements, /)")]
+    pub fn new(qubits: Vec<usize>, number_measurements: usize) -> PyResult<Self> {
+        Ok(Self {
+            internal: StateTomography::new(qubits, number_measurements)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
+    /// Returns the qubits whose state is reconstructed.
+    ///
+    /// Returns:
+    ///     List[int]: The target qubits.
+    pub fn qubits(&self) -> Vec<usize> {
+        self.internal.qubits().to_vec()
+    }
+
+    /// Returns the number of measurements performed for each measurement setting.
+    ///
+    /// Returns:
+    ///     int: The number of measurements.
+    pub fn number_measurements(&self) -> usize {
+        self.internal.number_measurements()
+    }
+
+    /// Returns the measurement settings of the experiment.
+    ///
+    /// The i-th entry of each setting is the basis ("X", "Y" or "Z") the i-th target qubit is measured in.
+    ///
+    /// Returns:
+    ///     List[List[str]]: The measurement settings.
+    pub fn measurement_settings(&self) -> Vec<Vec<&'static str>> {
+        self.internal
+            .measurement_settings()
+            .iter()
+            .map(|setting| {
+                setting
+                    .iter()
+                    .map(|basis| match basis {
+                        TomographyBasis::X => "X",
+                        TomographyBasis::Y => "Y",
+                        TomographyBasis::Z => "Z",
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the measurement circuits of the experiment.
+    ///
+    /// Each circuit consists of the preparation circuit followed by the basis rotations and
+    /// the measurement of the target qubits into the `ro_tomography` register.
+    ///
+    /// Args:
+    ///     preparation (Circuit): The circuit preparing the state to reconstruct.
+    ///
+    /// Returns:
+    ///     List[Circuit]: The measurement circuits, one per measurement setting.
+    ///
+    /// Raises:
+    ///     TypeError: Preparation cannot be converted to a qoqo Circuit.
+    #[pyo3(text_signature = "($self, preparation, /)")]
+    pub fn measurement_circuits(
+        &self,
+        preparation: &Bound<PyAny>,
+    ) -> PyResult<Vec<CircuitWrapper>> {
+        let preparation = convert_into_circuit(preparation).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "Preparation cannot be converted to qoqo Circuit {:?}",
+                err
+            ))
+        })?;
+        Ok(self
+            .internal
+            .measurement_circuits(&preparation)
+            .into_iter()
+            .map(|internal| CircuitWrapper { internal })
+            .collect())
+    }
+
+    /// Reconstructs the density matrix from the measured bit registers.
+    ///
+    /// Args:
+    ///     registers (List[List[List[bool]]]): The measured bit registers, one per measurement setting.
+    ///     method (Optional[str]): "linear_inversion" (default) or "maximum_likelihood".
+    ///
+    /// Returns:
+    ///     np.ndarray: The reconstructed density matrix.
+    ///
+    /// Raises:
+    ///     ValueError: Unknown method or the registers do not match the measurement settings.
+    #[pyo3(text_signature = "($self, registers, method, /)")]
+    pub fn reconstruct(
+        &self,
+        registers: Vec<BitOutputRegister>,
+        method: Option<&str>,
+    ) -> PyResult<Py<PyArray2<Complex64>>> {
+        let density_matrix = self
+            .internal
+            .reconstruct(&registers, extract_method(method)?)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))?;
+        Python::with_gil(|py| Ok(density_matrix.to_pyarray_bound(py).unbind()))
+    }
+
+    /// Runs the experiment on a backend and reconstructs the density matrix.
+    ///
+    /// Args:
+    ///     backend (Union[SimulatorBackend, APIBackend]): The backend the measurement circuits are run on.
+    ///     preparation (Circuit): The circuit preparing the state to reconstruct.
+    ///     method (Optional[str]): "linear_inversion" (default) or "maximum_likelihood".
+    ///
+    /// Returns:
+    ///     np.ndarray: The reconstructed density matrix.
+    ///
+    /// Raises:
+    ///     TypeError: Preparation cannot be converted to a qoqo Circuit.
+    ///     RuntimeError: Running a measurement circuit failed.
+    ///     ValueError: Unknown method or the reconstruction failed.
+    #[pyo3(text_signature = "($self, backend, preparation, method, /)")]
+    pub fn run(
+        &self,
+        backend: &Bound<PyAny>,
+        preparation: &Bound<PyAny>,
+        method: Option<&str>,
+    ) -> PyResult<Py<PyArray2<Complex64>>> {
+        let method = extract_method(method)?;
+        let mut registers: Vec<BitOutputRegister> = Vec::new();
+        for circuit in self.measurement_circuits(preparation)? {
+            let result = backend.call_method1("run_circuit", (circuit,))?;
+            let bit_registers: HashMap<String, BitOutputRegister> =
+                result.get_item(0)?.extract().map_err(|_| {
+                    PyRuntimeError::new_err("Backend did not return bit registers.")
+                })?;
+            let register = bit_registers.get(TOMOGRAPHY_READOUT).ok_or_else(|| {
+                PyRuntimeError::new_err(format!(
+                    "Backend did not return the {} register.",
+                    TOMOGRAPHY_READOUT
+                ))
+            })?;
+            registers.push(register.clone());
+        }
+        let density_matrix = self
+            .internal
+            .reconstruct(&registers, method)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))?;
+        Python::with_gil(|py| Ok(density_matrix.to_pyarray_bound(py).unbind()))
+    }
+}
+
+fn extract_method(method: Option<&str>) -> PyResult<ReconstructionMethod> {
+    match method.unwrap_or("linear_inversion") {
+        "linear_inversion" => Ok(ReconstructionMethod::LinearInversion),
+        "maximum_likelihood" => Ok(ReconstructionMethod::MaximumLikelihood),
+        other => Err(PyValueError::new_err(format!(
+            "Unknown reconstruction method {}. Use linear_inversion or maximum_likelihood.",
+            other
+        ))),
+    }
+}
+
+/// State tomography experiments for QRyd devices.
+///
+/// .. autosummary::
+///    :toctree: generated/
+///
+///    StateTomography
+///
+#[pymodule]
+pub fn tomography(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_class::<StateTomographyWrapper>()?;
+    Ok(())
+}
@@ -10,24 +10,33 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{collections::HashSet, io::Cursor};
+use std::cell::RefCell;
+use std::{
+    collections::{HashMap, HashSet},
+    io::Cursor,
+};
 
 use bincode::{deserialize, serialize};
 use pyo3::{
     exceptions::{PyTypeError, PyValueError},
     prelude::*,
-    types::{IntoPyDict, PyByteArray},
+    types::{IntoPyDict, PyByteArray, PyDict},
 };
 
-use qoqo::{devices::GenericDeviceWrapper, QoqoBackendError};
+use qoqo::{
+    convert_into_circuit, devices::GenericDeviceWrapper, operations::convert_pyany_to_operation,
+    CircuitWrapper, QoqoBackendError,
+};
 use qoqo_calculator_pyo3::convert_into_calculator_float;
 use roqoqo::devices::Device;
+use roqoqo::operations::Operation;
 
 use roqoqo_qryd::tweezer_devices::{
-    ALLOWED_NATIVE_SINGLE_QUBIT_GATES, ALLOWED_NATIVE_THREE_QUBIT_GATES,
-    ALLOWED_NATIVE_TWO_QUBIT_GATES,
+    DrawOptions, NativeGates, PhiThetaRelation, TrivialMappingPolicy,
 };
-use roqoqo_qryd::{QRydAPIDevice, TweezerDevice};
+use roqoqo_qryd::{upgrade_circuit, DeviceFileFormat, QRydAPIDevice, QRydDevice, TweezerDevice};
+
+use crate::pragma_operations::{PragmaMoveToZoneWrapper, PragmaShiftQubitsTweezersWrapper};
 
 /// Tweezer Device
 ///
@@ -113,6 +122,65 @@ impl TweezerDeviceWrapper {
         Ok(Self { internal: rust_dev })
     }
 
+    /// Creates a new TweezerDevice from a calibration file.
+    ///
+    /// The calibration file is a JSON document, or a YAML document if the file extension is
+    /// `.yaml` or `.yml`, containing the gate times, gate fidelities, allowed shifts, tweezer
+    /// coordinates and tweezers per row of each Layout. Every Layout of the file is added to
+    /// the device, the default Layout is set as the current Layout.
+    ///
+    /// Args:
+    ///     path (str): The path of the calibration file.
+    ///
+    /// Returns:
+    ///     TweezerDevice: The device described by the calibration file.
+    ///
+    /// Raises:
+    ///     ValueError: The file could not be read or parsed, or a field is invalid.
+    #[staticmethod]
+    #[pyo3(text_signature = "(path, /)")]
+    pub fn from_calibration_file(path: &str) -> PyResult<TweezerDeviceWrapper> {
+        Ok(TweezerDeviceWrapper {
+            internal: TweezerDevice::from_calibration_file(path)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
+    /// Loads a TweezerDevice from a file written by `to_file`.
+    ///
+    /// Args:
+    ///     path (str): The path of the file.
+    ///     format (Optional[str]): The format of the file, "json", "bincode" or "yaml". Default: inferred from the extension, `.json`, `.bin`, `.bincode`, `.yaml` or `.yml`.
+    ///
+    /// Returns:
+    ///     TweezerDevice: The device stored in the file.
+    ///
+    /// Raises:
+    ///     ValueError: The format is unknown or the file could not be read or deserialized.
+    #[staticmethod]
+    #[pyo3(text_signature = "(path, format, /)")]
+    pub fn from_file(path: &str, format: Option<&str>) -> PyResult<TweezerDeviceWrapper> {
+        Ok(TweezerDeviceWrapper {
+            internal: TweezerDevice::from_file(path, device_file_format(format)?)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
+    /// Saves the TweezerDevice to a file.
+    ///
+    /// Args:
+    ///     path (str): The path of the file.
+    ///     format (Optional[str]): The format of the file, "json", "bincode" or "yaml". Default: inferred from the extension, `.json`, `.bin`, `.bincode`, `.yaml` or `.yml`.
+    ///
+    /// Raises:
+    ///     ValueError: The format is unknown or the device could not be serialized or written.
+    #[pyo3(text_signature = "(path, format, /)")]
+    pub fn to_file(&self, path: &str, format: Option<&str>) -> PyResult<()> {
+        self.internal
+            .to_file(path, device_file_format(format)?)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
     /// Creates a new TweezerDevice instance containing populated tweezer data.
     ///
     /// This requires a valid QRYD_API_TOKEN. Visit `https://thequantumlaend.de/get-access/` to get one.
@@ -170,13 +238,46 @@ impl TweezerDeviceWrapper {
     /// Args:
     ///     layout_number (str): The number index of the new Layout.
     ///     with_trivial_map (bool): Whether the qubit -> tweezer mapping should be trivially populated. Defaults to true.
+    ///     mapping_policy (Optional[str]): The policy populating the qubit -> tweezer mapping, one of
+    ///         `all-tweezers`, `usable-tweezers`, `occupied-first` or `none`. Overrides `with_trivial_map`.
     ///
     /// Raises:
     ///     PyValueError
-    #[pyo3(text_signature = "(name, with_trivial_map, /)")]
-    pub fn switch_layout(&mut self, name: &str, with_trivial_map: Option<bool>) -> PyResult<()> {
+    #[pyo3(text_signature = "(name, with_trivial_map, mapping_policy, /)")]
+    pub fn switch_layout(
+        &mut self,
+        name: &str,
+        with_trivial_map: Option<bool>,
+        mapping_policy: Option<&str>,
+    ) -> PyResult<()> {
+        match mapping_policy {
+            Some(policy) => self
+                .internal
+                .switch_layout_with_policy(name, trivial_mapping_policy(policy)?),
+            None => self.internal.switch_layout(name, with_trivial_map),
+        }
+        .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Switch to a different pre-defined Layout with an explicit qubit -> tweezer mapping.
+    ///
+    /// The mapping replaces the current qubit -> tweezer mapping. It is validated against the
+    /// new Layout before switching, the device is left unchanged if the validation fails.
+    ///
+    /// Args:
+    ///     name (str): The name of the new Layout.
+    ///     qubit_to_tweezer (Dict[int, int]): The qubit -> tweezer mapping to use in the new Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The Layout is not set, a tweezer of the mapping is not present in the Layout or several qubits are mapped to the same tweezer.
+    #[pyo3(text_signature = "(name, qubit_to_tweezer, /)")]
+    pub fn switch_layout_with_mapping(
+        &mut self,
+        name: &str,
+        qubit_to_tweezer: HashMap<usize, usize>,
+    ) -> PyResult<()> {
         self.internal
-            .switch_layout(name, with_trivial_map)
+            .switch_layout_with_mapping(name, qubit_to_tweezer)
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
@@ -188,746 +289,639 @@ impl TweezerDeviceWrapper {
         self.internal.available_layouts()
     }
 
-    /// Modifies the qubit -> tweezer mapping of the device.
-    ///
-    /// If a qubit -> tweezer mapping is already present, it is overwritten.
+    /// Returns the capability manifest of the device as a compact JSON document.
     ///
-    /// Args:
-    ///     qubit (int): The index of the qubit.
-    ///     tweezer (int): The index of the tweezer.
+    /// The manifest summarizes the gates, connectivity and limits of the current Layout, the
+    /// phase relations and the noise parameters. The schema is versioned by the
+    /// `schema_version` field.
     ///
     /// Returns:
-    ///     dict[int, int]: The updated qubit -> tweezer mapping.
-    ///     
+    ///     str: The JSON capability manifest.
+    ///
     /// Raises:
-    ///     ValueError: The tweezer is not present in the device.
-    #[pyo3(text_signature = "(qubit, tweezer, /)")]
-    pub fn add_qubit_tweezer_mapping(
-        &mut self,
-        qubit: usize,
-        tweezer: usize,
-    ) -> PyResult<PyObject> {
-        Python::with_gil(|py| -> PyResult<PyObject> {
-            match self.internal.add_qubit_tweezer_mapping(qubit, tweezer) {
-                Ok(mapping) => Ok(mapping.into_py_dict_bound(py).into()),
-                Err(err) => Err(PyValueError::new_err(format!("{:}", err))),
-            }
-        })
+    ///     ValueError: The manifest could not be created.
+    pub fn capability_manifest(&self) -> PyResult<String> {
+        self.internal
+            .capability_manifest()
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Get the qubit -> tweezer mapping of the device.
+    /// Set the number of gate time lookups memoized by the device.
+    ///
+    /// The gate times are cached for the current layout and the tweezers the gates act on, the
+    /// qubit -> tweezer mapping is resolved on every lookup. The cache is emptied whenever the
+    /// gate times or the current layout of the device are changed.
+    ///
+    /// Args:
+    ///     capacity (int): The maximal number of cached gate times, zero disables the cache.
+    #[pyo3(text_signature = "(capacity, /)")]
+    pub fn set_gate_time_cache_capacity(&mut self, capacity: usize) {
+        self.internal.set_gate_time_cache_capacity(capacity)
+    }
+
+    /// Returns the maximal number of cached gate times.
     ///
     /// Returns:
-    ///     dict[int, int]: The qubit -> tweezer mapping.
-    ///     None: The mapping is empty.
-    pub fn get_qubit_to_tweezer_mapping(&self) -> Option<PyObject> {
-        Python::with_gil(|py| -> Option<PyObject> {
-            self.internal
-                .qubit_to_tweezer
-                .as_ref()
-                .map(|mapping| mapping.into_py_dict_bound(py).into())
-        })
+    ///     int: The capacity of the cache, zero if the cache is disabled.
+    pub fn gate_time_cache_capacity(&self) -> usize {
+        self.internal.gate_time_cache_capacity()
     }
 
-    /// Get the names of the available gates in the given layout.
+    /// Remove all cached gate times.
+    pub fn clear_gate_time_cache(&mut self) {
+        self.internal.clear_gate_time_cache()
+    }
+
+    /// Applies a partial update of the device, e.g. a calibration delta served by the WebAPI.
     ///
-    /// Args:
-    ///     layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
+    /// The update is a JSON patch (RFC 6902) against the JSON serialization of the device
+    /// returned by `to_json`. The device is only changed if the whole patch could be applied.
     ///
-    /// Returns:
-    ///     list[str]: List of the names of the available gates in the given layout.
+    /// Args:
+    ///     json_patch (str): The JSON patch, an array of operations.
     ///
     /// Raises:
-    ///     ValueError: No layout name provided and no current layout set.
-    #[pyo3(text_signature = "(layout_name, /)")]
-    pub fn get_available_gates_names(&self, layout_name: Option<String>) -> PyResult<Vec<&str>> {
+    ///     ValueError: The patch is invalid, could not be applied or results in an invalid device.
+    #[pyo3(text_signature = "(json_patch, /)")]
+    pub fn apply_update(&mut self, json_patch: &str) -> PyResult<()> {
         self.internal
-            .get_available_gates_names(layout_name)
+            .apply_update(json_patch)
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Get whether the device allows PragmaActiveReset operations or not.
+    /// Returns the (x, y) coordinates of the tweezers of a given Layout.
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
     ///
     /// Returns:
-    ///     bool: Whether the device allows PragmaActiveReset operations or not.
-    pub fn get_allow_reset(&self) -> bool {
-        self.internal.allow_reset
+    ///     dict[int, (float, float)]: The coordinates of the tweezers that have coordinates.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn tweezer_coordinates(
+        &self,
+        layout_name: Option<String>,
+    ) -> PyResult<HashMap<usize, (f64, f64)>> {
+        self.internal
+            .tweezer_coordinates(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Deactivate the given qubit in the device.
+    /// Infers the tweezers per row of a given Layout.
+    ///
+    /// If all tweezers of the Layout have coordinates, the rows are the tweezers with the same
+    /// y coordinate, ordered by increasing y. Otherwise, if every tweezer can be shifted or
+    /// shifted into, the rows are the groups of tweezers connected by allowed shifts, ordered
+    /// by their smallest tweezer. Dynamic layout switching uses the inferred tweezers per row
+    /// of Layouts without tweezers per row set.
     ///
     /// Args:
-    ///     qubit (int): The input qubit identifier.
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
     ///
     /// Returns:
-    ///     dict[int, int]: The updated qubit -> tweezer mapping.
+    ///     Optional[List[int]]: The inferred number of tweezers per row, None if the Layout has neither coordinates nor shifts for all of its tweezers.
     ///
     /// Raises:
-    ///     PyValueError: If the given qubit identifier is not present in the mapping.
-    #[pyo3(text_signature = "(qubit, /)")]
-    pub fn deactivate_qubit(&mut self, qubit: usize) -> PyResult<PyObject> {
-        Python::with_gil(|py| -> PyResult<PyObject> {
-            match self.internal.deactivate_qubit(qubit) {
-                Ok(tweezers) => Ok(tweezers.into_py_dict_bound(py).into()),
-                Err(err) => Err(PyValueError::new_err(format!("{:}", err))),
-            }
-        })
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn infer_tweezers_per_row(
+        &self,
+        layout_name: Option<String>,
+    ) -> PyResult<Option<Vec<usize>>> {
+        self.internal
+            .infer_tweezers_per_row(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Returns the gate time of a single qubit operation on this device.
+    /// Returns the zone labels of the tweezers of a given Layout.
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
     ///
     /// Returns:
-    ///     float: The gate time.
+    ///     dict[int, str]: The zone labels of the tweezers that have a zone.
     ///
     /// Raises:
-    ///     ValueError: The gate is not available in the device.
-    #[pyo3(text_signature = "(hqslang, qubit, /)")]
-    pub fn single_qubit_gate_time(&self, hqslang: &str, qubit: usize) -> PyResult<f64> {
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn tweezer_zones(&self, layout_name: Option<String>) -> PyResult<HashMap<usize, String>> {
         self.internal
-            .single_qubit_gate_time(hqslang, &qubit)
-            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+            .tweezer_zones(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Returns the gate time of a two qubit operation on this device.
+    /// Returns the zone label of a tweezer in a given Layout.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
     ///
     /// Returns:
-    ///     float: The gate time.
+    ///     Optional[str]: The zone label of the tweezer, None if the tweezer has no zone.
     ///
     /// Raises:
-    ///     ValueError: The gate is not available in the device.
-    #[pyo3(text_signature = "(hqslang, control, target, /)")]
-    pub fn two_qubit_gate_time(
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(tweezer, layout_name, /)")]
+    pub fn tweezer_zone(
         &self,
-        hqslang: &str,
-        control: usize,
-        target: usize,
-    ) -> PyResult<f64> {
+        tweezer: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<Option<String>> {
         self.internal
-            .two_qubit_gate_time(hqslang, &control, &target)
-            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+            .tweezer_zone(tweezer, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Returns the gate time of a three qubit operation on this device.
+    /// Checks that the two-qubit gates of a given Layout are only defined within the interaction zone.
     ///
-    /// Returns:
-    ///     float: The gate time.
+    /// Layouts without zone labels always pass the check. In a zoned Layout both tweezers of
+    /// every two-qubit gate need to be labelled "interaction".
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
     ///
     /// Raises:
-    ///     ValueError: The gate is not available in the device.
-    #[pyo3(text_signature = "(hqslang, control_0, control_1, target, /)")]
-    pub fn three_qubit_gate_time(
-        &self,
-        hqslang: &str,
-        control_0: usize,
-        control_1: usize,
-        target: usize,
-    ) -> PyResult<f64> {
+    ///     ValueError: A two-qubit gate is defined outside of the interaction zone, no layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn check_zone_constraints(&self, layout_name: Option<String>) -> PyResult<()> {
         self.internal
-            .three_qubit_gate_time(hqslang, &control_0, &control_1, &target)
-            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+            .check_zone_constraints(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Returns the gate time of a multi qubit operation on this device.
+    /// Checks the device for inconsistencies.
     ///
-    /// Returns:
-    ///     float: The gate time.
+    /// All Layouts are checked for invalid gate times, two-qubit gates defined for only one order
+    /// of a tweezer pair, shifts starting from or leading into tweezers that are not present and
+    /// tweezers per row differing from those of the current Layout. The current and the default
+    /// Layout need to be present and the qubits need to be mapped to tweezers of the current Layout.
     ///
-    /// Raises:
-    ///     ValueError: The gate is not available in the device.
-    #[pyo3(text_signature = "(hqslang, qubits, /)")]
-    pub fn multi_qubit_gate_time(&self, hqslang: &str, qubits: Vec<usize>) -> PyResult<f64> {
+    /// Returns:
+    ///     list[str]: The descriptions of all issues found, empty if the device is consistent.
+    pub fn validate(&self) -> Vec<String> {
         self.internal
-            .multi_qubit_gate_time(hqslang, &qubits)
-            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+            .validate()
+            .err()
+            .unwrap_or_default()
+            .iter()
+            .map(|issue| issue.to_string())
+            .collect()
     }
 
-    /// Returns the PhaseShiftedControlledZ phase shift according to the device's relation.
+    /// Returns whether two tweezers of a given Layout can not be driven simultaneously.
+    ///
+    /// Args:
+    ///     tweezer0 (int): The index of the first tweezer.
+    ///     tweezer1 (int): The index of the second tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
     ///
     /// Returns:
-    ///     float: The PhaseShiftedControlledZ phase shift.
+    ///     bool: Whether the tweezers are the same, have been added as a blockaded pair or are within the blockade radius.
     ///
     /// Raises:
-    ///     ValueError: Error in relation selection.
-    pub fn phase_shift_controlled_z(&self) -> PyResult<f64> {
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(tweezer0, tweezer1, layout_name, /)")]
+    pub fn are_tweezers_blockaded(
+        &self,
+        tweezer0: usize,
+        tweezer1: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<bool> {
         self.internal
-            .phase_shift_controlled_z()
-            .ok_or_else(|| PyValueError::new_err("Error in relation selection."))
+            .are_tweezers_blockaded(tweezer0, tweezer1, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Returns the PhaseShiftedControlledPhase phase shift according to the device's relation.
+    /// Returns whether operations can be executed simultaneously in the current Layout.
+    ///
+    /// The operations can be executed in parallel if no tweezer of an operation is blockaded
+    /// with a tweezer of another operation, in particular if no two operations act on the same qubit.
+    ///
+    /// Args:
+    ///     operations (list[Operation]): The operations to execute in parallel.
     ///
     /// Returns:
-    ///     float: The PhaseShiftedControlledPhase phase shift.
+    ///     bool: Whether the operations can be executed in parallel.
     ///
     /// Raises:
-    ///     ValueError: Error in relation selection.
-    #[pyo3(text_signature = "(theta, /)")]
-    pub fn phase_shift_controlled_phase(&self, theta: &Bound<PyAny>) -> PyResult<f64> {
-        let float = if let Ok(conv) = convert_into_calculator_float(theta) {
-            *conv
-                .float()
-                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?
-        } else {
-            theta.extract::<f64>()?
-        };
-
+    ///     TypeError: An input is not an Operation.
+    ///     ValueError: No current Layout is set or a qubit is not mapped to a tweezer.
+    #[pyo3(text_signature = "(operations, /)")]
+    pub fn can_execute_in_parallel(&self, operations: Vec<Bound<PyAny>>) -> PyResult<bool> {
+        let operations = operations
+            .iter()
+            .map(|operation| {
+                convert_pyany_to_operation(operation)
+                    .map_err(|_| PyTypeError::new_err("Input is not an Operation."))
+            })
+            .collect::<PyResult<Vec<Operation>>>()?;
         self.internal
-            .phase_shift_controlled_phase(float)
-            .ok_or_else(|| PyValueError::new_err("Error in relation selection."))
+            .can_execute_in_parallel(&operations)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Returns the gate time of a PhaseShiftedControlledZ operation with the given qubits and phi angle.
+    /// Returns the transport time of shifting a qubit between two tweezers in the current Layout.
     ///
     /// Args:
-    ///     control (int): The control qubit the gate acts on
-    ///     target (int): The target qubit the gate acts on
-    ///     phi (float): The phi angle to be checked.
+    ///     start (int): The tweezer the qubit is shifted out of.
+    ///     end (int): The tweezer the qubit is shifted into.
     ///
     /// Returns:
-    ///     float: The gate time.
+    ///     Optional[float]: The transport time set for the shift, or the number of tweezers passed times the time per site.
+    ///         None if the shift is not allowed or has no transport time.
+    #[pyo3(text_signature = "(start, end, /)")]
+    pub fn shift_time(&self, start: usize, end: usize) -> Option<f64> {
+        self.internal.shift_time(start, end)
+    }
+
+    /// Returns the duration of a PragmaShiftQubitsTweezers operation in the current Layout.
     ///
-    /// Raises:
-    ///     ValueError: The gate is not available on the device.
-    #[pyo3(text_signature = "(control, target, phi, /)")]
-    pub fn gate_time_controlled_z(&self, control: usize, target: usize, phi: f64) -> PyResult<f64> {
-        self.internal
-            .gate_time_controlled_z(&control, &target, phi)
-            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+    /// A shift takes the duration given in the operation or else the transport time of the device.
+    /// Sequential shifts take the sum of the durations of the shifts, parallel shifts the longest duration.
+    ///
+    /// Args:
+    ///     pragma (PragmaShiftQubitsTweezers): The operation.
+    ///
+    /// Returns:
+    ///     Optional[float]: The duration of the operation.
+    ///         None if a shift has neither a duration in the operation nor a transport time in the device.
+    #[pyo3(text_signature = "(pragma, /)")]
+    pub fn shift_duration(&self, pragma: PragmaShiftQubitsTweezersWrapper) -> Option<f64> {
+        self.internal.shift_duration(&pragma.internal)
     }
 
-    /// Returns the gate time of a PhaseShiftedControlledPhase operation with the given qubits and phi and theta angles.
+    /// Resolves a PragmaMoveToZone operation into the shifts applied by the device.
+    ///
+    /// The qubits are moved in the given order. A qubit already in the zone is not moved, any
+    /// other qubit is shifted into the nearest free tweezer of the zone it can reach with one
+    /// allowed shift. The distance is taken from the tweezer coordinates of the current Layout if
+    /// both tweezers have coordinates, otherwise it is the number of tweezers passed.
     ///
     /// Args:
-    ///     control (int): The control qubit the gate acts on
-    ///     target (int): The target qubit the gate acts on
-    ///     phi (float): The phi angle to be checked.
-    ///     theta (float): The theta angle to be checked.
+    ///     pragma (PragmaMoveToZone): The operation.
     ///
     /// Returns:
-    ///     float: The gate time.
+    ///     PragmaShiftQubitsTweezers: The sequential shifts moving the qubits into the zone.
     ///
     /// Raises:
-    ///     ValueError: The gate is not available on the device.
-    #[pyo3(text_signature = "(control, target, phi, theta, /)")]
-    pub fn gate_time_controlled_phase(
+    ///     ValueError: A qubit is not mapped or listed twice, the zone is not present in the current Layout or no free tweezer of the zone can be reached.
+    #[pyo3(text_signature = "(pragma, /)")]
+    pub fn resolve_zone_move(
         &self,
-        control: usize,
-        target: usize,
-        phi: f64,
-        theta: f64,
-    ) -> PyResult<f64> {
+        pragma: PragmaMoveToZoneWrapper,
+    ) -> PyResult<PragmaShiftQubitsTweezersWrapper> {
         self.internal
-            .gate_time_controlled_phase(&control, &target, phi, theta)
-            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+            .resolve_zone_move(&pragma.internal)
+            .map(|internal| PragmaShiftQubitsTweezersWrapper { internal })
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Turns Device into GenericDevice
+    /// Returns the gate time of the global rotation PragmaGlobalRotateXY in the current Layout.
     ///
-    /// Can be used as a generic interface for devices when a boxed dyn trait object cannot be used
-    /// (for example when the interface needs to be serialized)
+    /// Returns:
+    ///     Optional[float]: The global rotation time of the current Layout.
+    ///         None if no global rotation time is set or no current Layout is set.
+    pub fn global_rotation_time(&self) -> Option<f64> {
+        self.internal.global_rotation_time()
+    }
+
+    /// Returns the time to switch the device into the current Layout.
     ///
     /// Returns:
-    ///     GenericDevice: The device in generic representation
+    ///     Optional[float]: The layout switch time of the current Layout.
+    ///         None if no layout switch time is set or no current Layout is set.
+    pub fn layout_switch_time(&self) -> Option<f64> {
+        self.internal.layout_switch_time()
+    }
+
+    /// Checks whether a PragmaShiftQubitsTweezers operation can be applied to the device.
     ///
-    /// Note:
-    ///     GenericDevice uses nested HashMaps to represent the most general device connectivity.
-    ///     The memory usage will be inefficient for devices with large qubit numbers.
-    fn generic_device(&self) -> GenericDeviceWrapper {
-        GenericDeviceWrapper {
-            internal: self.internal.to_generic_device(),
-        }
+    /// Sequential shifts are checked in order, each shift sees the qubits moved by the previous ones.
+    /// Parallel shifts all see the qubits before the operation and their paths may not cross.
+    /// The durations, if given, must be one non-negative duration per shift.
+    /// The device is not changed.
+    ///
+    /// Args:
+    ///     pragma (PragmaShiftQubitsTweezers): The operation to check.
+    ///
+    /// Raises:
+    ///     ValueError: Describes the first shift that is not valid and why.
+    #[pyo3(text_signature = "(pragma, /)")]
+    pub fn validate_pragma(&self, pragma: PragmaShiftQubitsTweezersWrapper) -> PyResult<()> {
+        self.internal
+            .validate_pragma(&pragma.internal)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Return a copy of the TweezerDevice (copy here produces a deepcopy).
+    /// Returns the error rate of a single-qubit gate on a qubit in the current Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of the gate.
+    ///     qubit (int): The qubit the gate acts on.
     ///
     /// Returns:
-    ///     TweezerDevice: A deep copy of self.
-    pub fn __copy__(&self) -> TweezerDeviceWrapper {
-        self.clone()
+    ///     Optional[float]: The error rate of the gate, None if no error rate is set.
+    #[pyo3(text_signature = "(hqslang, qubit, /)")]
+    pub fn single_qubit_gate_error(&self, hqslang: &str, qubit: usize) -> Option<f64> {
+        self.internal.single_qubit_gate_error(hqslang, &qubit)
     }
 
-    /// Return a deep copy of the TweezerDevice.
+    /// Returns the error rate of a two-qubit gate on two qubits in the current Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of the gate.
+    ///     control (int): The control qubit the gate acts on.
+    ///     target (int): The target qubit the gate acts on.
     ///
     /// Returns:
-    ///     TweezerDevice: A deep copy of self.
-    pub fn __deepcopy__(&self, _memodict: Py<PyAny>) -> TweezerDeviceWrapper {
-        self.clone()
+    ///     Optional[float]: The error rate of the gate, None if no error rate is set.
+    #[pyo3(text_signature = "(hqslang, control, target, /)")]
+    pub fn two_qubit_gate_error(
+        &self,
+        hqslang: &str,
+        control: usize,
+        target: usize,
+    ) -> Option<f64> {
+        self.internal
+            .two_qubit_gate_error(hqslang, &control, &target)
     }
 
-    /// Return the bincode representation of the TweezerDevice using the bincode crate.
+    /// Returns the error rate of a three-qubit gate on three qubits in the current Layout.
     ///
-    /// Returns:
-    ///     ByteArray: The serialized TweezerDevice (in bincode form).
+    /// Args:
+    ///     hqslang (str): The hqslang name of the gate.
+    ///     control_0 (int): The first control qubit the gate acts on.
+    ///     control_1 (int): The second control qubit the gate acts on.
+    ///     target (int): The target qubit the gate acts on.
     ///
-    /// Raises:
-    ///     ValueError: Cannot serialize TweezerDevice to bytes.
-    pub fn to_bincode(&self) -> PyResult<Py<PyByteArray>> {
-        let serialized = serialize(&self.internal)
-            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerDevice to bytes"))?;
-        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
-            PyByteArray::new_bound(py, &serialized[..]).into()
-        });
-        Ok(b)
+    /// Returns:
+    ///     Optional[float]: The error rate of the gate, None if no error rate is set.
+    #[pyo3(text_signature = "(hqslang, control_0, control_1, target, /)")]
+    pub fn three_qubit_gate_error(
+        &self,
+        hqslang: &str,
+        control_0: usize,
+        control_1: usize,
+        target: usize,
+    ) -> Option<f64> {
+        self.internal
+            .three_qubit_gate_error(hqslang, &control_0, &control_1, &target)
     }
 
-    /// Convert the bincode representation of the TweezerDevice to a TweezerDevice using the bincode crate.
+    /// Modifies the qubit -> tweezer mapping of the device.
+    ///
+    /// If a qubit -> tweezer mapping is already present, it is overwritten.
     ///
     /// Args:
-    ///     input (ByteArray): The serialized TweezerDevice (in bincode form).
+    ///     qubit (int): The index of the qubit.
+    ///     tweezer (int): The index of the tweezer.
     ///
     /// Returns:
-    ///     TweezerDevice: The deserialized TweezerDevice.
-    ///
+    ///     dict[int, int]: The updated qubit -> tweezer mapping.
+    ///     
     /// Raises:
-    ///     TypeError: Input cannot be converted to byte array.
-    ///     ValueError: Input cannot be deserialized to TweezerDevice.
-    #[staticmethod]
-    #[pyo3(text_signature = "(input, /)")]
-    pub fn from_bincode(input: &Bound<PyAny>) -> PyResult<TweezerDeviceWrapper> {
-        let bytes = input
-            .extract::<Vec<u8>>()
-            .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
-
-        Ok(TweezerDeviceWrapper {
-            internal: deserialize(&bytes[..]).map_err(|_| {
-                PyValueError::new_err("Input cannot be deserialized to TweezerDevice")
-            })?,
+    ///     ValueError: The tweezer is not present in the device.
+    #[pyo3(text_signature = "(qubit, tweezer, /)")]
+    pub fn add_qubit_tweezer_mapping(
+        &mut self,
+        qubit: usize,
+        tweezer: usize,
+    ) -> PyResult<PyObject> {
+        Python::with_gil(|py| -> PyResult<PyObject> {
+            match self.internal.add_qubit_tweezer_mapping(qubit, tweezer) {
+                Ok(mapping) => Ok(mapping.into_py_dict_bound(py).into()),
+                Err(err) => Err(PyValueError::new_err(format!("{:}", err))),
+            }
         })
     }
 
-    /// Return the json representation of the TweezerDevice.
-    ///
-    /// Additionally, a gate set check is performed.
+    /// Get the qubit -> tweezer mapping of the device.
     ///
     /// Returns:
-    ///     str: The serialized form of TweezerDevice.
-    ///
-    /// Raises:
-    ///     ValueError: Cannot serialize TweezerDevice to json or
-    ///         the device does not have valid QRyd gates available.
-    fn to_json(&self) -> PyResult<String> {
-        let mut all_gates_names: HashSet<&str> = HashSet::new();
-        for layout in self.internal.available_layouts() {
-            all_gates_names.extend(
-                &self
-                    .internal
-                    .get_available_gates_names(Some(layout.to_string()))
-                    .unwrap(),
-            );
-        }
-        if all_gates_names.iter().any(|name| {
-            !ALLOWED_NATIVE_SINGLE_QUBIT_GATES.contains(name)
-                && !ALLOWED_NATIVE_TWO_QUBIT_GATES.contains(name)
-                && !ALLOWED_NATIVE_THREE_QUBIT_GATES.contains(name)
-        }) || all_gates_names.is_empty()
-        {
-            return Err(PyValueError::new_err(
-                "The device does not support valid gates in a layout. ".to_owned()
-                    + "The valid gates are: "
-                    + &ALLOWED_NATIVE_SINGLE_QUBIT_GATES.join(", ")
-                    + ", "
-                    + &ALLOWED_NATIVE_TWO_QUBIT_GATES.join(", ")
-                    + ", "
-                    + &ALLOWED_NATIVE_THREE_QUBIT_GATES.join(", ")
-                    + ".",
-            ));
-        }
-        let serialized = serde_json::to_string(&self.internal)
-            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerDevice to json"))?;
-        Ok(serialized)
+    ///     dict[int, int]: The qubit -> tweezer mapping.
+    ///     None: The mapping is empty.
+    pub fn get_qubit_to_tweezer_mapping(&self) -> Option<PyObject> {
+        Python::with_gil(|py| -> Option<PyObject> {
+            self.internal
+                .qubit_to_tweezer
+                .as_ref()
+                .map(|mapping| mapping.into_py_dict_bound(py).into())
+        })
     }
 
-    /// Convert the json representation of a TweezerDevice to a TweezerDevice.
-    ///
-    /// If a default_layout is found in the input, a layout switch is executed.
-    /// Additionally, a gate set check is performed.
+    /// Returns whether a qubit is mapped to the given tweezer.
     ///
     /// Args:
-    ///     input (str): The serialized TweezerDevice in json form.
+    ///     tweezer (int): The index of the tweezer.
     ///
     /// Returns:
-    ///     TweezerDevice: The deserialized TweezerDevice.
+    ///     bool: Whether the tweezer holds a qubit according to the qubit -> tweezer mapping.
+    #[pyo3(text_signature = "(tweezer, /)")]
+    pub fn is_tweezer_occupied(&self, tweezer: usize) -> bool {
+        self.internal.is_tweezer_occupied(tweezer)
+    }
+
+    /// Returns the tweezers holding a qubit according to the qubit -> tweezer mapping.
     ///
-    /// Raises:
-    ///     ValueError: Input cannot be deserialized to TweezerDevice  or
-    ///         the device does not have valid QRyd gates available.
-    #[staticmethod]
-    #[pyo3(text_signature = "(input, /)")]
-    fn from_json(input: &str) -> PyResult<TweezerDeviceWrapper> {
-        let mut internal: TweezerDevice = serde_json::from_str(input)
-            .map_err(|_| PyValueError::new_err("Input cannot be deserialized to TweezerDevice"))?;
-        let mut all_gates_names: HashSet<&str> = HashSet::new();
-        for layout in internal.available_layouts() {
-            all_gates_names.extend(
-                &internal
-                    .get_available_gates_names(Some(layout.to_string()))
-                    .unwrap(),
-            );
-        }
-        if all_gates_names.iter().any(|name| {
-            !ALLOWED_NATIVE_SINGLE_QUBIT_GATES.contains(name)
-                && !ALLOWED_NATIVE_TWO_QUBIT_GATES.contains(name)
-                && !ALLOWED_NATIVE_THREE_QUBIT_GATES.contains(name)
-        }) || all_gates_names.is_empty()
-        {
-            return Err(PyValueError::new_err(
-                "The device does not support valid gates in a layout. ".to_owned()
-                    + "The valid gates are: "
-                    + &ALLOWED_NATIVE_SINGLE_QUBIT_GATES.join(", ")
-                    + ", "
-                    + &ALLOWED_NATIVE_TWO_QUBIT_GATES.join(", ")
-                    + ", "
-                    + &ALLOWED_NATIVE_THREE_QUBIT_GATES.join(", ")
-                    + ".",
-            ));
-        }
-        if let Some(layout) = &internal.default_layout {
-            let _ = internal
-                .switch_layout(&layout.to_string(), None)
-                .map_err(|err| PyValueError::new_err(format!("{:}", err)));
-        }
-        Ok(TweezerDeviceWrapper { internal })
+    /// Returns:
+    ///     List[int]: The occupied tweezers, in ascending order.
+    pub fn occupied_tweezers(&self) -> Vec<usize> {
+        self.internal.occupied_tweezers()
     }
 
-    /// Return number of qubits in device.
+    /// Returns the tweezers of a Layout that do not hold a qubit.
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
     ///
     /// Returns:
-    ///     int: The number of qubits.
-    pub fn number_qubits(&self) -> usize {
-        self.internal.number_qubits()
+    ///     List[int]: The free tweezers of the Layout, in ascending order.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn free_tweezers(&self, layout_name: Option<String>) -> PyResult<Vec<usize>> {
+        self.internal
+            .free_tweezers(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Returns the number of total tweezer positions in the device.
+    /// Get the names of the available gates in the given layout.
     ///
     /// Args:
-    ///     layout_name (Optional[str]): The name of the layout to reference. Defaults to the current layout.
+    ///     layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
     ///
     /// Returns:
-    ///     int: The number of tweezer positions in the device.
+    ///     list[str]: List of the names of the available gates in the given layout.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set.
     #[pyo3(text_signature = "(layout_name, /)")]
-    pub fn number_tweezer_positions(&self, layout_name: Option<String>) -> PyResult<usize> {
+    pub fn get_available_gates_names(&self, layout_name: Option<String>) -> PyResult<Vec<&str>> {
         self.internal
-            .number_tweezer_positions(layout_name)
+            .get_available_gates_names(layout_name)
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Return the list of pairs of qubits linked by a native two-qubit-gate in the device.
+    /// Get the names of the gates available on a tweezer in the given layout.
     ///
-    /// A pair of qubits is considered linked by a native two-qubit-gate if the device
-    /// can implement a two-qubit-gate between the two qubits without decomposing it
-    /// into a sequence of gates that involves a third qubit of the device.
-    /// The two-qubit-gate also has to form a universal set together with the available
-    /// single qubit gates.
+    /// Returns the single-qubit gates defined on the tweezer and the two-, three- and multi-qubit
+    /// gates defined on at least one group of tweezers including it.
     ///
-    /// The returned vectors is a simple, graph-library independent, representation of
-    /// the undirected connectivity graph of the device.
-    /// It can be used to construct the connectivity graph in a graph library of the user's
-    /// choice from a list of edges and can be used for applications like routing in quantum algorithms.
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
     ///
     /// Returns:
-    ///     Sequence[(int, int)]: List of two qubit edges in the undirected connectivity graph
-    fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
-        self.internal.two_qubit_edges()
+    ///     list[str]: The sorted names of the gates available on the tweezer.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(tweezer, layout_name, /)")]
+    pub fn get_available_gates_for_tweezer(
+        &self,
+        tweezer: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<Vec<&str>> {
+        self.internal
+            .get_available_gates_for_tweezer(tweezer, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Returns the two tweezer edges of the device.
+    /// Get whether the device allows PragmaActiveReset operations or not.
     ///
-    /// And edge between two tweezer is valid only if the
-    /// PhaseShiftedControlledPhase gate can be performed.
+    /// Returns:
+    ///     bool: Whether the device allows PragmaActiveReset operations or not.
+    pub fn get_allow_reset(&self) -> bool {
+        self.internal.allow_reset
+    }
+
+    /// Get whether setting a two-qubit gate time for a tweezer pair also sets it for the reversed pair.
     ///
     /// Returns:
-    ///     Sequence[(int, int)]: List of two tweezer edges
-    fn two_tweezer_edges(&self) -> Vec<(usize, usize)> {
-        self.internal.two_tweezer_edges()
+    ///     bool: Whether the two-qubit gate times are set symmetrically.
+    pub fn get_symmetric_two_qubit_gate_times(&self) -> bool {
+        self.internal.symmetric_two_qubit_gate_times
     }
 
-    /// Returns the backend associated with the device.
-    pub fn qrydbackend(&self) -> String {
-        self.internal.qrydbackend()
+    /// Get the native gates gate times and error rates can be set for.
+    ///
+    /// Returns:
+    ///     Dict[str, List[str]]: The hqslang names of the native gates under the keys "single_qubit",
+    ///         "two_qubit", "three_qubit" and "multi_qubit".
+    pub fn get_native_gates(&self) -> HashMap<String, Vec<String>> {
+        let native_gates = self.internal.get_native_gates();
+        HashMap::from([
+            (
+                "single_qubit".to_string(),
+                native_gates.single_qubit_gates.clone(),
+            ),
+            (
+                "two_qubit".to_string(),
+                native_gates.two_qubit_gates.clone(),
+            ),
+            (
+                "three_qubit".to_string(),
+                native_gates.three_qubit_gates.clone(),
+            ),
+            (
+                "multi_qubit".to_string(),
+                native_gates.multi_qubit_gates.clone(),
+            ),
+        ])
     }
 
-    /// Returns the seed usized for the API.
-    pub fn seed(&self) -> Option<usize> {
-        self.internal.seed()
+    /// Get the maximal number of qubits the hardware supports.
+    ///
+    /// Returns:
+    ///     Optional[int]: The maximal number of qubits, None if the capacity is not limited.
+    pub fn max_qubits(&self) -> Option<usize> {
+        self.internal.max_qubits
     }
 
-    /// Return the bincode representation of the Enum variant of the Device.
+    /// Deactivate the given qubit in the device.
     ///
-    /// Only used for internal interfacing.
+    /// Args:
+    ///     qubit (int): The input qubit identifier.
     ///
     /// Returns:
-    ///     ByteArray: The serialized TweezerDevice (in [bincode] form).
+    ///     dict[int, int]: The updated qubit -> tweezer mapping.
     ///
     /// Raises:
-    ///     ValueError: Cannot serialize Device to bytes.
-    pub fn _enum_to_bincode(&self) -> PyResult<Py<PyByteArray>> {
-        let qryd_enum: QRydAPIDevice = (&self.internal).into();
-        let serialized = bincode::serialize(&qryd_enum)
-            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerDevice to bytes"))?;
-        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
-            PyByteArray::new_bound(py, &serialized[..]).into()
-        });
-        Ok(b)
+    ///     PyValueError: If the given qubit identifier is not present in the mapping.
+    #[pyo3(text_signature = "(qubit, /)")]
+    pub fn deactivate_qubit(&mut self, qubit: usize) -> PyResult<PyObject> {
+        Python::with_gil(|py| -> PyResult<PyObject> {
+            match self.internal.deactivate_qubit(qubit) {
+                Ok(tweezers) => Ok(tweezers.into_py_dict_bound(py).into()),
+                Err(err) => Err(PyValueError::new_err(format!("{:}", err))),
+            }
+        })
     }
 
-    /// Creates a graph representing a TweezerDevice.
+    /// Exchange the qubits of two occupied tweezers of the current Layout.
     ///
     /// Args:
-    ///     draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
-    ///     pixel_per_point (Optional[float]): The quality of the image.
-    ///     file_save_path (Optional[str]): Path to save the image to. Default: output the image with the display method.
+    ///     tweezer_0 (int): The first tweezer of the exchange.
+    ///     tweezer_1 (int): The second tweezer of the exchange.
     ///
     /// Raises:
-    ///     PyValueError - if there is no layout, an error occurred during the compilation or and invalid path was provided.
-    ///
-    #[pyo3(text_signature = "(draw_shifts, pixel_per_point, file_save_path, /)")]
-    pub fn draw(
-        &self,
-        draw_shifts: Option<bool>,
-        pixel_per_point: Option<f32>,
-        file_save_path: Option<String>,
-    ) -> PyResult<()> {
-        let display_image = file_save_path.is_none();
-        let image = self
-            .internal
-            .draw(
-                pixel_per_point,
-                draw_shifts.unwrap_or(false),
-                &file_save_path,
-            )
-            .map_err(|x| PyValueError::new_err(format!("Error during Circuit drawing: {x:?}")))?;
-
-        if display_image {
-            let mut buffer = Cursor::new(Vec::new());
-            image
-                .write_to(&mut buffer, image::ImageFormat::Png)
-                .map_err(|x| {
-                    PyValueError::new_err(format!(
-                        "Error during the generation of the Png file: {x:?}"
-                    ))
-                })?;
-            Python::with_gil(|py| {
-                let pil = PyModule::import_bound(py, "PIL.Image").unwrap();
-                let io = PyModule::import_bound(py, "io").unwrap();
-                let display = PyModule::import_bound(py, "IPython.display").unwrap();
-                let builtins = PyModule::import_bound(py, "builtins").unwrap();
-
-                let bytes_image_data = builtins
-                    .call_method1("bytes", (buffer.clone().into_inner(),))
-                    .unwrap();
-                let bytes_io = io.call_method1("BytesIO", (bytes_image_data,)).unwrap();
-                let image = pil.call_method1("open", (bytes_io,)).unwrap();
-
-                display.call_method1("display", (image,)).unwrap();
-            });
-        }
-        Ok(())
-    }
-}
-
-/// Tweezer Mutable Device
-///
-/// This interface allows setting any piece of information about the device
-/// tweezer.
-///
-/// Args:
-///     controlled_z_phase_relation ((Optional[Union[str, float]])): The relation to use for the PhaseShiftedControlledZ gate.
-///                                   It can be hardcoded to a specific value if a float is passed in as String.
-///     controlled_phase_phase_relation ((Optional[Union[str, float]])): The relation to use for the PhaseShiftedControlledPhase gate.
-#[pyclass(name = "TweezerMutableDevice", module = "qoqo_qryd")]
-#[derive(Clone, Debug, Default, PartialEq)]
-pub struct TweezerMutableDeviceWrapper {
-    /// Internal storage of [roqoqo_qryd::TweezerDevice]
-    pub internal: TweezerDevice,
-}
-
-#[pymethods]
-impl TweezerMutableDeviceWrapper {
-    /// Creates a new TweezerMutableDevice instance.
-    ///
-    /// Args:
-    ///     seed (int): Optional seed, for simulation purposes.
-    ///     controlled_z_phase_relation (Optional[Union[str, float]]): The relation to use for the PhaseShiftedControlledZ gate.
-    ///     controlled_phase_phase_relation (Optional[Union[str, float]]): The relation to use for the PhaseShiftedControlledPhase gate.
-    ///
-    /// Returns:
-    ///     TweezerMutableDevice: The new TweezerMutableDevice instance.
-    #[new]
-    #[pyo3(
-        text_signature = "(seed, controlled_z_phase_relation, controlled_phase_phase_relation, /)"
-    )]
-    pub fn new(
-        seed: Option<usize>,
-        controlled_z_phase_relation: Option<&Bound<PyAny>>,
-        controlled_phase_phase_relation: Option<&Bound<PyAny>>,
-    ) -> Self {
-        let czpr = if let Some(value) = controlled_z_phase_relation {
-            if convert_into_calculator_float(value).is_ok() {
-                Some(convert_into_calculator_float(value).unwrap().to_string())
-            } else {
-                Some(
-                    controlled_z_phase_relation
-                        .unwrap()
-                        .extract::<String>()
-                        .unwrap(),
-                )
-            }
-        } else {
-            None
-        };
-        let cppr = if let Some(value) = controlled_phase_phase_relation {
-            if convert_into_calculator_float(value).is_ok() {
-                Some(convert_into_calculator_float(value).unwrap().to_string())
-            } else {
-                Some(
-                    controlled_phase_phase_relation
-                        .unwrap()
-                        .extract::<String>()
-                        .unwrap(),
-                )
-            }
-        } else {
-            None
-        };
-        Self {
-            internal: TweezerDevice::new(seed, czpr, cppr),
-        }
-    }
-
-    /// Get the name of the current layout.
-    ///
-    /// Returns:
-    ///     str: The name of the current layout.
-    pub fn current_layout(&self) -> &str {
-        self.internal
-            .current_layout
-            .as_ref()
-            .expect("None")
-            .as_str()
-    }
-
-    /// Add a new layout to the device.
-    ///
-    /// Args:
-    ///     name (str): The name that is assigned to the new Layout.
-    #[pyo3(text_signature = "(name, /)")]
-    pub fn add_layout(&mut self, name: &str) -> PyResult<()> {
-        self.internal
-            .add_layout(name)
-            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
-    }
-
-    /// Switch to a different pre-defined Layout.
-    ///
-    /// It is updated only if the given Layout name is present in the device's
-    /// Layout register. If the qubit -> tweezer mapping is empty, it is
-    /// trivially populated by default.
-    ///
-    /// Args:
-    ///     layout_number (str): The number index of the new Layout.
-    ///     with_trivial_map (bool): Whether the qubit -> tweezer mapping should be trivially populated. Defaults to true.
-    ///
-    /// Raises:
-    ///     PyValueError
-    #[pyo3(text_signature = "(name, with_trivial_map, /)")]
-    pub fn switch_layout(&mut self, name: &str, with_trivial_map: Option<bool>) -> PyResult<()> {
-        self.internal
-            .switch_layout(name, with_trivial_map)
-            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
-    }
-
-    /// Returns a list of all available Layout names.
-    ///
-    /// Returns:
-    ///     List[str]: The list of all available Layout names.
-    pub fn available_layouts(&self) -> Vec<&str> {
-        self.internal.available_layouts()
-    }
-
-    /// Modifies the qubit -> tweezer mapping of the device.
-    ///
-    /// If a qubit -> tweezer mapping is already present, it is overwritten.
+    ///     ValueError: The tweezers are the same, not present in the current Layout or do not hold a qubit.
+    #[pyo3(text_signature = "(tweezer_0, tweezer_1, /)")]
+    pub fn swap_tweezers(&mut self, tweezer_0: usize, tweezer_1: usize) -> PyResult<()> {
+        self.internal
+            .swap_tweezers(tweezer_0, tweezer_1)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Get the noise parameters of the qubit in the given tweezer.
     ///
     /// Args:
-    ///     qubit (int): The index of the qubit.
     ///     tweezer (int): The index of the tweezer.
     ///
     /// Returns:
-    ///     dict[int, int]: The updated qubit -> tweezer mapping.
-    ///     
-    /// Raises:
-    ///     ValueError: The tweezer is not present in the device.
-    #[pyo3(text_signature = "(qubit, tweezer, /)")]
-    pub fn add_qubit_tweezer_mapping(
-        &mut self,
-        qubit: usize,
-        tweezer: usize,
-    ) -> PyResult<PyObject> {
+    ///     dict[str, Optional[float]]: The relaxation time `t1`, the dephasing time `t2` and
+    ///         the `depolarising_rate`, None if not set.
+    #[pyo3(text_signature = "(tweezer, /)")]
+    pub fn get_tweezer_noise(&self, tweezer: usize) -> PyResult<PyObject> {
         Python::with_gil(|py| -> PyResult<PyObject> {
-            match self.internal.add_qubit_tweezer_mapping(qubit, tweezer) {
-                Ok(mapping) => Ok(mapping.into_py_dict_bound(py).into()),
-                Err(err) => Err(PyValueError::new_err(format!("{:}", err))),
-            }
-        })
-    }
-
-    /// Get the qubit -> tweezer mapping of the device.
-    ///
-    /// Returns:
-    ///     dict[int, int]: The qubit -> tweezer mapping.
-    ///     None: The mapping is empty.
-    pub fn get_qubit_to_tweezer_mapping(&self) -> Option<PyObject> {
-        Python::with_gil(|py| -> Option<PyObject> {
-            self.internal
-                .qubit_to_tweezer
-                .as_ref()
-                .map(|mapping| mapping.into_py_dict_bound(py).into())
+            let noise = self.internal.get_tweezer_noise(tweezer);
+            let dict = PyDict::new_bound(py);
+            dict.set_item("t1", noise.t1)?;
+            dict.set_item("t2", noise.t2)?;
+            dict.set_item("depolarising_rate", noise.depolarising_rate)?;
+            Ok(dict.into())
         })
     }
 
-    /// Get the names of the available gates in the given layout.
+    /// Get the probability that the given tweezer is loaded with an atom at the start of a shot.
     ///
     /// Args:
-    ///     layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
+    ///     tweezer (int): The index of the tweezer.
     ///
     /// Returns:
-    ///     list[str]: List of the names of the available gates in the given layout.
-    ///
-    /// Raises:
-    ///     ValueError: No layout name provided and no current layout set.
-    #[pyo3(text_signature = "(layout_name, /)")]
-    pub fn get_available_gates_names(&self, layout_name: Option<String>) -> PyResult<Vec<&str>> {
-        self.internal
-            .get_available_gates_names(layout_name)
-            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    ///     float: The loading probability, 1.0 if not set.
+    #[pyo3(text_signature = "(tweezer, /)")]
+    pub fn loading_probability(&self, tweezer: usize) -> f64 {
+        self.internal.loading_probability(tweezer)
     }
 
-    /// Get whether the device allows PragmaActiveReset operations or not.
+    /// Get the probability that the atom of a qubit is lost when its tweezer is shifted.
     ///
     /// Returns:
-    ///     bool: Whether the device allows PragmaActiveReset operations or not.
-    pub fn get_allow_reset(&self) -> bool {
-        self.internal.allow_reset
+    ///     float: The loss probability per shifted qubit.
+    pub fn shift_loss_probability(&self) -> f64 {
+        self.internal.shift_loss_probability()
     }
 
-    /// Deactivate the given qubit in the device.
-    ///
-    /// Args:
-    ///     qubit (int): The input qubit identifier.
+    /// Get the probability that the atom of a qubit is lost when the qubit is measured.
     ///
     /// Returns:
-    ///     dict[int, int]: The updated qubit -> tweezer mapping.
-    ///
-    /// Raises:
-    ///     PyValueError: If the given qubit identifier is not present in the mapping.
-    #[pyo3(text_signature = "(qubit, /)")]
-    pub fn deactivate_qubit(&mut self, qubit: usize) -> PyResult<PyObject> {
-        Python::with_gil(|py| -> PyResult<PyObject> {
-            match self.internal.deactivate_qubit(qubit) {
-                Ok(tweezers) => Ok(tweezers.into_py_dict_bound(py).into()),
-                Err(err) => Err(PyValueError::new_err(format!("{:}", err))),
-            }
-        })
+    ///     float: The loss probability per measured qubit.
+    pub fn measurement_loss_probability(&self) -> f64 {
+        self.internal.measurement_loss_probability()
     }
 
     /// Returns the gate time of a single qubit operation on this device.
@@ -1032,6 +1026,19 @@ impl TweezerMutableDeviceWrapper {
             .ok_or_else(|| PyValueError::new_err("Error in relation selection."))
     }
 
+    /// Returns phi for the given theta according to a custom or builtin phi-theta relation.
+    ///
+    /// Args:
+    ///     relation_name (str): The name of the relation.
+    ///     theta (float): The theta angle.
+    ///
+    /// Returns:
+    ///     Optional[float]: The phi angle, None if the relation does not exist or is not defined for theta.
+    #[pyo3(text_signature = "(relation_name, theta, /)")]
+    pub fn phi_theta_relation(&self, relation_name: &str, theta: f64) -> Option<f64> {
+        self.internal.phi_theta_relation(relation_name, theta)
+    }
+
     /// Returns the gate time of a PhaseShiftedControlledZ operation with the given qubits and phi angle.
     ///
     /// Args:
@@ -1082,170 +1089,148 @@ impl TweezerMutableDeviceWrapper {
     /// Can be used as a generic interface for devices when a boxed dyn trait object cannot be used
     /// (for example when the interface needs to be serialized)
     ///
+    /// The gate times of the current Layout are translated through the qubit -> tweezer mapping,
+    /// gates on tweezers not holding a qubit are skipped and three-qubit gates are approximated
+    /// by multi-qubit gates on the same qubits.
+    ///
     /// Returns:
     ///     GenericDevice: The device in generic representation
     ///
+    /// Raises:
+    ///     ValueError: No current Layout is set.
+    ///
     /// Note:
     ///     GenericDevice uses nested HashMaps to represent the most general device connectivity.
     ///     The memory usage will be inefficient for devices with large qubit numbers.
-    fn generic_device(&self) -> GenericDeviceWrapper {
-        GenericDeviceWrapper {
-            internal: self.internal.to_generic_device(),
-        }
+    fn generic_device(&self) -> PyResult<GenericDeviceWrapper> {
+        self.internal
+            .try_to_generic_device()
+            .map(|internal| GenericDeviceWrapper { internal })
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Return a copy of the TweezerMutableDevice (copy here produces a deepcopy).
+    /// Turns Device into GenericDevice, failing if gate times of the current Layout would be lost.
+    ///
+    /// Unlike `generic_device` no gate is skipped or approximated.
     ///
     /// Returns:
-    ///     TweezerMutableDevice: A deep copy of self.
-    pub fn __copy__(&self) -> TweezerMutableDeviceWrapper {
+    ///     GenericDevice: The device in generic representation
+    ///
+    /// Raises:
+    ///     ValueError: No current Layout is set, a gate is defined on a tweezer not holding a qubit or a three-qubit gate is defined.
+    fn generic_device_checked(&self) -> PyResult<GenericDeviceWrapper> {
+        self.internal
+            .to_generic_device_checked()
+            .map(|internal| GenericDeviceWrapper { internal })
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Return a copy of the TweezerDevice (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     TweezerDevice: A deep copy of self.
+    pub fn __copy__(&self) -> TweezerDeviceWrapper {
         self.clone()
     }
 
-    /// Return a deep copy of the TweezerMutableDevice.
+    /// Return a deep copy of the TweezerDevice.
     ///
     /// Returns:
-    ///     TweezerMutableDevice: A deep copy of self.
-    pub fn __deepcopy__(&self, _memodict: Py<PyAny>) -> TweezerMutableDeviceWrapper {
+    ///     TweezerDevice: A deep copy of self.
+    pub fn __deepcopy__(&self, _memodict: Py<PyAny>) -> TweezerDeviceWrapper {
         self.clone()
     }
 
-    /// Return the bincode representation of the TweezerMutableDevice using the bincode crate.
+    /// Return the bincode representation of the TweezerDevice using the bincode crate.
     ///
     /// Returns:
-    ///     ByteArray: The serialized TweezerMutableDevice (in bincode form).
+    ///     ByteArray: The serialized TweezerDevice (in bincode form).
     ///
     /// Raises:
-    ///     ValueError: Cannot serialize TweezerMutableDevice to bytes.
+    ///     ValueError: Cannot serialize TweezerDevice to bytes.
     pub fn to_bincode(&self) -> PyResult<Py<PyByteArray>> {
         let serialized = serialize(&self.internal)
-            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerMutableDevice to bytes"))?;
+            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerDevice to bytes"))?;
         let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
             PyByteArray::new_bound(py, &serialized[..]).into()
         });
         Ok(b)
     }
 
-    /// Convert the bincode representation of the TweezerMutableDevice to an
-    /// TweezerMutableDevice using the bincode crate.
+    /// Convert the bincode representation of the TweezerDevice to a TweezerDevice using the bincode crate.
     ///
     /// Args:
-    ///     input (ByteArray): The serialized TweezerMutableDevice (in bincode form).
+    ///     input (ByteArray): The serialized TweezerDevice (in bincode form).
     ///
     /// Returns:
-    ///     TweezerMutableDevice: The deserialized TweezerMutableDevice.
+    ///     TweezerDevice: The deserialized TweezerDevice.
     ///
     /// Raises:
     ///     TypeError: Input cannot be converted to byte array.
-    ///     ValueError: Input cannot be deserialized to TweezerMutableDevice.
+    ///     ValueError: Input cannot be deserialized to TweezerDevice.
     #[staticmethod]
     #[pyo3(text_signature = "(input, /)")]
-    pub fn from_bincode(input: &Bound<PyAny>) -> PyResult<TweezerMutableDeviceWrapper> {
+    pub fn from_bincode(input: &Bound<PyAny>) -> PyResult<TweezerDeviceWrapper> {
         let bytes = input
             .extract::<Vec<u8>>()
             .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
 
-        Ok(TweezerMutableDeviceWrapper {
+        Ok(TweezerDeviceWrapper {
             internal: deserialize(&bytes[..]).map_err(|_| {
-                PyValueError::new_err("Input cannot be deserialized to TweezerMutableDevice")
+                PyValueError::new_err("Input cannot be deserialized to TweezerDevice")
             })?,
         })
     }
 
-    /// Return the json representation of the TweezerMutableDevice.
+    /// Return the json representation of the TweezerDevice.
     ///
     /// Additionally, a gate set check is performed.
     ///
     /// Returns:
-    ///     str: The serialized form of TweezerMutableDevice.
+    ///     str: The serialized form of TweezerDevice.
     ///
     /// Raises:
-    ///     ValueError: Cannot serialize TweezerMutableDevice to json or
+    ///     ValueError: Cannot serialize TweezerDevice to json or
     ///         the device does not have valid QRyd gates available.
     fn to_json(&self) -> PyResult<String> {
-        let mut all_gates_names: HashSet<&str> = HashSet::new();
-        for layout in self.internal.available_layouts() {
-            all_gates_names.extend(
-                &self
-                    .internal
-                    .get_available_gates_names(Some(layout.to_string()))
-                    .unwrap(),
-            );
-        }
-        if all_gates_names.iter().any(|name| {
-            !ALLOWED_NATIVE_SINGLE_QUBIT_GATES.contains(name)
-                && !ALLOWED_NATIVE_TWO_QUBIT_GATES.contains(name)
-                && !ALLOWED_NATIVE_THREE_QUBIT_GATES.contains(name)
-        }) || all_gates_names.is_empty()
-        {
-            return Err(PyValueError::new_err(
-                "The device does not support valid gates in a layout. ".to_owned()
-                    + "The valid gates are: "
-                    + &ALLOWED_NATIVE_SINGLE_QUBIT_GATES.join(", ")
-                    + ", "
-                    + &ALLOWED_NATIVE_TWO_QUBIT_GATES.join(", ")
-                    + ", "
-                    + &ALLOWED_NATIVE_THREE_QUBIT_GATES.join(", ")
-                    + ".",
-            ));
-        }
+        check_native_gates(&self.internal)?;
         let serialized = serde_json::to_string(&self.internal)
-            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerMutableDevice to json"))?;
+            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerDevice to json"))?;
         Ok(serialized)
     }
 
-    /// Convert the json representation of a TweezerMutableDevice to an TweezerMutableDevice.
+    /// Convert the json representation of a TweezerDevice to a TweezerDevice.
     ///
+    /// If a default_layout is found in the input, a layout switch is executed.
     /// Additionally, a gate set check is performed.
     ///
     /// Args:
-    ///     input (str): The serialized TweezerMutableDevice in json form.
+    ///     input (str): The serialized TweezerDevice in json form.
     ///
     /// Returns:
-    ///     TweezerMutableDevice: The deserialized TweezerMutableDevice.
+    ///     TweezerDevice: The deserialized TweezerDevice.
     ///
     /// Raises:
-    ///     ValueError: Input cannot be deserialized to TweezerMutableDevice or
+    ///     ValueError: Input cannot be deserialized to TweezerDevice  or
     ///         the device does not have valid QRyd gates available.
     #[staticmethod]
     #[pyo3(text_signature = "(input, /)")]
-    fn from_json(input: &str) -> PyResult<TweezerMutableDeviceWrapper> {
-        let internal: TweezerDevice = serde_json::from_str(input).map_err(|_| {
-            PyValueError::new_err("Input cannot be deserialized to TweezerMutableDevice")
-        })?;
-        let mut all_gates_names: HashSet<&str> = HashSet::new();
-        for layout in internal.available_layouts() {
-            all_gates_names.extend(
-                &internal
-                    .get_available_gates_names(Some(layout.to_string()))
-                    .unwrap(),
-            );
-        }
-        if all_gates_names.iter().any(|name| {
-            !ALLOWED_NATIVE_SINGLE_QUBIT_GATES.contains(name)
-                && !ALLOWED_NATIVE_TWO_QUBIT_GATES.contains(name)
-                && !ALLOWED_NATIVE_THREE_QUBIT_GATES.contains(name)
-        }) || all_gates_names.is_empty()
-        {
-            return Err(PyValueError::new_err(
-                "The device does not support valid gates in a layout. ".to_owned()
-                    + "The valid gates are: "
-                    + &ALLOWED_NATIVE_SINGLE_QUBIT_GATES.join(", ")
-                    + ", "
-                    + &ALLOWED_NATIVE_TWO_QUBIT_GATES.join(", ")
-                    + ", "
-                    + &ALLOWED_NATIVE_THREE_QUBIT_GATES.join(", ")
-                    + ".",
-            ));
+    fn from_json(input: &str) -> PyResult<TweezerDeviceWrapper> {
+        let mut internal: TweezerDevice = serde_json::from_str(input)
+            .map_err(|_| PyValueError::new_err("Input cannot be deserialized to TweezerDevice"))?;
+        check_native_gates(&internal)?;
+        if let Some(layout) = &internal.default_layout {
+            let _ = internal
+                .switch_layout(&layout.to_string(), None)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)));
         }
-        Ok(TweezerMutableDeviceWrapper { internal })
+        Ok(TweezerDeviceWrapper { internal })
     }
 
     /// Return number of qubits in device.
     ///
     /// Returns:
     ///     int: The number of qubits.
-    ///
     pub fn number_qubits(&self) -> usize {
         self.internal.number_qubits()
     }
@@ -1299,240 +1284,2565 @@ impl TweezerMutableDeviceWrapper {
         self.internal.qrydbackend()
     }
 
-    /// Returns the seed usized for the API.
-    pub fn seed(&self) -> Option<usize> {
-        self.internal.seed()
+    /// Returns the seed usized for the API.
+    pub fn seed(&self) -> Option<usize> {
+        self.internal.seed()
+    }
+
+    /// Return the bincode representation of the Enum variant of the Device.
+    ///
+    /// Only used for internal interfacing.
+    ///
+    /// Returns:
+    ///     ByteArray: The serialized TweezerDevice (in [bincode] form).
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize Device to bytes.
+    pub fn _enum_to_bincode(&self) -> PyResult<Py<PyByteArray>> {
+        let qryd_enum: QRydAPIDevice = (&self.internal).into();
+        let serialized = bincode::serialize(&qryd_enum)
+            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerDevice to bytes"))?;
+        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+            PyByteArray::new_bound(py, &serialized[..]).into()
+        });
+        Ok(b)
+    }
+
+    /// Creates a graph representing a TweezerDevice.
+    ///
+    /// Args:
+    ///     draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+    ///     pixel_per_point (Optional[float]): The quality of the image.
+    ///     file_save_path (Optional[str]): Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics. Default: output the image with the display method.
+    ///     show_tweezer_labels (Optional[bool]): Whether to label the tweezers with their index. Default: true
+    ///     show_qubit_labels (Optional[bool]): Whether to label the tweezers with the qubit they hold. Default: true
+    ///     show_legend (Optional[bool]): Whether to add a legend explaining the labels and edges. Default: false
+    ///     dpi (Optional[float]): The resolution of the image in dots per inch, overrides pixel_per_point.
+    ///
+    /// Raises:
+    ///     PyValueError - if there is no layout, an error occurred during the compilation or and invalid path was provided.
+    ///
+    #[pyo3(
+        text_signature = "(draw_shifts, pixel_per_point, file_save_path, show_tweezer_labels, show_qubit_labels, show_legend, dpi, /)"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw(
+        &self,
+        draw_shifts: Option<bool>,
+        pixel_per_point: Option<f32>,
+        file_save_path: Option<String>,
+        show_tweezer_labels: Option<bool>,
+        show_qubit_labels: Option<bool>,
+        show_legend: Option<bool>,
+        dpi: Option<f32>,
+    ) -> PyResult<()> {
+        let options = DrawOptions {
+            show_tweezer_labels: show_tweezer_labels.unwrap_or(true),
+            show_qubit_labels: show_qubit_labels.unwrap_or(true),
+            show_legend: show_legend.unwrap_or(false),
+            dpi,
+        };
+        let display_image = file_save_path.is_none();
+        let image = self
+            .internal
+            .draw_with_options(
+                pixel_per_point,
+                draw_shifts.unwrap_or(false),
+                &file_save_path,
+                &options,
+            )
+            .map_err(|x| PyValueError::new_err(format!("Error during Circuit drawing: {x:?}")))?;
+
+        if display_image {
+            display_drawing(&image)?;
+        }
+        Ok(())
+    }
+
+    /// Creates a graph showing several Layouts of the TweezerDevice next to each other.
+    ///
+    /// Tweezers whose available gates differ between the Layouts are highlighted.
+    ///
+    /// Args:
+    ///     layout_names (List[str]): The names of the Layouts to draw, from left to right.
+    ///     draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+    ///     pixel_per_point (Optional[float]): The quality of the image.
+    ///     file_save_path (Optional[str]): Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics. Default: output the image with the display method.
+    ///     show_legend (Optional[bool]): Whether to add a legend explaining the labels, edges and highlighted tweezers. Default: false
+    ///
+    /// Raises:
+    ///     ValueError: if no Layout is given, a Layout is not present, an error occurred during the compilation or an invalid path was provided.
+    ///
+    #[pyo3(
+        text_signature = "(layout_names, draw_shifts, pixel_per_point, file_save_path, show_legend, /)"
+    )]
+    pub fn draw_layouts(
+        &self,
+        layout_names: Vec<String>,
+        draw_shifts: Option<bool>,
+        pixel_per_point: Option<f32>,
+        file_save_path: Option<String>,
+        show_legend: Option<bool>,
+    ) -> PyResult<()> {
+        let options = DrawOptions {
+            show_legend: show_legend.unwrap_or(false),
+            ..Default::default()
+        };
+        let layout_names: Vec<&str> = layout_names.iter().map(String::as_str).collect();
+        let image = self
+            .internal
+            .draw_layouts(
+                &layout_names,
+                pixel_per_point,
+                draw_shifts.unwrap_or(false),
+                &file_save_path,
+                &options,
+            )
+            .map_err(|x| PyValueError::new_err(format!("Error during Layout drawing: {x:?}")))?;
+        if file_save_path.is_none() {
+            display_drawing(&image)?;
+        }
+        Ok(())
+    }
+
+    /// Creates one drawing of the TweezerDevice per device-changing step of a circuit.
+    ///
+    /// The first frame shows the device before the circuit. The PragmaChangeDevice operations of
+    /// the circuit, e.g. Layout switches, shifts and deactivations, are applied in order to a copy
+    /// of the device and each adds a frame. The device itself is not changed.
+    ///
+    /// Args:
+    ///     circuit (Circuit): The circuit whose device-changing operations are drawn.
+    ///     file_save_path (str): Path to save the drawings to. A `.gif` file is saved as an animated GIF, otherwise every frame is saved with its index appended to the file name.
+    ///     draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+    ///     pixel_per_point (Optional[float]): The quality of the images.
+    ///     frame_delay_ms (Optional[int]): The time each frame of the animated GIF is shown in milliseconds. Default: 500
+    ///
+    /// Raises:
+    ///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+    ///     ValueError: if there is no layout, a device-changing operation is not valid on the device, an error occurred during the compilation or an invalid path was provided.
+    ///
+    #[pyo3(
+        text_signature = "(circuit, file_save_path, draw_shifts, pixel_per_point, frame_delay_ms, /)"
+    )]
+    pub fn draw_circuit_shifts(
+        &self,
+        circuit: &Bound<PyAny>,
+        file_save_path: String,
+        draw_shifts: Option<bool>,
+        pixel_per_point: Option<f32>,
+        frame_delay_ms: Option<u32>,
+    ) -> PyResult<()> {
+        let circuit = convert_into_circuit(circuit).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "Circuit argument cannot be converted to qoqo Circuit {:?}",
+                err
+            ))
+        })?;
+        self.internal
+            .draw_circuit_shifts(
+                &circuit,
+                pixel_per_point,
+                draw_shifts.unwrap_or(false),
+                &Some(file_save_path),
+                frame_delay_ms.unwrap_or(500),
+                &DrawOptions::default(),
+            )
+            .map_err(|x| PyValueError::new_err(format!("Error during Circuit drawing: {x:?}")))?;
+        Ok(())
+    }
+
+    /// Rewrites the legacy QRyd pragmas of a circuit into the tweezer pragmas.
+    ///
+    /// A PragmaChangeQRydLayout to the layout number `n` becomes a PragmaSwitchDeviceLayout to the
+    /// Layout named `n`. A PragmaShiftQRydQubit becomes a parallel PragmaShiftQubitsTweezers moving
+    /// every qubit to the tweezer of its new position, with the tweezers numbered row by row.
+    /// Legacy pragmas that can not be converted are left unchanged. The device itself is not changed.
+    ///
+    /// Args:
+    ///     circuit (Circuit): The circuit to convert.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit with the convertible legacy pragmas replaced.
+    ///
+    /// Raises:
+    ///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+    #[pyo3(text_signature = "(circuit, /)")]
+    pub fn upgrade_circuit(&self, circuit: &Bound<PyAny>) -> PyResult<CircuitWrapper> {
+        let circuit = convert_into_circuit(circuit).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "Circuit argument cannot be converted to qoqo Circuit {:?}",
+                err
+            ))
+        })?;
+        Ok(CircuitWrapper {
+            internal: upgrade_circuit(&circuit, &self.internal),
+        })
+    }
+}
+
+/// Tweezer Mutable Device
+///
+/// This interface allows setting any piece of information about the device
+/// tweezer.
+///
+/// Args:
+///     controlled_z_phase_relation ((Optional[Union[str, float]])): The relation to use for the PhaseShiftedControlledZ gate.
+///                                   It can be hardcoded to a specific value if a float is passed in as String.
+///     controlled_phase_phase_relation ((Optional[Union[str, float]])): The relation to use for the PhaseShiftedControlledPhase gate.
+#[pyclass(name = "TweezerMutableDevice", module = "qoqo_qryd")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TweezerMutableDeviceWrapper {
+    /// Internal storage of [roqoqo_qryd::TweezerDevice]
+    pub internal: TweezerDevice,
+}
+
+#[pymethods]
+impl TweezerMutableDeviceWrapper {
+    /// Creates a new TweezerMutableDevice instance.
+    ///
+    /// Args:
+    ///     seed (int): Optional seed, for simulation purposes.
+    ///     controlled_z_phase_relation (Optional[Union[str, float]]): The relation to use for the PhaseShiftedControlledZ gate.
+    ///     controlled_phase_phase_relation (Optional[Union[str, float]]): The relation to use for the PhaseShiftedControlledPhase gate.
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: The new TweezerMutableDevice instance.
+    #[new]
+    #[pyo3(
+        text_signature = "(seed, controlled_z_phase_relation, controlled_phase_phase_relation, /)"
+    )]
+    pub fn new(
+        seed: Option<usize>,
+        controlled_z_phase_relation: Option<&Bound<PyAny>>,
+        controlled_phase_phase_relation: Option<&Bound<PyAny>>,
+    ) -> Self {
+        let czpr = if let Some(value) = controlled_z_phase_relation {
+            if convert_into_calculator_float(value).is_ok() {
+                Some(convert_into_calculator_float(value).unwrap().to_string())
+            } else {
+                Some(
+                    controlled_z_phase_relation
+                        .unwrap()
+                        .extract::<String>()
+                        .unwrap(),
+                )
+            }
+        } else {
+            None
+        };
+        let cppr = if let Some(value) = controlled_phase_phase_relation {
+            if convert_into_calculator_float(value).is_ok() {
+                Some(convert_into_calculator_float(value).unwrap().to_string())
+            } else {
+                Some(
+                    controlled_phase_phase_relation
+                        .unwrap()
+                        .extract::<String>()
+                        .unwrap(),
+                )
+            }
+        } else {
+            None
+        };
+        Self {
+            internal: TweezerDevice::new(seed, czpr, cppr),
+        }
+    }
+
+    /// Creates a new TweezerMutableDevice with a square lattice of tweezers.
+    ///
+    /// The device contains the Layout "default", which is set as the current and the default
+    /// Layout. The tweezers are numbered row by row and placed at the coordinates (column, row).
+    /// Single-qubit gates are available on all tweezers, two-qubit gates between horizontally and
+    /// vertically neighbouring tweezers. Qubits can be shifted along the rows.
+    ///
+    /// Args:
+    ///     rows (int): The number of rows of the lattice.
+    ///     columns (int): The number of tweezers per row.
+    ///     gate_times (dict[str, float]): The hqslang names of the single- and two-qubit gates and their gate times.
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: The device with the lattice Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The lattice is empty, no single-qubit gate is given or a gate is not supported.
+    #[staticmethod]
+    #[pyo3(text_signature = "(rows, columns, gate_times, /)")]
+    pub fn square_lattice(
+        rows: usize,
+        columns: usize,
+        gate_times: HashMap<String, f64>,
+    ) -> PyResult<TweezerMutableDeviceWrapper> {
+        Ok(TweezerMutableDeviceWrapper {
+            internal: TweezerDevice::square_lattice(rows, columns, &gate_times)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
+    /// Creates a new TweezerMutableDevice with a triangular lattice of tweezers.
+    ///
+    /// The device contains the Layout "default", which is set as the current and the default
+    /// Layout. The tweezers are numbered row by row, every second row is offset by half a
+    /// tweezer spacing so that each tweezer has up to six neighbours at the same distance.
+    /// Single-qubit gates are available on all tweezers, two-qubit gates between neighbouring
+    /// tweezers. Qubits can be shifted along the rows.
+    ///
+    /// Args:
+    ///     rows (int): The number of rows of the lattice.
+    ///     columns (int): The number of tweezers per row.
+    ///     gate_times (dict[str, float]): The hqslang names of the single- and two-qubit gates and their gate times.
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: The device with the lattice Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The lattice is empty, no single-qubit gate is given or a gate is not supported.
+    #[staticmethod]
+    #[pyo3(text_signature = "(rows, columns, gate_times, /)")]
+    pub fn triangular_lattice(
+        rows: usize,
+        columns: usize,
+        gate_times: HashMap<String, f64>,
+    ) -> PyResult<TweezerMutableDeviceWrapper> {
+        Ok(TweezerMutableDeviceWrapper {
+            internal: TweezerDevice::triangular_lattice(rows, columns, &gate_times)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
+    /// Creates a new TweezerMutableDevice from a FirstDevice of the deprecated `qryd_devices` module.
+    ///
+    /// The current layout of the FirstDevice becomes the Layout "default", which is set as the
+    /// current and the default Layout. The tweezers are numbered row by row and placed at the
+    /// positions of the FirstDevice. The gate times are the ones of the FirstDevice, qubits can be
+    /// shifted along the rows and are mapped to the tweezers of their positions in the FirstDevice.
+    ///
+    /// Args:
+    ///     device (FirstDevice): The FirstDevice to convert.
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: The converted device.
+    ///
+    /// Raises:
+    ///     TypeError: The input is not a FirstDevice.
+    ///     ValueError: The FirstDevice has no tweezers.
+    #[staticmethod]
+    #[pyo3(text_signature = "(device, /)")]
+    pub fn from_first_device(device: &Bound<PyAny>) -> PyResult<TweezerMutableDeviceWrapper> {
+        let QRydDevice::FirstDevice(first_device) =
+            crate::qryd_devices::convert_into_device(device)
+                .map_err(|_| PyTypeError::new_err("Input is not a FirstDevice"))?;
+        Ok(TweezerMutableDeviceWrapper {
+            internal: TweezerDevice::from_first_device(&first_device)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
+    /// Creates a new TweezerMutableDevice from a calibration file.
+    ///
+    /// The calibration file is a JSON document, or a YAML document if the file extension is
+    /// `.yaml` or `.yml`, containing the gate times, gate fidelities, allowed shifts, tweezer
+    /// coordinates and tweezers per row of each Layout. Every Layout of the file is added to
+    /// the device, the default Layout is set as the current Layout.
+    ///
+    /// Args:
+    ///     path (str): The path of the calibration file.
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: The device described by the calibration file.
+    ///
+    /// Raises:
+    ///     ValueError: The file could not be read or parsed, or a field is invalid.
+    #[staticmethod]
+    #[pyo3(text_signature = "(path, /)")]
+    pub fn from_calibration_file(path: &str) -> PyResult<TweezerMutableDeviceWrapper> {
+        Ok(TweezerMutableDeviceWrapper {
+            internal: TweezerDevice::from_calibration_file(path)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
+    /// Loads a TweezerMutableDevice from a file written by `to_file`.
+    ///
+    /// Args:
+    ///     path (str): The path of the file.
+    ///     format (Optional[str]): The format of the file, "json", "bincode" or "yaml". Default: inferred from the extension, `.json`, `.bin`, `.bincode`, `.yaml` or `.yml`.
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: The device stored in the file.
+    ///
+    /// Raises:
+    ///     ValueError: The format is unknown or the file could not be read or deserialized.
+    #[staticmethod]
+    #[pyo3(text_signature = "(path, format, /)")]
+    pub fn from_file(path: &str, format: Option<&str>) -> PyResult<TweezerMutableDeviceWrapper> {
+        Ok(TweezerMutableDeviceWrapper {
+            internal: TweezerDevice::from_file(path, device_file_format(format)?)
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?,
+        })
+    }
+
+    /// Saves the TweezerMutableDevice to a file.
+    ///
+    /// Args:
+    ///     path (str): The path of the file.
+    ///     format (Optional[str]): The format of the file, "json", "bincode" or "yaml". Default: inferred from the extension, `.json`, `.bin`, `.bincode`, `.yaml` or `.yml`.
+    ///
+    /// Raises:
+    ///     ValueError: The format is unknown or the device could not be serialized or written.
+    #[pyo3(text_signature = "(path, format, /)")]
+    pub fn to_file(&self, path: &str, format: Option<&str>) -> PyResult<()> {
+        self.internal
+            .to_file(path, device_file_format(format)?)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Get the name of the current layout.
+    ///
+    /// Returns:
+    ///     str: The name of the current layout.
+    pub fn current_layout(&self) -> &str {
+        self.internal
+            .current_layout
+            .as_ref()
+            .expect("None")
+            .as_str()
+    }
+
+    /// Add a new layout to the device.
+    ///
+    /// Args:
+    ///     name (str): The name that is assigned to the new Layout.
+    #[pyo3(text_signature = "(name, /)")]
+    pub fn add_layout(&mut self, name: &str) -> PyResult<()> {
+        self.internal
+            .add_layout(name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Remove a layout from the device.
+    ///
+    /// The current and the default Layout can not be removed.
+    ///
+    /// Args:
+    ///     name (str): The name of the Layout to remove.
+    ///
+    /// Raises:
+    ///     ValueError: The Layout is not set or is the current or the default Layout.
+    #[pyo3(text_signature = "(name, /)")]
+    pub fn remove_layout(&mut self, name: &str) -> PyResult<()> {
+        self.internal
+            .remove_layout(name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Rename a layout of the device.
+    ///
+    /// The current and the default Layout are updated if they refer to the renamed Layout.
+    ///
+    /// Args:
+    ///     old_name (str): The name of the Layout to rename.
+    ///     new_name (str): The new name of the Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The Layout is not set or the new name is already in use.
+    #[pyo3(text_signature = "(old_name, new_name, /)")]
+    pub fn rename_layout(&mut self, old_name: &str, new_name: &str) -> PyResult<()> {
+        self.internal
+            .rename_layout(old_name, new_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Add a deep copy of a layout to the device under a new name.
+    ///
+    /// The copy can be changed without affecting the source Layout, e.g. to derive a
+    /// recalibrated variant of a Layout by updating some of its gate times.
+    ///
+    /// Args:
+    ///     source (str): The name of the Layout to copy.
+    ///     new_name (str): The name of the new Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The source Layout is not set, could not be deserialized or the new name is already in use.
+    #[pyo3(text_signature = "(source, new_name, /)")]
+    pub fn duplicate_layout(&mut self, source: &str, new_name: &str) -> PyResult<()> {
+        self.internal
+            .duplicate_layout(source, new_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Switch to a different pre-defined Layout.
+    ///
+    /// It is updated only if the given Layout name is present in the device's
+    /// Layout register. If the qubit -> tweezer mapping is empty, it is
+    /// trivially populated by default.
+    ///
+    /// Args:
+    ///     layout_number (str): The number index of the new Layout.
+    ///     with_trivial_map (bool): Whether the qubit -> tweezer mapping should be trivially populated. Defaults to true.
+    ///     mapping_policy (Optional[str]): The policy populating the qubit -> tweezer mapping, one of
+    ///         `all-tweezers`, `usable-tweezers`, `occupied-first` or `none`. Overrides `with_trivial_map`.
+    ///
+    /// Raises:
+    ///     PyValueError
+    #[pyo3(text_signature = "(name, with_trivial_map, mapping_policy, /)")]
+    pub fn switch_layout(
+        &mut self,
+        name: &str,
+        with_trivial_map: Option<bool>,
+        mapping_policy: Option<&str>,
+    ) -> PyResult<()> {
+        match mapping_policy {
+            Some(policy) => self
+                .internal
+                .switch_layout_with_policy(name, trivial_mapping_policy(policy)?),
+            None => self.internal.switch_layout(name, with_trivial_map),
+        }
+        .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Switch to a different pre-defined Layout with an explicit qubit -> tweezer mapping.
+    ///
+    /// The mapping replaces the current qubit -> tweezer mapping. It is validated against the
+    /// new Layout before switching, the device is left unchanged if the validation fails.
+    ///
+    /// Args:
+    ///     name (str): The name of the new Layout.
+    ///     qubit_to_tweezer (Dict[int, int]): The qubit -> tweezer mapping to use in the new Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The Layout is not set, a tweezer of the mapping is not present in the Layout or several qubits are mapped to the same tweezer.
+    #[pyo3(text_signature = "(name, qubit_to_tweezer, /)")]
+    pub fn switch_layout_with_mapping(
+        &mut self,
+        name: &str,
+        qubit_to_tweezer: HashMap<usize, usize>,
+    ) -> PyResult<()> {
+        self.internal
+            .switch_layout_with_mapping(name, qubit_to_tweezer)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns a list of all available Layout names.
+    ///
+    /// Returns:
+    ///     List[str]: The list of all available Layout names.
+    pub fn available_layouts(&self) -> Vec<&str> {
+        self.internal.available_layouts()
+    }
+
+    /// Returns the capability manifest of the device as a compact JSON document.
+    ///
+    /// The manifest summarizes the gates, connectivity and limits of the current Layout, the
+    /// phase relations and the noise parameters. The schema is versioned by the
+    /// `schema_version` field.
+    ///
+    /// Returns:
+    ///     str: The JSON capability manifest.
+    ///
+    /// Raises:
+    ///     ValueError: The manifest could not be created.
+    pub fn capability_manifest(&self) -> PyResult<String> {
+        self.internal
+            .capability_manifest()
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the number of gate time lookups memoized by the device.
+    ///
+    /// The gate times are cached for the current layout and the tweezers the gates act on, the
+    /// qubit -> tweezer mapping is resolved on every lookup. The cache is emptied whenever the
+    /// gate times or the current layout of the device are changed.
+    ///
+    /// Args:
+    ///     capacity (int): The maximal number of cached gate times, zero disables the cache.
+    #[pyo3(text_signature = "(capacity, /)")]
+    pub fn set_gate_time_cache_capacity(&mut self, capacity: usize) {
+        self.internal.set_gate_time_cache_capacity(capacity)
+    }
+
+    /// Returns the maximal number of cached gate times.
+    ///
+    /// Returns:
+    ///     int: The capacity of the cache, zero if the cache is disabled.
+    pub fn gate_time_cache_capacity(&self) -> usize {
+        self.internal.gate_time_cache_capacity()
+    }
+
+    /// Remove all cached gate times.
+    pub fn clear_gate_time_cache(&mut self) {
+        self.internal.clear_gate_time_cache()
+    }
+
+    /// Applies a partial update of the device, e.g. a calibration delta served by the WebAPI.
+    ///
+    /// The update is a JSON patch (RFC 6902) against the JSON serialization of the device
+    /// returned by `to_json`. The device is only changed if the whole patch could be applied.
+    ///
+    /// Args:
+    ///     json_patch (str): The JSON patch, an array of operations.
+    ///
+    /// Raises:
+    ///     ValueError: The patch is invalid, could not be applied or results in an invalid device.
+    #[pyo3(text_signature = "(json_patch, /)")]
+    pub fn apply_update(&mut self, json_patch: &str) -> PyResult<()> {
+        self.internal
+            .apply_update(json_patch)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns the (x, y) coordinates of the tweezers of a given Layout.
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     dict[int, (float, float)]: The coordinates of the tweezers that have coordinates.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn tweezer_coordinates(
+        &self,
+        layout_name: Option<String>,
+    ) -> PyResult<HashMap<usize, (f64, f64)>> {
+        self.internal
+            .tweezer_coordinates(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Infers the tweezers per row of a given Layout.
+    ///
+    /// If all tweezers of the Layout have coordinates, the rows are the tweezers with the same
+    /// y coordinate, ordered by increasing y. Otherwise, if every tweezer can be shifted or
+    /// shifted into, the rows are the groups of tweezers connected by allowed shifts, ordered
+    /// by their smallest tweezer. Dynamic layout switching uses the inferred tweezers per row
+    /// of Layouts without tweezers per row set.
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     Optional[List[int]]: The inferred number of tweezers per row, None if the Layout has neither coordinates nor shifts for all of its tweezers.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn infer_tweezers_per_row(
+        &self,
+        layout_name: Option<String>,
+    ) -> PyResult<Option<Vec<usize>>> {
+        self.internal
+            .infer_tweezers_per_row(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns the zone labels of the tweezers of a given Layout.
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     dict[int, str]: The zone labels of the tweezers that have a zone.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn tweezer_zones(&self, layout_name: Option<String>) -> PyResult<HashMap<usize, String>> {
+        self.internal
+            .tweezer_zones(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns the zone label of a tweezer in a given Layout.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     Optional[str]: The zone label of the tweezer, None if the tweezer has no zone.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(tweezer, layout_name, /)")]
+    pub fn tweezer_zone(
+        &self,
+        tweezer: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<Option<String>> {
+        self.internal
+            .tweezer_zone(tweezer, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Checks that the two-qubit gates of a given Layout are only defined within the interaction zone.
+    ///
+    /// Layouts without zone labels always pass the check. In a zoned Layout both tweezers of
+    /// every two-qubit gate need to be labelled "interaction".
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: A two-qubit gate is defined outside of the interaction zone, no layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn check_zone_constraints(&self, layout_name: Option<String>) -> PyResult<()> {
+        self.internal
+            .check_zone_constraints(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Checks the device for inconsistencies.
+    ///
+    /// All Layouts are checked for invalid gate times, two-qubit gates defined for only one order
+    /// of a tweezer pair, shifts starting from or leading into tweezers that are not present and
+    /// tweezers per row differing from those of the current Layout. The current and the default
+    /// Layout need to be present and the qubits need to be mapped to tweezers of the current Layout.
+    ///
+    /// Returns:
+    ///     list[str]: The descriptions of all issues found, empty if the device is consistent.
+    pub fn validate(&self) -> Vec<String> {
+        self.internal
+            .validate()
+            .err()
+            .unwrap_or_default()
+            .iter()
+            .map(|issue| issue.to_string())
+            .collect()
+    }
+
+    /// Returns whether two tweezers of a given Layout can not be driven simultaneously.
+    ///
+    /// Args:
+    ///     tweezer0 (int): The index of the first tweezer.
+    ///     tweezer1 (int): The index of the second tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     bool: Whether the tweezers are the same, have been added as a blockaded pair or are within the blockade radius.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(tweezer0, tweezer1, layout_name, /)")]
+    pub fn are_tweezers_blockaded(
+        &self,
+        tweezer0: usize,
+        tweezer1: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<bool> {
+        self.internal
+            .are_tweezers_blockaded(tweezer0, tweezer1, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns whether operations can be executed simultaneously in the current Layout.
+    ///
+    /// The operations can be executed in parallel if no tweezer of an operation is blockaded
+    /// with a tweezer of another operation, in particular if no two operations act on the same qubit.
+    ///
+    /// Args:
+    ///     operations (list[Operation]): The operations to execute in parallel.
+    ///
+    /// Returns:
+    ///     bool: Whether the operations can be executed in parallel.
+    ///
+    /// Raises:
+    ///     TypeError: An input is not an Operation.
+    ///     ValueError: No current Layout is set or a qubit is not mapped to a tweezer.
+    #[pyo3(text_signature = "(operations, /)")]
+    pub fn can_execute_in_parallel(&self, operations: Vec<Bound<PyAny>>) -> PyResult<bool> {
+        let operations = operations
+            .iter()
+            .map(|operation| {
+                convert_pyany_to_operation(operation)
+                    .map_err(|_| PyTypeError::new_err("Input is not an Operation."))
+            })
+            .collect::<PyResult<Vec<Operation>>>()?;
+        self.internal
+            .can_execute_in_parallel(&operations)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns the transport time of shifting a qubit between two tweezers in the current Layout.
+    ///
+    /// Args:
+    ///     start (int): The tweezer the qubit is shifted out of.
+    ///     end (int): The tweezer the qubit is shifted into.
+    ///
+    /// Returns:
+    ///     Optional[float]: The transport time set for the shift, or the number of tweezers passed times the time per site.
+    ///         None if the shift is not allowed or has no transport time.
+    #[pyo3(text_signature = "(start, end, /)")]
+    pub fn shift_time(&self, start: usize, end: usize) -> Option<f64> {
+        self.internal.shift_time(start, end)
+    }
+
+    /// Returns the duration of a PragmaShiftQubitsTweezers operation in the current Layout.
+    ///
+    /// A shift takes the duration given in the operation or else the transport time of the device.
+    /// Sequential shifts take the sum of the durations of the shifts, parallel shifts the longest duration.
+    ///
+    /// Args:
+    ///     pragma (PragmaShiftQubitsTweezers): The operation.
+    ///
+    /// Returns:
+    ///     Optional[float]: The duration of the operation.
+    ///         None if a shift has neither a duration in the operation nor a transport time in the device.
+    #[pyo3(text_signature = "(pragma, /)")]
+    pub fn shift_duration(&self, pragma: PragmaShiftQubitsTweezersWrapper) -> Option<f64> {
+        self.internal.shift_duration(&pragma.internal)
+    }
+
+    /// Resolves a PragmaMoveToZone operation into the shifts applied by the device.
+    ///
+    /// The qubits are moved in the given order. A qubit already in the zone is not moved, any
+    /// other qubit is shifted into the nearest free tweezer of the zone it can reach with one
+    /// allowed shift. The distance is taken from the tweezer coordinates of the current Layout if
+    /// both tweezers have coordinates, otherwise it is the number of tweezers passed.
+    ///
+    /// Args:
+    ///     pragma (PragmaMoveToZone): The operation.
+    ///
+    /// Returns:
+    ///     PragmaShiftQubitsTweezers: The sequential shifts moving the qubits into the zone.
+    ///
+    /// Raises:
+    ///     ValueError: A qubit is not mapped or listed twice, the zone is not present in the current Layout or no free tweezer of the zone can be reached.
+    #[pyo3(text_signature = "(pragma, /)")]
+    pub fn resolve_zone_move(
+        &self,
+        pragma: PragmaMoveToZoneWrapper,
+    ) -> PyResult<PragmaShiftQubitsTweezersWrapper> {
+        self.internal
+            .resolve_zone_move(&pragma.internal)
+            .map(|internal| PragmaShiftQubitsTweezersWrapper { internal })
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns the gate time of the global rotation PragmaGlobalRotateXY in the current Layout.
+    ///
+    /// Returns:
+    ///     Optional[float]: The global rotation time of the current Layout.
+    ///         None if no global rotation time is set or no current Layout is set.
+    pub fn global_rotation_time(&self) -> Option<f64> {
+        self.internal.global_rotation_time()
+    }
+
+    /// Returns the time to switch the device into the current Layout.
+    ///
+    /// Returns:
+    ///     Optional[float]: The layout switch time of the current Layout.
+    ///         None if no layout switch time is set or no current Layout is set.
+    pub fn layout_switch_time(&self) -> Option<f64> {
+        self.internal.layout_switch_time()
+    }
+
+    /// Checks whether a PragmaShiftQubitsTweezers operation can be applied to the device.
+    ///
+    /// Sequential shifts are checked in order, each shift sees the qubits moved by the previous ones.
+    /// Parallel shifts all see the qubits before the operation and their paths may not cross.
+    /// The durations, if given, must be one non-negative duration per shift.
+    /// The device is not changed.
+    ///
+    /// Args:
+    ///     pragma (PragmaShiftQubitsTweezers): The operation to check.
+    ///
+    /// Raises:
+    ///     ValueError: Describes the first shift that is not valid and why.
+    #[pyo3(text_signature = "(pragma, /)")]
+    pub fn validate_pragma(&self, pragma: PragmaShiftQubitsTweezersWrapper) -> PyResult<()> {
+        self.internal
+            .validate_pragma(&pragma.internal)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Returns the error rate of a single-qubit gate on a qubit in the current Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of the gate.
+    ///     qubit (int): The qubit the gate acts on.
+    ///
+    /// Returns:
+    ///     Optional[float]: The error rate of the gate, None if no error rate is set.
+    #[pyo3(text_signature = "(hqslang, qubit, /)")]
+    pub fn single_qubit_gate_error(&self, hqslang: &str, qubit: usize) -> Option<f64> {
+        self.internal.single_qubit_gate_error(hqslang, &qubit)
+    }
+
+    /// Returns the error rate of a two-qubit gate on two qubits in the current Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of the gate.
+    ///     control (int): The control qubit the gate acts on.
+    ///     target (int): The target qubit the gate acts on.
+    ///
+    /// Returns:
+    ///     Optional[float]: The error rate of the gate, None if no error rate is set.
+    #[pyo3(text_signature = "(hqslang, control, target, /)")]
+    pub fn two_qubit_gate_error(
+        &self,
+        hqslang: &str,
+        control: usize,
+        target: usize,
+    ) -> Option<f64> {
+        self.internal
+            .two_qubit_gate_error(hqslang, &control, &target)
+    }
+
+    /// Returns the error rate of a three-qubit gate on three qubits in the current Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of the gate.
+    ///     control_0 (int): The first control qubit the gate acts on.
+    ///     control_1 (int): The second control qubit the gate acts on.
+    ///     target (int): The target qubit the gate acts on.
+    ///
+    /// Returns:
+    ///     Optional[float]: The error rate of the gate, None if no error rate is set.
+    #[pyo3(text_signature = "(hqslang, control_0, control_1, target, /)")]
+    pub fn three_qubit_gate_error(
+        &self,
+        hqslang: &str,
+        control_0: usize,
+        control_1: usize,
+        target: usize,
+    ) -> Option<f64> {
+        self.internal
+            .three_qubit_gate_error(hqslang, &control_0, &control_1, &target)
+    }
+
+    /// Modifies the qubit -> tweezer mapping of the device.
+    ///
+    /// If a qubit -> tweezer mapping is already present, it is overwritten.
+    ///
+    /// Args:
+    ///     qubit (int): The index of the qubit.
+    ///     tweezer (int): The index of the tweezer.
+    ///
+    /// Returns:
+    ///     dict[int, int]: The updated qubit -> tweezer mapping.
+    ///     
+    /// Raises:
+    ///     ValueError: The tweezer is not present in the device.
+    #[pyo3(text_signature = "(qubit, tweezer, /)")]
+    pub fn add_qubit_tweezer_mapping(
+        &mut self,
+        qubit: usize,
+        tweezer: usize,
+    ) -> PyResult<PyObject> {
+        Python::with_gil(|py| -> PyResult<PyObject> {
+            match self.internal.add_qubit_tweezer_mapping(qubit, tweezer) {
+                Ok(mapping) => Ok(mapping.into_py_dict_bound(py).into()),
+                Err(err) => Err(PyValueError::new_err(format!("{:}", err))),
+            }
+        })
+    }
+
+    /// Get the qubit -> tweezer mapping of the device.
+    ///
+    /// Returns:
+    ///     dict[int, int]: The qubit -> tweezer mapping.
+    ///     None: The mapping is empty.
+    pub fn get_qubit_to_tweezer_mapping(&self) -> Option<PyObject> {
+        Python::with_gil(|py| -> Option<PyObject> {
+            self.internal
+                .qubit_to_tweezer
+                .as_ref()
+                .map(|mapping| mapping.into_py_dict_bound(py).into())
+        })
+    }
+
+    /// Returns whether a qubit is mapped to the given tweezer.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///
+    /// Returns:
+    ///     bool: Whether the tweezer holds a qubit according to the qubit -> tweezer mapping.
+    #[pyo3(text_signature = "(tweezer, /)")]
+    pub fn is_tweezer_occupied(&self, tweezer: usize) -> bool {
+        self.internal.is_tweezer_occupied(tweezer)
+    }
+
+    /// Returns the tweezers holding a qubit according to the qubit -> tweezer mapping.
+    ///
+    /// Returns:
+    ///     List[int]: The occupied tweezers, in ascending order.
+    pub fn occupied_tweezers(&self) -> Vec<usize> {
+        self.internal.occupied_tweezers()
+    }
+
+    /// Returns the tweezers of a Layout that do not hold a qubit.
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the Layout. Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     List[int]: The free tweezers of the Layout, in ascending order.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn free_tweezers(&self, layout_name: Option<String>) -> PyResult<Vec<usize>> {
+        self.internal
+            .free_tweezers(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Get the names of the available gates in the given layout.
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     list[str]: List of the names of the available gates in the given layout.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn get_available_gates_names(&self, layout_name: Option<String>) -> PyResult<Vec<&str>> {
+        self.internal
+            .get_available_gates_names(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Get the names of the gates available on a tweezer in the given layout.
+    ///
+    /// Returns the single-qubit gates defined on the tweezer and the two-, three- and multi-qubit
+    /// gates defined on at least one group of tweezers including it.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     layout_name (Optional[str]): The name of the layout. Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     list[str]: The sorted names of the gates available on the tweezer.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(tweezer, layout_name, /)")]
+    pub fn get_available_gates_for_tweezer(
+        &self,
+        tweezer: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<Vec<&str>> {
+        self.internal
+            .get_available_gates_for_tweezer(tweezer, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Get whether the device allows PragmaActiveReset operations or not.
+    ///
+    /// Returns:
+    ///     bool: Whether the device allows PragmaActiveReset operations or not.
+    pub fn get_allow_reset(&self) -> bool {
+        self.internal.allow_reset
+    }
+
+    /// Get whether setting a two-qubit gate time for a tweezer pair also sets it for the reversed pair.
+    ///
+    /// Returns:
+    ///     bool: Whether the two-qubit gate times are set symmetrically.
+    pub fn get_symmetric_two_qubit_gate_times(&self) -> bool {
+        self.internal.symmetric_two_qubit_gate_times
+    }
+
+    /// Get the native gates gate times and error rates can be set for.
+    ///
+    /// Returns:
+    ///     Dict[str, List[str]]: The hqslang names of the native gates under the keys "single_qubit",
+    ///         "two_qubit", "three_qubit" and "multi_qubit".
+    pub fn get_native_gates(&self) -> HashMap<String, Vec<String>> {
+        let native_gates = self.internal.get_native_gates();
+        HashMap::from([
+            (
+                "single_qubit".to_string(),
+                native_gates.single_qubit_gates.clone(),
+            ),
+            (
+                "two_qubit".to_string(),
+                native_gates.two_qubit_gates.clone(),
+            ),
+            (
+                "three_qubit".to_string(),
+                native_gates.three_qubit_gates.clone(),
+            ),
+            (
+                "multi_qubit".to_string(),
+                native_gates.multi_qubit_gates.clone(),
+            ),
+        ])
+    }
+
+    /// Get the maximal number of qubits the hardware supports.
+    ///
+    /// Returns:
+    ///     Optional[int]: The maximal number of qubits, None if the capacity is not limited.
+    pub fn max_qubits(&self) -> Option<usize> {
+        self.internal.max_qubits
+    }
+
+    /// Deactivate the given qubit in the device.
+    ///
+    /// Args:
+    ///     qubit (int): The input qubit identifier.
+    ///
+    /// Returns:
+    ///     dict[int, int]: The updated qubit -> tweezer mapping.
+    ///
+    /// Raises:
+    ///     PyValueError: If the given qubit identifier is not present in the mapping.
+    #[pyo3(text_signature = "(qubit, /)")]
+    pub fn deactivate_qubit(&mut self, qubit: usize) -> PyResult<PyObject> {
+        Python::with_gil(|py| -> PyResult<PyObject> {
+            match self.internal.deactivate_qubit(qubit) {
+                Ok(tweezers) => Ok(tweezers.into_py_dict_bound(py).into()),
+                Err(err) => Err(PyValueError::new_err(format!("{:}", err))),
+            }
+        })
+    }
+
+    /// Exchange the qubits of two occupied tweezers of the current Layout.
+    ///
+    /// Args:
+    ///     tweezer_0 (int): The first tweezer of the exchange.
+    ///     tweezer_1 (int): The second tweezer of the exchange.
+    ///
+    /// Raises:
+    ///     ValueError: The tweezers are the same, not present in the current Layout or do not hold a qubit.
+    #[pyo3(text_signature = "(tweezer_0, tweezer_1, /)")]
+    pub fn swap_tweezers(&mut self, tweezer_0: usize, tweezer_1: usize) -> PyResult<()> {
+        self.internal
+            .swap_tweezers(tweezer_0, tweezer_1)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Get the noise parameters of the qubit in the given tweezer.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///
+    /// Returns:
+    ///     dict[str, Optional[float]]: The relaxation time `t1`, the dephasing time `t2` and
+    ///         the `depolarising_rate`, None if not set.
+    #[pyo3(text_signature = "(tweezer, /)")]
+    pub fn get_tweezer_noise(&self, tweezer: usize) -> PyResult<PyObject> {
+        Python::with_gil(|py| -> PyResult<PyObject> {
+            let noise = self.internal.get_tweezer_noise(tweezer);
+            let dict = PyDict::new_bound(py);
+            dict.set_item("t1", noise.t1)?;
+            dict.set_item("t2", noise.t2)?;
+            dict.set_item("depolarising_rate", noise.depolarising_rate)?;
+            Ok(dict.into())
+        })
+    }
+
+    /// Get the probability that the given tweezer is loaded with an atom at the start of a shot.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///
+    /// Returns:
+    ///     float: The loading probability, 1.0 if not set.
+    #[pyo3(text_signature = "(tweezer, /)")]
+    pub fn loading_probability(&self, tweezer: usize) -> f64 {
+        self.internal.loading_probability(tweezer)
+    }
+
+    /// Get the probability that the atom of a qubit is lost when its tweezer is shifted.
+    ///
+    /// Returns:
+    ///     float: The loss probability per shifted qubit.
+    pub fn shift_loss_probability(&self) -> f64 {
+        self.internal.shift_loss_probability()
+    }
+
+    /// Get the probability that the atom of a qubit is lost when the qubit is measured.
+    ///
+    /// Returns:
+    ///     float: The loss probability per measured qubit.
+    pub fn measurement_loss_probability(&self) -> f64 {
+        self.internal.measurement_loss_probability()
+    }
+
+    /// Set the relaxation time T1 of the qubit in the given tweezer.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     t1 (float): The relaxation time.
+    ///
+    /// Raises:
+    ///     ValueError: The relaxation time is not positive.
+    #[pyo3(text_signature = "(tweezer, t1, /)")]
+    pub fn set_tweezer_t1(&mut self, tweezer: usize, t1: f64) -> PyResult<()> {
+        self.internal
+            .set_tweezer_t1(tweezer, t1)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the dephasing time T2 of the qubit in the given tweezer.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     t2 (float): The dephasing time.
+    ///
+    /// Raises:
+    ///     ValueError: The dephasing time is not positive.
+    #[pyo3(text_signature = "(tweezer, t2, /)")]
+    pub fn set_tweezer_t2(&mut self, tweezer: usize, t2: f64) -> PyResult<()> {
+        self.internal
+            .set_tweezer_t2(tweezer, t2)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the depolarising rate of the qubit in the given tweezer.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     rate (float): The depolarising rate.
+    ///
+    /// Raises:
+    ///     ValueError: The depolarising rate is negative.
+    #[pyo3(text_signature = "(tweezer, rate, /)")]
+    pub fn set_tweezer_depolarising_rate(&mut self, tweezer: usize, rate: f64) -> PyResult<()> {
+        self.internal
+            .set_tweezer_depolarising_rate(tweezer, rate)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Remove all noise parameters from the device.
+    pub fn clear_noise(&mut self) {
+        self.internal.clear_noise()
+    }
+
+    /// Set the probability that the given tweezer is loaded with an atom at the start of a shot.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     probability (float): The loading probability.
+    ///
+    /// Raises:
+    ///     ValueError: The loading probability is not between 0 and 1.
+    #[pyo3(text_signature = "(tweezer, probability, /)")]
+    pub fn set_loading_probability(&mut self, tweezer: usize, probability: f64) -> PyResult<()> {
+        self.internal
+            .set_loading_probability(tweezer, probability)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set whether the loaded atoms are rearranged before each shot.
+    ///
+    /// With rearrangement the atoms loaded into any tweezer of the current Layout fill the
+    /// tweezers of the qubits of a circuit in ascending qubit order.
+    ///
+    /// Args:
+    ///     rearrangement (bool): Whether the loaded atoms are rearranged.
+    #[pyo3(text_signature = "(rearrangement, /)")]
+    pub fn set_rearrangement(&mut self, rearrangement: bool) {
+        self.internal.set_rearrangement(rearrangement)
+    }
+
+    /// Set the probability that the atom of a qubit is lost when its tweezer is shifted.
+    ///
+    /// A lost qubit is reset, the subsequent operations acting on it are skipped by the simulator
+    /// and the loss is reported as an atom loss event of the shot.
+    ///
+    /// Args:
+    ///     probability (float): The loss probability per shifted qubit.
+    ///
+    /// Raises:
+    ///     ValueError: The loss probability is not between 0 and 1.
+    #[pyo3(text_signature = "(probability, /)")]
+    pub fn set_shift_loss_probability(&mut self, probability: f64) -> PyResult<()> {
+        self.internal
+            .set_shift_loss_probability(probability)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the probability that the atom of a qubit is lost when the qubit is measured.
+    ///
+    /// A lost qubit is reset, the subsequent operations acting on it are skipped by the simulator
+    /// and the loss is reported as an atom loss event of the shot.
+    ///
+    /// Args:
+    ///     probability (float): The loss probability per measured qubit.
+    ///
+    /// Raises:
+    ///     ValueError: The loss probability is not between 0 and 1.
+    #[pyo3(text_signature = "(probability, /)")]
+    pub fn set_measurement_loss_probability(&mut self, probability: f64) -> PyResult<()> {
+        self.internal
+            .set_measurement_loss_probability(probability)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Registers a custom polynomial phi-theta relation.
+    ///
+    /// The relation gives phi = sum_k coefficients[k] * theta^k and can be used as phase relation
+    /// of the device by its name. A relation registered under the name of an existing custom
+    /// relation replaces it.
+    ///
+    /// Args:
+    ///     name (str): The name of the relation.
+    ///     coefficients (List[float]): The coefficients, starting with the constant one.
+    ///
+    /// Raises:
+    ///     ValueError: The name is empty, a number or the name of a builtin relation.
+    #[pyo3(text_signature = "(name, coefficients, /)")]
+    pub fn add_polynomial_phi_theta_relation(
+        &mut self,
+        name: &str,
+        coefficients: Vec<f64>,
+    ) -> PyResult<()> {
+        self.internal
+            .add_phi_theta_relation(name, PhiThetaRelation::Polynomial { coefficients })
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Registers a custom logarithmic phi-theta relation.
+    ///
+    /// The relation gives phi = offset + scale * ln(quadratic * theta^2 * exp(exponent * theta) + linear * theta + constant),
+    /// the form of the "DefaultRelation", and can be used as phase relation of the device by its name.
+    /// A relation registered under the name of an existing custom relation replaces it.
+    ///
+    /// Args:
+    ///     name (str): The name of the relation.
+    ///     offset (float): The offset of phi.
+    ///     scale (float): The factor of the logarithm.
+    ///     quadratic (float): The factor of the quadratic term.
+    ///     exponent (float): The factor of theta in the exponential of the quadratic term.
+    ///     linear (float): The factor of the linear term.
+    ///     constant (float): The constant term.
+    ///
+    /// Raises:
+    ///     ValueError: The name is empty, a number or the name of a builtin relation.
+    #[pyo3(text_signature = "(name, offset, scale, quadratic, exponent, linear, constant, /)")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_logarithmic_phi_theta_relation(
+        &mut self,
+        name: &str,
+        offset: f64,
+        scale: f64,
+        quadratic: f64,
+        exponent: f64,
+        linear: f64,
+        constant: f64,
+    ) -> PyResult<()> {
+        self.internal
+            .add_phi_theta_relation(
+                name,
+                PhiThetaRelation::Logarithmic {
+                    offset,
+                    scale,
+                    quadratic,
+                    exponent,
+                    linear,
+                    constant,
+                },
+            )
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Removes a custom phi-theta relation.
+    ///
+    /// Args:
+    ///     name (str): The name of the relation.
+    ///
+    /// Returns:
+    ///     bool: Whether a custom relation with the name was registered.
+    #[pyo3(text_signature = "(name, /)")]
+    pub fn remove_phi_theta_relation(&mut self, name: &str) -> bool {
+        self.internal.remove_phi_theta_relation(name).is_some()
+    }
+
+    /// Returns the gate time of a single qubit operation on this device.
+    ///
+    /// Returns:
+    ///     float: The gate time.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not available in the device.
+    #[pyo3(text_signature = "(hqslang, qubit, /)")]
+    pub fn single_qubit_gate_time(&self, hqslang: &str, qubit: usize) -> PyResult<f64> {
+        self.internal
+            .single_qubit_gate_time(hqslang, &qubit)
+            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+    }
+
+    /// Returns the gate time of a two qubit operation on this device.
+    ///
+    /// Returns:
+    ///     float: The gate time.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not available in the device.
+    #[pyo3(text_signature = "(hqslang, control, target, /)")]
+    pub fn two_qubit_gate_time(
+        &self,
+        hqslang: &str,
+        control: usize,
+        target: usize,
+    ) -> PyResult<f64> {
+        self.internal
+            .two_qubit_gate_time(hqslang, &control, &target)
+            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+    }
+
+    /// Returns the gate time of a three qubit operation on this device.
+    ///
+    /// Returns:
+    ///     float: The gate time.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not available in the device.
+    #[pyo3(text_signature = "(hqslang, control_0, control_1, target, /)")]
+    pub fn three_qubit_gate_time(
+        &self,
+        hqslang: &str,
+        control_0: usize,
+        control_1: usize,
+        target: usize,
+    ) -> PyResult<f64> {
+        self.internal
+            .three_qubit_gate_time(hqslang, &control_0, &control_1, &target)
+            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+    }
+
+    /// Returns the gate time of a multi qubit operation on this device.
+    ///
+    /// Returns:
+    ///     float: The gate time.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not available in the device.
+    #[pyo3(text_signature = "(hqslang, qubits, /)")]
+    pub fn multi_qubit_gate_time(&self, hqslang: &str, qubits: Vec<usize>) -> PyResult<f64> {
+        self.internal
+            .multi_qubit_gate_time(hqslang, &qubits)
+            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+    }
+
+    /// Returns the PhaseShiftedControlledZ phase shift according to the device's relation.
+    ///
+    /// Returns:
+    ///     float: The PhaseShiftedControlledZ phase shift.
+    ///
+    /// Raises:
+    ///     ValueError: Error in relation selection.
+    pub fn phase_shift_controlled_z(&self) -> PyResult<f64> {
+        self.internal
+            .phase_shift_controlled_z()
+            .ok_or_else(|| PyValueError::new_err("Error in relation selection."))
+    }
+
+    /// Returns the PhaseShiftedControlledPhase phase shift according to the device's relation.
+    ///
+    /// Returns:
+    ///     float: The PhaseShiftedControlledPhase phase shift.
+    ///
+    /// Raises:
+    ///     ValueError: Error in relation selection.
+    #[pyo3(text_signature = "(theta, /)")]
+    pub fn phase_shift_controlled_phase(&self, theta: &Bound<PyAny>) -> PyResult<f64> {
+        let float = if let Ok(conv) = convert_into_calculator_float(theta) {
+            *conv
+                .float()
+                .map_err(|err| PyValueError::new_err(format!("{:}", err)))?
+        } else {
+            theta.extract::<f64>()?
+        };
+
+        self.internal
+            .phase_shift_controlled_phase(float)
+            .ok_or_else(|| PyValueError::new_err("Error in relation selection."))
+    }
+
+    /// Returns phi for the given theta according to a custom or builtin phi-theta relation.
+    ///
+    /// Args:
+    ///     relation_name (str): The name of the relation.
+    ///     theta (float): The theta angle.
+    ///
+    /// Returns:
+    ///     Optional[float]: The phi angle, None if the relation does not exist or is not defined for theta.
+    #[pyo3(text_signature = "(relation_name, theta, /)")]
+    pub fn phi_theta_relation(&self, relation_name: &str, theta: f64) -> Option<f64> {
+        self.internal.phi_theta_relation(relation_name, theta)
+    }
+
+    /// Returns the gate time of a PhaseShiftedControlledZ operation with the given qubits and phi angle.
+    ///
+    /// Args:
+    ///     control (int): The control qubit the gate acts on
+    ///     target (int): The target qubit the gate acts on
+    ///     phi (float): The phi angle to be checked.
+    ///
+    /// Returns:
+    ///     float: The gate time.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not available on the device.
+    #[pyo3(text_signature = "(control, target, phi, /)")]
+    pub fn gate_time_controlled_z(&self, control: usize, target: usize, phi: f64) -> PyResult<f64> {
+        self.internal
+            .gate_time_controlled_z(&control, &target, phi)
+            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+    }
+
+    /// Returns the gate time of a PhaseShiftedControlledPhase operation with the given qubits and phi and theta angles.
+    ///
+    /// Args:
+    ///     control (int): The control qubit the gate acts on
+    ///     target (int): The target qubit the gate acts on
+    ///     phi (float): The phi angle to be checked.
+    ///     theta (float): The theta angle to be checked.
+    ///
+    /// Returns:
+    ///     float: The gate time.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not available on the device.
+    #[pyo3(text_signature = "(control, target, phi, theta, /)")]
+    pub fn gate_time_controlled_phase(
+        &self,
+        control: usize,
+        target: usize,
+        phi: f64,
+        theta: f64,
+    ) -> PyResult<f64> {
+        self.internal
+            .gate_time_controlled_phase(&control, &target, phi, theta)
+            .ok_or_else(|| PyValueError::new_err("The gate is not available on the device."))
+    }
+
+    /// Turns Device into GenericDevice
+    ///
+    /// Can be used as a generic interface for devices when a boxed dyn trait object cannot be used
+    /// (for example when the interface needs to be serialized)
+    ///
+    /// The gate times of the current Layout are translated through the qubit -> tweezer mapping,
+    /// gates on tweezers not holding a qubit are skipped and three-qubit gates are approximated
+    /// by multi-qubit gates on the same qubits.
+    ///
+    /// Returns:
+    ///     GenericDevice: The device in generic representation
+    ///
+    /// Raises:
+    ///     ValueError: No current Layout is set.
+    ///
+    /// Note:
+    ///     GenericDevice uses nested HashMaps to represent the most general device connectivity.
+    ///     The memory usage will be inefficient for devices with large qubit numbers.
+    fn generic_device(&self) -> PyResult<GenericDeviceWrapper> {
+        self.internal
+            .try_to_generic_device()
+            .map(|internal| GenericDeviceWrapper { internal })
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Turns Device into GenericDevice, failing if gate times of the current Layout would be lost.
+    ///
+    /// Unlike `generic_device` no gate is skipped or approximated.
+    ///
+    /// Returns:
+    ///     GenericDevice: The device in generic representation
+    ///
+    /// Raises:
+    ///     ValueError: No current Layout is set, a gate is defined on a tweezer not holding a qubit or a three-qubit gate is defined.
+    fn generic_device_checked(&self) -> PyResult<GenericDeviceWrapper> {
+        self.internal
+            .to_generic_device_checked()
+            .map(|internal| GenericDeviceWrapper { internal })
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Return a copy of the TweezerMutableDevice (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: A deep copy of self.
+    pub fn __copy__(&self) -> TweezerMutableDeviceWrapper {
+        self.clone()
+    }
+
+    /// Return a deep copy of the TweezerMutableDevice.
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: A deep copy of self.
+    pub fn __deepcopy__(&self, _memodict: Py<PyAny>) -> TweezerMutableDeviceWrapper {
+        self.clone()
+    }
+
+    /// Return the bincode representation of the TweezerMutableDevice using the bincode crate.
+    ///
+    /// Returns:
+    ///     ByteArray: The serialized TweezerMutableDevice (in bincode form).
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize TweezerMutableDevice to bytes.
+    pub fn to_bincode(&self) -> PyResult<Py<PyByteArray>> {
+        let serialized = serialize(&self.internal)
+            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerMutableDevice to bytes"))?;
+        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+            PyByteArray::new_bound(py, &serialized[..]).into()
+        });
+        Ok(b)
+    }
+
+    /// Convert the bincode representation of the TweezerMutableDevice to an
+    /// TweezerMutableDevice using the bincode crate.
+    ///
+    /// Args:
+    ///     input (ByteArray): The serialized TweezerMutableDevice (in bincode form).
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: The deserialized TweezerMutableDevice.
+    ///
+    /// Raises:
+    ///     TypeError: Input cannot be converted to byte array.
+    ///     ValueError: Input cannot be deserialized to TweezerMutableDevice.
+    #[staticmethod]
+    #[pyo3(text_signature = "(input, /)")]
+    pub fn from_bincode(input: &Bound<PyAny>) -> PyResult<TweezerMutableDeviceWrapper> {
+        let bytes = input
+            .extract::<Vec<u8>>()
+            .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
+        Ok(TweezerMutableDeviceWrapper {
+            internal: deserialize(&bytes[..]).map_err(|_| {
+                PyValueError::new_err("Input cannot be deserialized to TweezerMutableDevice")
+            })?,
+        })
+    }
+
+    /// Return the json representation of the TweezerMutableDevice.
+    ///
+    /// Additionally, a gate set check is performed.
+    ///
+    /// Returns:
+    ///     str: The serialized form of TweezerMutableDevice.
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize TweezerMutableDevice to json or
+    ///         the device does not have valid QRyd gates available.
+    fn to_json(&self) -> PyResult<String> {
+        check_native_gates(&self.internal)?;
+        let serialized = serde_json::to_string(&self.internal)
+            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerMutableDevice to json"))?;
+        Ok(serialized)
+    }
+
+    /// Convert the json representation of a TweezerMutableDevice to an TweezerMutableDevice.
+    ///
+    /// Additionally, a gate set check is performed.
+    ///
+    /// Args:
+    ///     input (str): The serialized TweezerMutableDevice in json form.
+    ///
+    /// Returns:
+    ///     TweezerMutableDevice: The deserialized TweezerMutableDevice.
+    ///
+    /// Raises:
+    ///     ValueError: Input cannot be deserialized to TweezerMutableDevice or
+    ///         the device does not have valid QRyd gates available.
+    #[staticmethod]
+    #[pyo3(text_signature = "(input, /)")]
+    fn from_json(input: &str) -> PyResult<TweezerMutableDeviceWrapper> {
+        let internal: TweezerDevice = serde_json::from_str(input).map_err(|_| {
+            PyValueError::new_err("Input cannot be deserialized to TweezerMutableDevice")
+        })?;
+        check_native_gates(&internal)?;
+        Ok(TweezerMutableDeviceWrapper { internal })
+    }
+
+    /// Return number of qubits in device.
+    ///
+    /// Returns:
+    ///     int: The number of qubits.
+    ///
+    pub fn number_qubits(&self) -> usize {
+        self.internal.number_qubits()
+    }
+
+    /// Returns the number of total tweezer positions in the device.
+    ///
+    /// Args:
+    ///     layout_name (Optional[str]): The name of the layout to reference. Defaults to the current layout.
+    ///
+    /// Returns:
+    ///     int: The number of tweezer positions in the device.
+    #[pyo3(text_signature = "(layout_name, /)")]
+    pub fn number_tweezer_positions(&self, layout_name: Option<String>) -> PyResult<usize> {
+        self.internal
+            .number_tweezer_positions(layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Return the list of pairs of qubits linked by a native two-qubit-gate in the device.
+    ///
+    /// A pair of qubits is considered linked by a native two-qubit-gate if the device
+    /// can implement a two-qubit-gate between the two qubits without decomposing it
+    /// into a sequence of gates that involves a third qubit of the device.
+    /// The two-qubit-gate also has to form a universal set together with the available
+    /// single qubit gates.
+    ///
+    /// The returned vectors is a simple, graph-library independent, representation of
+    /// the undirected connectivity graph of the device.
+    /// It can be used to construct the connectivity graph in a graph library of the user's
+    /// choice from a list of edges and can be used for applications like routing in quantum algorithms.
+    ///
+    /// Returns:
+    ///     Sequence[(int, int)]: List of two qubit edges in the undirected connectivity graph
+    fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
+        self.internal.two_qubit_edges()
+    }
+
+    /// Returns the two tweezer edges of the device.
+    ///
+    /// And edge between two tweezer is valid only if the
+    /// PhaseShiftedControlledPhase gate can be performed.
+    ///
+    /// Returns:
+    ///     Sequence[(int, int)]: List of two tweezer edges
+    fn two_tweezer_edges(&self) -> Vec<(usize, usize)> {
+        self.internal.two_tweezer_edges()
+    }
+
+    /// Returns the backend associated with the device.
+    pub fn qrydbackend(&self) -> String {
+        self.internal.qrydbackend()
+    }
+
+    /// Returns the seed usized for the API.
+    pub fn seed(&self) -> Option<usize> {
+        self.internal.seed()
+    }
+
+    /// Return the bincode representation of the Enum variant of the Device.
+    ///
+    /// Only used for internal interfacing.
+    ///
+    /// Returns:
+    ///     ByteArray: The serialized TweezerMutableDevice (in [bincode] form).
+    ///
+    /// Raises:
+    ///     ValueError: Cannot serialize Device to bytes.
+    pub fn _enum_to_bincode(&self) -> PyResult<Py<PyByteArray>> {
+        let qryd_enum: QRydAPIDevice = (&self.internal).into();
+        let serialized = bincode::serialize(&qryd_enum)
+            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerMutableDevice to bytes"))?;
+        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+            PyByteArray::new_bound(py, &serialized[..]).into()
+        });
+        Ok(b)
+    }
+
+    /// Set the time of a single-qubit gate for a tweezer in a given Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a single-qubit gate.
+    ///     tweezer (int): The index of the tweezer.
+    ///     gate_time (float): The the gate time for the given gate.
+    ///     layout_name (Optional[str]): The name of the Layout to apply the gate time in.
+    ///         Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set.
+    #[pyo3(text_signature = "(hqslang, tweezer, gate_time, layout_name, /)")]
+    pub fn set_tweezer_single_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer: usize,
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_single_qubit_gate_time(hqslang, tweezer, gate_time, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the time of a two-qubit gate for a tweezer couple in a given Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a single-qubit gate.
+    ///     tweezer0 (int): The index of the first tweezer.
+    ///     tweezer1 (int): The index of the second tweezer.
+    ///     gate_time (float): The the gate time for the given gate.
+    ///     layout_name (Optional[str]): The name of the Layout to apply the gate time in.
+    ///         Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set.
+    #[pyo3(text_signature = "(hqslang, tweezer0, tweezer1, gate_time, layout_name, /)")]
+    pub fn set_tweezer_two_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_two_qubit_gate_time(hqslang, tweezer0, tweezer1, gate_time, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the theta-dependent gate time curve of the PhaseShiftedControlledPhase gate for a tweezer pair in a given Layout.
+    ///
+    /// The gate time of a PhaseShiftedControlledPhase gate with the rotation angle theta is
+    /// interpolated linearly between the samples of the curve. Theta is wrapped into [-pi, pi]
+    /// and only its absolute value is used, so the samples are given for theta in [0, pi].
+    ///
+    /// Args:
+    ///     tweezer0 (int): The index of the first tweezer.
+    ///     tweezer1 (int): The index of the second tweezer.
+    ///     samples (List[Tuple[float, float]]): The (theta, gate time) samples of the curve.
+    ///     layout_name (Optional[str]): The name of the Layout to set the curve in. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The samples are invalid, no layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(tweezer0, tweezer1, samples, layout_name, /)")]
+    pub fn set_controlled_phase_time_curve(
+        &mut self,
+        tweezer0: usize,
+        tweezer1: usize,
+        samples: Vec<(f64, f64)>,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_controlled_phase_time_curve(tweezer0, tweezer1, &samples, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the time of a three-qubit gate for a tweezer trio in a given Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a three-qubit gate.
+    ///     tweezer0 (int): The index of the first tweezer.
+    ///     tweezer1 (int): The index of the second tweezer.
+    ///     tweezer2 (int): The index of the third tweezer.
+    ///     gate_time (float): The the gate time for the given gate.
+    ///     layout_name (Optional[str]): The name of the Layout to apply the gate time in.
+    ///         Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set.
+    #[pyo3(text_signature = "(hqslang, tweezer0, tweezer1, tweezer2, gate_time, layout_name, /)")]
+    pub fn set_tweezer_three_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        tweezer2: usize,
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_three_qubit_gate_time(
+                hqslang,
+                tweezer0,
+                tweezer1,
+                tweezer2,
+                gate_time,
+                layout_name,
+            )
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the time of a multi-qubit gate for a list of tweezers in a given Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a multi-qubit gate.
+    ///     tweezers (List[int]): The list of tweezer indexes.
+    ///     gate_time (float): The the gate time for the given gate.
+    ///     layout_name (Optional[str]): The name of the Layout to apply the gate time in.
+    ///         Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set.
+    #[pyo3(text_signature = "(hqslang, tweezers, gate_time, layout_name, /)")]
+    pub fn set_tweezer_multi_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezers: Vec<usize>,
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_multi_qubit_gate_time(hqslang, &tweezers, gate_time, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the times of a single-qubit gate for many tweezers in a given Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a single-qubit gate.
+    ///     gate_times (List[Tuple[int, float]]): The (tweezer, gate time) entries.
+    ///     layout_name (Optional[str]): The name of the Layout to apply the gate times in.
+    ///         Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not supported, no layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(hqslang, gate_times, layout_name, /)")]
+    pub fn set_tweezer_single_qubit_gate_times(
+        &mut self,
+        hqslang: &str,
+        gate_times: Vec<(usize, f64)>,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_single_qubit_gate_times(hqslang, &gate_times, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the times of a two-qubit gate for many tweezer couples in a given Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a two-qubit gate.
+    ///     gate_times (List[Tuple[int, int, float]]): The (tweezer0, tweezer1, gate time) entries.
+    ///     layout_name (Optional[str]): The name of the Layout to apply the gate times in.
+    ///         Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not supported, no layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(hqslang, gate_times, layout_name, /)")]
+    pub fn set_tweezer_two_qubit_gate_times(
+        &mut self,
+        hqslang: &str,
+        gate_times: Vec<(usize, usize, f64)>,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_two_qubit_gate_times(hqslang, &gate_times, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the times of a three-qubit gate for many tweezer trios in a given Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a three-qubit gate.
+    ///     gate_times (List[Tuple[int, int, int, float]]): The (tweezer0, tweezer1, tweezer2, gate time) entries.
+    ///     layout_name (Optional[str]): The name of the Layout to apply the gate times in.
+    ///         Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The gate is not supported, no layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(hqslang, gate_times, layout_name, /)")]
+    pub fn set_tweezer_three_qubit_gate_times(
+        &mut self,
+        hqslang: &str,
+        gate_times: Vec<(usize, usize, usize, f64)>,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_three_qubit_gate_times(hqslang, &gate_times, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Remove the time of a single-qubit gate for a tweezer in a given Layout.
+    ///
+    /// The error rate of the gate is removed as well.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a single-qubit gate.
+    ///     tweezer (int): The index of the tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout to remove the gate time from.
+    ///         Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     Optional[float]: The removed gate time, None if it was not set.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(hqslang, tweezer, layout_name, /)")]
+    pub fn unset_tweezer_single_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<Option<f64>> {
+        self.internal
+            .unset_tweezer_single_qubit_gate_time(hqslang, tweezer, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Remove the time of a two-qubit gate for a tweezer couple in a given Layout.
+    ///
+    /// The error rate of the gate is removed as well.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a two-qubit gate.
+    ///     tweezer0 (int): The index of the first tweezer.
+    ///     tweezer1 (int): The index of the second tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout to remove the gate time from.
+    ///         Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     Optional[float]: The removed gate time, None if it was not set.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(hqslang, tweezer0, tweezer1, layout_name, /)")]
+    pub fn unset_tweezer_two_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<Option<f64>> {
+        self.internal
+            .unset_tweezer_two_qubit_gate_time(hqslang, tweezer0, tweezer1, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Remove the time of a three-qubit gate for a tweezer trio in a given Layout.
+    ///
+    /// The error rate of the gate is removed as well.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a three-qubit gate.
+    ///     tweezer0 (int): The index of the first tweezer.
+    ///     tweezer1 (int): The index of the second tweezer.
+    ///     tweezer2 (int): The index of the third tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout to remove the gate time from.
+    ///         Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     Optional[float]: The removed gate time, None if it was not set.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(hqslang, tweezer0, tweezer1, tweezer2, layout_name, /)")]
+    pub fn unset_tweezer_three_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        tweezer2: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<Option<f64>> {
+        self.internal
+            .unset_tweezer_three_qubit_gate_time(hqslang, tweezer0, tweezer1, tweezer2, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Remove the time of a multi-qubit gate for a list of tweezers in a given Layout.
+    ///
+    /// Args:
+    ///     hqslang (str): The hqslang name of a multi-qubit gate.
+    ///     tweezers (List[int]): The list of tweezer indexes.
+    ///     layout_name (Optional[str]): The name of the Layout to remove the gate time from.
+    ///         Defaults to the current Layout.
+    ///
+    /// Returns:
+    ///     Optional[float]: The removed gate time, None if it was not set.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set, or the layout is not present.
+    #[pyo3(text_signature = "(hqslang, tweezers, layout_name, /)")]
+    pub fn unset_tweezer_multi_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezers: Vec<usize>,
+        layout_name: Option<String>,
+    ) -> PyResult<Option<f64>> {
+        self.internal
+            .unset_tweezer_multi_qubit_gate_time(hqslang, &tweezers, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Remove all gate times, error rates, shifts, coordinates and tweezers per row of a Layout.
+    ///
+    /// The Layout stays in the register as an empty Layout.
+    ///
+    /// Args:
+    ///     name (str): The name of the Layout to clear.
+    ///
+    /// Raises:
+    ///     ValueError: The layout is not present.
+    #[pyo3(text_signature = "(name, /)")]
+    pub fn clear_layout(&mut self, name: &str) -> PyResult<()> {
+        self.internal
+            .clear_layout(name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the allowed Tweezer shifts of a specified Tweezer.
+    ///
+    /// The tweezer give the tweezer a qubit can be shifted out of. The values are lists
+    /// over the directions the qubit in the tweezer can be shifted into.
+    /// The items in the list give the allowed tweezers the qubit can be shifted into in order.
+    /// For a list 1,2,3 the qubit can be shifted into tweezer 1, into tweezer 2 if tweezer 1 is not occupied,
+    /// and into tweezer 3 if tweezer 1 and 2 are not occupied.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     allowed_shifts (list[list[int]]): The allowed tweezer shifts.
+    ///     layout_name (Optional[str]): The name of the Layout to apply the allowed shifts in.
+    ///         Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The tweezer or shifts are not present in the device or
+    ///         the given tweezer is contained in the shift list.
+    #[pyo3(text_signature = "(tweezer, allowed_shifts, layout_name, /)")]
+    pub fn set_allowed_tweezer_shifts(
+        &mut self,
+        tweezer: usize,
+        allowed_shifts: Vec<Vec<usize>>,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_allowed_tweezer_shifts(
+                &tweezer,
+                allowed_shifts
+                    .iter()
+                    .map(|vec| vec.as_slice())
+                    .collect::<Vec<&[usize]>>()
+                    .as_slice(),
+                layout_name,
+            )
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the allowed Tweezer shifts from a list of tweezers.
+    ///
+    /// The items in the rows give the allowed tweezers that qubit can be shifted into.
+    /// For a row defined as 1,2,3, a qubit in tweezer 1 can be shifted into tweezer 2,
+    /// and into tweezer 3 if tweezer 2 is not occupied by a qubit.
+    ///
+    /// Args:
+    ///     row_shifts (list[list[int]]): A list of lists, each representing a row of tweezers.
+    ///     layout_name (Optional[str]): The name of the Layout to apply the allowed shifts in.
+    ///         Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The involved tweezers are not present in the device.
+    #[pyo3(text_signature = "(row_shifts, layout_name, /)")]
+    pub fn set_allowed_tweezer_shifts_from_rows(
+        &mut self,
+        row_shifts: Vec<Vec<usize>>,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_allowed_tweezer_shifts_from_rows(
+                row_shifts
+                    .iter()
+                    .map(|vec| vec.as_slice())
+                    .collect::<Vec<&[usize]>>()
+                    .as_slice(),
+                layout_name,
+            )
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the transport time of an allowed shift in a given Layout.
+    ///
+    /// The transport time takes precedence over the time per site set with `set_shift_time_per_site`.
+    ///
+    /// Args:
+    ///     start (int): The tweezer the qubit is shifted out of.
+    ///     end (int): The tweezer the qubit is shifted into.
+    ///     shift_time (float): The transport time of the shift.
+    ///     layout_name (Optional[str]): The name of the Layout to set the transport time in. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The transport time is negative or not finite, the shift is not allowed, no layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(start, end, shift_time, layout_name, /)")]
+    pub fn set_tweezer_shift_time(
+        &mut self,
+        start: usize,
+        end: usize,
+        shift_time: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_shift_time(start, end, shift_time, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the transport time per tweezer passed during a shift in a given Layout.
+    ///
+    /// Shifting a qubit into the n-th tweezer of an allowed shift direction takes n times the
+    /// time per site, unless a transport time is set for the shift with `set_tweezer_shift_time`.
+    ///
+    /// Args:
+    ///     time_per_site (float): The transport time per tweezer passed.
+    ///     layout_name (Optional[str]): The name of the Layout to set the time in. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The time is negative or not finite, no layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(time_per_site, layout_name, /)")]
+    pub fn set_shift_time_per_site(
+        &mut self,
+        time_per_site: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_shift_time_per_site(time_per_site, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the gate time of the global rotation PragmaGlobalRotateXY in a given Layout.
+    ///
+    /// The global rotation is only available in the Layouts with a global rotation time.
+    ///
+    /// Args:
+    ///     gate_time (float): The gate time of the global rotation.
+    ///     layout_name (Optional[str]): The name of the Layout to set the time in. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The time is negative or not finite, no layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(gate_time, layout_name, /)")]
+    pub fn set_global_rotation_time(
+        &mut self,
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_global_rotation_time(gate_time, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the time to switch the device into a given Layout with a PragmaSwitchDeviceLayout.
+    ///
+    /// Args:
+    ///     switch_time (float): The time to switch into the Layout.
+    ///     layout_name (Optional[str]): The name of the Layout to set the time in. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The time is negative or not finite, no layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(switch_time, layout_name, /)")]
+    pub fn set_layout_switch_time(
+        &mut self,
+        switch_time: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_layout_switch_time(switch_time, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the tweezer per row value for a given Layout.
+    ///
+    /// This is needed for dynamically switching layouts during circuit execution.
+    /// Only switching between layouts having the same tweezer per row value is supported.
+    ///
+    /// Args:
+    ///     tweezers_per_row(List[int]): Vector containing the number of tweezers per row to set.
+    ///     layout_name(Optional[str]): The name of the Layout to set the tweezer per row for. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: No layout name provided and no current layout set.
+    #[pyo3(text_signature = "(tweezers_per_row, layout_name, /)")]
+    pub fn set_tweezers_per_row(
+        &mut self,
+        tweezers_per_row: Vec<usize>,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezers_per_row(tweezers_per_row, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the (x, y) coordinates of a tweezer in a given Layout.
+    ///
+    /// The coordinates are used by `set_two_qubit_gate_times_from_distance` and by `draw`,
+    /// which places the tweezers at their true positions when all tweezers have coordinates.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     x (float): The x coordinate of the tweezer.
+    ///     y (float): The y coordinate of the tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout to set the coordinates in. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The coordinates are not finite or no layout name provided and no current layout set.
+    #[pyo3(text_signature = "(tweezer, x, y, layout_name, /)")]
+    pub fn set_tweezer_coordinates(
+        &mut self,
+        tweezer: usize,
+        x: f64,
+        y: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_coordinates(tweezer, x, y, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Set the zone label of a tweezer in a given Layout.
+    ///
+    /// Zone labels describe the region of a zoned trap a tweezer is placed in, e.g. "storage",
+    /// "interaction" or "readout". In a Layout with zone labels two-qubit gates are only allowed
+    /// between tweezers labelled "interaction", see `check_zone_constraints`.
+    ///
+    /// Args:
+    ///     tweezer (int): The index of the tweezer.
+    ///     zone (str): The label of the zone of the tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout to set the zone in. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The zone label is empty or no layout name provided and no current layout set.
+    #[pyo3(text_signature = "(tweezer, zone, layout_name, /)")]
+    pub fn set_tweezer_zone(
+        &mut self,
+        tweezer: usize,
+        zone: &str,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_tweezer_zone(tweezer, zone, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    }
+
+    /// Add a pair of tweezers that can not be driven simultaneously in a given Layout.
+    ///
+    /// The constraint is symmetric, e.g. due to the Rydberg blockade or crosstalk between the
+    /// tweezers. See `can_execute_in_parallel`.
+    ///
+    /// Args:
+    ///     tweezer0 (int): The index of the first tweezer.
+    ///     tweezer1 (int): The index of the second tweezer.
+    ///     layout_name (Optional[str]): The name of the Layout to add the pair to. Defaults to the current Layout.
+    ///
+    /// Raises:
+    ///     ValueError: The tweezers are the same, no layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(tweezer0, tweezer1, layout_name, /)")]
+    pub fn add_blockaded_tweezer_pair(
+        &mut self,
+        tweezer0: usize,
+        tweezer1: usize,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .add_blockaded_tweezer_pair(tweezer0, tweezer1, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Return the bincode representation of the Enum variant of the Device.
+    /// Set the blockade radius of a given Layout.
     ///
-    /// Only used for internal interfacing.
+    /// Tweezers with coordinates at most the blockade radius apart can not be driven simultaneously.
     ///
-    /// Returns:
-    ///     ByteArray: The serialized TweezerMutableDevice (in [bincode] form).
+    /// Args:
+    ///     radius (float): The blockade radius, in the units of the tweezer coordinates.
+    ///     layout_name (Optional[str]): The name of the Layout to set the radius in. Defaults to the current Layout.
     ///
     /// Raises:
-    ///     ValueError: Cannot serialize Device to bytes.
-    pub fn _enum_to_bincode(&self) -> PyResult<Py<PyByteArray>> {
-        let qryd_enum: QRydAPIDevice = (&self.internal).into();
-        let serialized = bincode::serialize(&qryd_enum)
-            .map_err(|_| PyValueError::new_err("Cannot serialize TweezerMutableDevice to bytes"))?;
-        let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
-            PyByteArray::new_bound(py, &serialized[..]).into()
-        });
-        Ok(b)
+    ///     ValueError: The radius is negative or not finite, no layout name provided and no current layout set or the Layout does not exist.
+    #[pyo3(text_signature = "(radius, layout_name, /)")]
+    pub fn set_blockade_radius(
+        &mut self,
+        radius: f64,
+        layout_name: Option<String>,
+    ) -> PyResult<()> {
+        self.internal
+            .set_blockade_radius(radius, layout_name)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Set the time of a single-qubit gate for a tweezer in a given Layout.
+    /// Set the error rate of a single-qubit gate for a tweezer in a given Layout.
     ///
     /// Args:
     ///     hqslang (str): The hqslang name of a single-qubit gate.
     ///     tweezer (int): The index of the tweezer.
-    ///     gate_time (float): The the gate time for the given gate.
-    ///     layout_name (Optional[str]): The name of the Layout to apply the gate time in.
-    ///         Defaults to the current Layout.
+    ///     error (float): The error rate of the gate, between 0 and 1.
+    ///     layout_name (Optional[str]): The name of the Layout to set the error rate in. Defaults to the current Layout.
     ///
     /// Raises:
-    ///     ValueError: No layout name provided and no current layout set.
-    #[pyo3(text_signature = "(hqslang, tweezer, gate_time, layout_name, /)")]
-    pub fn set_tweezer_single_qubit_gate_time(
+    ///     ValueError: The gate is not supported, the error rate is invalid or the Layout does not exist.
+    #[pyo3(text_signature = "(hqslang, tweezer, error, layout_name, /)")]
+    pub fn set_tweezer_single_qubit_gate_error(
         &mut self,
         hqslang: &str,
         tweezer: usize,
-        gate_time: f64,
+        error: f64,
         layout_name: Option<String>,
     ) -> PyResult<()> {
         self.internal
-            .set_tweezer_single_qubit_gate_time(hqslang, tweezer, gate_time, layout_name)
+            .set_tweezer_single_qubit_gate_error(hqslang, tweezer, error, layout_name)
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Set the time of a two-qubit gate for a tweezer couple in a given Layout.
+    /// Set the error rate of a two-qubit gate for a tweezer couple in a given Layout.
     ///
     /// Args:
-    ///     hqslang (str): The hqslang name of a single-qubit gate.
+    ///     hqslang (str): The hqslang name of a two-qubit gate.
     ///     tweezer0 (int): The index of the first tweezer.
     ///     tweezer1 (int): The index of the second tweezer.
-    ///     gate_time (float): The the gate time for the given gate.
-    ///     layout_name (Optional[str]): The name of the Layout to apply the gate time in.
-    ///         Defaults to the current Layout.
+    ///     error (float): The error rate of the gate, between 0 and 1.
+    ///     layout_name (Optional[str]): The name of the Layout to set the error rate in. Defaults to the current Layout.
     ///
     /// Raises:
-    ///     ValueError: No layout name provided and no current layout set.
-    #[pyo3(text_signature = "(hqslang, tweezer0, tweezer1, gate_time, layout_name, /)")]
-    pub fn set_tweezer_two_qubit_gate_time(
+    ///     ValueError: The gate is not supported, the error rate is invalid or the Layout does not exist.
+    #[pyo3(text_signature = "(hqslang, tweezer0, tweezer1, error, layout_name, /)")]
+    pub fn set_tweezer_two_qubit_gate_error(
         &mut self,
         hqslang: &str,
         tweezer0: usize,
         tweezer1: usize,
-        gate_time: f64,
+        error: f64,
         layout_name: Option<String>,
     ) -> PyResult<()> {
         self.internal
-            .set_tweezer_two_qubit_gate_time(hqslang, tweezer0, tweezer1, gate_time, layout_name)
+            .set_tweezer_two_qubit_gate_error(hqslang, tweezer0, tweezer1, error, layout_name)
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Set the time of a three-qubit gate for a tweezer trio in a given Layout.
+    /// Set the error rate of a three-qubit gate for a tweezer trio in a given Layout.
     ///
     /// Args:
     ///     hqslang (str): The hqslang name of a three-qubit gate.
     ///     tweezer0 (int): The index of the first tweezer.
     ///     tweezer1 (int): The index of the second tweezer.
     ///     tweezer2 (int): The index of the third tweezer.
-    ///     gate_time (float): The the gate time for the given gate.
-    ///     layout_name (Optional[str]): The name of the Layout to apply the gate time in.
-    ///         Defaults to the current Layout.
+    ///     error (float): The error rate of the gate, between 0 and 1.
+    ///     layout_name (Optional[str]): The name of the Layout to set the error rate in. Defaults to the current Layout.
     ///
     /// Raises:
-    ///     ValueError: No layout name provided and no current layout set.
-    #[pyo3(text_signature = "(hqslang, tweezer0, tweezer1, tweezer2, gate_time, layout_name, /)")]
-    pub fn set_tweezer_three_qubit_gate_time(
+    ///     ValueError: The gate is not supported, the error rate is invalid or the Layout does not exist.
+    #[pyo3(text_signature = "(hqslang, tweezer0, tweezer1, tweezer2, error, layout_name, /)")]
+    pub fn set_tweezer_three_qubit_gate_error(
         &mut self,
         hqslang: &str,
         tweezer0: usize,
         tweezer1: usize,
         tweezer2: usize,
-        gate_time: f64,
+        error: f64,
         layout_name: Option<String>,
     ) -> PyResult<()> {
         self.internal
-            .set_tweezer_three_qubit_gate_time(
+            .set_tweezer_three_qubit_gate_error(
                 hqslang,
                 tweezer0,
                 tweezer1,
                 tweezer2,
-                gate_time,
+                error,
                 layout_name,
             )
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Set the time of a multi-qubit gate for a list of tweezers in a given Layout.
+    /// Set the time of a two-qubit gate for all tweezer pairs within a distance in a given Layout.
+    ///
+    /// For every pair of tweezers with coordinates at most `max_distance` apart, the gate time
+    /// `time_fn(distance)` is set for both orders of the tweezers.
     ///
     /// Args:
-    ///     hqslang (str): The hqslang name of a multi-qubit gate.
-    ///     tweezers (List[int]): The list of tweezer indexes.
-    ///     gate_time (float): The the gate time for the given gate.
-    ///     layout_name (Optional[str]): The name of the Layout to apply the gate time in.
-    ///         Defaults to the current Layout.
+    ///     hqslang (str): The hqslang name of a two-qubit gate.
+    ///     max_distance (float): The maximal distance between two tweezers supporting the gate.
+    ///     time_fn (Callable[[float], float]): Returns the gate time for the distance between two tweezers.
+    ///     layout_name (Optional[str]): The name of the Layout to set the gate times in. Defaults to the current Layout.
     ///
     /// Raises:
-    ///     ValueError: No layout name provided and no current layout set.
-    #[pyo3(text_signature = "(hqslang, tweezers, gate_time, layout_name, /)")]
-    pub fn set_tweezer_multi_qubit_gate_time(
+    ///     ValueError: The gate is not supported, the maximal distance is negative, time_fn failed or
+    ///         returned an invalid gate time or the Layout does not exist.
+    #[pyo3(text_signature = "(hqslang, max_distance, time_fn, layout_name, /)")]
+    pub fn set_two_qubit_gate_times_from_distance(
         &mut self,
         hqslang: &str,
-        tweezers: Vec<usize>,
-        gate_time: f64,
+        max_distance: f64,
+        time_fn: &Bound<PyAny>,
         layout_name: Option<String>,
     ) -> PyResult<()> {
-        self.internal
-            .set_tweezer_multi_qubit_gate_time(hqslang, &tweezers, gate_time, layout_name)
-            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+        let error: RefCell<Option<PyErr>> = RefCell::new(None);
+        let result = self.internal.set_two_qubit_gate_times_from_distance(
+            hqslang,
+            max_distance,
+            |distance| match time_fn
+                .call1((distance,))
+                .and_then(|time| time.extract::<f64>())
+            {
+                Ok(time) => time,
+                Err(err) => {
+                    error.borrow_mut().get_or_insert(err);
+                    f64::NAN
+                }
+            },
+            layout_name,
+        );
+        if let Some(err) = error.into_inner() {
+            return Err(PyValueError::new_err(format!(
+                "Error calling time_fn: {:}",
+                err
+            )));
+        }
+        result.map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Set the allowed Tweezer shifts of a specified Tweezer.
-    ///
-    /// The tweezer give the tweezer a qubit can be shifted out of. The values are lists
-    /// over the directions the qubit in the tweezer can be shifted into.
-    /// The items in the list give the allowed tweezers the qubit can be shifted into in order.
-    /// For a list 1,2,3 the qubit can be shifted into tweezer 1, into tweezer 2 if tweezer 1 is not occupied,
-    /// and into tweezer 3 if tweezer 1 and 2 are not occupied.
+    /// Set whether the device allows PragmaActiveReset operations or not.
     ///
     /// Args:
-    ///     tweezer (int): The index of the tweezer.
-    ///     allowed_shifts (list[list[int]]): The allowed tweezer shifts.
-    ///     layout_name (Optional[str]): The name of the Layout to apply the allowed shifts in.
-    ///         Defaults to the current Layout.
+    ///     allow_reset (bool): Whether the device should allow PragmaActiveReset operations or not.
     ///
     /// Raises:
-    ///     ValueError: The tweezer or shifts are not present in the device or
-    ///         the given tweezer is contained in the shift list.
-    #[pyo3(text_signature = "(tweezer, allowed_shifts, layout_name, /)")]
-    pub fn set_allowed_tweezer_shifts(
-        &mut self,
-        tweezer: usize,
-        allowed_shifts: Vec<Vec<usize>>,
-        layout_name: Option<String>,
-    ) -> PyResult<()> {
+    ///     ValueError: The device isn't compatible with PragmaActiveReset.
+    #[pyo3(text_signature = "(allow_reset, /)")]
+    pub fn set_allow_reset(&mut self, allow_reset: bool) -> PyResult<()> {
         self.internal
-            .set_allowed_tweezer_shifts(
-                &tweezer,
-                allowed_shifts
-                    .iter()
-                    .map(|vec| vec.as_slice())
-                    .collect::<Vec<&[usize]>>()
-                    .as_slice(),
-                layout_name,
-            )
+            .set_allow_reset(allow_reset)
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Set the allowed Tweezer shifts from a list of tweezers.
+    /// Set whether setting a two-qubit gate time for a tweezer pair also sets it for the reversed pair.
     ///
-    /// The items in the rows give the allowed tweezers that qubit can be shifted into.
-    /// For a row defined as 1,2,3, a qubit in tweezer 1 can be shifted into tweezer 2,
-    /// and into tweezer 3 if tweezer 2 is not occupied by a qubit.
+    /// Applies to `set_tweezer_two_qubit_gate_time`, `set_tweezer_two_qubit_gate_times` and
+    /// `unset_tweezer_two_qubit_gate_time`. Gate times set before are not changed.
     ///
     /// Args:
-    ///     row_shifts (list[list[int]]): A list of lists, each representing a row of tweezers.
-    ///     layout_name (Optional[str]): The name of the Layout to apply the allowed shifts in.
-    ///         Defaults to the current Layout.
-    ///
-    /// Raises:
-    ///     ValueError: The involved tweezers are not present in the device.
-    #[pyo3(text_signature = "(row_shifts, layout_name, /)")]
-    pub fn set_allowed_tweezer_shifts_from_rows(
-        &mut self,
-        row_shifts: Vec<Vec<usize>>,
-        layout_name: Option<String>,
-    ) -> PyResult<()> {
-        self.internal
-            .set_allowed_tweezer_shifts_from_rows(
-                row_shifts
-                    .iter()
-                    .map(|vec| vec.as_slice())
-                    .collect::<Vec<&[usize]>>()
-                    .as_slice(),
-                layout_name,
-            )
-            .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+    ///     symmetric (bool): Whether the two-qubit gate times are set symmetrically.
+    #[pyo3(text_signature = "(symmetric, /)")]
+    pub fn set_symmetric_two_qubit_gate_times(&mut self, symmetric: bool) {
+        self.internal.set_symmetric_two_qubit_gate_times(symmetric)
     }
 
-    /// Set the tweezer per row value for a given Layout.
+    /// Set the native gates gate times and error rates can be set for.
     ///
-    /// This is needed for dynamically switching layouts during circuit execution.
-    /// Only switching between layouts having the same tweezer per row value is supported.
+    /// Allows modeling devices supporting other native gates than the QRyd backend, e.g.
+    /// `SqrtPauliX`. The gates with gate times in a Layout of the device need to stay native.
     ///
     /// Args:
-    ///     tweezers_per_row(List[int]): Vector containing the number of tweezers per row to set.
-    ///     layout_name(Optional[str]): The name of the Layout to set the tweezer per row for. Defaults to the current Layout.
+    ///     single_qubit_gates (List[str]): The hqslang names of the native single-qubit gates.
+    ///     two_qubit_gates (List[str]): The hqslang names of the native two-qubit gates.
+    ///     three_qubit_gates (List[str]): The hqslang names of the native three-qubit gates.
+    ///     multi_qubit_gates (List[str]): The hqslang names of the native multi-qubit gates.
     ///
     /// Raises:
-    ///     ValueError: No layout name provided and no current layout set.
-    #[pyo3(text_signature = "(tweezers_per_row, layout_name, /)")]
-    pub fn set_tweezers_per_row(
+    ///     ValueError: A gate with gate times in a Layout is not native anymore.
+    #[pyo3(
+        text_signature = "(single_qubit_gates, two_qubit_gates, three_qubit_gates, multi_qubit_gates, /)"
+    )]
+    pub fn set_native_gates(
         &mut self,
-        tweezers_per_row: Vec<usize>,
-        layout_name: Option<String>,
+        single_qubit_gates: Vec<String>,
+        two_qubit_gates: Vec<String>,
+        three_qubit_gates: Vec<String>,
+        multi_qubit_gates: Vec<String>,
     ) -> PyResult<()> {
         self.internal
-            .set_tweezers_per_row(tweezers_per_row, layout_name)
+            .set_native_gates(NativeGates {
+                single_qubit_gates,
+                two_qubit_gates,
+                three_qubit_gates,
+                multi_qubit_gates,
+            })
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
-    /// Set whether the device allows PragmaActiveReset operations or not.
+    /// Set the maximal number of qubits the hardware supports.
+    ///
+    /// Qubits with an index of at least the capacity can not be mapped to tweezers, and circuits
+    /// acting on them are rejected by the backends before they are run or posted.
     ///
     /// Args:
-    ///     allow_reset (bool): Whether the device should allow PragmaActiveReset operations or not.
+    ///     max_qubits (Optional[int]): The maximal number of qubits, None if the capacity is not limited.
     ///
     /// Raises:
-    ///     ValueError: The device isn't compatible with PragmaActiveReset.
-    #[pyo3(text_signature = "(allow_reset, /)")]
-    pub fn set_allow_reset(&mut self, allow_reset: bool) -> PyResult<()> {
+    ///     ValueError: A qubit of the qubit -> tweezer mapping exceeds the capacity.
+    #[pyo3(text_signature = "(max_qubits, /)")]
+    pub fn set_max_qubits(&mut self, max_qubits: Option<usize>) -> PyResult<()> {
         self.internal
-            .set_allow_reset(allow_reset)
+            .set_max_qubits(max_qubits)
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
@@ -1540,13 +3850,23 @@ impl TweezerMutableDeviceWrapper {
     ///
     /// Args:
     ///     layout (str): The name of the layout to use.
+    ///     mapping_policy (Optional[str]): The policy populating the qubit -> tweezer mapping, one of
+    ///         `all-tweezers`, `usable-tweezers`, `occupied-first` or `none`. Defaults to `all-tweezers`.
     ///
     /// Raises:
-    ///     ValueError: The given layout name is not present in the layout register.
-    #[pyo3(text_signature = "(layout, /)")]
-    pub fn set_default_layout(&mut self, layout: &str) -> PyResult<()> {
+    ///     ValueError: The given layout name is not present in the layout register or the policy is unknown.
+    #[pyo3(text_signature = "(layout, mapping_policy, /)")]
+    pub fn set_default_layout(
+        &mut self,
+        layout: &str,
+        mapping_policy: Option<&str>,
+    ) -> PyResult<()> {
+        let policy = match mapping_policy {
+            Some(policy) => trivial_mapping_policy(policy)?,
+            None => TrivialMappingPolicy::AllTweezers,
+        };
         self.internal
-            .set_default_layout(layout)
+            .set_default_layout_with_policy(layout, policy)
             .map_err(|err| PyValueError::new_err(format!("{:}", err)))
     }
 
@@ -1555,53 +3875,171 @@ impl TweezerMutableDeviceWrapper {
     /// Args:
     ///     draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
     ///     pixel_per_point (Optional[float]): The quality of the image.
-    ///     file_save_path (Optional[str]): Path to save the image to. Default: output the image with the display method.
+    ///     file_save_path (Optional[str]): Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics. Default: output the image with the display method.
+    ///     show_tweezer_labels (Optional[bool]): Whether to label the tweezers with their index. Default: true
+    ///     show_qubit_labels (Optional[bool]): Whether to label the tweezers with the qubit they hold. Default: true
+    ///     show_legend (Optional[bool]): Whether to add a legend explaining the labels and edges. Default: false
+    ///     dpi (Optional[float]): The resolution of the image in dots per inch, overrides pixel_per_point.
     ///
     /// Raises:
     ///     PyValueError - if there is no layout, an error occurred during the compilation or and invalid path was provided.
     ///
-    #[pyo3(text_signature = "(draw_shifts, pixel_per_point, file_save_path, /)")]
+    #[pyo3(
+        text_signature = "(draw_shifts, pixel_per_point, file_save_path, show_tweezer_labels, show_qubit_labels, show_legend, dpi, /)"
+    )]
+    #[allow(clippy::too_many_arguments)]
     pub fn draw(
         &self,
         draw_shifts: Option<bool>,
         pixel_per_point: Option<f32>,
         file_save_path: Option<String>,
+        show_tweezer_labels: Option<bool>,
+        show_qubit_labels: Option<bool>,
+        show_legend: Option<bool>,
+        dpi: Option<f32>,
     ) -> PyResult<()> {
+        let options = DrawOptions {
+            show_tweezer_labels: show_tweezer_labels.unwrap_or(true),
+            show_qubit_labels: show_qubit_labels.unwrap_or(true),
+            show_legend: show_legend.unwrap_or(false),
+            dpi,
+        };
         let image = self
             .internal
-            .draw(
+            .draw_with_options(
                 pixel_per_point,
                 draw_shifts.unwrap_or(false),
                 &file_save_path,
+                &options,
             )
             .map_err(|x| PyValueError::new_err(format!("Error during Circuit drawing: {x:?}")))?;
 
         if file_save_path.is_none() {
-            let mut buffer = Cursor::new(Vec::new());
-            image
-                .write_to(&mut buffer, image::ImageFormat::Png)
-                .map_err(|x| {
-                    PyValueError::new_err(format!(
-                        "Error during the generation of the Png file: {x:?}"
-                    ))
-                })?;
-            Python::with_gil(|py| {
-                let pil = PyModule::import_bound(py, "PIL.Image").unwrap();
-                let io = PyModule::import_bound(py, "io").unwrap();
-                let display = PyModule::import_bound(py, "IPython.display").unwrap();
-                let builtins = PyModule::import_bound(py, "builtins").unwrap();
-
-                let bytes_image_data = builtins
-                    .call_method1("bytes", (buffer.clone().into_inner(),))
-                    .unwrap();
-                let bytes_io = io.call_method1("BytesIO", (bytes_image_data,)).unwrap();
-                let image = pil.call_method1("open", (bytes_io,)).unwrap();
-
-                display.call_method1("display", (image,)).unwrap();
-            });
+            display_drawing(&image)?;
+        }
+        Ok(())
+    }
+
+    /// Creates a graph showing several Layouts of the TweezerDevice next to each other.
+    ///
+    /// Tweezers whose available gates differ between the Layouts are highlighted.
+    ///
+    /// Args:
+    ///     layout_names (List[str]): The names of the Layouts to draw, from left to right.
+    ///     draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+    ///     pixel_per_point (Optional[float]): The quality of the image.
+    ///     file_save_path (Optional[str]): Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics. Default: output the image with the display method.
+    ///     show_legend (Optional[bool]): Whether to add a legend explaining the labels, edges and highlighted tweezers. Default: false
+    ///
+    /// Raises:
+    ///     ValueError: if no Layout is given, a Layout is not present, an error occurred during the compilation or an invalid path was provided.
+    ///
+    #[pyo3(
+        text_signature = "(layout_names, draw_shifts, pixel_per_point, file_save_path, show_legend, /)"
+    )]
+    pub fn draw_layouts(
+        &self,
+        layout_names: Vec<String>,
+        draw_shifts: Option<bool>,
+        pixel_per_point: Option<f32>,
+        file_save_path: Option<String>,
+        show_legend: Option<bool>,
+    ) -> PyResult<()> {
+        let options = DrawOptions {
+            show_legend: show_legend.unwrap_or(false),
+            ..Default::default()
+        };
+        let layout_names: Vec<&str> = layout_names.iter().map(String::as_str).collect();
+        let image = self
+            .internal
+            .draw_layouts(
+                &layout_names,
+                pixel_per_point,
+                draw_shifts.unwrap_or(false),
+                &file_save_path,
+                &options,
+            )
+            .map_err(|x| PyValueError::new_err(format!("Error during Layout drawing: {x:?}")))?;
+        if file_save_path.is_none() {
+            display_drawing(&image)?;
         }
         Ok(())
     }
+
+    /// Creates one drawing of the TweezerDevice per device-changing step of a circuit.
+    ///
+    /// The first frame shows the device before the circuit. The PragmaChangeDevice operations of
+    /// the circuit, e.g. Layout switches, shifts and deactivations, are applied in order to a copy
+    /// of the device and each adds a frame. The device itself is not changed.
+    ///
+    /// Args:
+    ///     circuit (Circuit): The circuit whose device-changing operations are drawn.
+    ///     file_save_path (str): Path to save the drawings to. A `.gif` file is saved as an animated GIF, otherwise every frame is saved with its index appended to the file name.
+    ///     draw_shifts (Optional[bool]): Whether to draw shifts or not. Default: false
+    ///     pixel_per_point (Optional[float]): The quality of the images.
+    ///     frame_delay_ms (Optional[int]): The time each frame of the animated GIF is shown in milliseconds. Default: 500
+    ///
+    /// Raises:
+    ///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+    ///     ValueError: if there is no layout, a device-changing operation is not valid on the device, an error occurred during the compilation or an invalid path was provided.
+    ///
+    #[pyo3(
+        text_signature = "(circuit, file_save_path, draw_shifts, pixel_per_point, frame_delay_ms, /)"
+    )]
+    pub fn draw_circuit_shifts(
+        &self,
+        circuit: &Bound<PyAny>,
+        file_save_path: String,
+        draw_shifts: Option<bool>,
+        pixel_per_point: Option<f32>,
+        frame_delay_ms: Option<u32>,
+    ) -> PyResult<()> {
+        let circuit = convert_into_circuit(circuit).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "Circuit argument cannot be converted to qoqo Circuit {:?}",
+                err
+            ))
+        })?;
+        self.internal
+            .draw_circuit_shifts(
+                &circuit,
+                pixel_per_point,
+                draw_shifts.unwrap_or(false),
+                &Some(file_save_path),
+                frame_delay_ms.unwrap_or(500),
+                &DrawOptions::default(),
+            )
+            .map_err(|x| PyValueError::new_err(format!("Error during Circuit drawing: {x:?}")))?;
+        Ok(())
+    }
+
+    /// Rewrites the legacy QRyd pragmas of a circuit into the tweezer pragmas.
+    ///
+    /// A PragmaChangeQRydLayout to the layout number `n` becomes a PragmaSwitchDeviceLayout to the
+    /// Layout named `n`. A PragmaShiftQRydQubit becomes a parallel PragmaShiftQubitsTweezers moving
+    /// every qubit to the tweezer of its new position, with the tweezers numbered row by row.
+    /// Legacy pragmas that can not be converted are left unchanged. The device itself is not changed.
+    ///
+    /// Args:
+    ///     circuit (Circuit): The circuit to convert.
+    ///
+    /// Returns:
+    ///     Circuit: The circuit with the convertible legacy pragmas replaced.
+    ///
+    /// Raises:
+    ///     TypeError: Circuit argument cannot be converted to qoqo Circuit.
+    #[pyo3(text_signature = "(circuit, /)")]
+    pub fn upgrade_circuit(&self, circuit: &Bound<PyAny>) -> PyResult<CircuitWrapper> {
+        let circuit = convert_into_circuit(circuit).map_err(|err| {
+            PyTypeError::new_err(format!(
+                "Circuit argument cannot be converted to qoqo Circuit {:?}",
+                err
+            ))
+        })?;
+        Ok(CircuitWrapper {
+            internal: upgrade_circuit(&circuit, &self.internal),
+        })
+    }
 }
 
 impl TweezerMutableDeviceWrapper {
@@ -1632,6 +4070,91 @@ impl From<TweezerMutableDeviceWrapper> for TweezerDeviceWrapper {
     }
 }
 
+/// Checks that the gates of all Layouts of a device are native gates of the device.
+fn check_native_gates(device: &TweezerDevice) -> PyResult<()> {
+    let mut all_gates_names: HashSet<&str> = HashSet::new();
+    for layout in device.available_layouts() {
+        all_gates_names.extend(
+            &device
+                .get_available_gates_names(Some(layout.to_string()))
+                .unwrap(),
+        );
+    }
+    let native_gates = device.get_native_gates();
+    if all_gates_names
+        .iter()
+        .any(|name| !native_gates.contains(name))
+        || all_gates_names.is_empty()
+    {
+        return Err(PyValueError::new_err(
+            "The device does not support valid gates in a layout. ".to_owned()
+                + "The valid gates are: "
+                + &native_gates
+                    .single_qubit_gates
+                    .iter()
+                    .chain(&native_gates.two_qubit_gates)
+                    .chain(&native_gates.three_qubit_gates)
+                    .chain(&native_gates.multi_qubit_gates)
+                    .map(String::as_str)
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+                + ".",
+        ));
+    }
+    Ok(())
+}
+
+/// Converts the name of a file format to a [DeviceFileFormat].
+fn device_file_format(format: Option<&str>) -> PyResult<Option<DeviceFileFormat>> {
+    match format {
+        None => Ok(None),
+        Some("json") => Ok(Some(DeviceFileFormat::Json)),
+        Some("bincode") => Ok(Some(DeviceFileFormat::Bincode)),
+        Some("yaml") => Ok(Some(DeviceFileFormat::Yaml)),
+        Some(other) => Err(PyValueError::new_err(format!(
+            "Unknown file format {}, use one of json, bincode or yaml.",
+            other
+        ))),
+    }
+}
+
+/// Converts the name of a mapping policy to a [TrivialMappingPolicy].
+fn trivial_mapping_policy(name: &str) -> PyResult<TrivialMappingPolicy> {
+    TrivialMappingPolicy::from_name(name).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unknown mapping policy {}, use one of all-tweezers, usable-tweezers, occupied-first or none.",
+            name
+        ))
+    })
+}
+
+/// Displays a drawing with the display method of IPython.
+pub(crate) fn display_drawing(image: &image::DynamicImage) -> PyResult<()> {
+    let mut buffer = Cursor::new(Vec::new());
+    image
+        .write_to(&mut buffer, image::ImageFormat::Png)
+        .map_err(|x| {
+            PyValueError::new_err(format!(
+                "Error during the generation of the Png file: {x:?}"
+            ))
+        })?;
+    Python::with_gil(|py| {
+        let pil = PyModule::import_bound(py, "PIL.Image").unwrap();
+        let io = PyModule::import_bound(py, "io").unwrap();
+        let display = PyModule::import_bound(py, "IPython.display").unwrap();
+        let builtins = PyModule::import_bound(py, "builtins").unwrap();
+
+        let bytes_image_data = builtins
+            .call_method1("bytes", (buffer.clone().into_inner(),))
+            .unwrap();
+        let bytes_io = io.call_method1("BytesIO", (bytes_image_data,)).unwrap();
+        let image = pil.call_method1("open", (bytes_io,)).unwrap();
+
+        display.call_method1("display", (image,)).unwrap();
+    });
+    Ok(())
+}
+
 /// Convert generic python object to [roqoqo_qryd::TweezerDevice].
 ///
 /// Fallible conversion of generic python object to [roqoqo_qryd::TweezerDevice].
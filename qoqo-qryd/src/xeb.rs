@@ -0,0 +1,252 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cross-entropy benchmarking (XEB).
+
+use crate::emulator_devices::EmulatorDeviceWrapper;
+use crate::tweezer_devices::convert_into_device;
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use qoqo::CircuitWrapper;
+use roqoqo::registers::BitOutputRegister;
+use roqoqo_qryd::xeb::{linear_xeb_fidelity, XEBExperiment, XEBResult, XEB_READOUT};
+use std::collections::HashMap;
+
+/// Experiment builder for cross-entropy benchmarking.
+///
+/// Each cycle consists of a layer of random `RotateXY(pi/2, phi)` rotations on all qubits,
+/// with `phi` a multiple of `pi/4`, followed by `ControlledPauliZ` gates on a set of
+/// non-overlapping qubit pairs connected on the device. A final rotation layer precedes
+/// the measurement. The same experiment can be run on the SimulatorBackend and the APIBackend.
+///
+/// Args:
+///     device (Union[TweezerDevice, EmulatorDevice]): The device the circuits are generated for.
+///     qubits (List[int]): The benchmarked qubits.
+///     depths (List[int]): The positive numbers of cycles to benchmark.
+///     number_circuits (int): The number of random circuits for each depth.
+///     number_measurements (int): The number of measurements performed for each circuit.
+///     seed (Optional[int]): The seed of the random circuit generation.
+///
+/// Raises:
+///     TypeError: The device is not a TweezerDevice or EmulatorDevice.
+///     ValueError: Invalid arguments or the device does not support `RotateXY` on all qubits.
+#[pyclass(name = "XEBExperiment", module = "qoqo_qryd")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct XEBExperimentWrapper {
+    /// Internal storage of [roqoqo_qryd::xeb::XEBExperiment]
+    pub internal: XEBExperiment,
+}
+
+#[pymethods]
+impl XEBExperimentWrapper {
+    /// Creates a new XEBExperiment.
+    ///
+    /// Args:
+    ///     device (Union[TweezerDevice, EmulatorDevice]): The device the circuits are generated for.
+    ///     qubits (List[int]): The benchmarked qubits.
+    ///     depths (List[int]): The positive numbers of cycles to benchmark.
+    ///     number_circuits (int): The number of random circuits for each depth.
+    ///     number_measurements (int): The number of measurements performed for each circuit.
+    ///     seed (Optional[int]): The seed of the random circuit generation.
+    ///
+    /// Returns:
+    ///     XEBExperiment: The new XEBExperiment.
+    ///
+    /// Raises:
+    ///     TypeError: The device is not a TweezerDevice or EmulatorDevice.
+    ///     ValueError: Invalid arguments or the device does not support `RotateXY` on all qubits.
+    #[new]
+    #[pyo3(
+        text_signature = "(device, qubits, depths, number_circuits, number_measurements, seed, /)"
+    )]
+    pub fn new(
+        device: &Bound<PyAny>,
+        qubits: Vec<usize>,
+        depths: Vec<usize>,
+        number_circuits: usize,
+        number_measurements: usize,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        let internal = if let Ok(emulator) = device.downcast::<EmulatorDeviceWrapper>() {
+            XEBExperiment::new(
+                &emulator.borrow().internal,
+                qubits,
+                depths,
+                number_circuits,
+                number_measurements,
+                seed,
+            )
+        } else {
+            let device = convert_into_device(device).map_err(|err| {
+                PyTypeError::new_err(format!(
+                    "Device Parameter is not TweezerDevice or EmulatorDevice {:?}",
+                    err
+                ))
+            })?;
+            XEBExperiment::new(
+                &device,
+                qubits,
+                depths,
+                number_circuits,
+                number_measurements,
+                seed,
+            )
+        }
+        .map_err(|err| PyValueError::new_err(format!("{:}", err)))?;
+        Ok(Self { internal })
+    }
+
+    /// Returns the benchmarked qubits.
+    ///
+    /// Returns:
+    ///     List[int]: The benchmarked qubits.
+    pub fn qubits(&self) -> Vec<usize> {
+        self.internal.qubits().to_vec()
+    }
+
+    /// Returns the number of measurements performed for each circuit.
+    ///
+    /// Returns:
+    ///     int: The number of measurements.
+    pub fn number_measurements(&self) -> usize {
+        self.internal.number_measurements()
+    }
+
+    /// Returns the number of cycles of each circuit, in the order of `circuits`.
+    ///
+    /// Returns:
+    ///     List[int]: The number of cycles of each circuit.
+    pub fn depths(&self) -> Vec<usize> {
+        self.internal.depths().to_vec()
+    }
+
+    /// Returns the measurement circuits of the experiment.
+    ///
+    /// The i-th benchmarked qubit is measured into the i-th bit of the `ro_xeb` register.
+    ///
+    /// Returns:
+    ///     List[Circuit]: The measurement circuits.
+    pub fn circuits(&self) -> Vec<CircuitWrapper> {
+        self.internal
+            .circuits()
+            .into_iter()
+            .map(|internal| CircuitWrapper { internal })
+            .collect()
+    }
+
+    /// Returns the ideal output probabilities of each circuit.
+    ///
+    /// Bit `i` of the basis state index corresponds to the i-th benchmarked qubit.
+    ///
+    /// Returns:
+    ///     List[List[float]]: The ideal output probabilities.
+    pub fn ideal_probabilities(&self) -> Vec<Vec<f64>> {
+        self.internal.ideal_probabilities()
+    }
+
+    /// Estimates the fidelities from the measured bit registers.
+    ///
+    /// Args:
+    ///     registers (List[List[List[bool]]]): The measured bit registers, one per circuit.
+    ///
+    /// Returns:
+    ///     Dict[str, Any]: The depths, the normalized linear XEB fidelities per depth and the fitted fidelity_per_cycle.
+    ///
+    /// Raises:
+    ///     ValueError: The registers do not match the circuits of the experiment.
+    #[pyo3(text_signature = "($self, registers, /)")]
+    pub fn analyse(&self, registers: Vec<BitOutputRegister>) -> PyResult<Py<PyDict>> {
+        let result = self
+            .internal
+            .analyse(&registers)
+            .map_err(|err| PyValueError::new_err(format!("{:}", err)))?;
+        result_to_dict(result)
+    }
+
+    /// Runs the experiment on a backend and estimates the fidelities.
+    ///
+    /// Args:
+    ///     backend (Union[SimulatorBackend, APIBackend]): The backend the circuits are run on.
+    ///
+    /// Returns:
+    ///     Dict[str, Any]: The depths, the normalized linear XEB fidelities per depth and the fitted fidelity_per_cycle.
+    ///
+    /// Raises:
+    ///     RuntimeError: Running a circuit failed.
+    ///     ValueError: The analysis failed.
+    #[pyo3(text_signature = "($self, backend, /)")]
+    pub fn run(&self, backend: &Bound<PyAny>) -> PyResult<Py<PyDict>> {
+        let mut registers: Vec<BitOutputRegister> = Vec::new();
+        for circuit in self.circuits() {
+            let result = backend.call_method1("run_circuit", (circuit,))?;
+            let bit_registers: HashMap<String, BitOutputRegister> =
+                result.get_item(0)?.extract().map_err(|_| {
+                    PyRuntimeError::new_err("Backend did not return bit registers.")
+                })?;
+            let register = bit_registers.get(XEB_READOUT).ok_or_else(|| {
+                PyRuntimeError::new_err(format!(
+                    "Backend did not return the {} register.",
+                    XEB_READOUT
+                ))
+            })?;
+            registers.push(register.clone());
+        }
+        self.analyse(registers)
+    }
+}
+
+fn result_to_dict(result: XEBResult) -> PyResult<Py<PyDict>> {
+    Python::with_gil(|py| {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("depths", result.depths)?;
+        dict.set_item("fidelities", result.fidelities)?;
+        dict.set_item("fidelity_per_cycle", result.fidelity_per_cycle)?;
+        Ok(dict.unbind())
+    })
+}
+
+/// Returns the linear XEB fidelity `2^n * <p(x)> - 1` of a measured bit register.
+///
+/// Args:
+///     ideal_probabilities (List[float]): The ideal output probabilities of the circuit.
+///     register (List[List[bool]]): The measured bit register, one entry per shot.
+///
+/// Returns:
+///     float: The linear XEB fidelity.
+///
+/// Raises:
+///     ValueError: The register is empty or does not match the probabilities.
+#[pyfunction]
+#[pyo3(name = "linear_xeb_fidelity")]
+pub fn py_linear_xeb_fidelity(
+    ideal_probabilities: Vec<f64>,
+    register: Vec<Vec<bool>>,
+) -> PyResult<f64> {
+    linear_xeb_fidelity(&ideal_probabilities, &register)
+        .map_err(|err| PyValueError::new_err(format!("{:}", err)))
+}
+
+/// Cross-entropy benchmarking for QRyd devices.
+///
+/// .. autosummary::
+///    :toctree: generated/
+///
+///    XEBExperiment
+///    linear_xeb_fidelity
+///
+#[pymodule]
+pub fn xeb(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_class::<XEBExperimentWrapper>()?;
+    m.add_function(wrap_pyfunction!(py_linear_xeb_fidelity, m)?)?;
+    Ok(())
+}
@@ -28,7 +28,7 @@ use qoqo_qryd::api_backend::{convert_into_backend, APIBackendWrapper, Registers}
 use qoqo_qryd::api_devices::{QrydEmuSquareDeviceWrapper, QrydEmuTriangularDeviceWrapper};
 use qoqo_qryd::tweezer_devices::TweezerDeviceWrapper;
 use roqoqo_qryd::api_devices::{QRydAPIDevice, QrydEmuSquareDevice};
-use roqoqo_qryd::{APIBackend, QRydJobResult, QRydJobStatus, ResultCounts};
+use roqoqo_qryd::{APIBackend, QRydAPICapabilities, QRydJobResult, QRydJobStatus, ResultCounts};
 
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -355,10 +355,12 @@ async fn async_test_run_job() {
     let qryd_job_status_in_progress = QRydJobStatus {
         status: "in progress".to_string(),
         msg: "the job is still in progress".to_string(),
+        ..Default::default()
     };
     let qryd_job_status_completed = QRydJobStatus {
         status: "completed".to_string(),
         msg: "the job has been completed".to_string(),
+        ..Default::default()
     };
     let result_counts = ResultCounts {
         counts: HashMap::from([("0x1".to_string(), 100), ("0x4".to_string(), 20)]),
@@ -377,6 +379,7 @@ async fn async_test_run_job() {
         fusion_generated_gates: 100,
         executed_single_qubit_gates: 50,
         executed_two_qubit_gates: 50,
+        dev_diagnostics: None,
     };
     let _mock_post = Mock::given(method("POST"))
         .respond_with(
@@ -508,6 +511,7 @@ async fn async_test_run_circuit() {
     let qryd_job_status_completed = QRydJobStatus {
         status: "completed".to_string(),
         msg: "the job has been completed".to_string(),
+        ..Default::default()
     };
     let result_counts = ResultCounts {
         counts: HashMap::from([("0x0".to_string(), 40)]),
@@ -526,6 +530,7 @@ async fn async_test_run_circuit() {
         fusion_generated_gates: 100,
         executed_single_qubit_gates: 0,
         executed_two_qubit_gates: 0,
+        dev_diagnostics: None,
     };
 
     let _mock_post = Mock::given(method("POST"))
@@ -613,6 +618,7 @@ async fn async_test_run_measurement_registers() {
     let qryd_job_status_completed = QRydJobStatus {
         status: "completed".to_string(),
         msg: "the job has been completed".to_string(),
+        ..Default::default()
     };
     let result_counts = ResultCounts {
         counts: HashMap::from([("0x0".to_string(), 10)]),
@@ -631,6 +637,7 @@ async fn async_test_run_measurement_registers() {
         fusion_generated_gates: 100,
         executed_single_qubit_gates: 0,
         executed_two_qubit_gates: 0,
+        dev_diagnostics: None,
     };
 
     let _mock_post = Mock::given(method("POST"))
@@ -719,6 +726,7 @@ async fn async_test_run_measurement() {
     let qryd_job_status_completed = QRydJobStatus {
         status: "completed".to_string(),
         msg: "the job has been completed".to_string(),
+        ..Default::default()
     };
     let result_counts = ResultCounts {
         counts: HashMap::from([("0x0".to_string(), 40)]),
@@ -737,6 +745,7 @@ async fn async_test_run_measurement() {
         fusion_generated_gates: 100,
         executed_single_qubit_gates: 0,
         executed_two_qubit_gates: 0,
+        dev_diagnostics: None,
     };
 
     let _mock_post = Mock::given(method("POST"))
@@ -894,3 +903,458 @@ async fn test_dev() {
         assert!(internal.dev);
     });
 }
+
+#[tokio::test]
+async fn test_compress_payload() {
+    let wiremock_server = MockServer::start().await;
+    let port = wiremock_server.address().port().to_string();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<QrydEmuSquareDeviceWrapper>();
+        let device = device_type.call1((11,)).unwrap();
+
+        let backend_type: &Bound<PyType> = &py.get_type_bound::<APIBackendWrapper>();
+        let binding = backend_type
+            .call1((
+                device.downcast::<QrydEmuSquareDeviceWrapper>().unwrap(),
+                Option::<String>::None,
+                Option::<usize>::None,
+                port,
+                false,
+            ))
+            .unwrap();
+        let backend: &Bound<APIBackendWrapper> = binding.downcast::<APIBackendWrapper>().unwrap();
+
+        assert!(!backend
+            .call_method0("compress_payload")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        assert!(backend
+            .call_method1("set_compress_payload", (true,))
+            .is_ok());
+        assert!(backend
+            .call_method0("compress_payload")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        assert!(backend.borrow().internal.compress_payload());
+    });
+}
+
+#[tokio::test]
+async fn test_operation_whitelist() {
+    let wiremock_server = MockServer::start().await;
+    let port = wiremock_server.address().port().to_string();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<QrydEmuSquareDeviceWrapper>();
+        let device = device_type.call1((11,)).unwrap();
+
+        let backend_type: &Bound<PyType> = &py.get_type_bound::<APIBackendWrapper>();
+        let binding = backend_type
+            .call1((
+                device.downcast::<QrydEmuSquareDeviceWrapper>().unwrap(),
+                Option::<String>::None,
+                Option::<usize>::None,
+                port,
+                false,
+            ))
+            .unwrap();
+        let backend: &Bound<APIBackendWrapper> = binding.downcast::<APIBackendWrapper>().unwrap();
+        let supported = |backend: &Bound<APIBackendWrapper>| {
+            backend
+                .call_method0("supported_operations")
+                .unwrap()
+                .extract::<Vec<String>>()
+                .unwrap()
+        };
+
+        assert!(supported(backend).contains(&"RotateX".to_string()));
+        assert!(backend
+            .call_method1("allow_operation", ("PragmaDamping",))
+            .is_err());
+        backend.call_method1("set_dev", (true,)).unwrap();
+        backend
+            .call_method1("allow_operation", ("PragmaDamping",))
+            .unwrap();
+        backend
+            .call_method1("disallow_operation", ("RotateX",))
+            .unwrap();
+        assert!(supported(backend).contains(&"PragmaDamping".to_string()));
+        assert!(!supported(backend).contains(&"RotateX".to_string()));
+
+        backend.call_method0("reset_supported_operations").unwrap();
+        assert!(supported(backend).contains(&"RotateX".to_string()));
+        assert!(!supported(backend).contains(&"PragmaDamping".to_string()));
+    });
+}
+
+#[tokio::test]
+async fn test_client_config() {
+    let wiremock_server = MockServer::start().await;
+    let port = wiremock_server.address().port().to_string();
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<QrydEmuSquareDeviceWrapper>();
+        let device = device_type.call1((11,)).unwrap();
+
+        let backend_type: &Bound<PyType> = &py.get_type_bound::<APIBackendWrapper>();
+        let binding = backend_type
+            .call1((
+                device.downcast::<QrydEmuSquareDeviceWrapper>().unwrap(),
+                Option::<String>::None,
+                Option::<usize>::None,
+                port,
+                false,
+            ))
+            .unwrap();
+        let backend: &Bound<APIBackendWrapper> = binding.downcast::<APIBackendWrapper>().unwrap();
+
+        backend
+            .call_method1(
+                "set_client_config",
+                (4, Option::<f64>::None, 10.0, 5.0, Option::<f64>::None),
+            )
+            .unwrap();
+        let config = backend
+            .call_method0("client_config")
+            .unwrap()
+            .extract::<HashMap<String, Option<f64>>>()
+            .unwrap();
+        assert_eq!(config["pool_max_idle_per_host"], Some(4.0));
+        assert_eq!(config["pool_idle_timeout"], None);
+        assert_eq!(config["tcp_keepalive"], Some(10.0));
+        assert_eq!(config["request_timeout"], Some(5.0));
+        assert_eq!(config["connect_timeout"], None);
+
+        assert!(backend
+            .call_method1(
+                "set_client_config",
+                (
+                    Option::<usize>::None,
+                    Option::<f64>::None,
+                    Option::<f64>::None,
+                    -1.0,
+                    Option::<f64>::None
+                ),
+            )
+            .is_err());
+    });
+}
+
+#[tokio::test]
+async fn async_test_capabilities() {
+    let wiremock_server = MockServer::start().await;
+    let port = wiremock_server.address().port().to_string();
+    let capabilities = QRydAPICapabilities {
+        api_version: "v5_2".to_string(),
+        supported_operations: vec!["RotateX".to_string()],
+        supported_fields: vec![],
+    };
+    let _mock_capabilities = Mock::given(method("GET"))
+        .and(path("/capabilities"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&capabilities))
+        .expect(1)
+        .mount(&wiremock_server)
+        .await;
+
+    pyo3::prepare_freethreaded_python();
+    let backend = Python::with_gil(|py| {
+        create_valid_backend_with_square_device_mocked(py, Some(11), port).into_py(py)
+    });
+    let cloned_backend = backend.clone();
+    tokio::task::spawn_blocking(move || {
+        Python::with_gil(|py| {
+            let result = cloned_backend.call_method0(py, "capabilities").unwrap();
+            let api_version: String = result
+                .bind(py)
+                .get_item("api_version")
+                .unwrap()
+                .extract()
+                .unwrap();
+            let supported_operations: Vec<String> = result
+                .bind(py)
+                .get_item("supported_operations")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(api_version, "v5_2");
+            assert_eq!(supported_operations, vec!["RotateX".to_string()]);
+
+            let internal = cloned_backend
+                .bind(py)
+                .downcast::<APIBackendWrapper>()
+                .unwrap()
+                .borrow()
+                .internal
+                .clone();
+            assert_eq!(internal.recorded_capabilities(), Some(&capabilities));
+        })
+    })
+    .await
+    .unwrap();
+
+    wiremock_server.verify().await;
+}
+
+#[tokio::test]
+async fn async_test_job_status_queue_information() {
+    let wiremock_server = MockServer::start().await;
+    let port = wiremock_server.address().port().to_string();
+    let uri = wiremock_server.uri();
+    let qryd_job_status_queued = QRydJobStatus {
+        status: "pending".to_string(),
+        msg: "the job is waiting in the queue".to_string(),
+        queue_position: Some(2),
+        estimated_start_time: Some("2024-05-02T10:00:00Z".to_string()),
+        progress: None,
+    };
+    let _mock_status = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_queued))
+        .expect(1)
+        .mount(&wiremock_server)
+        .await;
+
+    pyo3::prepare_freethreaded_python();
+    let backend = Python::with_gil(|py| {
+        create_valid_backend_with_square_device_mocked(py, Some(11), port).into_py(py)
+    });
+    let cloned_backend = backend.clone();
+    tokio::task::spawn_blocking(move || {
+        Python::with_gil(|py| {
+            let status = cloned_backend
+                .call_method1(py, "get_job_status", (format!("{}/DummyLocation", uri),))
+                .unwrap();
+            let status = status.bind(py);
+            let queue_position: usize = status
+                .get_item("queue_position")
+                .unwrap()
+                .extract()
+                .unwrap();
+            let estimated_start_time: String = status
+                .get_item("estimated_start_time")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(queue_position, 2);
+            assert_eq!(estimated_start_time, "2024-05-02T10:00:00Z");
+            assert!(status.get_item("progress").is_err());
+        })
+    })
+    .await
+    .unwrap();
+
+    wiremock_server.verify().await;
+}
+
+#[tokio::test]
+async fn async_test_progress_callback() {
+    let wiremock_server = MockServer::start().await;
+    let port = wiremock_server.address().port().to_string();
+    let uri = wiremock_server.uri();
+    let qryd_job_status_completed = QRydJobStatus {
+        status: "completed".to_string(),
+        msg: "the job has been completed".to_string(),
+        progress: Some(1.0),
+        ..Default::default()
+    };
+    let qryd_job_result_completed = QRydJobResult {
+        data: ResultCounts {
+            counts: HashMap::from([("0x1".to_string(), 10)]),
+        },
+        ..Default::default()
+    };
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(201).insert_header("Location", &format!("{}/DummyLocation", uri)),
+        )
+        .expect(1)
+        .mount(&wiremock_server)
+        .await;
+    let _mock_status = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_completed))
+        .expect(1)
+        .mount(&wiremock_server)
+        .await;
+    let _mock_result = Mock::given(method("GET"))
+        .and(path("/DummyLocation/result"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_result_completed))
+        .expect(1)
+        .mount(&wiremock_server)
+        .await;
+
+    pyo3::prepare_freethreaded_python();
+    let backend = Python::with_gil(|py| {
+        create_valid_backend_with_square_device_mocked(py, Some(11), port).into_py(py)
+    });
+    let cloned_backend = backend.clone();
+    tokio::task::spawn_blocking(move || {
+        Python::with_gil(|py| {
+            let progresses = PyList::empty_bound(py);
+            cloned_backend
+                .call_method1(
+                    py,
+                    "set_progress_callback",
+                    (progresses.getattr("append").unwrap(),),
+                )
+                .unwrap();
+            assert!(cloned_backend
+                .call_method1(py, "set_progress_callback", ("not callable",))
+                .is_err());
+
+            let mut circuit = Circuit::new();
+            circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+            circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+            circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+            circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+            cloned_backend
+                .call_method1(py, "run_circuit", (CircuitWrapper { internal: circuit },))
+                .unwrap();
+
+            assert_eq!(progresses.len(), 1);
+            let progress = progresses.get_item(0).unwrap();
+            let status: String = progress.get_item("status").unwrap().extract().unwrap();
+            let attempt: usize = progress.get_item("attempt").unwrap().extract().unwrap();
+            let elapsed: f64 = progress.get_item("elapsed").unwrap().extract().unwrap();
+            let job_location: String = progress
+                .get_item("job_location")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(status, "completed");
+            assert_eq!(attempt, 1);
+            assert!(elapsed >= 0.0);
+            assert_eq!(job_location, format!("{}/DummyLocation", uri));
+
+            assert!(cloned_backend
+                .call_method1(py, "set_progress_callback", (Option::<PyObject>::None,))
+                .is_ok());
+        })
+    })
+    .await
+    .unwrap();
+
+    wiremock_server.verify().await;
+}
+
+#[tokio::test]
+async fn async_test_record_replay() {
+    let wiremock_server = MockServer::start().await;
+    let port = wiremock_server.address().port().to_string();
+    let uri = wiremock_server.uri();
+    let qryd_job_status_completed = QRydJobStatus {
+        status: "completed".to_string(),
+        msg: "the job has been completed".to_string(),
+        ..Default::default()
+    };
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(201).insert_header("Location", &format!("{}/DummyLocation", uri)),
+        )
+        .expect(1)
+        .mount(&wiremock_server)
+        .await;
+    let _mock_status = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_completed))
+        .expect(1)
+        .mount(&wiremock_server)
+        .await;
+
+    let fixture_file = env::temp_dir()
+        .join(format!("qoqo_qryd_fixtures_{}.json", port))
+        .to_string_lossy()
+        .to_string();
+    pyo3::prepare_freethreaded_python();
+    let recording_backend = Python::with_gil(|py| {
+        create_valid_backend_with_square_device_mocked(py, Some(11), port).into_py(py)
+    });
+    let replaying_backend = Python::with_gil(|py| {
+        create_valid_backend_with_square_device_mocked(py, Some(11), "1".to_string()).into_py(py)
+    });
+    let cloned_fixture_file = fixture_file.clone();
+    tokio::task::spawn_blocking(move || {
+        Python::with_gil(|py| {
+            assert!(recording_backend
+                .call_method1(py, "set_fixture_mode", ("rewind", &cloned_fixture_file))
+                .is_err());
+            recording_backend
+                .call_method1(py, "set_fixture_mode", ("record", &cloned_fixture_file))
+                .unwrap();
+            let mode: Option<String> = recording_backend
+                .call_method0(py, "fixture_mode")
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            assert_eq!(mode, Some("record".to_string()));
+
+            let program = create_quantum_program(true);
+            let job_location: String = recording_backend
+                .call_method1(py, "post_job", (program,))
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            let recorded_status = recording_backend
+                .call_method1(py, "get_job_status", (job_location.clone(),))
+                .unwrap();
+
+            replaying_backend
+                .call_method1(py, "set_fixture_mode", ("replay", &cloned_fixture_file))
+                .unwrap();
+            let replayed_status = replaying_backend
+                .call_method1(py, "get_job_status", (job_location,))
+                .unwrap();
+            let recorded_status: HashMap<String, String> = recorded_status.extract(py).unwrap();
+            let replayed_status: HashMap<String, String> = replayed_status.extract(py).unwrap();
+            assert_eq!(replayed_status, recorded_status);
+            assert_eq!(
+                replayed_status.get("status"),
+                Some(&"completed".to_string())
+            );
+
+            replaying_backend
+                .call_method0(py, "clear_fixture_mode")
+                .unwrap();
+            let mode: Option<String> = replaying_backend
+                .call_method0(py, "fixture_mode")
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            assert_eq!(mode, None);
+        })
+    })
+    .await
+    .unwrap();
+
+    wiremock_server.verify().await;
+    std::fs::remove_file(&fixture_file).unwrap();
+}
+
+/// Test converting counts to a numpy array of the measured bits
+#[test]
+fn test_counts_to_array() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let backend_type = py.get_type_bound::<APIBackendWrapper>();
+        let counts: HashMap<String, u64> =
+            HashMap::from([("0x1".to_string(), 3), ("0x2".to_string(), 1)]);
+        let result = backend_type
+            .call_method1("counts_to_array", (counts, 2))
+            .unwrap();
+        let bits = result.extract::<numpy::PyReadonlyArray2<bool>>().unwrap();
+        let bits = bits.as_array();
+        assert_eq!(bits.shape(), &[4, 2]);
+        for row in 0..3 {
+            assert!(bits[[row, 0]] && !bits[[row, 1]]);
+        }
+        assert!(!bits[[3, 0]] && bits[[3, 1]]);
+
+        let invalid_counts: HashMap<String, u64> = HashMap::from([("1x1".to_string(), 3)]);
+        assert!(backend_type
+            .call_method1("counts_to_array", (invalid_counts, 2))
+            .is_err());
+    });
+}
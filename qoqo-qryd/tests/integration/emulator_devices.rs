@@ -154,6 +154,190 @@ fn test_available_gate_names() {
     })
 }
 
+/// Test remove_available_gate, set_available_gates and the arity queries for EmulatorDeviceWrapper
+#[test]
+fn test_available_gates_management() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<EmulatorDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+
+        assert!(device
+            .call_method1("set_available_gates", (vec!["RotateX", "error"],))
+            .is_err());
+        device
+            .call_method1(
+                "set_available_gates",
+                (vec!["RotateX", "CNOT", "Toffoli", "MultiQubitZZ"],),
+            )
+            .unwrap();
+
+        let single = device
+            .call_method0("get_available_single_qubit_gates")
+            .unwrap()
+            .extract::<Vec<String>>()
+            .unwrap();
+        assert_eq!(single, vec!["RotateX".to_string()]);
+        let two = device
+            .call_method0("get_available_two_qubit_gates")
+            .unwrap()
+            .extract::<Vec<String>>()
+            .unwrap();
+        assert_eq!(two, vec!["CNOT".to_string()]);
+        let three = device
+            .call_method0("get_available_three_qubit_gates")
+            .unwrap()
+            .extract::<Vec<String>>()
+            .unwrap();
+        assert_eq!(three, vec!["Toffoli".to_string()]);
+        let multi = device
+            .call_method0("get_available_multi_qubit_gates")
+            .unwrap()
+            .extract::<Vec<String>>()
+            .unwrap();
+        assert_eq!(multi, vec!["MultiQubitZZ".to_string()]);
+
+        assert!(device
+            .call_method1("remove_available_gate", ("SWAP",))
+            .is_err());
+        device
+            .call_method1("remove_available_gate", ("CNOT",))
+            .unwrap();
+        let two = device
+            .call_method0("get_available_two_qubit_gates")
+            .unwrap()
+            .extract::<Vec<String>>()
+            .unwrap();
+        assert!(two.is_empty());
+    })
+}
+
+/// Test set_allowed_edges for EmulatorDeviceWrapper
+#[test]
+fn test_allowed_edges() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<EmulatorDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+        device
+            .call_method1("add_available_gate", ("CNOT",))
+            .unwrap();
+
+        assert!(device.call_method0("allowed_edges").unwrap().is_none());
+        assert!(device
+            .call_method1("set_allowed_edges", (vec![(2, 2)],))
+            .is_err());
+        device
+            .call_method1("set_allowed_edges", (vec![(1, 0)],))
+            .unwrap();
+        let edges = device
+            .call_method0("allowed_edges")
+            .unwrap()
+            .extract::<Option<Vec<(usize, usize)>>>()
+            .unwrap();
+        assert_eq!(edges, Some(vec![(0, 1)]));
+
+        assert!(device
+            .call_method1("two_qubit_gate_time", ("CNOT", 0, 1))
+            .is_ok());
+        assert!(device
+            .call_method1("two_qubit_gate_time", ("CNOT", 0, 2))
+            .is_err());
+    })
+}
+
+/// Test set_number_qubits for EmulatorDeviceWrapper
+#[test]
+fn test_number_qubits_limit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<EmulatorDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+        device
+            .call_method1("add_qubit_tweezer_mapping", (2, 2))
+            .unwrap();
+
+        assert!(device.call_method1("set_number_qubits", (2,)).is_err());
+        device.call_method1("set_number_qubits", (5,)).unwrap();
+        let number_qubits = device
+            .call_method0("number_qubits")
+            .unwrap()
+            .extract::<usize>()
+            .unwrap();
+        assert_eq!(number_qubits, 5);
+
+        let none: Option<usize> = None;
+        device.call_method1("set_number_qubits", (none,)).unwrap();
+        let number_qubits = device
+            .call_method0("number_qubits")
+            .unwrap()
+            .extract::<usize>()
+            .unwrap();
+        assert_eq!(number_qubits, 3);
+    })
+}
+
+/// Test the noise parameters of EmulatorDeviceWrapper
+#[test]
+fn test_noise_parameters() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<EmulatorDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+        let none: Option<f64> = None;
+
+        assert!(!device
+            .call_method0("is_noisy")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        assert!(device
+            .call_method1("set_noise_parameters", (none, none, 2.0))
+            .is_err());
+        device
+            .call_method1("set_noise_parameters", (0.1, none, 0.05))
+            .unwrap();
+        assert!(device
+            .call_method0("is_noisy")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        assert_eq!(
+            device
+                .call_method0("damping")
+                .unwrap()
+                .extract::<Option<f64>>()
+                .unwrap(),
+            Some(0.1)
+        );
+        assert_eq!(
+            device
+                .call_method0("dephasing")
+                .unwrap()
+                .extract::<Option<f64>>()
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            device
+                .call_method0("spam_error")
+                .unwrap()
+                .extract::<Option<f64>>()
+                .unwrap(),
+            Some(0.05)
+        );
+
+        device
+            .call_method1("set_noise_parameters", (none, none, none))
+            .unwrap();
+        assert!(!device
+            .call_method0("is_noisy")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+    })
+}
+
 /// Test allow_reset for EmulatorDeviceWrapper
 #[test]
 fn test_allow_reset() {
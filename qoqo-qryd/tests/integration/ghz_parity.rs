@@ -0,0 +1,99 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the GHZ parity experiment
+
+use pyo3::prelude::*;
+use qoqo::CircuitWrapper;
+use qoqo_qryd::GHZParityExperimentWrapper;
+use std::collections::HashMap;
+
+/// Test new instantiation and circuits of GHZParityExperimentWrapper
+#[test]
+fn test_new_circuits() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let experiment_type = py.get_type_bound::<GHZParityExperimentWrapper>();
+        let experiment = experiment_type.call1((vec![0, 1, 2], 7, 50)).unwrap();
+        assert_eq!(
+            experiment
+                .call_method0("qubits")
+                .unwrap()
+                .extract::<Vec<usize>>()
+                .unwrap(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            experiment
+                .call_method0("phases")
+                .unwrap()
+                .extract::<Vec<f64>>()
+                .unwrap()
+                .len(),
+            7
+        );
+        let preparation = experiment
+            .call_method0("preparation_circuit")
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert_eq!(
+            preparation.internal.count_occurences(&["ControlledPauliZ"]),
+            2
+        );
+        let circuits = experiment
+            .call_method0("circuits")
+            .unwrap()
+            .extract::<Vec<CircuitWrapper>>()
+            .unwrap();
+        assert_eq!(circuits.len(), 8);
+
+        assert!(experiment_type.call1((vec![0], 7, 50)).is_err());
+        assert!(experiment_type.call1((vec![0, 1, 2], 6, 50)).is_err());
+    })
+}
+
+/// Test the analysis of GHZParityExperimentWrapper
+#[test]
+fn test_analyse() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let experiment_type = py.get_type_bound::<GHZParityExperimentWrapper>();
+        let experiment = experiment_type.call1((vec![0, 1], 8, 2)).unwrap();
+        let even = vec![vec![false, false], vec![true, true]];
+        let odd = vec![vec![true, false], vec![false, true]];
+        let mixed = vec![vec![false, false], vec![true, false]];
+        let mut registers = vec![even.clone()];
+        for index in 0..8 {
+            registers.push(match index % 4 {
+                0 => even.clone(),
+                2 => odd.clone(),
+                _ => mixed.clone(),
+            });
+        }
+        let result = experiment
+            .call_method1("analyse", (registers.clone(),))
+            .unwrap()
+            .extract::<HashMap<String, PyObject>>()
+            .unwrap();
+        let fidelity: f64 = result.get("fidelity").unwrap().extract(py).unwrap();
+        assert!((fidelity - 1.0).abs() < 1e-12);
+        let populations: f64 = result.get("populations").unwrap().extract(py).unwrap();
+        assert_eq!(populations, 1.0);
+        let parities: Vec<f64> = result.get("parities").unwrap().extract(py).unwrap();
+        assert_eq!(parities.len(), 8);
+
+        assert!(experiment
+            .call_method1("analyse", (registers[1..].to_vec(),))
+            .is_err());
+    })
+}
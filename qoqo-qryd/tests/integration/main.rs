@@ -29,6 +29,18 @@ mod operations;
 #[cfg(test)]
 mod emulator_devices;
 
+#[cfg(test)]
+mod tomography;
+
+#[cfg(test)]
+mod ghz_parity;
+
+#[cfg(test)]
+mod xeb;
+
+#[cfg(test)]
+mod noise_fitting;
+
 #[cfg(test)]
 #[cfg(feature = "simulator")]
 mod simulator_backend;
@@ -42,3 +54,190 @@ fn test_device_from_api() {
     assert!(response.is_ok());
     // TODO: add more specific testing once the available devices gathered from the API endpoint can be distinguished
 }
+
+#[test]
+fn test_plot_counts() {
+    use qoqo_qryd::{counts_histogram, plot_counts};
+    use std::collections::HashMap;
+
+    let counts: HashMap<String, u64> =
+        HashMap::from([("01".to_string(), 10), ("00".to_string(), 30)]);
+    assert_eq!(
+        counts_histogram(counts.clone()),
+        vec![("00".to_string(), 30), ("01".to_string(), 10)]
+    );
+
+    pyo3::prepare_freethreaded_python();
+    assert!(plot_counts(counts, None, Some("counts_test_py.png".to_string())).is_ok());
+    assert!(std::path::Path::new("counts_test_py.png").exists());
+    std::fs::remove_file("counts_test_py.png").unwrap();
+
+    assert!(plot_counts(HashMap::new(), None, Some("counts_test_py.png".to_string())).is_err());
+}
+
+#[test]
+fn test_sanitize_for_api() {
+    use pyo3::prelude::*;
+    use qoqo::CircuitWrapper;
+    use qoqo_qryd::sanitize_for_api;
+    use roqoqo::{operations, Circuit};
+
+    let mut circuit = Circuit::new();
+    circuit += operations::RotateX::new(0, 1.0.into());
+    circuit += operations::PragmaDepolarising::new(0, 0.1.into(), 0.01.into());
+    let mut expected = Circuit::new();
+    expected += operations::RotateX::new(0, 1.0.into());
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = CircuitWrapper { internal: circuit }.into_py(py);
+        let (sanitized, report) = sanitize_for_api(circuit.bind(py)).unwrap();
+        assert_eq!(sanitized.internal, expected);
+        let removed: Vec<String> = report
+            .bind(py)
+            .get_item("removed")
+            .unwrap()
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(removed, vec!["PragmaDepolarising".to_string()]);
+
+        let not_a_circuit = 3_u32.into_py(py);
+        assert!(sanitize_for_api(not_a_circuit.bind(py)).is_err());
+    });
+}
+
+#[test]
+fn test_substitute_circuit_parameters() {
+    use pyo3::prelude::*;
+    use qoqo::CircuitWrapper;
+    use qoqo_qryd::substitute_circuit_parameters;
+    use roqoqo::{operations, Circuit};
+    use roqoqo_qryd::PragmaGlobalRotateXY;
+    use std::collections::HashMap;
+
+    let global_rotation = |theta: &str| {
+        PragmaGlobalRotateXY::new(theta.into(), 0.5.into())
+            .to_pragma_change_device()
+            .unwrap()
+    };
+    let mut circuit = Circuit::new();
+    circuit += operations::RotateX::new(0, "theta".into());
+    circuit += global_rotation("theta");
+    let mut expected = Circuit::new();
+    expected += operations::RotateX::new(0, 1.0.into());
+    expected += PragmaGlobalRotateXY::new(1.0.into(), 0.5.into())
+        .to_pragma_change_device()
+        .unwrap();
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = CircuitWrapper { internal: circuit }.into_py(py);
+        let substituted = substitute_circuit_parameters(
+            circuit.bind(py),
+            HashMap::from([("theta".to_string(), 1.0)]),
+        )
+        .unwrap();
+        assert_eq!(substituted.internal, expected);
+        assert!(substitute_circuit_parameters(circuit.bind(py), HashMap::new()).is_err());
+    });
+}
+
+#[test]
+fn test_remap_circuit_qubits() {
+    use pyo3::prelude::*;
+    use qoqo::CircuitWrapper;
+    use qoqo_qryd::remap_circuit_qubits;
+    use roqoqo::{operations, Circuit};
+    use roqoqo_qryd::PragmaDeactivateQRydQubit;
+    use std::collections::HashMap;
+
+    let mut circuit = Circuit::new();
+    circuit += operations::PauliX::new(0);
+    circuit += PragmaDeactivateQRydQubit::new(0)
+        .to_pragma_change_device()
+        .unwrap();
+    let mut expected = Circuit::new();
+    expected += operations::PauliX::new(1);
+    expected += PragmaDeactivateQRydQubit::new(1)
+        .to_pragma_change_device()
+        .unwrap();
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let circuit = CircuitWrapper { internal: circuit }.into_py(py);
+        let remapped =
+            remap_circuit_qubits(circuit.bind(py), HashMap::from([(0, 1), (1, 0)])).unwrap();
+        assert_eq!(remapped.internal, expected);
+        let not_a_circuit: PyObject = 0.into_py(py);
+        assert!(remap_circuit_qubits(not_a_circuit.bind(py), HashMap::new()).is_err());
+    });
+}
+
+#[test]
+fn test_check_circuit_compatibility() {
+    use pyo3::prelude::*;
+    use qoqo::CircuitWrapper;
+    use qoqo_qryd::{check_circuit_compatibility, TweezerDeviceWrapper};
+    use roqoqo::{operations, Circuit};
+    use roqoqo_qryd::TweezerDevice;
+
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.1, Some("default".to_string()))
+        .unwrap();
+    device.switch_layout("default", Some(false)).unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    let mut circuit = Circuit::new();
+    circuit += operations::RotateX::new(0, 1.0.into());
+    circuit += operations::RotateY::new(0, 1.0.into());
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = TweezerDeviceWrapper { internal: device }.into_py(py);
+        let circuit = CircuitWrapper { internal: circuit }.into_py(py);
+        let reports = check_circuit_compatibility(circuit.bind(py), device.bind(py)).unwrap();
+        assert_eq!(reports.len(), 1);
+        let report = reports[0].bind(py);
+        assert_eq!(
+            report
+                .get_item("index")
+                .unwrap()
+                .unwrap()
+                .extract::<usize>()
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            report
+                .get_item("hqslang")
+                .unwrap()
+                .unwrap()
+                .extract::<String>()
+                .unwrap(),
+            "RotateY"
+        );
+        assert_eq!(
+            report
+                .get_item("layout")
+                .unwrap()
+                .unwrap()
+                .extract::<Option<String>>()
+                .unwrap(),
+            Some("default".to_string())
+        );
+
+        let compatible = CircuitWrapper {
+            internal: Circuit::new(),
+        }
+        .into_py(py);
+        assert!(
+            check_circuit_compatibility(compatible.bind(py), device.bind(py))
+                .unwrap()
+                .is_empty()
+        );
+        let not_a_device: PyObject = 0.into_py(py);
+        assert!(check_circuit_compatibility(circuit.bind(py), not_a_device.bind(py)).is_err());
+    });
+}
@@ -0,0 +1,147 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the noise fitting
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use qoqo_qryd::{
+    fit_decay_time, fit_depolarising_rate, fit_t1, TweezerMutableDeviceWrapper,
+    XEBExperimentWrapper,
+};
+use roqoqo_qryd::TweezerDevice;
+
+// Helper function creating a device with two connected tweezers
+fn create_device(py: Python) -> Py<TweezerMutableDeviceWrapper> {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..2 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateXY",
+                tweezer,
+                1.0,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device
+        .set_tweezer_two_qubit_gate_time("ControlledPauliZ", 0, 1, 1.0, Some("default".to_string()))
+        .unwrap();
+    device.switch_layout("default", None).unwrap();
+    Py::new(py, TweezerMutableDeviceWrapper { internal: device }).unwrap()
+}
+
+/// Test the noise setters and getters of TweezerMutableDeviceWrapper
+#[test]
+fn test_tweezer_noise() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = create_device(py);
+        let device = device.bind(py);
+        let noise = device.call_method1("get_tweezer_noise", (0,)).unwrap();
+        assert!(noise.get_item("t1").unwrap().is_none());
+
+        device.call_method1("set_tweezer_t1", (0, 10.0)).unwrap();
+        device.call_method1("set_tweezer_t2", (0, 5.0)).unwrap();
+        device
+            .call_method1("set_tweezer_depolarising_rate", (0, 0.01))
+            .unwrap();
+        let noise = device.call_method1("get_tweezer_noise", (0,)).unwrap();
+        assert_eq!(
+            noise.get_item("t1").unwrap().extract::<f64>().unwrap(),
+            10.0
+        );
+        assert_eq!(noise.get_item("t2").unwrap().extract::<f64>().unwrap(), 5.0);
+        assert_eq!(
+            noise
+                .get_item("depolarising_rate")
+                .unwrap()
+                .extract::<f64>()
+                .unwrap(),
+            0.01
+        );
+        assert!(device.call_method1("set_tweezer_t1", (0, -1.0)).is_err());
+
+        device.call_method0("clear_noise").unwrap();
+        let noise = device.call_method1("get_tweezer_noise", (0,)).unwrap();
+        assert!(noise.get_item("t2").unwrap().is_none());
+    })
+}
+
+/// Test fitting the decay times into a device
+#[test]
+fn test_fit_decay_times() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = create_device(py);
+        let times = vec![0.0, 1.0, 2.0];
+        let populations: Vec<f64> = times.iter().map(|t: &f64| (-t / 4.0).exp()).collect();
+        assert!((fit_decay_time(times.clone(), populations.clone()).unwrap() - 4.0).abs() < 1e-10);
+
+        let t1 = fit_t1(
+            device.bind(py).as_any(),
+            1,
+            times.clone(),
+            populations.clone(),
+        )
+        .unwrap();
+        assert!((t1 - 4.0).abs() < 1e-10);
+        assert_eq!(device.borrow(py).internal.get_tweezer_noise(1).t1, Some(t1));
+
+        assert!(fit_t1(
+            device.bind(py).as_any(),
+            5,
+            times.clone(),
+            populations.clone()
+        )
+        .is_err());
+        let not_a_device: PyObject = 1.into_py(py);
+        assert!(fit_t1(not_a_device.bind(py), 0, times, populations).is_err());
+    })
+}
+
+/// Test fitting the depolarising rate from an XEB result
+#[test]
+fn test_fit_depolarising_rate() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = create_device(py);
+        let experiment = py
+            .get_type_bound::<XEBExperimentWrapper>()
+            .call1((device.clone_ref(py), vec![0, 1], vec![1, 2], 2, 10, 1))
+            .unwrap();
+        let experiment = experiment.downcast::<XEBExperimentWrapper>().unwrap();
+        let result = PyDict::new_bound(py);
+        result.set_item("depths", vec![1, 2]).unwrap();
+        result.set_item("fidelities", vec![0.9, 0.81]).unwrap();
+        result.set_item("fidelity_per_cycle", 0.9).unwrap();
+
+        let rate =
+            fit_depolarising_rate(device.bind(py).as_any(), &experiment.borrow(), &result).unwrap();
+        // Each cycle: two RotateXY gates and one ControlledPauliZ on two qubits, all of time 1.0
+        assert!((rate - (-(0.9_f64).ln() / 4.0)).abs() < 1e-10);
+        assert_eq!(
+            device
+                .borrow(py)
+                .internal
+                .get_tweezer_noise(0)
+                .depolarising_rate,
+            Some(rate)
+        );
+
+        result.set_item("fidelity_per_cycle", py.None()).unwrap();
+        assert!(
+            fit_depolarising_rate(device.bind(py).as_any(), &experiment.borrow(), &result).is_err()
+        );
+    })
+}
@@ -12,15 +12,19 @@
 
 //! Integration test for public API of QRyd PragmaOperations
 
+use ndarray::array;
+use numpy::ToPyArray;
 use pyo3::prelude::*;
 use pyo3::Python;
 use std::collections::{HashMap, HashSet};
 
 use qoqo::operations::PragmaChangeDeviceWrapper;
 use qoqo_qryd::pragma_operations::{
-    PragmaChangeQRydLayoutWrapper, PragmaDeactivateQRydQubitWrapper, PragmaShiftQRydQubitWrapper,
-    PragmaShiftQubitsTweezersWrapper, PragmaSwitchDeviceLayoutWrapper,
+    PragmaChangeQRydLayoutWrapper, PragmaDeactivateQRydQubitWrapper, PragmaGlobalRotateXYWrapper,
+    PragmaMoveToZoneWrapper, PragmaSetPhaseRelationWrapper, PragmaShiftQRydQubitWrapper,
+    PragmaShiftQubitsTweezersWrapper, PragmaSwapTweezersWrapper, PragmaSwitchDeviceLayoutWrapper,
 };
+use qoqo_qryd::qryd_devices::FirstDeviceWrapper;
 
 fn new_pragma_layout(py: Python, layout: usize) -> Bound<PragmaChangeQRydLayoutWrapper> {
     let operation_type = py.get_type_bound::<PragmaChangeQRydLayoutWrapper>();
@@ -80,6 +84,20 @@ fn new_pragma_switch_layout(
         .to_owned()
 }
 
+fn new_pragma_swap_tweezers(
+    py: Python,
+    tweezer_0: usize,
+    tweezer_1: usize,
+) -> Bound<PragmaSwapTweezersWrapper> {
+    let operation_type = py.get_type_bound::<PragmaSwapTweezersWrapper>();
+    operation_type
+        .call1((tweezer_0, tweezer_1))
+        .unwrap()
+        .downcast::<PragmaSwapTweezersWrapper>()
+        .unwrap()
+        .to_owned()
+}
+
 #[test]
 fn test_pyo3_new_change_layout() {
     pyo3::prepare_freethreaded_python();
@@ -227,11 +245,51 @@ fn test_pyo3_new_shift_tweezers() {
 
         assert_eq!(
             format!("{:?}", pragma_wrapper),
-            "PragmaShiftQubitsTweezersWrapper { internal: PragmaShiftQubitsTweezers { shifts: [(0, 1)] } }"
+            "PragmaShiftQubitsTweezersWrapper { internal: PragmaShiftQubitsTweezers { shifts: [(0, 1)], durations: None, parallel: false } }"
         );
     })
 }
 
+#[test]
+fn test_pyo3_shift_tweezers_durations_parallel() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = py.get_type_bound::<PragmaShiftQubitsTweezersWrapper>();
+        let default_op = operation.call1((vec![(0, 1)],)).unwrap();
+        assert!(default_op
+            .call_method0("durations")
+            .unwrap()
+            .extract::<Option<Vec<f64>>>()
+            .unwrap()
+            .is_none());
+        assert!(!default_op
+            .call_method0("parallel")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+
+        let timed_op = operation
+            .call1((vec![(0, 1), (2, 3)], vec![0.5, 1.0], true))
+            .unwrap();
+        assert_eq!(
+            timed_op
+                .call_method0("durations")
+                .unwrap()
+                .extract::<Option<Vec<f64>>>()
+                .unwrap(),
+            Some(vec![0.5, 1.0])
+        );
+        assert!(timed_op
+            .call_method0("parallel")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        let comparison =
+            bool::extract_bound(&timed_op.call_method1("__eq__", (default_op,)).unwrap()).unwrap();
+        assert!(!comparison);
+    })
+}
+
 #[test]
 fn test_pyo3_new_switch_layout() {
     pyo3::prepare_freethreaded_python();
@@ -326,6 +384,54 @@ fn test_shift_positions_to_change_device() {
     });
 }
 
+#[test]
+fn test_shift_positions_builder() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<FirstDeviceWrapper>();
+        let device = device_type
+            .call1((
+                2,
+                3,
+                vec![2, 2],
+                1.0,
+                array![[0.0, 1.0, 2.0], [0.0, 1.0, 2.0]].to_pyarray_bound(py),
+            ))
+            .unwrap();
+        let operation_type = py.get_type_bound::<PragmaShiftQRydQubitWrapper>();
+        let operation = operation_type
+            .call_method1("from_device", (device.clone(),))
+            .unwrap();
+        assert!(operation
+            .call_method1("validate", (device.clone(),))
+            .is_ok());
+
+        let shifted = operation
+            .call_method1("shift_row", (0, 1))
+            .unwrap()
+            .call_method1("move_qubit", (2, 1, 2))
+            .unwrap();
+        let new_positions: HashMap<usize, (usize, usize)> = shifted
+            .call_method0("new_positions")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let mut map: HashMap<usize, (usize, usize)> = HashMap::new();
+        map.insert(0, (0, 1));
+        map.insert(1, (0, 2));
+        map.insert(2, (1, 2));
+        map.insert(3, (1, 1));
+        assert_eq!(new_positions, map);
+        assert!(shifted.call_method1("validate", (device.clone(),)).is_ok());
+
+        assert!(operation.call_method1("shift_row", (1, -1)).is_err());
+        let invalid = operation.call_method1("move_qubit", (0, 1, 2)).unwrap();
+        assert!(invalid.call_method1("validate", (device,)).is_err());
+        assert!(operation.call_method1("validate", (0,)).is_err());
+        assert!(operation_type.call_method1("from_device", (0,)).is_err());
+    });
+}
+
 #[test]
 fn test_deactivate_qubit_new() {
     pyo3::prepare_freethreaded_python();
@@ -397,6 +503,161 @@ fn test_switch_layout_to_change_device() {
     });
 }
 
+#[test]
+fn test_swap_tweezers_new() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = new_pragma_swap_tweezers(py, 0, 1);
+        let tweezer_0: usize = operation
+            .call_method0("tweezer_0")
+            .unwrap()
+            .extract()
+            .unwrap();
+        let tweezer_1: usize = operation
+            .call_method0("tweezer_1")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!((tweezer_0, tweezer_1), (0, 1));
+
+        let comparison = bool::extract_bound(
+            &operation
+                .call_method1("__eq__", (new_pragma_swap_tweezers(py, 1, 0),))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!comparison);
+    });
+}
+
+#[test]
+fn test_swap_tweezers_to_change_device() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = new_pragma_swap_tweezers(py, 0, 1);
+        let pragma_change_device = operation.call_method0("to_pragma_change_device").unwrap();
+        assert!(pragma_change_device
+            .downcast::<PragmaChangeDeviceWrapper>()
+            .is_ok())
+    });
+}
+
+#[test]
+fn test_move_to_zone_new() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation_type = py.get_type_bound::<PragmaMoveToZoneWrapper>();
+        let operation = operation_type.call1((vec![0, 1], "interaction")).unwrap();
+        let qubits: Vec<usize> = operation.call_method0("qubits").unwrap().extract().unwrap();
+        assert_eq!(qubits, vec![0, 1]);
+        let zone: String = operation.call_method0("zone").unwrap().extract().unwrap();
+        assert_eq!(zone, "interaction");
+
+        let comparison = bool::extract_bound(
+            &operation
+                .call_method1(
+                    "__eq__",
+                    (operation_type.call1((vec![0, 1], "storage")).unwrap(),),
+                )
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!comparison);
+
+        let pragma_change_device = operation.call_method0("to_pragma_change_device").unwrap();
+        assert!(pragma_change_device
+            .downcast::<PragmaChangeDeviceWrapper>()
+            .is_ok())
+    });
+}
+
+#[test]
+fn test_set_phase_relation_new() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation_type = py.get_type_bound::<PragmaSetPhaseRelationWrapper>();
+        let operation = operation_type.call1(("DefaultRelation", "0.5")).unwrap();
+        let cz_relation: String = operation
+            .call_method0("cz_relation")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(cz_relation, "DefaultRelation");
+        let cp_relation: String = operation
+            .call_method0("cp_relation")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(cp_relation, "0.5");
+
+        let comparison = bool::extract_bound(
+            &operation
+                .call_method1(
+                    "__eq__",
+                    (operation_type.call1(("0.5", "DefaultRelation")).unwrap(),),
+                )
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!comparison);
+
+        let pragma_change_device = operation.call_method0("to_pragma_change_device").unwrap();
+        assert!(pragma_change_device
+            .downcast::<PragmaChangeDeviceWrapper>()
+            .is_ok())
+    });
+}
+
+#[test]
+fn test_global_rotate_xy_new() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation_type = py.get_type_bound::<PragmaGlobalRotateXYWrapper>();
+        let operation = operation_type.call1((1.0, "phi")).unwrap();
+        let theta: f64 = operation
+            .call_method0("theta")
+            .unwrap()
+            .call_method0("__float__")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(theta, 1.0);
+        let phi: String = operation
+            .call_method0("phi")
+            .unwrap()
+            .call_method0("__str__")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(phi, "phi");
+        let is_parametrized: bool = operation
+            .call_method0("is_parametrized")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert!(is_parametrized);
+
+        let mut substitution_parameters: HashMap<String, f64> = HashMap::new();
+        substitution_parameters.insert("phi".to_string(), 0.0);
+        let substituted = operation
+            .call_method1("substitute_parameters", (substitution_parameters,))
+            .unwrap();
+        let comparison = bool::extract_bound(
+            &substituted
+                .call_method1("__eq__", (operation_type.call1((1.0, 0.0)).unwrap(),))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        assert!(operation_type.call1((vec![1.0], 0.0)).is_err());
+        let pragma_change_device = substituted.call_method0("to_pragma_change_device").unwrap();
+        assert!(pragma_change_device
+            .downcast::<PragmaChangeDeviceWrapper>()
+            .is_ok())
+    });
+}
+
 /// Test involved_qubits function for Pragmas
 #[test]
 fn test_pragmas_involved_qubits() {
@@ -614,6 +875,7 @@ fn test_pragmas_remap_qubits_deactivate_qubit() {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
         let operation = new_pragma_deactivate(py, 0);
+        let operation2 = new_pragma_deactivate(py, 2);
         let qubit_mapping: HashMap<usize, usize> = HashMap::new();
 
         let remapped_op = operation
@@ -626,8 +888,13 @@ fn test_pragmas_remap_qubits_deactivate_qubit() {
         let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
         qubit_mapping.insert(0, 2);
         qubit_mapping.insert(2, 0);
-        let remapped_op = operation.call_method1("remap_qubits", (qubit_mapping,));
-        assert!(remapped_op.is_err());
+        let remapped_op = operation
+            .call_method1("remap_qubits", (qubit_mapping,))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&remapped_op.call_method1("__eq__", (operation2,)).unwrap())
+                .unwrap();
+        assert!(comparison);
     });
 }
 
@@ -637,7 +904,6 @@ fn test_pragmas_remap_qubits_shift_tweezers() {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
         let operation = new_pragma_shift_tweezers(py, vec![(0, 1)]);
-        let operation2 = new_pragma_shift_tweezers(py, vec![(2, 1)]);
         let qubit_mapping: HashMap<usize, usize> = HashMap::new();
 
         let remapped_op = operation
@@ -655,8 +921,31 @@ fn test_pragmas_remap_qubits_shift_tweezers() {
             .call_method1("remap_qubits", (qubit_mapping,))
             .unwrap();
 
+        // The shifts refer to tweezers and are not relabeled with the qubits
         let comparison =
-            bool::extract_bound(&remapped_op.call_method1("__eq__", (operation2,)).unwrap())
+            bool::extract_bound(&remapped_op.call_method1("__eq__", (&operation,)).unwrap())
+                .unwrap();
+        assert!(comparison);
+    });
+}
+
+/// Test remap_qubits function for PragmaSwapTweezers
+#[test]
+fn test_pragmas_remap_qubits_swap_tweezers() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let operation = new_pragma_swap_tweezers(py, 0, 1);
+
+        let mut qubit_mapping: HashMap<usize, usize> = HashMap::new();
+        qubit_mapping.insert(0, 2);
+        qubit_mapping.insert(2, 0);
+        let remapped_op = operation
+            .call_method1("remap_qubits", (qubit_mapping,))
+            .unwrap();
+
+        // The tweezers are not relabeled with the qubits
+        let comparison =
+            bool::extract_bound(&remapped_op.call_method1("__eq__", (&operation,)).unwrap())
                 .unwrap();
         assert!(comparison);
     });
@@ -732,7 +1021,7 @@ fn test_pragmas_format_repr() {
                 "PragmaChangeQRydLayout { new_layout: 0 }",
                 "PragmaShiftQRydQubit { new_positions: {0: (0, 1)} }",
                 "PragmaDeactivateQRydQubit { qubit: 0 }",
-                "PragmaShiftQubitsTweezers { shifts: [(0, 1)] }",
+                "PragmaShiftQubitsTweezers { shifts: [(0, 1)], durations: None, parallel: false }",
                 "PragmaSwitchDeviceLayout { new_layout: \"Square\" }",
             ]
             .iter(),
@@ -340,6 +340,228 @@ fn test_change_qubit_positions() {
     });
 }
 
+/// Test ragged FirstDeviceWrapper with explicit tweezer positions
+#[test]
+fn test_ragged_device() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<FirstDeviceWrapper>();
+        let binding = device_type
+            .call_method1(
+                "ragged",
+                (
+                    vec![2, 1],
+                    vec![vec![(0.0, 0.0), (0.0, 1.0), (0.0, 2.0)], vec![(0.8, 0.5)]],
+                ),
+            )
+            .unwrap();
+        let device = binding.downcast::<FirstDeviceWrapper>().unwrap();
+
+        let columns_per_row = device
+            .call_method0("columns_per_row")
+            .unwrap()
+            .extract::<Vec<usize>>()
+            .unwrap();
+        assert_eq!(columns_per_row, vec![3, 1]);
+        let position = device
+            .call_method1("tweezer_position", (1, 0))
+            .unwrap()
+            .extract::<(f64, f64)>()
+            .unwrap();
+        assert_eq!(position, (0.8, 0.5));
+        assert!(device.call_method1("tweezer_position", (1, 1)).is_err());
+        assert!(device
+            .call_method1("two_qubit_gate_time", ("PhaseShiftedControlledZ", 0, 2))
+            .is_ok());
+
+        let new_device = device
+            .call_method1(
+                "add_irregular_layout",
+                (
+                    1,
+                    vec![vec![(0.0, 0.0), (0.0, 1.0), (0.0, 2.0)], vec![(3.0, 0.0)]],
+                ),
+            )
+            .unwrap();
+        new_device.call_method1("switch_layout", (1,)).unwrap();
+        assert!(new_device
+            .call_method1("two_qubit_gate_time", ("PhaseShiftedControlledZ", 0, 2))
+            .is_err());
+        assert!(device
+            .call_method1("add_irregular_layout", (1, vec![vec![(0.0, 0.0)]]))
+            .is_err());
+        assert!(device_type
+            .call_method1("ragged", (vec![2], vec![vec![(0.0, 0.0)]]))
+            .is_err());
+    });
+}
+
+/// Test runtime setters of FirstDeviceWrapper
+#[test]
+fn test_runtime_updates() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let original_layout = array![[0.0, 1.0, 2.0], [0.0, 1.0, 2.0]];
+        let device_type = py.get_type_bound::<FirstDeviceWrapper>();
+        let binding = device_type
+            .call1((2, 3, vec![2, 1], 1.0, original_layout.to_pyarray_bound(py)))
+            .unwrap();
+        let device = binding.downcast::<FirstDeviceWrapper>().unwrap();
+
+        device.call_method1("set_cutoff", (1.5,)).unwrap();
+        let cutoff = device
+            .call_method0("cutoff")
+            .unwrap()
+            .extract::<f64>()
+            .unwrap();
+        assert_eq!(cutoff, 1.5);
+        let edges = device
+            .call_method0("two_qubit_edges")
+            .unwrap()
+            .extract::<Vec<(usize, usize)>>()
+            .unwrap();
+        assert_eq!(edges, vec![(0, 1), (0, 2), (1, 2)]);
+
+        device.call_method1("set_qubit_position", (1, 2)).unwrap();
+        let qubit_positions = device
+            .call_method0("qubit_positions")
+            .unwrap()
+            .extract::<HashMap<usize, (usize, usize)>>()
+            .unwrap();
+        assert_eq!(qubit_positions.get(&1), Some(&(0, 2)));
+        assert!(device.call_method1("set_qubit_position", (1, 0)).is_err());
+
+        device
+            .call_method1("set_tweezer_position", (0, 2, (0.0, 0.5)))
+            .unwrap();
+        let position = device
+            .call_method1("tweezer_position", (0, 2))
+            .unwrap()
+            .extract::<(f64, f64)>()
+            .unwrap();
+        assert_eq!(position, (0.0, 0.5));
+        assert!(device
+            .call_method1("set_tweezer_position", (2, 0, (0.0, 0.5)))
+            .is_err());
+    });
+}
+
+/// Test three-qubit gate settings of FirstDeviceWrapper
+#[test]
+fn test_three_qubit_gates() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let original_layout = array![[0.0, 1.0], [0.0, 1.0]];
+        let device_type = py.get_type_bound::<FirstDeviceWrapper>();
+        let binding = device_type
+            .call1((2, 2, vec![2, 2], 1.0, original_layout.to_pyarray_bound(py)))
+            .unwrap();
+        let device = binding.downcast::<FirstDeviceWrapper>().unwrap();
+        device.call_method1("set_cutoff", (1.5,)).unwrap();
+
+        assert!(device
+            .call_method0("allow_ccz_gate")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        device.call_method1("set_allow_ccp_gate", (true,)).unwrap();
+        assert!(device
+            .call_method0("allow_ccp_gate")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        device.call_method1("set_allow_ccz_gate", (false,)).unwrap();
+        let gates = device
+            .call_method0("available_three_qubit_gates")
+            .unwrap()
+            .extract::<Vec<String>>()
+            .unwrap();
+        assert_eq!(gates, vec!["ControlledControlledPhaseShift".to_string()]);
+        let triples = device
+            .call_method1(
+                "three_qubit_gate_triples",
+                ("ControlledControlledPhaseShift",),
+            )
+            .unwrap()
+            .extract::<Vec<(usize, usize, usize)>>()
+            .unwrap();
+        assert_eq!(triples.len(), 24);
+        assert!(device
+            .call_method1(
+                "three_qubit_gate_time",
+                ("ControlledControlledPauliZ", 0, 1, 2)
+            )
+            .is_err());
+    });
+}
+
+/// Test the distance gate model methods of FirstDeviceWrapper
+#[test]
+fn test_distance_gate_model() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let original_layout = array![[0.0, 0.5, 1.0]];
+        let device_type = py.get_type_bound::<FirstDeviceWrapper>();
+        let binding = device_type
+            .call1((1, 3, vec![2], 1.0, original_layout.to_pyarray_bound(py)))
+            .unwrap();
+        let device = binding.downcast::<FirstDeviceWrapper>().unwrap();
+        assert!(device
+            .call_method0("distance_gate_model")
+            .unwrap()
+            .extract::<Option<String>>()
+            .unwrap()
+            .is_none());
+
+        device
+            .call_method1("set_power_law_gate_model", (3e-6, 3.0, 0.99))
+            .unwrap();
+        let gate_time = device
+            .call_method1("two_qubit_gate_time", ("PhaseShiftedControlledZ", 0, 1))
+            .unwrap()
+            .extract::<f64>()
+            .unwrap();
+        assert_eq!(gate_time, 3.75e-7);
+        let fidelity = device
+            .call_method1("two_qubit_gate_fidelity", ("PhaseShiftedControlledZ", 0, 1))
+            .unwrap()
+            .extract::<f64>()
+            .unwrap();
+        assert_eq!(fidelity, 0.99);
+
+        device
+            .call_method1("set_van_der_waals_gate_model", (1.0, 1e-6))
+            .unwrap();
+        let model = device
+            .call_method0("distance_gate_model")
+            .unwrap()
+            .extract::<Option<String>>()
+            .unwrap()
+            .unwrap();
+        assert!(model.contains("VanDerWaals"));
+        let fidelity = device
+            .call_method1("two_qubit_gate_fidelity", ("PhaseShiftedControlledZ", 0, 1))
+            .unwrap()
+            .extract::<f64>()
+            .unwrap();
+        assert_eq!(fidelity, 1.0);
+        assert!(device
+            .call_method1("set_van_der_waals_gate_model", (-1.0, 1e-6))
+            .is_err());
+        assert!(device
+            .call_method1("two_qubit_gate_fidelity", ("ControlledPauliZ", 0, 1))
+            .is_err());
+
+        device.call_method0("reset_distance_gate_model").unwrap();
+        let gate_time = device
+            .call_method1("two_qubit_gate_time", ("PhaseShiftedControlledZ", 0, 1))
+            .unwrap()
+            .extract::<f64>()
+            .unwrap();
+        assert_eq!(gate_time, 5e-7);
+    });
+}
+
 // Test gate time methods of FirstDeviceWrapper
 #[test]
 fn test_gate_times() {
@@ -575,7 +797,7 @@ fn test_pyo3_new_change_layout() {
         let check_2: &str = check_str.split("qubit_positions").collect::<Vec<&str>>()[1]
             .split(")}")
             .collect::<Vec<&str>>()[1];
-        let comp_str = format!("FirstDeviceWrapper {{ internal: FirstDevice {{ number_rows: 3, number_columns: 2, qubit_positions: {{0: (0, 0), 1: (0, 1), 2: (1, 0), 3: (1, 1), 4: (2, 0), 5: (2, 1)}}, row_distance: 1.0, layout_register: {{0: {:?}}}, current_layout: 0, cutoff: 1.0, controlled_z_phase_relation: \"DefaultRelation\", controlled_phase_phase_relation: \"DefaultRelation\", allow_ccz_gate: true, allow_ccp_gate: false }} }}", layout);
+        let comp_str = format!("FirstDeviceWrapper {{ internal: FirstDevice {{ number_rows: 3, number_columns: 2, columns_per_row: None, qubit_positions: {{0: (0, 0), 1: (0, 1), 2: (1, 0), 3: (1, 1), 4: (2, 0), 5: (2, 1)}}, row_distance: 1.0, layout_register: {{0: {:?}}}, irregular_layout_register: {{}}, current_layout: 0, cutoff: 1.0, controlled_z_phase_relation: \"DefaultRelation\", controlled_phase_phase_relation: \"DefaultRelation\", allow_ccz_gate: true, allow_ccp_gate: false, distance_gate_model: None }} }}", layout);
         let comp_1: &str = comp_str.split("qubit_positions").collect::<Vec<&str>>()[0];
         let comp_2: &str = comp_str.split("qubit_positions").collect::<Vec<&str>>()[1]
             .split(")}")
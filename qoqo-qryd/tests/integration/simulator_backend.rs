@@ -21,8 +21,8 @@ use qoqo_qryd::{TweezerDeviceWrapper, TweezerMutableDeviceWrapper};
 use roqoqo::measurements::{ClassicalRegister, PauliZProduct, PauliZProductInput};
 use roqoqo::operations;
 use roqoqo::Circuit;
-use roqoqo_qryd::SimulatorBackend;
 use roqoqo_qryd::TweezerDevice;
+use roqoqo_qryd::{PragmaSwitchDeviceLayout, SimulatorBackend};
 
 #[test]
 fn test_creating_backend() {
@@ -48,6 +48,212 @@ fn test_creating_backend_error() {
     });
 }
 
+#[test]
+fn test_set_noisy() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<TweezerDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+        let backend_type = py.get_type_bound::<SimulatorBackendWrapper>();
+        let backend = backend_type.call1((device,)).unwrap();
+        assert!(!backend
+            .call_method0("is_noisy")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        backend.call_method1("set_noisy", (true,)).unwrap();
+        assert!(backend
+            .call_method0("is_noisy")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        assert!(
+            backend
+                .downcast::<SimulatorBackendWrapper>()
+                .unwrap()
+                .borrow()
+                .internal
+                .noisy
+        );
+    });
+}
+
+#[test]
+fn test_set_number_threads() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<TweezerDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+        let backend_type = py.get_type_bound::<SimulatorBackendWrapper>();
+        let backend = backend_type.call1((device,)).unwrap();
+        assert_eq!(
+            backend
+                .call_method0("number_threads")
+                .unwrap()
+                .extract::<usize>()
+                .unwrap(),
+            1
+        );
+        backend.call_method1("set_number_threads", (4,)).unwrap();
+        assert_eq!(
+            backend
+                .call_method0("number_threads")
+                .unwrap()
+                .extract::<usize>()
+                .unwrap(),
+            4
+        );
+    });
+}
+
+#[test]
+fn test_set_sample_terminal_measurements() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<TweezerDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+        let backend_type = py.get_type_bound::<SimulatorBackendWrapper>();
+        let backend = backend_type.call1((device,)).unwrap();
+        assert!(backend
+            .call_method0("sample_terminal_measurements")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        backend
+            .call_method1("set_sample_terminal_measurements", (false,))
+            .unwrap();
+        assert!(!backend
+            .call_method0("sample_terminal_measurements")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+    });
+}
+
+#[test]
+fn test_density_matrix() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<TweezerDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+        let backend_type = py.get_type_bound::<SimulatorBackendWrapper>();
+        let backend = backend_type.call1((device.clone(),)).unwrap();
+        assert!(!backend
+            .call_method0("is_density_matrix")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        let backend = backend_type
+            .call1((device, Option::<usize>::None, true))
+            .unwrap();
+        assert!(backend
+            .call_method0("is_density_matrix")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+    });
+}
+
+#[test]
+fn test_memory_limit() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<TweezerDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+        let backend_type = py.get_type_bound::<SimulatorBackendWrapper>();
+        let backend = backend_type.call1((device, 2)).unwrap();
+        assert_eq!(
+            backend
+                .call_method1("required_memory", (true,))
+                .unwrap()
+                .extract::<Option<usize>>()
+                .unwrap(),
+            Some(256)
+        );
+        assert_eq!(
+            backend
+                .call_method0("memory_limit")
+                .unwrap()
+                .extract::<Option<usize>>()
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            backend
+                .call_method0("uses_gpu")
+                .unwrap()
+                .extract::<bool>()
+                .unwrap(),
+            cfg!(feature = "simulator-gpu")
+        );
+        backend.call_method1("set_memory_limit", (1024,)).unwrap();
+        assert_eq!(
+            backend
+                .call_method0("memory_limit")
+                .unwrap()
+                .extract::<Option<usize>>()
+                .unwrap(),
+            Some(1024)
+        );
+    });
+}
+
+/// Test set_transport_noise, transport_noise and transport_time functions of SimulatorBackend
+#[test]
+fn test_transport_noise() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit += PragmaSwitchDeviceLayout::new("other".to_string())
+        .to_pragma_change_device()
+        .unwrap();
+    let circuit_wrapper = CircuitWrapper { internal: circuit };
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<TweezerMutableDeviceWrapper>();
+        let device = device_type.call0().unwrap();
+        for layout in ["test", "other"] {
+            device.call_method1("add_layout", (layout,)).unwrap();
+            device
+                .call_method1(
+                    "set_tweezer_single_qubit_gate_time",
+                    ("RotateX", 0, 1.0, layout),
+                )
+                .unwrap();
+            device
+                .call_method1("set_tweezers_per_row", (vec![1], layout))
+                .unwrap();
+        }
+        device
+            .call_method1("set_layout_switch_time", (2.0, "other"))
+            .unwrap();
+        device.call_method1("switch_layout", ("test",)).unwrap();
+
+        let backend_type = py.get_type_bound::<SimulatorBackendWrapper>();
+        let backend = backend_type.call1((device,)).unwrap();
+        assert!(!backend
+            .call_method0("transport_noise")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        backend
+            .call_method1("set_transport_noise", (true,))
+            .unwrap();
+        assert!(backend
+            .call_method0("transport_noise")
+            .unwrap()
+            .extract::<bool>()
+            .unwrap());
+        assert_eq!(
+            backend
+                .call_method1("transport_time", (circuit_wrapper,))
+                .unwrap()
+                .extract::<f64>()
+                .unwrap(),
+            2.0
+        );
+        assert!(backend.call_method1("transport_time", (0,)).is_err());
+    });
+}
+
 #[test]
 fn test_running_circuit() {
     pyo3::prepare_freethreaded_python();
@@ -88,6 +294,43 @@ fn test_running_circuit() {
     })
 }
 
+#[test]
+fn test_running_circuit_state_readout() {
+    pyo3::prepare_freethreaded_python();
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionComplex::new("psi".to_string(), 4, true);
+    circuit += operations::RotateX::new(0, 1.0.into());
+    circuit += operations::PragmaGetStateVector::new("psi".to_string(), None);
+    let circuit_wrapper = CircuitWrapper { internal: circuit };
+    Python::with_gil(|py| {
+        let device_type = py.get_type_bound::<TweezerMutableDeviceWrapper>();
+        let device_tw = device_type.call0().unwrap();
+        device_tw.call_method1("add_layout", ("test",)).unwrap();
+        device_tw
+            .call_method1(
+                "set_tweezer_single_qubit_gate_time",
+                ("RotateX", 0, 1.0, "test"),
+            )
+            .unwrap();
+        device_tw
+            .call_method1(
+                "set_tweezer_single_qubit_gate_time",
+                ("RotateX", 1, 1.0, "test"),
+            )
+            .unwrap();
+        device_tw.call_method1("switch_layout", ("test",)).unwrap();
+
+        let backend_type = py.get_type_bound::<SimulatorBackendWrapper>();
+        let backend_tw = backend_type.call1((device_tw,)).unwrap();
+        let registers = backend_tw
+            .call_method1("run_circuit", (circuit_wrapper,))
+            .unwrap();
+        let psi = registers.get_item(2).unwrap().get_item("psi").unwrap();
+        assert_eq!(psi.len().unwrap(), 1);
+        assert_eq!(psi.get_item(0).unwrap().len().unwrap(), 4);
+    })
+}
+
 #[test]
 fn test_running_circuit_error() {
     pyo3::prepare_freethreaded_python();
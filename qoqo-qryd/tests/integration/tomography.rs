@@ -0,0 +1,110 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for state tomography
+
+use numpy::{Complex64, PyReadonlyArray2};
+use pyo3::prelude::*;
+use qoqo::CircuitWrapper;
+use qoqo_qryd::StateTomographyWrapper;
+
+/// Test new instantiation of StateTomographyWrapper
+#[test]
+fn test_new() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let tomography_type = py.get_type_bound::<StateTomographyWrapper>();
+        let tomography = tomography_type.call1((vec![0, 1], 50)).unwrap();
+        assert_eq!(
+            tomography
+                .call_method0("qubits")
+                .unwrap()
+                .extract::<Vec<usize>>()
+                .unwrap(),
+            vec![0, 1]
+        );
+        assert_eq!(
+            tomography
+                .call_method0("number_measurements")
+                .unwrap()
+                .extract::<usize>()
+                .unwrap(),
+            50
+        );
+
+        assert!(tomography_type.call1((Vec::<usize>::new(), 50)).is_err());
+        assert!(tomography_type.call1((vec![0, 0], 50)).is_err());
+    })
+}
+
+/// Test measurement settings and circuits of StateTomographyWrapper
+#[test]
+fn test_measurement_circuits() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let tomography_type = py.get_type_bound::<StateTomographyWrapper>();
+        let tomography = tomography_type.call1((vec![0], 50)).unwrap();
+        assert_eq!(
+            tomography
+                .call_method0("measurement_settings")
+                .unwrap()
+                .extract::<Vec<Vec<String>>>()
+                .unwrap(),
+            vec![
+                vec!["X".to_string()],
+                vec!["Y".to_string()],
+                vec!["Z".to_string()]
+            ]
+        );
+
+        let circuits = tomography
+            .call_method1("measurement_circuits", (CircuitWrapper::new(),))
+            .unwrap()
+            .extract::<Vec<CircuitWrapper>>()
+            .unwrap();
+        assert_eq!(circuits.len(), 3);
+        assert_eq!(circuits[2].internal.count_occurences(&["MeasureQubit"]), 1);
+
+        assert!(tomography
+            .call_method1("measurement_circuits", (2,))
+            .is_err());
+    })
+}
+
+/// Test the reconstruction of StateTomographyWrapper
+#[test]
+fn test_reconstruct() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let tomography_type = py.get_type_bound::<StateTomographyWrapper>();
+        let tomography = tomography_type.call1((vec![0], 4)).unwrap();
+        let balanced = vec![vec![false], vec![true], vec![false], vec![true]];
+        let registers = vec![balanced.clone(), balanced, vec![vec![true]; 4]];
+
+        for method in ["linear_inversion", "maximum_likelihood"] {
+            let result = tomography
+                .call_method1("reconstruct", (registers.clone(), method))
+                .unwrap();
+            let density_matrix = result.extract::<PyReadonlyArray2<Complex64>>().unwrap();
+            let density_matrix = density_matrix.as_array();
+            assert!((density_matrix[[1, 1]] - Complex64::new(1.0, 0.0)).norm() < 1e-6);
+            assert!(density_matrix[[0, 1]].norm() < 1e-6);
+        }
+
+        assert!(tomography
+            .call_method1("reconstruct", (registers, "unknown"))
+            .is_err());
+        assert!(tomography
+            .call_method1("reconstruct", (vec![vec![vec![true]]],))
+            .is_err());
+    })
+}
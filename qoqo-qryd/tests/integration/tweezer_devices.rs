@@ -20,10 +20,18 @@ use pyo3::{
 #[cfg(feature = "web-api")]
 use serde_json::Value;
 
+use ndarray::array;
+use qoqo::CircuitWrapper;
 use qoqo_qryd::{
-    tweezer_devices::convert_into_device, TweezerDeviceWrapper, TweezerMutableDeviceWrapper,
+    tweezer_devices::convert_into_device, FirstDeviceWrapper, PragmaMoveToZoneWrapper,
+    TweezerDeviceWrapper, TweezerMutableDeviceWrapper,
 };
-use roqoqo_qryd::{phi_theta_relation, TweezerDevice};
+use roqoqo::Circuit;
+use roqoqo_qryd::{
+    phi_theta_relation, FirstDevice, PragmaChangeQRydLayout, PragmaShiftQRydQubit,
+    PragmaShiftQubitsTweezers, PragmaSwitchDeviceLayout, TweezerDevice,
+};
+use std::collections::HashMap;
 
 #[cfg(feature = "web-api")]
 use wiremock::matchers::method;
@@ -124,6 +132,60 @@ fn test_from_mutable() {
     })
 }
 
+/// Test from_first_device() of TweezerMutableDeviceWrapper
+#[test]
+fn test_from_first_device() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let first_device = Bound::new(
+            py,
+            FirstDeviceWrapper {
+                internal: FirstDevice::new(
+                    2,
+                    2,
+                    &[2, 2],
+                    1.0,
+                    array![[0.0, 1.0], [0.0, 1.0]],
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            },
+        )
+        .unwrap();
+        let device_type_mut = py.get_type_bound::<TweezerMutableDeviceWrapper>();
+        let device = device_type_mut
+            .call_method1("from_first_device", (first_device,))
+            .unwrap();
+
+        assert_eq!(
+            device
+                .call_method0("current_layout")
+                .unwrap()
+                .extract::<String>()
+                .unwrap(),
+            "default"
+        );
+        assert_eq!(
+            device
+                .call_method1("two_qubit_gate_time", ("PhaseShiftedControlledZ", 0, 2))
+                .unwrap()
+                .extract::<f64>()
+                .unwrap(),
+            2e-6
+        );
+        assert!(device
+            .call_method1("two_qubit_gate_time", ("PhaseShiftedControlledZ", 0, 3))
+            .is_err());
+
+        assert!(device_type_mut
+            .call_method1("from_first_device", (device_type_mut.call0().unwrap(),))
+            .is_err());
+    })
+}
+
 /// Test available_layout() switch_layout(), add_layout() and set_tweezers_per_row() methods of TweezerDeviceWrapper and TweezerMutableDeviceWrapper
 #[test]
 fn test_layouts() {
@@ -508,6 +570,215 @@ fn test_deactivate_qubit() {
     })
 }
 
+/// Test resolve_zone_move function of TweezerDeviceWrapper
+#[test]
+fn test_resolve_zone_move() {
+    // Setup fake preconfigured device
+    let mut exp = TweezerDevice::new(None, None, None);
+    exp.add_layout("default").unwrap();
+    exp.current_layout = Some("default".to_string());
+    for tweezer in 0..4 {
+        exp.set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.23, None)
+            .unwrap();
+        let zone = if tweezer < 2 {
+            "storage"
+        } else {
+            "interaction"
+        };
+        exp.set_tweezer_zone(tweezer, zone, None).unwrap();
+    }
+    exp.set_allowed_tweezer_shifts(&0, &[&[2, 3]], None)
+        .unwrap();
+    exp.add_qubit_tweezer_mapping(0, 0).unwrap();
+    let fake_api_device = TweezerDeviceWrapper { internal: exp };
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let fake_api_pypyany = fake_api_device.into_py(py);
+        let device = fake_api_pypyany.bind(py);
+        let pragma_type = py.get_type_bound::<PragmaMoveToZoneWrapper>();
+
+        let pragma = pragma_type.call1((vec![0], "interaction")).unwrap();
+        let shifts: Vec<(usize, usize)> = device
+            .call_method1("resolve_zone_move", (pragma,))
+            .unwrap()
+            .call_method0("shifts")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(shifts, vec![(0, 2)]);
+
+        let pragma = pragma_type.call1((vec![0], "readout")).unwrap();
+        assert!(device.call_method1("resolve_zone_move", (pragma,)).is_err());
+    })
+}
+
+/// Test upgrade_circuit function of TweezerDevice
+#[test]
+fn test_upgrade_circuit() {
+    let mut exp = TweezerDevice::new(None, None, None);
+    for layout in ["0", "1"] {
+        exp.add_layout(layout).unwrap();
+        for tweezer in 0..4 {
+            exp.set_tweezer_single_qubit_gate_time(
+                "RotateX",
+                tweezer,
+                0.23,
+                Some(layout.to_string()),
+            )
+            .unwrap();
+        }
+        exp.set_tweezers_per_row(vec![2, 2], Some(layout.to_string()))
+            .unwrap();
+    }
+    exp.set_allowed_tweezer_shifts(&0, &[&[1]], Some("1".to_string()))
+        .unwrap();
+    exp.switch_layout("0", Some(false)).unwrap();
+    exp.add_qubit_tweezer_mapping(0, 0).unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += PragmaChangeQRydLayout::new(1)
+        .to_pragma_change_device()
+        .unwrap();
+    circuit += PragmaShiftQRydQubit::new(HashMap::from([(0, (0, 1))]))
+        .to_pragma_change_device()
+        .unwrap();
+    let mut expected = Circuit::new();
+    expected += PragmaSwitchDeviceLayout::new("1".to_string())
+        .to_pragma_change_device()
+        .unwrap();
+    expected += PragmaShiftQubitsTweezers::new_parallel(vec![(0, 1)])
+        .to_pragma_change_device()
+        .unwrap();
+
+    let fake_api_device = TweezerDeviceWrapper { internal: exp };
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = fake_api_device.into_py(py);
+        let device = device.bind(py);
+        let upgraded = device
+            .call_method1("upgrade_circuit", (CircuitWrapper { internal: circuit },))
+            .unwrap()
+            .extract::<CircuitWrapper>()
+            .unwrap();
+        assert_eq!(upgraded.internal, expected);
+
+        assert!(device.call_method1("upgrade_circuit", (0,)).is_err());
+    })
+}
+
+/// Test set_layout_switch_time and layout_switch_time functions of TweezerMutableDevice
+#[test]
+fn test_layout_switch_time() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type_mut = py.get_type_bound::<TweezerMutableDeviceWrapper>();
+        let device_mut = device_type_mut.call0().unwrap();
+
+        assert!(device_mut
+            .call_method1("set_layout_switch_time", (1.0,))
+            .is_err());
+        device_mut.call_method1("add_layout", ("default",)).unwrap();
+        device_mut
+            .call_method1("switch_layout", ("default",))
+            .unwrap();
+        assert!(device_mut
+            .call_method0("layout_switch_time")
+            .unwrap()
+            .extract::<Option<f64>>()
+            .unwrap()
+            .is_none());
+        assert!(device_mut
+            .call_method1("set_layout_switch_time", (-1.0,))
+            .is_err());
+        device_mut
+            .call_method1("set_layout_switch_time", (2.0,))
+            .unwrap();
+        assert_eq!(
+            device_mut
+                .call_method0("layout_switch_time")
+                .unwrap()
+                .extract::<Option<f64>>()
+                .unwrap(),
+            Some(2.0)
+        );
+    })
+}
+
+/// Test the atom loss probability functions of TweezerMutableDevice
+#[test]
+fn test_atom_loss_probabilities() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type_mut = py.get_type_bound::<TweezerMutableDeviceWrapper>();
+        let device_mut = device_type_mut.call0().unwrap();
+
+        for (setter, getter) in [
+            ("set_shift_loss_probability", "shift_loss_probability"),
+            (
+                "set_measurement_loss_probability",
+                "measurement_loss_probability",
+            ),
+        ] {
+            assert_eq!(
+                device_mut
+                    .call_method0(getter)
+                    .unwrap()
+                    .extract::<f64>()
+                    .unwrap(),
+                0.0
+            );
+            assert!(device_mut.call_method1(setter, (1.5,)).is_err());
+            device_mut.call_method1(setter, (0.25,)).unwrap();
+            assert_eq!(
+                device_mut
+                    .call_method0(getter)
+                    .unwrap()
+                    .extract::<f64>()
+                    .unwrap(),
+                0.25
+            );
+        }
+    })
+}
+
+/// Test set_global_rotation_time and global_rotation_time functions of TweezerMutableDevice
+#[test]
+fn test_global_rotation_time() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device_type_mut = py.get_type_bound::<TweezerMutableDeviceWrapper>();
+        let device_mut = device_type_mut.call0().unwrap();
+
+        assert!(device_mut
+            .call_method1("set_global_rotation_time", (1.0,))
+            .is_err());
+        device_mut.call_method1("add_layout", ("default",)).unwrap();
+        device_mut
+            .call_method1("switch_layout", ("default",))
+            .unwrap();
+        assert!(device_mut
+            .call_method0("global_rotation_time")
+            .unwrap()
+            .extract::<Option<f64>>()
+            .unwrap()
+            .is_none());
+        assert!(device_mut
+            .call_method1("set_global_rotation_time", (-1.0,))
+            .is_err());
+        device_mut
+            .call_method1("set_global_rotation_time", (0.5,))
+            .unwrap();
+        assert_eq!(
+            device_mut
+                .call_method0("global_rotation_time")
+                .unwrap()
+                .extract::<Option<f64>>()
+                .unwrap(),
+            Some(0.5)
+        );
+    })
+}
+
 /// Test _qubit_time functions of TweezerDeviceWrapper and TweezerMutableDeviceWrapper
 #[test]
 fn test_qubit_times() {
@@ -533,18 +804,17 @@ fn test_qubit_times() {
         Some("OtherLayout".to_string()),
     )
     .unwrap();
-    // TODO: Add this back in when the backend supports multi-qubit gates
-    // exp.set_tweezer_multi_qubit_gate_time(
-    //     "MultiQubitZZ",
-    //     &[0, 1, 2, 3],
-    //     0.65,
-    //     Some("OtherLayout".to_string()),
-    // )
-    // .unwrap();
+    exp.set_tweezer_multi_qubit_gate_time(
+        "MultiQubitZZ",
+        &[0, 1, 2, 3],
+        0.65,
+        Some("OtherLayout".to_string()),
+    )
+    .unwrap();
     exp.switch_layout("OtherLayout", None).unwrap();
     exp.add_qubit_tweezer_mapping(0, 1).unwrap();
     exp.add_qubit_tweezer_mapping(1, 2).unwrap();
-    // exp.add_qubit_tweezer_mapping(2, 3).unwrap();
+    exp.add_qubit_tweezer_mapping(2, 3).unwrap();
     exp.add_qubit_tweezer_mapping(3, 0).unwrap();
     let fake_api_device = TweezerDeviceWrapper { internal: exp };
     pyo3::prepare_freethreaded_python();
@@ -575,13 +845,12 @@ fn test_qubit_times() {
                 ("ControlledControlledPauliZ", 0, 1, 2, 0.45, "OtherLayout"),
             )
             .unwrap();
-        // TODO: Add this back in when the backend supports multi-qubit gates
-        // device_mut
-        //     .call_method1(
-        //         "set_tweezer_multi_qubit_gate_time",
-        //         ("MultiQubitZZ", vec![0, 1, 2, 3], 0.6, "OtherLayout"),
-        //     )
-        //     .unwrap();
+        device_mut
+            .call_method1(
+                "set_tweezer_multi_qubit_gate_time",
+                ("MultiQubitZZ", vec![0, 1, 2, 3], 0.6, "OtherLayout"),
+            )
+            .unwrap();
         device_mut
             .call_method1("switch_layout", ("OtherLayout",))
             .unwrap();
@@ -592,10 +861,9 @@ fn test_qubit_times() {
         device_mut
             .call_method1("add_qubit_tweezer_mapping", (1, 2))
             .unwrap();
-        // TODO: Add this back in when the backend supports multi-qubit gates
-        // device_mut
-        //     .call_method1("add_qubit_tweezer_mapping", (2, 3))
-        //     .unwrap();
+        device_mut
+            .call_method1("add_qubit_tweezer_mapping", (2, 3))
+            .unwrap();
         device_mut
             .call_method1("add_qubit_tweezer_mapping", (3, 0))
             .unwrap();
@@ -631,13 +899,12 @@ fn test_qubit_times() {
                 ("ControlledControlledPauliZ", 3, 0, 1)
             )
             .is_ok());
-        // TODO: Add this back in when the backend supports multi-qubit gates
-        // assert!(device
-        //     .call_method1("multi_qubit_gate_time", ("MultiQubitZZ", vec![3, 0, 1, 2]))
-        //     .is_ok());
-        // assert!(device_mut
-        //     .call_method1("multi_qubit_gate_time", ("MultiQubitZZ", vec![3, 0, 1, 2]))
-        //     .is_ok());
+        assert!(device
+            .call_method1("multi_qubit_gate_time", ("MultiQubitZZ", vec![3, 0, 1, 2]))
+            .is_ok());
+        assert!(device_mut
+            .call_method1("multi_qubit_gate_time", ("MultiQubitZZ", vec![3, 0, 1, 2]))
+            .is_ok());
     })
 }
 
@@ -1002,7 +1269,7 @@ fn test_to_from_json() {
                 "The valid gates are: RotateZ, RotateX, RotateXY, PhaseShiftState0, " +
                 "PhaseShiftState1, ControlledPhaseShift, ControlledPauliZ, " +
                 "PhaseShiftedControlledZ, PhaseShiftedControlledPhase, ControlledControlledPauliZ, " +
-                "ControlledControlledPhaseShift."
+                "ControlledControlledPhaseShift, MultiQubitZZ."
             ).to_string()
         );
 
@@ -1010,7 +1277,8 @@ fn test_to_from_json() {
         let str_serialized_device_with_wrong_gate = serialized_mut
             .extract::<String>()
             .unwrap()
-            .replace("PhaseShiftedControlledPhase", "CNOT");
+            // Only the gate time entry, the native gates of the device are left unchanged
+            .replace("[\"PhaseShiftedControlledPhase\",", "[\"CNOT\",");
         let device = device_type_mut.call0().unwrap();
         let deserialized_with_wrong_gate =
             device.call_method1("from_json", (str_serialized_device_with_wrong_gate,));
@@ -1022,7 +1290,7 @@ fn test_to_from_json() {
                 "The valid gates are: RotateZ, RotateX, RotateXY, PhaseShiftState0, " +
                 "PhaseShiftState1, ControlledPhaseShift, ControlledPauliZ, " +
                 "PhaseShiftedControlledZ, PhaseShiftedControlledPhase, ControlledControlledPauliZ, " +
-                "ControlledControlledPhaseShift."
+                "ControlledControlledPhaseShift, MultiQubitZZ."
             ).to_string()
         );
     });
@@ -0,0 +1,126 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for cross-entropy benchmarking
+
+use pyo3::prelude::*;
+use qoqo::CircuitWrapper;
+use qoqo_qryd::{py_linear_xeb_fidelity, TweezerDeviceWrapper, XEBExperimentWrapper};
+use roqoqo_qryd::TweezerDevice;
+use std::collections::HashMap;
+
+// Helper function creating a device with two connected tweezers
+fn create_device(py: Python) -> Py<TweezerDeviceWrapper> {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..2 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateXY",
+                tweezer,
+                1.0,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device
+        .set_tweezer_two_qubit_gate_time("ControlledPauliZ", 0, 1, 1.0, Some("default".to_string()))
+        .unwrap();
+    device.switch_layout("default", None).unwrap();
+    Py::new(py, TweezerDeviceWrapper { internal: device }).unwrap()
+}
+
+/// Test new instantiation and circuits of XEBExperimentWrapper
+#[test]
+fn test_new_circuits() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = create_device(py);
+        let experiment_type = py.get_type_bound::<XEBExperimentWrapper>();
+        let experiment = experiment_type
+            .call1((device.clone_ref(py), vec![0, 1], vec![1, 2], 3, 100, 7))
+            .unwrap();
+        assert_eq!(
+            experiment
+                .call_method0("depths")
+                .unwrap()
+                .extract::<Vec<usize>>()
+                .unwrap(),
+            vec![1, 1, 1, 2, 2, 2]
+        );
+        let circuits = experiment
+            .call_method0("circuits")
+            .unwrap()
+            .extract::<Vec<CircuitWrapper>>()
+            .unwrap();
+        assert_eq!(circuits.len(), 6);
+        let probabilities = experiment
+            .call_method0("ideal_probabilities")
+            .unwrap()
+            .extract::<Vec<Vec<f64>>>()
+            .unwrap();
+        assert!((probabilities[0].iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+        assert!(experiment_type
+            .call1((device.clone_ref(py), vec![0, 3], vec![1], 3, 100))
+            .is_err());
+        assert!(experiment_type
+            .call1(("device", vec![0, 1], vec![1], 3, 100))
+            .is_err());
+    })
+}
+
+/// Test the analysis of XEBExperimentWrapper
+#[test]
+fn test_analyse() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let device = create_device(py);
+        let experiment_type = py.get_type_bound::<XEBExperimentWrapper>();
+        let experiment = experiment_type
+            .call1((device, vec![0, 1], vec![2, 4], 3, 10))
+            .unwrap();
+        let probabilities = experiment
+            .call_method0("ideal_probabilities")
+            .unwrap()
+            .extract::<Vec<Vec<f64>>>()
+            .unwrap();
+        let registers: Vec<Vec<Vec<bool>>> = probabilities
+            .iter()
+            .map(|probabilities| {
+                let index = (0..probabilities.len())
+                    .max_by(|a, b| probabilities[*a].total_cmp(&probabilities[*b]))
+                    .unwrap();
+                vec![vec![index & 1 == 1, index & 2 == 2]; 10]
+            })
+            .collect();
+        let result = experiment
+            .call_method1("analyse", (registers.clone(),))
+            .unwrap()
+            .extract::<HashMap<String, PyObject>>()
+            .unwrap();
+        let depths: Vec<usize> = result.get("depths").unwrap().extract(py).unwrap();
+        assert_eq!(depths, vec![2, 4]);
+        let fidelities: Vec<f64> = result.get("fidelities").unwrap().extract(py).unwrap();
+        assert!(fidelities.iter().all(|fidelity| *fidelity > 0.0));
+
+        assert!(experiment
+            .call_method1("analyse", (registers[1..].to_vec(),))
+            .is_err());
+
+        assert_eq!(
+            py_linear_xeb_fidelity(vec![0.0, 0.0, 1.0, 0.0], vec![vec![false, true]]).unwrap(),
+            3.0
+        );
+        assert!(py_linear_xeb_fidelity(vec![0.25; 4], vec![]).is_err());
+    })
+}
@@ -0,0 +1,85 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Downloads a TweezerDevice from the QRyd WebAPI and validates its layouts before use.
+//!
+//! Requires a valid access token in the environment variable `QRYD_API_TOKEN`.
+//!
+//! ```bash
+//! cargo run --example download_device -- qryd_tweezer_device
+//! ```
+
+use roqoqo::devices::Device;
+use roqoqo::RoqoqoBackendError;
+use roqoqo_qryd::TweezerDevice;
+use std::env;
+
+fn main() -> Result<(), RoqoqoBackendError> {
+    let device_name = env::args().nth(1);
+    let mut device = TweezerDevice::from_api(device_name, None, None, None, None, None)?;
+    println!(
+        "Downloaded {} with the layouts {:?}",
+        device.qrydbackend(),
+        device.available_layouts()
+    );
+
+    // Layouts other than the current and the default one are only downloaded in serialized form
+    device.load_layouts()?;
+    let layouts: Vec<String> = device
+        .available_layouts()
+        .iter()
+        .map(|layout| layout.to_string())
+        .collect();
+    let mut problems: Vec<String> = Vec::new();
+    for layout in layouts {
+        device.switch_layout(&layout, None)?;
+        let number_tweezers = device.number_tweezer_positions(Some(layout.clone()))?;
+        let gates = device.get_available_gates_names(Some(layout.clone()))?;
+        let edges = device.two_qubit_edges();
+        println!(
+            "Layout {}: {} tweezers, {} two-qubit edges, gates {:?}",
+            layout,
+            number_tweezers,
+            edges.len(),
+            gates
+        );
+        if number_tweezers == 0 {
+            problems.push(format!("Layout {} has no tweezers.", layout));
+        }
+        for qubit in 0..device.number_qubits() {
+            if !gates
+                .iter()
+                .any(|gate| device.single_qubit_gate_time(gate, &qubit).is_some())
+            {
+                problems.push(format!(
+                    "Layout {}: qubit {} has no single-qubit gate.",
+                    layout, qubit
+                ));
+            }
+        }
+        if device.number_qubits() > 1 && edges.is_empty() {
+            problems.push(format!("Layout {} has no two-qubit gates.", layout));
+        }
+    }
+    if let Some(default_layout) = device.default_layout.clone() {
+        device.switch_layout(&default_layout, None)?;
+    }
+
+    if problems.is_empty() {
+        println!("All layouts of {} are valid.", device.qrydbackend());
+        Ok(())
+    } else {
+        Err(RoqoqoBackendError::GenericError {
+            msg: problems.join("\n"),
+        })
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sweeps the rotation angle of a parametrized circuit on the local simulator.
+//!
+//! Builds a two-qubit TweezerDevice, prepares the circuit once with a symbolic angle and
+//! evaluates the measured probability of the |11> state for each angle of the sweep.
+//!
+//! ```bash
+//! cargo run --example simulator_sweep
+//! ```
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::measurements::ClassicalRegister;
+use roqoqo::operations::*;
+use roqoqo::prelude::Measure;
+use roqoqo::{Circuit, RoqoqoBackendError};
+use roqoqo_qryd::{SimulatorBackend, TweezerDevice};
+use std::collections::HashMap;
+
+const NUMBER_MEASUREMENTS: usize = 200;
+const NUMBER_ANGLES: usize = 9;
+
+fn main() -> Result<(), RoqoqoBackendError> {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("sweep")?;
+    for tweezer in 0..2 {
+        for gate in ["RotateX", "RotateY", "RotateZ"] {
+            device.set_tweezer_single_qubit_gate_time(
+                gate,
+                tweezer,
+                1e-6,
+                Some("sweep".to_string()),
+            )?;
+        }
+    }
+    device.set_tweezer_two_qubit_gate_time(
+        "PhaseShiftedControlledZ",
+        0,
+        1,
+        2e-6,
+        Some("sweep".to_string()),
+    )?;
+    device.switch_layout("sweep", None)?;
+    let phi = device.phase_shift_controlled_z().unwrap_or_default();
+    let backend = SimulatorBackend::new(device, None);
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateY::new(0, "angle".into());
+    circuit += RotateY::new(1, "angle".into());
+    circuit += PhaseShiftedControlledZ::new(0, 1, phi.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), NUMBER_MEASUREMENTS, None);
+    let measurement = ClassicalRegister {
+        constant_circuit: None,
+        circuits: vec![circuit],
+    };
+
+    println!("angle  P(11)");
+    for step in 0..NUMBER_ANGLES {
+        let angle = std::f64::consts::PI * step as f64 / (NUMBER_ANGLES - 1) as f64;
+        let measurement = measurement
+            .substitute_parameters(HashMap::from([("angle".to_string(), angle)]))
+            .map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("Could not substitute the angle: {}", err),
+            })?;
+        let (bit_registers, _, _) = backend.run_measurement_registers(&measurement)?;
+        let shots = &bit_registers["ro"];
+        let ones = shots
+            .iter()
+            .filter(|shot| shot.iter().all(|bit| *bit))
+            .count();
+        println!("{:5.3}  {:5.3}", angle, ones as f64 / shots.len() as f64);
+    }
+    Ok(())
+}
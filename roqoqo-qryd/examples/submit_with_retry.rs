@@ -0,0 +1,119 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Submits a job to the QRyd WebAPI, retrying on network failures, and waits for its result.
+//!
+//! Network errors are retried with an exponential backoff, all other errors, e.g. a program the
+//! device does not support, are returned immediately. Requires a valid access token in the
+//! environment variable `QRYD_API_TOKEN`.
+//!
+//! ```bash
+//! cargo run --example submit_with_retry -- qryd_emulator
+//! ```
+
+use roqoqo::measurements::ClassicalRegister;
+use roqoqo::operations::*;
+use roqoqo::{Circuit, QuantumProgram, RoqoqoBackendError};
+use roqoqo_qryd::{APIBackend, QRydAPIDevice, TweezerDevice};
+use std::{env, thread, time};
+
+const MAX_ATTEMPTS: u32 = 5;
+const NUMBER_MEASUREMENTS: usize = 100;
+
+/// Calls `request` until it succeeds, retrying network errors with an exponential backoff.
+fn with_retry<T, F>(description: &str, mut request: F) -> Result<T, RoqoqoBackendError>
+where
+    F: FnMut() -> Result<T, RoqoqoBackendError>,
+{
+    let mut backoff = time::Duration::from_millis(500);
+    for attempt in 1..=MAX_ATTEMPTS {
+        match request() {
+            Err(RoqoqoBackendError::NetworkError { msg }) if attempt < MAX_ATTEMPTS => {
+                eprintln!(
+                    "{} failed (attempt {}/{}): {}",
+                    description, attempt, MAX_ATTEMPTS, msg
+                );
+                thread::sleep(backoff);
+                backoff *= 2;
+            }
+            result => return result,
+        }
+    }
+    unreachable!("the last attempt always returns")
+}
+
+fn main() -> Result<(), RoqoqoBackendError> {
+    let device_name = env::args().nth(1);
+    let device = with_retry("Downloading the device", || {
+        TweezerDevice::from_api(device_name.clone(), None, None, None, None, None)
+    })?;
+    let phi = device.phase_shift_controlled_z().unwrap_or_default();
+    let backend = APIBackend::new(
+        QRydAPIDevice::from(device),
+        None,
+        Some(60),
+        None,
+        None,
+        None,
+    )?;
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::FRAC_PI_2.into());
+    circuit += PhaseShiftedControlledZ::new(0, 1, phi.into());
+    circuit += RotateX::new(1, std::f64::consts::FRAC_PI_2.into());
+    circuit += PragmaSetNumberOfMeasurements::new(NUMBER_MEASUREMENTS, "ro".to_string());
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec![],
+    };
+
+    let job_location = with_retry("Posting the job", || backend.post_job(program.clone()))?;
+    println!("Posted job {}", job_location);
+
+    let started = time::Instant::now();
+    let status = loop {
+        let status = with_retry("Polling the job status", || {
+            backend.get_job_status(job_location.clone())
+        })?;
+        if ["completed", "error", "cancelled"].contains(&status.status.as_str()) {
+            break status;
+        }
+        if started.elapsed() > time::Duration::from_secs(backend.timeout() as u64 * 30) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("Job {} did not finish in time.", job_location),
+            });
+        }
+        thread::sleep(time::Duration::from_secs(1));
+    };
+    if status.status != "completed" {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Job {} finished with status {}: {}",
+                job_location, status.status, status.msg
+            ),
+        });
+    }
+
+    let result = with_retry("Downloading the result", || {
+        backend.get_job_result(job_location.clone())
+    })?;
+    println!(
+        "Job finished after {:.1}s with the counts {:?}",
+        started.elapsed().as_secs_f64(),
+        result.data.counts
+    );
+    Ok(())
+}
@@ -11,22 +11,39 @@
 // limitations under the License.
 
 use crate::api_devices::QRydAPIDevice;
+use crate::api_jobs::{
+    validate_job_program, validation_error, OperationWhitelist, QRydRunData, ValidationError,
+};
+pub use crate::api_jobs::{
+    DevDiagnostics, QRydAPICapabilities, QRydJobResult, QRydJobStatus, ResultCounts,
+    SUPPORTED_OPERATIONS,
+};
+use crate::bound_circuit::BoundCircuit;
+use crate::parameter_substitution::substitute_program_parameters;
+use crate::resource_estimation::{estimate_resources, ResourceEstimate};
 use bitvec::prelude::*;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use ndarray::Array2;
 use num_complex::Complex64;
 use reqwest::blocking::Client;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_ENCODING, CONTENT_TYPE};
 use roqoqo::backends::RegisterResult;
-use roqoqo::measurements::ClassicalRegister;
-use roqoqo::operations::Define;
+use roqoqo::measurements::{ClassicalRegister, PauliZProduct};
 use roqoqo::operations::Operation;
 use roqoqo::operations::*;
 use roqoqo::prelude::EvaluatingBackend;
-use roqoqo::prelude::Operate;
+use roqoqo::prelude::MeasureExpectationValues;
+use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister, Registers};
 use roqoqo::Circuit;
 use roqoqo::QuantumProgram;
 use roqoqo::RoqoqoBackendError;
 // use roqoqo_1_0;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::env;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::{thread, time};
 
 /// QRyd WebAPI backend.
@@ -53,82 +70,277 @@ pub struct APIBackend {
     pub dev: bool,
     /// API version.
     api_version: String,
+    /// Compress the body of posted jobs with gzip. Defaults to `false`.
+    #[serde(default)]
+    compress_payload: bool,
+    /// Capabilities of the API version, recorded by [APIBackend::capabilities].
+    #[serde(default)]
+    capabilities: Option<QRydAPICapabilities>,
+    /// Callback invoked with the job progress on each poll, set by [APIBackend::set_progress_callback].
+    #[serde(skip)]
+    progress_callback: Option<ProgressCallback>,
+    /// Request/response fixtures recorded or replayed, set by [APIBackend::set_fixture_mode].
+    #[serde(skip)]
+    fixtures: Option<SharedAPIFixtures>,
+    /// Operations accepted in addition to [SUPPORTED_OPERATIONS], only used with the dev option.
+    #[serde(default)]
+    additional_operations: BTreeSet<String>,
+    /// Operations of [SUPPORTED_OPERATIONS] rejected by this backend.
+    #[serde(default)]
+    excluded_operations: BTreeSet<String>,
+    /// Configuration of the HTTP client, set by [APIBackend::set_client_config].
+    #[serde(default)]
+    client_config: APIClientConfig,
+    /// HTTP client reused for all requests to the WebAPI, built on the first request.
+    #[serde(skip)]
+    client: SharedClient,
 }
 
-/// Local struct representing the body of the request message
-#[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
-struct QRydRunData {
-    /// Format of the quantum program: qoqo
-    format: String,
-    /// The QRyd WebAPI Backend used to execute operations and circuits.
-    /// At the moment limited to the string ('qryd_emulator')
-    backend: String,
-    /// Is develop version default: false
-    dev: bool,
-    /// Qubits that are fused in simulator default none
-    fusion_max_qubits: usize,
-    /// Random seed for the simulator default none
-    seed_simulator: Option<usize>,
-    /// Random seed for the compiler default none
-    seed_compiler: Option<usize>,
-    /// Use the extended set in SABRE routing
-    /// default true
-    use_extended_set: bool,
-    /// Use back-and-forth SABRE runs to optimize initial qubit mapping
-    /// default true
-    use_reverse_traversal: bool,
-    /// Number of back-and-forth iterations used
-    reverse_traversal_iterations: usize,
-    /// Size of the extended set if used, default 5
-    extended_set_size: usize,
-    /// Weight given to the extended set, default 0.5
-    extended_set_weight: f64,
-    /// Roqoqo QuantumProgram to be executed.
-    program: QuantumProgram,
+/// Fields of [QRydRunData] that are never dropped when down-converting a job.
+const REQUIRED_RUN_DATA_FIELDS: [&str; 3] = ["format", "backend", "program"];
+
+/// Progress of a job polled by the synchronous EvaluatingBackend of the [APIBackend].
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobProgress {
+    /// location (url) of the polled job
+    pub job_location: String,
+    /// status of the job returned by the poll
+    pub status: QRydJobStatus,
+    /// time elapsed since the job was posted
+    pub elapsed: time::Duration,
+    /// number of the poll, starting at 1
+    pub attempt: usize,
+    /// number of polls after which the run times out
+    pub max_attempts: usize,
 }
 
-/// Local struct representing the body of a validation error message
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
-struct ValidationError {
-    detail: ValidationTypes,
-    body: Option<QRydRunData>,
+/// Callback invoked with the job progress on each poll of the synchronous EvaluatingBackend.
+type ProgressCallbackFn = dyn FnMut(JobProgress) + Send;
+
+/// Shareable wrapper around a progress callback of the [APIBackend].
+#[derive(Clone)]
+struct ProgressCallback(Arc<Mutex<Box<ProgressCallbackFn>>>);
+
+impl std::fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProgressCallback")
+    }
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
-#[serde(untagged)]
-enum LocTypes {
-    LocStr(String),
-    LocInt(i32),
+impl PartialEq for ProgressCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
-#[serde(untagged)]
-enum ValidationTypes {
-    Simple(String),
-    Detailed(Vec<ValidationErrorDetail>),
+/// Mode of the request/response fixtures of the [APIBackend].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum APIFixtureMode {
+    /// Send requests to the WebAPI and record each request/response pair to the fixture file.
+    Record,
+    /// Serve the responses from the fixture file without accessing the network.
+    Replay,
 }
 
-/// Local struct representing the body of a validation error message
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
-struct ValidationErrorDetail {
-    #[serde(default)]
-    loc: Vec<LocTypes>,
+/// Request/response pair of the WebAPI recorded by the [APIBackend].
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct APIFixtureExchange {
+    /// HTTP method of the request
+    pub method: String,
+    /// path of the requested url, without scheme and host
+    pub path: String,
+    /// HTTP status code of the response
+    pub status: u16,
+    /// Location header of the response, if any
     #[serde(default)]
-    msg: String,
-    #[serde(alias = "type")]
+    pub location: Option<String>,
+    /// body of the response
     #[serde(default)]
-    internal_type: String,
+    pub body: String,
+}
+
+/// Recorded or replayed fixtures of the [APIBackend].
+#[derive(Debug)]
+struct APIFixtures {
+    /// Whether the fixtures are recorded or replayed.
+    mode: APIFixtureMode,
+    /// File the fixtures are written to or read from.
+    file: PathBuf,
+    /// The recorded exchanges in the order of the requests.
+    exchanges: Vec<APIFixtureExchange>,
+    /// Number of replayed responses for each method and path.
+    replayed: HashMap<(String, String), usize>,
+}
+
+impl APIFixtures {
+    /// Returns the next recorded response for the method and path.
+    ///
+    /// Once all recorded responses for a method and path have been served,
+    /// the last one is served again.
+    fn replay(&mut self, method: &str, path: &str) -> Result<APIResponse, RoqoqoBackendError> {
+        let matching: Vec<&APIFixtureExchange> = self
+            .exchanges
+            .iter()
+            .filter(|exchange| exchange.method == method && exchange.path == path)
+            .collect();
+        let counter = self
+            .replayed
+            .entry((method.to_string(), path.to_string()))
+            .or_insert(0);
+        let exchange = matching
+            .get(*counter)
+            .or_else(|| matching.last())
+            .ok_or_else(|| RoqoqoBackendError::NetworkError {
+                msg: format!("No recorded response for {} {}", method, path),
+            })?;
+        *counter += 1;
+        Ok(APIResponse {
+            status: reqwest::StatusCode::from_u16(exchange.status).map_err(|err| {
+                RoqoqoBackendError::NetworkError {
+                    msg: format!("Invalid recorded HTTP status code {:?}", err),
+                }
+            })?,
+            location: exchange.location.clone(),
+            body: exchange.body.clone(),
+        })
+    }
+
+    /// Appends an exchange to the recorded fixtures and writes them to the fixture file.
+    fn record(&mut self, exchange: APIFixtureExchange) -> Result<(), RoqoqoBackendError> {
+        self.exchanges.push(exchange);
+        let serialized = serde_json::to_string_pretty(&self.exchanges).map_err(|err| {
+            RoqoqoBackendError::GenericError {
+                msg: format!("Could not serialize the recorded fixtures {:?}", err),
+            }
+        })?;
+        std::fs::write(&self.file, serialized).map_err(|err| RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Could not write the fixture file {}: {:?}",
+                self.file.display(),
+                err
+            ),
+        })
+    }
+}
+
+/// Shareable wrapper around the fixtures of the [APIBackend].
+#[derive(Clone)]
+struct SharedAPIFixtures(Arc<Mutex<APIFixtures>>);
+
+impl std::fmt::Debug for SharedAPIFixtures {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SharedAPIFixtures")
+    }
+}
+
+impl PartialEq for SharedAPIFixtures {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
-/// Struct to represent QRyd response when calling for the Job status.
-#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
-pub struct QRydJobStatus {
-    /// status of the job, e.g. "pending"
-    #[serde(default)] // for optional fields
-    pub status: String,
-    /// message, if any
+/// HTTP client of the [APIBackend], built on the first request and reused afterwards.
+#[derive(Default)]
+struct SharedClient(OnceLock<Client>);
+
+impl Clone for SharedClient {
+    // A clone builds its own client, so that no client is dropped by a backend that never used it.
+    fn clone(&self) -> Self {
+        SharedClient::default()
+    }
+}
+
+impl std::fmt::Debug for SharedClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SharedClient")
+    }
+}
+
+impl PartialEq for SharedClient {
+    // The client is determined by the client configuration of the backend.
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// Response of the WebAPI, either received over the network or replayed from a fixture.
+struct APIResponse {
+    /// HTTP status code of the response
+    status: reqwest::StatusCode,
+    /// Location header of the response, if any
+    location: Option<String>,
+    /// body of the response
+    body: String,
+}
+
+impl APIResponse {
+    fn status(&self) -> reqwest::StatusCode {
+        self.status
+    }
+
+    fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.body)
+    }
+}
+
+/// Returns the path of a url, removing the scheme and host.
+fn url_path(url: &str) -> String {
+    let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    match without_scheme.find('/') {
+        Some(index) => without_scheme[index..].to_string(),
+        None => "/".to_string(),
+    }
+}
+
+/// Records the HTTP status code of a response in the current span and emits an event.
+#[cfg(feature = "tracing")]
+fn trace_response(
+    resp: &Result<reqwest::blocking::Response, RoqoqoBackendError>,
+    elapsed: time::Duration,
+) {
+    let elapsed_ms = elapsed.as_millis() as u64;
+    match resp {
+        Ok(resp) => {
+            let status = resp.status();
+            tracing::Span::current().record("status", status.as_u16());
+            if status.is_client_error() || status.is_server_error() {
+                tracing::warn!(
+                    status = status.as_u16(),
+                    elapsed_ms,
+                    "QRyd WebAPI request returned an error status"
+                );
+            } else {
+                tracing::debug!(
+                    status = status.as_u16(),
+                    elapsed_ms,
+                    "QRyd WebAPI request completed"
+                );
+            }
+        }
+        Err(err) => tracing::warn!(error = %err, elapsed_ms, "QRyd WebAPI request failed"),
+    }
+}
+
+/// Configuration of the HTTP client the [APIBackend] reuses for all requests to the WebAPI.
+///
+/// Options that are not set keep the defaults of the HTTP client.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct APIClientConfig {
+    /// The maximal number of idle connections kept open to the WebAPI.
+    #[serde(default)]
+    pub pool_max_idle_per_host: Option<usize>,
+    /// The time after which idle connections are closed.
     #[serde(default)]
-    pub msg: String,
+    pub pool_idle_timeout: Option<time::Duration>,
+    /// The interval of the TCP keep-alive probes of open connections.
+    #[serde(default)]
+    pub tcp_keepalive: Option<time::Duration>,
+    /// The timeout of a request, from connecting until the response has been read.
+    #[serde(default)]
+    pub request_timeout: Option<time::Duration>,
+    /// The timeout for establishing a connection.
+    #[serde(default)]
+    pub connect_timeout: Option<time::Duration>,
 }
 
 // /// Convert from new roqoqo 1.1.0 QuantumProgram to 1.0.0
@@ -217,71 +429,6 @@ pub struct QRydJobStatus {
 //     Ok(downconverted_program)
 // }
 
-/// Struct to represent QRyd response on the result for the posted Job.
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
-pub struct QRydJobResult {
-    /// The actual measured data
-    #[serde(default)]
-    pub data: ResultCounts,
-    /// Time taken to run and return the result
-    #[serde(default)]
-    pub time_taken: f64,
-    #[serde(default)]
-    /// The noise that was used in the run
-    pub noise: String,
-    #[serde(default)]
-    /// The method that was used for the run
-    pub method: String,
-    #[serde(default)]
-    /// The device that was used for the run
-    pub device: String,
-    // #[serde(default)]
-    // /// The used precision
-    // pub precision: String,
-    #[serde(default)]
-    /// The number of qubits that were used in the run
-    pub num_qubits: u32,
-    /// Number of classical bits
-    #[serde(default)]
-    pub num_clbits: u32,
-    #[serde(default)]
-    /// Max qubits
-    pub fusion_max_qubits: u32,
-    #[serde(default)]
-    /// Average qubits
-    pub fusion_avg_qubits: f64,
-    #[serde(default)]
-    /// Number of gates generated by gate fusion
-    pub fusion_generated_gates: u32,
-    #[serde(default)]
-    /// Number of single qubit gates actually executed in the circuit
-    pub executed_single_qubit_gates: u32,
-    #[serde(default)]
-    /// Number of two qubit gates actually executed in the circuit
-    pub executed_two_qubit_gates: u32,
-    /// The time taken to compile the quantum program on the WebAPI
-    #[serde(default)]
-    pub compilation_time: f64,
-}
-
-/// Represents the counts of measurements returned by QRyd API
-///
-/// Format corresponds to qiskit count format e.g.
-///
-/// ```python
-/// counts = {'0x1': 100.0, '0x4': 20.0}
-/// ```
-///
-/// where out of a total of 120 measurements 100 times
-/// qubit 0 was measured in state |1> while the same measurement gave |0> for
-/// qubits 1 and 2 and 20 times qubit 2 was measured in state |1>
-/// with qubits 1 and 0 in state |0>
-#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
-pub struct ResultCounts {
-    /// The dictionary of counts for each measured string
-    pub counts: HashMap<String, u64>,
-}
-
 impl APIBackend {
     /// Creates a new QRyd WebAPI backend.
     ///
@@ -315,6 +462,14 @@ impl APIBackend {
                 mock_port,
                 dev: false,
                 api_version: api_version.unwrap_or("v5_2".to_string()),
+                compress_payload: false,
+                capabilities: None,
+                progress_callback: None,
+                fixtures: None,
+                additional_operations: BTreeSet::new(),
+                excluded_operations: BTreeSet::new(),
+                client_config: APIClientConfig::default(),
+                client: SharedClient::default(),
             })
         } else {
             let access_token_internal: String = match access_token {
@@ -333,6 +488,14 @@ impl APIBackend {
                 mock_port,
                 dev: dev.unwrap_or(false),
                 api_version: api_version.unwrap_or("v5_2".to_string()),
+                compress_payload: false,
+                capabilities: None,
+                progress_callback: None,
+                fixtures: None,
+                additional_operations: BTreeSet::new(),
+                excluded_operations: BTreeSet::new(),
+                client_config: APIClientConfig::default(),
+                client: SharedClient::default(),
             })
         }
     }
@@ -351,36 +514,274 @@ impl APIBackend {
     /// * `quantumprogram` - Roqoqo QuantumProgram to be executed.
     ///
     pub fn post_job(&self, quantumprogram: QuantumProgram) -> Result<String, RoqoqoBackendError> {
-        // Prepare data that need to be passed to the WebAPI client
-        let seed_param: Option<usize> = self.device.seed(); // seed.unwrap_or(0);
-        let mut transform_pragma_repeated_measurement: bool = false;
+        self._post_job(quantumprogram, self.device.seed())
+    }
 
-        match &quantumprogram {
-            QuantumProgram::ClassicalRegister { measurement, .. } => {
-                if measurement.circuits.len() != 1 {
-                    return Err(RoqoqoBackendError::GenericError { msg: "QRyd API Backend only supports posting ClassicalRegister with one circuit".to_string() });
-                }
-                if measurement.circuits[0].is_parametrized() {
-                    return Err(RoqoqoBackendError::GenericError { msg: "Qoqo circuit contains symbolic parameters. The QrydWebAPI does not support symbolic parameters.".to_string() });
-                }
-                if measurement.circuits[0].count_occurences(&["PragmaRepeatedMeasurement"]) >= 1 {
-                    transform_pragma_repeated_measurement = true;
-                }
-                if let Some(const_c) = &measurement.constant_circuit {
-                    if const_c.count_occurences(&["PragmaRepeatedMeasurement"]) >= 1 {
-                        transform_pragma_repeated_measurement = true;
-                    }
-                }
-            }
+    /// Substitute the symbolic parameters of a program and post it as a job.
+    ///
+    /// The WebAPI does not support symbolic parameters. Like [QuantumProgram::run], the values
+    /// are assigned to the input parameter names of the program and substituted into all
+    /// circuits, including the constant circuit and the wrapped QRyd pragmas, before the job
+    /// is serialized.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantumprogram` - Roqoqo QuantumProgram to be executed.
+    /// * `parameters` - The values of the input parameters of the program.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The location of the posted job.
+    /// * `Err(RoqoqoBackendError)` - The number of parameters does not match, a circuit remains parametrized or posting the job failed.
+    pub fn post_job_with_parameters(
+        &self,
+        quantumprogram: QuantumProgram,
+        parameters: &[f64],
+    ) -> Result<String, RoqoqoBackendError> {
+        self.post_job(substitute_program_parameters(quantumprogram, parameters)?)
+    }
+
+    /// Estimate the resources of a program before posting it.
+    ///
+    /// The number of qubits, native gates and shots and the execution time are computed
+    /// locally from the gate times of the device of the backend, see [estimate_resources].
+    ///
+    /// # Arguments
+    ///
+    /// * `quantumprogram` - Roqoqo QuantumProgram to be estimated.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ResourceEstimate)` - The estimated resources.
+    /// * `Err(RoqoqoBackendError)` - The program is neither a ClassicalRegister nor a PauliZProduct program.
+    pub fn estimate_resources(
+        &self,
+        quantumprogram: &QuantumProgram,
+    ) -> Result<ResourceEstimate, RoqoqoBackendError> {
+        estimate_resources(&self.device, quantumprogram)
+    }
+
+    /// Post a job once per simulator seed and return the locations of the jobs.
+    ///
+    /// The same quantum program is executed once for every seed, overriding the seed
+    /// of the device. This allows reproducible studies of stochastic emulator runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantumprogram` - Roqoqo QuantumProgram to be executed.
+    /// * `seeds` - The simulator seeds, one job is posted for each seed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<usize, String>)` - The location of the job posted for each seed.
+    /// * `Err(RoqoqoBackendError)` - The seeds are empty or contain duplicates, or posting a job failed.
+    pub fn post_job_with_seeds(
+        &self,
+        quantumprogram: QuantumProgram,
+        seeds: &[usize],
+    ) -> Result<HashMap<usize, String>, RoqoqoBackendError> {
+        check_seeds(seeds)?;
+        let mut job_locations: HashMap<usize, String> = HashMap::new();
+        for seed in seeds {
+            let job_location = self._post_job(quantumprogram.clone(), Some(*seed))?;
+            job_locations.insert(*seed, job_location);
+        }
+        Ok(job_locations)
+    }
+
+    /// Run a circuit once per simulator seed and return the results for each seed.
+    ///
+    /// All jobs are posted before the results are polled, so the runs can be
+    /// executed concurrently on the WebAPI.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit` - The circuit to be executed.
+    /// * `seeds` - The simulator seeds, the circuit is executed once for each seed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<usize, _>)` - The output registers of the run with each seed.
+    /// * `Err(RoqoqoBackendError)` - The seeds are empty or contain duplicates, or a run failed.
+    #[allow(clippy::type_complexity)]
+    pub fn run_circuit_with_seeds(
+        &self,
+        circuit: &Circuit,
+        seeds: &[usize],
+    ) -> Result<HashMap<usize, Registers>, RoqoqoBackendError> {
+        let (program, registers) = circuit_to_program(circuit.clone())?;
+        let job_locations = self.post_job_with_seeds(program, seeds)?;
+        let mut results = HashMap::new();
+        for seed in seeds {
+            let result = self._wait_for_result(job_locations[seed].clone(), &registers)?;
+            results.insert(*seed, result);
+        }
+        Ok(results)
+    }
+
+    /// Runs a circuit bound to the device of the backend and waits for the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `bound_circuit` - The circuit validated against the device of the backend.
+    ///
+    /// # Returns
+    ///
+    /// * `RegisterResult` - The output registers of the circuit.
+    /// * `Err(RoqoqoBackendError)` - The circuit is bound to a different device or running the job failed.
+    pub fn run_bound_circuit(&self, bound_circuit: &BoundCircuit) -> RegisterResult {
+        match &self.device {
+            QRydAPIDevice::TweezerDevice(device) => bound_circuit.verify(device)?,
             _ => {
                 return Err(RoqoqoBackendError::GenericError {
-                    msg: "QRyd API Backend only supports posting ClassicalRegister QuantumPrograms"
+                    msg: "Bound circuits can only be run on backends with a TweezerDevice."
                         .to_string(),
                 })
             }
         }
+        self.run_circuit(bound_circuit.circuit())
+    }
+
+    /// Post the jobs of an expectation value measurement and return their locations.
+    ///
+    /// The WebAPI only executes ClassicalRegister measurements with a single circuit.
+    /// A PauliZProduct measurement is converted into one ClassicalRegister job per circuit,
+    /// the expectation values are computed locally from the results with
+    /// [APIBackend::evaluate_measurement_jobs]. ClassicalRegister programs are posted unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantumprogram` - Roqoqo QuantumProgram to be executed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The locations of the posted jobs, in the order of the circuits of the measurement.
+    /// * `Err(RoqoqoBackendError)` - The measurement is not supported by the WebAPI or posting a job failed.
+    pub fn post_measurement_jobs(
+        &self,
+        quantumprogram: QuantumProgram,
+    ) -> Result<Vec<String>, RoqoqoBackendError> {
+        match quantumprogram {
+            QuantumProgram::PauliZProduct { measurement, .. } => {
+                pauli_z_product_to_programs(&measurement)
+                    .into_iter()
+                    .map(|program| self._post_job(program, self.device.seed()))
+                    .collect()
+            }
+            _ => Ok(vec![self.post_job(quantumprogram)?]),
+        }
+    }
+
+    /// Wait for the jobs of an expectation value measurement and evaluate the expectation values.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantumprogram` - The QuantumProgram the jobs were posted for.
+    /// * `job_locations` - The locations returned by [APIBackend::post_measurement_jobs].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Option<HashMap<String, f64>>)` - The expectation values of the measurement.
+    /// * `Err(RoqoqoBackendError)` - The program is not a PauliZProduct measurement, the number of
+    ///                               job locations does not match the circuits or a job failed.
+    pub fn evaluate_measurement_jobs(
+        &self,
+        quantumprogram: &QuantumProgram,
+        job_locations: &[String],
+    ) -> Result<Option<HashMap<String, f64>>, RoqoqoBackendError> {
+        let measurement =
+            match quantumprogram {
+                QuantumProgram::PauliZProduct { measurement, .. } => measurement,
+                _ => return Err(RoqoqoBackendError::GenericError {
+                    msg: "Only PauliZProduct measurements can be evaluated to expectation values."
+                        .to_string(),
+                }),
+            };
+        if measurement.circuits.len() != job_locations.len() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "The measurement contains {} circuits but {} job locations were given.",
+                    measurement.circuits.len(),
+                    job_locations.len()
+                ),
+            });
+        }
+        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+        let mut float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let mut complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        for (program, job_location) in pauli_z_product_to_programs(measurement)
+            .into_iter()
+            .zip(job_locations.iter())
+        {
+            let registers = match &program {
+                QuantumProgram::ClassicalRegister { measurement, .. } => {
+                    let mut circuit = measurement
+                        .constant_circuit
+                        .clone()
+                        .unwrap_or_else(Circuit::new);
+                    circuit += measurement.circuits[0].clone();
+                    readout_registers(&circuit)?
+                }
+                _ => Vec::new(),
+            };
+            let (tmp_bit_registers, tmp_float_registers, tmp_complex_registers) =
+                self._wait_for_result(job_location.clone(), &registers)?;
+            bit_registers.extend(tmp_bit_registers);
+            float_registers.extend(tmp_float_registers);
+            complex_registers.extend(tmp_complex_registers);
+        }
+        measurement
+            .evaluate(bit_registers, float_registers, complex_registers)
+            .map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("Could not evaluate the expectation values: {}", err),
+            })
+    }
 
-        self._check_for_api_compatability(&quantumprogram)?;
+    /// Post a job with the given simulator seed and return the location of the job.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "qryd_post_job",
+            skip_all,
+            fields(api_version = %self.api_version, job_location = tracing::field::Empty)
+        )
+    )]
+    fn _post_job(
+        &self,
+        quantumprogram: QuantumProgram,
+        seed_param: Option<usize>,
+    ) -> Result<String, RoqoqoBackendError> {
+        // PauliZProduct measurements with a single circuit are posted as ClassicalRegister,
+        // the expectation values are evaluated locally after download.
+        let quantumprogram = match quantumprogram {
+            QuantumProgram::PauliZProduct { measurement, .. } => {
+                if measurement.circuits.len() != 1 {
+                    return Err(RoqoqoBackendError::GenericError { msg: "QRyd API Backend only supports posting PauliZProduct measurements with one circuit as a single job, use post_measurement_jobs to post one job per circuit".to_string() });
+                }
+                pauli_z_product_to_programs(&measurement).remove(0)
+            }
+            _ => quantumprogram,
+        };
+        validate_job_program(
+            &self.device,
+            &quantumprogram,
+            Some(OperationWhitelist {
+                dev: self.dev,
+                additional_operations: &self.additional_operations,
+                excluded_operations: &self.excluded_operations,
+            }),
+            self.capabilities.as_ref(),
+        )?;
+        // Prepare data that need to be passed to the WebAPI client
+        let transform_pragma_repeated_measurement = match &quantumprogram {
+            QuantumProgram::ClassicalRegister { measurement, .. } => measurement
+                .circuits
+                .iter()
+                .chain(measurement.constant_circuit.iter())
+                .any(|circuit| circuit.count_occurences(&["PragmaRepeatedMeasurement"]) >= 1),
+            _ => false,
+        };
 
         // If a PragmaRepeatedMeasurement is present, substitute it with a set of MeasureQubit operations
         //  followed by a PragmaSetNumberOfMeasurements.
@@ -458,101 +859,80 @@ impl APIBackend {
         // let quantumprogram: roqoqo_1_0::QuantumProgram =
         //     downconvert_roqoqo_version(quantumprogram)?;
         // dbg!(&serde_json::to_string(&quantumprogram).unwrap());
-        let data = QRydRunData {
-            format: "qoqo".to_string(),
-            backend: self.device.qrydbackend(),
-            program: filtered_qp,
-            dev: self.dev,
-            fusion_max_qubits: 4,
-            seed_simulator: seed_param,
-            seed_compiler: None,
-            use_extended_set: true,
-            use_reverse_traversal: true,
-            extended_set_size: 5,
-            extended_set_weight: 0.5,
-            reverse_traversal_iterations: 3,
-        };
+        let data = QRydRunData::new(self.device.qrydbackend(), filtered_qp, self.dev, seed_param);
+        let (payload_headers, payload_body) = self._prepare_payload(&data)?;
 
         // Prepare WebAPI client
-        let client: Client = if self.mock_port.is_some() {
-            reqwest::blocking::Client::builder().build().map_err(|x| {
-                RoqoqoBackendError::NetworkError {
-                    msg: format!("could not create test client {:?}", x),
-                }
-            })?
-        } else {
-            reqwest::blocking::Client::builder()
-                .https_only(true)
-                .build()
-                .map_err(|x| RoqoqoBackendError::NetworkError {
-                    msg: format!("could not create https client {:?}", x),
-                })?
-        };
+        let client: Client = self._client()?;
         let hqs_env_var = env::var("QRYD_API_HQS").is_ok();
 
         // Call WebAPI client
         // here: value for put() temporarily fixed.
         // needs to be derived dynamically based on the provided parameter 'qrydbackend'
-        let resp = if let Some(mock_port) = &self.mock_port {
-            client
-                .post(format!("http://127.0.0.1:{}", mock_port))
-                .json(&data)
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?
+        let url_string: String = if let Some(mock_port) = &self.mock_port {
+            format!("http://127.0.0.1:{}", mock_port)
         } else {
-            match (self.dev, hqs_env_var) {
-                (true, true) => client
-                    .post(format!(
-                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/jobs",
-                        self.api_version
-                    ))
-                    .header("X-API-KEY", self.access_token.clone())
-                    .header("X-DEV", "?1")
-                    .header("X-HQS", "?1")
-                    .json(&data)
-                    .send()
-                    .map_err(|e| RoqoqoBackendError::NetworkError {
-                        msg: format!("{:?}", e),
-                    })?,
-                (true, false) => client
-                    .post(format!(
-                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/jobs",
-                        self.api_version
-                    ))
-                    .header("X-API-KEY", self.access_token.clone())
-                    .header("X-DEV", "?1")
-                    .json(&data)
-                    .send()
-                    .map_err(|e| RoqoqoBackendError::NetworkError {
-                        msg: format!("{:?}", e),
-                    })?,
-                (false, true) => client
-                    .post(format!(
-                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/jobs",
-                        self.api_version
-                    ))
-                    .header("X-API-KEY", self.access_token.clone())
-                    .header("X-HQS", "?1")
-                    .json(&data)
-                    .send()
-                    .map_err(|e| RoqoqoBackendError::NetworkError {
-                        msg: format!("{:?}", e),
-                    })?,
-                (false, false) => client
-                    .post(format!(
-                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/jobs",
-                        self.api_version
-                    ))
-                    .header("X-API-KEY", self.access_token.clone())
-                    .json(&data)
+            format!(
+                "https://api.qryddemo.itp3.uni-stuttgart.de/{}/jobs",
+                self.api_version
+            )
+        };
+        let path = url_path(&url_string);
+        let resp = self._exchange("POST", path, || {
+            Ok(if self.mock_port.is_some() {
+                client
+                    .post(url_string)
+                    .headers(payload_headers)
+                    .body(payload_body)
                     .send()
                     .map_err(|e| RoqoqoBackendError::NetworkError {
                         msg: format!("{:?}", e),
-                    })?,
-            }
-        };
+                    })?
+            } else {
+                match (self.dev, hqs_env_var) {
+                    (true, true) => client
+                        .post(url_string)
+                        .header("X-API-KEY", self.access_token.clone())
+                        .header("X-DEV", "?1")
+                        .header("X-HQS", "?1")
+                        .headers(payload_headers.clone())
+                        .body(payload_body.clone())
+                        .send()
+                        .map_err(|e| RoqoqoBackendError::NetworkError {
+                            msg: format!("{:?}", e),
+                        })?,
+                    (true, false) => client
+                        .post(url_string)
+                        .header("X-API-KEY", self.access_token.clone())
+                        .header("X-DEV", "?1")
+                        .headers(payload_headers.clone())
+                        .body(payload_body.clone())
+                        .send()
+                        .map_err(|e| RoqoqoBackendError::NetworkError {
+                            msg: format!("{:?}", e),
+                        })?,
+                    (false, true) => client
+                        .post(url_string)
+                        .header("X-API-KEY", self.access_token.clone())
+                        .header("X-HQS", "?1")
+                        .headers(payload_headers.clone())
+                        .body(payload_body.clone())
+                        .send()
+                        .map_err(|e| RoqoqoBackendError::NetworkError {
+                            msg: format!("{:?}", e),
+                        })?,
+                    (false, false) => client
+                        .post(url_string)
+                        .header("X-API-KEY", self.access_token.clone())
+                        .headers(payload_headers.clone())
+                        .body(payload_body.clone())
+                        .send()
+                        .map_err(|e| RoqoqoBackendError::NetworkError {
+                            msg: format!("{:?}", e),
+                        })?,
+                }
+            })
+        })?;
 
         let status_code = resp.status();
         if status_code != reqwest::StatusCode::CREATED {
@@ -563,7 +943,7 @@ impl APIBackend {
                             msg: format!("Error parsing ValidationError message {:?}", e),
                         }
                     })?;
-                return Err(self._handle_validation_error(querry_response));
+                return Err(validation_error(querry_response));
             }
             Err(RoqoqoBackendError::NetworkError {
                 msg: format!(
@@ -572,19 +952,14 @@ impl APIBackend {
                 ),
             })
         } else {
-            let resp_headers = resp.headers();
-            if resp_headers.contains_key("Location") {
-                Ok(resp_headers["Location"]
-                    .to_str()
-                    .map_err(|err| RoqoqoBackendError::NetworkError {
-                        msg: format!("Server response missing the Location header {:?}", err),
-                    })?
-                    .to_string())
-            } else {
-                Err(RoqoqoBackendError::NetworkError {
+            let job_location = resp
+                .location
+                .ok_or_else(|| RoqoqoBackendError::NetworkError {
                     msg: "Server response missing the Location header".to_string(),
-                })
-            }
+                })?;
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("job_location", job_location.as_str());
+            Ok(job_location)
         }
     }
 
@@ -599,64 +974,58 @@ impl APIBackend {
     /// * QRydJobStatus - status and message of the job.
     /// * RoqoqoBackendError in case of a network failure.
     ///
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", name = "qryd_get_job_status", skip(self))
+    )]
     pub fn get_job_status(
         &self,
         job_location: String,
     ) -> Result<QRydJobStatus, RoqoqoBackendError> {
         // Prepare WebAPI client
-        let client: Client = if self.mock_port.is_some() {
-            reqwest::blocking::Client::builder().build().map_err(|x| {
-                RoqoqoBackendError::NetworkError {
-                    msg: format!("could not create test client {:?}", x),
-                }
-            })?
-        } else {
-            reqwest::blocking::Client::builder()
-                .https_only(true)
-                .build()
-                .map_err(|x| RoqoqoBackendError::NetworkError {
-                    msg: format!("could not create https client {:?}", x),
-                })?
-        };
+        let client: Client = self._client()?;
 
         let url_string: String = job_location + "/status";
         let hqs_env_var = env::var("QRYD_API_HQS").is_ok();
 
         // Call WebAPI client
-        let resp = match (self.dev, hqs_env_var) {
-            (true, true) => client
-                .get(url_string)
-                .header("X-API-KEY", self.access_token.clone())
-                .header("X-DEV", "?1")
-                .header("X-HQS", "?1")
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-            (true, false) => client
-                .get(url_string)
-                .header("X-API-KEY", self.access_token.clone())
-                .header("X-DEV", "?1")
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-            (false, true) => client
-                .get(url_string)
-                .header("X-API-KEY", self.access_token.clone())
-                .header("X-HQS", "?1")
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-            (false, false) => client
-                .get(url_string)
-                .header("X-API-KEY", self.access_token.clone())
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-        };
+        let path = url_path(&url_string);
+        let resp = self._exchange("GET", path, || {
+            Ok(match (self.dev, hqs_env_var) {
+                (true, true) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-DEV", "?1")
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (true, false) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-DEV", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, true) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, false) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+            })
+        })?;
 
         let status_code = resp.status();
         if status_code != reqwest::StatusCode::OK {
@@ -667,7 +1036,7 @@ impl APIBackend {
                             msg: format!("Error parsing ValidationError message {:?}", e),
                         }
                     })?;
-                return Err(self._handle_validation_error(querry_response));
+                return Err(validation_error(querry_response));
             }
             Err(RoqoqoBackendError::NetworkError {
                 msg: format!(
@@ -700,61 +1069,80 @@ impl APIBackend {
         &self,
         job_location: String,
     ) -> Result<QRydJobResult, RoqoqoBackendError> {
+        let raw_result = self.get_job_result_raw(job_location)?;
+        serde_json::from_value::<QRydJobResult>(raw_result).map_err(|e| {
+            RoqoqoBackendError::NetworkError {
+                msg: format!("Error parsing job status response {:?}", e),
+            }
+        })
+    }
+
+    /// Get the result of a completed WebAPI job as untyped JSON.
+    ///
+    /// Contrary to [APIBackend::get_job_result], all fields returned by the WebAPI are kept,
+    /// including fields added in newer WebAPI versions that [QRydJobResult] does not know yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_location` - location (url) of the job one is interested in.
+    ///
+    /// # Returns
+    /// * The JSON body returned by the WebAPI.
+    /// * RoqoqoBackendError in case of a network failure or a body that is not valid JSON.
+    ///
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", name = "qryd_get_job_result", skip(self))
+    )]
+    pub fn get_job_result_raw(
+        &self,
+        job_location: String,
+    ) -> Result<serde_json::Value, RoqoqoBackendError> {
         // Prepare WebAPI client
-        let client: Client = if self.mock_port.is_some() {
-            reqwest::blocking::Client::builder().build().map_err(|x| {
-                RoqoqoBackendError::NetworkError {
-                    msg: format!("could not create test client {:?}", x),
-                }
-            })?
-        } else {
-            reqwest::blocking::Client::builder()
-                .https_only(true)
-                .build()
-                .map_err(|x| RoqoqoBackendError::NetworkError {
-                    msg: format!("could not create https client {:?}", x),
-                })?
-        };
+        let client: Client = self._client()?;
 
         // construct URL with {job_id} not required?
         let url_string: String = job_location + "/result";
         let hqs_env_var = env::var("QRYD_API_HQS").is_ok();
 
         // Call WebAPI client
-        let resp = match (self.dev, hqs_env_var) {
-            (true, true) => client
-                .get(url_string)
-                .header("X-API-KEY", self.access_token.clone())
-                .header("X-DEV", "?1")
-                .header("X-HQS", "?1")
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-            (true, false) => client
-                .get(url_string)
-                .header("X-API-KEY", self.access_token.clone())
-                .header("X-DEV", "?1")
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-            (false, true) => client
-                .get(url_string)
-                .header("X-API-KEY", self.access_token.clone())
-                .header("X-HQS", "?1")
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-            (false, false) => client
-                .get(url_string)
-                .header("X-API-KEY", self.access_token.clone())
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-        };
+        let path = url_path(&url_string);
+        let resp = self._exchange("GET", path, || {
+            Ok(match (self.dev, hqs_env_var) {
+                (true, true) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-DEV", "?1")
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (true, false) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-DEV", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, true) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, false) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+            })
+        })?;
 
         let status_code = resp.status();
         if status_code != reqwest::StatusCode::OK {
@@ -765,7 +1153,7 @@ impl APIBackend {
                             msg: format!("Error parsing ValidationError message {:?}", e),
                         }
                     })?;
-                return Err(self._handle_validation_error(querry_response));
+                return Err(validation_error(querry_response));
             }
             Err(RoqoqoBackendError::NetworkError {
                 msg: format!(
@@ -775,12 +1163,10 @@ impl APIBackend {
             })
         } else {
             // response object
-            let response: Result<QRydJobResult, RoqoqoBackendError> = resp
-                .json::<QRydJobResult>()
+            resp.json::<serde_json::Value>()
                 .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("Error parsing job status response {:?}", e),
-                });
-            response
+                    msg: format!("Error parsing job result response {:?}", e),
+                })
         }
     }
 
@@ -793,218 +1179,734 @@ impl APIBackend {
     /// # Returns
     /// * RoqoqoBackendError in case of a network failure.
     ///
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", name = "qryd_delete_job", skip(self))
+    )]
     pub fn delete_job(&self, job_location: String) -> Result<(), RoqoqoBackendError> {
         // Prepare WebAPI client
-        let client: Client = if self.mock_port.is_some() {
-            reqwest::blocking::Client::builder().build().map_err(|x| {
-                RoqoqoBackendError::NetworkError {
-                    msg: format!("could not create test client {:?}", x),
-                }
-            })?
+        let client: Client = self._client()?;
+
+        let hqs_env_var = env::var("QRYD_API_HQS").is_ok();
+
+        // Call WebAPI client
+        let path = url_path(&job_location);
+        let resp = self._exchange("DELETE", path, || {
+            Ok(match (self.dev, hqs_env_var) {
+                (true, true) => client
+                    .delete(job_location)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-DEV", "?1")
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (true, false) => client
+                    .delete(job_location)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-DEV", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, true) => client
+                    .delete(job_location)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, false) => client
+                    .delete(job_location)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+            })
+        })?;
+
+        let status_code = resp.status();
+        if status_code != reqwest::StatusCode::OK {
+            if status_code == reqwest::StatusCode::UNPROCESSABLE_ENTITY {
+                let querry_response: ValidationError =
+                    resp.json::<ValidationError>().map_err(|e| {
+                        RoqoqoBackendError::NetworkError {
+                            msg: format!("Error parsing ValidationError message {:?}", e),
+                        }
+                    })?;
+                return Err(validation_error(querry_response));
+            }
+            Err(RoqoqoBackendError::NetworkError {
+                msg: format!(
+                    "Request to server failed with HTTP status code {:?}",
+                    status_code
+                ),
+            })
         } else {
-            reqwest::blocking::Client::builder()
-                .https_only(true)
-                .build()
-                .map_err(|x| RoqoqoBackendError::NetworkError {
-                    msg: format!("could not create https client {:?}", x),
+            Ok(())
+        }
+    }
+
+    /// Convert the counts returned from the QRyd WebAPI to Qoqo-style registers
+    ///
+    /// # Arguments
+    ///
+    /// `counts` - The counts returned from the Qryd WebAPI
+    /// `readout` - The name of the readout register. Needs to be specified based on original circuit
+    ///             cannont be extrected from returned result
+    /// `number_qubits` - The number of measured qubits. Needs to be specified based on original circuit
+    ///                   cannont be extrected from returned result
+    ///
+    pub fn counts_to_result(
+        counts: ResultCounts,
+        readout: String,
+        number_qubits: usize,
+    ) -> RegisterResult {
+        APIBackend::counts_to_result_registers(
+            counts,
+            &[ReadoutRegister {
+                name: readout,
+                offset: 0,
+                length: number_qubits,
+            }],
+        )
+    }
+
+    /// Convert the counts returned from the QRyd WebAPI to Qoqo-style registers for several readout registers
+    ///
+    /// Each measured bitstring contains the classical bits of all readout registers of the circuit.
+    /// The bits of every register are extracted from its offset in the bitstring, see [readout_registers]
+    /// for the layout used by the WebAPI.
+    ///
+    /// # Arguments
+    ///
+    /// * `counts` - The counts returned from the Qryd WebAPI
+    /// * `registers` - The readout registers with their bit offsets. Need to be specified based on original circuit
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Registers)` - The bit registers, one entry for each readout register.
+    /// * `Err(RoqoqoBackendError)` - A register name is used twice, two registers overlap or a measurement result could not be parsed.
+    pub fn counts_to_result_registers(
+        counts: ResultCounts,
+        registers: &[ReadoutRegister],
+    ) -> RegisterResult {
+        let mut bit_map: HashMap<String, Vec<Vec<bool>>> = HashMap::new();
+        let float_map: HashMap<String, Vec<Vec<f64>>> = HashMap::new();
+        let complex_map: HashMap<String, Vec<Vec<Complex64>>> = HashMap::new();
+        for (index, register) in registers.iter().enumerate() {
+            if bit_map.insert(register.name.clone(), Vec::new()).is_some() {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!("Readout register {} is defined twice.", register.name),
+                });
+            }
+            if let Some(other) = registers[..index].iter().find(|other| {
+                register.offset < other.offset + other.length
+                    && other.offset < register.offset + register.length
+            }) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Readout registers {} and {} overlap in the measured bitstring.",
+                        other.name, register.name
+                    ),
+                });
+            }
+        }
+        let number_bits = registers
+            .iter()
+            .map(|register| register.offset + register.length)
+            .max()
+            .unwrap_or(0);
+        for (measurement, count) in counts.counts.into_iter() {
+            let tmp_vec = measurement_to_bits(&measurement, number_bits)?;
+            for register in registers {
+                let register_bits =
+                    tmp_vec[register.offset..register.offset + register.length].to_vec();
+                if let Some(measurement_record) = bit_map.get_mut(&register.name) {
+                    for _ in 0..count {
+                        measurement_record.push(register_bits.clone())
+                    }
+                }
+            }
+        }
+        Ok((bit_map, float_map, complex_map))
+    }
+
+    /// Convert the counts returned from the QRyd WebAPI to a boolean matrix of the measured bits.
+    ///
+    /// Row `i` of the matrix holds the measured bits of shot `i`, column `j` the bit of qubit `j`.
+    /// Compared to [APIBackend::counts_to_result] the bits of all shots are stored in a
+    /// single contiguous allocation, which keeps the memory footprint low for large numbers of shots.
+    /// The shots of the same measured bitstring are stored in consecutive rows,
+    /// sorted by the hexadecimal bitstrings returned by the WebAPI.
+    ///
+    /// # Arguments
+    ///
+    /// * `counts` - The counts returned from the Qryd WebAPI
+    /// * `number_qubits` - The number of measured qubits. Needs to be specified based on original circuit
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<bool>)` - The measured bits with shape (number of shots, number_qubits).
+    /// * `Err(RoqoqoBackendError)` - A measurement result could not be parsed.
+    pub fn counts_to_array(
+        counts: &ResultCounts,
+        number_qubits: usize,
+    ) -> Result<Array2<bool>, RoqoqoBackendError> {
+        let number_shots: u64 = counts.counts.values().sum();
+        let mut measurements: Vec<(&String, &u64)> = counts.counts.iter().collect();
+        measurements.sort();
+        let mut bits: Array2<bool> =
+            Array2::from_elem((number_shots as usize, number_qubits), false);
+        let mut row = 0;
+        for (measurement, count) in measurements {
+            let measured_bits = measurement_to_bits(measurement, number_qubits)?;
+            for _ in 0..*count {
+                for (qubit, bit) in measured_bits.iter().enumerate() {
+                    bits[[row, qubit]] = *bit;
+                }
+                row += 1;
+            }
+        }
+        Ok(bits)
+    }
+
+    /// Setter for the dev option of the APIDevice.
+    ///
+    /// # Arguments
+    ///
+    /// * `dev` - The boolean to set the dev option to.
+    ///
+    pub fn set_dev(&mut self, dev: bool) {
+        self.dev = dev;
+    }
+
+    /// Returns whether the dev option of the APIDevice is set.
+    pub fn dev(&self) -> bool {
+        self.dev
+    }
+
+    /// Returns the version of the QRyd WebAPI used by the backend.
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
+    /// Returns the number of status queries of the synchronous EvaluatingBackend before it times out.
+    pub fn timeout(&self) -> usize {
+        self.timeout
+    }
+
+    /// Adds an operation to the operations accepted by `post_job`.
+    ///
+    /// Allows exercising new capabilities of the WebAPI before they are supported by a release
+    /// of this crate. Operations not in [SUPPORTED_OPERATIONS] can only be added with the dev
+    /// option set and are only accepted as long as it is set.
+    /// An operation previously removed with [APIBackend::disallow_operation] is accepted again.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The operation is accepted by the backend.
+    /// * `Err(RoqoqoBackendError)` - The operation is not in [SUPPORTED_OPERATIONS] and the dev option is not set.
+    pub fn allow_operation(&mut self, hqslang: &str) -> Result<(), RoqoqoBackendError> {
+        if SUPPORTED_OPERATIONS.contains(&hqslang) {
+            self.excluded_operations.remove(hqslang);
+            return Ok(());
+        }
+        if !self.dev {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Operation {} can only be added to the accepted operations with the dev option set.",
+                    hqslang
+                ),
+            });
+        }
+        self.additional_operations.insert(hqslang.to_string());
+        Ok(())
+    }
+
+    /// Removes an operation from the operations accepted by `post_job`.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the operation.
+    ///
+    pub fn disallow_operation(&mut self, hqslang: &str) {
+        self.additional_operations.remove(hqslang);
+        if SUPPORTED_OPERATIONS.contains(&hqslang) {
+            self.excluded_operations.insert(hqslang.to_string());
+        }
+    }
+
+    /// Resets the operations accepted by `post_job` to [SUPPORTED_OPERATIONS].
+    pub fn reset_supported_operations(&mut self) {
+        self.additional_operations.clear();
+        self.excluded_operations.clear();
+    }
+
+    /// Returns the sorted hqslang names of the operations currently accepted by `post_job`.
+    pub fn supported_operations(&self) -> Vec<String> {
+        let mut operations: BTreeSet<String> = SUPPORTED_OPERATIONS
+            .iter()
+            .filter(|name| {
+                **name != "PragmaActiveReset" || self.device.qrydbackend() == "qiskit_emulator"
+            })
+            .filter(|name| !self.excluded_operations.contains(**name))
+            .map(|name| name.to_string())
+            .collect();
+        if self.dev {
+            operations.extend(self.additional_operations.iter().cloned());
+        }
+        operations.into_iter().collect()
+    }
+
+    /// Setter for the gzip compression of posted job payloads.
+    ///
+    /// When enabled, the serialized job is sent gzip compressed with a
+    /// `Content-Encoding: gzip` header, reducing the upload size of large circuits.
+    ///
+    /// # Arguments
+    ///
+    /// * `compress_payload` - Whether to compress the body of posted jobs.
+    ///
+    pub fn set_compress_payload(&mut self, compress_payload: bool) {
+        self.compress_payload = compress_payload;
+    }
+
+    /// Returns whether the body of posted jobs is gzip compressed.
+    pub fn compress_payload(&self) -> bool {
+        self.compress_payload
+    }
+
+    /// Setter for the configuration of the HTTP client used for the requests to the WebAPI.
+    ///
+    /// The client is built on the first request and reused by all following requests of the
+    /// backend, keeping the connections to the WebAPI open between polls. Setting a new
+    /// configuration discards the current client. Clones of the backend build their own client.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_config` - The pool size, keep-alive and timeouts of the client.
+    ///
+    pub fn set_client_config(&mut self, client_config: APIClientConfig) {
+        self.client_config = client_config;
+        self.client = SharedClient::default();
+    }
+
+    /// Returns the configuration of the HTTP client used for the requests to the WebAPI.
+    pub fn client_config(&self) -> &APIClientConfig {
+        &self.client_config
+    }
+
+    /// Sets a callback that is invoked with the job progress on each poll of the synchronous
+    /// EvaluatingBackend.
+    ///
+    /// The progress contains the polled job status, including the queue position, the estimated
+    /// start time and the progress of the job when the WebAPI provides them, as well as the
+    /// elapsed time since posting the job and the number of the poll.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The function called with the progress of the job.
+    ///
+    pub fn set_progress_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(JobProgress) + Send + 'static,
+    {
+        self.progress_callback = Some(ProgressCallback(Arc::new(Mutex::new(Box::new(callback)))));
+    }
+
+    /// Removes the progress callback set by [APIBackend::set_progress_callback].
+    pub fn clear_progress_callback(&mut self) {
+        self.progress_callback = None;
+    }
+
+    /// Sets the backend to record or replay the requests to the WebAPI.
+    ///
+    /// In record mode, every request is sent to the WebAPI (or the mock server) as usual and
+    /// the request method and path are written to the fixture file together with the response.
+    /// In replay mode, the responses are served from the fixture file without accessing the network,
+    /// in the order they were recorded for each method and path. This allows deterministic
+    /// offline tests of workflows using the APIBackend.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Whether to record or replay the requests.
+    /// * `file` - The JSON file the fixtures are written to or read from.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The fixture mode has been set.
+    /// * `Err(RoqoqoBackendError)` - The fixture file could not be read in replay mode.
+    ///
+    pub fn set_fixture_mode<P: AsRef<Path>>(
+        &mut self,
+        mode: APIFixtureMode,
+        file: P,
+    ) -> Result<(), RoqoqoBackendError> {
+        let file = file.as_ref().to_path_buf();
+        let exchanges: Vec<APIFixtureExchange> = match mode {
+            APIFixtureMode::Record => Vec::new(),
+            APIFixtureMode::Replay => {
+                let content = std::fs::read_to_string(&file).map_err(|err| {
+                    RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Could not read the fixture file {}: {:?}",
+                            file.display(),
+                            err
+                        ),
+                    }
+                })?;
+                serde_json::from_str(&content).map_err(|err| RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Could not parse the fixture file {}: {:?}",
+                        file.display(),
+                        err
+                    ),
                 })?
+            }
         };
+        self.fixtures = Some(SharedAPIFixtures(Arc::new(Mutex::new(APIFixtures {
+            mode,
+            file,
+            exchanges,
+            replayed: HashMap::new(),
+        }))));
+        Ok(())
+    }
+
+    /// Returns the fixture mode set by [APIBackend::set_fixture_mode], if any.
+    pub fn fixture_mode(&self) -> Option<APIFixtureMode> {
+        self.fixtures
+            .as_ref()
+            .and_then(|fixtures| fixtures.0.lock().ok().map(|fixtures| fixtures.mode))
+    }
+
+    /// Stops recording or replaying the requests to the WebAPI.
+    pub fn clear_fixture_mode(&mut self) {
+        self.fixtures = None;
+    }
+
+    /// Queries the capabilities of the used WebAPI version and records them in the backend.
+    ///
+    /// Once recorded, `post_job` rejects programs containing operations the WebAPI version
+    /// does not support and omits job fields it does not understand.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QRydAPICapabilities)` - The capabilities reported by the WebAPI.
+    /// * `Err(RoqoqoBackendError)` - In case of a network failure or an unparsable response.
+    ///
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "qryd_capabilities",
+            skip_all,
+            fields(api_version = %self.api_version)
+        )
+    )]
+    pub fn capabilities(&mut self) -> Result<QRydAPICapabilities, RoqoqoBackendError> {
+        // Prepare WebAPI client
+        let client: Client = self._client()?;
 
+        let url_string: String = if let Some(mock_port) = &self.mock_port {
+            format!("http://127.0.0.1:{}/capabilities", mock_port)
+        } else {
+            format!(
+                "https://api.qryddemo.itp3.uni-stuttgart.de/{}/capabilities",
+                self.api_version
+            )
+        };
         let hqs_env_var = env::var("QRYD_API_HQS").is_ok();
 
         // Call WebAPI client
-        let resp = match (self.dev, hqs_env_var) {
-            (true, true) => client
-                .delete(job_location)
-                .header("X-API-KEY", self.access_token.clone())
-                .header("X-DEV", "?1")
-                .header("X-HQS", "?1")
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-            (true, false) => client
-                .delete(job_location)
-                .header("X-API-KEY", self.access_token.clone())
-                .header("X-DEV", "?1")
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-            (false, true) => client
-                .delete(job_location)
-                .header("X-API-KEY", self.access_token.clone())
-                .header("X-HQS", "?1")
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-            (false, false) => client
-                .delete(job_location)
-                .header("X-API-KEY", self.access_token.clone())
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?,
-        };
+        let path = url_path(&url_string);
+        let resp = self._exchange("GET", path, || {
+            Ok(match (self.dev, hqs_env_var) {
+                (true, true) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-DEV", "?1")
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (true, false) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-DEV", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, true) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, false) => client
+                    .get(url_string)
+                    .header("X-API-KEY", self.access_token.clone())
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+            })
+        })?;
 
         let status_code = resp.status();
         if status_code != reqwest::StatusCode::OK {
-            if status_code == reqwest::StatusCode::UNPROCESSABLE_ENTITY {
-                let querry_response: ValidationError =
-                    resp.json::<ValidationError>().map_err(|e| {
-                        RoqoqoBackendError::NetworkError {
-                            msg: format!("Error parsing ValidationError message {:?}", e),
-                        }
-                    })?;
-                return Err(self._handle_validation_error(querry_response));
-            }
-            Err(RoqoqoBackendError::NetworkError {
+            return Err(RoqoqoBackendError::NetworkError {
                 msg: format!(
                     "Request to server failed with HTTP status code {:?}",
                     status_code
                 ),
-            })
-        } else {
-            Ok(())
+            });
         }
-    }
-
-    /// Convert the counts returned from the QRyd WebAPI to Qoqo-style registers
-    ///
-    /// # Arguments
-    ///
-    /// `counts` - The counts returned from the Qryd WebAPI
-    /// `readout` - The name of the readout register. Needs to be specified based on original circuit
-    ///             cannont be extrected from returned result
-    /// `number_qubits` - The number of measured qubits. Needs to be specified based on original circuit
-    ///                   cannont be extrected from returned result
-    ///
-    pub fn counts_to_result(
-        counts: ResultCounts,
-        readout: String,
-        number_qubits: usize,
-    ) -> RegisterResult {
-        let mut bit_map: HashMap<String, Vec<Vec<bool>>> = HashMap::new();
-        let float_map: HashMap<String, Vec<Vec<f64>>> = HashMap::new();
-        let complex_map: HashMap<String, Vec<Vec<Complex64>>> = HashMap::new();
-        let mut measurement_record: Vec<Vec<bool>> = Vec::new();
-        for (measurement, count) in counts.counts.into_iter() {
-            let bit_representation: Vec<u8> = hex::decode(
-                measurement
-                    .strip_prefix("0x")
-                    .map(|s| {
-                        if s.len() % 2 == 0 {
-                            s.to_string()
-                        } else {
-                            format!("0{}", s)
-                        }
-                    })
-                    .ok_or(RoqoqoBackendError::GenericError {
-                        msg: format!(
-                            "Cannot parse a measurement result as bit representation {}",
-                            measurement.clone()
-                        ),
-                    })?,
-            )
-            .map_err(|err| RoqoqoBackendError::GenericError {
-                msg: format!(
-                    "Cannot parse a measurement result as bit representation {:?}",
-                    err
-                ),
-            })?;
-            let qubit_results = bit_representation.view_bits::<Lsb0>();
-            let mut tmp_vec: Vec<bool> = (0..number_qubits).map(|_| false).collect();
-            // only iterating over qubits in number_qubits returns of larger qubits will be ignored
-            for (mut_val, tmp_val) in (tmp_vec.iter_mut()).zip(qubit_results.iter()) {
-                *mut_val = *tmp_val
-            }
-            for _ in 0..count {
-                measurement_record.push(tmp_vec.clone())
-            }
+        let mut capabilities =
+            resp.json::<QRydAPICapabilities>()
+                .map_err(|e| RoqoqoBackendError::NetworkError {
+                    msg: format!("Error parsing capabilities response {:?}", e),
+                })?;
+        if capabilities.api_version.is_empty() {
+            capabilities.api_version = self.api_version.clone();
         }
-        bit_map.insert(readout, measurement_record);
-        Ok((bit_map, float_map, complex_map))
+        self.capabilities = Some(capabilities.clone());
+        Ok(capabilities)
     }
 
-    /// Setter for the dev option of the APIDevice.
-    ///
-    /// # Arguments
-    ///
-    /// * `dev` - The boolean to set the dev option to.
-    ///
-    pub fn set_dev(&mut self, dev: bool) {
-        self.dev = dev;
+    /// Returns the capabilities recorded by the last call to [APIBackend::capabilities], if any.
+    pub fn recorded_capabilities(&self) -> Option<&QRydAPICapabilities> {
+        self.capabilities.as_ref()
     }
 
-    fn _check_operation_compatability(&self, op: &Operation) -> Result<(), RoqoqoBackendError> {
-        match op {
-            Operation::MeasureQubit(_) => Ok(()),
-            Operation::DefinitionBit(_) => Ok(()),
-            Operation::PhaseShiftState1(_) => Ok(()),
-            Operation::RotateXY(_) => Ok(()),
-            Operation::RotateX(_) => Ok(()),
-            Operation::RotateY(_) => Ok(()),
-            Operation::RotateZ(_) => Ok(()),
-            Operation::PhaseShiftedControlledZ(_) => Ok(()),
-            Operation::PhaseShiftedControlledPhase(_) => Ok(()),
-            Operation::Hadamard(_) => Ok(()),
-            Operation::PauliX(_) => Ok(()),
-            Operation::PauliY(_) => Ok(()),
-            Operation::PauliZ(_) => Ok(()),
-            Operation::SqrtPauliX(_) =>  Ok(()),
-            Operation::InvSqrtPauliX(_) =>  Ok(()),
-            Operation::CNOT(_) => Ok(()),
-            Operation::ControlledPauliY(_) =>  Ok(()),
-            Operation::ControlledPauliZ(_) =>  Ok(()),
-            Operation::ControlledPhaseShift(_) => Ok(()),
-            Operation::PragmaControlledCircuit(_) => Ok(()),
-            Operation::ControlledControlledPauliZ(_) => Ok(()),
-            Operation::ControlledControlledPhaseShift(_) => Ok(()),
-            Operation::SWAP(_) =>  Ok(()),
-            Operation::ISwap(_) => Ok(()),
-            Operation::PragmaSetNumberOfMeasurements(_) => Ok(()),
-            Operation::PragmaRepeatedMeasurement(_) => Ok(()),
-            Operation::PragmaActiveReset(_) => {
-                if self.device.qrydbackend() != "qiskit_emulator" {
-                    Err(RoqoqoBackendError::GenericError { msg: "The device isn't qryd_emulator, PragmaActiveReset is not supported.".to_string() })
-                } else {
-                    Ok(())
+    /// Polls a posted job until it is finished and returns its output registers.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "qryd_wait_for_result",
+            skip_all,
+            fields(job_location = %job_loc)
+        )
+    )]
+    fn _wait_for_result(&self, job_loc: String, registers: &[ReadoutRegister]) -> RegisterResult {
+        let posted = time::Instant::now();
+
+        let mut test_counter = 0;
+        let mut status = "".to_string();
+        let mut job_result = QRydJobResult::default();
+        let fifteen = time::Duration::from_millis(200);
+        while test_counter < self.timeout && status != "completed" {
+            test_counter += 1;
+            let job_status = self.get_job_status(job_loc.clone())?;
+            status.clone_from(&job_status.status);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                attempt = test_counter,
+                max_attempts = self.timeout,
+                status = %status,
+                elapsed_ms = posted.elapsed().as_millis() as u64,
+                "Polled QRyd WebAPI job status"
+            );
+            if let Some(callback) = self.progress_callback.as_ref() {
+                if let Ok(mut callback) = callback.0.lock() {
+                    (callback)(JobProgress {
+                        job_location: job_loc.clone(),
+                        status: job_status,
+                        elapsed: posted.elapsed(),
+                        attempt: test_counter,
+                        max_attempts: self.timeout,
+                    });
                 }
-            },
-            _ => Err(RoqoqoBackendError::GenericError {
-                msg: format!("Operation {} is not supported by QRydDemo Web API backend.\n
-                Use: MeasureQubit, PragmaSetNumberOfMeasurements, PragmaRepeatedMeasurement, PragmaActiveReset, PhaseShiftState1, RotateXY, RotateX, RotateY, RotateZ, RotateZ, Hadamard, PauliX, PauliY, PauliZ, SqrtPauliX, InvSqrtPauliX, PhaseShiftedControlledZ, PhaseShiftedControlledPhase, CNOT, ControlledPauliY, ControlledPauliZ, ControlledPhaseShift, PragmaControlledCircuit, ControlledControlledPauliZ, ControlledControlledPhaseShift, SWAP or ISwap instead.", op.hqslang())
+            }
+            thread::sleep(fifteen);
+            if status == *"completed" {
+                job_result = self.get_job_result(job_loc.clone())?;
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        if status != "completed" {
+            tracing::warn!(
+                attempts = test_counter,
+                status = %status,
+                elapsed_ms = posted.elapsed().as_millis() as u64,
+                "QRyd WebAPI job did not complete"
+            );
+        }
+        if status == "completed" {
+            APIBackend::counts_to_result_registers(job_result.data, registers)
+        } else if status == "error" {
+            Err(RoqoqoBackendError::GenericError {
+                msg: format!("WebAPI returned an error status for the job {}.", job_loc),
+            })
+        } else if status == "cancelled" {
+            Err(RoqoqoBackendError::GenericError {
+                msg: format!("Job {} got cancelled.", job_loc),
+            })
+        } else {
+            Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "WebAPI did not return finished result in timeout: {} * 30s",
+                    self.timeout
+                ),
             })
         }
     }
 
-    fn _check_for_api_compatability(
+    /// Sends a request to the WebAPI, recording or replaying it according to the fixture mode.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "qryd_request",
+            skip(self, send),
+            fields(status = tracing::field::Empty)
+        )
+    )]
+    fn _exchange<F>(
         &self,
-        program: &QuantumProgram,
-    ) -> Result<(), RoqoqoBackendError> {
-        let (measurement, _input_parameter_names) = match program {
-            QuantumProgram::ClassicalRegister {
-                measurement,
-                input_parameter_names,
-            } => Ok((measurement, input_parameter_names)),
-            _ => Err(RoqoqoBackendError::GenericError {
-                msg:
-                    "Only ClassicalRegister measurements are supported by the Qryd WebAPI at the moment"
-                        .to_string(),
-            }),
-        }?;
-        for op in measurement.circuits[0].iter() {
-            self._check_operation_compatability(op)?
+        method: &str,
+        path: String,
+        send: F,
+    ) -> Result<APIResponse, RoqoqoBackendError>
+    where
+        F: FnOnce() -> Result<reqwest::blocking::Response, RoqoqoBackendError>,
+    {
+        let fixtures_error = |_| RoqoqoBackendError::GenericError {
+            msg: "The APIBackend fixtures are poisoned.".to_string(),
+        };
+        if let Some(fixtures) = &self.fixtures {
+            let mut fixtures = fixtures.0.lock().map_err(fixtures_error)?;
+            if fixtures.mode == APIFixtureMode::Replay {
+                return fixtures.replay(method, &path);
+            }
+        }
+        #[cfg(feature = "tracing")]
+        let sent = time::Instant::now();
+        let resp = send();
+        #[cfg(feature = "tracing")]
+        trace_response(&resp, sent.elapsed());
+        let resp = resp?;
+        let status = resp.status();
+        let location = resp
+            .headers()
+            .get("Location")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        let body = resp.text().map_err(|e| RoqoqoBackendError::NetworkError {
+            msg: format!("Error reading server response {:?}", e),
+        })?;
+        if let Some(fixtures) = &self.fixtures {
+            fixtures
+                .0
+                .lock()
+                .map_err(fixtures_error)?
+                .record(APIFixtureExchange {
+                    method: method.to_string(),
+                    path,
+                    status: status.as_u16(),
+                    location: location.clone(),
+                    body: body.clone(),
+                })?;
         }
+        Ok(APIResponse {
+            status,
+            location,
+            body,
+        })
+    }
 
-        if let Some(constant_circuit) = &measurement.constant_circuit {
-            for op in constant_circuit.iter() {
-                self._check_operation_compatability(op)?
-            }
+    /// Returns the HTTP client of the backend, building it on the first call.
+    fn _client(&self) -> Result<Client, RoqoqoBackendError> {
+        if let Some(client) = self.client.0.get() {
+            return Ok(client.clone());
+        }
+        let config = &self.client_config;
+        let mut builder = reqwest::blocking::Client::builder();
+        if let Some(pool_max_idle_per_host) = config.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
         }
+        if let Some(pool_idle_timeout) = config.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        if let Some(tcp_keepalive) = config.tcp_keepalive {
+            builder = builder.tcp_keepalive(tcp_keepalive);
+        }
+        if let Some(request_timeout) = config.request_timeout {
+            builder = builder.timeout(request_timeout);
+        }
+        if let Some(connect_timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        let client = if self.mock_port.is_some() {
+            builder
+                .build()
+                .map_err(|x| RoqoqoBackendError::NetworkError {
+                    msg: format!("could not create test client {:?}", x),
+                })?
+        } else {
+            builder
+                .https_only(true)
+                .build()
+                .map_err(|x| RoqoqoBackendError::NetworkError {
+                    msg: format!("could not create https client {:?}", x),
+                })?
+        };
+        Ok(self.client.0.get_or_init(|| client).clone())
+    }
 
-        Ok(())
+    fn _prepare_payload(
+        &self,
+        data: &QRydRunData,
+    ) -> Result<(HeaderMap, Vec<u8>), RoqoqoBackendError> {
+        let mut value =
+            serde_json::to_value(data).map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("Could not serialize job data: {:?}", err),
+            })?;
+        // Down-convert the job by dropping optional fields the WebAPI version does not support
+        if let (Some(capabilities), Some(fields)) = (&self.capabilities, value.as_object_mut()) {
+            if !capabilities.supported_fields.is_empty() {
+                fields.retain(|key, _| {
+                    REQUIRED_RUN_DATA_FIELDS.contains(&key.as_str())
+                        || capabilities.supported_fields.contains(key)
+                });
+            }
+        }
+        let serialized =
+            serde_json::to_vec(&value).map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("Could not serialize job data: {:?}", err),
+            })?;
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        if !self.compress_payload {
+            return Ok((headers, serialized));
+        }
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&serialized)
+            .map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("Could not compress job data: {:?}", err),
+            })?;
+        let compressed = encoder
+            .finish()
+            .map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("Could not compress job data: {:?}", err),
+            })?;
+        headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+        Ok((headers, compressed))
     }
 
     /// Transforms a PragmaRepeatedMeasurement operation into a set of
@@ -1026,19 +1928,6 @@ impl APIBackend {
         );
         equivalent_circuit
     }
-
-    fn _handle_validation_error(&self, val_error: ValidationError) -> RoqoqoBackendError {
-        let types = val_error.detail;
-        match types {
-            ValidationTypes::Simple(x) => RoqoqoBackendError::GenericError { msg: format!("QuantumProgram or metadata could not be parsed by QRyd Web-API Backend. msg: {}", x) },
-            ValidationTypes::Detailed(x) => {
-                let mut msg = "QuantumProgram or metadata could not be parsed by QRyd Web-API Backend. ".to_owned();
-                msg.extend(x.iter().map(|detail| format!("[loc: {:?}, msg: {}, type: {:?}]", detail.loc, detail.msg, detail.internal_type)));
-                msg.push('.');
-                RoqoqoBackendError::GenericError { msg }
-            },
-        }
-    }
 }
 
 impl EvaluatingBackend for APIBackend {
@@ -1046,79 +1935,166 @@ impl EvaluatingBackend for APIBackend {
         &self,
         circuit: impl Iterator<Item = &'a Operation>,
     ) -> RegisterResult {
-        let new_circ: Circuit = circuit.cloned().collect();
-
-        let mut readout = "".to_string();
-        let mut number_qubits = 0;
+        let (program, registers) = circuit_to_program(circuit.cloned().collect())?;
+        let job_loc = self.post_job(program)?;
+        self._wait_for_result(job_loc, &registers)
+    }
+}
 
-        for op in new_circ.iter() {
-            if let Operation::DefinitionBit(x) = op {
-                let new_readout = x.name().clone();
-                if readout == *"" {
-                    readout = new_readout;
-                    number_qubits = *x.length();
+/// Converts a hexadecimal measurement result of the QRyd WebAPI to the measured bits of each qubit.
+///
+/// Results of qubits beyond `number_qubits` are ignored.
+fn measurement_to_bits(
+    measurement: &str,
+    number_qubits: usize,
+) -> Result<Vec<bool>, RoqoqoBackendError> {
+    let bit_representation: Vec<u8> = hex::decode(
+        measurement
+            .strip_prefix("0x")
+            .map(|s| {
+                if s.len() % 2 == 0 {
+                    s.to_string()
                 } else {
-                    return Err(RoqoqoBackendError::GenericError {
-                        msg: "QRydAPIBAckend does not support more than one readout register"
-                            .to_string(),
-                    });
+                    format!("0{}", s)
                 }
-            }
-        }
+            })
+            .ok_or(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Cannot parse a measurement result as bit representation {}",
+                    measurement
+                ),
+            })?,
+    )
+    .map_err(|err| RoqoqoBackendError::GenericError {
+        msg: format!(
+            "Cannot parse a measurement result as bit representation {:?}",
+            err
+        ),
+    })?;
+    let qubit_results = bit_representation.view_bits::<Lsb0>();
+    let mut tmp_vec: Vec<bool> = (0..number_qubits).map(|_| false).collect();
+    // only iterating over qubits in number_qubits returns of larger qubits will be ignored
+    for (mut_val, tmp_val) in (tmp_vec.iter_mut()).zip(qubit_results.iter()) {
+        *mut_val = *tmp_val
+    }
+    Ok(tmp_vec)
+}
 
-        let measurement = ClassicalRegister {
-            constant_circuit: None,
-            circuits: vec![new_circ],
-        };
-        let program = QuantumProgram::ClassicalRegister {
-            measurement,
-            input_parameter_names: vec![],
-        };
-        let job_loc = self.post_job(program)?;
+/// Readout register of a circuit and the position of its bits in the results of the QRyd WebAPI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadoutRegister {
+    /// The name of the register.
+    pub name: String,
+    /// The index of the first bit of the register in the measured bitstrings.
+    pub offset: usize,
+    /// The number of bits of the register.
+    pub length: usize,
+}
 
-        let mut test_counter = 0;
-        let mut status = "".to_string();
-        let mut job_result = QRydJobResult::default();
-        let fifteen = time::Duration::from_millis(200);
-        while test_counter < self.timeout && status != "completed" {
-            test_counter += 1;
-            let job_status = self.get_job_status(job_loc.clone()).unwrap();
-            status.clone_from(&job_status.status);
-            thread::sleep(fifteen);
-            if status == *"completed" {
-                job_result = self.get_job_result(job_loc.clone()).unwrap();
+/// Returns the readout registers of a circuit with the offsets of their bits in the WebAPI results.
+///
+/// The WebAPI returns the classical bits of all bit registers (DefinitionBit) in a single bitstring.
+/// The registers are concatenated in the order of their DefinitionBit operations in the circuit,
+/// independent of the order in which the circuit measures into them:
+/// the register defined first occupies bits `0..length`, the next one follows directly after it.
+/// Within a register, bit `i` is the readout index `i` of the register.
+///
+/// A register name that is defined twice, e.g. in the constant circuit and in a measurement circuit,
+/// does not have a unique position in the bitstring and is rejected.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit posted to the WebAPI.
+///
+/// # Returns
+///
+/// * `Ok(Vec<ReadoutRegister>)` - The bit registers of the circuit in order of definition.
+/// * `Err(RoqoqoBackendError)` - A bit register is defined more than once.
+pub fn readout_registers(circuit: &Circuit) -> Result<Vec<ReadoutRegister>, RoqoqoBackendError> {
+    let mut registers: Vec<ReadoutRegister> = Vec::new();
+    let mut offset = 0;
+    for op in circuit.iter() {
+        if let Operation::DefinitionBit(x) = op {
+            if registers.iter().any(|register| &register.name == x.name()) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Readout register {} is defined twice, the position of its bits in the results is ambiguous.",
+                        x.name()
+                    ),
+                });
             }
+            registers.push(ReadoutRegister {
+                name: x.name().clone(),
+                offset,
+                length: *x.length(),
+            });
+            offset += *x.length();
         }
+    }
+    Ok(registers)
+}
 
-        if status == "completed" {
-            APIBackend::counts_to_result(job_result.data, readout, number_qubits)
-        } else if status == "error" {
-            Err(RoqoqoBackendError::GenericError {
-                msg: format!("WebAPI returned an error status for the job {}.", job_loc),
-            })
-        } else if status == "cancelled" {
-            Err(RoqoqoBackendError::GenericError {
-                msg: format!("Job {} got cancelled.", job_loc),
-            })
-        } else {
-            Err(RoqoqoBackendError::GenericError {
-                msg: format!(
-                    "WebAPI did not return finished result in timeout: {} * 30s",
-                    self.timeout
-                ),
-            })
+/// Wraps a circuit in a QuantumProgram and returns it with its readout registers.
+fn circuit_to_program(
+    circuit: Circuit,
+) -> Result<(QuantumProgram, Vec<ReadoutRegister>), RoqoqoBackendError> {
+    let registers = readout_registers(&circuit)?;
+    let measurement = ClassicalRegister {
+        constant_circuit: None,
+        circuits: vec![circuit],
+    };
+    let program = QuantumProgram::ClassicalRegister {
+        measurement,
+        input_parameter_names: vec![],
+    };
+    Ok((program, registers))
+}
+
+/// Converts a PauliZProduct measurement into one ClassicalRegister program per circuit.
+///
+/// The constant circuit of the measurement is kept as constant circuit of every program.
+fn pauli_z_product_to_programs(measurement: &PauliZProduct) -> Vec<QuantumProgram> {
+    measurement
+        .circuits
+        .iter()
+        .map(|circuit| QuantumProgram::ClassicalRegister {
+            measurement: ClassicalRegister {
+                constant_circuit: measurement.constant_circuit.clone(),
+                circuits: vec![circuit.clone()],
+            },
+            input_parameter_names: vec![],
+        })
+        .collect()
+}
+
+/// Checks that a list of simulator seeds is not empty and does not contain duplicates.
+fn check_seeds(seeds: &[usize]) -> Result<(), RoqoqoBackendError> {
+    if seeds.is_empty() {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: "At least one simulator seed needs to be provided.".to_string(),
+        });
+    }
+    let mut unique_seeds = HashSet::new();
+    for seed in seeds {
+        if !unique_seeds.insert(seed) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("Simulator seed {} is provided more than once.", seed),
+            });
         }
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::api_devices::QrydEmuSquareDevice;
+    use crate::api_jobs::{LocTypes, ValidationErrorDetail, ValidationTypes};
     use roqoqo::operations;
     use roqoqo::{Circuit, QuantumProgram};
     use serde_json::json;
-    use wiremock::matchers::{body_json, method, path};
+    use std::io::Read;
+    use wiremock::matchers::{body_json, header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     /// Test Debug, Clone and PartialEq of ApiBackend
@@ -1194,8 +2170,9 @@ mod test {
             executed_two_qubit_gates: 0,
             // precision: "single".to_string(),
             compilation_time: 1.0,
+            dev_diagnostics: None,
         };
-        assert_eq!(format!("{:?}", result), "QRydJobResult { data: ResultCounts { counts: {} }, time_taken: 0.0, noise: \"noise\", method: \"method\", device: \"device\", num_qubits: 2, num_clbits: 2, fusion_max_qubits: 0, fusion_avg_qubits: 0.0, fusion_generated_gates: 0, executed_single_qubit_gates: 0, executed_two_qubit_gates: 0, compilation_time: 1.0 }");
+        assert_eq!(format!("{:?}", result), "QRydJobResult { data: ResultCounts { counts: {} }, time_taken: 0.0, noise: \"noise\", method: \"method\", device: \"device\", num_qubits: 2, num_clbits: 2, fusion_max_qubits: 0, fusion_avg_qubits: 0.0, fusion_generated_gates: 0, executed_single_qubit_gates: 0, executed_two_qubit_gates: 0, compilation_time: 1.0, dev_diagnostics: None }");
     }
 
     /// Test Debug of QRydJobStatus
@@ -1204,10 +2181,13 @@ mod test {
         let status = QRydJobStatus {
             status: "in progress".to_string(),
             msg: "the job is still in progress".to_string(),
+            queue_position: Some(3),
+            estimated_start_time: None,
+            progress: None,
         };
         assert_eq!(
             format!("{:?}", status),
-            "QRydJobStatus { status: \"in progress\", msg: \"the job is still in progress\" }"
+            "QRydJobStatus { status: \"in progress\", msg: \"the job is still in progress\", queue_position: Some(3), estimated_start_time: None, progress: None }"
         );
     }
 
@@ -1535,4 +2515,74 @@ mod test {
 
         server_wiremock.verify().await;
     }
+
+    // Test gzip compressed `.post_job()` payload
+    #[tokio::test]
+    async fn async_api_backend_compressed_payload() {
+        let server_wiremock = MockServer::start().await;
+        let device = QrydEmuSquareDevice::new(Some(1), None, None);
+        let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+        let mut api_backend_new = APIBackend::new(
+            qryd_device,
+            None,
+            None,
+            Some(server_wiremock.address().port().to_string()),
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(!api_backend_new.compress_payload());
+        api_backend_new.set_compress_payload(true);
+        assert!(api_backend_new.compress_payload());
+
+        let mut circuit = Circuit::new();
+        circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+        circuit += operations::RotateX::new(0, std::f64::consts::FRAC_PI_2.into());
+        circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+        circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+        let program = QuantumProgram::ClassicalRegister {
+            measurement: ClassicalRegister {
+                constant_circuit: None,
+                circuits: vec![circuit],
+            },
+            input_parameter_names: vec![],
+        };
+        let data = QRydRunData {
+            format: "qoqo".to_string(),
+            backend: device.qrydbackend(),
+            program: program.clone(),
+            dev: false,
+            fusion_max_qubits: 4,
+            extended_set_size: 5,
+            extended_set_weight: 0.5,
+            seed_simulator: Some(1),
+            seed_compiler: None,
+            use_extended_set: true,
+            use_reverse_traversal: true,
+            reverse_traversal_iterations: 3,
+        };
+        let expected = json!(data);
+
+        let _mock = Mock::given(method("POST"))
+            .and(header("content-encoding", "gzip"))
+            .and(move |request: &wiremock::Request| {
+                let mut decoded = String::new();
+                flate2::read::GzDecoder::new(request.body.as_slice())
+                    .read_to_string(&mut decoded)
+                    .is_ok()
+                    && serde_json::from_str::<serde_json::Value>(&decoded).ok()
+                        == Some(expected.clone())
+            })
+            .respond_with(ResponseTemplate::new(201).insert_header("Location", "http://test.job/0"))
+            .expect(1)
+            .mount(&server_wiremock)
+            .await;
+
+        let response = tokio::task::spawn_blocking(move || api_backend_new.post_job(program))
+            .await
+            .unwrap();
+        assert_eq!(response.unwrap(), "http://test.job/0".to_string());
+
+        server_wiremock.verify().await;
+    }
 }
@@ -0,0 +1,329 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asynchronous QRyd WebAPI backend for WebAssembly targets.
+//!
+//! The blocking [reqwest] client and `std::thread::sleep` are not available in browsers. The
+//! [AsyncAPIBackend] uses the asynchronous client, which uses the fetch API on WebAssembly
+//! targets, and waits between status requests with a timer future. Build for WebAssembly with
+//! `--no-default-features --features web-api-wasm`.
+
+use crate::api_devices::QRydAPIDevice;
+use crate::api_jobs::{validate_job_program, validation_error, QRydRunData, ValidationError};
+pub use crate::api_jobs::{
+    DevDiagnostics, QRydJobResult, QRydJobStatus, ResultCounts, SUPPORTED_OPERATIONS,
+};
+use futures_timer::Delay;
+use roqoqo::{QuantumProgram, RoqoqoBackendError};
+use std::env;
+use std::time::Duration;
+
+/// Asynchronous QRyd WebAPI backend usable in WebAssembly environments.
+///
+/// Posts ClassicalRegister quantum programs to the QRyd WebAPI and polls their status and
+/// results without blocking the executing thread.
+#[derive(Debug, Clone)]
+pub struct AsyncAPIBackend {
+    /// Device representing the model of a QRyd device.
+    pub device: QRydAPIDevice,
+    /// Access token for identification with QRyd devices.
+    access_token: String,
+    /// Number of status requests before waiting for a job is aborted.
+    timeout: usize,
+    /// The address of the Mock server, used for testing purposes.
+    mock_port: Option<String>,
+    /// Is the job run on the develop version of the WebAPI.
+    dev: bool,
+    /// The version of the QRyd WebAPI to use.
+    api_version: String,
+    /// The asynchronous HTTP client shared by all requests.
+    client: reqwest::Client,
+}
+
+impl AsyncAPIBackend {
+    /// Creates a new asynchronous QRyd WebAPI backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The QRyd device the backend uses to execute operations and circuits.
+    /// * `access_token` - An access_token is required to access QRYD hardware and emulators.
+    ///                    The access_token can either be given as an argument here
+    ///                    or set via the environmental variable `$QRYD_API_TOKEN`, which is
+    ///                    not available in browsers.
+    /// * `timeout` - Number of status requests before waiting for a job is aborted. Defaults to 30.
+    /// * `mock_port` - The address of the Mock server, used for testing purposes.
+    /// * `dev` - The boolean to set the dev header to.
+    /// * `api_version` - The version of the QRYD API to use. Defaults to "v5_2".
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AsyncAPIBackend)` - The new backend.
+    /// * `Err(RoqoqoBackendError)` - The access token is missing.
+    pub fn new(
+        device: QRydAPIDevice,
+        access_token: Option<String>,
+        timeout: Option<usize>,
+        mock_port: Option<String>,
+        dev: Option<bool>,
+        api_version: Option<String>,
+    ) -> Result<Self, RoqoqoBackendError> {
+        let access_token = if mock_port.is_some() {
+            "".to_string()
+        } else {
+            match access_token {
+                Some(access_token) => access_token,
+                None => env::var("QRYD_API_TOKEN").map_err(|_| {
+                    RoqoqoBackendError::MissingAuthentication {
+                        msg: "QRYD access token is missing".to_string(),
+                    }
+                })?,
+            }
+        };
+        Ok(Self {
+            device,
+            access_token,
+            timeout: timeout.unwrap_or(30),
+            mock_port,
+            dev: dev.unwrap_or(false),
+            api_version: api_version.unwrap_or_else(|| "v5_2".to_string()),
+            client: reqwest::Client::new(),
+        })
+    }
+
+    /// Returns the number of status requests before waiting for a job is aborted.
+    pub fn timeout(&self) -> usize {
+        self.timeout
+    }
+
+    /// Posts a ClassicalRegister QuantumProgram to the WebAPI.
+    ///
+    /// The program is checked locally with the same rules as [crate::APIBackend::post_job] before
+    /// posting, the operations are restricted to the [SUPPORTED_OPERATIONS].
+    ///
+    /// # Arguments
+    ///
+    /// * `quantumprogram` - The ClassicalRegister QuantumProgram to be executed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The location (url) of the posted job.
+    /// * `Err(RoqoqoBackendError)` - The program is not a ClassicalRegister program without
+    ///                               input parameters, contains unsupported operations, it was
+    ///                               rejected by the WebAPI or in case of a network failure.
+    pub async fn post_job(
+        &self,
+        quantumprogram: QuantumProgram,
+    ) -> Result<String, RoqoqoBackendError> {
+        match &quantumprogram {
+            QuantumProgram::ClassicalRegister {
+                input_parameter_names,
+                ..
+            } if input_parameter_names.is_empty() => (),
+            _ => {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: "The AsyncAPIBackend only supports ClassicalRegister QuantumPrograms without input parameters.".to_string(),
+                })
+            }
+        }
+        validate_job_program(&self.device, &quantumprogram, None, None)?;
+        let data = QRydRunData::new(
+            self.device.qrydbackend(),
+            quantumprogram,
+            self.dev,
+            self.device.seed(),
+        );
+        let url_string: String = if let Some(mock_port) = &self.mock_port {
+            format!("http://127.0.0.1:{}", mock_port)
+        } else {
+            format!(
+                "https://api.qryddemo.itp3.uni-stuttgart.de/{}/jobs",
+                self.api_version
+            )
+        };
+        let resp = self
+            ._request(self.client.post(url_string).json(&data))
+            .await?;
+        let status_code = resp.status();
+        if status_code == reqwest::StatusCode::CREATED {
+            resp.headers()
+                .get("Location")
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string())
+                .ok_or_else(|| RoqoqoBackendError::NetworkError {
+                    msg: "Server response missing the Location header".to_string(),
+                })
+        } else {
+            Err(Self::_status_error(resp).await)
+        }
+    }
+
+    /// Gets the status of a posted WebAPI job.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_location` - location (url) of the job one is interested in.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QRydJobStatus)` - status and message of the job.
+    /// * `Err(RoqoqoBackendError)` - in case of a network failure.
+    pub async fn get_job_status(
+        &self,
+        job_location: String,
+    ) -> Result<QRydJobStatus, RoqoqoBackendError> {
+        let resp = self
+            ._request(self.client.get(job_location + "/status"))
+            .await?;
+        if resp.status() != reqwest::StatusCode::OK {
+            return Err(Self::_status_error(resp).await);
+        }
+        resp.json::<QRydJobStatus>()
+            .await
+            .map_err(|e| RoqoqoBackendError::NetworkError {
+                msg: format!("Error parsing job status response {:?}", e),
+            })
+    }
+
+    /// Gets the result of a completed WebAPI job.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_location` - location (url) of the job one is interested in.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QRydJobResult)` - the result of the job.
+    /// * `Err(RoqoqoBackendError)` - in case of a network failure.
+    pub async fn get_job_result(
+        &self,
+        job_location: String,
+    ) -> Result<QRydJobResult, RoqoqoBackendError> {
+        let resp = self
+            ._request(self.client.get(job_location + "/result"))
+            .await?;
+        if resp.status() != reqwest::StatusCode::OK {
+            return Err(Self::_status_error(resp).await);
+        }
+        resp.json::<QRydJobResult>()
+            .await
+            .map_err(|e| RoqoqoBackendError::NetworkError {
+                msg: format!("Error parsing job result response {:?}", e),
+            })
+    }
+
+    /// Deletes a job that has been posted to the WebAPI.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_location` - location (url) of the job one is interested in.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(RoqoqoBackendError)` - in case of a network failure.
+    pub async fn delete_job(&self, job_location: String) -> Result<(), RoqoqoBackendError> {
+        let resp = self._request(self.client.delete(job_location)).await?;
+        if resp.status() != reqwest::StatusCode::OK {
+            return Err(Self::_status_error(resp).await);
+        }
+        Ok(())
+    }
+
+    /// Polls a posted job until it is completed and returns its result.
+    ///
+    /// The status is requested up to `timeout` times, waiting for `poll_interval` between the
+    /// requests without blocking the executing thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_location` - location (url) of the job one is interested in.
+    /// * `poll_interval` - The time waited between two status requests.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QRydJobResult)` - the result of the completed job.
+    /// * `Err(RoqoqoBackendError)` - The job failed, was cancelled, did not complete within the
+    ///                               timeout or in case of a network failure.
+    pub async fn wait_for_result(
+        &self,
+        job_location: String,
+        poll_interval: Duration,
+    ) -> Result<QRydJobResult, RoqoqoBackendError> {
+        for attempt in 0..self.timeout {
+            if attempt > 0 {
+                Delay::new(poll_interval).await;
+            }
+            let status = self.get_job_status(job_location.clone()).await?.status;
+            match status.as_str() {
+                "completed" => return self.get_job_result(job_location).await,
+                "error" => {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "WebAPI returned an error status for the job {}.",
+                            job_location
+                        ),
+                    })
+                }
+                "cancelled" => {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!("Job {} got cancelled.", job_location),
+                    })
+                }
+                _ => (),
+            }
+        }
+        Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "WebAPI did not return finished result in timeout: {} status requests",
+                self.timeout
+            ),
+        })
+    }
+
+    /// Adds the authentication and version headers to a request and sends it.
+    async fn _request(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, RoqoqoBackendError> {
+        let mut request = request.header("X-API-KEY", self.access_token.clone());
+        if self.dev {
+            request = request.header("X-DEV", "?1");
+        }
+        if env::var("QRYD_API_HQS").is_ok() {
+            request = request.header("X-HQS", "?1");
+        }
+        request
+            .send()
+            .await
+            .map_err(|e| RoqoqoBackendError::NetworkError {
+                msg: format!("{:?}", e),
+            })
+    }
+
+    /// Converts a response with an unexpected HTTP status code into a RoqoqoBackendError.
+    async fn _status_error(resp: reqwest::Response) -> RoqoqoBackendError {
+        let status_code = resp.status();
+        if status_code == reqwest::StatusCode::UNPROCESSABLE_ENTITY {
+            return match resp.json::<ValidationError>().await {
+                Ok(val_error) => validation_error(val_error),
+                Err(e) => RoqoqoBackendError::NetworkError {
+                    msg: format!("Error parsing ValidationError message {:?}", e),
+                },
+            };
+        }
+        RoqoqoBackendError::NetworkError {
+            msg: format!(
+                "Request to server failed with HTTP status code {:?}",
+                status_code
+            ),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Differential testing between versions of the QRyd WebAPI.
+//!
+//! Posts the same quantum program to a reference and a candidate backend, usually configured
+//! with the established `api_version` and a newer version behind the dev flag, and reports
+//! the discrepancies between the returned job statuses and results.
+
+use crate::{APIBackend, QRydJobResult};
+use roqoqo::{QuantumProgram, RoqoqoBackendError};
+use std::collections::{BTreeSet, HashMap};
+use std::{thread, time};
+
+/// Job statuses after which the WebAPI does not change the job anymore.
+const FINAL_JOB_STATUSES: [&str; 3] = ["completed", "error", "cancelled"];
+
+/// Outcome of running the same program on two versions of the QRyd WebAPI.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct APIVersionComparison {
+    /// The API version of the reference backend.
+    pub reference_version: String,
+    /// The API version of the candidate backend.
+    pub candidate_version: String,
+    /// The last job status returned by the reference backend.
+    pub reference_status: String,
+    /// The last job status returned by the candidate backend.
+    pub candidate_status: String,
+    /// The measured counts returned by the reference backend, empty if the job did not complete.
+    pub reference_counts: HashMap<String, u64>,
+    /// The measured counts returned by the candidate backend, empty if the job did not complete.
+    pub candidate_counts: HashMap<String, u64>,
+    /// The total variation distance between the measured distributions, if both jobs completed.
+    pub total_variation_distance: Option<f64>,
+    /// Human readable descriptions of the discrepancies found.
+    pub discrepancies: Vec<String>,
+}
+
+impl APIVersionComparison {
+    /// Returns true if no discrepancies have been found.
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Runs a program on a reference and a candidate backend and compares the statuses and results.
+///
+/// Both jobs are posted before either is polled. Each backend is polled until its job reaches
+/// a final status ("completed", "error" or "cancelled") or the timeout of the backend is reached.
+/// Differing statuses, result metadata (number of qubits and classical bits) and number of shots
+/// are reported as discrepancies, as is a total variation distance between the measured
+/// distributions above `tolerance`.
+///
+/// # Arguments
+///
+/// * `reference` - The backend using the established API version, e.g. "v1_1".
+/// * `candidate` - The backend using the API version to be validated, e.g. with the dev flag set.
+/// * `quantumprogram` - The program posted to both backends.
+/// * `tolerance` - The maximal total variation distance between the measured distributions
+///                 that is not reported as a discrepancy.
+///
+/// # Returns
+///
+/// * `Ok(APIVersionComparison)` - The comparison of both runs.
+/// * `Err(RoqoqoBackendError)` - The tolerance is negative or posting or polling a job failed.
+pub fn compare_api_versions(
+    reference: &APIBackend,
+    candidate: &APIBackend,
+    quantumprogram: QuantumProgram,
+    tolerance: f64,
+) -> Result<APIVersionComparison, RoqoqoBackendError> {
+    if tolerance.is_nan() || tolerance < 0.0 {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!("The tolerance needs to be non-negative, got {}.", tolerance),
+        });
+    }
+    let reference_location = reference.post_job(quantumprogram.clone())?;
+    let candidate_location = candidate.post_job(quantumprogram)?;
+    let (reference_status, reference_result) = wait_for_job(reference, reference_location)?;
+    let (candidate_status, candidate_result) = wait_for_job(candidate, candidate_location)?;
+
+    let mut comparison = APIVersionComparison {
+        reference_version: reference.api_version().to_string(),
+        candidate_version: candidate.api_version().to_string(),
+        reference_status,
+        candidate_status,
+        ..Default::default()
+    };
+    if comparison.reference_status != comparison.candidate_status {
+        comparison.discrepancies.push(format!(
+            "Job status differs: {} returned '{}', {} returned '{}'.",
+            comparison.reference_version,
+            comparison.reference_status,
+            comparison.candidate_version,
+            comparison.candidate_status
+        ));
+    }
+    if let (Some(reference_result), Some(candidate_result)) = (reference_result, candidate_result) {
+        compare_results(
+            &mut comparison,
+            reference_result,
+            candidate_result,
+            tolerance,
+        );
+    }
+    Ok(comparison)
+}
+
+/// Polls a job until it reaches a final status or the timeout of the backend.
+///
+/// Returns the last status and the result of the job if it completed.
+fn wait_for_job(
+    backend: &APIBackend,
+    job_location: String,
+) -> Result<(String, Option<QRydJobResult>), RoqoqoBackendError> {
+    let poll_interval = time::Duration::from_millis(200);
+    let mut status = "".to_string();
+    for attempt in 0..backend.timeout() {
+        if attempt > 0 {
+            thread::sleep(poll_interval);
+        }
+        status = backend.get_job_status(job_location.clone())?.status;
+        if FINAL_JOB_STATUSES.contains(&status.as_str()) {
+            break;
+        }
+    }
+    let result = if status == "completed" {
+        Some(backend.get_job_result(job_location)?)
+    } else {
+        None
+    };
+    Ok((status, result))
+}
+
+/// Compares the results of two completed jobs and records the discrepancies.
+fn compare_results(
+    comparison: &mut APIVersionComparison,
+    reference_result: QRydJobResult,
+    candidate_result: QRydJobResult,
+    tolerance: f64,
+) {
+    let (reference_version, candidate_version) = (
+        comparison.reference_version.clone(),
+        comparison.candidate_version.clone(),
+    );
+    let mut report = |name: &str, reference_value: String, candidate_value: String| {
+        if reference_value != candidate_value {
+            comparison.discrepancies.push(format!(
+                "{} differs: {} returned {}, {} returned {}.",
+                name, reference_version, reference_value, candidate_version, candidate_value
+            ));
+        }
+    };
+    report(
+        "Number of qubits",
+        reference_result.num_qubits.to_string(),
+        candidate_result.num_qubits.to_string(),
+    );
+    report(
+        "Number of classical bits",
+        reference_result.num_clbits.to_string(),
+        candidate_result.num_clbits.to_string(),
+    );
+    let reference_shots: u64 = reference_result.data.counts.values().sum();
+    let candidate_shots: u64 = candidate_result.data.counts.values().sum();
+    report(
+        "Number of shots",
+        reference_shots.to_string(),
+        candidate_shots.to_string(),
+    );
+
+    let distance =
+        total_variation_distance(&reference_result.data.counts, &candidate_result.data.counts);
+    if distance > tolerance {
+        comparison.discrepancies.push(format!(
+            "Measured distributions differ: total variation distance {} exceeds tolerance {}.",
+            distance, tolerance
+        ));
+    }
+    comparison.total_variation_distance = Some(distance);
+    comparison.reference_counts = reference_result.data.counts;
+    comparison.candidate_counts = candidate_result.data.counts;
+}
+
+/// Returns the total variation distance between the distributions of two sets of counts.
+///
+/// Bitstrings are compared by value, so differently padded hexadecimal strings match.
+fn total_variation_distance(
+    reference: &HashMap<String, u64>,
+    candidate: &HashMap<String, u64>,
+) -> f64 {
+    let normalize = |counts: &HashMap<String, u64>| -> HashMap<String, f64> {
+        let total: u64 = counts.values().sum();
+        let mut probabilities: HashMap<String, f64> = HashMap::new();
+        if total == 0 {
+            return probabilities;
+        }
+        for (bitstring, count) in counts {
+            *probabilities
+                .entry(normalize_bitstring(bitstring))
+                .or_insert(0.0) += *count as f64 / total as f64;
+        }
+        probabilities
+    };
+    let reference = normalize(reference);
+    let candidate = normalize(candidate);
+    let bitstrings: BTreeSet<&String> = reference.keys().chain(candidate.keys()).collect();
+    bitstrings
+        .into_iter()
+        .map(|bitstring| {
+            (reference.get(bitstring).unwrap_or(&0.0) - candidate.get(bitstring).unwrap_or(&0.0))
+                .abs()
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Removes the leading zeros of a hexadecimal bitstring.
+fn normalize_bitstring(bitstring: &str) -> String {
+    let digits = bitstring
+        .strip_prefix("0x")
+        .unwrap_or(bitstring)
+        .trim_start_matches('0')
+        .to_lowercase();
+    if digits.is_empty() {
+        "0x0".to_string()
+    } else {
+        format!("0x{}", digits)
+    }
+}
@@ -0,0 +1,472 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Data exchanged with the QRyd WebAPI when posting and polling jobs.
+//!
+//! Shared by the blocking [crate::APIBackend] and the asynchronous backend of the
+//! `web-api-wasm` feature.
+
+use crate::api_devices::QRydAPIDevice;
+use roqoqo::operations::{Operate, Operation};
+use roqoqo::{QuantumProgram, RoqoqoBackendError};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+
+/// Operations accepted by `post_job` unless the whitelist of the backend has been changed.
+///
+/// PragmaActiveReset is only accepted by the "qiskit_emulator" backend.
+pub static SUPPORTED_OPERATIONS: [&str; 27] = [
+    "MeasureQubit",
+    "DefinitionBit",
+    "PhaseShiftState1",
+    "RotateXY",
+    "RotateX",
+    "RotateY",
+    "RotateZ",
+    "PhaseShiftedControlledZ",
+    "PhaseShiftedControlledPhase",
+    "Hadamard",
+    "PauliX",
+    "PauliY",
+    "PauliZ",
+    "SqrtPauliX",
+    "InvSqrtPauliX",
+    "CNOT",
+    "ControlledPauliY",
+    "ControlledPauliZ",
+    "ControlledPhaseShift",
+    "PragmaControlledCircuit",
+    "ControlledControlledPauliZ",
+    "ControlledControlledPhaseShift",
+    "SWAP",
+    "ISwap",
+    "PragmaSetNumberOfMeasurements",
+    "PragmaRepeatedMeasurement",
+    "PragmaActiveReset",
+];
+
+/// Local struct representing the body of the request message
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
+pub(crate) struct QRydRunData {
+    /// Format of the quantum program: qoqo
+    pub(crate) format: String,
+    /// The QRyd WebAPI Backend used to execute operations and circuits.
+    /// At the moment limited to the string ('qryd_emulator')
+    pub(crate) backend: String,
+    /// Is develop version default: false
+    pub(crate) dev: bool,
+    /// Qubits that are fused in simulator default none
+    pub(crate) fusion_max_qubits: usize,
+    /// Random seed for the simulator default none
+    pub(crate) seed_simulator: Option<usize>,
+    /// Random seed for the compiler default none
+    pub(crate) seed_compiler: Option<usize>,
+    /// Use the extended set in SABRE routing
+    /// default true
+    pub(crate) use_extended_set: bool,
+    /// Use back-and-forth SABRE runs to optimize initial qubit mapping
+    /// default true
+    pub(crate) use_reverse_traversal: bool,
+    /// Number of back-and-forth iterations used
+    pub(crate) reverse_traversal_iterations: usize,
+    /// Size of the extended set if used, default 5
+    pub(crate) extended_set_size: usize,
+    /// Weight given to the extended set, default 0.5
+    pub(crate) extended_set_weight: f64,
+    /// Roqoqo QuantumProgram to be executed.
+    pub(crate) program: QuantumProgram,
+}
+
+/// Local struct representing the body of a validation error message
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ValidationError {
+    pub(crate) detail: ValidationTypes,
+    pub(crate) body: Option<QRydRunData>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub(crate) enum LocTypes {
+    LocStr(String),
+    LocInt(i32),
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub(crate) enum ValidationTypes {
+    Simple(String),
+    Detailed(Vec<ValidationErrorDetail>),
+}
+
+/// Local struct representing the body of a validation error message
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ValidationErrorDetail {
+    #[serde(default)]
+    pub(crate) loc: Vec<LocTypes>,
+    #[serde(default)]
+    pub(crate) msg: String,
+    #[serde(alias = "type")]
+    #[serde(default)]
+    pub(crate) internal_type: String,
+}
+
+impl QRydRunData {
+    /// Creates the body of a job request with the default compiler and simulator settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend` - The QRyd WebAPI backend executing the program.
+    /// * `program` - The QuantumProgram to be executed.
+    /// * `dev` - Whether the job is run on the develop version of the WebAPI.
+    /// * `seed_simulator` - The random seed of the simulator.
+    pub(crate) fn new(
+        backend: String,
+        program: QuantumProgram,
+        dev: bool,
+        seed_simulator: Option<usize>,
+    ) -> Self {
+        QRydRunData {
+            format: "qoqo".to_string(),
+            backend,
+            program,
+            dev,
+            fusion_max_qubits: 4,
+            seed_simulator,
+            seed_compiler: None,
+            use_extended_set: true,
+            use_reverse_traversal: true,
+            extended_set_size: 5,
+            extended_set_weight: 0.5,
+            reverse_traversal_iterations: 3,
+        }
+    }
+}
+
+/// Converts a validation error returned by the WebAPI into a RoqoqoBackendError.
+pub(crate) fn validation_error(val_error: ValidationError) -> RoqoqoBackendError {
+    match val_error.detail {
+        ValidationTypes::Simple(x) => RoqoqoBackendError::GenericError {
+            msg: format!(
+                "QuantumProgram or metadata could not be parsed by QRyd Web-API Backend. msg: {}",
+                x
+            ),
+        },
+        ValidationTypes::Detailed(x) => {
+            let mut msg =
+                "QuantumProgram or metadata could not be parsed by QRyd Web-API Backend. "
+                    .to_owned();
+            msg.extend(x.iter().map(|detail| {
+                format!(
+                    "[loc: {:?}, msg: {}, type: {:?}]",
+                    detail.loc, detail.msg, detail.internal_type
+                )
+            }));
+            msg.push('.');
+            RoqoqoBackendError::GenericError { msg }
+        }
+    }
+}
+
+/// Struct to represent the capabilities of a QRyd WebAPI version.
+///
+/// Empty lists are interpreted as "no restriction reported by the API".
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct QRydAPICapabilities {
+    /// The version of the WebAPI the capabilities refer to
+    #[serde(default)]
+    pub api_version: String,
+    /// The hqslang names of the operations accepted by the WebAPI
+    #[serde(default)]
+    pub supported_operations: Vec<String>,
+    /// The fields of the job submission understood by the WebAPI
+    #[serde(default)]
+    pub supported_fields: Vec<String>,
+}
+
+/// Changes of a backend to the operations accepted by the QRyd WebAPI.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OperationWhitelist<'a> {
+    /// Whether the additional operations are accepted (develop version of the WebAPI).
+    pub(crate) dev: bool,
+    /// Operations accepted in addition to the [SUPPORTED_OPERATIONS].
+    pub(crate) additional_operations: &'a BTreeSet<String>,
+    /// Operations removed from the accepted operations.
+    pub(crate) excluded_operations: &'a BTreeSet<String>,
+}
+
+/// Checks a QuantumProgram before it is posted to the QRyd WebAPI.
+///
+/// Does not communicate with the WebAPI, the checks are shared by the blocking and the
+/// asynchronous backend. In order, the program has to be a ClassicalRegister measurement with
+/// a single circuit without symbolic parameters, every operation has to be accepted by the
+/// backend and by the capabilities of the WebAPI version and the circuits have to fit on a
+/// TweezerDevice.
+///
+/// # Arguments
+///
+/// * `device` - The device the program is posted to.
+/// * `program` - The QuantumProgram to be posted.
+/// * `whitelist` - The changes of the backend to the accepted operations, if any.
+/// * `capabilities` - The capabilities reported by the WebAPI, if known.
+///
+/// # Returns
+///
+/// * `Ok(())` - The program can be posted.
+/// * `Err(RoqoqoBackendError)` - The program can not be executed by the QRyd WebAPI.
+pub(crate) fn validate_job_program(
+    device: &QRydAPIDevice,
+    program: &QuantumProgram,
+    whitelist: Option<OperationWhitelist>,
+    capabilities: Option<&QRydAPICapabilities>,
+) -> Result<(), RoqoqoBackendError> {
+    let measurement = match program {
+        QuantumProgram::ClassicalRegister { measurement, .. } => measurement,
+        QuantumProgram::Cheated { .. } | QuantumProgram::CheatedPauliZProduct { .. } => {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "Cheated measurements read out the simulated state and can not be executed by the QRyd WebAPI, use a PauliZProduct measurement instead"
+                    .to_string(),
+            })
+        }
+        _ => {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "QRyd API Backend only supports posting ClassicalRegister and PauliZProduct QuantumPrograms"
+                    .to_string(),
+            })
+        }
+    };
+    if measurement.circuits.len() != 1 {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: "QRyd API Backend only supports posting ClassicalRegister with one circuit"
+                .to_string(),
+        });
+    }
+    if measurement.circuits[0].is_parametrized() {
+        return Err(RoqoqoBackendError::GenericError { msg: "Qoqo circuit contains symbolic parameters. The QrydWebAPI does not support symbolic parameters.".to_string() });
+    }
+
+    for op in measurement.circuits[0]
+        .iter()
+        .chain(measurement.constant_circuit.iter().flat_map(|c| c.iter()))
+    {
+        check_operation_compatability(device, op, whitelist)?;
+    }
+
+    if let Some(capabilities) =
+        capabilities.filter(|capabilities| !capabilities.supported_operations.is_empty())
+    {
+        for op in measurement
+            .circuits
+            .iter()
+            .chain(measurement.constant_circuit.iter())
+            .flat_map(|circuit| circuit.iter())
+        {
+            // PragmaRepeatedMeasurement is always down-converted before posting
+            if op.hqslang() == "PragmaRepeatedMeasurement" {
+                continue;
+            }
+            if !capabilities
+                .supported_operations
+                .iter()
+                .any(|name| name == op.hqslang())
+            {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Operation {} is not supported by QRydDemo Web API version {}.",
+                        op.hqslang(),
+                        capabilities.api_version
+                    ),
+                });
+            }
+        }
+    }
+
+    if let QRydAPIDevice::TweezerDevice(device) = device {
+        for circuit in measurement
+            .constant_circuit
+            .iter()
+            .chain(measurement.circuits.iter())
+        {
+            device.check_qubit_capacity(circuit)?;
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a single operation is accepted by the QRyd WebAPI backend.
+///
+/// Whether an operation is accepted is decided by its membership in [SUPPORTED_OPERATIONS], the
+/// PragmaActiveReset is in addition only accepted by the "qiskit_emulator" backend.
+fn check_operation_compatability(
+    device: &QRydAPIDevice,
+    op: &Operation,
+    whitelist: Option<OperationWhitelist>,
+) -> Result<(), RoqoqoBackendError> {
+    if let Some(whitelist) = whitelist {
+        if whitelist.excluded_operations.contains(op.hqslang()) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Operation {} has been removed from the accepted operations of this APIBackend.",
+                    op.hqslang()
+                ),
+            });
+        }
+        if whitelist.dev && whitelist.additional_operations.contains(op.hqslang()) {
+            return Ok(());
+        }
+    }
+    if !SUPPORTED_OPERATIONS.contains(&op.hqslang()) {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Operation {} is not supported by QRydDemo Web API backend.\nUse: {} instead.",
+                op.hqslang(),
+                SUPPORTED_OPERATIONS.join(", ")
+            ),
+        });
+    }
+    match op {
+        Operation::PragmaActiveReset(_) if device.qrydbackend() != "qiskit_emulator" => {
+            Err(RoqoqoBackendError::GenericError {
+                msg: "The device isn't qryd_emulator, PragmaActiveReset is not supported."
+                    .to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Struct to represent QRyd response when calling for the Job status.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct QRydJobStatus {
+    /// status of the job, e.g. "pending"
+    #[serde(default)] // for optional fields
+    pub status: String,
+    /// message, if any
+    #[serde(default)]
+    pub msg: String,
+    /// position of the job in the queue, if provided by the API
+    #[serde(default)]
+    pub queue_position: Option<usize>,
+    /// estimated start time of the job, if provided by the API
+    #[serde(default)]
+    pub estimated_start_time: Option<String>,
+    /// progress of the running job between 0 and 1, if provided by the API
+    #[serde(default)]
+    pub progress: Option<f64>,
+}
+
+/// Struct to represent QRyd response on the result for the posted Job.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+pub struct QRydJobResult {
+    /// The actual measured data
+    #[serde(default)]
+    pub data: ResultCounts,
+    /// Time taken to run and return the result
+    #[serde(default)]
+    pub time_taken: f64,
+    #[serde(default)]
+    /// The noise that was used in the run
+    pub noise: String,
+    #[serde(default)]
+    /// The method that was used for the run
+    pub method: String,
+    #[serde(default)]
+    /// The device that was used for the run
+    pub device: String,
+    // #[serde(default)]
+    // /// The used precision
+    // pub precision: String,
+    #[serde(default)]
+    /// The number of qubits that were used in the run
+    pub num_qubits: u32,
+    /// Number of classical bits
+    #[serde(default)]
+    pub num_clbits: u32,
+    #[serde(default)]
+    /// Max qubits
+    pub fusion_max_qubits: u32,
+    #[serde(default)]
+    /// Average qubits
+    pub fusion_avg_qubits: f64,
+    #[serde(default)]
+    /// Number of gates generated by gate fusion
+    pub fusion_generated_gates: u32,
+    #[serde(default)]
+    /// Number of single qubit gates actually executed in the circuit
+    pub executed_single_qubit_gates: u32,
+    #[serde(default)]
+    /// Number of two qubit gates actually executed in the circuit
+    pub executed_two_qubit_gates: u32,
+    /// The time taken to compile the quantum program on the WebAPI
+    #[serde(default)]
+    pub compilation_time: f64,
+    /// The diagnostic fields the WebAPI returns for jobs run with `dev=true`.
+    ///
+    /// Collects all fields not listed above, `None` if the response contains no further fields.
+    #[serde(flatten, deserialize_with = "deserialize_dev_diagnostics")]
+    pub dev_diagnostics: Option<DevDiagnostics>,
+}
+
+/// Diagnostic fields of the result of a job run on the develop version of the WebAPI.
+///
+/// The fields are not part of the stable result format and change with the develop version,
+/// they are kept as untyped JSON values under the names returned by the WebAPI.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(transparent)]
+pub struct DevDiagnostics {
+    /// The diagnostic fields by name.
+    pub fields: HashMap<String, Value>,
+}
+
+impl DevDiagnostics {
+    /// Returns the value of a diagnostic field, if it is present.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field in the response of the WebAPI.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.fields.get(name)
+    }
+
+    /// Returns the names of the diagnostic fields, sorted alphabetically.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.fields.keys().map(|name| name.as_str()).collect();
+        names.sort();
+        names
+    }
+}
+
+/// Collects the fields of a job result not known to [QRydJobResult], `None` if there are none.
+fn deserialize_dev_diagnostics<'de, D>(deserializer: D) -> Result<Option<DevDiagnostics>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let fields = <HashMap<String, Value> as serde::Deserialize>::deserialize(deserializer)?;
+    Ok((!fields.is_empty()).then_some(DevDiagnostics { fields }))
+}
+
+/// Represents the counts of measurements returned by QRyd API
+///
+/// Format corresponds to qiskit count format e.g.
+///
+/// ```python
+/// counts = {'0x1': 100.0, '0x4': 20.0}
+/// ```
+///
+/// where out of a total of 120 measurements 100 times
+/// qubit 0 was measured in state |1> while the same measurement gave |0> for
+/// qubits 1 and 2 and 20 times qubit 2 was measured in state |1>
+/// with qubits 1 and 0 in state |0>
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+pub struct ResultCounts {
+    /// The dictionary of counts for each measured string
+    pub counts: HashMap<String, u64>,
+}
@@ -0,0 +1,171 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Circuits bound to the snapshot of the device they have been validated against.
+//!
+//! A [BoundCircuit] can only be created by validating a circuit against a [TweezerDevice]. It
+//! records a hash of the device together with its qubit to tweezer mapping and current layout.
+//! The backends only run a bound circuit on a device with the same hash, so a circuit is never
+//! executed on a device that changed after the validation, e.g. by a new calibration.
+
+use crate::resource_estimation::{gate_qubits, native_gate_time};
+use crate::TweezerDevice;
+use roqoqo::devices::Device;
+use roqoqo::operations::{InvolveQubits, InvolvedQubits, Operate, Operation};
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::collections::HashMap;
+
+/// A circuit validated against a snapshot of a [TweezerDevice].
+///
+/// The fields can not be changed after the validation, a changed circuit needs to be bound again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundCircuit {
+    circuit: Circuit,
+    device_hash: u64,
+    mapping: Option<HashMap<usize, usize>>,
+    layout: Option<String>,
+}
+
+impl BoundCircuit {
+    /// Validates a circuit against a device and binds it to the device.
+    ///
+    /// Every gate of the circuit needs to be provided by the device on the qubits it acts on,
+    /// and all qubits need to be part of the device. Device-changing pragmas are applied to a
+    /// copy of the device, the following gates are validated against the changed device.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit` - The circuit to bind.
+    /// * `device` - The device the circuit is executed on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BoundCircuit)` - The circuit bound to the device.
+    /// * `Err(RoqoqoBackendError)` - The circuit is not supported by the device.
+    pub fn bind(circuit: Circuit, device: &TweezerDevice) -> Result<Self, RoqoqoBackendError> {
+        let mut changed_device = device.clone();
+        for operation in circuit.iter() {
+            let number_qubits = changed_device.number_qubits();
+            if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+                if let Some(qubit) = qubits.iter().find(|qubit| **qubit >= number_qubits) {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Operation {} acts on qubit {}, the device has {} qubits.",
+                            operation.hqslang(),
+                            qubit,
+                            number_qubits
+                        ),
+                    });
+                }
+            }
+            if let Operation::PragmaChangeDevice(pragma) = operation {
+                changed_device.change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)?;
+                continue;
+            }
+            if !operation.tags().contains(&"GateOperation") {
+                continue;
+            }
+            let qubits =
+                gate_qubits(operation).ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Gate {} can not be validated against the device.",
+                        operation.hqslang()
+                    ),
+                })?;
+            if native_gate_time(&changed_device, operation.hqslang(), &qubits).is_none() {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Gate {} on qubits {:?} is not supported by the device.",
+                        operation.hqslang(),
+                        qubits
+                    ),
+                });
+            }
+        }
+        Ok(BoundCircuit {
+            circuit,
+            device_hash: device_hash(device)?,
+            mapping: device.qubit_to_tweezer.clone(),
+            layout: device.current_layout.clone(),
+        })
+    }
+
+    /// Returns the bound circuit.
+    pub fn circuit(&self) -> &Circuit {
+        &self.circuit
+    }
+
+    /// Returns the hash of the device the circuit has been validated against.
+    pub fn device_hash(&self) -> u64 {
+        self.device_hash
+    }
+
+    /// Returns the qubit to tweezer mapping of the device the circuit has been validated against.
+    pub fn mapping(&self) -> Option<&HashMap<usize, usize>> {
+        self.mapping.as_ref()
+    }
+
+    /// Returns the current layout of the device the circuit has been validated against.
+    pub fn layout(&self) -> Option<&str> {
+        self.layout.as_deref()
+    }
+
+    /// Checks that a device is the snapshot the circuit has been validated against.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The device the circuit is about to be executed on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The device has the hash recorded when binding the circuit.
+    /// * `Err(RoqoqoBackendError)` - The device differs from the one the circuit is bound to.
+    pub fn verify(&self, device: &TweezerDevice) -> Result<(), RoqoqoBackendError> {
+        let hash = device_hash(device)?;
+        if hash != self.device_hash {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "The circuit is bound to device {:016x}, the backend device is {:016x}. The circuit needs to be bound to the current device.",
+                    self.device_hash, hash
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Returns a hash of a TweezerDevice, stable across processes.
+///
+/// The hash is computed from the JSON serialization of the device with sorted keys, so devices
+/// comparing equal have the same hash. The gate time lookup caches are not part of the hash.
+///
+/// # Arguments
+///
+/// * `device` - The device to hash.
+///
+/// # Returns
+///
+/// * `Ok(u64)` - The FNV-1a hash of the device.
+/// * `Err(RoqoqoBackendError)` - The device could not be serialized.
+pub fn device_hash(device: &TweezerDevice) -> Result<u64, RoqoqoBackendError> {
+    // serde_json::Value stores objects in sorted maps, giving a canonical serialization
+    let canonical = serde_json::to_value(device)
+        .map_err(|err| RoqoqoBackendError::GenericError {
+            msg: format!("Could not serialize the TweezerDevice: {}", err),
+        })?
+        .to_string();
+    Ok(canonical
+        .bytes()
+        .fold(0xcbf29ce484222325, |hash: u64, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        }))
+}
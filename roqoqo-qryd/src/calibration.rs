@@ -0,0 +1,461 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Import of calibration files into a [crate::TweezerDevice].
+//!
+//! A calibration file is a JSON or YAML document describing the Layouts of a device.
+//! Files with the extension `.yaml` or `.yml` are read as YAML, which requires the `yaml`
+//! feature, all other files as JSON.
+//! All fields except `layouts` are optional:
+//!
+//! ```json
+//! {
+//!     "controlled_z_phase_relation": "DefaultRelation",
+//!     "controlled_phase_phase_relation": "DefaultRelation",
+//!     "default_layout": "default",
+//!     "layouts": {
+//!         "default": {
+//!             "single_qubit_gates": {
+//!                 "RotateX": [{"tweezer": 0, "time": 1e-6, "fidelity": 0.999}]
+//!             },
+//!             "two_qubit_gates": {
+//!                 "PhaseShiftedControlledZ": [{"tweezers": [0, 1], "time": 2e-6, "fidelity": 0.99}]
+//!             },
+//!             "three_qubit_gates": {
+//!                 "ControlledControlledPauliZ": [{"tweezers": [0, 1, 2], "time": 4e-6}]
+//!             },
+//!             "shifts": [{"tweezer": 0, "shifts": [[1, 2], [3]]}],
+//!             "coordinates": [{"tweezer": 0, "x": 0.0, "y": 0.0}],
+//!             "tweezers_per_row": [2, 2]
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The gates are given by their hqslang name, the `fidelity` of a gate is optional and stored as
+//! the gate error rate `1 - fidelity`. Shifts can only be given for tweezers with a gate time.
+//! The `default_layout` defaults to the first Layout in alphabetical order.
+//! Errors name the offending field, e.g. `layouts.default.two_qubit_gates.CNOT[0].time`.
+
+use crate::TweezerDevice;
+use roqoqo::RoqoqoBackendError;
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// Reads a calibration file and creates the device it describes.
+///
+/// # Arguments
+///
+/// * `path` - The path of the calibration file.
+///
+/// # Returns
+///
+/// * `Ok(TweezerDevice)` - The calibrated device.
+/// * `Err(RoqoqoBackendError)` - The file could not be read or parsed or a field is invalid.
+pub(crate) fn device_from_calibration_file(
+    path: &Path,
+) -> Result<TweezerDevice, RoqoqoBackendError> {
+    let content =
+        std::fs::read_to_string(path).map_err(|err| RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Could not read the calibration file {}: {}",
+                path.display(),
+                err
+            ),
+        })?;
+    let is_yaml = matches!(
+        path.extension().and_then(|extension| extension.to_str()),
+        Some("yaml") | Some("yml")
+    );
+    let calibration: Value = if is_yaml {
+        parse_yaml_calibration(&content)?
+    } else {
+        serde_json::from_str(&content).map_err(|err| RoqoqoBackendError::GenericError {
+            msg: format!("Could not parse the calibration file as JSON: {}", err),
+        })?
+    };
+    device_from_calibration(&calibration)
+}
+
+/// Parses the content of a YAML calibration file.
+#[cfg(feature = "yaml")]
+fn parse_yaml_calibration(content: &str) -> Result<Value, RoqoqoBackendError> {
+    serde_yaml::from_str(content).map_err(|err| RoqoqoBackendError::GenericError {
+        msg: format!("Could not parse the calibration file as YAML: {}", err),
+    })
+}
+
+/// YAML calibration files are only read with the `yaml` feature.
+#[cfg(not(feature = "yaml"))]
+fn parse_yaml_calibration(_content: &str) -> Result<Value, RoqoqoBackendError> {
+    Err(crate::device_files::yaml_feature_error())
+}
+
+/// Creates the device described by a parsed calibration file.
+pub(crate) fn device_from_calibration(
+    calibration: &Value,
+) -> Result<TweezerDevice, RoqoqoBackendError> {
+    let fields = object(calibration, "")?;
+    check_fields(
+        fields,
+        &[
+            "controlled_z_phase_relation",
+            "controlled_phase_phase_relation",
+            "default_layout",
+            "layouts",
+        ],
+        "",
+    )?;
+    let relation = |name: &str| -> Result<Option<String>, RoqoqoBackendError> {
+        fields
+            .get(name)
+            .map(|value| string(value, name).map(str::to_string))
+            .transpose()
+    };
+    let mut device = TweezerDevice::new(
+        None,
+        relation("controlled_z_phase_relation")?,
+        relation("controlled_phase_phase_relation")?,
+    );
+
+    let layouts = object(required(fields, "layouts", "")?, "layouts")?;
+    if layouts.is_empty() {
+        return Err(field_error("layouts", "expected at least one Layout"));
+    }
+    let mut names: Vec<&String> = layouts.keys().collect();
+    names.sort();
+    for name in names.iter() {
+        device.add_layout(name)?;
+        add_layout_calibration(
+            &mut device,
+            name,
+            &layouts[*name],
+            &format!("layouts.{}", name),
+        )?;
+    }
+
+    let default_layout = match fields.get("default_layout") {
+        Some(value) => string(value, "default_layout")?,
+        None => names[0].as_str(),
+    };
+    if !layouts.contains_key(default_layout) {
+        return Err(field_error(
+            "default_layout",
+            &format!("Layout {} is not described in the file", default_layout),
+        ));
+    }
+    device.set_default_layout(default_layout)?;
+    Ok(device)
+}
+
+/// Applies the calibration of one Layout to the device.
+fn add_layout_calibration(
+    device: &mut TweezerDevice,
+    name: &str,
+    layout: &Value,
+    path: &str,
+) -> Result<(), RoqoqoBackendError> {
+    let fields = object(layout, path)?;
+    check_fields(
+        fields,
+        &[
+            "single_qubit_gates",
+            "two_qubit_gates",
+            "three_qubit_gates",
+            "shifts",
+            "coordinates",
+            "tweezers_per_row",
+        ],
+        path,
+    )?;
+    let layout_name = || Some(name.to_string());
+
+    for (number_tweezers, gates_field) in [
+        (1, "single_qubit_gates"),
+        (2, "two_qubit_gates"),
+        (3, "three_qubit_gates"),
+    ] {
+        let gates = match fields.get(gates_field) {
+            Some(gates) => gates,
+            None => continue,
+        };
+        let gates_path = format!("{}.{}", path, gates_field);
+        for (hqslang, entries) in object(gates, &gates_path)? {
+            let gate_path = format!("{}.{}", gates_path, hqslang);
+            for (index, entry) in array(entries, &gate_path)?.iter().enumerate() {
+                let entry_path = format!("{}[{}]", gate_path, index);
+                let entry_fields = object(entry, &entry_path)?;
+                let tweezers: Vec<usize> = if number_tweezers == 1 {
+                    check_fields(entry_fields, &["tweezer", "time", "fidelity"], &entry_path)?;
+                    vec![index_field(entry_fields, "tweezer", &entry_path)?]
+                } else {
+                    check_fields(entry_fields, &["tweezers", "time", "fidelity"], &entry_path)?;
+                    let tweezers_path = format!("{}.tweezers", entry_path);
+                    let tweezers = index_list(
+                        required(entry_fields, "tweezers", &entry_path)?,
+                        &tweezers_path,
+                    )?;
+                    if tweezers.len() != number_tweezers {
+                        return Err(field_error(
+                            &tweezers_path,
+                            &format!("expected {} tweezers", number_tweezers),
+                        ));
+                    }
+                    tweezers
+                };
+                let time_path = format!("{}.time", entry_path);
+                let time = number(required(entry_fields, "time", &entry_path)?, &time_path)?;
+                if time.is_nan() || time < 0.0 {
+                    return Err(field_error(&time_path, "expected a non-negative gate time"));
+                }
+                let error = match entry_fields.get("fidelity") {
+                    Some(fidelity) => {
+                        let fidelity_path = format!("{}.fidelity", entry_path);
+                        let fidelity = number(fidelity, &fidelity_path)?;
+                        if fidelity.is_nan() || !(0.0..=1.0).contains(&fidelity) {
+                            return Err(field_error(
+                                &fidelity_path,
+                                "expected a fidelity between 0 and 1",
+                            ));
+                        }
+                        Some(1.0 - fidelity)
+                    }
+                    None => None,
+                };
+                let in_field = |err: RoqoqoBackendError| prefix_error(&entry_path, err);
+                match tweezers[..] {
+                    [tweezer] => {
+                        device
+                            .set_tweezer_single_qubit_gate_time(
+                                hqslang,
+                                tweezer,
+                                time,
+                                layout_name(),
+                            )
+                            .map_err(in_field)?;
+                        if let Some(error) = error {
+                            device
+                                .set_tweezer_single_qubit_gate_error(
+                                    hqslang,
+                                    tweezer,
+                                    error,
+                                    layout_name(),
+                                )
+                                .map_err(in_field)?;
+                        }
+                    }
+                    [tweezer0, tweezer1] => {
+                        device
+                            .set_tweezer_two_qubit_gate_time(
+                                hqslang,
+                                tweezer0,
+                                tweezer1,
+                                time,
+                                layout_name(),
+                            )
+                            .map_err(in_field)?;
+                        if let Some(error) = error {
+                            device
+                                .set_tweezer_two_qubit_gate_error(
+                                    hqslang,
+                                    tweezer0,
+                                    tweezer1,
+                                    error,
+                                    layout_name(),
+                                )
+                                .map_err(in_field)?;
+                        }
+                    }
+                    [tweezer0, tweezer1, tweezer2] => {
+                        device
+                            .set_tweezer_three_qubit_gate_time(
+                                hqslang,
+                                tweezer0,
+                                tweezer1,
+                                tweezer2,
+                                time,
+                                layout_name(),
+                            )
+                            .map_err(in_field)?;
+                        if let Some(error) = error {
+                            device
+                                .set_tweezer_three_qubit_gate_error(
+                                    hqslang,
+                                    tweezer0,
+                                    tweezer1,
+                                    tweezer2,
+                                    error,
+                                    layout_name(),
+                                )
+                                .map_err(in_field)?;
+                        }
+                    }
+                    _ => unreachable!("The number of tweezers has been checked above."),
+                }
+            }
+        }
+    }
+
+    if let Some(shifts) = fields.get("shifts") {
+        let shifts_path = format!("{}.shifts", path);
+        for (index, entry) in array(shifts, &shifts_path)?.iter().enumerate() {
+            let entry_path = format!("{}[{}]", shifts_path, index);
+            let entry_fields = object(entry, &entry_path)?;
+            check_fields(entry_fields, &["tweezer", "shifts"], &entry_path)?;
+            let tweezer = index_field(entry_fields, "tweezer", &entry_path)?;
+            let lists_path = format!("{}.shifts", entry_path);
+            let lists = array(required(entry_fields, "shifts", &entry_path)?, &lists_path)?
+                .iter()
+                .enumerate()
+                .map(|(list_index, list)| {
+                    index_list(list, &format!("{}[{}]", lists_path, list_index))
+                })
+                .collect::<Result<Vec<Vec<usize>>, RoqoqoBackendError>>()?;
+            let lists: Vec<&[usize]> = lists.iter().map(Vec::as_slice).collect();
+            device
+                .set_allowed_tweezer_shifts(&tweezer, &lists, layout_name())
+                .map_err(|err| prefix_error(&entry_path, err))?;
+        }
+    }
+
+    if let Some(coordinates) = fields.get("coordinates") {
+        let coordinates_path = format!("{}.coordinates", path);
+        for (index, entry) in array(coordinates, &coordinates_path)?.iter().enumerate() {
+            let entry_path = format!("{}[{}]", coordinates_path, index);
+            let entry_fields = object(entry, &entry_path)?;
+            check_fields(entry_fields, &["tweezer", "x", "y"], &entry_path)?;
+            let tweezer = index_field(entry_fields, "tweezer", &entry_path)?;
+            let x = number(
+                required(entry_fields, "x", &entry_path)?,
+                &format!("{}.x", entry_path),
+            )?;
+            let y = number(
+                required(entry_fields, "y", &entry_path)?,
+                &format!("{}.y", entry_path),
+            )?;
+            device
+                .set_tweezer_coordinates(tweezer, x, y, layout_name())
+                .map_err(|err| prefix_error(&entry_path, err))?;
+        }
+    }
+
+    if let Some(tweezers_per_row) = fields.get("tweezers_per_row") {
+        let tweezers_per_row = index_list(tweezers_per_row, &format!("{}.tweezers_per_row", path))?;
+        device.set_tweezers_per_row(tweezers_per_row, layout_name())?;
+    }
+    Ok(())
+}
+
+/// Creates the error for an invalid field of a calibration file.
+fn field_error(path: &str, msg: &str) -> RoqoqoBackendError {
+    let path = if path.is_empty() { "<root>" } else { path };
+    RoqoqoBackendError::GenericError {
+        msg: format!("Invalid calibration file at {}: {}.", path, msg),
+    }
+}
+
+/// Adds the field of a calibration file to the error of a device setter.
+fn prefix_error(path: &str, err: RoqoqoBackendError) -> RoqoqoBackendError {
+    match err {
+        RoqoqoBackendError::GenericError { msg } => field_error(path, msg.trim_end_matches('.')),
+        err => err,
+    }
+}
+
+/// Returns an error for the first field not in `allowed`.
+fn check_fields(
+    fields: &Map<String, Value>,
+    allowed: &[&str],
+    path: &str,
+) -> Result<(), RoqoqoBackendError> {
+    let mut unknown: Vec<&String> = fields
+        .keys()
+        .filter(|key| !allowed.contains(&key.as_str()))
+        .collect();
+    unknown.sort();
+    match unknown.first() {
+        Some(key) => Err(field_error(
+            path,
+            &format!(
+                "unknown field \"{}\", expected one of {}",
+                key,
+                allowed.join(", ")
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+fn required<'a>(
+    fields: &'a Map<String, Value>,
+    name: &str,
+    path: &str,
+) -> Result<&'a Value, RoqoqoBackendError> {
+    fields
+        .get(name)
+        .ok_or_else(|| field_error(&join(path, name), "missing field"))
+}
+
+fn object<'a>(value: &'a Value, path: &str) -> Result<&'a Map<String, Value>, RoqoqoBackendError> {
+    value
+        .as_object()
+        .ok_or_else(|| field_error(path, "expected an object"))
+}
+
+fn array<'a>(value: &'a Value, path: &str) -> Result<&'a Vec<Value>, RoqoqoBackendError> {
+    value
+        .as_array()
+        .ok_or_else(|| field_error(path, "expected a list"))
+}
+
+fn string<'a>(value: &'a Value, path: &str) -> Result<&'a str, RoqoqoBackendError> {
+    value
+        .as_str()
+        .ok_or_else(|| field_error(path, "expected a string"))
+}
+
+fn number(value: &Value, path: &str) -> Result<f64, RoqoqoBackendError> {
+    value
+        .as_f64()
+        .ok_or_else(|| field_error(path, "expected a number"))
+}
+
+fn index(value: &Value, path: &str) -> Result<usize, RoqoqoBackendError> {
+    value
+        .as_u64()
+        .map(|index| index as usize)
+        .ok_or_else(|| field_error(path, "expected a non-negative integer"))
+}
+
+fn index_field(
+    fields: &Map<String, Value>,
+    name: &str,
+    path: &str,
+) -> Result<usize, RoqoqoBackendError> {
+    index(required(fields, name, path)?, &join(path, name))
+}
+
+fn index_list(value: &Value, path: &str) -> Result<Vec<usize>, RoqoqoBackendError> {
+    array(value, path)?
+        .iter()
+        .enumerate()
+        .map(|(position, item)| index(item, &format!("{}[{}]", path, position)))
+        .collect()
+}
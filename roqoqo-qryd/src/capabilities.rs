@@ -0,0 +1,259 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Machine-readable summary of the capabilities of a tweezer device.
+//!
+//! The capability manifest is a compact JSON document for external schedulers and frontends
+//! that need the capabilities of a device without interpreting its full serialization.
+//! The schema is stable within a `schema_version`, fields are only added in new versions:
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "device_name": "qryd_tweezer_device",
+//!   "current_layout": "default",
+//!   "layouts": ["default", "triangle"],
+//!   "gates": {
+//!     "single_qubit": [{"name": "RotateX", "number_entries": 4, "min_time": 0.1, "max_time": 0.1}],
+//!     "two_qubit": [...],
+//!     "three_qubit": [...],
+//!     "multi_qubit": [...]
+//!   },
+//!   "connectivity": {"number_tweezers": 4, "number_edges": 3, "max_degree": 2, "mean_degree": 1.5},
+//!   "limits": {"number_qubits": 4, "number_tweezers": 4, "tweezers_per_row": [4], "allow_reset": false, "shiftable_tweezers": 4},
+//!   "phase_relations": {"controlled_z": "DefaultRelation", "controlled_phase": "DefaultRelation", "controlled_z_phase": 3.14},
+//!   "noise": [{"tweezer": 0, "t1": 100.0, "t2": null, "depolarising_rate": null}]
+//! }
+//! ```
+//!
+//! Gates, connectivity and limits describe the current Layout, they are empty if no Layout is
+//! set. Gates are sorted by their name, `number_entries` counts the tweezers, or tweezer tuples
+//! for multi-qubit gates, the gate is available on. Edges are the unordered tweezer pairs with a
+//! two-qubit gate. `noise` is `null` if no noise parameters are set and sorted by the tweezer
+//! otherwise.
+
+use crate::tweezer_devices::TweezerDevice;
+use roqoqo::devices::Device;
+use roqoqo::RoqoqoBackendError;
+use std::collections::{BTreeSet, HashMap};
+
+/// The version of the schema of the capability manifest.
+pub const CAPABILITY_MANIFEST_SCHEMA_VERSION: u32 = 1;
+
+/// Capabilities of a tweezer device, see [crate::capabilities] for the JSON schema.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CapabilityManifest {
+    /// The version of the schema of the manifest.
+    pub schema_version: u32,
+    /// The name of the device.
+    pub device_name: String,
+    /// The name of the current Layout.
+    pub current_layout: Option<String>,
+    /// The names of all Layouts of the device, sorted.
+    pub layouts: Vec<String>,
+    /// The gates available in the current Layout.
+    pub gates: GateCapabilities,
+    /// The two-qubit connectivity of the current Layout.
+    pub connectivity: ConnectivitySummary,
+    /// The limits of the device.
+    pub limits: DeviceLimits,
+    /// The phase relations of the PhaseShiftedControlledZ and PhaseShiftedControlledPhase gates.
+    pub phase_relations: PhaseRelations,
+    /// The noise parameters of the tweezers, None if no noise parameters are set.
+    pub noise: Option<Vec<TweezerNoiseCapability>>,
+}
+
+/// The gates available in a Layout, sorted by their name.
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct GateCapabilities {
+    /// The single-qubit gates.
+    pub single_qubit: Vec<GateCapability>,
+    /// The two-qubit gates.
+    pub two_qubit: Vec<GateCapability>,
+    /// The three-qubit gates.
+    pub three_qubit: Vec<GateCapability>,
+    /// The multi-qubit gates.
+    pub multi_qubit: Vec<GateCapability>,
+}
+
+/// Summary of a gate available in a Layout.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GateCapability {
+    /// The hqslang name of the gate.
+    pub name: String,
+    /// The number of tweezers, or tweezer tuples, the gate is available on.
+    pub number_entries: usize,
+    /// The shortest gate time.
+    pub min_time: f64,
+    /// The longest gate time.
+    pub max_time: f64,
+}
+
+/// Summary of the two-qubit connectivity of a Layout.
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct ConnectivitySummary {
+    /// The number of tweezers of the Layout.
+    pub number_tweezers: usize,
+    /// The number of unordered tweezer pairs with a two-qubit gate.
+    pub number_edges: usize,
+    /// The largest number of tweezers connected to a tweezer.
+    pub max_degree: usize,
+    /// The mean number of tweezers connected to a tweezer.
+    pub mean_degree: f64,
+}
+
+/// Limits of a device.
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct DeviceLimits {
+    /// The number of qubits of the device.
+    pub number_qubits: usize,
+    /// The number of tweezers of the current Layout.
+    pub number_tweezers: usize,
+    /// The number of tweezers per row of the current Layout.
+    pub tweezers_per_row: Option<Vec<usize>>,
+    /// Whether PragmaActiveReset operations are allowed.
+    pub allow_reset: bool,
+    /// The number of tweezers of the current Layout qubits can be shifted out of.
+    pub shiftable_tweezers: usize,
+}
+
+/// Phase relations of the phase-shifted controlled gates.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PhaseRelations {
+    /// The relation of the PhaseShiftedControlledZ gate.
+    pub controlled_z: String,
+    /// The relation of the PhaseShiftedControlledPhase gate.
+    pub controlled_phase: String,
+    /// The phase shift of the PhaseShiftedControlledZ gate, None if the relation is unknown.
+    pub controlled_z_phase: Option<f64>,
+}
+
+/// Noise parameters of a tweezer.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TweezerNoiseCapability {
+    /// The index of the tweezer.
+    pub tweezer: usize,
+    /// Relaxation time T1.
+    pub t1: Option<f64>,
+    /// Dephasing time T2.
+    pub t2: Option<f64>,
+    /// Depolarising rate.
+    pub depolarising_rate: Option<f64>,
+}
+
+/// Returns the summaries of the gates of a gate time map, sorted by the gate name.
+fn gate_capabilities<K>(gate_times: &HashMap<String, HashMap<K, f64>>) -> Vec<GateCapability> {
+    let mut gates: Vec<GateCapability> = gate_times
+        .iter()
+        .filter(|(_, times)| !times.is_empty())
+        .map(|(name, times)| GateCapability {
+            name: name.clone(),
+            number_entries: times.len(),
+            min_time: times.values().copied().fold(f64::INFINITY, f64::min),
+            max_time: times.values().copied().fold(f64::NEG_INFINITY, f64::max),
+        })
+        .collect();
+    gates.sort_by(|gate, other| gate.name.cmp(&other.name));
+    gates
+}
+
+/// Builds the capability manifest of a device.
+pub(crate) fn capability_manifest(
+    device: &TweezerDevice,
+) -> Result<CapabilityManifest, RoqoqoBackendError> {
+    let mut layouts: Vec<String> = device
+        .available_layouts()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    layouts.sort();
+
+    let mut gates = GateCapabilities::default();
+    let mut connectivity = ConnectivitySummary::default();
+    let mut limits = DeviceLimits {
+        number_qubits: device.number_qubits(),
+        allow_reset: device.allow_reset,
+        ..Default::default()
+    };
+    if device.current_layout.is_some() {
+        let layout = device.get_current_layout_info()?;
+        gates = GateCapabilities {
+            single_qubit: gate_capabilities(&layout.tweezer_single_qubit_gate_times),
+            two_qubit: gate_capabilities(&layout.tweezer_two_qubit_gate_times),
+            three_qubit: gate_capabilities(&layout.tweezer_three_qubit_gate_times),
+            multi_qubit: gate_capabilities(&layout.tweezer_multi_qubit_gate_times),
+        };
+        let edges: BTreeSet<(usize, usize)> = layout
+            .tweezer_two_qubit_gate_times
+            .values()
+            .flat_map(HashMap::keys)
+            .filter(|(tweezer0, tweezer1)| tweezer0 != tweezer1)
+            .map(|(tweezer0, tweezer1)| (*tweezer0.min(tweezer1), *tweezer0.max(tweezer1)))
+            .collect();
+        let mut degrees: HashMap<usize, usize> = HashMap::new();
+        for (tweezer0, tweezer1) in edges.iter() {
+            *degrees.entry(*tweezer0).or_insert(0) += 1;
+            *degrees.entry(*tweezer1).or_insert(0) += 1;
+        }
+        let number_tweezers = device.number_tweezer_positions(None)?;
+        connectivity = ConnectivitySummary {
+            number_tweezers,
+            number_edges: edges.len(),
+            max_degree: degrees.values().copied().max().unwrap_or(0),
+            mean_degree: if number_tweezers == 0 {
+                0.0
+            } else {
+                2.0 * edges.len() as f64 / number_tweezers as f64
+            },
+        };
+        limits.number_tweezers = number_tweezers;
+        limits.tweezers_per_row = layout.tweezers_per_row.clone();
+        limits.shiftable_tweezers = layout
+            .allowed_tweezer_shifts
+            .values()
+            .filter(|shifts| shifts.iter().any(|shift| !shift.is_empty()))
+            .count();
+    }
+
+    let noise = if device.tweezer_noise.is_empty() {
+        None
+    } else {
+        let mut noise: Vec<TweezerNoiseCapability> = device
+            .tweezer_noise
+            .iter()
+            .map(|(tweezer, parameters)| TweezerNoiseCapability {
+                tweezer: *tweezer,
+                t1: parameters.t1,
+                t2: parameters.t2,
+                depolarising_rate: parameters.depolarising_rate,
+            })
+            .collect();
+        noise.sort_by_key(|parameters| parameters.tweezer);
+        Some(noise)
+    };
+
+    Ok(CapabilityManifest {
+        schema_version: CAPABILITY_MANIFEST_SCHEMA_VERSION,
+        device_name: device.device_name.clone(),
+        current_layout: device.current_layout.clone(),
+        layouts,
+        gates,
+        connectivity,
+        limits,
+        phase_relations: PhaseRelations {
+            controlled_z: device.controlled_z_phase_relation.clone(),
+            controlled_phase: device.controlled_phase_phase_relation.clone(),
+            controlled_z_phase: device.phase_shift_controlled_z(),
+        },
+        noise,
+    })
+}
@@ -0,0 +1,138 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compatibility checks of circuits against tweezer devices.
+//!
+//! The simulator and the WebAPI reject a circuit at its first operation the device does not
+//! support. [check_circuit_compatibility] instead walks the whole circuit, following the
+//! device-changing pragmas, and reports every incompatible operation at once.
+
+use crate::resource_estimation::{gate_qubits, native_gate_time};
+use crate::TweezerDevice;
+use roqoqo::devices::Device;
+use roqoqo::operations::{InvolveQubits, InvolvedQubits, Operate, Operation};
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::fmt;
+
+/// An operation of a circuit that is not compatible with the device at its position in the circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncompatibilityReport {
+    /// The index of the operation in the circuit.
+    pub index: usize,
+    /// The hqslang name of the operation.
+    pub hqslang: String,
+    /// The qubits the operation acts on, empty if it does not act on a set of qubits.
+    pub qubits: Vec<usize>,
+    /// The current Layout of the device at the position of the operation.
+    pub layout: Option<String>,
+    /// The reason the operation is not compatible.
+    pub reason: String,
+}
+
+impl fmt::Display for IncompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Operation {} ({}) on qubits {:?}: {}",
+            self.index, self.hqslang, self.qubits, self.reason
+        )
+    }
+}
+
+/// Checks every operation of a circuit against a device.
+///
+/// The device-changing pragmas are applied to a copy of the device as
+/// [TweezerDevice::change_device] would, so every gate is checked against the Layout, qubit
+/// positions and deactivated qubits at its position in the circuit. A device-changing pragma the
+/// device rejects is reported and leaves the device unchanged. Operations inside a PragmaLoop are
+/// not checked.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit to check.
+/// * `device` - The device the circuit is run on.
+///
+/// # Returns
+///
+/// * `Ok(())` - All operations of the circuit are compatible with the device.
+/// * `Err(Vec<IncompatibilityReport>)` - The incompatible operations, in the order of the circuit.
+pub fn check_circuit_compatibility(
+    circuit: &Circuit,
+    device: &TweezerDevice,
+) -> Result<(), Vec<IncompatibilityReport>> {
+    let mut device = device.clone();
+    let mut reports: Vec<IncompatibilityReport> = Vec::new();
+    for (index, operation) in circuit.iter().enumerate() {
+        let qubits: Vec<usize> = match operation.involved_qubits() {
+            InvolvedQubits::Set(qubits) => {
+                let mut qubits: Vec<usize> = qubits.into_iter().collect();
+                qubits.sort_unstable();
+                qubits
+            }
+            _ => Vec::new(),
+        };
+        let layout = device.current_layout.clone();
+        let report = |reason: String| IncompatibilityReport {
+            index,
+            hqslang: operation.hqslang().to_string(),
+            qubits: qubits.clone(),
+            layout: layout.clone(),
+            reason,
+        };
+        let number_qubits = device.number_qubits();
+        if let Some(qubit) = qubits.iter().find(|qubit| **qubit >= number_qubits) {
+            reports.push(report(format!(
+                "qubit {} is not part of the device with {} qubits",
+                qubit, number_qubits
+            )));
+            continue;
+        }
+        if let Operation::PragmaChangeDevice(pragma) = operation {
+            // Only a successful change is kept, a rejected pragma leaves the device unchanged
+            let mut changed_device = device.clone();
+            match changed_device.change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation) {
+                Ok(()) => device = changed_device,
+                Err(err) => reports.push(report(error_reason(err))),
+            }
+            continue;
+        }
+        if !operation.tags().contains(&"GateOperation") {
+            continue;
+        }
+        match gate_qubits(operation) {
+            Some(gate_qubits) => {
+                if native_gate_time(&device, operation.hqslang(), &gate_qubits).is_none() {
+                    reports.push(report(format!(
+                        "the gate is not available on qubits {:?}",
+                        gate_qubits
+                    )));
+                }
+            }
+            None => reports.push(report(
+                "the gate is not a native gate of the device".to_string(),
+            )),
+        }
+    }
+    if reports.is_empty() {
+        Ok(())
+    } else {
+        Err(reports)
+    }
+}
+
+/// Returns the message of a device error.
+fn error_reason(err: RoqoqoBackendError) -> String {
+    match err {
+        RoqoqoBackendError::GenericError { msg } => msg,
+        _ => err.to_string(),
+    }
+}
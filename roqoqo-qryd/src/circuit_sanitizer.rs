@@ -0,0 +1,107 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sanitizing of circuits developed against the simulator for submission to the QRyd WebAPI.
+//!
+//! Circuits used with the simulator often contain pragmas that only make sense in a simulation,
+//! such as state vector readouts or noise pragmas. The WebAPI rejects these operations.
+//! [sanitize_for_api] removes them and unrolls loops, reporting every change it made.
+
+use roqoqo::operations::{Operate, Operation};
+use roqoqo::Circuit;
+
+/// Pragmas that only have an effect in simulations and are removed by [sanitize_for_api].
+pub static SIMULATION_ONLY_PRAGMAS: [&str; 19] = [
+    // Readout of the simulated state
+    "PragmaGetStateVector",
+    "PragmaGetDensityMatrix",
+    "PragmaGetOccupationProbability",
+    "PragmaGetPauliProduct",
+    // Initialisation of the simulated state
+    "PragmaSetStateVector",
+    "PragmaSetDensityMatrix",
+    // Noise
+    "PragmaDamping",
+    "PragmaDepolarising",
+    "PragmaDephasing",
+    "PragmaRandomNoise",
+    "PragmaGeneralNoise",
+    "PragmaOverrotation",
+    "PragmaBoostNoise",
+    "PragmaSleep",
+    // Compiler and verification hints
+    "PragmaStopParallelBlock",
+    "PragmaGlobalPhase",
+    "PragmaRepeatGate",
+    "PragmaStartDecompositionBlock",
+    "PragmaStopDecompositionBlock",
+];
+
+/// Report of the changes made by [sanitize_for_api].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SanitizationReport {
+    /// The hqslang names of the removed operations, in the order they appeared in the circuit.
+    pub removed: Vec<String>,
+    /// The number of PragmaLoop operations unrolled into repeated operations.
+    pub unrolled_loops: usize,
+}
+
+impl SanitizationReport {
+    /// Returns true if the circuit was not changed.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.unrolled_loops == 0
+    }
+}
+
+/// Strips the simulation-only pragmas from a circuit so it can be submitted to the QRyd WebAPI.
+///
+/// The pragmas listed in [SIMULATION_ONLY_PRAGMAS] are removed, PragmaLoop operations with a
+/// numeric number of repetitions are unrolled. All other operations are kept unchanged,
+/// unsupported gates are still rejected when posting the job.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit to sanitize.
+///
+/// # Returns
+///
+/// * `(Circuit, SanitizationReport)` - The sanitized circuit and the report of the changes made.
+pub fn sanitize_for_api(circuit: &Circuit) -> (Circuit, SanitizationReport) {
+    let mut report = SanitizationReport::default();
+    let sanitized = sanitize_operations(circuit, &mut report);
+    (sanitized, report)
+}
+
+fn sanitize_operations(circuit: &Circuit, report: &mut SanitizationReport) -> Circuit {
+    let mut sanitized = Circuit::new();
+    for operation in circuit.iter() {
+        match operation {
+            Operation::PragmaLoop(pragma) => match pragma.repetitions().float() {
+                Ok(repetitions) => {
+                    report.unrolled_loops += 1;
+                    let body = sanitize_operations(pragma.circuit(), report);
+                    for _ in 0..(repetitions.floor().max(0.0) as usize) {
+                        for inner in body.iter() {
+                            sanitized.add_operation(inner.clone());
+                        }
+                    }
+                }
+                Err(_) => sanitized.add_operation(operation.clone()),
+            },
+            _ if SIMULATION_ONLY_PRAGMAS.contains(&operation.hqslang()) => {
+                report.removed.push(operation.hqslang().to_string());
+            }
+            _ => sanitized.add_operation(operation.clone()),
+        }
+    }
+    sanitized
+}
@@ -0,0 +1,80 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Symbolic circuits compiled once and evaluated for many parameter values.
+//!
+//! Substituting the parameters of a [roqoqo::QuantumProgram] rebuilds every circuit of the
+//! measurement, including all operations without symbolic parameters. A [CircuitTemplate]
+//! stores the operations of a circuit together with the positions of the parametrized operations,
+//! so that only these are substituted for every set of parameter values. The parameters of QRyd
+//! pragmas wrapped in PragmaChangeDevice operations are substituted as well.
+
+use crate::parameter_substitution::{is_operation_parametrized, substitute_operation_parameters};
+use qoqo_calculator::Calculator;
+use roqoqo::operations::Operation;
+use roqoqo::{Circuit, RoqoqoBackendError};
+
+/// A circuit with symbolic parameters prepared for repeated substitution.
+#[derive(Debug, Clone)]
+pub(crate) struct CircuitTemplate {
+    /// The operations of the circuit, with the symbolic parameters of the last substitution.
+    operations: Vec<Operation>,
+    /// The positions and symbolic forms of the parametrized operations.
+    parametrized: Vec<(usize, Operation)>,
+}
+
+impl CircuitTemplate {
+    /// Compiles the concatenation of the given circuits into a template.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuits` - The circuits to concatenate, e.g. the constant circuit and a measurement circuit.
+    pub(crate) fn new<'a>(circuits: impl IntoIterator<Item = &'a Circuit>) -> Self {
+        let operations: Vec<Operation> = circuits
+            .into_iter()
+            .flat_map(Circuit::iter)
+            .cloned()
+            .collect();
+        let parametrized = operations
+            .iter()
+            .enumerate()
+            .filter(|(_, operation)| is_operation_parametrized(operation))
+            .map(|(index, operation)| (index, operation.clone()))
+            .collect();
+        Self {
+            operations,
+            parametrized,
+        }
+    }
+
+    /// Substitutes the symbolic parameters and returns the operations of the circuit.
+    ///
+    /// Only the parametrized operations are substituted, all other operations are reused.
+    ///
+    /// # Arguments
+    ///
+    /// * `calculator` - The calculator holding the values of the symbolic parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&[Operation])` - The operations with the substituted parameters.
+    /// * `Err(RoqoqoBackendError)` - A symbolic parameter could not be substituted.
+    pub(crate) fn substitute(
+        &mut self,
+        calculator: &Calculator,
+    ) -> Result<&[Operation], RoqoqoBackendError> {
+        for (index, operation) in self.parametrized.iter() {
+            self.operations[*index] = substitute_operation_parameters(operation, calculator)?;
+        }
+        Ok(&self.operations)
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of circuits written for the deprecated FirstDevice to the tweezer devices.
+//!
+//! The [TweezerDevice] rejects the legacy pragmas [PragmaChangeQRydLayout] and
+//! [PragmaShiftQRydQubit], which refer to layouts by number and to qubit positions by row and
+//! column. [upgrade_circuit] rewrites them into [PragmaSwitchDeviceLayout] and
+//! [PragmaShiftQubitsTweezers] operations referring to the layout names and tweezer indices.
+
+use crate::tweezer_devices::layout_tweezers_per_row;
+use crate::{
+    PragmaChangeQRydLayout, PragmaShiftQRydQubit, PragmaShiftQubitsTweezers,
+    PragmaSwitchDeviceLayout, TweezerDevice,
+};
+use bincode::deserialize;
+use roqoqo::devices::Device;
+use roqoqo::operations::{Operation, PragmaChangeDevice};
+use roqoqo::Circuit;
+
+/// Rewrites the legacy QRyd pragmas of a circuit into the tweezer pragmas.
+///
+/// A PragmaChangeQRydLayout to the layout number `n` becomes a PragmaSwitchDeviceLayout to the
+/// Layout named `n` of the device. A PragmaShiftQRydQubit becomes a parallel
+/// PragmaShiftQubitsTweezers moving every qubit from its current tweezer to the tweezer of its
+/// new position. The tweezers are numbered row by row as in [TweezerDevice::from_first_device],
+/// following the tweezers per row of the current Layout.
+///
+/// The circuit is followed on a copy of the device, so that the layout switches and shifts are
+/// converted for the device state at their position in the circuit. Legacy pragmas that can not
+/// be converted, for example because the Layout does not exist or a qubit is not placed in a
+/// tweezer, are left unchanged and are still rejected by the device. Operations inside a
+/// PragmaLoop are left unchanged.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit to convert.
+/// * `device` - The device the circuit is run on.
+///
+/// # Returns
+///
+/// * `Circuit` - The circuit with the convertible legacy pragmas replaced.
+pub fn upgrade_circuit(circuit: &Circuit, device: &TweezerDevice) -> Circuit {
+    let mut device = device.clone();
+    let mut upgraded = Circuit::new();
+    for operation in circuit.iter() {
+        let operation = match operation {
+            Operation::PragmaChangeDevice(pragma) => {
+                let converted = upgrade_pragma(pragma, &device).unwrap_or_else(|| pragma.clone());
+                // Keep the device in sync with the circuit, invalid pragmas leave it unchanged
+                let _ =
+                    device.change_device(&converted.wrapped_hqslang, &converted.wrapped_operation);
+                Operation::PragmaChangeDevice(converted)
+            }
+            _ => operation.clone(),
+        };
+        upgraded += operation;
+    }
+    upgraded
+}
+
+/// Converts a wrapped legacy pragma, returns None if it is not a legacy pragma or can not be converted.
+fn upgrade_pragma(
+    pragma: &PragmaChangeDevice,
+    device: &TweezerDevice,
+) -> Option<PragmaChangeDevice> {
+    match pragma.wrapped_hqslang.as_str() {
+        "PragmaChangeQRydLayout" => {
+            let legacy: PragmaChangeQRydLayout = deserialize(&pragma.wrapped_operation).ok()?;
+            let layout_name = legacy.new_layout().to_string();
+            if !device.available_layouts().contains(&layout_name.as_str()) {
+                return None;
+            }
+            PragmaSwitchDeviceLayout::new(layout_name)
+                .to_pragma_change_device()
+                .ok()
+        }
+        "PragmaShiftQRydQubit" => {
+            let legacy: PragmaShiftQRydQubit = deserialize(&pragma.wrapped_operation).ok()?;
+            let qubit_to_tweezer = device.qubit_to_tweezer.as_ref()?;
+            let tweezers_per_row = layout_tweezers_per_row(device.get_current_layout_info().ok()?)?;
+            let mut new_positions: Vec<(&usize, &(usize, usize))> =
+                legacy.new_positions().iter().collect();
+            new_positions.sort();
+            let mut shifts: Vec<(usize, usize)> = Vec::with_capacity(new_positions.len());
+            for (qubit, (row, column)) in new_positions {
+                if *column >= *tweezers_per_row.get(*row)? {
+                    return None;
+                }
+                let start = *qubit_to_tweezer.get(qubit)?;
+                let end = tweezers_per_row[..*row].iter().sum::<usize>() + column;
+                if start != end {
+                    shifts.push((start, end));
+                }
+            }
+            PragmaShiftQubitsTweezers::new_parallel(shifts)
+                .to_pragma_change_device()
+                .ok()
+        }
+        _ => None,
+    }
+}
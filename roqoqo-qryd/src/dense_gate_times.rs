@@ -0,0 +1,160 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dense lookup tables caching the two-qubit gate times of the current layout.
+//!
+//! The two-qubit gate times of a layout are stored in a `HashMap<(usize, usize), f64>` per gate.
+//! For layouts where most tweezer pairs have a gate time, e.g. large devices with all-to-all
+//! connectivity, a dense `Array2<Option<f64>>` indexed by the tweezers is faster to look up than
+//! hashing the pair. The dense tables are built lazily from the maps of the current layout on the
+//! first lookup of a gate and are only used when the map is dense enough. They are a cache kept
+//! in addition to the maps, not a replacement: the maps stay the storage and the serialized
+//! representation of the gate times, and every change of the gate times discards the tables. The
+//! tables are built for one layout, a lookup in a different layout discards them.
+
+use ndarray::Array2;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::RwLock;
+
+/// Minimal fraction of tweezer pairs with a gate time for which a dense table is used.
+const DENSITY_THRESHOLD: f64 = 0.25;
+
+/// Minimal number of gate times for which a dense table is used, smaller maps are fast enough.
+const MINIMAL_DENSE_ENTRIES: usize = 64;
+
+/// Dense tables of the gate time maps of one layout.
+#[derive(Default)]
+struct LayoutTables {
+    /// The layout the tables are built for.
+    layout: Option<String>,
+    /// The dense tables keyed by the hqslang name of the gate.
+    ///
+    /// `None` marks gates whose map is too sparse for a dense table.
+    tables: HashMap<String, Option<Array2<Option<f64>>>>,
+}
+
+/// Cached dense two-qubit gate time tables of the current layout.
+#[derive(Default)]
+pub(crate) struct DenseGateTimes {
+    tables: RwLock<LayoutTables>,
+}
+
+impl DenseGateTimes {
+    /// Discards all dense tables, they are rebuilt on the next lookup.
+    pub(crate) fn invalidate(&mut self) {
+        if let Ok(tables) = self.tables.get_mut() {
+            *tables = LayoutTables::default();
+        }
+    }
+
+    /// Returns the time of a two-qubit gate between two tweezers.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The layout the gate time is looked up in.
+    /// * `hqslang` - The hqslang name of the gate.
+    /// * `gate_times` - The (tweezer, tweezer) -> time mapping of the gate in the layout.
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    pub(crate) fn two_qubit_gate_time(
+        &self,
+        layout: Option<&str>,
+        hqslang: &str,
+        gate_times: &HashMap<(usize, usize), f64>,
+        tweezer0: usize,
+        tweezer1: usize,
+    ) -> Option<f64> {
+        if gate_times.len() < MINIMAL_DENSE_ENTRIES {
+            return gate_times.get(&(tweezer0, tweezer1)).copied();
+        }
+        if let Ok(tables) = self.tables.read() {
+            if tables.layout.as_deref() == layout {
+                if let Some(table) = tables.tables.get(hqslang) {
+                    return lookup(table.as_ref(), gate_times, tweezer0, tweezer1);
+                }
+            }
+        }
+        let table = dense_table(gate_times);
+        let time = lookup(table.as_ref(), gate_times, tweezer0, tweezer1);
+        if let Ok(mut tables) = self.tables.write() {
+            if tables.layout.as_deref() != layout {
+                *tables = LayoutTables {
+                    layout: layout.map(str::to_string),
+                    ..LayoutTables::default()
+                };
+            }
+            tables.tables.insert(hqslang.to_string(), table);
+        }
+        time
+    }
+}
+
+/// Looks up a gate time in the dense table if there is one, in the map otherwise.
+fn lookup(
+    table: Option<&Array2<Option<f64>>>,
+    gate_times: &HashMap<(usize, usize), f64>,
+    tweezer0: usize,
+    tweezer1: usize,
+) -> Option<f64> {
+    match table {
+        Some(table) => table.get((tweezer0, tweezer1)).copied().flatten(),
+        None => gate_times.get(&(tweezer0, tweezer1)).copied(),
+    }
+}
+
+/// Builds the dense table of a gate time map, `None` if the map is too sparse.
+fn dense_table(gate_times: &HashMap<(usize, usize), f64>) -> Option<Array2<Option<f64>>> {
+    let size = gate_times
+        .keys()
+        .map(|(tweezer0, tweezer1)| tweezer0.max(tweezer1) + 1)
+        .max()?;
+    if (gate_times.len() as f64) < DENSITY_THRESHOLD * (size as f64) * (size as f64) {
+        return None;
+    }
+    let mut table: Array2<Option<f64>> = Array2::from_elem((size, size), None);
+    for ((tweezer0, tweezer1), time) in gate_times {
+        table[(*tweezer0, *tweezer1)] = Some(*time);
+    }
+    Some(table)
+}
+
+impl Clone for DenseGateTimes {
+    // The tables are derived from the gate times and rebuilt by the clone when needed.
+    fn clone(&self) -> Self {
+        DenseGateTimes::default()
+    }
+}
+
+impl PartialEq for DenseGateTimes {
+    // The tables do not change the gate times of a device and are ignored when comparing devices.
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for DenseGateTimes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dense_gates: Vec<String> = match self.tables.read() {
+            Ok(tables) => tables
+                .tables
+                .iter()
+                .filter(|(_, table)| table.is_some())
+                .map(|(hqslang, _)| hqslang.clone())
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        f.debug_struct("DenseGateTimes")
+            .field("dense_gates", &dense_gates)
+            .finish()
+    }
+}
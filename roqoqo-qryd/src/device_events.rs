@@ -0,0 +1,126 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Device events recorded per shot during a simulation.
+//!
+//! When the simulator models events of the hardware, e.g. atoms missing in their tweezers, it
+//! writes them into the bit register [EVENTS_REGISTER]. The register contains one entry per
+//! shot, aligned with the entries of the measured registers of the same run, so measurement
+//! outcomes can be correlated with the events. Each entry holds one flag per event kind and
+//! qubit, the flag of `kind` on `qubit` is at the index `kind.index() * number_qubits + qubit`.
+
+use roqoqo::registers::BitOutputRegister;
+use roqoqo::RoqoqoBackendError;
+use std::collections::HashMap;
+
+/// The name of the bit register the device events are written to.
+pub const EVENTS_REGISTER: &str = "qryd_events";
+
+/// The kinds of device events, in the order of their flags in the events register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DeviceEventKind {
+    /// The atom of the qubit is missing, the qubit is not acted on and measured as `false`.
+    AtomLoss,
+    /// The qubit has leaked out of the computational subspace.
+    Leakage,
+    /// A shift of the qubit to another tweezer has failed.
+    FailedShift,
+}
+
+impl DeviceEventKind {
+    /// All kinds of device events, in the order of their flags in the events register.
+    pub const ALL: [DeviceEventKind; 3] = [
+        DeviceEventKind::AtomLoss,
+        DeviceEventKind::Leakage,
+        DeviceEventKind::FailedShift,
+    ];
+
+    /// Returns the position of the flags of the event kind in an entry of the events register.
+    pub fn index(&self) -> usize {
+        match self {
+            DeviceEventKind::AtomLoss => 0,
+            DeviceEventKind::Leakage => 1,
+            DeviceEventKind::FailedShift => 2,
+        }
+    }
+}
+
+/// An event of the device affecting a qubit in one shot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DeviceEvent {
+    /// The kind of the event.
+    pub kind: DeviceEventKind,
+    /// The qubit affected by the event.
+    pub qubit: usize,
+}
+
+/// Returns the entry of the events register of one shot.
+///
+/// # Arguments
+///
+/// * `number_qubits` - The number of qubits of the simulation.
+/// * `events` - The events of the shot, events on qubits outside the simulation are ignored.
+pub(crate) fn events_entry(
+    number_qubits: usize,
+    events: impl IntoIterator<Item = DeviceEvent>,
+) -> Vec<bool> {
+    let mut entry = vec![false; DeviceEventKind::ALL.len() * number_qubits];
+    for event in events {
+        if event.qubit < number_qubits {
+            entry[event.kind.index() * number_qubits + event.qubit] = true;
+        }
+    }
+    entry
+}
+
+/// Decodes the device events of each shot from the output registers of a run.
+///
+/// # Arguments
+///
+/// * `bit_registers` - The bit output registers of the run.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Vec<DeviceEvent>>)` - The events of each shot, sorted by kind and qubit. Empty if the run recorded no events register.
+/// * `Err(RoqoqoBackendError)` - An entry of the events register does not have one flag per event kind and qubit.
+pub fn device_events(
+    bit_registers: &HashMap<String, BitOutputRegister>,
+) -> Result<Vec<Vec<DeviceEvent>>, RoqoqoBackendError> {
+    let entries = match bit_registers.get(EVENTS_REGISTER) {
+        Some(entries) => entries,
+        None => return Ok(Vec::new()),
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            if entry.len() % DeviceEventKind::ALL.len() != 0 {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Entry of length {} of register {} does not have one flag per event kind and qubit.",
+                        entry.len(),
+                        EVENTS_REGISTER
+                    ),
+                });
+            }
+            let number_qubits = entry.len() / DeviceEventKind::ALL.len();
+            Ok(entry
+                .iter()
+                .enumerate()
+                .filter(|(_, flag)| **flag)
+                .map(|(index, _)| DeviceEvent {
+                    kind: DeviceEventKind::ALL[index / number_qubits],
+                    qubit: index % number_qubits,
+                })
+                .collect())
+        })
+        .collect()
+}
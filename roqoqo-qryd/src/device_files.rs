@@ -0,0 +1,137 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Snapshots of tweezer devices saved to and loaded from files.
+//!
+//! [TweezerDevice::to_file] and [TweezerDevice::from_file] store the same serialization as
+//! `to_json` and `to_bincode`, the [DeviceFileFormat] is inferred from the file extension:
+//! `.json` for JSON, `.bin` or `.bincode` for bincode and `.yaml` or `.yml` for YAML.
+//! YAML files require the `yaml` feature.
+
+use crate::TweezerDevice;
+use roqoqo::RoqoqoBackendError;
+use std::path::Path;
+
+/// File formats of the device snapshots written by [TweezerDevice::to_file].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DeviceFileFormat {
+    /// JSON document, the same as `to_json`.
+    Json,
+    /// Binary bincode serialization, the same as `to_bincode`.
+    Bincode,
+    /// YAML document, requires the `yaml` feature.
+    Yaml,
+}
+
+impl DeviceFileFormat {
+    /// Returns the format matching the extension of a file path, case-insensitive.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(DeviceFileFormat)` - The format of a `.json`, `.bin`, `.bincode`, `.yaml` or `.yml` file.
+    /// * `None` - The path has no or another extension.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<DeviceFileFormat> {
+        let extension = path.as_ref().extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "json" => Some(DeviceFileFormat::Json),
+            "bin" | "bincode" => Some(DeviceFileFormat::Bincode),
+            "yaml" | "yml" => Some(DeviceFileFormat::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the given format or the format inferred from the extension of the path.
+pub(crate) fn resolve_file_format(
+    path: &Path,
+    format: Option<DeviceFileFormat>,
+) -> Result<DeviceFileFormat, RoqoqoBackendError> {
+    format
+        .or_else(|| DeviceFileFormat::from_path(path))
+        .ok_or_else(|| RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Could not infer the file format of {}, use one of the extensions .json, .bin, .bincode, .yaml or .yml.",
+                path.display()
+            ),
+        })
+}
+
+/// Writes a device to a file in the given format.
+pub(crate) fn write_device_file(
+    device: &TweezerDevice,
+    path: &Path,
+    format: DeviceFileFormat,
+) -> Result<(), RoqoqoBackendError> {
+    let serialization_error = |err: String| RoqoqoBackendError::GenericError {
+        msg: format!("Could not serialize the TweezerDevice: {}", err),
+    };
+    let content: Vec<u8> = match format {
+        DeviceFileFormat::Json => {
+            serde_json::to_vec_pretty(device).map_err(|err| serialization_error(err.to_string()))?
+        }
+        DeviceFileFormat::Bincode => {
+            bincode::serialize(device).map_err(|err| serialization_error(err.to_string()))?
+        }
+        #[cfg(feature = "yaml")]
+        DeviceFileFormat::Yaml => serde_yaml::to_string(device)
+            .map_err(|err| serialization_error(err.to_string()))?
+            .into_bytes(),
+        #[cfg(not(feature = "yaml"))]
+        DeviceFileFormat::Yaml => return Err(yaml_feature_error()),
+    };
+    std::fs::write(path, content).map_err(|err| RoqoqoBackendError::GenericError {
+        msg: format!("Could not write the file {}: {}", path.display(), err),
+    })
+}
+
+/// Reads a device from a file in the given format.
+pub(crate) fn read_device_file(
+    path: &Path,
+    format: DeviceFileFormat,
+) -> Result<TweezerDevice, RoqoqoBackendError> {
+    let content = std::fs::read(path).map_err(|err| RoqoqoBackendError::GenericError {
+        msg: format!("Could not read the file {}: {}", path.display(), err),
+    })?;
+    let deserialization_error = |err: String| RoqoqoBackendError::GenericError {
+        msg: format!(
+            "Could not deserialize the TweezerDevice from {}: {}",
+            path.display(),
+            err
+        ),
+    };
+    match format {
+        DeviceFileFormat::Json => {
+            serde_json::from_slice(&content).map_err(|err| deserialization_error(err.to_string()))
+        }
+        DeviceFileFormat::Bincode => {
+            bincode::deserialize(&content).map_err(|err| deserialization_error(err.to_string()))
+        }
+        #[cfg(feature = "yaml")]
+        DeviceFileFormat::Yaml => {
+            serde_yaml::from_slice(&content).map_err(|err| deserialization_error(err.to_string()))
+        }
+        #[cfg(not(feature = "yaml"))]
+        DeviceFileFormat::Yaml => Err(yaml_feature_error()),
+    }
+}
+
+/// Error returned for YAML files in builds without the `yaml` feature.
+#[cfg(not(feature = "yaml"))]
+pub(crate) fn yaml_feature_error() -> RoqoqoBackendError {
+    RoqoqoBackendError::GenericError {
+        msg: "Reading and writing YAML files requires the yaml feature of roqoqo-qryd.".to_string(),
+    }
+}
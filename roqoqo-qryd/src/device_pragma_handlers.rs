@@ -0,0 +1,157 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Registry of custom device-changing pragmas.
+//!
+//! `TweezerDevice::change_device` natively handles the QRyd pragmas defined in this crate.
+//! Downstream crates can define additional device-mutating pragmas by implementing
+//! [DevicePragmaHandler] and registering it with [register_device_pragma_handler]. When a
+//! device receives a wrapped operation whose hqslang is not handled natively, the registered
+//! handler for that hqslang is called with the serialized operation.
+
+use crate::TweezerDevice;
+use roqoqo::RoqoqoBackendError;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Names of the pragmas handled natively by the `change_device` method of the QRyd devices.
+///
+/// Handlers for these names can not be registered.
+pub static BUILTIN_DEVICE_PRAGMAS: [&str; 9] = [
+    "PragmaChangeQRydLayout",
+    "PragmaSwitchDeviceLayout",
+    "PragmaDeactivateQRydQubit",
+    "PragmaShiftQRydQubit",
+    "PragmaShiftQubitsTweezers",
+    "PragmaSwapTweezers",
+    "PragmaGlobalRotateXY",
+    "PragmaMoveToZone",
+    "PragmaSetPhaseRelation",
+];
+
+/// Handler applying a custom device-changing pragma to a [TweezerDevice].
+pub trait DevicePragmaHandler: Send + Sync {
+    /// Returns the hqslang name of the pragma handled by this handler.
+    fn hqslang(&self) -> &str;
+
+    /// Applies the pragma to the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The device to be changed.
+    /// * `operation` - The pragma serialized with bincode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The device has been changed.
+    /// * `Err(RoqoqoBackendError)` - The operation could not be deserialized or applied to the device.
+    fn apply(&self, device: &mut TweezerDevice, operation: &[u8])
+        -> Result<(), RoqoqoBackendError>;
+}
+
+type HandlerRegistry = RwLock<HashMap<String, Arc<dyn DevicePragmaHandler>>>;
+
+fn registry() -> &'static HandlerRegistry {
+    static REGISTRY: OnceLock<HandlerRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Registers a handler for a custom device-changing pragma.
+///
+/// A handler previously registered for the same hqslang is replaced.
+///
+/// # Arguments
+///
+/// * `handler` - The handler to register.
+///
+/// # Returns
+///
+/// * `Ok(())` - The handler has been registered.
+/// * `Err(RoqoqoBackendError)` - The hqslang of the handler is handled natively by the devices.
+pub fn register_device_pragma_handler<H>(handler: H) -> Result<(), RoqoqoBackendError>
+where
+    H: DevicePragmaHandler + 'static,
+{
+    let hqslang = handler.hqslang().to_string();
+    if BUILTIN_DEVICE_PRAGMAS.contains(&hqslang.as_str()) {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "{} is handled natively by the QRyd devices and can not be overridden.",
+                hqslang
+            ),
+        });
+    }
+    registry()
+        .write()
+        .map_err(|_| poisoned_registry())?
+        .insert(hqslang, Arc::new(handler));
+    Ok(())
+}
+
+/// Removes the handler registered for a custom device-changing pragma.
+///
+/// # Arguments
+///
+/// * `hqslang` - The hqslang name of the pragma.
+///
+/// # Returns
+///
+/// * `true` - A handler was registered and has been removed.
+/// * `false` - No handler was registered for the pragma.
+pub fn unregister_device_pragma_handler(hqslang: &str) -> bool {
+    match registry().write() {
+        Ok(mut handlers) => handlers.remove(hqslang).is_some(),
+        Err(_) => false,
+    }
+}
+
+/// Returns the sorted hqslang names of all registered custom device-changing pragmas.
+pub fn registered_device_pragmas() -> Vec<String> {
+    let mut names: Vec<String> = match registry().read() {
+        Ok(handlers) => handlers.keys().cloned().collect(),
+        Err(_) => Vec::new(),
+    };
+    names.sort();
+    names
+}
+
+/// Applies a custom device-changing pragma to a device using the registered handler.
+///
+/// # Arguments
+///
+/// * `device` - The device to be changed.
+/// * `hqslang` - The hqslang name of the pragma.
+/// * `operation` - The pragma serialized with bincode.
+///
+/// # Returns
+///
+/// * `Some(Ok(()))` - The device has been changed.
+/// * `Some(Err(RoqoqoBackendError))` - The handler failed to apply the pragma.
+/// * `None` - No handler is registered for the pragma.
+pub(crate) fn apply_registered_pragma(
+    device: &mut TweezerDevice,
+    hqslang: &str,
+    operation: &[u8],
+) -> Option<Result<(), RoqoqoBackendError>> {
+    // Clone the handler out of the registry so it may itself use the registry.
+    let handler = match registry().read() {
+        Ok(handlers) => handlers.get(hqslang).cloned(),
+        Err(_) => return Some(Err(poisoned_registry())),
+    };
+    handler.map(|handler| handler.apply(device, operation))
+}
+
+fn poisoned_registry() -> RoqoqoBackendError {
+    RoqoqoBackendError::GenericError {
+        msg: "The device pragma handler registry is poisoned.".to_string(),
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Consistency checks of tweezer devices.
+//!
+//! [TweezerDevice::validate] collects every inconsistency of a device as a [DeviceIssue], so
+//! that a device built by hand, from a calibration file or downloaded from the WebAPI can be
+//! checked before a job is submitted. The issues are sorted by the Layout they are found in.
+
+use crate::tweezer_devices::{layout_tweezers, layout_tweezers_per_row, TweezerDevice};
+use itertools::Itertools;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// An inconsistency of a [TweezerDevice] found by [TweezerDevice::validate].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeviceIssue {
+    /// The current Layout is not present in the Layout register.
+    MissingCurrentLayout {
+        /// The name of the current Layout.
+        layout: String,
+    },
+    /// The default Layout is not present in the Layout register.
+    MissingDefaultLayout {
+        /// The name of the default Layout.
+        layout: String,
+    },
+    /// A Layout of the register could not be deserialized.
+    InvalidLayout {
+        /// The name of the Layout.
+        layout: String,
+        /// The deserialization error.
+        msg: String,
+    },
+    /// A gate time is negative or not finite.
+    InvalidGateTime {
+        /// The name of the Layout.
+        layout: String,
+        /// The hqslang name of the gate.
+        hqslang: String,
+        /// The tweezers of the gate.
+        tweezers: Vec<usize>,
+        /// The gate time.
+        gate_time: f64,
+    },
+    /// A two-qubit gate is only defined for one order of a tweezer pair.
+    AsymmetricTwoQubitGate {
+        /// The name of the Layout.
+        layout: String,
+        /// The hqslang name of the gate.
+        hqslang: String,
+        /// The first tweezer of the defined order.
+        tweezer0: usize,
+        /// The second tweezer of the defined order.
+        tweezer1: usize,
+    },
+    /// Shifts are allowed out of a tweezer that is not present in the Layout.
+    MissingShiftStart {
+        /// The name of the Layout.
+        layout: String,
+        /// The tweezer the shifts start from.
+        tweezer: usize,
+    },
+    /// A shift leads into a tweezer that is not present in the Layout.
+    OrphanShiftTarget {
+        /// The name of the Layout.
+        layout: String,
+        /// The tweezer the shift starts from.
+        start: usize,
+        /// The tweezer the shift leads into.
+        target: usize,
+    },
+    /// The tweezers per row of a Layout differ from those of the reference Layout, so the
+    /// device can not switch between them.
+    IncompatibleTweezersPerRow {
+        /// The name of the Layout.
+        layout: String,
+        /// The name of the current Layout, or of the default Layout if the current Layout is not set.
+        reference_layout: String,
+    },
+    /// A qubit is mapped to a tweezer that is not present in the current Layout.
+    MissingMappedTweezer {
+        /// The qubit.
+        qubit: usize,
+        /// The tweezer the qubit is mapped to.
+        tweezer: usize,
+    },
+}
+
+impl fmt::Display for DeviceIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceIssue::MissingCurrentLayout { layout } => write!(
+                f,
+                "The current Layout {} is not present in the Layout register.",
+                layout
+            ),
+            DeviceIssue::MissingDefaultLayout { layout } => write!(
+                f,
+                "The default Layout {} is not present in the Layout register.",
+                layout
+            ),
+            DeviceIssue::InvalidLayout { layout, msg } => {
+                write!(f, "Layout {} could not be deserialized: {}", layout, msg)
+            }
+            DeviceIssue::InvalidGateTime {
+                layout,
+                hqslang,
+                tweezers,
+                gate_time,
+            } => write!(
+                f,
+                "Gate {} on tweezers {:?} in Layout {} has the invalid gate time {}.",
+                hqslang, tweezers, layout, gate_time
+            ),
+            DeviceIssue::AsymmetricTwoQubitGate {
+                layout,
+                hqslang,
+                tweezer0,
+                tweezer1,
+            } => write!(
+                f,
+                "Gate {} in Layout {} is defined between tweezers {} and {} but not between tweezers {} and {}.",
+                hqslang, layout, tweezer0, tweezer1, tweezer1, tweezer0
+            ),
+            DeviceIssue::MissingShiftStart { layout, tweezer } => write!(
+                f,
+                "Shifts are allowed out of tweezer {} which is not present in Layout {}.",
+                tweezer, layout
+            ),
+            DeviceIssue::OrphanShiftTarget {
+                layout,
+                start,
+                target,
+            } => write!(
+                f,
+                "The shift from tweezer {} leads into tweezer {} which is not present in Layout {}.",
+                start, target, layout
+            ),
+            DeviceIssue::IncompatibleTweezersPerRow {
+                layout,
+                reference_layout,
+            } => write!(
+                f,
+                "The tweezers per row of Layout {} differ from those of Layout {}.",
+                layout, reference_layout
+            ),
+            DeviceIssue::MissingMappedTweezer { qubit, tweezer } => write!(
+                f,
+                "Qubit {} is mapped to tweezer {} which is not present in the current Layout.",
+                qubit, tweezer
+            ),
+        }
+    }
+}
+
+/// Returns all issues of a device, see [TweezerDevice::validate].
+pub(crate) fn device_issues(device: &TweezerDevice) -> Vec<DeviceIssue> {
+    let mut issues: Vec<DeviceIssue> = Vec::new();
+    let layouts: Vec<&str> = device.available_layouts().into_iter().sorted().collect();
+    if let Some(layout) = &device.current_layout {
+        if !layouts.contains(&layout.as_str()) {
+            issues.push(DeviceIssue::MissingCurrentLayout {
+                layout: layout.clone(),
+            });
+        }
+    }
+    if let Some(layout) = &device.default_layout {
+        if !layouts.contains(&layout.as_str()) {
+            issues.push(DeviceIssue::MissingDefaultLayout {
+                layout: layout.clone(),
+            });
+        }
+    }
+
+    // Switching Layouts is only possible between Layouts with the same tweezers per row
+    let reference_layout = [&device.current_layout, &device.default_layout]
+        .into_iter()
+        .flatten()
+        .find(|layout| layouts.contains(&layout.as_str()));
+    let reference_tweezers_per_row = reference_layout
+        .and_then(|layout| device.layout_info(layout).ok().flatten())
+        .and_then(layout_tweezers_per_row);
+
+    for layout in layouts {
+        let info = match device.layout_info(layout) {
+            Ok(Some(info)) => info,
+            Ok(None) => continue,
+            Err(err) => {
+                issues.push(DeviceIssue::InvalidLayout {
+                    layout: layout.to_string(),
+                    msg: err.to_string(),
+                });
+                continue;
+            }
+        };
+        let invalid_gate_time = |hqslang: &str, tweezers: Vec<usize>, gate_time: f64| {
+            (!gate_time.is_finite() || gate_time < 0.0).then(|| DeviceIssue::InvalidGateTime {
+                layout: layout.to_string(),
+                hqslang: hqslang.to_string(),
+                tweezers,
+                gate_time,
+            })
+        };
+        for (hqslang, times) in info
+            .tweezer_single_qubit_gate_times
+            .iter()
+            .sorted_by(|a, b| a.0.cmp(b.0))
+        {
+            for (tweezer, gate_time) in times.iter().sorted_by_key(|(tweezer, _)| **tweezer) {
+                issues.extend(invalid_gate_time(hqslang, vec![*tweezer], *gate_time));
+            }
+        }
+        for (hqslang, times) in info
+            .tweezer_two_qubit_gate_times
+            .iter()
+            .sorted_by(|a, b| a.0.cmp(b.0))
+        {
+            for ((tweezer0, tweezer1), gate_time) in
+                times.iter().sorted_by_key(|(tweezers, _)| **tweezers)
+            {
+                issues.extend(invalid_gate_time(
+                    hqslang,
+                    vec![*tweezer0, *tweezer1],
+                    *gate_time,
+                ));
+                if tweezer0 != tweezer1 && !times.contains_key(&(*tweezer1, *tweezer0)) {
+                    issues.push(DeviceIssue::AsymmetricTwoQubitGate {
+                        layout: layout.to_string(),
+                        hqslang: hqslang.clone(),
+                        tweezer0: *tweezer0,
+                        tweezer1: *tweezer1,
+                    });
+                }
+            }
+        }
+        for (hqslang, times) in info
+            .tweezer_three_qubit_gate_times
+            .iter()
+            .sorted_by(|a, b| a.0.cmp(b.0))
+        {
+            for ((tweezer0, tweezer1, tweezer2), gate_time) in
+                times.iter().sorted_by_key(|(tweezers, _)| **tweezers)
+            {
+                issues.extend(invalid_gate_time(
+                    hqslang,
+                    vec![*tweezer0, *tweezer1, *tweezer2],
+                    *gate_time,
+                ));
+            }
+        }
+        for (hqslang, times) in info
+            .tweezer_multi_qubit_gate_times
+            .iter()
+            .sorted_by(|a, b| a.0.cmp(b.0))
+        {
+            for (tweezers, gate_time) in times.iter().sorted_by(|a, b| a.0.cmp(b.0)) {
+                issues.extend(invalid_gate_time(hqslang, tweezers.clone(), *gate_time));
+            }
+        }
+
+        let tweezers: BTreeSet<usize> = layout_tweezers(info);
+        for (start, directions) in info
+            .allowed_tweezer_shifts
+            .iter()
+            .sorted_by_key(|(start, _)| **start)
+        {
+            if !tweezers.contains(start) {
+                issues.push(DeviceIssue::MissingShiftStart {
+                    layout: layout.to_string(),
+                    tweezer: *start,
+                });
+            }
+            for target in directions.iter().flatten().unique() {
+                if !tweezers.contains(target) {
+                    issues.push(DeviceIssue::OrphanShiftTarget {
+                        layout: layout.to_string(),
+                        start: *start,
+                        target: *target,
+                    });
+                }
+            }
+        }
+
+        if let (Some(reference_layout), Some(reference), Some(tweezers_per_row)) = (
+            reference_layout,
+            &reference_tweezers_per_row,
+            &layout_tweezers_per_row(info),
+        ) {
+            if reference_layout != layout && reference != tweezers_per_row {
+                issues.push(DeviceIssue::IncompatibleTweezersPerRow {
+                    layout: layout.to_string(),
+                    reference_layout: reference_layout.clone(),
+                });
+            }
+        }
+    }
+
+    // A missing current Layout is already reported above
+    let current_info = device
+        .current_layout
+        .as_ref()
+        .and_then(|layout| device.layout_info(layout).ok().flatten());
+    if let (Some(mapping), Some(info)) = (&device.qubit_to_tweezer, current_info) {
+        let tweezers = layout_tweezers(info);
+        for (qubit, tweezer) in mapping.iter().sorted_by_key(|(qubit, _)| **qubit) {
+            if !tweezers.contains(tweezer) {
+                issues.push(DeviceIssue::MissingMappedTweezer {
+                    qubit: *qubit,
+                    tweezer: *tweezer,
+                });
+            }
+        }
+    }
+    issues
+}
@@ -17,19 +17,140 @@
 //! QRyd devices can be physical hardware or simulators.
 
 use bincode::deserialize;
+use image::DynamicImage;
+use itertools::{iproduct, Itertools};
 use ndarray::Array2;
 use std::collections::HashMap;
 use std::env;
+use std::f64::consts::PI;
 
 use roqoqo::devices::{Device, GenericDevice};
 use roqoqo::operations::*;
 use roqoqo::RoqoqoBackendError;
 
-use crate::{tweezer_devices::TweezerDevice, PragmaDeactivateQRydQubit, PragmaShiftQubitsTweezers};
+use crate::dense_gate_times::DenseGateTimes;
+use crate::device_pragma_handlers::apply_registered_pragma;
+use crate::gate_time_cache::GateTimeCache;
+use crate::{
+    tweezer_devices::{
+        square_lattice_position, triangular_lattice_position, ArrayPreparation, AtomLoss,
+        DrawOptions, NativeGates, TrivialMappingPolicy, TweezerDevice, TweezerLayoutInfo,
+    },
+    PragmaDeactivateQRydQubit, PragmaSetPhaseRelation,
+};
+
+/// The gate time of every available gate of the EmulatorDevice.
+pub const EMULATOR_GATE_TIME: f64 = 1.0;
+
+/// The single-qubit gates known to the EmulatorDevice.
+pub static EMULATOR_SINGLE_QUBIT_GATES: [&str; 25] = [
+    "SingleQubitGate",
+    "RotateZ",
+    "RotateX",
+    "RotateY",
+    "PauliX",
+    "PauliY",
+    "PauliZ",
+    "SqrtPauliX",
+    "InvSqrtPauliX",
+    "Hadamard",
+    "SGate",
+    "TGate",
+    "PhaseShiftState1",
+    "PhaseShiftState0",
+    "RotateAroundSphericalAxis",
+    "RotateXY",
+    "GPi",
+    "GPi2",
+    "Identity",
+    "SqrtPauliY",
+    "InvSqrtPauliY",
+    "InvSGate",
+    "InvTGate",
+    "SXGate",
+    "InvSXGate",
+];
+
+/// The two-qubit gates known to the EmulatorDevice.
+pub static EMULATOR_TWO_QUBIT_GATES: [&str; 25] = [
+    "CNOT",
+    "SWAP",
+    "ISwap",
+    "FSwap",
+    "SqrtISwap",
+    "InvSqrtISwap",
+    "XY",
+    "ControlledPhaseShift",
+    "ControlledPauliY",
+    "ControlledPauliZ",
+    "MolmerSorensenXX",
+    "VariableMSXX",
+    "GivensRotation",
+    "GivensRotationLittleEndian",
+    "Qsim",
+    "Fsim",
+    "SpinInteraction",
+    "Bogoliubov",
+    "PMInteraction",
+    "ComplexPMInteraction",
+    "PhaseShiftedControlledZ",
+    "PhaseShiftedControlledPhase",
+    "ControlledRotateX",
+    "ControlledRotateXY",
+    "EchoCrossResonance",
+];
+
+/// The three-qubit gates known to the EmulatorDevice.
+pub static EMULATOR_THREE_QUBIT_GATES: [&str; 6] = [
+    "ControlledControlledPauliZ",
+    "ControlledControlledPhaseShift",
+    "Toffoli",
+    "ControlledSWAP",
+    "PhaseShiftedControlledControlledZ",
+    "PhaseShiftedControlledControlledPhase",
+];
+
+/// Global noise parameters of a noisy emulator.
+///
+/// The parameters are reported by the WebAPI for noisy emulators and describe all qubits of the
+/// device. Unset parameters are not modeled by the emulator.
+#[derive(Debug, Default, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct EmulatorNoiseParameters {
+    /// Damping rate of the qubits.
+    #[serde(default)]
+    pub damping: Option<f64>,
+    /// Dephasing rate of the qubits.
+    #[serde(default)]
+    pub dephasing: Option<f64>,
+    /// Probability of a state preparation and measurement error.
+    #[serde(default)]
+    pub spam_error: Option<f64>,
+}
+
+/// Geometry of the Layout created by [EmulatorDevice::into_tweezer_device].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmulatorLayoutSpec {
+    /// Square lattice of tweezers, see [TweezerDevice::square_lattice].
+    Square {
+        /// The number of rows of the lattice.
+        rows: usize,
+        /// The number of tweezers per row.
+        columns: usize,
+    },
+    /// Triangular lattice of tweezers, see [TweezerDevice::triangular_lattice].
+    Triangular {
+        /// The number of rows of the lattice.
+        rows: usize,
+        /// The number of tweezers per row.
+        columns: usize,
+    },
+}
 
 /// Emulator Device
 ///
 #[derive(Debug, PartialEq, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct EmulatorDevice {
     /// Internal TweezerDevice instance.
     pub internal: TweezerDevice,
@@ -68,8 +189,20 @@ impl EmulatorDevice {
                 default_layout: None,
                 seed,
                 allow_reset: false,
+                symmetric_two_qubit_gate_times: false,
+                native_gates: NativeGates::default(),
+                max_qubits: None,
                 device_name: String::from("qryd_tweezer_device"),
                 available_gates: Some(vec![]),
+                allowed_edges: None,
+                emulator_noise: None,
+                tweezer_noise: HashMap::new(),
+                array_preparation: ArrayPreparation::default(),
+                atom_loss: AtomLoss::default(),
+                phi_theta_relations: HashMap::new(),
+                gate_time_cache: GateTimeCache::default(),
+                dense_gate_times: DenseGateTimes::default(),
+                serialized_layouts: HashMap::new(),
             },
         }
     }
@@ -221,6 +354,112 @@ impl EmulatorDevice {
         }
     }
 
+    /// Converts the EmulatorDevice into a TweezerDevice with the given geometry.
+    ///
+    /// The TweezerDevice contains the Layout "default" of the lattice, which is set as the
+    /// current and the default Layout. The available gates become the native gates of the
+    /// TweezerDevice and keep the gate time of the emulator: single-qubit gates on every
+    /// tweezer, two-qubit gates between neighbouring tweezers and three-qubit gates on
+    /// tweezer trios that are pairwise neighbours. Multi-qubit gates stay native without gate
+    /// times. Qubits can be shifted along the rows of the lattice. The connectivity restriction
+    /// of the emulator is replaced by the lattice, the qubit -> tweezer mapping, the qubit
+    /// limit, the noise parameters and the phase relations are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout_spec` - The geometry of the Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TweezerDevice)` - The TweezerDevice with the lattice Layout.
+    /// * `Err(RoqoqoBackendError)` - The lattice is empty, no single-qubit gate is available or a
+    ///                               qubit is mapped to a tweezer outside of the lattice.
+    pub fn into_tweezer_device(
+        self,
+        layout_spec: EmulatorLayoutSpec,
+    ) -> Result<TweezerDevice, RoqoqoBackendError> {
+        let to_vec = |gates: Vec<&str>| -> Vec<String> {
+            gates.into_iter().map(|gate| gate.to_string()).collect()
+        };
+        let native_gates = NativeGates {
+            single_qubit_gates: to_vec(self.get_available_single_qubit_gates()),
+            two_qubit_gates: to_vec(self.get_available_two_qubit_gates()),
+            three_qubit_gates: to_vec(self.get_available_three_qubit_gates()),
+            multi_qubit_gates: to_vec(self.get_available_multi_qubit_gates()),
+        };
+        let gate_times: HashMap<String, f64> = native_gates
+            .single_qubit_gates
+            .iter()
+            .chain(&native_gates.two_qubit_gates)
+            .map(|gate| (gate.clone(), EMULATOR_GATE_TIME))
+            .collect();
+        let three_qubit_gates = native_gates.three_qubit_gates.clone();
+        let (rows, columns) = match layout_spec {
+            EmulatorLayoutSpec::Square { rows, columns }
+            | EmulatorLayoutSpec::Triangular { rows, columns } => (rows, columns),
+        };
+        let position: fn(usize, usize) -> (f64, f64) = match layout_spec {
+            EmulatorLayoutSpec::Square { .. } => square_lattice_position,
+            EmulatorLayoutSpec::Triangular { .. } => triangular_lattice_position,
+        };
+        let mut device =
+            TweezerDevice::lattice(rows, columns, native_gates, &gate_times, position)?;
+
+        if !three_qubit_gates.is_empty() {
+            let positions: Vec<(f64, f64)> = iproduct!(0..rows, 0..columns)
+                .map(|(row, column)| position(row, column))
+                .collect();
+            // Small tolerance for the rounding of the triangular lattice coordinates
+            let neighbours = |tweezer0: usize, tweezer1: usize| {
+                let (x0, y0) = positions[tweezer0];
+                let (x1, y1) = positions[tweezer1];
+                (x0 - x1).hypot(y0 - y1) <= 1.0 + 1e-9
+            };
+            for trio in (0..positions.len()).combinations(3) {
+                if !(neighbours(trio[0], trio[1])
+                    && neighbours(trio[0], trio[2])
+                    && neighbours(trio[1], trio[2]))
+                {
+                    continue;
+                }
+                for order in trio.iter().permutations(3) {
+                    for gate in three_qubit_gates.iter() {
+                        device.set_tweezer_three_qubit_gate_time(
+                            gate,
+                            *order[0],
+                            *order[1],
+                            *order[2],
+                            EMULATOR_GATE_TIME,
+                            Some("default".to_string()),
+                        )?;
+                    }
+                }
+            }
+        }
+
+        let emulator = self.internal;
+        device.controlled_z_phase_relation = emulator.controlled_z_phase_relation;
+        device.controlled_phase_phase_relation = emulator.controlled_phase_phase_relation;
+        device.seed = emulator.seed;
+        device.allow_reset = emulator.allow_reset;
+        device.device_name = emulator.device_name;
+        device.tweezer_noise = emulator.tweezer_noise;
+        device.array_preparation = emulator.array_preparation;
+        device.atom_loss = emulator.atom_loss;
+        device.phi_theta_relations = emulator.phi_theta_relations;
+        device.max_qubits = emulator.max_qubits;
+        match emulator
+            .qubit_to_tweezer
+            .filter(|mapping| !mapping.is_empty())
+        {
+            Some(mapping) => device.switch_layout_with_mapping("default", mapping)?,
+            None => {
+                device.switch_layout_with_policy("default", TrivialMappingPolicy::AllTweezers)?
+            }
+        }
+        Ok(device)
+    }
+
     /// Returns a vector of all available Layout names.
     ///
     /// Implemented for compatibility reasons, as the returning vector
@@ -266,17 +505,221 @@ impl EmulatorDevice {
     /// * `Ok(())` - The gate has been successfully added to the available ones.
     /// * `Err(RoqoqoBackendError)` - The gate does not exist.
     pub fn add_available_gate(&mut self, hqslang: &str) -> Result<(), RoqoqoBackendError> {
-        if !AVAILABLE_GATES_HQSLANG.contains(&hqslang) {
-            return Err(RoqoqoBackendError::GenericError {
-                msg: format!("Gate '{}' does not exist.", hqslang),
-            });
-        }
-        if let Some(available) = self.internal.available_gates.as_mut() {
+        check_gate_name(hqslang)?;
+        let available = self.internal.available_gates.get_or_insert_with(Vec::new);
+        if !available.iter().any(|gate| gate == hqslang) {
             available.push(hqslang.to_string());
         }
         Ok(())
     }
 
+    /// Removes a gate from the available list.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate has been successfully removed from the available ones.
+    /// * `Err(RoqoqoBackendError)` - The gate is not available in the device.
+    pub fn remove_available_gate(&mut self, hqslang: &str) -> Result<(), RoqoqoBackendError> {
+        match self
+            .internal
+            .available_gates
+            .as_mut()
+            .and_then(|available| {
+                available
+                    .iter()
+                    .position(|gate| gate == hqslang)
+                    .map(|index| available.remove(index))
+            }) {
+            Some(_) => Ok(()),
+            None => Err(RoqoqoBackendError::GenericError {
+                msg: format!("Gate '{}' is not available in the device.", hqslang),
+            }),
+        }
+    }
+
+    /// Replaces the available list by the given gates.
+    ///
+    /// Duplicated names are only added once. The available list is left unchanged if
+    /// any of the gates does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang_names` - The hqslang names of the gates.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The available list has been successfully replaced.
+    /// * `Err(RoqoqoBackendError)` - One of the gates does not exist.
+    pub fn set_available_gates(
+        &mut self,
+        hqslang_names: &[&str],
+    ) -> Result<(), RoqoqoBackendError> {
+        let mut available: Vec<String> = Vec::with_capacity(hqslang_names.len());
+        for hqslang in hqslang_names {
+            check_gate_name(hqslang)?;
+            if !available.iter().any(|gate| gate == hqslang) {
+                available.push(hqslang.to_string());
+            }
+        }
+        self.internal.available_gates = Some(available);
+        Ok(())
+    }
+
+    /// Set the global noise parameters of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `noise` - The noise parameters, None for an ideal emulator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The noise parameters have been set.
+    /// * `Err(RoqoqoBackendError)` - A rate is negative or not finite, or the SPAM error is not a probability.
+    pub fn set_noise_parameters(
+        &mut self,
+        noise: Option<EmulatorNoiseParameters>,
+    ) -> Result<(), RoqoqoBackendError> {
+        if let Some(parameters) = &noise {
+            for (name, rate) in [
+                ("damping", parameters.damping),
+                ("dephasing", parameters.dephasing),
+            ] {
+                if let Some(rate) = rate.filter(|rate| !rate.is_finite() || *rate < 0.0) {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "The {} rate needs to be a non-negative number, got {}.",
+                            name, rate
+                        ),
+                    });
+                }
+            }
+            if let Some(error) = parameters
+                .spam_error
+                .filter(|error| error.is_nan() || !(0.0..=1.0).contains(error))
+            {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!("The SPAM error needs to be between 0 and 1, got {}.", error),
+                });
+            }
+        }
+        self.internal.emulator_noise = noise;
+        Ok(())
+    }
+
+    /// Returns the global noise parameters of the device, None for an ideal emulator.
+    pub fn noise_parameters(&self) -> Option<&EmulatorNoiseParameters> {
+        self.internal.emulator_noise.as_ref()
+    }
+
+    /// Returns the damping rate of the qubits, None if damping is not modeled.
+    pub fn damping(&self) -> Option<f64> {
+        self.internal.emulator_noise.and_then(|noise| noise.damping)
+    }
+
+    /// Returns the dephasing rate of the qubits, None if dephasing is not modeled.
+    pub fn dephasing(&self) -> Option<f64> {
+        self.internal
+            .emulator_noise
+            .and_then(|noise| noise.dephasing)
+    }
+
+    /// Returns the SPAM error probability, None if SPAM errors are not modeled.
+    pub fn spam_error(&self) -> Option<f64> {
+        self.internal
+            .emulator_noise
+            .and_then(|noise| noise.spam_error)
+    }
+
+    /// Returns whether the device is a noisy emulator, i.e. any noise parameter is set.
+    pub fn is_noisy(&self) -> bool {
+        self.damping().is_some() || self.dephasing().is_some() || self.spam_error().is_some()
+    }
+
+    /// Limits the number of qubits of the device.
+    ///
+    /// The limit is reported by `number_qubits()` and stored as the hardware capacity of the
+    /// internal TweezerDevice, so the SimulatorBackend and the APIBackend reject circuits
+    /// exceeding it. Without a limit the number of qubits is derived from the
+    /// qubit -> tweezer mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_qubits` - The maximal number of qubits, None to remove the limit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The limit has been successfully set.
+    /// * `Err(RoqoqoBackendError)` - A mapped qubit exceeds the new limit.
+    pub fn set_number_qubits(
+        &mut self,
+        number_qubits: Option<usize>,
+    ) -> Result<(), RoqoqoBackendError> {
+        self.internal.set_max_qubits(number_qubits)
+    }
+
+    /// Restricts the two-qubit connectivity of the device to the given edges.
+    ///
+    /// The edges are undirected, a two-qubit gate is available between the qubits of an edge
+    /// in both orders. Passing None restores the all-to-all connectivity.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - The allowed edges between qubits, None for all-to-all connectivity.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The connectivity has been successfully restricted.
+    /// * `Err(RoqoqoBackendError)` - An edge connects a qubit to itself.
+    pub fn set_allowed_edges(
+        &mut self,
+        edges: Option<Vec<(usize, usize)>>,
+    ) -> Result<(), RoqoqoBackendError> {
+        let normalized = match edges {
+            Some(edges) => {
+                let mut normalized: Vec<(usize, usize)> = Vec::with_capacity(edges.len());
+                for (qubit0, qubit1) in edges {
+                    if qubit0 == qubit1 {
+                        return Err(RoqoqoBackendError::GenericError {
+                            msg: format!(
+                                "The edge ({}, {}) connects a qubit to itself.",
+                                qubit0, qubit1
+                            ),
+                        });
+                    }
+                    normalized.push((qubit0.min(qubit1), qubit0.max(qubit1)));
+                }
+                normalized.sort_unstable();
+                normalized.dedup();
+                Some(normalized)
+            }
+            None => None,
+        };
+        self.internal.allowed_edges = normalized;
+        Ok(())
+    }
+
+    /// Returns the allowed two-qubit edges of the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&[(usize, usize)])` - The allowed edges, sorted and with the smaller qubit first.
+    /// * `None` - The device has all-to-all connectivity.
+    pub fn allowed_edges(&self) -> Option<&[(usize, usize)]> {
+        self.internal.allowed_edges.as_deref()
+    }
+
+    // Returns whether a two-qubit gate is allowed between the given qubits.
+    fn edge_allowed(&self, control: usize, target: usize) -> bool {
+        match &self.internal.allowed_edges {
+            Some(edges) => edges.contains(&(control.min(target), control.max(target))),
+            None => true,
+        }
+    }
+
     /// Set whether the device allows PragmaActiveReset operations or not.
     ///
     /// # Arguments
@@ -319,6 +762,63 @@ impl EmulatorDevice {
         }
     }
 
+    /// Get the names of the available single-qubit gates in the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<&str>` - Vector of the names of the available single-qubit gates.
+    pub fn get_available_single_qubit_gates(&self) -> Vec<&str> {
+        self.available_gates_filtered(|gate| EMULATOR_SINGLE_QUBIT_GATES.contains(&gate))
+    }
+
+    /// Get the names of the available two-qubit gates in the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<&str>` - Vector of the names of the available two-qubit gates.
+    pub fn get_available_two_qubit_gates(&self) -> Vec<&str> {
+        self.available_gates_filtered(|gate| EMULATOR_TWO_QUBIT_GATES.contains(&gate))
+    }
+
+    /// Get the names of the available three-qubit gates in the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<&str>` - Vector of the names of the available three-qubit gates.
+    pub fn get_available_three_qubit_gates(&self) -> Vec<&str> {
+        self.available_gates_filtered(|gate| EMULATOR_THREE_QUBIT_GATES.contains(&gate))
+    }
+
+    /// Get the names of the available multi-qubit gates in the device.
+    ///
+    /// Every available gate that is neither a single-, two- nor three-qubit gate
+    /// is considered a multi-qubit gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<&str>` - Vector of the names of the available multi-qubit gates.
+    pub fn get_available_multi_qubit_gates(&self) -> Vec<&str> {
+        self.available_gates_filtered(|gate| {
+            !EMULATOR_SINGLE_QUBIT_GATES.contains(&gate)
+                && !EMULATOR_TWO_QUBIT_GATES.contains(&gate)
+                && !EMULATOR_THREE_QUBIT_GATES.contains(&gate)
+        })
+    }
+
+    // Returns the names of the available gates matching the filter.
+    fn available_gates_filtered<F>(&self, filter: F) -> Vec<&str>
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.internal
+            .available_gates
+            .iter()
+            .flatten()
+            .map(|gate| gate.as_str())
+            .filter(|gate| filter(gate))
+            .collect()
+    }
+
     /// Deactivate the given qubit in the device.
     ///
     /// # Arguments
@@ -413,6 +913,87 @@ impl EmulatorDevice {
         None
     }
 
+    /// Creates a graph representing the EmulatorDevice.
+    ///
+    /// The qubits are drawn as nodes on a circle, the edges connect the qubits a two-qubit gate
+    /// is available between, respecting the connectivity restriction of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels_per_point` - The quality of the image.
+    /// * `file_save_path` - Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics with the `vector-drawing` feature.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DynamicImage)` - The representation of the device.
+    /// * `Err(RoqoqoBackendError)` - The device has no qubits or an error occurred during the compilation.
+    pub fn draw(
+        &self,
+        pixels_per_point: Option<f32>,
+        file_save_path: &Option<String>,
+    ) -> Result<DynamicImage, RoqoqoBackendError> {
+        let options = DrawOptions {
+            show_tweezer_labels: false,
+            ..DrawOptions::default()
+        };
+        self.draw_with_options(pixels_per_point, file_save_path, &options)
+    }
+
+    /// Creates a graph representing the EmulatorDevice with the given drawing options.
+    ///
+    /// The nodes are the qubits of the device, the tweezer labels are the qubit indices as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels_per_point` - The quality of the image, ignored if `options.dpi` is set.
+    /// * `file_save_path` - Path to save the image to, `.svg` and `.pdf` files are saved as vector graphics with the `vector-drawing` feature.
+    /// * `options` - The labels, legend and resolution of the drawing.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DynamicImage)` - The representation of the device.
+    /// * `Err(RoqoqoBackendError)` - The device has no qubits, the DPI is not positive or an error
+    ///                               occurred during the compilation.
+    pub fn draw_with_options(
+        &self,
+        pixels_per_point: Option<f32>,
+        file_save_path: &Option<String>,
+        options: &DrawOptions,
+    ) -> Result<DynamicImage, RoqoqoBackendError> {
+        let number_qubits = self.number_qubits();
+        if number_qubits == 0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "The EmulatorDevice has no qubits to draw.".to_string(),
+            });
+        }
+        // Neighbouring qubits on the circle are about one unit apart
+        let radius = (number_qubits as f64 / (2.0 * PI)).max(0.5);
+        let mut layout = TweezerLayoutInfo {
+            tweezer_coordinates: (0..number_qubits)
+                .map(|qubit| {
+                    let angle = 2.0 * PI * qubit as f64 / number_qubits as f64;
+                    (qubit, (radius * angle.cos(), radius * angle.sin()))
+                })
+                .collect(),
+            ..TweezerLayoutInfo::default()
+        };
+        if !self.get_available_two_qubit_gates().is_empty() {
+            let edges: HashMap<(usize, usize), f64> = (0..number_qubits)
+                .tuple_combinations()
+                .filter(|(qubit0, qubit1)| self.edge_allowed(*qubit0, *qubit1))
+                .map(|edge| (edge, EMULATOR_GATE_TIME))
+                .collect();
+            layout
+                .tweezer_two_qubit_gate_times
+                .insert("TwoQubitGate".to_string(), edges);
+        }
+        let drawing_device = TweezerDevice {
+            qubit_to_tweezer: Some((0..number_qubits).map(|qubit| (qubit, qubit)).collect()),
+            ..TweezerDevice::default()
+        };
+        drawing_device.draw_layout_info(&layout, pixels_per_point, file_save_path, options)
+    }
+
     /// Returns the number of total tweezer positions in the device.
     ///
     /// # Returns
@@ -436,20 +1017,34 @@ impl EmulatorDevice {
     }
 }
 
+/// Returns an error if the gate is not a known roqoqo gate.
+fn check_gate_name(hqslang: &str) -> Result<(), RoqoqoBackendError> {
+    if AVAILABLE_GATES_HQSLANG.contains(&hqslang) {
+        Ok(())
+    } else {
+        Err(RoqoqoBackendError::GenericError {
+            msg: format!("Gate '{}' does not exist.", hqslang),
+        })
+    }
+}
+
 impl Device for EmulatorDevice {
     fn single_qubit_gate_time(&self, hqslang: &str, _qubit: &usize) -> Option<f64> {
         if let Some(available) = &self.internal.available_gates {
             if available.contains(&hqslang.to_string()) {
-                return Some(1.0);
+                return Some(EMULATOR_GATE_TIME);
             }
         }
         None
     }
 
-    fn two_qubit_gate_time(&self, hqslang: &str, _control: &usize, _target: &usize) -> Option<f64> {
+    fn two_qubit_gate_time(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        if !self.edge_allowed(*control, *target) {
+            return None;
+        }
         if let Some(available) = &self.internal.available_gates {
             if available.contains(&hqslang.to_string()) {
-                return Some(1.0);
+                return Some(EMULATOR_GATE_TIME);
             }
         }
         None
@@ -464,7 +1059,7 @@ impl Device for EmulatorDevice {
     ) -> Option<f64> {
         if let Some(available) = &self.internal.available_gates {
             if available.contains(&hqslang.to_string()) {
-                return Some(1.0);
+                return Some(EMULATOR_GATE_TIME);
             }
         }
         None
@@ -473,7 +1068,7 @@ impl Device for EmulatorDevice {
     fn multi_qubit_gate_time(&self, hqslang: &str, _qubits: &[usize]) -> Option<f64> {
         if let Some(available) = &self.internal.available_gates {
             if available.contains(&hqslang.to_string()) {
-                return Some(1.0);
+                return Some(EMULATOR_GATE_TIME);
             }
         }
         None
@@ -485,20 +1080,26 @@ impl Device for EmulatorDevice {
     }
 
     fn number_qubits(&self) -> usize {
-        self.internal.number_qubits()
+        self.internal
+            .max_qubits
+            .unwrap_or_else(|| self.internal.number_qubits())
     }
 
+    // Devices with all-to-all connectivity report no edges.
     fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
-        vec![]
+        self.internal.allowed_edges.clone().unwrap_or_default()
     }
 
+    // The EmulatorDevice has a single layout with all-to-all connectivity: only the deactivation
+    // of qubits and phase relation changes are supported, layout changes and qubit shifts are
+    // rejected.
     fn change_device(&mut self, hqslang: &str, operation: &[u8]) -> Result<(), RoqoqoBackendError> {
         match hqslang {
-            "PragmaChangeQRydLayout" => Err(RoqoqoBackendError::GenericError {
-                msg: "Operation PragmaChangeQRydLayout not supported in EmulatorDevice.".to_string(),
-            }),
-            "PragmaSwitchDeviceLayout" => Err(RoqoqoBackendError::GenericError {
-                msg: "Operation PragmaSwitchDeviceLayout not supported in EmulatorDevice.".to_string(),
+            "PragmaChangeQRydLayout" | "PragmaSwitchDeviceLayout" => Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Operation {} not supported in EmulatorDevice. The EmulatorDevice has a single layout with all-to-all connectivity.",
+                    hqslang
+                ),
             }),
             "PragmaDeactivateQRydQubit" => {
                 let de_change_layout: Result<PragmaDeactivateQRydQubit, Box<bincode::ErrorKind>> =
@@ -513,102 +1114,36 @@ impl Device for EmulatorDevice {
                     }),
                 }
             },
-            "PragmaShiftQRydQubit" => Err(RoqoqoBackendError::GenericError {
-                msg: "Operation PragmaShiftQRydQubit not supported in EmulatorDevice. Please use PragmaShiftQubitsTweezers.".to_string(),
-            }),
-            "PragmaShiftQubitsTweezers" => {
-                let de_shift_qubits_tweezers: Result<
-                    PragmaShiftQubitsTweezers,
-                    Box<bincode::ErrorKind>,
-                > = deserialize(operation);
-                match de_shift_qubits_tweezers {
-                    Ok(pragma) => {
-                        // Check if the there are qubits to move
-                        if self.internal.qubit_to_tweezer.is_none() {
-                            return Err(RoqoqoBackendError::GenericError {
-                                msg: "The device qubit -> tweezer mapping is empty: no qubits to shift.".to_string(),
-                            });
-                        }
-                        // Start applying the shifts
-                        if let Some(map) = &mut self.internal.qubit_to_tweezer {
-                            for (shift_start, shift_end) in &pragma.shifts {
-                                if let Some(qubit_to_move) =
-                                    map.iter()
-                                        .find_map(|(&qbt, &twz)| if twz == *shift_start { Some(qbt) } else { None })
-                                {
-                                    // Move the qubit into the new tweezer
-                                    map.remove(&qubit_to_move);
-                                    map.insert(qubit_to_move, *shift_end);
-                                }
-                            }
-                        }
-                        Ok(())
-                    }
+            "PragmaSetPhaseRelation" => {
+                let de_set_phase_relation: Result<PragmaSetPhaseRelation, Box<bincode::ErrorKind>> =
+                    deserialize(operation);
+                match de_set_phase_relation {
+                    Ok(pragma) => self
+                        .internal
+                        .set_phase_relations(&pragma.cz_relation, &pragma.cp_relation),
                     Err(_) => Err(RoqoqoBackendError::GenericError {
-                        msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+                        msg: "Wrapped operation not supported in EmulatorDevice".to_string(),
                     }),
                 }
             },
-            _ => Err(RoqoqoBackendError::GenericError {
-                msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+            "PragmaShiftQRydQubit" | "PragmaShiftQubitsTweezers" | "PragmaSwapTweezers" | "PragmaMoveToZone" => Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Operation {} not supported in EmulatorDevice. The EmulatorDevice has all-to-all connectivity, qubits do not need to be shifted.",
+                    hqslang
+                ),
+            }),
+            _ => apply_registered_pragma(&mut self.internal, hqslang, operation).unwrap_or_else(|| {
+                Err(RoqoqoBackendError::GenericError {
+                    msg: "Wrapped operation not supported in EmulatorDevice".to_string(),
+                })
             }),
         }
     }
 
     fn to_generic_device(&self) -> GenericDevice {
         let mut new_generic_device = GenericDevice::new(self.number_qubits());
-        let single_qubit_gates_names = vec![
-            "SingleQubitGate",
-            "RotateZ",
-            "RotateX",
-            "RotateY",
-            "PauliX",
-            "PauliY",
-            "PauliZ",
-            "SqrtPauliX",
-            "InvSqrtPauliX",
-            "Hadamard",
-            "SGate",
-            "TGate",
-            "PhaseShiftState1",
-            "PhaseShiftState0",
-            "RotateAroundSphericalAxis",
-            "RotateXY",
-            "GPi",
-            "GPi2",
-            "Identity",
-            "SqrtPauliY",
-            "InvSqrtPauliY",
-        ];
-        let two_qubit_gates_names = vec![
-            "CNOT",
-            "SWAP",
-            "ISwap",
-            "FSwap",
-            "SqrtISwap",
-            "InvSqrtISwap",
-            "XY",
-            "ControlledPhaseShift",
-            "ControlledPauliY",
-            "ControlledPauliZ",
-            "MolmerSorensenXX",
-            "VariableMSXX",
-            "GivensRotation",
-            "GivensRotationLittleEndian",
-            "Qsim",
-            "Fsim",
-            "SpinInteraction",
-            "Bogoliubov",
-            "PMInteraction",
-            "ComplexPMInteraction",
-            "PhaseShiftedControlledZ",
-            "PhaseShiftedControlledPhase",
-            "ControlledRotateX",
-            "ControlledRotateXY",
-            "EchoCrossResonance",
-        ];
-
-        for single_qubit_gate_name in single_qubit_gates_names {
+
+        for single_qubit_gate_name in EMULATOR_SINGLE_QUBIT_GATES {
             for i in 0..self.number_qubits() {
                 new_generic_device
                     .set_single_qubit_gate_time(single_qubit_gate_name, i, 1.0)
@@ -616,10 +1151,10 @@ impl Device for EmulatorDevice {
             }
         }
 
-        for two_qubit_gate_name in two_qubit_gates_names {
+        for two_qubit_gate_name in EMULATOR_TWO_QUBIT_GATES {
             for i in 0..self.number_qubits() {
                 for j in 0..self.number_qubits() {
-                    if i != j {
+                    if i != j && self.edge_allowed(i, j) {
                         new_generic_device
                             .set_two_qubit_gate_time(two_qubit_gate_name, i, j, 1.0)
                             .unwrap();
@@ -0,0 +1,195 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Execution on the QRyd WebAPI with a local simulation as fallback.
+//!
+//! The [FallbackBackend] runs every circuit on the WebAPI first. When the WebAPI can not be
+//! reached, or the job fails if the policy allows it, the circuit is simulated locally instead.
+//! The path that produced each result is recorded in the run metadata of the backend.
+
+use crate::{APIBackend, SimulatorBackend};
+use roqoqo::backends::{EvaluatingBackend, RegisterResult};
+use roqoqo::operations::Operation;
+use roqoqo::registers::Registers;
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::sync::{Arc, Mutex};
+
+/// Conditions under which the [FallbackBackend] simulates a circuit locally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FallbackPolicy {
+    /// Never simulate locally, errors of the WebAPI are returned.
+    Never,
+    /// Simulate locally when the WebAPI can not be reached or the access token is missing.
+    #[default]
+    OnUnreachable,
+    /// Simulate locally on any error of the WebAPI, including rejected, failed, cancelled and
+    /// timed out jobs.
+    OnAnyError,
+}
+
+impl FallbackPolicy {
+    /// Returns true if the policy falls back to the simulator for the error of the WebAPI.
+    fn falls_back(&self, error: &RoqoqoBackendError) -> bool {
+        match self {
+            FallbackPolicy::Never => false,
+            FallbackPolicy::OnUnreachable => matches!(
+                error,
+                RoqoqoBackendError::NetworkError { .. }
+                    | RoqoqoBackendError::MissingAuthentication { .. }
+            ),
+            FallbackPolicy::OnAnyError => true,
+        }
+    }
+}
+
+/// The backend that produced the result of a run of the [FallbackBackend].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionPath {
+    /// The circuit has been run on the QRyd WebAPI.
+    WebAPI,
+    /// The circuit has been simulated locally by the SimulatorBackend.
+    Simulator,
+}
+
+/// Metadata of one circuit run by the [FallbackBackend].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FallbackRunMetadata {
+    /// The backend that produced the result.
+    pub path: ExecutionPath,
+    /// The error of the WebAPI that caused the local simulation, None if the WebAPI succeeded.
+    pub api_error: Option<String>,
+}
+
+/// Backend running circuits on the QRyd WebAPI and simulating them locally on failure.
+///
+/// Implements the [roqoqo::backends::EvaluatingBackend] trait, so that measurements and
+/// QuantumPrograms can be run with it. Each circuit is first posted to the WebAPI, the
+/// [FallbackPolicy] decides which errors cause a local simulation with the SimulatorBackend.
+/// The simulator should use a device model matching the device of the WebAPI backend.
+///
+/// The metadata of every run is appended to the run metadata of the backend, which is shared
+/// between clones, so that the paths of the circuits of a program run with a clone of the
+/// backend can be read from the original.
+#[derive(Debug, Clone)]
+pub struct FallbackBackend {
+    /// The backend running the circuits on the QRyd WebAPI.
+    pub api_backend: APIBackend,
+    /// The backend simulating the circuits locally.
+    pub simulator_backend: SimulatorBackend,
+    /// The conditions under which circuits are simulated locally.
+    pub policy: FallbackPolicy,
+    /// Metadata of all runs, in the order of the runs.
+    run_metadata: Arc<Mutex<Vec<FallbackRunMetadata>>>,
+}
+
+impl FallbackBackend {
+    /// Creates a new FallbackBackend.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_backend` - The backend running the circuits on the QRyd WebAPI.
+    /// * `simulator_backend` - The backend simulating the circuits locally.
+    /// * `policy` - The conditions under which circuits are simulated locally.
+    pub fn new(
+        api_backend: APIBackend,
+        simulator_backend: SimulatorBackend,
+        policy: FallbackPolicy,
+    ) -> Self {
+        Self {
+            api_backend,
+            simulator_backend,
+            policy,
+            run_metadata: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Runs a circuit and returns the output registers with the metadata of the run.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit` - The circuit to run.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Registers, FallbackRunMetadata))` - The output registers and the path that produced them.
+    /// * `Err(RoqoqoBackendError)` - The WebAPI failed and the policy does not allow a local simulation,
+    ///                               or the local simulation failed.
+    pub fn run_circuit_with_metadata(
+        &self,
+        circuit: &Circuit,
+    ) -> Result<(Registers, FallbackRunMetadata), RoqoqoBackendError> {
+        let api_error = match self.api_backend.run_circuit(circuit) {
+            Ok(registers) => {
+                return Ok((
+                    registers,
+                    self.record(FallbackRunMetadata {
+                        path: ExecutionPath::WebAPI,
+                        api_error: None,
+                    }),
+                ))
+            }
+            Err(err) if self.policy.falls_back(&err) => err,
+            Err(err) => return Err(err),
+        };
+        #[cfg(feature = "tracing")]
+        tracing::warn!(error = %api_error, "QRyd WebAPI failed, simulating the circuit locally");
+        let registers = self.simulator_backend.run_circuit(circuit).map_err(|err| {
+            RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "The WebAPI failed with: {} and the local simulation failed with: {}",
+                    api_error, err
+                ),
+            }
+        })?;
+        Ok((
+            registers,
+            self.record(FallbackRunMetadata {
+                path: ExecutionPath::Simulator,
+                api_error: Some(api_error.to_string()),
+            }),
+        ))
+    }
+
+    /// Returns the metadata of all runs, in the order of the runs.
+    pub fn run_metadata(&self) -> Vec<FallbackRunMetadata> {
+        self.run_metadata
+            .lock()
+            .map(|metadata| metadata.clone())
+            .unwrap_or_default()
+    }
+
+    /// Clears the metadata of all previous runs.
+    pub fn clear_run_metadata(&self) {
+        if let Ok(mut metadata) = self.run_metadata.lock() {
+            metadata.clear();
+        }
+    }
+
+    /// Appends the metadata of a run to the run metadata and returns it.
+    fn record(&self, metadata: FallbackRunMetadata) -> FallbackRunMetadata {
+        if let Ok(mut run_metadata) = self.run_metadata.lock() {
+            run_metadata.push(metadata.clone());
+        }
+        metadata
+    }
+}
+
+impl EvaluatingBackend for FallbackBackend {
+    fn run_circuit_iterator<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+    ) -> RegisterResult {
+        let circuit: Circuit = circuit.cloned().collect();
+        self.run_circuit_with_metadata(&circuit)
+            .map(|(registers, _)| registers)
+    }
+}
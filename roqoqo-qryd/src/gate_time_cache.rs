@@ -0,0 +1,142 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Size-bounded least-recently-used cache of gate time lookups.
+//!
+//! Looking up a gate time of a [crate::TweezerDevice] goes through the layout register and the
+//! gate time maps of the current layout. When simulating deep circuits the same lookups are
+//! repeated for every operation. The cache memoizes the results by the gate and the tweezers it
+//! acts on for one layout. The qubit to tweezer mapping is resolved before every lookup and a
+//! lookup in a different layout than the cached one empties the cache, so changes of the public
+//! `qubit_to_tweezer` and `current_layout` fields never return stale gate times. Changing the
+//! gate times of a layout empties the cache.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Mutex;
+
+type GateTimeKey = (String, Vec<usize>);
+
+#[derive(Default)]
+struct CacheState {
+    /// The layout the cached gate times belong to.
+    layout: Option<String>,
+    /// Cached gate times and the tick of their last use.
+    entries: HashMap<GateTimeKey, (Option<f64>, u64)>,
+    /// Keys of the cached gate times ordered by the tick of their last use.
+    recency: BTreeMap<u64, GateTimeKey>,
+    /// Counter increased on every use of the cache.
+    tick: u64,
+}
+
+/// Cache of the gate times of a device, disabled when the capacity is zero.
+#[derive(Default)]
+pub(crate) struct GateTimeCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+impl GateTimeCache {
+    /// Returns the maximal number of cached gate times.
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximal number of cached gate times and empties the cache.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.invalidate();
+    }
+
+    /// Discards all cached gate times.
+    pub(crate) fn invalidate(&mut self) {
+        if let Ok(state) = self.state.get_mut() {
+            *state = CacheState::default();
+        }
+    }
+
+    /// Returns the cached gate time or computes and caches it.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The layout the gate time is looked up in.
+    /// * `hqslang` - The hqslang name of the gate.
+    /// * `tweezers` - The tweezers the gate acts on.
+    /// * `compute` - Computes the gate time if it is not cached.
+    pub(crate) fn get_or_compute<F>(
+        &self,
+        layout: Option<&str>,
+        hqslang: &str,
+        tweezers: &[usize],
+        compute: F,
+    ) -> Option<f64>
+    where
+        F: FnOnce() -> Option<f64>,
+    {
+        if self.capacity == 0 {
+            return compute();
+        }
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(_) => return compute(),
+        };
+        let state = &mut *state;
+        if state.layout.as_deref() != layout {
+            *state = CacheState {
+                layout: layout.map(str::to_string),
+                ..CacheState::default()
+            };
+        }
+        let key: GateTimeKey = (hqslang.to_string(), tweezers.to_vec());
+        state.tick += 1;
+        if let Some((time, last_use)) = state.entries.get_mut(&key) {
+            state.recency.remove(last_use);
+            *last_use = state.tick;
+            state.recency.insert(state.tick, key);
+            return *time;
+        }
+        let time = compute();
+        if state.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = state.recency.pop_first() {
+                state.entries.remove(&oldest);
+            }
+        }
+        state.recency.insert(state.tick, key.clone());
+        state.entries.insert(key, (time, state.tick));
+        time
+    }
+}
+
+impl Clone for GateTimeCache {
+    // The cached gate times are not cloned, the clone fills its own cache.
+    fn clone(&self) -> Self {
+        GateTimeCache {
+            capacity: self.capacity,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+}
+
+impl PartialEq for GateTimeCache {
+    // The cache does not change the gate times of a device and is ignored when comparing devices.
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for GateTimeCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GateTimeCache")
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GHZ parity oscillation experiment.
+//!
+//! Prepares a GHZ state on a line of qubits using only gates native to QRyd devices,
+//! measures the population of the `|0...0>` and `|1...1>` states as well as the parity
+//! oscillations under a global analysis rotation and estimates the GHZ state fidelity
+//! `F = (P_0...0 + P_1...1) / 2 + C / 2`, where `C` is the parity oscillation amplitude.
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::operations::{
+    ControlledPauliZ, DefinitionBit, MeasureQubit, PragmaSetNumberOfMeasurements, RotateXY,
+};
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::collections::HashSet;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Name of the readout register used by the GHZ parity circuits.
+pub const GHZ_READOUT: &str = "ro_ghz";
+
+/// Result of a GHZ parity oscillation experiment.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GHZFidelityResult {
+    /// Summed probability of measuring `|0...0>` or `|1...1>`.
+    pub populations: f64,
+    /// Fitted amplitude `C` of the parity oscillations (the GHZ coherence).
+    pub parity_amplitude: f64,
+    /// Fitted phase offset of the parity oscillations.
+    pub parity_phase_offset: f64,
+    /// Estimated fidelity with the ideal GHZ state.
+    pub fidelity: f64,
+    /// Measured parity for each analysis phase.
+    pub parities: Vec<f64>,
+}
+
+/// Experiment builder for GHZ state parity oscillations.
+///
+/// The GHZ state is prepared along the given line of qubits, consecutive qubits are entangled
+/// with a `ControlledPauliZ` gate and therefore need to be connected on the device.
+/// The parity is measured after a `RotateXY(pi/2, phi)` rotation on every qubit for
+/// `number_phases` equidistant phases `phi` in `[0, 2 pi)`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GHZParityExperiment {
+    /// The line of qubits the GHZ state is prepared on.
+    qubits: Vec<usize>,
+    /// The number of analysis phases.
+    number_phases: usize,
+    /// The number of measurements performed for each circuit.
+    number_measurements: usize,
+}
+
+impl GHZParityExperiment {
+    /// Creates a new GHZParityExperiment.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits` - The line of qubits the GHZ state is prepared on.
+    /// * `number_phases` - The number of analysis phases, needs to be larger than twice the number of qubits.
+    /// * `number_measurements` - The number of measurements performed for each circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new GHZParityExperiment.
+    /// * `Err(RoqoqoBackendError)` - Less than two qubits, duplicated qubits, too few phases or no measurements were given.
+    pub fn new(
+        qubits: Vec<usize>,
+        number_phases: usize,
+        number_measurements: usize,
+    ) -> Result<Self, RoqoqoBackendError> {
+        if qubits.len() < 2 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "A GHZ state requires at least two qubits.".to_string(),
+            });
+        }
+        if qubits.iter().collect::<HashSet<&usize>>().len() != qubits.len() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("The GHZ qubits {:?} contain duplicates.", qubits),
+            });
+        }
+        if number_phases <= 2 * qubits.len() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Resolving the parity oscillations of {} qubits requires more than {} phases.",
+                    qubits.len(),
+                    2 * qubits.len()
+                ),
+            });
+        }
+        if number_measurements == 0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "The GHZ experiment requires at least one measurement per circuit."
+                    .to_string(),
+            });
+        }
+        Ok(Self {
+            qubits,
+            number_phases,
+            number_measurements,
+        })
+    }
+
+    /// Returns the line of qubits the GHZ state is prepared on.
+    pub fn qubits(&self) -> &[usize] {
+        &self.qubits
+    }
+
+    /// Returns the number of analysis phases.
+    pub fn number_phases(&self) -> usize {
+        self.number_phases
+    }
+
+    /// Returns the number of measurements performed for each circuit.
+    pub fn number_measurements(&self) -> usize {
+        self.number_measurements
+    }
+
+    /// Returns the analysis phases of the parity circuits.
+    pub fn phases(&self) -> Vec<f64> {
+        (0..self.number_phases)
+            .map(|index| 2.0 * PI * (index as f64) / (self.number_phases as f64))
+            .collect()
+    }
+
+    /// Returns the circuit preparing the GHZ state on the line of qubits.
+    ///
+    /// Each CNOT of the standard preparation is decomposed into a `ControlledPauliZ`
+    /// conjugated by `RotateXY` rotations on the target qubit.
+    pub fn preparation_circuit(&self) -> Circuit {
+        let mut circuit = Circuit::new();
+        circuit += RotateXY::new(self.qubits[0], FRAC_PI_2.into(), FRAC_PI_2.into());
+        for pair in self.qubits.windows(2) {
+            circuit += RotateXY::new(pair[1], (-FRAC_PI_2).into(), FRAC_PI_2.into());
+            circuit += ControlledPauliZ::new(pair[0], pair[1]);
+            circuit += RotateXY::new(pair[1], FRAC_PI_2.into(), FRAC_PI_2.into());
+        }
+        circuit
+    }
+
+    /// Returns the measurement circuits of the experiment.
+    ///
+    /// The first circuit measures the populations in the computational basis, followed by one
+    /// parity circuit per entry of [GHZParityExperiment::phases]. The i-th qubit of the line
+    /// is measured into the i-th bit of the [GHZ_READOUT] register.
+    pub fn circuits(&self) -> Vec<Circuit> {
+        let mut circuits = vec![self.measured_circuit(None)];
+        circuits.extend(
+            self.phases()
+                .into_iter()
+                .map(|phase| self.measured_circuit(Some(phase))),
+        );
+        circuits
+    }
+
+    /// Runs the experiment on a backend and analyses the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend` - The backend the circuits are run on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GHZFidelityResult)` - The analysed results.
+    /// * `Err(RoqoqoBackendError)` - Running a circuit or the analysis failed.
+    pub fn run<T: EvaluatingBackend>(
+        &self,
+        backend: &T,
+    ) -> Result<GHZFidelityResult, RoqoqoBackendError> {
+        let mut registers: Vec<Vec<Vec<bool>>> = Vec::new();
+        for circuit in self.circuits().iter() {
+            let (bit_registers, _, _) = backend.run_circuit(circuit)?;
+            let register =
+                bit_registers
+                    .get(GHZ_READOUT)
+                    .ok_or_else(|| RoqoqoBackendError::GenericError {
+                        msg: format!("Backend did not return the {} register.", GHZ_READOUT),
+                    })?;
+            registers.push(register.clone());
+        }
+        self.analyse(&registers)
+    }
+
+    /// Analyses the measured bit registers.
+    ///
+    /// # Arguments
+    ///
+    /// * `registers` - The measured bit registers, one per circuit, in the order of
+    ///                 [GHZParityExperiment::circuits].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GHZFidelityResult)` - The analysed results.
+    /// * `Err(RoqoqoBackendError)` - The registers do not match the circuits of the experiment.
+    pub fn analyse(
+        &self,
+        registers: &[Vec<Vec<bool>>],
+    ) -> Result<GHZFidelityResult, RoqoqoBackendError> {
+        if registers.len() != self.number_phases + 1 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Expected {} registers but {} were given.",
+                    self.number_phases + 1,
+                    registers.len()
+                ),
+            });
+        }
+        for register in registers.iter() {
+            if register.is_empty() {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: "Registers without measurements cannot be analysed.".to_string(),
+                });
+            }
+            if register.iter().any(|shot| shot.len() < self.qubits.len()) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Measured bitstrings need to contain at least {} bits.",
+                        self.qubits.len()
+                    ),
+                });
+            }
+        }
+        let number_qubits = self.qubits.len();
+
+        let population_register = &registers[0];
+        let populations = population_register
+            .iter()
+            .filter(|shot| {
+                let ones = shot[..number_qubits].iter().filter(|b| **b).count();
+                ones == 0 || ones == number_qubits
+            })
+            .count() as f64
+            / population_register.len() as f64;
+
+        let parities: Vec<f64> = registers[1..]
+            .iter()
+            .map(|register| {
+                register
+                    .iter()
+                    .map(|shot| {
+                        if shot[..number_qubits].iter().filter(|b| **b).count() % 2 == 1 {
+                            -1.0
+                        } else {
+                            1.0
+                        }
+                    })
+                    .sum::<f64>()
+                    / register.len() as f64
+            })
+            .collect();
+
+        // Least squares fit of parity(phi) = a cos(N phi) + b sin(N phi)
+        let (mut cc, mut ss, mut cs, mut pc, mut ps) = (0.0, 0.0, 0.0, 0.0, 0.0);
+        for (phase, parity) in self.phases().iter().zip(parities.iter()) {
+            let (sin, cos) = (number_qubits as f64 * phase).sin_cos();
+            cc += cos * cos;
+            ss += sin * sin;
+            cs += cos * sin;
+            pc += parity * cos;
+            ps += parity * sin;
+        }
+        let determinant = cc * ss - cs * cs;
+        let a = (pc * ss - ps * cs) / determinant;
+        let b = (ps * cc - pc * cs) / determinant;
+        let parity_amplitude = (a * a + b * b).sqrt();
+
+        Ok(GHZFidelityResult {
+            populations,
+            parity_amplitude,
+            parity_phase_offset: (-b).atan2(a),
+            fidelity: (populations + parity_amplitude) / 2.0,
+            parities,
+        })
+    }
+
+    /// Returns the preparation circuit followed by the optional analysis rotation and the measurement.
+    fn measured_circuit(&self, phase: Option<f64>) -> Circuit {
+        let mut circuit = self.preparation_circuit();
+        circuit += DefinitionBit::new(GHZ_READOUT.to_string(), self.qubits.len(), true);
+        if let Some(phase) = phase {
+            for qubit in self.qubits.iter() {
+                circuit += RotateXY::new(*qubit, FRAC_PI_2.into(), phase.into());
+            }
+        }
+        for (position, qubit) in self.qubits.iter().enumerate() {
+            circuit += MeasureQubit::new(*qubit, GHZ_READOUT.to_string(), position);
+        }
+        circuit +=
+            PragmaSetNumberOfMeasurements::new(self.number_measurements, GHZ_READOUT.to_string());
+        circuit
+    }
+}
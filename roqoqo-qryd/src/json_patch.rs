@@ -0,0 +1,165 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON patches (RFC 6902) applied to the serialized form of the devices.
+//!
+//! Supports the `add`, `remove`, `replace`, `move`, `copy` and `test` operations with the
+//! locations given as JSON pointers (RFC 6901).
+
+use roqoqo::RoqoqoBackendError;
+use serde_json::Value;
+
+/// Applies a JSON patch to a document.
+///
+/// The operations are applied in order, the document is left partially patched when an
+/// operation fails. Callers needing an atomic update apply the patch to a copy.
+///
+/// # Arguments
+///
+/// * `document` - The JSON document to patch.
+/// * `patch` - The JSON patch, an array of operation objects.
+///
+/// # Returns
+///
+/// * `Ok(())` - All operations have been applied.
+/// * `Err(RoqoqoBackendError)` - The patch is malformed, a location does not exist or a test failed.
+pub(crate) fn apply_json_patch(
+    document: &mut Value,
+    patch: &Value,
+) -> Result<(), RoqoqoBackendError> {
+    let operations = patch
+        .as_array()
+        .ok_or_else(|| patch_error("The patch is not an array of operations."))?;
+    for (index, operation) in operations.iter().enumerate() {
+        apply_operation(document, operation)
+            .map_err(|err| patch_error(&format!("Operation {}: {}", index, err)))?;
+    }
+    Ok(())
+}
+
+fn patch_error(msg: &str) -> RoqoqoBackendError {
+    RoqoqoBackendError::GenericError {
+        msg: format!("Error applying JSON patch. {}", msg),
+    }
+}
+
+fn apply_operation(document: &mut Value, operation: &Value) -> Result<(), String> {
+    let member = |name: &str| -> Result<&Value, String> {
+        operation
+            .get(name)
+            .ok_or_else(|| format!("Missing member \"{}\".", name))
+    };
+    let string_member = |name: &str| -> Result<&str, String> {
+        member(name)?
+            .as_str()
+            .ok_or_else(|| format!("Member \"{}\" is not a string.", name))
+    };
+    let path = string_member("path")?;
+    match string_member("op")? {
+        "add" => add(document, path, member("value")?.clone()),
+        "remove" => remove(document, path).map(|_| ()),
+        "replace" => {
+            let target = document
+                .pointer_mut(path)
+                .ok_or_else(|| format!("Location {} does not exist.", path))?;
+            *target = member("value")?.clone();
+            Ok(())
+        }
+        "move" => {
+            let from = string_member("from")?;
+            if path.starts_with(from) && path[from.len()..].starts_with('/') {
+                return Err(format!("Can not move {} into one of its children.", from));
+            }
+            let value = remove(document, from)?;
+            add(document, path, value)
+        }
+        "copy" => {
+            let from = string_member("from")?;
+            let value = document
+                .pointer(from)
+                .ok_or_else(|| format!("Location {} does not exist.", from))?
+                .clone();
+            add(document, path, value)
+        }
+        "test" => match document.pointer(path) {
+            Some(value) if value == member("value")? => Ok(()),
+            Some(_) => Err(format!("Test of location {} failed.", path)),
+            None => Err(format!("Location {} does not exist.", path)),
+        },
+        op => Err(format!("Unknown operation \"{}\".", op)),
+    }
+}
+
+/// Splits a JSON pointer into the pointer to the parent and the unescaped last reference token.
+fn split_pointer(path: &str) -> Result<(&str, String), String> {
+    match path.rfind('/') {
+        Some(position) => Ok((
+            &path[..position],
+            path[position + 1..].replace("~1", "/").replace("~0", "~"),
+        )),
+        None => Err(format!("Invalid JSON pointer \"{}\".", path)),
+    }
+}
+
+/// Parses an array index, rejecting leading zeros as required by RFC 6901.
+fn array_index(token: &str) -> Result<usize, String> {
+    if token.is_empty()
+        || (token.len() > 1 && token.starts_with('0'))
+        || !token.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(format!("Invalid array index \"{}\".", token));
+    }
+    token
+        .parse()
+        .map_err(|_| format!("Invalid array index \"{}\".", token))
+}
+
+fn add(document: &mut Value, path: &str, value: Value) -> Result<(), String> {
+    if path.is_empty() {
+        *document = value;
+        return Ok(());
+    }
+    let (parent, token) = split_pointer(path)?;
+    match document.pointer_mut(parent) {
+        Some(Value::Object(map)) => {
+            map.insert(token, value);
+            Ok(())
+        }
+        Some(Value::Array(array)) => {
+            let index = if token == "-" {
+                array.len()
+            } else {
+                array_index(&token)?
+            };
+            if index > array.len() {
+                return Err(format!("Index {} is out of bounds.", index));
+            }
+            array.insert(index, value);
+            Ok(())
+        }
+        Some(_) => Err(format!("Location {} is not an object or an array.", parent)),
+        None => Err(format!("Location {} does not exist.", parent)),
+    }
+}
+
+fn remove(document: &mut Value, path: &str) -> Result<Value, String> {
+    let (parent, token) = split_pointer(path)?;
+    let removed = match document.pointer_mut(parent) {
+        Some(Value::Object(map)) => map.remove(&token),
+        Some(Value::Array(array)) => {
+            let index = array_index(&token)?;
+            (index < array.len()).then(|| array.remove(index))
+        }
+        _ => None,
+    };
+    removed.ok_or_else(|| format!("Location {} does not exist.", path))
+}
@@ -50,6 +50,18 @@ pub use qryd_devices::*;
 pub mod tweezer_devices;
 pub use tweezer_devices::*;
 
+/// Machine-readable capability manifests of the tweezer devices
+pub mod capabilities;
+pub use capabilities::*;
+
+/// Consistency checks of the tweezer devices
+pub mod device_validation;
+pub use device_validation::*;
+
+/// Snapshots of the tweezer devices saved to and loaded from files
+pub mod device_files;
+pub use device_files::*;
+
 /// Devices representing QRyd quantum computer(s)
 pub mod api_devices;
 pub use api_devices::*;
@@ -58,22 +70,124 @@ pub use api_devices::*;
 pub mod pragma_operations;
 pub use pragma_operations::*;
 
+/// Registry of custom device-changing pragmas handled by the tweezer devices
+pub mod device_pragma_handlers;
+pub use device_pragma_handlers::*;
+
+/// Symbolic parameter substitution and qubit remapping of circuits and programs containing QRyd pragmas
+pub mod parameter_substitution;
+pub use parameter_substitution::*;
+
+/// Conversion of the legacy QRyd pragmas of circuits into the tweezer pragmas
+pub mod circuit_upgrade;
+pub use circuit_upgrade::*;
+
+/// Checks of all operations of a circuit against the tweezer devices
+pub mod circuit_compatibility;
+pub use circuit_compatibility::*;
+
+/// Memoization of the gate time lookups of the tweezer devices
+mod gate_time_cache;
+
+/// Dense lookup tables caching the two-qubit gate times of densely connected tweezer layouts
+mod dense_gate_times;
+
+/// JSON patches applied to the serialized devices
+mod json_patch;
+
+/// Import of JSON and YAML calibration files into the tweezer devices
+mod calibration;
+
 /// Emulator device, TweezerDevice instance with all-to-all connectivity
 pub mod emulator_devices;
 pub use emulator_devices::*;
 
+/// Statistics helpers for measurement results with standard errors and confidence intervals
+pub mod result_statistics;
+pub use result_statistics::*;
+
+/// Histogram data and plots of measured bitstring counts
+pub mod result_plotting;
+pub use result_plotting::*;
+
+/// State tomography experiments reconstructing density matrices from measured data
+pub mod tomography;
+pub use tomography::*;
+
+/// GHZ parity oscillation experiment estimating the GHZ state fidelity
+pub mod ghz_parity;
+pub use ghz_parity::*;
+
+/// Cross-entropy benchmarking with random circuits of native gates
+pub mod xeb;
+pub use xeb::*;
+
+/// Fitting of the simulator noise parameters from experimental results
+pub mod noise_fitting;
+pub use noise_fitting::*;
+
+/// Removal of simulation-only pragmas from circuits submitted to the WebAPI
+pub mod circuit_sanitizer;
+pub use circuit_sanitizer::*;
+
+/// Local estimation of the qubits, native gates, shots and execution time of quantum programs
+pub mod resource_estimation;
+pub use resource_estimation::*;
+
+/// Circuits bound to the snapshot of the tweezer device they have been validated against
+pub mod bound_circuit;
+pub use bound_circuit::*;
+
 /// Simulator backend for the QRyd quantum computer
 #[cfg(feature = "simulator")]
 mod simulator_backend;
 #[cfg(feature = "simulator")]
 pub use simulator_backend::*;
 
+/// Data exchanged with the QRyd WebAPI when posting and polling jobs
+#[cfg(any(feature = "web-api", feature = "web-api-wasm"))]
+mod api_jobs;
+
+/// Device events recorded per shot during a simulation
+#[cfg(feature = "simulator")]
+pub mod device_events;
+#[cfg(feature = "simulator")]
+pub use device_events::*;
+
+/// Symbolic circuits compiled once and evaluated for many parameter values
+#[cfg(feature = "simulator")]
+mod circuit_template;
+
 /// WebAPI backend for the QRyd quantum computer(s)
 #[cfg(feature = "web-api")]
 pub mod api_backend;
 #[cfg(feature = "web-api")]
 pub use api_backend::*;
 
+/// Asynchronous WebAPI backend for the QRyd quantum computer(s) usable in WebAssembly
+#[cfg(feature = "web-api-wasm")]
+pub mod api_backend_wasm;
+#[cfg(feature = "web-api-wasm")]
+pub use api_backend_wasm::*;
+
+/// Differential testing of quantum programs between versions of the QRyd WebAPI
+#[cfg(feature = "web-api")]
+pub mod api_differential;
+#[cfg(feature = "web-api")]
+pub use api_differential::*;
+
+/// Scheduling of quantum programs on the QRyd device with the best expected turnaround
+#[cfg(feature = "web-api")]
+pub mod scheduling;
+#[cfg(feature = "web-api")]
+pub use scheduling::*;
+
+/// Execution on the QRyd WebAPI with a local simulation as fallback
+#[cfg(all(feature = "web-api", feature = "simulator"))]
+pub mod fallback_backend;
+#[cfg(all(feature = "web-api", feature = "simulator"))]
+pub use fallback_backend::*;
+
 #[cfg(feature = "web-api")]
 use roqoqo::RoqoqoBackendError;
 #[cfg(feature = "web-api")]
@@ -140,6 +254,15 @@ pub enum CombinedDevice {
 ///
 /// * `RoqoqoBackendError`
 #[cfg(feature = "web-api")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        name = "qryd_device_from_api",
+        skip_all,
+        fields(device_name = ?device_name, api_version = ?api_version, status = tracing::field::Empty)
+    )
+)]
 pub fn device_from_api(
     device_name: Option<String>,
     access_token: Option<String>,
@@ -219,8 +342,20 @@ pub fn device_from_api(
 
     // Response handling
     let status_code = resp.status();
+    #[cfg(feature = "tracing")]
+    {
+        tracing::Span::current().record("status", status_code.as_u16());
+        tracing::debug!(
+            status = status_code.as_u16(),
+            "QRyd device request completed"
+        );
+    }
     if status_code == reqwest::StatusCode::OK {
-        if let Ok(mut device) = resp.json::<TweezerDevice>() {
+        if let Some(mut device) = resp
+            .text()
+            .ok()
+            .and_then(|text| TweezerDevice::from_api_json(&text).ok())
+        {
             if device.available_gates.is_some() {
                 if let Some(new_seed) = seed {
                     device.seed = Some(new_seed);
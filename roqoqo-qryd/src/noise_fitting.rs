@@ -0,0 +1,273 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fitting of device noise parameters from experimental results.
+//!
+//! Infers the relaxation time T1 and dephasing time T2 from decay measurements and the
+//! depolarising rates from cross-entropy benchmarking results. The fitted values are written
+//! into the noise parameters of a [TweezerDevice], so that the simulator emulates the
+//! measured hardware.
+
+use crate::{TweezerDevice, XEBExperiment, XEBResult};
+use roqoqo::devices::Device;
+use roqoqo::operations::{OperateSingleQubit, OperateTwoQubit, Operation};
+use roqoqo::RoqoqoBackendError;
+
+/// Fits the decay time `T` of `signal(t) = A * exp(-t / T)`.
+///
+/// The fit is a log-linear least squares fit, points with a non-positive signal are ignored.
+///
+/// # Arguments
+///
+/// * `times` - The times at which the signal was measured.
+/// * `signal` - The measured signal.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The fitted decay time.
+/// * `Err(RoqoqoBackendError)` - The data does not have matching lengths, contains fewer than two usable points or does not decay.
+pub fn fit_decay_time(times: &[f64], signal: &[f64]) -> Result<f64, RoqoqoBackendError> {
+    if times.len() != signal.len() {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Got {} times but {} signal values.",
+                times.len(),
+                signal.len()
+            ),
+        });
+    }
+    let points: Vec<(f64, f64)> = times
+        .iter()
+        .zip(signal.iter())
+        .filter(|(_, value)| **value > 0.0)
+        .map(|(time, value)| (*time, value.ln()))
+        .collect();
+    if points.len() < 2 {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: "Fitting a decay requires at least two points with a positive signal.".to_string(),
+        });
+    }
+    let number_points = points.len() as f64;
+    let mean_time = points.iter().map(|(t, _)| t).sum::<f64>() / number_points;
+    let mean_log = points.iter().map(|(_, l)| l).sum::<f64>() / number_points;
+    let variance: f64 = points.iter().map(|(t, _)| (t - mean_time).powi(2)).sum();
+    if variance == 0.0 {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: "Fitting a decay requires at least two distinct times.".to_string(),
+        });
+    }
+    let covariance: f64 = points
+        .iter()
+        .map(|(t, l)| (t - mean_time) * (l - mean_log))
+        .sum();
+    let slope = covariance / variance;
+    if slope >= 0.0 {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: "The measured signal does not decay.".to_string(),
+        });
+    }
+    Ok(-1.0 / slope)
+}
+
+/// Fits the relaxation time T1 of a qubit and writes it into the device.
+///
+/// # Arguments
+///
+/// * `device` - The device whose noise parameters are updated.
+/// * `qubit` - The measured qubit.
+/// * `times` - The waiting times after the excitation of the qubit.
+/// * `excited_populations` - The measured population of the excited state.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The fitted relaxation time.
+/// * `Err(RoqoqoBackendError)` - The fit failed or the qubit is not mapped to a tweezer.
+pub fn fit_t1(
+    device: &mut TweezerDevice,
+    qubit: usize,
+    times: &[f64],
+    excited_populations: &[f64],
+) -> Result<f64, RoqoqoBackendError> {
+    let tweezer = device.get_tweezer_from_qubit(&qubit)?;
+    let t1 = fit_decay_time(times, excited_populations)?;
+    device.set_tweezer_t1(tweezer, t1)?;
+    Ok(t1)
+}
+
+/// Fits the dephasing time T2 of a qubit and writes it into the device.
+///
+/// # Arguments
+///
+/// * `device` - The device whose noise parameters are updated.
+/// * `qubit` - The measured qubit.
+/// * `times` - The waiting times of the Ramsey or echo sequence.
+/// * `coherences` - The measured contrast of the coherence, e.g. the fringe amplitude.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The fitted dephasing time.
+/// * `Err(RoqoqoBackendError)` - The fit failed or the qubit is not mapped to a tweezer.
+pub fn fit_t2(
+    device: &mut TweezerDevice,
+    qubit: usize,
+    times: &[f64],
+    coherences: &[f64],
+) -> Result<f64, RoqoqoBackendError> {
+    let tweezer = device.get_tweezer_from_qubit(&qubit)?;
+    let t2 = fit_decay_time(times, coherences)?;
+    device.set_tweezer_t2(tweezer, t2)?;
+    Ok(t2)
+}
+
+/// Fits the depolarising rate of the benchmarked qubits from an XEB result.
+///
+/// Under depolarising noise with rate `r`, the Bloch vector of a qubit shrinks by `exp(-r t)`
+/// while a gate of duration `t` acts on it. The fidelity per cycle is therefore modelled as
+/// `f = exp(-r T)`, where `T` is the gate time per cycle summed over all qubits the gates
+/// act on, averaged over the circuits of the experiment.
+///
+/// # Arguments
+///
+/// * `device` - The device the experiment was run on, providing the gate times.
+/// * `experiment` - The benchmarking experiment.
+/// * `result` - The result of the experiment.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The fitted depolarising rate.
+/// * `Err(RoqoqoBackendError)` - No valid fidelity per cycle was fitted or a gate time is missing in the device.
+pub fn fit_depolarising_rate<D: Device>(
+    device: &D,
+    experiment: &XEBExperiment,
+    result: &XEBResult,
+) -> Result<f64, RoqoqoBackendError> {
+    let fidelity_per_cycle =
+        result
+            .fidelity_per_cycle
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The XEB result does not contain a fidelity per cycle.".to_string(),
+            })?;
+    if fidelity_per_cycle <= 0.0 || fidelity_per_cycle > 1.0 {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "The fidelity per cycle needs to be in (0, 1], got {}.",
+                fidelity_per_cycle
+            ),
+        });
+    }
+    let time_per_cycle = gate_time_per_cycle(device, experiment)?;
+    Ok(-fidelity_per_cycle.ln() / time_per_cycle)
+}
+
+/// Writes a depolarising rate into the tweezers of the given qubits.
+///
+/// # Arguments
+///
+/// * `device` - The device whose noise parameters are updated.
+/// * `qubits` - The qubits whose tweezers are updated.
+/// * `rate` - The depolarising rate.
+///
+/// # Returns
+///
+/// * `Ok(())` - The rate has been written into the device.
+/// * `Err(RoqoqoBackendError)` - A qubit is not mapped to a tweezer or the rate is negative.
+pub fn apply_depolarising_rate(
+    device: &mut TweezerDevice,
+    qubits: &[usize],
+    rate: f64,
+) -> Result<(), RoqoqoBackendError> {
+    let tweezers = qubits
+        .iter()
+        .map(|qubit| device.get_tweezer_from_qubit(qubit))
+        .collect::<Result<Vec<usize>, RoqoqoBackendError>>()?;
+    for tweezer in tweezers {
+        device.set_tweezer_depolarising_rate(tweezer, rate)?;
+    }
+    Ok(())
+}
+
+/// Fits the depolarising rate from an XEB result and writes it into the tweezers of all benchmarked qubits.
+///
+/// See [fit_depolarising_rate] for the noise model.
+///
+/// # Arguments
+///
+/// * `device` - The device the experiment was run on, whose noise parameters are updated.
+/// * `experiment` - The benchmarking experiment.
+/// * `result` - The result of the experiment.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The fitted depolarising rate.
+/// * `Err(RoqoqoBackendError)` - The fit failed or a qubit is not mapped to a tweezer.
+pub fn fit_xeb_noise(
+    device: &mut TweezerDevice,
+    experiment: &XEBExperiment,
+    result: &XEBResult,
+) -> Result<f64, RoqoqoBackendError> {
+    let rate = fit_depolarising_rate(device, experiment, result)?;
+    apply_depolarising_rate(device, experiment.qubits(), rate)?;
+    Ok(rate)
+}
+
+/// Returns the gate time per cycle of an XEB experiment, summed over the qubits the gates act on.
+///
+/// The final rotation layer preceding the measurement is not part of a cycle and is removed.
+fn gate_time_per_cycle<D: Device>(
+    device: &D,
+    experiment: &XEBExperiment,
+) -> Result<f64, RoqoqoBackendError> {
+    let missing_time = |name: &str| RoqoqoBackendError::GenericError {
+        msg: format!("The device does not provide a gate time for {}.", name),
+    };
+    let mut final_layer_time = 0.0;
+    for qubit in experiment.qubits() {
+        final_layer_time += device
+            .single_qubit_gate_time("RotateXY", qubit)
+            .ok_or_else(|| missing_time("RotateXY"))?;
+    }
+    let mut total_time = 0.0;
+    for circuit in experiment.circuits() {
+        for operation in circuit.iter() {
+            match operation {
+                Operation::RotateXY(op) => {
+                    total_time += device
+                        .single_qubit_gate_time("RotateXY", op.qubit())
+                        .ok_or_else(|| missing_time("RotateXY"))?;
+                }
+                Operation::ControlledPauliZ(op) => {
+                    total_time += 2.0
+                        * device
+                            .two_qubit_gate_time("ControlledPauliZ", op.control(), op.target())
+                            .or_else(|| {
+                                device.two_qubit_gate_time(
+                                    "ControlledPauliZ",
+                                    op.target(),
+                                    op.control(),
+                                )
+                            })
+                            .ok_or_else(|| missing_time("ControlledPauliZ"))?;
+                }
+                _ => {}
+            }
+        }
+        total_time -= final_layer_time;
+    }
+    let number_cycles: usize = experiment.depths().iter().sum();
+    if number_cycles == 0 || total_time <= 0.0 {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: "The experiment does not contain any gate time to attribute the noise to."
+                .to_string(),
+        });
+    }
+    Ok(total_time / number_cycles as f64)
+}
@@ -0,0 +1,376 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Symbolic parameter substitution and qubit remapping of circuits containing QRyd pragmas.
+//!
+//! The QRyd pragmas are added to circuits wrapped in [PragmaChangeDevice] operations, which store
+//! the wrapped operation serialized with bincode. The parameter substitution and qubit remapping
+//! of roqoqo leave the wrapped operation unchanged, so a symbolic parameter of a QRyd pragma would
+//! never be replaced and a relabeled qubit would still be referred to by its old index. The
+//! functions of this module substitute the parameters and remap the qubits of the wrapped QRyd
+//! pragmas together with those of all other operations.
+
+use crate::{
+    PragmaChangeQRydLayout, PragmaDeactivateQRydQubit, PragmaGlobalRotateXY, PragmaMoveToZone,
+    PragmaSetPhaseRelation, PragmaShiftQRydQubit, PragmaShiftQubitsTweezers, PragmaSwapTweezers,
+    PragmaSwitchDeviceLayout,
+};
+use bincode::{deserialize, serialize};
+use qoqo_calculator::Calculator;
+use roqoqo::operations::{Operate, Operation, PragmaChangeDevice, Substitute};
+use roqoqo::RoqoqoError;
+use roqoqo::{Circuit, QuantumProgram, RoqoqoBackendError};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Returns true when the operation, or the QRyd pragma it wraps, has symbolic parameters.
+///
+/// # Arguments
+///
+/// * `operation` - The operation to check.
+///
+/// # Returns
+///
+/// * `bool` - True if the operation or the wrapped QRyd pragma contains symbolic parameters.
+pub fn is_operation_parametrized(operation: &Operation) -> bool {
+    match operation {
+        Operation::PragmaChangeDevice(pragma) => match pragma.wrapped_hqslang.as_str() {
+            "PragmaChangeQRydLayout" => is_wrapped_parametrized::<PragmaChangeQRydLayout>(pragma),
+            "PragmaShiftQRydQubit" => is_wrapped_parametrized::<PragmaShiftQRydQubit>(pragma),
+            "PragmaDeactivateQRydQubit" => {
+                is_wrapped_parametrized::<PragmaDeactivateQRydQubit>(pragma)
+            }
+            "PragmaShiftQubitsTweezers" => {
+                is_wrapped_parametrized::<PragmaShiftQubitsTweezers>(pragma)
+            }
+            "PragmaSwitchDeviceLayout" => {
+                is_wrapped_parametrized::<PragmaSwitchDeviceLayout>(pragma)
+            }
+            "PragmaSwapTweezers" => is_wrapped_parametrized::<PragmaSwapTweezers>(pragma),
+            "PragmaGlobalRotateXY" => is_wrapped_parametrized::<PragmaGlobalRotateXY>(pragma),
+            "PragmaMoveToZone" => is_wrapped_parametrized::<PragmaMoveToZone>(pragma),
+            "PragmaSetPhaseRelation" => is_wrapped_parametrized::<PragmaSetPhaseRelation>(pragma),
+            _ => false,
+        },
+        _ => operation.is_parametrized(),
+    }
+}
+
+/// Substitutes the symbolic parameters of an operation, including the QRyd pragma it wraps.
+///
+/// Operations wrapped in a PragmaChangeDevice that are not QRyd pragmas are returned unchanged.
+///
+/// # Arguments
+///
+/// * `operation` - The operation to substitute the parameters of.
+/// * `calculator` - The calculator holding the values of the symbolic parameters.
+///
+/// # Returns
+///
+/// * `Ok(Operation)` - The operation with the substituted parameters.
+/// * `Err(RoqoqoBackendError)` - A symbolic parameter could not be substituted.
+pub fn substitute_operation_parameters(
+    operation: &Operation,
+    calculator: &Calculator,
+) -> Result<Operation, RoqoqoBackendError> {
+    match operation {
+        Operation::PragmaChangeDevice(pragma) => {
+            let substituted = match pragma.wrapped_hqslang.as_str() {
+                "PragmaChangeQRydLayout" => {
+                    map_wrapped(pragma, |wrapped: &PragmaChangeQRydLayout| {
+                        wrapped.substitute_parameters(calculator)
+                    })?
+                }
+                "PragmaShiftQRydQubit" => map_wrapped(pragma, |wrapped: &PragmaShiftQRydQubit| {
+                    wrapped.substitute_parameters(calculator)
+                })?,
+                "PragmaDeactivateQRydQubit" => {
+                    map_wrapped(pragma, |wrapped: &PragmaDeactivateQRydQubit| {
+                        wrapped.substitute_parameters(calculator)
+                    })?
+                }
+                // Read with the legacy fallback, the shifts do not contain parameters
+                "PragmaShiftQubitsTweezers" => {
+                    PragmaShiftQubitsTweezers::from_bincode(&pragma.wrapped_operation)
+                        .map_err(|err| RoqoqoBackendError::GenericError {
+                            msg: format!(
+                                "Could not deserialize the wrapped {}: {}",
+                                pragma.wrapped_hqslang, err
+                            ),
+                        })?
+                        .to_pragma_change_device()?
+                }
+                "PragmaSwitchDeviceLayout" => {
+                    map_wrapped(pragma, |wrapped: &PragmaSwitchDeviceLayout| {
+                        wrapped.substitute_parameters(calculator)
+                    })?
+                }
+                "PragmaSwapTweezers" => map_wrapped(pragma, |wrapped: &PragmaSwapTweezers| {
+                    wrapped.substitute_parameters(calculator)
+                })?,
+                "PragmaGlobalRotateXY" => map_wrapped(pragma, |wrapped: &PragmaGlobalRotateXY| {
+                    wrapped.substitute_parameters(calculator)
+                })?,
+                "PragmaMoveToZone" => map_wrapped(pragma, |wrapped: &PragmaMoveToZone| {
+                    wrapped.substitute_parameters(calculator)
+                })?,
+                "PragmaSetPhaseRelation" => {
+                    map_wrapped(pragma, |wrapped: &PragmaSetPhaseRelation| {
+                        wrapped.substitute_parameters(calculator)
+                    })?
+                }
+                _ => pragma.clone(),
+            };
+            Ok(Operation::PragmaChangeDevice(substituted))
+        }
+        _ => operation.substitute_parameters(calculator).map_err(|err| {
+            RoqoqoBackendError::GenericError {
+                msg: format!("Could not substitute the symbolic parameters: {}", err),
+            }
+        }),
+    }
+}
+
+/// Substitutes the symbolic parameters of a circuit, including the wrapped QRyd pragmas.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit to substitute the parameters of.
+/// * `calculator` - The calculator holding the values of the symbolic parameters.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The circuit with the substituted parameters.
+/// * `Err(RoqoqoBackendError)` - A symbolic parameter could not be substituted.
+pub fn substitute_circuit_parameters(
+    circuit: &Circuit,
+    calculator: &Calculator,
+) -> Result<Circuit, RoqoqoBackendError> {
+    circuit
+        .iter()
+        .map(|operation| substitute_operation_parameters(operation, calculator))
+        .collect()
+}
+
+/// Substitutes the input parameters of a QuantumProgram with the given values.
+///
+/// Like [QuantumProgram::run], the values are assigned to the input parameter names of the
+/// program. They are substituted into all circuits of the measurement, including the constant
+/// circuit and the wrapped QRyd pragmas. The returned program has no input parameters.
+///
+/// # Arguments
+///
+/// * `program` - The QuantumProgram to substitute the parameters of.
+/// * `parameters` - The values of the input parameters of the program.
+///
+/// # Returns
+///
+/// * `Ok(QuantumProgram)` - The program with the substituted parameters.
+/// * `Err(RoqoqoBackendError)` - The number of parameters does not match or a symbolic parameter could not be substituted.
+pub fn substitute_program_parameters(
+    program: QuantumProgram,
+    parameters: &[f64],
+) -> Result<QuantumProgram, RoqoqoBackendError> {
+    let calculator = |input_parameter_names: &[String]| {
+        if input_parameter_names.len() != parameters.len() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Wrong number of parameters {} parameters expected {} parameters given",
+                    input_parameter_names.len(),
+                    parameters.len()
+                ),
+            });
+        }
+        let mut calculator = Calculator::new();
+        for (name, value) in input_parameter_names.iter().zip(parameters.iter()) {
+            calculator.set_variable(name, *value);
+        }
+        Ok(calculator)
+    };
+    let substitute = |constant_circuit: &mut Option<Circuit>,
+                      circuits: &mut Vec<Circuit>,
+                      calculator: &Calculator|
+     -> Result<(), RoqoqoBackendError> {
+        if let Some(circuit) = constant_circuit {
+            *circuit = substitute_circuit_parameters(circuit, calculator)?;
+        }
+        for circuit in circuits.iter_mut() {
+            *circuit = substitute_circuit_parameters(circuit, calculator)?;
+        }
+        Ok(())
+    };
+    match program {
+        QuantumProgram::PauliZProduct {
+            mut measurement,
+            input_parameter_names,
+        } => {
+            substitute(
+                &mut measurement.constant_circuit,
+                &mut measurement.circuits,
+                &calculator(&input_parameter_names)?,
+            )?;
+            Ok(QuantumProgram::PauliZProduct {
+                measurement,
+                input_parameter_names: vec![],
+            })
+        }
+        QuantumProgram::CheatedPauliZProduct {
+            mut measurement,
+            input_parameter_names,
+        } => {
+            substitute(
+                &mut measurement.constant_circuit,
+                &mut measurement.circuits,
+                &calculator(&input_parameter_names)?,
+            )?;
+            Ok(QuantumProgram::CheatedPauliZProduct {
+                measurement,
+                input_parameter_names: vec![],
+            })
+        }
+        QuantumProgram::Cheated {
+            mut measurement,
+            input_parameter_names,
+        } => {
+            substitute(
+                &mut measurement.constant_circuit,
+                &mut measurement.circuits,
+                &calculator(&input_parameter_names)?,
+            )?;
+            Ok(QuantumProgram::Cheated {
+                measurement,
+                input_parameter_names: vec![],
+            })
+        }
+        QuantumProgram::ClassicalRegister {
+            mut measurement,
+            input_parameter_names,
+        } => {
+            substitute(
+                &mut measurement.constant_circuit,
+                &mut measurement.circuits,
+                &calculator(&input_parameter_names)?,
+            )?;
+            Ok(QuantumProgram::ClassicalRegister {
+                measurement,
+                input_parameter_names: vec![],
+            })
+        }
+        _ => Err(RoqoqoBackendError::GenericError {
+            msg: "The parameters of this QuantumProgram can not be substituted.".to_string(),
+        }),
+    }
+}
+
+/// Remaps the qubits of an operation, including the qubits of the QRyd pragma it wraps.
+///
+/// The qubits of PragmaShiftQRydQubit, PragmaDeactivateQRydQubit and PragmaMoveToZone are
+/// remapped. The remaining QRyd pragmas refer to Layouts, tweezers or the whole register and are
+/// returned unchanged, as are operations wrapped in a PragmaChangeDevice that are not QRyd pragmas.
+///
+/// # Arguments
+///
+/// * `operation` - The operation to remap the qubits of.
+/// * `mapping` - The mapping from the old to the new qubit indices.
+///
+/// # Returns
+///
+/// * `Ok(Operation)` - The operation with the remapped qubits.
+/// * `Err(RoqoqoBackendError)` - The qubits could not be remapped.
+pub fn remap_operation_qubits(
+    operation: &Operation,
+    mapping: &HashMap<usize, usize>,
+) -> Result<Operation, RoqoqoBackendError> {
+    match operation {
+        Operation::PragmaChangeDevice(pragma) => {
+            let remapped = match pragma.wrapped_hqslang.as_str() {
+                "PragmaShiftQRydQubit" => map_wrapped(pragma, |wrapped: &PragmaShiftQRydQubit| {
+                    wrapped.remap_qubits(mapping)
+                })?,
+                "PragmaDeactivateQRydQubit" => {
+                    map_wrapped(pragma, |wrapped: &PragmaDeactivateQRydQubit| {
+                        wrapped.remap_qubits(mapping)
+                    })?
+                }
+                "PragmaMoveToZone" => map_wrapped(pragma, |wrapped: &PragmaMoveToZone| {
+                    wrapped.remap_qubits(mapping)
+                })?,
+                _ => pragma.clone(),
+            };
+            Ok(Operation::PragmaChangeDevice(remapped))
+        }
+        _ => operation
+            .remap_qubits(mapping)
+            .map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("Could not remap the qubits: {}", err),
+            }),
+    }
+}
+
+/// Remaps the qubits of a circuit, including the qubits of the wrapped QRyd pragmas.
+///
+/// # Arguments
+///
+/// * `circuit` - The circuit to remap the qubits of.
+/// * `mapping` - The mapping from the old to the new qubit indices.
+///
+/// # Returns
+///
+/// * `Ok(Circuit)` - The circuit with the remapped qubits.
+/// * `Err(RoqoqoBackendError)` - The qubits of an operation could not be remapped.
+pub fn remap_circuit_qubits(
+    circuit: &Circuit,
+    mapping: &HashMap<usize, usize>,
+) -> Result<Circuit, RoqoqoBackendError> {
+    circuit
+        .iter()
+        .map(|operation| remap_operation_qubits(operation, mapping))
+        .collect()
+}
+
+/// Returns true when the wrapped pragma has symbolic parameters.
+fn is_wrapped_parametrized<T: Operate + DeserializeOwned>(pragma: &PragmaChangeDevice) -> bool {
+    deserialize::<T>(&pragma.wrapped_operation).is_ok_and(|wrapped| wrapped.is_parametrized())
+}
+
+/// Applies a transformation to the wrapped pragma and wraps the result again.
+fn map_wrapped<T: Serialize + DeserializeOwned>(
+    pragma: &PragmaChangeDevice,
+    transformation: impl FnOnce(&T) -> Result<T, RoqoqoError>,
+) -> Result<PragmaChangeDevice, RoqoqoBackendError> {
+    let wrapped: T =
+        deserialize(&pragma.wrapped_operation).map_err(|err| RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Could not deserialize the wrapped {}: {}",
+                pragma.wrapped_hqslang, err
+            ),
+        })?;
+    let transformed = transformation(&wrapped).map_err(|err| RoqoqoBackendError::GenericError {
+        msg: format!(
+            "Could not transform the wrapped {}: {}",
+            pragma.wrapped_hqslang, err
+        ),
+    })?;
+    Ok(PragmaChangeDevice {
+        wrapped_tags: pragma.wrapped_tags.clone(),
+        wrapped_hqslang: pragma.wrapped_hqslang.clone(),
+        wrapped_operation: serialize(&transformed).map_err(|err| {
+            RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error occured during serialisation of {} {:?}",
+                    pragma.wrapped_hqslang, err
+                ),
+            }
+        })?,
+    })
+}
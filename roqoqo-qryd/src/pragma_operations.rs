@@ -14,7 +14,9 @@
 //!
 //! These Pragma operations are used to change QRyd devices mid circuit.
 
+use crate::FirstDevice;
 use bincode::serialize;
+use qoqo_calculator::CalculatorFloat;
 use roqoqo::operations::{
     InvolveQubits, InvolvedQubits, Operate, OperatePragma, PragmaChangeDevice, Substitute,
 };
@@ -36,6 +38,7 @@ use std::collections::HashMap;
     serde::Serialize,
     serde::Deserialize,
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PragmaChangeQRydLayout {
     /// The index of the new layout the device is changed to.
     new_layout: usize,
@@ -109,6 +112,7 @@ const TAGS_PragmaChangeQRydLayout: &[&str; 3] =
     serde::Serialize,
     serde::Deserialize,
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PragmaShiftQRydQubit {
     /// The new qubit positions in the row-column grid of the QRyd device.
     new_positions: HashMap<usize, (usize, usize)>,
@@ -155,6 +159,87 @@ impl PragmaShiftQRydQubit {
             })?,
         })
     }
+
+    /// Creates a PragmaShiftQRydQubit keeping all qubits of a FirstDevice at their current positions.
+    ///
+    /// A FirstDevice expects the new positions of all its qubits. Starting from the current
+    /// positions, only the moved qubits need to be changed with [PragmaShiftQRydQubit::move_qubit]
+    /// and [PragmaShiftQRydQubit::shift_row].
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The device the current qubit positions are taken from.
+    ///
+    /// # Returns
+    ///
+    /// * `PragmaShiftQRydQubit` - The pragma leaving all qubits at their positions.
+    pub fn from_device(device: &FirstDevice) -> Self {
+        Self {
+            new_positions: device.qubit_positions().clone(),
+        }
+    }
+
+    /// Sets the new position of a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The qubit to move.
+    /// * `row` - The row of the new position.
+    /// * `column` - The column of the new position.
+    ///
+    /// # Returns
+    ///
+    /// * `PragmaShiftQRydQubit` - The pragma with the new position of the qubit.
+    pub fn move_qubit(mut self, qubit: usize, row: usize, column: usize) -> Self {
+        self.new_positions.insert(qubit, (row, column));
+        self
+    }
+
+    /// Shifts the new positions of all qubits in a row by a number of columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row whose qubits are shifted.
+    /// * `offset` - The number of columns to shift the qubits by, negative to shift them to the left.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PragmaShiftQRydQubit)` - The pragma with the shifted positions.
+    /// * `Err(RoqoqoBackendError)` - A qubit would be shifted to a negative column.
+    pub fn shift_row(mut self, row: usize, offset: isize) -> Result<Self, RoqoqoBackendError> {
+        for (qubit, (qubit_row, column)) in self.new_positions.iter_mut() {
+            if *qubit_row != row {
+                continue;
+            }
+            let old_column = *column;
+            *column = old_column.checked_add_signed(offset).ok_or_else(|| {
+                RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Shifting row {} by {} columns moves qubit {} from column {} to a negative column.",
+                        row, offset, qubit, old_column
+                    ),
+                }
+            })?;
+        }
+        Ok(self)
+    }
+
+    /// Checks that a FirstDevice can change its qubit positions to the new positions.
+    ///
+    /// The new positions need to contain every qubit of the device and no other qubits. The
+    /// qubits need to stay in their rows and every column needs to exist in its row.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The device to validate the new positions against.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The device can change to the new positions.
+    /// * `Err(RoqoqoBackendError)` - The new positions are not valid on the device.
+    pub fn validate(&self, device: &FirstDevice) -> Result<(), RoqoqoBackendError> {
+        device.clone().change_qubit_positions(&self.new_positions)
+    }
 }
 
 // Implementing the InvolveQubits trait for PragmaShiftQRydQubit.
@@ -186,6 +271,7 @@ impl roqoqo::operations::SupportedVersion for PragmaShiftQRydQubit {}
     serde::Serialize,
     serde::Deserialize,
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PragmaDeactivateQRydQubit {
     /// The qubit to deactivate.
     pub qubit: usize,
@@ -200,11 +286,9 @@ impl Substitute for PragmaDeactivateQRydQubit {
     }
 
     fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
-        if let Some((index, _)) = mapping.iter().next() {
-            Err(RoqoqoError::QubitMappingError { qubit: *index })
-        } else {
-            Ok(self.clone())
-        }
+        Ok(Self {
+            qubit: *mapping.get(&self.qubit).unwrap_or(&self.qubit),
+        })
     }
 }
 
@@ -247,21 +331,20 @@ impl roqoqo::operations::SupportedVersion for PragmaDeactivateQRydQubit {}
 /// This PRAGMA Operation lists the shift operations to be executed in a QRyd Tweezer device.
 ///
 /// Each tuple contains first the starting tweezer identifier and second the ending tweezer identifier.
-/// Multiple instances indicate parallel operations.
+/// Sequential shifts are executed in the order of the list, each shift sees the qubits moved by
+/// the previous ones. Parallel shifts are executed at the same time, their paths may not cross.
 ///
-#[derive(
-    Debug,
-    Clone,
-    PartialEq,
-    Eq,
-    roqoqo_derive::Operate,
-    roqoqo_derive::OperatePragma,
-    serde::Serialize,
-    serde::Deserialize,
-)]
+#[derive(Debug, Clone, roqoqo_derive::OperatePragma, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PragmaShiftQubitsTweezers {
-    /// The list of shifts that can run in parallel.
+    /// The list of shifts.
     pub shifts: Vec<(usize, usize)>,
+    /// The duration of each shift, None to use the transport times of the device.
+    #[serde(default)]
+    pub durations: Option<Vec<f64>>,
+    /// Whether the shifts are executed in parallel instead of one after the other.
+    #[serde(default)]
+    pub parallel: bool,
 }
 
 impl Substitute for PragmaShiftQubitsTweezers {
@@ -272,18 +355,130 @@ impl Substitute for PragmaShiftQubitsTweezers {
         Ok(self.clone())
     }
 
-    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
-        let mut new_shifts = Vec::<(usize, usize)>::with_capacity(self.shifts.len());
-        for (start, end) in self.shifts.iter() {
-            let new_start = mapping.get(start).unwrap_or(start);
-            let new_end = mapping.get(end).unwrap_or(end);
-            new_shifts.push((*new_start, *new_end));
-        }
-        Ok(Self { shifts: new_shifts })
+    // The shifts refer to tweezers, relabeling the qubits does not change them
+    fn remap_qubits(&self, _mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+/// Bincode layout of PragmaShiftQubitsTweezers before the durations and the parallel flag were added.
+#[derive(serde::Deserialize)]
+struct PragmaShiftQubitsTweezersLegacy {
+    shifts: Vec<(usize, usize)>,
+}
+
+// The durations are compared with total_cmp so that the operation can implement Eq.
+impl PartialEq for PragmaShiftQubitsTweezers {
+    fn eq(&self, other: &Self) -> bool {
+        let durations_eq =
+            match (&self.durations, &other.durations) {
+                (Some(durations), Some(other_durations)) => {
+                    durations.len() == other_durations.len()
+                        && durations.iter().zip(other_durations).all(
+                            |(duration, other_duration)| duration.total_cmp(other_duration).is_eq(),
+                        )
+                }
+                (None, None) => true,
+                _ => false,
+            };
+        self.shifts == other.shifts && self.parallel == other.parallel && durations_eq
     }
 }
 
+impl Eq for PragmaShiftQubitsTweezers {}
+
 impl PragmaShiftQubitsTweezers {
+    /// Creates a new instance of `PragmaShiftQubitsTweezers` executing the shifts one after the other.
+    ///
+    /// The shifts take the transport times of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `shifts` - The list of shifts.
+    #[inline]
+    pub fn new(shifts: Vec<(usize, usize)>) -> Self {
+        Self {
+            shifts,
+            durations: None,
+            parallel: false,
+        }
+    }
+
+    /// Creates a new instance of `PragmaShiftQubitsTweezers` executing the shifts in parallel.
+    ///
+    /// The shifts take the transport times of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `shifts` - The list of shifts.
+    #[inline]
+    pub fn new_parallel(shifts: Vec<(usize, usize)>) -> Self {
+        Self {
+            shifts,
+            durations: None,
+            parallel: true,
+        }
+    }
+
+    /// Creates a new instance of `PragmaShiftQubitsTweezers` with a duration for each shift.
+    ///
+    /// # Arguments
+    ///
+    /// * `shifts` - The list of shifts.
+    /// * `durations` - The duration of each shift.
+    /// * `parallel` - Whether the shifts are executed in parallel instead of one after the other.
+    #[inline]
+    pub fn with_durations(
+        shifts: Vec<(usize, usize)>,
+        durations: Vec<f64>,
+        parallel: bool,
+    ) -> Self {
+        Self {
+            shifts,
+            durations: Some(durations),
+            parallel,
+        }
+    }
+
+    /// Returns the value of the field `shifts`.
+    #[inline]
+    pub fn shifts(&self) -> &Vec<(usize, usize)> {
+        &self.shifts
+    }
+
+    /// Returns the value of the field `durations`.
+    #[inline]
+    pub fn durations(&self) -> &Option<Vec<f64>> {
+        &self.durations
+    }
+
+    /// Returns the value of the field `parallel`.
+    #[inline]
+    pub fn parallel(&self) -> &bool {
+        &self.parallel
+    }
+
+    /// Deserializes a PragmaShiftQubitsTweezers from its bincode representation.
+    ///
+    /// Operations serialized before the durations and the parallel flag were added only contain
+    /// the shifts. They are read as sequential shifts using the transport times of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bincode representation, e.g. the wrapped operation of a PragmaChangeDevice.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PragmaShiftQubitsTweezers)` - The deserialized operation.
+    /// * `Err(bincode::Error)` - The bytes are neither in the current nor in the legacy layout.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize::<Self>(bytes).or_else(|err| {
+            bincode::deserialize::<PragmaShiftQubitsTweezersLegacy>(bytes)
+                .map(|legacy| Self::new(legacy.shifts))
+                .map_err(|_| err)
+        })
+    }
+
     /// Wrap PragmaShiftQubitsTweezers in PragmaChangeDevice operation
     ///
     /// PragmaShiftQubitsTweezers is device specific and can not be directly added to a Circuit.
@@ -305,6 +500,25 @@ impl PragmaShiftQubitsTweezers {
     }
 }
 
+// Implemented by hand instead of derived to keep `new` taking only the shifts.
+impl Operate for PragmaShiftQubitsTweezers {
+    /// Returns `true` when Operation has symbolic parameters.
+    #[inline]
+    fn is_parametrized(&self) -> bool {
+        false
+    }
+    /// Returns tags classifying the type of the Operation.
+    #[inline]
+    fn tags(&self) -> &'static [&'static str] {
+        TAGS_PragmaShiftQubitsTweezers
+    }
+    /// Returns hqslang name of the Operation.
+    #[inline]
+    fn hqslang(&self) -> &'static str {
+        "PragmaShiftQubitsTweezers"
+    }
+}
+
 // Implementing the InvolveQubits trait for PragmaShiftQubitsTweezers.
 impl InvolveQubits for PragmaShiftQubitsTweezers {
     /// Lists all involved qubits (here, All).
@@ -334,6 +548,7 @@ impl roqoqo::operations::SupportedVersion for PragmaShiftQubitsTweezers {}
     serde::Serialize,
     serde::Deserialize,
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct PragmaSwitchDeviceLayout {
     /// The name of the new layout the device is changed to.
     new_layout: String,
@@ -391,3 +606,302 @@ impl InvolveQubits for PragmaSwitchDeviceLayout {
 #[allow(non_upper_case_globals)]
 const TAGS_PragmaSwitchDeviceLayout: &[&str; 3] =
     &["Operation", "PragmaOperation", "PragmaSwitchDeviceLayout"];
+
+/// This PRAGMA Operation exchanges the qubits of two occupied tweezers in a QRyd Tweezer device.
+///
+/// The qubits trapped in the two tweezers swap places, the qubit -> tweezer mapping of the
+/// device is updated in one step.
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::OperatePragma,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaSwapTweezers {
+    /// The first tweezer of the exchange.
+    pub tweezer_0: usize,
+    /// The second tweezer of the exchange.
+    pub tweezer_1: usize,
+}
+
+impl Substitute for PragmaSwapTweezers {
+    fn substitute_parameters(
+        &self,
+        _calculator: &qoqo_calculator::Calculator,
+    ) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+
+    // The exchanged tweezers do not change when the qubits are relabeled
+    fn remap_qubits(&self, _mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+impl PragmaSwapTweezers {
+    /// Wrap PragmaSwapTweezers in PragmaChangeDevice operation
+    ///
+    /// PragmaSwapTweezers is device specific and can not be directly added to a Circuit.
+    /// Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+    /// to the circuit.
+    pub fn to_pragma_change_device(&self) -> Result<PragmaChangeDevice, RoqoqoBackendError> {
+        Ok(PragmaChangeDevice {
+            wrapped_tags: self.tags().iter().map(|s| s.to_string()).collect(),
+            wrapped_hqslang: self.hqslang().to_string(),
+            wrapped_operation: serialize(&self).map_err(|err| {
+                RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Error occured during serialisation of PragmaSwapTweezers {:?}",
+                        err
+                    ),
+                }
+            })?,
+        })
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaSwapTweezers.
+impl InvolveQubits for PragmaSwapTweezers {
+    /// Lists all involved qubits (here, All).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaSwapTweezers: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSwapTweezers"];
+
+impl roqoqo::operations::SupportedVersion for PragmaSwapTweezers {}
+
+/// This PRAGMA Operation applies a global single-qubit rotation to all qubits of a QRyd Tweezer device.
+///
+/// Rydberg platforms drive global Raman pulses addressing all atoms simultaneously. The
+/// operation acts as a RotateXY gate with the same angles on every active qubit of the device
+/// and takes the global rotation time of the current Layout.
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::OperatePragma,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaGlobalRotateXY {
+    /// The angle theta of the rotation, in the interval from 0 to 2 * 2pi.
+    theta: CalculatorFloat,
+    /// The rotation axis, in spherical coordinates phi gives the angle in the x-y plane.
+    phi: CalculatorFloat,
+}
+
+impl Substitute for PragmaGlobalRotateXY {
+    fn substitute_parameters(
+        &self,
+        calculator: &qoqo_calculator::Calculator,
+    ) -> Result<Self, RoqoqoError> {
+        Ok(Self {
+            theta: CalculatorFloat::from(calculator.parse_get(self.theta.clone())?),
+            phi: CalculatorFloat::from(calculator.parse_get(self.phi.clone())?),
+        })
+    }
+
+    fn remap_qubits(&self, _mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+impl PragmaGlobalRotateXY {
+    /// Wrap PragmaGlobalRotateXY in PragmaChangeDevice operation
+    ///
+    /// PragmaGlobalRotateXY is device specific and can not be directly added to a Circuit.
+    /// Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+    /// to the circuit.
+    pub fn to_pragma_change_device(&self) -> Result<PragmaChangeDevice, RoqoqoBackendError> {
+        Ok(PragmaChangeDevice {
+            wrapped_tags: self.tags().iter().map(|s| s.to_string()).collect(),
+            wrapped_hqslang: self.hqslang().to_string(),
+            wrapped_operation: serialize(&self).map_err(|err| {
+                RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Error occured during serialisation of PragmaGlobalRotateXY {:?}",
+                        err
+                    ),
+                }
+            })?,
+        })
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaGlobalRotateXY.
+impl InvolveQubits for PragmaGlobalRotateXY {
+    /// Lists all involved qubits (here, All).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaGlobalRotateXY: &[&str; 3] =
+    &["Operation", "PragmaOperation", "PragmaGlobalRotateXY"];
+
+impl roqoqo::operations::SupportedVersion for PragmaGlobalRotateXY {}
+
+/// This PRAGMA Operation moves qubits into a zone of a QRyd Tweezer device.
+///
+/// The device resolves the operation into shifts of the qubits into the nearest free tweezers
+/// labelled with the zone, so that transfers between e.g. the storage and the interaction zone
+/// can be expressed without giving the tweezers.
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::OperatePragma,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaMoveToZone {
+    /// The qubits to move, in the order they are moved.
+    pub qubits: Vec<usize>,
+    /// The label of the zone the qubits are moved into.
+    pub zone: String,
+}
+
+impl Substitute for PragmaMoveToZone {
+    fn substitute_parameters(
+        &self,
+        _calculator: &qoqo_calculator::Calculator,
+    ) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+
+    fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(Self {
+            qubits: self
+                .qubits
+                .iter()
+                .map(|qubit| *mapping.get(qubit).unwrap_or(qubit))
+                .collect(),
+            zone: self.zone.clone(),
+        })
+    }
+}
+
+impl PragmaMoveToZone {
+    /// Wrap PragmaMoveToZone in PragmaChangeDevice operation
+    ///
+    /// PragmaMoveToZone is device specific and can not be directly added to a Circuit.
+    /// Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+    /// to the circuit.
+    pub fn to_pragma_change_device(&self) -> Result<PragmaChangeDevice, RoqoqoBackendError> {
+        Ok(PragmaChangeDevice {
+            wrapped_tags: self.tags().iter().map(|s| s.to_string()).collect(),
+            wrapped_hqslang: self.hqslang().to_string(),
+            wrapped_operation: serialize(&self).map_err(|err| {
+                RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Error occured during serialisation of PragmaMoveToZone {:?}",
+                        err
+                    ),
+                }
+            })?,
+        })
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaMoveToZone.
+impl InvolveQubits for PragmaMoveToZone {
+    /// Lists all involved qubits (here, All).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaMoveToZone: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaMoveToZone"];
+
+impl roqoqo::operations::SupportedVersion for PragmaMoveToZone {}
+
+/// This PRAGMA Operation sets the phase relations of a QRyd Tweezer device.
+///
+/// The phase relations give the phase of the PhaseShiftedControlledZ and
+/// PhaseShiftedControlledPhase gates. Changing them mid-circuit expresses circuits calibrated
+/// for different interaction strengths in a single program.
+///
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    roqoqo_derive::Operate,
+    roqoqo_derive::OperatePragma,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct PragmaSetPhaseRelation {
+    /// The relation to use for the PhaseShiftedControlledZ gate.
+    pub cz_relation: String,
+    /// The relation to use for the PhaseShiftedControlledPhase gate.
+    pub cp_relation: String,
+}
+
+impl Substitute for PragmaSetPhaseRelation {
+    fn substitute_parameters(
+        &self,
+        _calculator: &qoqo_calculator::Calculator,
+    ) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+
+    fn remap_qubits(&self, _mapping: &HashMap<usize, usize>) -> Result<Self, RoqoqoError> {
+        Ok(self.clone())
+    }
+}
+
+impl PragmaSetPhaseRelation {
+    /// Wrap PragmaSetPhaseRelation in PragmaChangeDevice operation
+    ///
+    /// PragmaSetPhaseRelation is device specific and can not be directly added to a Circuit.
+    /// Instead it is first wrapped in a PragmaChangeDevice operation that is in turn added
+    /// to the circuit.
+    pub fn to_pragma_change_device(&self) -> Result<PragmaChangeDevice, RoqoqoBackendError> {
+        Ok(PragmaChangeDevice {
+            wrapped_tags: self.tags().iter().map(|s| s.to_string()).collect(),
+            wrapped_hqslang: self.hqslang().to_string(),
+            wrapped_operation: serialize(&self).map_err(|err| {
+                RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Error occured during serialisation of PragmaSetPhaseRelation {:?}",
+                        err
+                    ),
+                }
+            })?,
+        })
+    }
+}
+
+// Implementing the InvolveQubits trait for PragmaSetPhaseRelation.
+impl InvolveQubits for PragmaSetPhaseRelation {
+    /// Lists all involved qubits (here, All).
+    fn involved_qubits(&self) -> InvolvedQubits {
+        InvolvedQubits::All
+    }
+}
+
+#[allow(non_upper_case_globals)]
+const TAGS_PragmaSetPhaseRelation: &[&str; 3] =
+    &["Operation", "PragmaOperation", "PragmaSetPhaseRelation"];
+
+impl roqoqo::operations::SupportedVersion for PragmaSetPhaseRelation {}
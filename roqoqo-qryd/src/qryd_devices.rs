@@ -24,7 +24,10 @@ use std::str::FromStr;
 use roqoqo::devices::{Device, GenericDevice};
 use roqoqo::RoqoqoBackendError;
 
-use crate::{phi_theta_relation, PragmaChangeQRydLayout, PragmaShiftQRydQubit};
+use crate::{
+    phi_theta_relation, PragmaChangeQRydLayout, PragmaShiftQRydQubit,
+    ALLOWED_NATIVE_THREE_QUBIT_GATES,
+};
 
 /// Collection of all QRyd devices
 ///
@@ -55,6 +58,13 @@ impl QRydDevice {
         }
     }
 
+    /// Returns the number of tweezer positions in each row.
+    pub fn columns_per_row(&self) -> Vec<usize> {
+        match self {
+            QRydDevice::FirstDevice(x) => x.columns_per_row(),
+        }
+    }
+
     /// Change the positions of the qubits in their rows.
     ///
     /// The occupation of the available tweezer positions can be changed.
@@ -143,6 +153,56 @@ impl QRydDevice {
                 .map(QRydDevice::FirstDevice),
         }
     }
+
+    /// Sets the model of the distance dependence of the two-qubit gates.
+    ///
+    /// # Arguments
+    ///
+    /// `model` - The new model, None to use the default model
+    pub fn set_distance_gate_model(
+        &mut self,
+        model: Option<DistanceGateModel>,
+    ) -> Result<(), RoqoqoBackendError> {
+        match self {
+            QRydDevice::FirstDevice(x) => x.set_distance_gate_model(model),
+        }
+    }
+
+    /// Returns the fidelity of a two-qubit gate between two qubits.
+    pub fn two_qubit_gate_fidelity(
+        &self,
+        hqslang: &str,
+        control: &usize,
+        target: &usize,
+    ) -> Option<f64> {
+        match self {
+            QRydDevice::FirstDevice(x) => x.two_qubit_gate_fidelity(hqslang, control, target),
+        }
+    }
+
+    /// Add a new layout with explicit tweezer positions to the device.
+    ///
+    /// # Arguments
+    ///
+    /// `layout_number` - The number index that is assigned to the new layout
+    /// `positions` - The (x, y) position of every tweezer, given row by row
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Self)` - A clone of the device with the new layout added
+    /// `Err(RoqoqoBackendError)` - The layout_number index is already in use
+    ///                             or the positions do not fit the tweezers per row
+    pub fn add_irregular_layout(
+        &self,
+        layout_number: usize,
+        positions: Vec<Vec<(f64, f64)>>,
+    ) -> Result<Self, RoqoqoBackendError> {
+        match self {
+            QRydDevice::FirstDevice(x) => x
+                .add_irregular_layout(layout_number, positions)
+                .map(QRydDevice::FirstDevice),
+        }
+    }
 }
 
 impl Device for QRydDevice {
@@ -222,8 +282,11 @@ impl From<FirstDevice> for QRydDevice {
 pub struct FirstDevice {
     /// Fixed number of rows in the optical lattice
     number_rows: usize,
-    /// Fixed number of columns in the optical lattice
+    /// Fixed number of columns in the optical lattice, the largest number of tweezers in a row
     number_columns: usize,
+    /// Number of tweezers in each row, None if every row has `number_columns` tweezers
+    #[serde(default)]
+    columns_per_row: Option<Vec<usize>>,
     /// Each numbered qubit is assigned to a position in the row-column grid.
     /// The first tuple value gives the integer index of the row, the second of the column.
     /// The data structure can handle arbitrary changes in occupation, but we enforce a fixed
@@ -233,6 +296,9 @@ pub struct FirstDevice {
     row_distance: f64,
     /// Positions of tweezers in each row
     layout_register: HashMap<usize, Array2<f64>>,
+    /// Layouts with explicit (x, y) positions of the tweezers, given row by row
+    #[serde(default)]
+    irregular_layout_register: HashMap<usize, Vec<Vec<(f64, f64)>>>,
     /// The current chosen layout;
     current_layout: usize,
     /// The distance cut-off above which two-qubit gates are not possible
@@ -247,6 +313,9 @@ pub struct FirstDevice {
     allow_ccz_gate: bool,
     /// Whether the device allows ControlledControlledPhaseShift operations.
     allow_ccp_gate: bool,
+    /// Model of the distance dependence of the two-qubit gates, None for the default quadratic model
+    #[serde(default)]
+    distance_gate_model: Option<DistanceGateModel>,
 }
 
 impl FirstDevice {
@@ -317,9 +386,11 @@ impl FirstDevice {
         let return_self = Self {
             number_rows,
             number_columns,
+            columns_per_row: None,
             qubit_positions,
             row_distance,
             layout_register,
+            irregular_layout_register: HashMap::new(),
             current_layout,
             cutoff: 1.0,
             controlled_z_phase_relation,
@@ -327,16 +398,143 @@ impl FirstDevice {
             // multi_qubit_operations: true,
             allow_ccz_gate,
             allow_ccp_gate,
+            distance_gate_model: None,
         }
         .add_layout(0, initial_layout)?;
         Ok(return_self)
     }
 
-    /// Returns the number of qubits in the device.
+    /// Create new `First` QRyd device with a different number of tweezers in each row.
+    ///
+    /// The tweezers do not need to form a regular grid, the (x, y) position of every tweezer
+    /// is given explicitly. After creation the device will be in the initial layout with layout number 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits_per_row` - Fixed number of occupied tweezer positions in each row.
+    /// * `initial_positions` - The (x, y) position of every tweezer, given row by row.
+    ///                         The number of positions in a row fixes the number of tweezers in the row for all layouts.
+    /// * `controlled_z_phase_relation` - The relation to use for the PhaseShiftedControlledZ gate.
+    ///                                   It can be hardcoded to a specific value if a float is passed in as String.
+    /// * `controlled_phase_phase_relation` - The relation to use for the PhaseShiftedControlledPhase gate.
+    /// * `allow_ccz_gate` - Whether to allow ControlledControlledPauliZ operations in the device.
+    /// * `allow_ccp_gate` - Whether to allow ControlledControlledPhaseShift operations in the device.
+    pub fn new_ragged(
+        qubits_per_row: &[usize],
+        initial_positions: Vec<Vec<(f64, f64)>>,
+        controlled_z_phase_relation: Option<String>,
+        controlled_phase_phase_relation: Option<String>,
+        allow_ccz_gate: Option<bool>,
+        allow_ccp_gate: Option<bool>,
+    ) -> Result<Self, RoqoqoBackendError> {
+        let number_rows = initial_positions.len();
+        if qubits_per_row.len() != number_rows {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Device has {} rows but for {} rows qubit numbers have been specified",
+                    number_rows,
+                    qubits_per_row.len()
+                ),
+            });
+        }
+        let columns_per_row: Vec<usize> = initial_positions.iter().map(Vec::len).collect();
+        for (row, (number_qubits_row, number_columns_row)) in qubits_per_row
+            .iter()
+            .zip(columns_per_row.iter())
+            .enumerate()
+        {
+            if number_qubits_row > number_columns_row {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Row {} has {} tweezers but {} qubits have been specified",
+                        row, number_columns_row, number_qubits_row
+                    ),
+                });
+            }
+        }
+        let number_columns = columns_per_row.iter().copied().max().unwrap_or(0);
+        let mut qubit_positions: HashMap<usize, (usize, usize)> = HashMap::new();
+        let mut number_qubits: usize = 0;
+        for (row, number_qubits_row) in qubits_per_row.iter().enumerate() {
+            for i in 0..*number_qubits_row {
+                qubit_positions.insert(number_qubits + i, (row, i));
+            }
+            number_qubits += number_qubits_row;
+        }
+        Self {
+            number_rows,
+            number_columns,
+            columns_per_row: Some(columns_per_row),
+            qubit_positions,
+            row_distance: 0.0,
+            layout_register: HashMap::new(),
+            irregular_layout_register: HashMap::new(),
+            current_layout: 0,
+            cutoff: 1.0,
+            controlled_z_phase_relation: controlled_z_phase_relation
+                .unwrap_or_else(|| "DefaultRelation".to_string()),
+            controlled_phase_phase_relation: controlled_phase_phase_relation
+                .unwrap_or_else(|| "DefaultRelation".to_string()),
+            allow_ccz_gate: allow_ccz_gate.unwrap_or(true),
+            allow_ccp_gate: allow_ccp_gate.unwrap_or(false),
+            distance_gate_model: None,
+        }
+        .add_irregular_layout(0, initial_positions)
+    }
+
+    /// Sets the distance cutoff above which two-qubit gates are not possible.
+    ///
+    /// The connectivity of the device is derived from the cutoff, so `two_qubit_gate_time`,
+    /// `two_qubit_edges` and `to_generic_device` use the new cutoff right away.
     pub fn set_cutoff(&mut self, cutoff: f64) {
         self.cutoff = cutoff;
     }
 
+    /// Returns the distance cutoff above which two-qubit gates are not possible.
+    pub fn cutoff(&self) -> f64 {
+        self.cutoff
+    }
+
+    /// Sets the model of the distance dependence of the two-qubit gates.
+    ///
+    /// The gate times and fidelities are calculated from the current tweezer positions, so
+    /// moving qubits with `PragmaShiftQRydQubit` or switching the layout updates them right away.
+    /// The cutoff still limits the distance at which two-qubit gates are available.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The new model, None to use the default model with a gate time of `2e-6 * distance^2`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The model has been set.
+    /// * `Err(RoqoqoBackendError)` - A parameter of the model is not valid.
+    pub fn set_distance_gate_model(
+        &mut self,
+        model: Option<DistanceGateModel>,
+    ) -> Result<(), RoqoqoBackendError> {
+        if let Some(model) = &model {
+            model.validate()?;
+        }
+        self.distance_gate_model = model;
+        Ok(())
+    }
+
+    /// Returns the model of the distance dependence of the two-qubit gates, None for the default model.
+    pub fn distance_gate_model(&self) -> Option<&DistanceGateModel> {
+        self.distance_gate_model.as_ref()
+    }
+
+    /// Returns the relation used for the PhaseShiftedControlledZ gate.
+    pub fn controlled_z_phase_relation(&self) -> &str {
+        &self.controlled_z_phase_relation
+    }
+
+    /// Returns the relation used for the PhaseShiftedControlledPhase gate.
+    pub fn controlled_phase_phase_relation(&self) -> &str {
+        &self.controlled_phase_phase_relation
+    }
+
     /// Returns the number of rows of optical tweezers in the two-dimensional grid of potential qubit positions.
     pub fn number_rows(&self) -> usize {
         self.number_rows
@@ -347,11 +545,112 @@ impl FirstDevice {
         self.number_columns
     }
 
+    /// Returns the number of tweezer positions in each row.
+    pub fn columns_per_row(&self) -> Vec<usize> {
+        self.columns_per_row
+            .clone()
+            .unwrap_or_else(|| vec![self.number_columns; self.number_rows])
+    }
+
+    /// Returns the number of tweezer positions in a row, None if the row does not exist.
+    fn number_columns_in_row(&self, row: usize) -> Option<usize> {
+        if row >= self.number_rows {
+            return None;
+        }
+        match &self.columns_per_row {
+            Some(columns) => columns.get(row).copied(),
+            None => Some(self.number_columns),
+        }
+    }
+
     /// Returns the position of each qubit in the row-column grid of tweezer positions.
     pub fn qubit_positions(&self) -> &HashMap<usize, (usize, usize)> {
         &self.qubit_positions
     }
 
+    /// Returns whether the device allows ControlledControlledPauliZ operations.
+    pub fn allow_ccz_gate(&self) -> bool {
+        self.allow_ccz_gate
+    }
+
+    /// Sets whether the device allows ControlledControlledPauliZ operations.
+    pub fn set_allow_ccz_gate(&mut self, allow_ccz_gate: bool) {
+        self.allow_ccz_gate = allow_ccz_gate;
+    }
+
+    /// Returns whether the device allows ControlledControlledPhaseShift operations.
+    pub fn allow_ccp_gate(&self) -> bool {
+        self.allow_ccp_gate
+    }
+
+    /// Sets whether the device allows ControlledControlledPhaseShift operations.
+    pub fn set_allow_ccp_gate(&mut self, allow_ccp_gate: bool) {
+        self.allow_ccp_gate = allow_ccp_gate;
+    }
+
+    /// Returns the three-qubit gates allowed in the device.
+    ///
+    /// The names are a subset of [crate::ALLOWED_NATIVE_THREE_QUBIT_GATES], the native
+    /// three-qubit gates of a [crate::TweezerDevice].
+    pub fn available_three_qubit_gates(&self) -> Vec<&'static str> {
+        ALLOWED_NATIVE_THREE_QUBIT_GATES
+            .iter()
+            .copied()
+            .filter(|gate| match *gate {
+                "ControlledControlledPauliZ" => self.allow_ccz_gate,
+                "ControlledControlledPhaseShift" => self.allow_ccp_gate,
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Returns all qubit triples a three-qubit gate is available on.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The name of the three-qubit gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(usize, usize, usize)>` - The (control_0, control_1, target) triples, sorted.
+    pub fn three_qubit_gate_triples(&self, hqslang: &str) -> Vec<(usize, usize, usize)> {
+        let mut qubits: Vec<usize> = self.qubit_positions.keys().copied().collect();
+        qubits.sort_unstable();
+        qubits
+            .iter()
+            .copied()
+            .permutations(3)
+            .map(|triple| (triple[0], triple[1], triple[2]))
+            .filter(|(control_0, control_1, target)| {
+                self.three_qubit_gate_time(hqslang, control_0, control_1, target)
+                    .is_some()
+            })
+            .collect()
+    }
+
+    /// Returns the physical (x, y) position of a tweezer in the current layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The row and column of the tweezer.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((f64, f64))` - The position of the tweezer.
+    /// * `None` - The tweezer does not exist in the device.
+    pub fn tweezer_position(&self, position: &(usize, usize)) -> Option<(f64, f64)> {
+        if position.1 >= self.number_columns_in_row(position.0)? {
+            return None;
+        }
+        if let Some(layout) = self.irregular_layout_register.get(&self.current_layout) {
+            return layout.get(position.0)?.get(position.1).copied();
+        }
+        let layout = self.layout_register.get(&self.current_layout)?;
+        layout
+            .get(*position)
+            .map(|y| (self.row_distance * position.0 as f64, *y))
+    }
+
     /// Returns the PhaseShiftedControlledZ phase shift according to the device's relation.
     ///
     /// # Returns
@@ -461,7 +760,9 @@ impl FirstDevice {
         layout_number: usize,
         layout: Array2<f64>,
     ) -> Result<Self, RoqoqoBackendError> {
-        if self.layout_register.contains_key(&layout_number) {
+        if self.layout_register.contains_key(&layout_number)
+            || self.irregular_layout_register.contains_key(&layout_number)
+        {
             return Err(RoqoqoBackendError::GenericError {
                 msg: format!(
                     "Error adding layout to QRyd device layout key {} is already used for layout {:?}",
@@ -484,13 +785,62 @@ impl FirstDevice {
         Ok(self_clone)
     }
 
+    /// Add a new layout with explicit tweezer positions to the device.
+    ///
+    /// In contrast to [FirstDevice::add_layout] the tweezers do not need to lie on rows with a fixed distance.
+    /// The number of positions in each row has to match the number of tweezers in the row.
+    ///
+    /// # Arguments
+    ///
+    /// `layout_number` - The number index that is assigned to the new layout
+    /// `positions` - The (x, y) position of every tweezer, given row by row
+    ///
+    /// # Returns
+    ///
+    /// `Ok(Self)` - A clone of the device with the new layout added
+    /// `Err(RoqoqoBackendError)` - The layout_number index is already in use
+    ///                             or the positions do not fit the tweezers per row
+    pub fn add_irregular_layout(
+        &self,
+        layout_number: usize,
+        positions: Vec<Vec<(f64, f64)>>,
+    ) -> Result<Self, RoqoqoBackendError> {
+        if self.layout_register.contains_key(&layout_number)
+            || self.irregular_layout_register.contains_key(&layout_number)
+        {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error adding layout to QRyd device layout key {} is already used",
+                    layout_number
+                ),
+            });
+        }
+        let columns_per_row = self.columns_per_row();
+        let positions_per_row: Vec<usize> = positions.iter().map(Vec::len).collect();
+        if positions_per_row != columns_per_row {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error adding layout to QRyd device new layout {:?} tweezers per row required",
+                    columns_per_row
+                ),
+            });
+        }
+        let mut self_clone = self.clone();
+        self_clone
+            .irregular_layout_register
+            .insert(layout_number, positions);
+        Ok(self_clone)
+    }
+
     /// Switch to a different pre-defined layout.
     ///
     /// # Arguments
     ///
     /// `layout_number` - The number index of the new layout
     pub fn switch_layout(&mut self, layout_number: &usize) -> Result<(), RoqoqoBackendError> {
-        if self.layout_register.contains_key(layout_number) {
+        if self.layout_register.contains_key(layout_number)
+            || self.irregular_layout_register.contains_key(layout_number)
+        {
             self.current_layout = *layout_number;
             Ok(())
         } else {
@@ -517,7 +867,7 @@ impl FirstDevice {
         new_positions: &HashMap<usize, (usize, usize)>,
     ) -> Result<(), RoqoqoBackendError> {
         for (qubit, (old_row, _)) in self.qubit_positions.iter() {
-            let (new_row, _) =
+            let (new_row, new_column) =
                 new_positions
                     .get(qubit)
                     .ok_or(RoqoqoBackendError::GenericError {
@@ -527,6 +877,15 @@ impl FirstDevice {
                 return Err(RoqoqoBackendError::GenericError {
                     msg: format!("New qubit positions has a mismatch in rows for qubit {} old row {} new row {}", qubit, old_row, new_row)});
             }
+            let number_columns_row = self.number_columns_in_row(*new_row).unwrap_or(0);
+            if *new_column >= number_columns_row {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "New position of qubit {} is column {} but row {} only has {} tweezers",
+                        qubit, new_column, new_row, number_columns_row
+                    ),
+                });
+            }
         }
 
         if new_positions
@@ -542,6 +901,351 @@ impl FirstDevice {
         self.qubit_positions.clone_from(new_positions);
         Ok(())
     }
+
+    /// Returns the gate time of a two-qubit gate between two tweezers of the current layout.
+    ///
+    /// The gate is available when the distance between the tweezers is at most the cutoff.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The name of the two-qubit gate.
+    /// * `control_position` - The row and column of the control tweezer.
+    /// * `target_position` - The row and column of the target tweezer.
+    ///
+    /// # Returns
+    ///
+    /// * `Some<f64>` - The gate time.
+    /// * `None` - The gate is not available between the tweezers.
+    pub(crate) fn tweezer_two_qubit_gate_time(
+        &self,
+        hqslang: &str,
+        control_position: &(usize, usize),
+        target_position: &(usize, usize),
+    ) -> Option<f64> {
+        // Check for type of gate (as well as checking phi-theta relation)
+        if !FIRST_DEVICE_TWO_QUBIT_GATES.contains(&hqslang) {
+            return None;
+        }
+        // The following is just an example of how the availability of gates and the gate time could be calculated based on a simple theoretical model (using physical distance)
+        // For the actual device  more complex models or a lookup of callibration data can be performed instead
+        // Calculate the physical distance
+        let (control_x, control_y) = self.tweezer_position(control_position)?;
+        let (target_x, target_y) = self.tweezer_position(target_position)?;
+        let total_distance =
+            ((control_x - target_x).powi(2) + (control_y - target_y).powi(2)).sqrt();
+        if total_distance > self.cutoff {
+            None
+        } else {
+            match &self.distance_gate_model {
+                Some(model) => Some(model.gate_time(total_distance)),
+                // Example of gate time dependence on distance. Here gate time increases with the square of the distance.
+                None => Some(2e-6 * total_distance.powi(2)),
+            }
+        }
+    }
+
+    /// Returns the fidelity of a two-qubit gate between two qubits.
+    ///
+    /// The fidelity is calculated from the distance between the qubits with the distance gate
+    /// model of the device. The default model is noise free and has a fidelity of 1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The name of the two-qubit gate.
+    /// * `control` - The control qubit the gate acts on.
+    /// * `target` - The target qubit the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Some<f64>` - The gate fidelity.
+    /// * `None` - The gate is not available on the device.
+    pub fn two_qubit_gate_fidelity(
+        &self,
+        hqslang: &str,
+        control: &usize,
+        target: &usize,
+    ) -> Option<f64> {
+        let gate_time = self.two_qubit_gate_time(hqslang, control, target)?;
+        match &self.distance_gate_model {
+            Some(model) => Some(model.fidelity(gate_time)),
+            None => Some(1.0),
+        }
+    }
+
+    /// Returns the gate time of a three-qubit gate between three tweezers of the current layout.
+    ///
+    /// The gate is available when the underlying two-qubit gate is available between every
+    /// pair of the three distinct tweezers.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The name of the three-qubit gate.
+    /// * `control_0_position` - The row and column of the first control tweezer.
+    /// * `control_1_position` - The row and column of the second control tweezer.
+    /// * `target_position` - The row and column of the target tweezer.
+    ///
+    /// # Returns
+    ///
+    /// * `Some<f64>` - The gate time.
+    /// * `None` - The gate is not available on the tweezers.
+    pub(crate) fn tweezer_three_qubit_gate_time(
+        &self,
+        hqslang: &str,
+        control_0_position: &(usize, usize),
+        control_1_position: &(usize, usize),
+        target_position: &(usize, usize),
+    ) -> Option<f64> {
+        if control_0_position == control_1_position
+            || control_0_position == target_position
+            || control_1_position == target_position
+        {
+            return None;
+        }
+        let two_qubit_gate = match hqslang {
+            "ControlledControlledPauliZ" if self.allow_ccz_gate => "PhaseShiftedControlledZ",
+            "ControlledControlledPhaseShift" if self.allow_ccp_gate => {
+                "PhaseShiftedControlledPhase"
+            }
+            _ => return None,
+        };
+        if [
+            (control_0_position, target_position),
+            (control_0_position, control_1_position),
+            (control_1_position, target_position),
+        ]
+        .iter()
+        .all(|(first, second)| {
+            self.tweezer_two_qubit_gate_time(two_qubit_gate, first, second)
+                .is_some()
+        }) {
+            Some(1e-6)
+        } else {
+            None
+        }
+    }
+
+    /// Move a single qubit to an unoccupied tweezer position in its row.
+    ///
+    /// # Arguments
+    ///
+    /// `qubit` - The qubit that is moved
+    /// `column` - The new column of the qubit
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` - The qubit has been moved
+    /// `Err(RoqoqoBackendError)` - The qubit is not in the device or the new position does not exist or is occupied
+    pub fn set_qubit_position(
+        &mut self,
+        qubit: usize,
+        column: usize,
+    ) -> Result<(), RoqoqoBackendError> {
+        let (row, _) =
+            *self
+                .qubit_positions
+                .get(&qubit)
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!("Qubit {} is not in the device", qubit),
+                })?;
+        if let Some((other_qubit, _)) = self
+            .qubit_positions
+            .iter()
+            .find(|(other_qubit, position)| **other_qubit != qubit && **position == (row, column))
+        {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Tweezer ({}, {}) is already occupied by qubit {}",
+                    row, column, other_qubit
+                ),
+            });
+        }
+        let mut new_positions = self.qubit_positions.clone();
+        new_positions.insert(qubit, (row, column));
+        self.change_qubit_positions(&new_positions)
+    }
+
+    /// Set the physical (x, y) position of a tweezer in the current layout.
+    ///
+    /// A layout given by y-positions and the row distance is turned into a layout with explicit
+    /// tweezer positions the first time one of its tweezers is moved.
+    ///
+    /// # Arguments
+    ///
+    /// `position` - The row and column of the tweezer
+    /// `new_position` - The new (x, y) position of the tweezer
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` - The tweezer has been moved
+    /// `Err(RoqoqoBackendError)` - The tweezer does not exist in the device
+    pub fn set_tweezer_position(
+        &mut self,
+        position: &(usize, usize),
+        new_position: (f64, f64),
+    ) -> Result<(), RoqoqoBackendError> {
+        if self.tweezer_position(position).is_none() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Tweezer ({}, {}) does not exist in the current layout",
+                    position.0, position.1
+                ),
+            });
+        }
+        if !self
+            .irregular_layout_register
+            .contains_key(&self.current_layout)
+        {
+            let positions: Vec<Vec<(f64, f64)>> = self
+                .columns_per_row()
+                .iter()
+                .enumerate()
+                .map(|(row, number_columns_row)| {
+                    (0..*number_columns_row)
+                        .filter_map(|column| self.tweezer_position(&(row, column)))
+                        .collect()
+                })
+                .collect();
+            self.layout_register.remove(&self.current_layout);
+            self.irregular_layout_register
+                .insert(self.current_layout, positions);
+        }
+        if let Some(tweezer) = self
+            .irregular_layout_register
+            .get_mut(&self.current_layout)
+            .and_then(|layout| layout.get_mut(position.0))
+            .and_then(|row| row.get_mut(position.1))
+        {
+            *tweezer = new_position;
+        }
+        Ok(())
+    }
+}
+
+/// Two-qubit gates of a [FirstDevice], available between qubits closer than the cutoff.
+pub(crate) static FIRST_DEVICE_TWO_QUBIT_GATES: [&str; 2] =
+    ["PhaseShiftedControlledZ", "PhaseShiftedControlledPhase"];
+
+/// Model of the distance dependence of the two-qubit gates of a [FirstDevice].
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub enum DistanceGateModel {
+    /// The gate time grows with a power of the distance, `prefactor * distance^exponent`,
+    /// the fidelity is the same for all distances.
+    PowerLaw {
+        /// The gate time at distance 1.0.
+        prefactor: f64,
+        /// The exponent of the distance.
+        exponent: f64,
+        /// The fidelity of the gate.
+        fidelity: f64,
+    },
+    /// Van der Waals interaction with the strength `c6 / distance^6`.
+    ///
+    /// The gate time is the time to accumulate the interaction phase, `phase * distance^6 / c6`,
+    /// the fidelity decays exponentially with the gate time, `exp(-decay_rate * gate_time)`.
+    VanDerWaals {
+        /// The C6 interaction coefficient.
+        c6: f64,
+        /// The interaction phase accumulated during the gate.
+        phase: f64,
+        /// The decay rate of the fidelity during the gate.
+        decay_rate: f64,
+    },
+}
+
+impl DistanceGateModel {
+    /// Returns the gate time at a distance between two tweezers.
+    ///
+    /// # Arguments
+    ///
+    /// * `distance` - The distance between the tweezers.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The gate time.
+    pub fn gate_time(&self, distance: f64) -> f64 {
+        match self {
+            DistanceGateModel::PowerLaw {
+                prefactor,
+                exponent,
+                ..
+            } => prefactor * distance.powf(*exponent),
+            DistanceGateModel::VanDerWaals { c6, phase, .. } => phase * distance.powi(6) / c6,
+        }
+    }
+
+    /// Returns the fidelity of a gate with the given gate time.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate_time` - The gate time.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The gate fidelity.
+    pub fn fidelity(&self, gate_time: f64) -> f64 {
+        match self {
+            DistanceGateModel::PowerLaw { fidelity, .. } => *fidelity,
+            DistanceGateModel::VanDerWaals { decay_rate, .. } => (-decay_rate * gate_time).exp(),
+        }
+    }
+
+    /// Checks that the parameters of the model are valid.
+    fn validate(&self) -> Result<(), RoqoqoBackendError> {
+        let valid = match self {
+            DistanceGateModel::PowerLaw {
+                prefactor,
+                exponent,
+                fidelity,
+            } => {
+                prefactor.is_finite()
+                    && *prefactor >= 0.0
+                    && exponent.is_finite()
+                    && (0.0..=1.0).contains(fidelity)
+            }
+            DistanceGateModel::VanDerWaals {
+                c6,
+                phase,
+                decay_rate,
+            } => {
+                c6.is_finite()
+                    && *c6 > 0.0
+                    && phase.is_finite()
+                    && *phase >= 0.0
+                    && decay_rate.is_finite()
+                    && *decay_rate >= 0.0
+            }
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(RoqoqoBackendError::GenericError {
+                msg: format!("Invalid parameters of the distance gate model {:?}", self),
+            })
+        }
+    }
+}
+
+/// Returns the gate time of a single-qubit gate of a [FirstDevice], the same on every qubit.
+pub(crate) fn first_device_single_qubit_gate_time(hqslang: &str) -> Option<f64> {
+    // The gate time can optionally be used for noise considerations
+    // For the first device it is hardcoded, eventually for later device models
+    // it could be extracted from callibration data
+    match hqslang {
+        // "PhaseShiftState0" => Some(1e-6), // Updated gate definition as of April 2022
+        "PhaseShiftState1" => Some(1e-6),
+        "RotateX" => Some(1e-6),
+        "RotateY" => Some(1e-6),  // Updated gate definition as of April 2022
+        "RotateZ" => Some(1e-6),  // Updated gate definition as of February 2023
+        "RotateXY" => Some(1e-6), // Updated gate definition as of April 2022
+        "PauliX" => Some(1e-6),   // Updated gate definition as of February 2023
+        "PauliY" => Some(1e-6),   // Updated gate definition as of February 2023
+        "PauliZ" => Some(1e-6),   // Updated gate definition as of February 2023
+        "SqrtPauliX" => Some(1e-6), // Updated gate definition as of February 2023
+        "InvSqrtPauliX" => Some(1e-6), // Updated gate definition as of February 2023
+        // still needs to be implemented in qoqo
+        // All other single qubit gates are not available on the hardware
+        _ => None,
+    }
 }
 
 impl Device for FirstDevice {
@@ -553,72 +1257,16 @@ impl Device for FirstDevice {
             return None;
         }
 
-        // The gate time can optionally be used for noise considerations
-        // For the first device it is hardcoded, eventually for later device models
-        // it could be extracted from callibration data
-
-        match hqslang {
-            // "PhaseShiftState0" => Some(1e-6), // Updated gate definition as of April 2022
-            "PhaseShiftState1" => Some(1e-6),
-            "RotateX" => Some(1e-6),
-            "RotateY" => Some(1e-6), // Updated gate definition as of April 2022
-            "RotateZ" => Some(1e-6), // Updated gate definition as of February 2023
-            "RotateXY" => Some(1e-6), // Updated gate definition as of April 2022
-            "PauliX" => Some(1e-6),  // Updated gate definition as of February 2023
-            "PauliY" => Some(1e-6),  // Updated gate definition as of February 2023
-            "PauliZ" => Some(1e-6),  // Updated gate definition as of February 2023
-            "SqrtPauliX" => Some(1e-6), // Updated gate definition as of February 2023
-            "InvSqrtPauliX" => Some(1e-6), // Updated gate definition as of February 2023
-            // still needs to be implemented in qoqo
-            // All other single qubit gates are not available on the hardware
-            _ => None,
-        }
+        first_device_single_qubit_gate_time(hqslang)
     }
 
     fn two_qubit_gate_time(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
         // Check for availability of control and target on device
-        if !self.qubit_positions().contains_key(control) {
-            return None;
-        }
-        if !self.qubit_positions().contains_key(target) {
-            return None;
-        }
-
-        // Check if a layout has been selected and already prepare layout
-        let layout = self
-            .layout_register
-            .get(&self.current_layout)
-            .expect("Unexpectedly did not find current layout. Bug in roqoqo-qryd");
-        // Check for type of gate (as well as checking phi-theta relation)
-        match hqslang {
-            "PhaseShiftedControlledZ" => (),
-            "PhaseShiftedControlledPhase" => (),
-            _ => return None,
-        }
-        let control_position = self
-            .qubit_positions
-            .get(control)
-            .expect("Internal error entry in hashmap that was already checked not found");
-        let target_position = self
-            .qubit_positions
-            .get(target)
-            .expect("Internal error entry in hashmap that was already checked not found");
-        // The following is just an example of how the availability of gates and the gate time could be calculated based on a simple theoretical model (using physical distance)
-        // For the actual device  more complex models or a lookup of callibration data can be performed instead
-        // Calculate the physical distance
-        let x_distance = layout[*control_position] - layout[*target_position];
-        let y_distance =
-            self.row_distance * ((control_position.0 as isize - target_position.0 as isize) as f64);
-        let total_distance = (x_distance.powi(2) + y_distance.powi(2)).sqrt();
-        if total_distance > self.cutoff {
-            None
-        } else {
-            // Example of gate time dependence on distance. Here gate time increases with the square of the distance.
-            Some(2e-6 * total_distance.powi(2))
-        }
+        let control_position = self.qubit_positions.get(control)?;
+        let target_position = self.qubit_positions.get(target)?;
+        self.tweezer_two_qubit_gate_time(hqslang, control_position, target_position)
     }
 
-    #[allow(unused_variables)]
     fn three_qubit_gate_time(
         &self,
         hqslang: &str,
@@ -626,43 +1274,16 @@ impl Device for FirstDevice {
         control_1: &usize,
         target: &usize,
     ) -> Option<f64> {
-        match hqslang {
-            "ControlledControlledPauliZ" => {
-                if self.allow_ccz_gate
-                    && self
-                        .two_qubit_gate_time("PhaseShiftedControlledZ", control_0, target)
-                        .is_some()
-                    && self
-                        .two_qubit_gate_time("PhaseShiftedControlledZ", control_0, control_1)
-                        .is_some()
-                    && self
-                        .two_qubit_gate_time("PhaseShiftedControlledZ", control_1, target)
-                        .is_some()
-                {
-                    Some(1e-6)
-                } else {
-                    None
-                }
-            }
-            "ControlledControlledPhaseShift" => {
-                if self.allow_ccp_gate
-                    && self
-                        .two_qubit_gate_time("PhaseShiftedControlledPhase", control_0, target)
-                        .is_some()
-                    && self
-                        .two_qubit_gate_time("PhaseShiftedControlledPhase", control_0, control_1)
-                        .is_some()
-                    && self
-                        .two_qubit_gate_time("PhaseShiftedControlledPhase", control_1, target)
-                        .is_some()
-                {
-                    Some(1e-6)
-                } else {
-                    None
-                }
-            }
-            _ => None,
+        // The three qubits need to be distinct
+        if control_0 == control_1 || control_0 == target || control_1 == target {
+            return None;
         }
+        self.tweezer_three_qubit_gate_time(
+            hqslang,
+            self.qubit_positions.get(control_0)?,
+            self.qubit_positions.get(control_1)?,
+            self.qubit_positions.get(target)?,
+        )
     }
 
     #[allow(unused_variables)]
@@ -0,0 +1,246 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Local estimation of the resources used by a quantum program.
+//!
+//! Counts the qubits, the native gates and the shots of the circuits posted for a program and
+//! estimates the execution time from the gate times of the device, so that jobs can be budgeted
+//! before they are submitted. Gates the device does not provide natively are counted with a
+//! standard decomposition into the native gate set of the QRyd devices.
+
+use roqoqo::devices::Device;
+use roqoqo::operations::{
+    Operate, OperateMultiQubit, OperateSingleQubit, OperateThreeQubit, OperateTwoQubit, Operation,
+};
+use roqoqo::{Circuit, QuantumProgram, RoqoqoBackendError};
+use std::collections::{BTreeSet, HashMap};
+
+/// Estimated resources of a quantum program.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ResourceEstimate {
+    /// The number of distinct qubits the operations act on.
+    pub number_qubits: usize,
+    /// The number of circuits posted for the program, one job each.
+    pub number_circuits: usize,
+    /// The number of native gates of one shot of each circuit, summed over the circuits.
+    pub native_gate_counts: HashMap<String, usize>,
+    /// The number of shots, summed over the circuits.
+    pub number_shots: usize,
+    /// The estimated execution time of all shots of all circuits, from the gate times of the device.
+    pub estimated_time: f64,
+    /// The hqslang names of the gates whose time is not provided by the device.
+    ///
+    /// These gates do not contribute to the estimated time.
+    pub gates_without_time: Vec<String>,
+}
+
+/// Decomposition of gates not provided natively by the QRyd devices.
+///
+/// The decomposition may contain gates that are decomposed themselves.
+fn decomposition(hqslang: &str) -> Option<&'static [(&'static str, usize)]> {
+    match hqslang {
+        "Hadamard" => Some(&[("RotateY", 1), ("PauliZ", 1)]),
+        "ControlledPauliZ" => Some(&[("PhaseShiftedControlledZ", 1), ("RotateZ", 2)]),
+        "ControlledPhaseShift" => Some(&[("PhaseShiftedControlledPhase", 1), ("RotateZ", 2)]),
+        "CNOT" => Some(&[("Hadamard", 2), ("ControlledPauliZ", 1)]),
+        "ControlledPauliY" => Some(&[("CNOT", 1), ("RotateZ", 2)]),
+        "SWAP" => Some(&[("CNOT", 3)]),
+        "ISwap" => Some(&[("SWAP", 1), ("ControlledPauliZ", 1), ("RotateZ", 2)]),
+        "ControlledControlledPauliZ" => Some(&[("CNOT", 6), ("RotateZ", 7)]),
+        "ControlledControlledPhaseShift" => Some(&[("ControlledPhaseShift", 3), ("CNOT", 2)]),
+        _ => None,
+    }
+}
+
+/// Two-qubit gates used in the decompositions, all other components are single-qubit gates.
+const TWO_QUBIT_COMPONENTS: [&str; 6] = [
+    "PhaseShiftedControlledZ",
+    "PhaseShiftedControlledPhase",
+    "ControlledPauliZ",
+    "ControlledPhaseShift",
+    "CNOT",
+    "SWAP",
+];
+
+/// Returns the qubits a gate acts on, in the order used for the gate time lookup.
+pub(crate) fn gate_qubits(operation: &Operation) -> Option<Vec<usize>> {
+    let qubits = match operation {
+        Operation::RotateX(op) => vec![*op.qubit()],
+        Operation::RotateY(op) => vec![*op.qubit()],
+        Operation::RotateZ(op) => vec![*op.qubit()],
+        Operation::RotateXY(op) => vec![*op.qubit()],
+        Operation::PhaseShiftState1(op) => vec![*op.qubit()],
+        Operation::PauliX(op) => vec![*op.qubit()],
+        Operation::PauliY(op) => vec![*op.qubit()],
+        Operation::PauliZ(op) => vec![*op.qubit()],
+        Operation::SqrtPauliX(op) => vec![*op.qubit()],
+        Operation::InvSqrtPauliX(op) => vec![*op.qubit()],
+        Operation::Hadamard(op) => vec![*op.qubit()],
+        Operation::PhaseShiftedControlledZ(op) => vec![*op.control(), *op.target()],
+        Operation::PhaseShiftedControlledPhase(op) => vec![*op.control(), *op.target()],
+        Operation::ControlledPauliZ(op) => vec![*op.control(), *op.target()],
+        Operation::ControlledPhaseShift(op) => vec![*op.control(), *op.target()],
+        Operation::CNOT(op) => vec![*op.control(), *op.target()],
+        Operation::ControlledPauliY(op) => vec![*op.control(), *op.target()],
+        Operation::SWAP(op) => vec![*op.control(), *op.target()],
+        Operation::ISwap(op) => vec![*op.control(), *op.target()],
+        Operation::ControlledControlledPauliZ(op) => {
+            vec![*op.control_0(), *op.control_1(), *op.target()]
+        }
+        Operation::ControlledControlledPhaseShift(op) => {
+            vec![*op.control_0(), *op.control_1(), *op.target()]
+        }
+        Operation::MultiQubitZZ(op) => op.qubits().clone(),
+        _ => return None,
+    };
+    Some(qubits)
+}
+
+/// Returns the time of a gate on the device, if the device provides the gate natively.
+pub(crate) fn native_gate_time<D: Device>(
+    device: &D,
+    hqslang: &str,
+    qubits: &[usize],
+) -> Option<f64> {
+    // Multi-qubit gates are looked up as such for any number of qubits
+    if hqslang.starts_with("MultiQubit") {
+        return device.multi_qubit_gate_time(hqslang, qubits);
+    }
+    match qubits {
+        [qubit] => device.single_qubit_gate_time(hqslang, qubit),
+        [control, target] => device
+            .two_qubit_gate_time(hqslang, control, target)
+            .or_else(|| device.two_qubit_gate_time(hqslang, target, control)),
+        [control_0, control_1, target] => {
+            device.three_qubit_gate_time(hqslang, control_0, control_1, target)
+        }
+        _ => device.multi_qubit_gate_time(hqslang, qubits),
+    }
+}
+
+/// Resources of one shot of a circuit.
+#[derive(Default)]
+struct CircuitResources {
+    native_gate_counts: HashMap<String, usize>,
+    time: f64,
+    gates_without_time: BTreeSet<String>,
+}
+
+impl CircuitResources {
+    /// Adds `count` repetitions of a gate, decomposing it if the device does not provide it.
+    fn add_gate<D: Device>(&mut self, device: &D, hqslang: &str, qubits: &[usize], count: usize) {
+        if let Some(time) = native_gate_time(device, hqslang, qubits) {
+            *self
+                .native_gate_counts
+                .entry(hqslang.to_string())
+                .or_insert(0) += count;
+            self.time += time * count as f64;
+            return;
+        }
+        match decomposition(hqslang) {
+            Some(gates) => {
+                for (gate, gate_count) in gates {
+                    // Components act on the last qubits of the decomposed gate, e.g. its target
+                    let gate_qubits = if TWO_QUBIT_COMPONENTS.contains(gate) {
+                        &qubits[qubits.len().saturating_sub(2)..]
+                    } else {
+                        &qubits[qubits.len() - 1..]
+                    };
+                    self.add_gate(device, gate, gate_qubits, count * gate_count);
+                }
+            }
+            None => {
+                *self
+                    .native_gate_counts
+                    .entry(hqslang.to_string())
+                    .or_insert(0) += count;
+                self.gates_without_time.insert(hqslang.to_string());
+            }
+        }
+    }
+}
+
+/// Estimates the resources of a ClassicalRegister or PauliZProduct program on a device.
+///
+/// Each circuit of the measurement is combined with the constant circuit, as when posting the
+/// program to the WebAPI. Gates provided by the device are counted with their gate time, all
+/// other gates are decomposed into native gates. The estimated time is the sum of the gate
+/// times of all shots, neglecting measurement and reset times. Circuits without
+/// PragmaSetNumberOfMeasurements or PragmaRepeatedMeasurement are counted with one shot.
+///
+/// # Arguments
+///
+/// * `device` - The device providing the gate times.
+/// * `quantumprogram` - The program to estimate the resources of.
+///
+/// # Returns
+///
+/// * `Ok(ResourceEstimate)` - The estimated resources.
+/// * `Err(RoqoqoBackendError)` - The program is neither a ClassicalRegister nor a PauliZProduct program.
+pub fn estimate_resources<D: Device>(
+    device: &D,
+    quantumprogram: &QuantumProgram,
+) -> Result<ResourceEstimate, RoqoqoBackendError> {
+    let (constant_circuit, circuits) = match quantumprogram {
+        QuantumProgram::ClassicalRegister { measurement, .. } => {
+            (&measurement.constant_circuit, &measurement.circuits)
+        }
+        QuantumProgram::PauliZProduct { measurement, .. } => {
+            (&measurement.constant_circuit, &measurement.circuits)
+        }
+        _ => {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "Resources can only be estimated for ClassicalRegister and PauliZProduct QuantumPrograms".to_string(),
+            })
+        }
+    };
+    let mut estimate = ResourceEstimate {
+        number_circuits: circuits.len(),
+        ..Default::default()
+    };
+    let mut qubits: BTreeSet<usize> = BTreeSet::new();
+    let mut gates_without_time: BTreeSet<String> = BTreeSet::new();
+    for circuit in circuits {
+        let mut resources = CircuitResources::default();
+        let mut shots: Option<usize> = None;
+        for operation in constant_circuit
+            .iter()
+            .chain([circuit])
+            .flat_map(Circuit::iter)
+        {
+            match operation {
+                Operation::PragmaSetNumberOfMeasurements(op) => {
+                    shots = Some(shots.unwrap_or(0).max(*op.number_measurements()));
+                }
+                Operation::PragmaRepeatedMeasurement(op) => {
+                    shots = Some(shots.unwrap_or(0).max(*op.number_measurements()));
+                }
+                _ => {
+                    if let Some(gate_qubits) = gate_qubits(operation) {
+                        qubits.extend(gate_qubits.iter());
+                        resources.add_gate(device, operation.hqslang(), &gate_qubits, 1);
+                    }
+                }
+            }
+        }
+        let shots = shots.unwrap_or(1);
+        for (gate, count) in resources.native_gate_counts {
+            *estimate.native_gate_counts.entry(gate).or_insert(0) += count;
+        }
+        estimate.number_shots += shots;
+        estimate.estimated_time += resources.time * shots as f64;
+        gates_without_time.extend(resources.gates_without_time);
+    }
+    estimate.number_qubits = qubits.len();
+    estimate.gates_without_time = gates_without_time.into_iter().collect();
+    Ok(estimate)
+}
@@ -0,0 +1,122 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Histogram helpers for measurement results.
+//!
+//! Counts can either be returned as sorted structured data (e.g. for plotting with matplotlib)
+//! or rendered directly to an image with the same typst based stack used by
+//! [crate::TweezerDevice::draw].
+
+use image::DynamicImage;
+use roqollage::render_typst_str;
+use roqoqo::RoqoqoBackendError;
+use std::collections::HashMap;
+
+/// Maximal height of a bar in the rendered histogram, in typst points.
+const MAX_BAR_HEIGHT: f64 = 120.0;
+
+/// Converts a bit register into counts of the measured bitstrings.
+///
+/// The bitstrings are ordered by readout index, i.e. the first character
+/// corresponds to bit `0` of the register.
+///
+/// # Arguments
+///
+/// * `register` - The bit register, one entry per shot.
+///
+/// # Returns
+///
+/// * `HashMap<String, u64>` - The number of times each bitstring was measured.
+pub fn bit_register_to_counts(register: &[Vec<bool>]) -> HashMap<String, u64> {
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    for shot in register.iter() {
+        let bitstring: String = shot.iter().map(|b| if *b { '1' } else { '0' }).collect();
+        *counts.entry(bitstring).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Returns the histogram data of the counts, sorted by bitstring.
+///
+/// # Arguments
+///
+/// * `counts` - The number of times each bitstring was measured.
+///
+/// # Returns
+///
+/// * `Vec<(String, u64)>` - The sorted (bitstring, count) pairs.
+pub fn counts_histogram(counts: &HashMap<String, u64>) -> Vec<(String, u64)> {
+    let mut histogram: Vec<(String, u64)> = counts.iter().map(|(k, v)| (k.clone(), *v)).collect();
+    histogram.sort_by(|a, b| a.0.len().cmp(&b.0.len()).then_with(|| a.0.cmp(&b.0)));
+    histogram
+}
+
+/// Renders a bar chart of the measured bitstring counts.
+///
+/// # Arguments
+///
+/// * `counts` - The number of times each bitstring was measured.
+/// * `pixels_per_point` - The quality of the image.
+/// * `file_save_path` - Path to save the image to.
+///
+/// # Returns
+///
+/// * `Ok(DynamicImage)` - The rendered histogram.
+/// * `Err(RoqoqoBackendError)` - The counts are empty or an error occurred during rendering or saving.
+pub fn plot_counts(
+    counts: &HashMap<String, u64>,
+    pixels_per_point: Option<f32>,
+    file_save_path: &Option<String>,
+) -> Result<DynamicImage, RoqoqoBackendError> {
+    let histogram = counts_histogram(counts);
+    let max_count = histogram.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    if max_count == 0 {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: "No counts available for plotting.".to_owned(),
+        });
+    }
+    let mut bars = String::new();
+    let mut labels = String::new();
+    for (bitstring, count) in histogram.iter() {
+        bars.push_str(&format!(
+            "  stack(dir: ttb, spacing: 2pt, text(size: 8pt)[{}], rect(width: 16pt, height: {:.2}pt, fill: rgb(\"#4c72b0\"))),\n",
+            count,
+            MAX_BAR_HEIGHT * (*count as f64) / (max_count as f64)
+        ));
+        labels.push_str(&format!(
+            "  rotate(-90deg, reflow: true, text(size: 8pt, raw(\"{}\"))),\n",
+            bitstring.replace('\\', "\\\\").replace('"', "\\\"")
+        ));
+    }
+    let typst_str = format!(
+        r#"#set page(width: auto, height: auto, margin: 5mm, fill: white)
+
+#grid(
+  columns: {},
+  column-gutter: 4pt,
+  row-gutter: 4pt,
+  align: center + bottom,
+{}{})"#,
+        histogram.len(),
+        bars,
+        labels
+    );
+    let image = render_typst_str(typst_str, pixels_per_point)?;
+    if let Some(file_path) = file_save_path {
+        image
+            .save(file_path)
+            .map_err(|x| RoqoqoBackendError::GenericError {
+                msg: format!("Error during image saving: {x:?}"),
+            })?;
+    }
+    Ok(image)
+}
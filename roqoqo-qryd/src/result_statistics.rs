@@ -0,0 +1,276 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Statistics helpers for measurement results.
+//!
+//! Provides expectation values together with their shot-noise standard errors and
+//! Wilson score confidence intervals, computed either from bit registers or directly
+//! from the counts returned by the QRyd WebAPI.
+
+use roqoqo::RoqoqoBackendError;
+
+#[cfg(feature = "web-api")]
+use crate::api_backend::ResultCounts;
+#[cfg(feature = "web-api")]
+use bitvec::prelude::*;
+
+/// Default z-score used for confidence intervals (95% two-sided confidence).
+pub const DEFAULT_Z_SCORE: f64 = 1.96;
+
+/// Estimate of an expectation value together with its statistical uncertainty.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ExpectationEstimate {
+    /// The estimated expectation value.
+    pub value: f64,
+    /// The standard error of the estimate due to the finite number of shots.
+    pub standard_error: f64,
+    /// Lower bound of the Wilson score confidence interval.
+    pub lower_bound: f64,
+    /// Upper bound of the Wilson score confidence interval.
+    pub upper_bound: f64,
+    /// The number of shots the estimate is based on.
+    pub number_shots: usize,
+}
+
+/// Returns the Wilson score confidence interval of a binomial proportion.
+///
+/// # Arguments
+///
+/// * `successes` - The number of successful trials.
+/// * `trials` - The total number of trials.
+/// * `z_score` - The z-score of the requested confidence level. Defaults to 1.96 (95%).
+///
+/// # Returns
+///
+/// * `Ok((f64, f64))` - The lower and upper bound of the interval.
+/// * `Err(RoqoqoBackendError)` - No trials given or more successes than trials.
+pub fn wilson_interval(
+    successes: usize,
+    trials: usize,
+    z_score: Option<f64>,
+) -> Result<(f64, f64), RoqoqoBackendError> {
+    if trials == 0 {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: "Cannot compute a confidence interval without any shots.".to_string(),
+        });
+    }
+    if successes > trials {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Number of successes {} is larger than the number of trials {}.",
+                successes, trials
+            ),
+        });
+    }
+    let z = z_score.unwrap_or(DEFAULT_Z_SCORE);
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let denominator = 1.0 + z * z / n;
+    let center = (p + z * z / (2.0 * n)) / denominator;
+    let half_width = z * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt() / denominator;
+    Ok((
+        (center - half_width).max(0.0),
+        (center + half_width).min(1.0),
+    ))
+}
+
+/// Estimates the probability of measuring `1` on a given readout bit.
+///
+/// # Arguments
+///
+/// * `register` - The bit register, one entry per shot.
+/// * `bit` - The index of the readout bit.
+/// * `z_score` - The z-score of the requested confidence level. Defaults to 1.96 (95%).
+///
+/// # Returns
+///
+/// * `Ok(ExpectationEstimate)` - The estimated probability with its uncertainty.
+/// * `Err(RoqoqoBackendError)` - The register is empty or the bit is out of range.
+pub fn bit_probability(
+    register: &[Vec<bool>],
+    bit: usize,
+    z_score: Option<f64>,
+) -> Result<ExpectationEstimate, RoqoqoBackendError> {
+    let mut ones: usize = 0;
+    for shot in register.iter() {
+        match shot.get(bit) {
+            Some(true) => ones += 1,
+            Some(false) => (),
+            None => {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!("Bit {} is not present in the measured register.", bit),
+                })
+            }
+        }
+    }
+    probability_estimate(ones, register.len(), z_score)
+}
+
+/// Estimates the expectation value of a product of PauliZ operators from a bit register.
+///
+/// Every shot contributes `+1` if an even number of the given bits was measured in `1`
+/// and `-1` otherwise.
+///
+/// # Arguments
+///
+/// * `register` - The bit register, one entry per shot.
+/// * `bits` - The readout bits entering the PauliZ product.
+/// * `z_score` - The z-score of the requested confidence level. Defaults to 1.96 (95%).
+///
+/// # Returns
+///
+/// * `Ok(ExpectationEstimate)` - The estimated expectation value with its uncertainty.
+/// * `Err(RoqoqoBackendError)` - The register is empty or a bit is out of range.
+pub fn pauli_z_product_expectation(
+    register: &[Vec<bool>],
+    bits: &[usize],
+    z_score: Option<f64>,
+) -> Result<ExpectationEstimate, RoqoqoBackendError> {
+    let mut even: usize = 0;
+    for shot in register.iter() {
+        let mut parity = false;
+        for bit in bits.iter() {
+            parity ^= *shot
+                .get(*bit)
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!("Bit {} is not present in the measured register.", bit),
+                })?;
+        }
+        if !parity {
+            even += 1;
+        }
+    }
+    parity_estimate(even, register.len(), z_score)
+}
+
+/// Estimates the probability of measuring `1` on a given qubit from WebAPI counts.
+///
+/// # Arguments
+///
+/// * `counts` - The counts returned by the QRyd WebAPI.
+/// * `qubit` - The index of the measured qubit.
+/// * `z_score` - The z-score of the requested confidence level. Defaults to 1.96 (95%).
+///
+/// # Returns
+///
+/// * `Ok(ExpectationEstimate)` - The estimated probability with its uncertainty.
+/// * `Err(RoqoqoBackendError)` - The counts are empty or cannot be parsed.
+#[cfg(feature = "web-api")]
+pub fn bit_probability_from_counts(
+    counts: &ResultCounts,
+    qubit: usize,
+    z_score: Option<f64>,
+) -> Result<ExpectationEstimate, RoqoqoBackendError> {
+    let mut ones: usize = 0;
+    let mut total: usize = 0;
+    for (measurement, count) in counts.counts.iter() {
+        let bits = decode_hex_measurement(measurement)?;
+        let count = *count as usize;
+        total += count;
+        if bits.view_bits::<Lsb0>().get(qubit).map(|b| *b) == Some(true) {
+            ones += count;
+        }
+    }
+    probability_estimate(ones, total, z_score)
+}
+
+/// Estimates the expectation value of a product of PauliZ operators from WebAPI counts.
+///
+/// # Arguments
+///
+/// * `counts` - The counts returned by the QRyd WebAPI.
+/// * `qubits` - The qubits entering the PauliZ product.
+/// * `z_score` - The z-score of the requested confidence level. Defaults to 1.96 (95%).
+///
+/// # Returns
+///
+/// * `Ok(ExpectationEstimate)` - The estimated expectation value with its uncertainty.
+/// * `Err(RoqoqoBackendError)` - The counts are empty or cannot be parsed.
+#[cfg(feature = "web-api")]
+pub fn pauli_z_product_expectation_from_counts(
+    counts: &ResultCounts,
+    qubits: &[usize],
+    z_score: Option<f64>,
+) -> Result<ExpectationEstimate, RoqoqoBackendError> {
+    let mut even: usize = 0;
+    let mut total: usize = 0;
+    for (measurement, count) in counts.counts.iter() {
+        let bits = decode_hex_measurement(measurement)?;
+        let bit_view = bits.view_bits::<Lsb0>();
+        let parity = qubits.iter().fold(false, |acc, q| {
+            acc ^ bit_view.get(*q).map(|b| *b).unwrap_or(false)
+        });
+        let count = *count as usize;
+        total += count;
+        if !parity {
+            even += count;
+        }
+    }
+    parity_estimate(even, total, z_score)
+}
+
+fn probability_estimate(
+    successes: usize,
+    trials: usize,
+    z_score: Option<f64>,
+) -> Result<ExpectationEstimate, RoqoqoBackendError> {
+    let (lower_bound, upper_bound) = wilson_interval(successes, trials, z_score)?;
+    let p = successes as f64 / trials as f64;
+    Ok(ExpectationEstimate {
+        value: p,
+        standard_error: (p * (1.0 - p) / trials as f64).sqrt(),
+        lower_bound,
+        upper_bound,
+        number_shots: trials,
+    })
+}
+
+// Maps the estimate of the probability of the +1 outcome to the expectation value of a
+// +1/-1 valued observable.
+fn parity_estimate(
+    even: usize,
+    trials: usize,
+    z_score: Option<f64>,
+) -> Result<ExpectationEstimate, RoqoqoBackendError> {
+    let probability = probability_estimate(even, trials, z_score)?;
+    Ok(ExpectationEstimate {
+        value: 2.0 * probability.value - 1.0,
+        standard_error: 2.0 * probability.standard_error,
+        lower_bound: 2.0 * probability.lower_bound - 1.0,
+        upper_bound: 2.0 * probability.upper_bound - 1.0,
+        number_shots: trials,
+    })
+}
+
+#[cfg(feature = "web-api")]
+fn decode_hex_measurement(measurement: &str) -> Result<Vec<u8>, RoqoqoBackendError> {
+    let stripped = measurement
+        .strip_prefix("0x")
+        .ok_or(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Cannot parse a measurement result as bit representation {}",
+                measurement
+            ),
+        })?;
+    let padded = if stripped.len() % 2 == 0 {
+        stripped.to_string()
+    } else {
+        format!("0{}", stripped)
+    };
+    // Same bit convention as APIBackend::counts_to_result
+    hex::decode(padded).map_err(|err| RoqoqoBackendError::GenericError {
+        msg: format!(
+            "Cannot parse a measurement result as bit representation {:?}",
+            err
+        ),
+    })
+}
@@ -0,0 +1,252 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scheduling of quantum programs across several QRyd devices.
+//!
+//! Users with access to several QRyd devices or emulators configure one [APIBackend] per device.
+//! The [Scheduler] estimates the turnaround of a program on each of them, from the queue
+//! information provided by the user and the execution time estimated from the gate times of
+//! the device, and posts the program to the device selected by a [SchedulingPolicy].
+
+use crate::{APIBackend, ResourceEstimate};
+use roqoqo::{QuantumProgram, RoqoqoBackendError};
+
+/// A device a program can be posted to, with the information used to estimate the turnaround.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchedulingCandidate {
+    /// The backend posting the programs to the device.
+    pub backend: APIBackend,
+    /// The number of jobs queued on the device before a new job.
+    pub queue_length: usize,
+    /// The average time in seconds a queued job delays a new job.
+    pub seconds_per_queued_job: f64,
+    /// The time in seconds per unit of the gate times of the device.
+    pub seconds_per_time_unit: f64,
+    /// The fixed time in seconds of each job, e.g. for transpiling and loading the atoms.
+    pub overhead: f64,
+}
+
+impl SchedulingCandidate {
+    /// Creates a new SchedulingCandidate with an empty queue and no overhead.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend` - The backend posting the programs to the device.
+    /// * `seconds_per_time_unit` - The time in seconds per unit of the gate times of the device.
+    pub fn new(backend: APIBackend, seconds_per_time_unit: f64) -> Self {
+        SchedulingCandidate {
+            backend,
+            queue_length: 0,
+            seconds_per_queued_job: 0.0,
+            seconds_per_time_unit,
+            overhead: 0.0,
+        }
+    }
+}
+
+/// Estimated turnaround of a program on one of the candidates of a [Scheduler].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TurnaroundEstimate {
+    /// The index of the candidate in the scheduler.
+    pub candidate: usize,
+    /// The name of the device of the candidate.
+    pub device_name: String,
+    /// The estimated time in seconds until the program starts, including the overhead.
+    pub queue_time: f64,
+    /// The estimated time in seconds to run all shots of the program.
+    pub run_time: f64,
+    /// The estimated resources of the program on the device.
+    pub resources: ResourceEstimate,
+}
+
+impl TurnaroundEstimate {
+    /// Returns the estimated time in seconds from posting the program until the results are available.
+    pub fn turnaround(&self) -> f64 {
+        self.queue_time + self.run_time
+    }
+
+    /// Returns true if the device provides the gate times of all gates of the program.
+    ///
+    /// Devices missing gate times, e.g. for two-qubit gates between unconnected qubits,
+    /// can not run the program as posted.
+    pub fn is_supported(&self) -> bool {
+        self.resources.gates_without_time.is_empty()
+    }
+
+    /// Returns true if the estimated turnaround is within the deadline or no deadline is given.
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - The time in seconds after which the results are needed, if any.
+    pub fn meets_deadline(&self, deadline: Option<f64>) -> bool {
+        match deadline {
+            Some(deadline) => self.turnaround() <= deadline,
+            None => true,
+        }
+    }
+}
+
+/// Policy selecting the candidate a program is posted to.
+pub trait SchedulingPolicy {
+    /// Selects a candidate from the turnaround estimates.
+    ///
+    /// # Arguments
+    ///
+    /// * `estimates` - The turnaround estimates of all candidates.
+    /// * `deadline` - The time in seconds after which the results are needed, if any.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - The index of the selected candidate.
+    /// * `None` - No candidate is suitable.
+    fn select(&self, estimates: &[TurnaroundEstimate], deadline: Option<f64>) -> Option<usize>;
+}
+
+/// Selects the supported candidate with the shortest estimated turnaround meeting the deadline.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FastestTurnaround;
+
+impl SchedulingPolicy for FastestTurnaround {
+    fn select(&self, estimates: &[TurnaroundEstimate], deadline: Option<f64>) -> Option<usize> {
+        estimates
+            .iter()
+            .filter(|estimate| estimate.is_supported())
+            .filter(|estimate| estimate.meets_deadline(deadline))
+            .min_by(|a, b| a.turnaround().total_cmp(&b.turnaround()))
+            .map(|estimate| estimate.candidate)
+    }
+}
+
+/// Selects the supported candidate with the shortest queue that meets the deadline.
+///
+/// Spreads the jobs of a group over the devices when the run times are not critical.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ShortestQueue;
+
+impl SchedulingPolicy for ShortestQueue {
+    fn select(&self, estimates: &[TurnaroundEstimate], deadline: Option<f64>) -> Option<usize> {
+        estimates
+            .iter()
+            .filter(|estimate| estimate.is_supported())
+            .filter(|estimate| estimate.meets_deadline(deadline))
+            .min_by(|a, b| a.queue_time.total_cmp(&b.queue_time))
+            .map(|estimate| estimate.candidate)
+    }
+}
+
+/// Posts quantum programs to the device with the best expected turnaround.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scheduler<P: SchedulingPolicy> {
+    /// The devices the programs can be posted to.
+    pub candidates: Vec<SchedulingCandidate>,
+    /// The policy selecting the device.
+    pub policy: P,
+}
+
+impl<P: SchedulingPolicy> Scheduler<P> {
+    /// Creates a new Scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - The devices the programs can be posted to.
+    /// * `policy` - The policy selecting the device.
+    pub fn new(candidates: Vec<SchedulingCandidate>, policy: P) -> Self {
+        Scheduler { candidates, policy }
+    }
+
+    /// Estimates the turnaround of a program on all candidates.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantumprogram` - The program to estimate the turnaround of.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TurnaroundEstimate>)` - The estimates in the order of the candidates.
+    /// * `Err(RoqoqoBackendError)` - The resources of the program can not be estimated.
+    pub fn estimate(
+        &self,
+        quantumprogram: &QuantumProgram,
+    ) -> Result<Vec<TurnaroundEstimate>, RoqoqoBackendError> {
+        self.candidates
+            .iter()
+            .enumerate()
+            .map(|(index, candidate)| {
+                let resources = candidate.backend.estimate_resources(quantumprogram)?;
+                Ok(TurnaroundEstimate {
+                    candidate: index,
+                    device_name: candidate.backend.device.qrydbackend(),
+                    queue_time: candidate.overhead
+                        + candidate.queue_length as f64 * candidate.seconds_per_queued_job,
+                    run_time: resources.estimated_time * candidate.seconds_per_time_unit,
+                    resources,
+                })
+            })
+            .collect()
+    }
+
+    /// Selects the candidate a program is posted to.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantumprogram` - The program to schedule.
+    /// * `deadline` - The time in seconds after which the results are needed, if any.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TurnaroundEstimate)` - The estimate of the selected candidate.
+    /// * `Err(RoqoqoBackendError)` - No candidate is selected by the policy or the resources can not be estimated.
+    pub fn select(
+        &self,
+        quantumprogram: &QuantumProgram,
+        deadline: Option<f64>,
+    ) -> Result<TurnaroundEstimate, RoqoqoBackendError> {
+        let mut estimates = self.estimate(quantumprogram)?;
+        let selected = self
+            .policy
+            .select(&estimates, deadline)
+            .filter(|selected| *selected < estimates.len())
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: match deadline {
+                    Some(deadline) => format!(
+                        "No device supports the program with a turnaround within {} seconds.",
+                        deadline
+                    ),
+                    None => "No device supports the program.".to_string(),
+                },
+            })?;
+        Ok(estimates.swap_remove(selected))
+    }
+
+    /// Posts a program to the selected candidate.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantumprogram` - The program to post.
+    /// * `deadline` - The time in seconds after which the results are needed, if any.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((TurnaroundEstimate, String))` - The estimate of the selected candidate and the location of the job.
+    /// * `Err(RoqoqoBackendError)` - No candidate is selected or posting the job failed.
+    pub fn post_job(
+        &self,
+        quantumprogram: QuantumProgram,
+        deadline: Option<f64>,
+    ) -> Result<(TurnaroundEstimate, String), RoqoqoBackendError> {
+        let estimate = self.select(&quantumprogram, deadline)?;
+        let job_location = self.candidates[estimate.candidate]
+            .backend
+            .post_job(quantumprogram)?;
+        Ok((estimate, job_location))
+    }
+}
@@ -10,12 +10,28 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use qoqo_calculator::Calculator;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
 use roqoqo::backends::EvaluatingBackend;
 use roqoqo::backends::RegisterResult;
 use roqoqo::devices::Device;
+use roqoqo::measurements::{Cheated, CheatedPauliZProduct, PauliZProduct};
 use roqoqo::operations::*;
+use roqoqo::prelude::MeasureExpectationValues;
+use roqoqo::registers::{BitOutputRegister, ComplexOutputRegister, FloatOutputRegister, Registers};
+use roqoqo::{Circuit, QuantumProgram, RoqoqoBackendError};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
-use crate::TweezerDevice;
+use crate::bound_circuit::BoundCircuit;
+use crate::circuit_compatibility::check_circuit_compatibility;
+use crate::circuit_template::CircuitTemplate;
+use crate::device_events::{events_entry, DeviceEvent, DeviceEventKind, EVENTS_REGISTER};
+use crate::resource_estimation::{gate_qubits, native_gate_time};
+use crate::{
+    PragmaGlobalRotateXY, PragmaShiftQubitsTweezers, TweezerDevice, TweezerNoiseParameters,
+};
 
 /// QRyd simulator backend
 ///
@@ -30,14 +46,77 @@ use crate::TweezerDevice;
 /// The simulator backend implements the [roqoqo::backends::EvaluatingBackend] trait
 /// and is compatible with running single circuits, running and evaluating measurements
 /// and running QuantumPrograms on simulated QRyd devices.
+///
+/// A PragmaGlobalRotateXY is simulated as a RotateXY gate on every qubit that is active at its
+/// point in the circuit.
+///
+/// If `noisy` is set, the decoherence of the qubits during each gate is simulated from the noise
+/// parameters of their tweezers, see [TweezerDevice::set_tweezer_t1] and
+/// [TweezerDevice::set_tweezer_t2]. After every native gate, each active qubit in a tweezer with
+/// noise parameters is subject to a PragmaDamping with rate `1/T1`, a PragmaDephasing with rate
+/// `(1/T2 - 1/(2 T1)) / 2` and a PragmaDepolarising with the depolarising rate of the tweezer, for
+/// the gate time of the gate on the device. The noise turns the simulation into a density matrix
+/// simulation.
+///
+/// A PragmaGetStateVector or PragmaGetDensityMatrix writes the state vector or density matrix into
+/// its complex register. The gates of the circuit applied before the readout are checked against
+/// the device at the position of the readout, like the gates of the circuit itself.
+///
+/// Mid-circuit measurements with classical feedback are simulated shot by shot: a MeasureQubit
+/// writes into a bit register and the circuit of a subsequent PragmaConditional is applied if the
+/// condition bit is set. The gates of the conditional circuit are checked against the device at
+/// the position of the PragmaConditional, the only device-changing operation it may contain is a
+/// PragmaGlobalRotateXY. A PragmaActiveReset is only supported if the device allows resets,
+/// see [TweezerDevice::set_allow_reset].
+///
+/// If `transport_noise` is set, the active qubits are subject to the noise of their tweezers for
+/// the transport time of each PragmaShiftQubitsTweezers and PragmaSwitchDeviceLayout, see
+/// [SimulatorBackend::transport_time].
+///
+/// If the device has stochastic loading or atom loss probabilities, the empty and lost qubits of
+/// every shot are sampled and reported as device events, see
+/// [SimulatorBackend::run_circuit_with_preparation_statistics].
+///
+/// A backend created with [SimulatorBackend::new_density_matrix] always simulates a density
+/// matrix instead of a state vector, so that noise channels are applied exactly and the density
+/// matrix of a noise free circuit can be read out.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct SimulatorBackend {
     /// Device representing the model of a QRyd device.
     pub device: TweezerDevice,
     /// The number of qubits allocated by the simulator.
     pub number_qubits: usize,
+    /// Whether the decoherence of the qubits during the gates is simulated.
+    #[serde(default)]
+    pub noisy: bool,
+    /// The number of threads the shots are simulated on, 0 uses one thread per core.
+    #[serde(default = "default_number_threads")]
+    pub number_threads: usize,
+    /// Whether the shots of circuits with terminal measurements are sampled from a single simulation.
+    #[serde(default = "default_sample_terminal_measurements")]
+    pub sample_terminal_measurements: bool,
+    /// Whether the simulator always uses a density matrix instead of a state vector.
+    #[serde(default)]
+    pub density_matrix: bool,
+    /// The memory limit in bytes for the quantum register of a simulation.
+    #[serde(default)]
+    pub memory_limit: Option<usize>,
+    /// Whether the decoherence of the qubits during shifts and layout switches is simulated.
+    #[serde(default)]
+    pub transport_noise: bool,
 }
 
+fn default_number_threads() -> usize {
+    1
+}
+
+fn default_sample_terminal_measurements() -> bool {
+    true
+}
+
+/// The complex register the final state is read into when the shots are sampled.
+const SAMPLING_REGISTER: &str = "__qryd_sampling_state";
+
 impl SimulatorBackend {
     /// Creates a new QRyd SimulatorBackend.
     ///
@@ -49,7 +128,165 @@ impl SimulatorBackend {
         Self {
             device: device.clone(),
             number_qubits: number_qubits.unwrap_or(device.number_qubits()),
+            noisy: false,
+            number_threads: 1,
+            sample_terminal_measurements: true,
+            density_matrix: false,
+            memory_limit: None,
+            transport_noise: false,
+        }
+    }
+
+    /// Creates a new QRyd SimulatorBackend simulating a density matrix.
+    ///
+    /// # Arguments
+    ///
+    /// `device` - The TweezerDevice used for the simulation.
+    /// `number_qubits` - The number of qubits the simulator should use. Defaults to `device.number_qubits()`.
+    pub fn new_density_matrix(device: TweezerDevice, number_qubits: Option<usize>) -> Self {
+        Self {
+            density_matrix: true,
+            ..Self::new(device, number_qubits)
+        }
+    }
+
+    /// Sets whether the decoherence of the qubits during the gates is simulated.
+    ///
+    /// # Arguments
+    ///
+    /// * `noisy` - Whether to apply the noise of the tweezers after every gate.
+    pub fn set_noisy(&mut self, noisy: bool) {
+        self.noisy = noisy;
+    }
+
+    /// Sets the number of threads the shots of a circuit are simulated on.
+    ///
+    /// With more than one thread, the shots of a circuit are split evenly between the threads
+    /// and every thread runs its own QuEST simulation. The output registers of the threads are
+    /// concatenated, so registers that are written once per simulation, for example by a
+    /// PragmaGetStateVector, contain one entry per thread. Builds with the `simulator-gpu` feature
+    /// always simulate the shots sequentially, as the registers share the memory of the GPU.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_threads` - The number of threads, 1 simulates all shots sequentially and 0 uses one thread per core.
+    pub fn set_number_threads(&mut self, number_threads: usize) {
+        self.number_threads = number_threads;
+    }
+
+    /// Sets whether the shots of circuits with terminal measurements are sampled from a single simulation.
+    ///
+    /// A circuit has terminal measurements if it has no classical control, no float or complex
+    /// output registers and no operation acts on a qubit after the qubit has been measured. The
+    /// shots of such a circuit are sampled from its final state instead of simulating the circuit
+    /// once per shot. Enabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample_terminal_measurements` - Whether to sample the shots of circuits with terminal measurements.
+    pub fn set_sample_terminal_measurements(&mut self, sample_terminal_measurements: bool) {
+        self.sample_terminal_measurements = sample_terminal_measurements;
+    }
+
+    /// Sets the memory limit for the quantum register of a simulation.
+    ///
+    /// Simulations whose register does not fit into the limit are rejected before QuEST
+    /// allocates the register, see [SimulatorBackend::required_memory]. The memory of the GPU is
+    /// not queried: with the `simulator-gpu` feature the limit has to be set to the memory of the
+    /// GPU, registers exceeding the GPU memory without a limit fail in QuEST.
+    ///
+    /// # Arguments
+    ///
+    /// * `memory_limit` - The memory limit in bytes, None for no limit.
+    pub fn set_memory_limit(&mut self, memory_limit: Option<usize>) {
+        self.memory_limit = memory_limit;
+    }
+
+    /// Sets whether the decoherence of the qubits during shifts and layout switches is simulated.
+    ///
+    /// The active qubits idle for the transport time of each shift and layout switch, see
+    /// [SimulatorBackend::transport_time], and are subject to the noise of their tweezers for
+    /// that duration, independently of [SimulatorBackend::set_noisy].
+    ///
+    /// # Arguments
+    ///
+    /// * `transport_noise` - Whether to apply the noise of the tweezers during shifts and layout switches.
+    pub fn set_transport_noise(&mut self, transport_noise: bool) {
+        self.transport_noise = transport_noise;
+    }
+
+    /// Returns the accumulated transport time of the shifts and layout switches of a circuit.
+    ///
+    /// A PragmaShiftQubitsTweezers takes its duration on the device at its position in the
+    /// circuit, see [TweezerDevice::shift_duration], and a PragmaSwitchDeviceLayout the switch
+    /// time of the new Layout, see [TweezerDevice::set_layout_switch_time]. Shifts and layout
+    /// switches without a configured time do not contribute.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit` - The circuit to accumulate the transport time of.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The accumulated transport time.
+    /// * `Err(RoqoqoBackendError)` - A device-changing operation of the circuit is rejected by the device.
+    pub fn transport_time(&self, circuit: &Circuit) -> Result<f64, RoqoqoBackendError> {
+        let mut device = self.device.clone();
+        let mut transport_time = 0.0;
+        for operation in circuit.iter() {
+            if let Operation::PragmaChangeDevice(pragma) = operation {
+                transport_time += apply_device_change(&mut device, pragma)?;
+            }
         }
+        Ok(transport_time)
+    }
+
+    /// Returns true if the simulations run on a GPU.
+    ///
+    /// QuEST selects the CPU or the GPU when it is compiled. Builds with the `simulator-gpu`
+    /// feature simulate on a CUDA GPU and can not fall back to the CPU at runtime, builds without
+    /// it simulate on the CPU.
+    pub fn uses_gpu() -> bool {
+        cfg!(feature = "simulator-gpu")
+    }
+
+    /// Returns the memory of the quantum register of a simulation.
+    ///
+    /// QuEST stores every amplitude of the state vector, or every entry of the density matrix, as
+    /// two 8 byte floating point numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `density_matrix` - Whether the simulation uses a density matrix instead of a state vector.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - The memory of the register in bytes.
+    /// * `None` - The memory exceeds `usize::MAX` bytes.
+    pub fn required_memory(&self, density_matrix: bool) -> Option<usize> {
+        let exponent = if density_matrix {
+            self.number_qubits.checked_mul(2)?
+        } else {
+            self.number_qubits
+        };
+        2_usize
+            .checked_pow(u32::try_from(exponent).ok()?)?
+            .checked_mul(16)
+    }
+
+    /// Runs a circuit bound to the device of the backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `bound_circuit` - The circuit validated against the device of the backend.
+    ///
+    /// # Returns
+    ///
+    /// * `RegisterResult` - The output registers of the circuit.
+    /// * `Err(RoqoqoBackendError)` - The circuit is bound to a different device or the simulation failed.
+    pub fn run_bound_circuit(&self, bound_circuit: &BoundCircuit) -> RegisterResult {
+        bound_circuit.verify(&self.device)?;
+        self.run_circuit(bound_circuit.circuit())
     }
 }
 
@@ -58,10 +295,1009 @@ impl EvaluatingBackend for SimulatorBackend {
         &self,
         circuit: impl Iterator<Item = &'a Operation>,
     ) -> RegisterResult {
-        let mut tmp_device: Option<Box<dyn Device>> = Some(Box::new(self.device.clone()));
+        if self.device.has_stochastic_loading() || self.device.has_atom_loss() {
+            let circuit: Circuit = circuit.cloned().collect();
+            return self
+                .run_circuit_with_preparation_statistics(&circuit)
+                .map(|(bits, floats, complexes, _)| (bits, floats, complexes));
+        }
+        let circuit = self.resolve_device_operations(circuit, self.device.clone())?;
+        self.simulate(circuit.iter(), &mut self.random_generator())
+    }
+}
+
+/// The device QuEST checks the operations of a resolved circuit against.
+///
+/// The global rotations of a circuit are resolved into RotateXY gates on the active qubits,
+/// which are available on every qubit of a Layout with a global rotation time. All other
+/// operations are checked against the TweezerDevice.
+#[derive(Debug, Clone)]
+struct ResolvedDevice(TweezerDevice);
+
+impl Device for ResolvedDevice {
+    fn single_qubit_gate_time(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        match self.0.single_qubit_gate_time(hqslang, qubit) {
+            None if hqslang == "RotateXY" => self.0.global_rotation_time(),
+            gate_time => gate_time,
+        }
+    }
+
+    fn two_qubit_gate_time(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
+        self.0.two_qubit_gate_time(hqslang, control, target)
+    }
+
+    fn three_qubit_gate_time(
+        &self,
+        hqslang: &str,
+        control_0: &usize,
+        control_1: &usize,
+        target: &usize,
+    ) -> Option<f64> {
+        self.0
+            .three_qubit_gate_time(hqslang, control_0, control_1, target)
+    }
+
+    fn multi_qubit_gate_time(&self, hqslang: &str, qubits: &[usize]) -> Option<f64> {
+        self.0.multi_qubit_gate_time(hqslang, qubits)
+    }
+
+    fn qubit_decoherence_rates(&self, qubit: &usize) -> Option<ndarray::Array2<f64>> {
+        self.0.qubit_decoherence_rates(qubit)
+    }
+
+    fn number_qubits(&self) -> usize {
+        self.0.number_qubits()
+    }
+
+    fn two_qubit_edges(&self) -> Vec<(usize, usize)> {
+        self.0.two_qubit_edges()
+    }
+
+    fn change_device(&mut self, hqslang: &str, operation: &[u8]) -> Result<(), RoqoqoBackendError> {
+        self.0.change_device(hqslang, operation)
+    }
+
+    fn to_generic_device(&self) -> roqoqo::devices::GenericDevice {
+        self.0.to_generic_device()
+    }
+}
+
+/// Statistics of the stochastic loading of the tweezers during a simulation.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ArrayPreparationStatistics {
+    /// The number of simulated shots.
+    pub number_shots: usize,
+    /// The number of shots in which the tweezers of all qubits of the circuit were occupied.
+    pub fully_loaded_shots: usize,
+    /// The number of shots in which the tweezer of each qubit of the circuit was occupied.
+    pub qubit_occupation: HashMap<usize, usize>,
+    /// The mean number of atoms loaded into the tweezers of the Layout per shot, before rearrangement.
+    pub mean_loaded_atoms: f64,
+}
+
+impl SimulatorBackend {
+    /// Runs a circuit with stochastic loading of the tweezers and returns the loading statistics.
+    ///
+    /// For every shot the loaded tweezers of the current Layout are sampled from the loading
+    /// probabilities of the device, using the seed of the device if set. If rearrangement is
+    /// enabled, the loaded atoms fill the tweezers of the qubits of the circuit in ascending
+    /// order. Operations acting on a qubit in an empty tweezer are skipped and its measurement
+    /// returns `false`. Shots with the same empty qubits are simulated together, so the output
+    /// registers are grouped by the empty qubits instead of being in the order of the shots.
+    /// The empty qubits of each shot are recorded as [DeviceEventKind::AtomLoss] events in the
+    /// bit register [EVENTS_REGISTER], see [crate::device_events].
+    ///
+    /// If the device has atom loss probabilities, see [crate::AtomLoss], the atoms lost during
+    /// the shifts and measurements of every shot are sampled as well. A lost qubit is reset after
+    /// the operation it is lost in, the subsequent operations acting on it are skipped and it is
+    /// recorded as a [DeviceEventKind::AtomLoss] event like an empty qubit. Shots with the same
+    /// losses are simulated together.
+    ///
+    /// The device operations are resolved once for all shots with the qubit -> tweezer mapping
+    /// of the device. Empty and lost qubits keep their tweezer in that mapping, so the shifts of
+    /// the circuit are validated as if their tweezers were occupied: a shift into the tweezer of
+    /// a lost atom is rejected for all shots.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit` - The circuit to run.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((bit_registers, float_registers, complex_registers, statistics))` - The output registers and the loading statistics.
+    /// * `Err(RoqoqoBackendError)` - The simulation failed.
+    #[allow(clippy::type_complexity)]
+    pub fn run_circuit_with_preparation_statistics(
+        &self,
+        circuit: &Circuit,
+    ) -> Result<
+        (
+            HashMap<String, BitOutputRegister>,
+            HashMap<String, FloatOutputRegister>,
+            HashMap<String, ComplexOutputRegister>,
+            ArrayPreparationStatistics,
+        ),
+        RoqoqoBackendError,
+    > {
+        let circuit = &self.resolve_device_operations(circuit.iter(), self.device.clone())?;
+        let mut qubits: BTreeSet<usize> = BTreeSet::new();
+        let mut number_shots: usize = 1;
+        for operation in circuit.iter() {
+            match operation {
+                Operation::PragmaSetNumberOfMeasurements(op) => {
+                    number_shots = number_shots.max(*op.number_measurements());
+                }
+                Operation::PragmaRepeatedMeasurement(op) => {
+                    number_shots = number_shots.max(*op.number_measurements());
+                    qubits.extend(0..self.number_qubits);
+                }
+                _ => match operation.involved_qubits() {
+                    InvolvedQubits::Set(involved) => qubits.extend(involved),
+                    InvolvedQubits::All => qubits.extend(0..self.number_qubits),
+                    InvolvedQubits::None => {}
+                },
+            }
+        }
+        let qubits: Vec<usize> = qubits.into_iter().collect();
+        let loss_points = self.loss_points(circuit)?;
+        let tweezers = self.device.current_layout_tweezers();
+        let mut rng = self.random_generator();
+
+        // Sample the empty qubits of every shot
+        let mut statistics = ArrayPreparationStatistics {
+            number_shots,
+            ..Default::default()
+        };
+        // The lost qubits of a shot, mapped to the index of the first operation skipped on them
+        let mut shots_per_losses: BTreeMap<BTreeMap<usize, usize>, usize> = BTreeMap::new();
+        let mut loaded_atoms: usize = 0;
+        for _ in 0..number_shots {
+            let loaded: BTreeSet<usize> = tweezers
+                .iter()
+                .copied()
+                .filter(|tweezer| rng.gen::<f64>() < self.device.loading_probability(*tweezer))
+                .collect();
+            loaded_atoms += loaded.len();
+            let empty_qubits: BTreeSet<usize> = if self.device.array_preparation.rearrangement {
+                qubits.iter().skip(loaded.len()).copied().collect()
+            } else {
+                qubits
+                    .iter()
+                    .copied()
+                    .filter(|qubit| match self.device.get_tweezer_from_qubit(qubit) {
+                        Ok(tweezer) => !loaded.contains(&tweezer),
+                        Err(_) => false,
+                    })
+                    .collect()
+            };
+            for qubit in qubits.iter().filter(|qubit| !empty_qubits.contains(*qubit)) {
+                *statistics.qubit_occupation.entry(*qubit).or_insert(0) += 1;
+            }
+            if empty_qubits.is_empty() {
+                statistics.fully_loaded_shots += 1;
+            }
+            let mut losses: BTreeMap<usize, usize> =
+                empty_qubits.into_iter().map(|qubit| (qubit, 0)).collect();
+            for (index, lost_qubits, probability) in loss_points.iter() {
+                for qubit in lost_qubits {
+                    if !losses.contains_key(qubit) && rng.gen::<f64>() < *probability {
+                        losses.insert(*qubit, index + 1);
+                    }
+                }
+            }
+            *shots_per_losses.entry(losses).or_insert(0) += 1;
+        }
+        statistics.mean_loaded_atoms = loaded_atoms as f64 / number_shots as f64;
+
+        // Simulate the shots with the same losses together
+        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+        let mut float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let mut complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        for (losses, shots) in shots_per_losses {
+            let shot_circuit = loaded_circuit(circuit, &losses, shots);
+            let (bits, floats, complexes) = self.simulate(shot_circuit.iter(), &mut rng)?;
+            for (name, values) in bits {
+                bit_registers.entry(name).or_default().extend(values);
+            }
+            for (name, values) in floats {
+                float_registers.entry(name).or_default().extend(values);
+            }
+            for (name, values) in complexes {
+                complex_registers.entry(name).or_default().extend(values);
+            }
+            let events = events_entry(
+                self.number_qubits,
+                losses.keys().map(|qubit| DeviceEvent {
+                    kind: DeviceEventKind::AtomLoss,
+                    qubit: *qubit,
+                }),
+            );
+            bit_registers
+                .entry(EVENTS_REGISTER.to_string())
+                .or_default()
+                .extend(std::iter::repeat(events).take(shots));
+        }
+        Ok((
+            bit_registers,
+            float_registers,
+            complex_registers,
+            statistics,
+        ))
+    }
+
+    /// Returns the operations of a resolved circuit in which atoms can be lost.
+    ///
+    /// Every entry holds the index of the operation, the qubits whose atoms can be lost in it and
+    /// the loss probability. The qubits of a PragmaShiftQubitsTweezers are the qubits in the
+    /// start tweezers of its shifts at its position in the circuit.
+    fn loss_points(
+        &self,
+        circuit: &Circuit,
+    ) -> Result<Vec<(usize, Vec<usize>, f64)>, RoqoqoBackendError> {
+        let shift_loss_probability = self.device.shift_loss_probability();
+        let measurement_loss_probability = self.device.measurement_loss_probability();
+        let mut device = self.device.clone();
+        let mut loss_points: Vec<(usize, Vec<usize>, f64)> = Vec::new();
+        for (index, operation) in circuit.iter().enumerate() {
+            match operation {
+                Operation::MeasureQubit(op) if measurement_loss_probability > 0.0 => {
+                    loss_points.push((index, vec![*op.qubit()], measurement_loss_probability));
+                }
+                Operation::PragmaChangeDevice(pragma) => {
+                    if pragma.wrapped_hqslang == "PragmaShiftQubitsTweezers"
+                        && shift_loss_probability > 0.0
+                    {
+                        if let (Ok(shift), Some(qubit_to_tweezer)) = (
+                            PragmaShiftQubitsTweezers::from_bincode(&pragma.wrapped_operation),
+                            device.qubit_to_tweezer.as_ref(),
+                        ) {
+                            let shifted_qubits: Vec<usize> = qubit_to_tweezer
+                                .iter()
+                                .filter(|(_, tweezer)| {
+                                    shift.shifts.iter().any(|(start, _)| start == *tweezer)
+                                })
+                                .map(|(qubit, _)| *qubit)
+                                .collect();
+                            loss_points.push((index, shifted_qubits, shift_loss_probability));
+                        }
+                    }
+                    device.change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(loss_points)
+    }
+
+    /// Runs a parametric QuantumProgram for every set of parameter values of a grid.
+    ///
+    /// The circuits of the measurement are compiled once into templates, for every set of
+    /// parameter values only the operations with symbolic parameters are substituted. The values
+    /// are assigned to the input parameter names of the program, as in [QuantumProgram::run].
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The PauliZProduct, CheatedPauliZProduct or Cheated QuantumProgram to run.
+    /// * `parameter_grid` - The values of the input parameters of the program, one set per evaluation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Option<HashMap<String, f64>>>)` - The expectation values for each set of parameter values.
+    /// * `Err(RoqoqoBackendError)` - The program has no expectation values, the number of parameters does not match, a parameter could not be substituted or the simulation failed.
+    pub fn run_template(
+        &self,
+        program: &QuantumProgram,
+        parameter_grid: &[Vec<f64>],
+    ) -> Result<Vec<Option<HashMap<String, f64>>>, RoqoqoBackendError> {
+        match program {
+            QuantumProgram::PauliZProduct {
+                measurement,
+                input_parameter_names,
+            } => self.run_measurement_template::<PauliZProduct>(
+                measurement,
+                input_parameter_names,
+                parameter_grid,
+            ),
+            QuantumProgram::CheatedPauliZProduct {
+                measurement,
+                input_parameter_names,
+            } => self.run_measurement_template::<CheatedPauliZProduct>(
+                measurement,
+                input_parameter_names,
+                parameter_grid,
+            ),
+            QuantumProgram::Cheated {
+                measurement,
+                input_parameter_names,
+            } => self.run_measurement_template::<Cheated>(
+                measurement,
+                input_parameter_names,
+                parameter_grid,
+            ),
+            _ => Err(RoqoqoBackendError::GenericError {
+                msg: "Templates can only be run for PauliZProduct, CheatedPauliZProduct and Cheated QuantumPrograms".to_string(),
+            }),
+        }
+    }
+
+    /// Runs the templates of the circuits of a measurement for every set of parameter values.
+    fn run_measurement_template<M: MeasureExpectationValues>(
+        &self,
+        measurement: &M,
+        input_parameter_names: &[String],
+        parameter_grid: &[Vec<f64>],
+    ) -> Result<Vec<Option<HashMap<String, f64>>>, RoqoqoBackendError> {
+        if let Some(parameters) = parameter_grid
+            .iter()
+            .find(|parameters| parameters.len() != input_parameter_names.len())
+        {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Wrong number of parameters {} parameters expected {} parameters given",
+                    input_parameter_names.len(),
+                    parameters.len()
+                ),
+            });
+        }
+        let mut templates: Vec<CircuitTemplate> = measurement
+            .circuits()
+            .map(|circuit| {
+                CircuitTemplate::new(measurement.constant_circuit().iter().chain([circuit]))
+            })
+            .collect();
+        let mut expectation_values = Vec::with_capacity(parameter_grid.len());
+        for parameters in parameter_grid {
+            let mut calculator = Calculator::new();
+            for (name, value) in input_parameter_names.iter().zip(parameters.iter()) {
+                calculator.set_variable(name, *value);
+            }
+            let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+            let mut float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
+            let mut complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+            for template in templates.iter_mut() {
+                let (bits, floats, complexes) =
+                    self.run_circuit_iterator(template.substitute(&calculator)?.iter())?;
+                bit_registers.extend(bits);
+                float_registers.extend(floats);
+                complex_registers.extend(complexes);
+            }
+            expectation_values.push(
+                measurement
+                    .evaluate(bit_registers, float_registers, complex_registers)
+                    .map_err(|err| RoqoqoBackendError::GenericError {
+                        msg: format!("Could not evaluate the measurement: {}", err),
+                    })?,
+            );
+        }
+        Ok(expectation_values)
+    }
+
+    /// Returns the circuit with the global rotations replaced by RotateXY gates.
+    ///
+    /// The other device-changing operations are kept and applied to a copy of the device, so
+    /// that a global rotation acts on the qubits that are active at its point in the circuit.
+    /// Without a qubit -> tweezer mapping all qubits of the simulator are active. If the backend
+    /// is noisy, the noise of the active qubits is added after every native gate. The circuits
+    /// of state readouts and conditional operations are resolved with the device at their
+    /// position in the circuit.
+    fn resolve_device_operations<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+        mut device: TweezerDevice,
+    ) -> Result<Circuit, RoqoqoBackendError> {
+        let mut resolved_circuit = Circuit::new();
+        for operation in circuit {
+            if let Operation::PragmaChangeDevice(pragma) = operation {
+                let transport_time = apply_device_change(&mut device, pragma)?;
+                if self.transport_noise && transport_time > 0.0 {
+                    self.add_idle_noise(&mut resolved_circuit, &device, transport_time);
+                }
+                if pragma.wrapped_hqslang == "PragmaGlobalRotateXY" {
+                    let rotation: PragmaGlobalRotateXY =
+                        bincode::deserialize(&pragma.wrapped_operation).map_err(|err| {
+                            RoqoqoBackendError::GenericError {
+                                msg: format!("Could not deserialize PragmaGlobalRotateXY: {}", err),
+                            }
+                        })?;
+                    for qubit in self.active_qubits(&device) {
+                        resolved_circuit +=
+                            RotateXY::new(qubit, rotation.theta().clone(), rotation.phi().clone());
+                    }
+                    continue;
+                }
+            }
+            let resolved_operation: Operation = match operation {
+                // QuEST accepts RotateXY gates on all qubits of a Layout with a global rotation,
+                // the gates of the circuit itself are checked here
+                Operation::RotateXY(op)
+                    if device
+                        .single_qubit_gate_time(op.hqslang(), op.qubit())
+                        .is_none() =>
+                {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Operation {:?} not available for qubit {} in device",
+                            op,
+                            op.qubit()
+                        ),
+                    });
+                }
+                Operation::PragmaGetStateVector(op) => match op.circuit() {
+                    Some(readout_circuit) => PragmaGetStateVector::new(
+                        op.readout().clone(),
+                        Some(self.resolve_inner_circuit(readout_circuit, &device, op.hqslang())?),
+                    )
+                    .into(),
+                    None => operation.clone(),
+                },
+                Operation::PragmaGetDensityMatrix(op) => match op.circuit() {
+                    Some(readout_circuit) => PragmaGetDensityMatrix::new(
+                        op.readout().clone(),
+                        Some(self.resolve_inner_circuit(readout_circuit, &device, op.hqslang())?),
+                    )
+                    .into(),
+                    None => operation.clone(),
+                },
+                Operation::PragmaConditional(op) => {
+                    if let Some(Operation::PragmaChangeDevice(pragma)) =
+                        op.circuit().iter().find(|inner| {
+                            matches!(inner, Operation::PragmaChangeDevice(pragma)
+                                if pragma.wrapped_hqslang != "PragmaGlobalRotateXY")
+                        })
+                    {
+                        return Err(RoqoqoBackendError::GenericError {
+                            msg: format!(
+                                "The circuit of the PragmaConditional contains a {}, the device \
+                                 can not be changed conditionally.",
+                                pragma.wrapped_hqslang
+                            ),
+                        });
+                    }
+                    PragmaConditional::new(
+                        op.condition_register().clone(),
+                        *op.condition_index(),
+                        self.resolve_inner_circuit(op.circuit(), &device, op.hqslang())?,
+                    )
+                    .into()
+                }
+                Operation::PragmaActiveReset(_) if !device.allow_reset => {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: "The device does not allow resets, PragmaActiveReset is not \
+                              supported."
+                            .to_string(),
+                    });
+                }
+                _ => operation.clone(),
+            };
+            resolved_circuit.add_operation(resolved_operation);
+            if self.noisy {
+                let gate_time = gate_qubits(operation)
+                    .and_then(|qubits| native_gate_time(&device, operation.hqslang(), &qubits));
+                if let Some(gate_time) = gate_time {
+                    self.add_idle_noise(&mut resolved_circuit, &device, gate_time);
+                }
+            }
+        }
+        Ok(resolved_circuit)
+    }
+
+    /// Adds the noise of the tweezers of the active qubits for the given duration to a circuit.
+    fn add_idle_noise(&self, circuit: &mut Circuit, device: &TweezerDevice, duration: f64) {
+        for qubit in self.active_qubits(device) {
+            if let Ok(tweezer) = device.get_tweezer_from_qubit(&qubit) {
+                for noise_operation in
+                    decoherence_operations(qubit, duration, &device.get_tweezer_noise(tweezer))
+                {
+                    circuit.add_operation(noise_operation);
+                }
+            }
+        }
+    }
+
+    /// Returns the circuit of a state readout or conditional operation, checked against and
+    /// resolved on the device.
+    ///
+    /// The device-changing operations are resolved on a copy of the device and removed from the
+    /// circuit, as a readout circuit is only applied to a copy of the state and a conditional
+    /// circuit may only contain global rotations.
+    fn resolve_inner_circuit(
+        &self,
+        circuit: &Circuit,
+        device: &TweezerDevice,
+        hqslang: &str,
+    ) -> Result<Circuit, RoqoqoBackendError> {
+        if let Err(reports) = check_circuit_compatibility(circuit, device) {
+            let reports: Vec<String> = reports.iter().map(|report| report.to_string()).collect();
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "The circuit of the {} is not compatible with the device: {}",
+                    hqslang,
+                    reports.join("; ")
+                ),
+            });
+        }
+        Ok(self
+            .resolve_device_operations(circuit.iter(), device.clone())?
+            .iter()
+            .filter(|operation| !matches!(operation, Operation::PragmaChangeDevice(_)))
+            .cloned()
+            .collect())
+    }
+
+    /// Returns the qubits of the simulator that are active on the device.
+    ///
+    /// Without a qubit -> tweezer mapping all qubits of the simulator are active.
+    fn active_qubits(&self, device: &TweezerDevice) -> BTreeSet<usize> {
+        match &device.qubit_to_tweezer {
+            Some(map) => map
+                .keys()
+                .copied()
+                .filter(|qubit| *qubit < self.number_qubits)
+                .collect(),
+            None => (0..self.number_qubits).collect(),
+        }
+    }
+
+    /// Returns the random number generator of a run, seeded with the seed of the device if set.
+    ///
+    /// One generator is used for all random decisions of a run, so that the shots of a seeded
+    /// device are reproducible without drawing the same random numbers twice.
+    fn random_generator(&self) -> StdRng {
+        match self.device.seed() {
+            Some(seed) => StdRng::seed_from_u64(seed as u64),
+            None => StdRng::from_entropy(),
+        }
+    }
+
+    /// Simulates a circuit on the device of the backend with all tweezers loaded.
+    ///
+    /// If all measurements are at the end of the circuit, the shots are sampled from a single
+    /// simulation. Otherwise the shots are split between the threads of the backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit` - The resolved circuit to simulate.
+    /// * `rng` - The random number generator of the run the shots are sampled with.
+    fn simulate<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+        rng: &mut StdRng,
+    ) -> RegisterResult {
+        if let Some(max_qubits) = self
+            .device
+            .max_qubits
+            .filter(|max_qubits| self.number_qubits > *max_qubits)
+        {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "The simulator uses {} qubits, the hardware capacity of the device is {} qubits.",
+                    self.number_qubits, max_qubits
+                ),
+            });
+        }
+        let circuit: Circuit = circuit.cloned().collect();
+        if let Some(memory_limit) = self.memory_limit {
+            let density_matrix = self.density_matrix
+                || circuit
+                    .iter()
+                    .any(|operation| operation.tags().contains(&"PragmaNoiseOperation"));
+            let register = if density_matrix {
+                "density matrix"
+            } else {
+                "state vector"
+            };
+            let gpu_hint = if Self::uses_gpu() {
+                ", simulations exceeding the GPU memory need a build without the simulator-gpu feature"
+            } else {
+                ""
+            };
+            match self.required_memory(density_matrix) {
+                Some(memory) if memory <= memory_limit => {}
+                Some(memory) => {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "The {} of {} qubits requires {} bytes, the memory limit of the simulator is {} bytes{}.",
+                            register, self.number_qubits, memory, memory_limit, gpu_hint
+                        ),
+                    });
+                }
+                None => {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "The {} of {} qubits exceeds the addressable memory.",
+                            register, self.number_qubits
+                        ),
+                    });
+                }
+            }
+        }
+        if self.sample_terminal_measurements && has_terminal_measurements(&circuit) {
+            return self.sample_shots(&circuit, rng);
+        }
+        let number_shots = number_shots(&circuit);
+        if number_shots > 1
+            && circuit
+                .iter()
+                .any(|operation| matches!(operation, Operation::PragmaConditional(_)))
+        {
+            return self.simulate_feedback_shots(&circuit, number_shots, rng);
+        }
+        // All registers of a GPU build share the memory of the GPU, the shots are not split
+        if self.number_threads == 1 || number_shots <= 1 || Self::uses_gpu() {
+            return self.simulate_shots(circuit.iter(), None);
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.number_threads)
+            .build()
+            .map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("Could not create the simulation threads: {}", err),
+            })?;
+        // Split the shots evenly between the threads, the registers are merged in chunk order
+        let number_chunks = pool.current_num_threads().min(number_shots);
+        let chunk_circuits: Vec<Circuit> = (0..number_chunks)
+            .map(|chunk| {
+                let shots = number_shots / number_chunks
+                    + usize::from(chunk < number_shots % number_chunks);
+                loaded_circuit(&circuit, &BTreeMap::new(), shots)
+            })
+            .collect();
+        let results: Vec<Registers> = pool.install(|| {
+            chunk_circuits
+                .par_iter()
+                .map(|chunk_circuit| self.simulate_shots(chunk_circuit.iter(), None))
+                .collect::<Result<_, RoqoqoBackendError>>()
+        })?;
+        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+        let mut float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let mut complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        for (bits, floats, complexes) in results {
+            for (name, values) in bits {
+                bit_registers.entry(name).or_default().extend(values);
+            }
+            for (name, values) in floats {
+                float_registers.entry(name).or_default().extend(values);
+            }
+            for (name, values) in complexes {
+                complex_registers.entry(name).or_default().extend(values);
+            }
+        }
+        Ok((bit_registers, float_registers, complex_registers))
+    }
+
+    /// Simulates the shots of a circuit with classical feedback one after the other.
+    ///
+    /// QuEST replaces the measurements of a circuit with several shots by one repeated measurement
+    /// at the end of the circuit, which skips the mid-circuit measurements the PragmaConditional
+    /// operations depend on. Every shot is therefore simulated on its own, with a random seed
+    /// drawn from the random number generator of the run.
+    fn simulate_feedback_shots(
+        &self,
+        circuit: &Circuit,
+        number_shots: usize,
+        rng: &mut StdRng,
+    ) -> RegisterResult {
+        let shot_circuit: Circuit = loaded_circuit(circuit, &BTreeMap::new(), 1)
+            .iter()
+            .filter(|operation| !matches!(operation, Operation::PragmaSetNumberOfMeasurements(_)))
+            .cloned()
+            .collect();
+        let mut bit_registers: HashMap<String, BitOutputRegister> = HashMap::new();
+        let mut float_registers: HashMap<String, FloatOutputRegister> = HashMap::new();
+        let mut complex_registers: HashMap<String, ComplexOutputRegister> = HashMap::new();
+        for _ in 0..number_shots {
+            let (bits, floats, complexes) =
+                self.simulate_shots(shot_circuit.iter(), Some(vec![rng.gen::<u64>()]))?;
+            for (name, values) in bits {
+                bit_registers.entry(name).or_default().extend(values);
+            }
+            for (name, values) in floats {
+                float_registers.entry(name).or_default().extend(values);
+            }
+            for (name, values) in complexes {
+                complex_registers.entry(name).or_default().extend(values);
+            }
+        }
+        Ok((bit_registers, float_registers, complex_registers))
+    }
+
+    /// Simulates the shots of a circuit in one QuEST simulation.
+    fn simulate_shots<'a>(
+        &self,
+        circuit: impl Iterator<Item = &'a Operation>,
+        random_seed: Option<Vec<u64>>,
+    ) -> RegisterResult {
+        let mut tmp_device: Option<Box<dyn Device>> =
+            Some(Box::new(ResolvedDevice(self.device.clone())));
 
-        let quest_backend = roqoqo_quest::Backend::new(self.number_qubits, None);
+        let quest_backend = roqoqo_quest::Backend::new(self.number_qubits, random_seed);
 
+        if self.density_matrix && self.number_qubits > 0 {
+            // QuEST uses a density matrix for circuits with noise operations, a noise operation
+            // without effect switches noise free circuits to a density matrix as well
+            let mut density_matrix_circuit = Circuit::new();
+            density_matrix_circuit += PragmaDamping::new(0, 0.0.into(), 0.0.into());
+            for operation in circuit {
+                density_matrix_circuit.add_operation(operation.clone());
+            }
+            return quest_backend
+                .run_circuit_iterator_with_device(density_matrix_circuit.iter(), &mut tmp_device);
+        }
         quest_backend.run_circuit_iterator_with_device(circuit, &mut tmp_device)
     }
+
+    /// Simulates a circuit with terminal measurements once and samples its shots.
+    ///
+    /// The measurements are replaced by a readout of the final state vector, or of the final
+    /// density matrix if the circuit contains noise or the backend simulates a density matrix. The measured bits of every shot are sampled
+    /// from the final distribution with the random number generator of the run.
+    fn sample_shots(&self, circuit: &Circuit, rng: &mut StdRng) -> RegisterResult {
+        let is_density_matrix = self.density_matrix
+            || circuit
+                .iter()
+                .any(|operation| operation.tags().contains(&"PragmaNoiseOperation"));
+        let dimension = 2_usize.pow(self.number_qubits as u32);
+        let mut state_circuit = Circuit::new();
+        state_circuit += DefinitionComplex::new(
+            SAMPLING_REGISTER.to_string(),
+            if is_density_matrix {
+                dimension * dimension
+            } else {
+                dimension
+            },
+            true,
+        );
+        let mut bit_definitions: Vec<(String, usize)> = Vec::new();
+        for operation in circuit.iter() {
+            match operation {
+                Operation::MeasureQubit(_)
+                | Operation::PragmaRepeatedMeasurement(_)
+                | Operation::PragmaSetNumberOfMeasurements(_) => {}
+                Operation::DefinitionBit(op) => {
+                    if *op.is_output() {
+                        bit_definitions.push((op.name().clone(), *op.length()));
+                    }
+                    state_circuit.add_operation(operation.clone());
+                }
+                _ => state_circuit.add_operation(operation.clone()),
+            }
+        }
+        if is_density_matrix {
+            state_circuit += PragmaGetDensityMatrix::new(SAMPLING_REGISTER.to_string(), None);
+        } else {
+            state_circuit += PragmaGetStateVector::new(SAMPLING_REGISTER.to_string(), None);
+        }
+        let (_, _, complex_registers) = self.simulate_shots(state_circuit.iter(), None)?;
+        let state = complex_registers
+            .get(SAMPLING_REGISTER)
+            .and_then(|states| states.first())
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The simulation did not return the final state.".to_string(),
+            })?;
+        // Cumulative probabilities of the basis states, qubit 0 is the least significant bit
+        let mut cumulative: Vec<f64> = Vec::with_capacity(dimension);
+        let mut total = 0.0;
+        for index in 0..dimension {
+            total += if is_density_matrix {
+                state[index * dimension + index].re.max(0.0)
+            } else {
+                state[index].norm_sqr()
+            };
+            cumulative.push(total);
+        }
+
+        let mut bit_registers: HashMap<String, BitOutputRegister> = bit_definitions
+            .iter()
+            .map(|(name, _)| (name.clone(), Vec::new()))
+            .collect();
+        for _ in 0..number_shots(circuit) {
+            let sample = rng.gen::<f64>() * total;
+            let basis_state = cumulative
+                .partition_point(|probability| *probability <= sample)
+                .min(dimension - 1);
+            let measured = |qubit: usize| (basis_state >> qubit) & 1 == 1;
+            let mut shot: HashMap<&str, Vec<bool>> = bit_definitions
+                .iter()
+                .map(|(name, length)| (name.as_str(), vec![false; *length]))
+                .collect();
+            for operation in circuit.iter() {
+                match operation {
+                    Operation::MeasureQubit(op) => {
+                        if let Some(bit) = shot
+                            .get_mut(op.readout().as_str())
+                            .and_then(|register| register.get_mut(*op.readout_index()))
+                        {
+                            *bit = measured(*op.qubit());
+                        }
+                    }
+                    Operation::PragmaRepeatedMeasurement(op) => {
+                        if let Some(register) = shot.get_mut(op.readout().as_str()) {
+                            match op.qubit_mapping() {
+                                Some(mapping) => {
+                                    for (qubit, index) in mapping {
+                                        if let Some(bit) = register.get_mut(*index) {
+                                            *bit = measured(*qubit);
+                                        }
+                                    }
+                                }
+                                None => {
+                                    for (qubit, bit) in
+                                        register.iter_mut().enumerate().take(self.number_qubits)
+                                    {
+                                        *bit = measured(qubit);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            for (name, values) in shot {
+                if let Some(register) = bit_registers.get_mut(name) {
+                    register.push(values);
+                }
+            }
+        }
+        Ok((bit_registers, HashMap::new(), HashMap::new()))
+    }
+}
+
+/// Returns whether the shots of a circuit can be sampled from a single simulation.
+///
+/// The circuit needs to measure its qubits only at the end, without classical control, float or
+/// complex output registers or operations acting on already measured qubits.
+fn has_terminal_measurements(circuit: &Circuit) -> bool {
+    let mut measured_qubits: BTreeSet<usize> = BTreeSet::new();
+    let mut all_measured = false;
+    for operation in circuit.iter() {
+        match operation {
+            Operation::MeasureQubit(op) => {
+                measured_qubits.insert(*op.qubit());
+            }
+            Operation::PragmaRepeatedMeasurement(_) => all_measured = true,
+            Operation::PragmaSetNumberOfMeasurements(_)
+            | Operation::DefinitionBit(_)
+            | Operation::DefinitionUsize(_)
+            | Operation::PragmaChangeDevice(_) => {}
+            Operation::DefinitionFloat(op) => {
+                if *op.is_output() {
+                    return false;
+                }
+            }
+            Operation::DefinitionComplex(op) => {
+                if *op.is_output() {
+                    return false;
+                }
+            }
+            _ => {
+                let tags = operation.tags();
+                if !tags.contains(&"GateOperation") && !tags.contains(&"PragmaNoiseOperation") {
+                    return false;
+                }
+                let acts_on_measured_qubit = all_measured
+                    || match operation.involved_qubits() {
+                        InvolvedQubits::Set(involved) => {
+                            involved.iter().any(|qubit| measured_qubits.contains(qubit))
+                        }
+                        _ => !measured_qubits.is_empty(),
+                    };
+                if acts_on_measured_qubit {
+                    return false;
+                }
+            }
+        }
+    }
+    all_measured || !measured_qubits.is_empty()
+}
+
+/// Returns the number of shots of a circuit, the largest number of measurements of its measurement pragmas.
+fn number_shots(circuit: &Circuit) -> usize {
+    circuit
+        .iter()
+        .filter_map(|operation| match operation {
+            Operation::PragmaSetNumberOfMeasurements(op) => Some(*op.number_measurements()),
+            Operation::PragmaRepeatedMeasurement(op) => Some(*op.number_measurements()),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(1)
+}
+
+/// Returns the noise operations of a qubit during a gate.
+///
+/// Unset noise parameters and vanishing rates do not add an operation.
+fn decoherence_operations(
+    qubit: usize,
+    gate_time: f64,
+    noise: &TweezerNoiseParameters,
+) -> Vec<Operation> {
+    let mut operations: Vec<Operation> = Vec::new();
+    let damping = noise.t1.map(|t1| 1.0 / t1).unwrap_or(0.0);
+    if damping > 0.0 {
+        operations.push(PragmaDamping::new(qubit, gate_time.into(), damping.into()).into());
+    }
+    let dephasing = noise
+        .t2
+        .map(|t2| ((1.0 / t2 - damping / 2.0) / 2.0).max(0.0))
+        .unwrap_or(0.0);
+    if dephasing > 0.0 {
+        operations.push(PragmaDephasing::new(qubit, gate_time.into(), dephasing.into()).into());
+    }
+    if let Some(rate) = noise.depolarising_rate.filter(|rate| *rate > 0.0) {
+        operations.push(PragmaDepolarising::new(qubit, gate_time.into(), rate.into()).into());
+    }
+    operations
+}
+
+/// Applies a device-changing operation to the device and returns its transport time.
+///
+/// A PragmaShiftQubitsTweezers takes its duration in the Layout before the shift, a
+/// PragmaSwitchDeviceLayout the switch time of the new Layout. Other operations and shifts or
+/// switches without a configured time take no time.
+fn apply_device_change(
+    device: &mut TweezerDevice,
+    pragma: &PragmaChangeDevice,
+) -> Result<f64, RoqoqoBackendError> {
+    let shift_duration = if pragma.wrapped_hqslang == "PragmaShiftQubitsTweezers" {
+        PragmaShiftQubitsTweezers::from_bincode(&pragma.wrapped_operation)
+            .ok()
+            .and_then(|shift| device.shift_duration(&shift))
+    } else {
+        None
+    };
+    device.change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)?;
+    let transport_time = match pragma.wrapped_hqslang.as_str() {
+        "PragmaShiftQubitsTweezers" => shift_duration,
+        "PragmaSwitchDeviceLayout" => device.layout_switch_time(),
+        _ => None,
+    };
+    Ok(transport_time.unwrap_or(0.0))
+}
+
+/// Returns the circuit of the shots with the given lost qubits.
+///
+/// The losses map a lost qubit to the index of the first operation skipped on it, 0 for a qubit
+/// in an empty tweezer. A qubit lost during the circuit is reset before that operation. The
+/// measurements of lost qubits are kept, they read out the empty tweezer as 0. The number of
+/// measurements is set to the number of shots. The circuit is already resolved with the
+/// qubit -> tweezer mapping of the device, the lost qubits are not removed from that mapping.
+fn loaded_circuit(circuit: &Circuit, losses: &BTreeMap<usize, usize>, shots: usize) -> Circuit {
+    let mut loaded_circuit = Circuit::new();
+    for (index, operation) in circuit.iter().enumerate() {
+        for (qubit, _) in losses
+            .iter()
+            .filter(|(_, first_skipped)| **first_skipped == index && index > 0)
+        {
+            loaded_circuit += PragmaActiveReset::new(*qubit);
+        }
+        match operation {
+            Operation::PragmaSetNumberOfMeasurements(op) => {
+                loaded_circuit += PragmaSetNumberOfMeasurements::new(shots, op.readout().clone());
+            }
+            Operation::PragmaRepeatedMeasurement(op) => {
+                loaded_circuit += PragmaRepeatedMeasurement::new(
+                    op.readout().clone(),
+                    shots,
+                    op.qubit_mapping().clone(),
+                );
+            }
+            Operation::MeasureQubit(_) => {
+                loaded_circuit.add_operation(operation.clone());
+            }
+            _ => {
+                let acts_on_lost_qubit = match operation.involved_qubits() {
+                    InvolvedQubits::Set(involved) => involved.iter().any(|qubit| {
+                        losses
+                            .get(qubit)
+                            .is_some_and(|first_skipped| *first_skipped <= index)
+                    }),
+                    _ => false,
+                };
+                if !acts_on_lost_qubit {
+                    loaded_circuit.add_operation(operation.clone());
+                }
+            }
+        }
+    }
+    loaded_circuit
 }
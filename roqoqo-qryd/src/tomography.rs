@@ -0,0 +1,415 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Quantum state tomography.
+//!
+//! Generates the measurement-basis circuits for a set of target qubits using only gates native
+//! to QRyd devices, runs them on a backend and reconstructs the density matrix of the target
+//! qubits either by linear inversion or by maximum likelihood estimation.
+
+use ndarray::Array2;
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::operations::{
+    DefinitionBit, MeasureQubit, PragmaSetNumberOfMeasurements, RotateX, RotateXY,
+};
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::FRAC_PI_2;
+
+/// Name of the readout register used by the tomography circuits.
+pub const TOMOGRAPHY_READOUT: &str = "ro_tomography";
+
+/// Maximal number of iterations of the maximum likelihood reconstruction.
+const MLE_MAX_ITERATIONS: usize = 1000;
+/// Convergence threshold of the maximum likelihood reconstruction.
+const MLE_TOLERANCE: f64 = 1e-10;
+
+/// Basis in which a single qubit is measured during state tomography.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum TomographyBasis {
+    /// Measurement of the PauliX operator.
+    X,
+    /// Measurement of the PauliY operator.
+    Y,
+    /// Measurement of the PauliZ operator.
+    Z,
+}
+
+/// Method used to reconstruct the density matrix from the measured data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ReconstructionMethod {
+    /// Direct linear inversion of the measured Pauli expectation values.
+    ///
+    /// Fast, but the result is not guaranteed to be positive semi-definite.
+    LinearInversion,
+    /// Iterative maximum likelihood estimation.
+    ///
+    /// Always returns a physical (positive semi-definite, unit trace) density matrix.
+    MaximumLikelihood,
+}
+
+/// Experiment builder for quantum state tomography.
+///
+/// For `n` target qubits, `3^n` measurement settings are generated, measuring every
+/// target qubit in the X, Y or Z basis. The basis rotations only use the native
+/// `RotateX` and `RotateXY` gates.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct StateTomography {
+    /// The qubits whose state is reconstructed.
+    qubits: Vec<usize>,
+    /// The number of measurements performed for each measurement setting.
+    number_measurements: usize,
+}
+
+impl StateTomography {
+    /// Creates a new StateTomography experiment.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits` - The qubits whose state is reconstructed.
+    /// * `number_measurements` - The number of measurements performed for each measurement setting.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new StateTomography experiment.
+    /// * `Err(RoqoqoBackendError)` - No qubits, duplicated qubits or no measurements were given.
+    pub fn new(qubits: Vec<usize>, number_measurements: usize) -> Result<Self, RoqoqoBackendError> {
+        if qubits.is_empty() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "State tomography requires at least one qubit.".to_string(),
+            });
+        }
+        if qubits.iter().collect::<HashSet<&usize>>().len() != qubits.len() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("The tomography qubits {:?} contain duplicates.", qubits),
+            });
+        }
+        if number_measurements == 0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "State tomography requires at least one measurement per setting.".to_string(),
+            });
+        }
+        Ok(Self {
+            qubits,
+            number_measurements,
+        })
+    }
+
+    /// Returns the qubits whose state is reconstructed.
+    pub fn qubits(&self) -> &[usize] {
+        &self.qubits
+    }
+
+    /// Returns the number of measurements performed for each measurement setting.
+    pub fn number_measurements(&self) -> usize {
+        self.number_measurements
+    }
+
+    /// Returns the measurement settings of the experiment.
+    ///
+    /// The i-th entry of each setting is the basis the i-th target qubit is measured in.
+    /// The order of the settings matches the order of [StateTomography::measurement_circuits].
+    pub fn measurement_settings(&self) -> Vec<Vec<TomographyBasis>> {
+        let number_qubits = self.qubits.len();
+        (0..3_usize.pow(number_qubits as u32))
+            .map(|index| {
+                (0..number_qubits)
+                    .map(
+                        |position| match (index / 3_usize.pow(position as u32)) % 3 {
+                            0 => TomographyBasis::X,
+                            1 => TomographyBasis::Y,
+                            _ => TomographyBasis::Z,
+                        },
+                    )
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the measurement circuits of the experiment.
+    ///
+    /// Each circuit consists of the preparation circuit followed by the basis rotations and
+    /// the measurement of the target qubits into the [TOMOGRAPHY_READOUT] register, where
+    /// the i-th target qubit is measured into the i-th bit.
+    ///
+    /// # Arguments
+    ///
+    /// * `preparation` - The circuit preparing the state to reconstruct.
+    pub fn measurement_circuits(&self, preparation: &Circuit) -> Vec<Circuit> {
+        self.measurement_settings()
+            .iter()
+            .map(|setting| {
+                let mut circuit = preparation.clone();
+                circuit +=
+                    DefinitionBit::new(TOMOGRAPHY_READOUT.to_string(), self.qubits.len(), true);
+                for (qubit, basis) in self.qubits.iter().zip(setting.iter()) {
+                    match basis {
+                        TomographyBasis::X => {
+                            circuit += RotateXY::new(*qubit, (-FRAC_PI_2).into(), FRAC_PI_2.into())
+                        }
+                        TomographyBasis::Y => circuit += RotateX::new(*qubit, FRAC_PI_2.into()),
+                        TomographyBasis::Z => (),
+                    }
+                }
+                for (position, qubit) in self.qubits.iter().enumerate() {
+                    circuit += MeasureQubit::new(*qubit, TOMOGRAPHY_READOUT.to_string(), position);
+                }
+                circuit += PragmaSetNumberOfMeasurements::new(
+                    self.number_measurements,
+                    TOMOGRAPHY_READOUT.to_string(),
+                );
+                circuit
+            })
+            .collect()
+    }
+
+    /// Runs the experiment on a backend and reconstructs the density matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend` - The backend the measurement circuits are run on.
+    /// * `preparation` - The circuit preparing the state to reconstruct.
+    /// * `method` - The reconstruction method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The reconstructed density matrix.
+    /// * `Err(RoqoqoBackendError)` - Running a circuit or the reconstruction failed.
+    pub fn run<T: EvaluatingBackend>(
+        &self,
+        backend: &T,
+        preparation: &Circuit,
+        method: ReconstructionMethod,
+    ) -> Result<Array2<Complex64>, RoqoqoBackendError> {
+        let mut registers: Vec<Vec<Vec<bool>>> = Vec::new();
+        for circuit in self.measurement_circuits(preparation).iter() {
+            let (bit_registers, _, _) = backend.run_circuit(circuit)?;
+            let register = bit_registers.get(TOMOGRAPHY_READOUT).ok_or_else(|| {
+                RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Backend did not return the {} register.",
+                        TOMOGRAPHY_READOUT
+                    ),
+                }
+            })?;
+            registers.push(register.clone());
+        }
+        self.reconstruct(&registers, method)
+    }
+
+    /// Reconstructs the density matrix from the measured bit registers.
+    ///
+    /// The basis of the returned density matrix follows the roqoqo convention: bit `i` of the
+    /// basis state index corresponds to the i-th target qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `registers` - The measured bit registers, one per measurement setting, in the order of
+    ///                 [StateTomography::measurement_settings].
+    /// * `method` - The reconstruction method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The reconstructed density matrix.
+    /// * `Err(RoqoqoBackendError)` - The registers do not match the measurement settings.
+    pub fn reconstruct(
+        &self,
+        registers: &[Vec<Vec<bool>>],
+        method: ReconstructionMethod,
+    ) -> Result<Array2<Complex64>, RoqoqoBackendError> {
+        let settings = self.measurement_settings();
+        if registers.len() != settings.len() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Expected {} measured registers, one per measurement setting, got {}.",
+                    settings.len(),
+                    registers.len()
+                ),
+            });
+        }
+        for register in registers.iter() {
+            if register.is_empty() {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: "A measured register does not contain any shots.".to_string(),
+                });
+            }
+            if register.iter().any(|shot| shot.len() < self.qubits.len()) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "A measured register contains less than {} bits.",
+                        self.qubits.len()
+                    ),
+                });
+            }
+        }
+        match method {
+            ReconstructionMethod::LinearInversion => {
+                Ok(self.linear_inversion(&settings, registers))
+            }
+            ReconstructionMethod::MaximumLikelihood => {
+                Ok(self.maximum_likelihood(&settings, registers))
+            }
+        }
+    }
+
+    fn linear_inversion(
+        &self,
+        settings: &[Vec<TomographyBasis>],
+        registers: &[Vec<Vec<bool>>],
+    ) -> Array2<Complex64> {
+        let number_qubits = self.qubits.len();
+        let dimension = 1 << number_qubits;
+        let mut density_matrix = Array2::<Complex64>::zeros((dimension, dimension));
+        // Pauli strings encoded per qubit as 0: I, 1: X, 2: Y, 3: Z
+        for pauli_index in 0..4_usize.pow(number_qubits as u32) {
+            let paulis: Vec<usize> = (0..number_qubits)
+                .map(|position| (pauli_index >> (2 * position)) & 3)
+                .collect();
+            let mut sum: i64 = 0;
+            let mut shots: usize = 0;
+            for (setting, register) in settings.iter().zip(registers.iter()) {
+                let compatible = paulis
+                    .iter()
+                    .zip(setting.iter())
+                    .all(|(pauli, basis)| *pauli == 0 || *pauli == pauli_of_basis(basis));
+                if !compatible {
+                    continue;
+                }
+                for shot in register.iter() {
+                    let parity = paulis
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, pauli)| **pauli != 0)
+                        .fold(false, |acc, (position, _)| acc ^ shot[position]);
+                    sum += if parity { -1 } else { 1 };
+                    shots += 1;
+                }
+            }
+            let expectation = sum as f64 / shots as f64;
+            for row in 0..dimension {
+                for column in 0..dimension {
+                    let mut element = Complex64::new(expectation, 0.0);
+                    for (position, pauli) in paulis.iter().enumerate() {
+                        element *=
+                            pauli_element(*pauli, (row >> position) & 1, (column >> position) & 1);
+                    }
+                    density_matrix[[row, column]] += element;
+                }
+            }
+        }
+        density_matrix / Complex64::new(dimension as f64, 0.0)
+    }
+
+    fn maximum_likelihood(
+        &self,
+        settings: &[Vec<TomographyBasis>],
+        registers: &[Vec<Vec<bool>>],
+    ) -> Array2<Complex64> {
+        let number_qubits = self.qubits.len();
+        let dimension = 1 << number_qubits;
+        // Relative frequencies of the outcomes and the corresponding projectors
+        let mut observations: Vec<(f64, Array2<Complex64>)> = Vec::new();
+        for (setting, register) in settings.iter().zip(registers.iter()) {
+            let mut counts: HashMap<usize, usize> = HashMap::new();
+            for shot in register.iter() {
+                let outcome = (0..number_qubits)
+                    .filter(|position| shot[*position])
+                    .fold(0, |acc, position| acc | (1 << position));
+                *counts.entry(outcome).or_insert(0) += 1;
+            }
+            for (outcome, count) in counts.into_iter() {
+                observations.push((
+                    count as f64 / register.len() as f64,
+                    outcome_projector(setting, outcome),
+                ));
+            }
+        }
+
+        let mut density_matrix =
+            Array2::<Complex64>::eye(dimension) / Complex64::new(dimension as f64, 0.0);
+        for _ in 0..MLE_MAX_ITERATIONS {
+            let mut r_operator = Array2::<Complex64>::zeros((dimension, dimension));
+            for (frequency, projector) in observations.iter() {
+                let probability = projector
+                    .iter()
+                    .zip(density_matrix.t().iter())
+                    .map(|(p, r)| p * r)
+                    .sum::<Complex64>()
+                    .re;
+                if probability > f64::EPSILON {
+                    r_operator.scaled_add(Complex64::new(frequency / probability, 0.0), projector);
+                }
+            }
+            let updated = r_operator.dot(&density_matrix).dot(&r_operator);
+            let trace: Complex64 = updated.diag().sum();
+            let updated = updated / trace;
+            let change: f64 = (&updated - &density_matrix).iter().map(|x| x.norm()).sum();
+            density_matrix = updated;
+            if change < MLE_TOLERANCE {
+                break;
+            }
+        }
+        density_matrix
+    }
+}
+
+/// Returns the Pauli operator (1: X, 2: Y, 3: Z) measured in the given basis.
+fn pauli_of_basis(basis: &TomographyBasis) -> usize {
+    match basis {
+        TomographyBasis::X => 1,
+        TomographyBasis::Y => 2,
+        TomographyBasis::Z => 3,
+    }
+}
+
+/// Returns the matrix element of a single-qubit Pauli operator (0: I, 1: X, 2: Y, 3: Z).
+fn pauli_element(pauli: usize, row: usize, column: usize) -> Complex64 {
+    match pauli {
+        0 if row == column => Complex64::new(1.0, 0.0),
+        1 if row != column => Complex64::new(1.0, 0.0),
+        2 if row < column => Complex64::new(0.0, -1.0),
+        2 if row > column => Complex64::new(0.0, 1.0),
+        3 if row == column && row == 0 => Complex64::new(1.0, 0.0),
+        3 if row == column => Complex64::new(-1.0, 0.0),
+        _ => Complex64::new(0.0, 0.0),
+    }
+}
+
+/// Returns the projector onto the eigenstate of the measured outcome of a measurement setting.
+fn outcome_projector(setting: &[TomographyBasis], outcome: usize) -> Array2<Complex64> {
+    let dimension = 1 << setting.len();
+    let frac = Complex64::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+    let eigenvectors: Vec<[Complex64; 2]> = setting
+        .iter()
+        .enumerate()
+        .map(|(position, basis)| {
+            let bit = (outcome >> position) & 1;
+            match (basis, bit) {
+                (TomographyBasis::Z, 0) => [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+                (TomographyBasis::Z, _) => [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+                (TomographyBasis::X, 0) => [frac, frac],
+                (TomographyBasis::X, _) => [frac, -frac],
+                (TomographyBasis::Y, 0) => [frac, frac * Complex64::new(0.0, 1.0)],
+                (TomographyBasis::Y, _) => [frac, frac * Complex64::new(0.0, -1.0)],
+            }
+        })
+        .collect();
+    Array2::from_shape_fn((dimension, dimension), |(row, column)| {
+        eigenvectors
+            .iter()
+            .enumerate()
+            .fold(Complex64::new(1.0, 0.0), |acc, (position, vector)| {
+                acc * vector[(row >> position) & 1] * vector[(column >> position) & 1].conj()
+            })
+    })
+}
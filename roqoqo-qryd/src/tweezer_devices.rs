@@ -20,22 +20,52 @@ use bincode::deserialize;
 use itertools::{iproduct, Itertools};
 use ndarray::Array2;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     env,
+    hash::Hash,
+    path::Path,
     str::FromStr,
+    sync::OnceLock,
 };
 
+use crate::calibration::device_from_calibration_file;
+use crate::capabilities::capability_manifest;
+use crate::dense_gate_times::DenseGateTimes;
+use crate::device_files::{
+    read_device_file, resolve_file_format, write_device_file, DeviceFileFormat,
+};
+use crate::device_pragma_handlers::apply_registered_pragma;
+use crate::device_validation::{device_issues, DeviceIssue};
+use crate::emulator_devices::EmulatorNoiseParameters;
+use crate::gate_time_cache::GateTimeCache;
+use crate::json_patch::apply_json_patch;
+use crate::qryd_devices::{
+    first_device_single_qubit_gate_time, FirstDevice, FIRST_DEVICE_TWO_QUBIT_GATES,
+};
 use crate::{
-    phi_theta_relation, PragmaDeactivateQRydQubit, PragmaShiftQubitsTweezers,
+    phi_theta_relation, PragmaDeactivateQRydQubit, PragmaGlobalRotateXY, PragmaMoveToZone,
+    PragmaSetPhaseRelation, PragmaShiftQubitsTweezers, PragmaSwapTweezers,
     PragmaSwitchDeviceLayout,
 };
 
-use image::DynamicImage;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, DynamicImage, Frame, Rgba, RgbaImage};
 use roqollage::render_typst_str;
+#[cfg(feature = "vector-drawing")]
+use roqollage::TypstBackend;
 use roqoqo::{
     devices::{Device, GenericDevice},
-    RoqoqoBackendError, RoqoqoError,
+    operations::{InvolveQubits, InvolvedQubits, Operate, Operation, AVAILABLE_GATES_HQSLANG},
+    Circuit, RoqoqoBackendError, RoqoqoError,
 };
+#[cfg(feature = "web-api")]
+use serde_json::value::RawValue;
+#[cfg(feature = "vector-drawing")]
+use typst::eval::Tracer;
+#[cfg(feature = "vector-drawing")]
+use typst::foundations::Smart;
+#[cfg(feature = "vector-drawing")]
+use typst::layout::Abs;
 
 /// Native single-qubit gates allowed by the QRyd backend.
 pub static ALLOWED_NATIVE_SINGLE_QUBIT_GATES: [&str; 5] = [
@@ -61,16 +91,109 @@ pub static ALLOWED_NATIVE_THREE_QUBIT_GATES: [&str; 2] = [
 ];
 
 /// Native multi-qubit gates allowed by the QRyd backend.
-pub static ALLOWED_NATIVE_MULTI_QUBIT_GATES: [&str; 0] = [];
+pub static ALLOWED_NATIVE_MULTI_QUBIT_GATES: [&str; 1] = ["MultiQubitZZ"];
+
+/// Native gates a [TweezerDevice] allows gate times and error rates to be set for.
+///
+/// Defaults to the native gates of the QRyd backend, [ALLOWED_NATIVE_SINGLE_QUBIT_GATES],
+/// [ALLOWED_NATIVE_TWO_QUBIT_GATES], [ALLOWED_NATIVE_THREE_QUBIT_GATES] and
+/// [ALLOWED_NATIVE_MULTI_QUBIT_GATES].
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct NativeGates {
+    /// The hqslang names of the native single-qubit gates.
+    pub single_qubit_gates: Vec<String>,
+    /// The hqslang names of the native two-qubit gates.
+    pub two_qubit_gates: Vec<String>,
+    /// The hqslang names of the native three-qubit gates.
+    pub three_qubit_gates: Vec<String>,
+    /// The hqslang names of the native multi-qubit gates.
+    pub multi_qubit_gates: Vec<String>,
+}
+
+impl Default for NativeGates {
+    fn default() -> Self {
+        let to_vec =
+            |gates: &[&str]| -> Vec<String> { gates.iter().map(|gate| gate.to_string()).collect() };
+        NativeGates {
+            single_qubit_gates: to_vec(&ALLOWED_NATIVE_SINGLE_QUBIT_GATES),
+            two_qubit_gates: to_vec(&ALLOWED_NATIVE_TWO_QUBIT_GATES),
+            three_qubit_gates: to_vec(&ALLOWED_NATIVE_THREE_QUBIT_GATES),
+            multi_qubit_gates: to_vec(&ALLOWED_NATIVE_MULTI_QUBIT_GATES),
+        }
+    }
+}
+
+impl NativeGates {
+    /// Returns whether a gate is one of the native gates of any kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the gate.
+    pub fn contains(&self, hqslang: &str) -> bool {
+        self.single_qubit_gates
+            .iter()
+            .chain(&self.two_qubit_gates)
+            .chain(&self.three_qubit_gates)
+            .chain(&self.multi_qubit_gates)
+            .any(|gate| gate == hqslang)
+    }
+}
+
+/// Policies populating the qubit -> tweezer mapping when a [TweezerDevice] switches Layouts.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum TrivialMappingPolicy {
+    /// If no mapping is set, qubit i is mapped to tweezer i up to the largest tweezer of the
+    /// Layout, including tweezers without gates.
+    #[default]
+    AllTweezers,
+    /// If no mapping is set, qubit i is mapped to the i-th tweezer with gates in the Layout.
+    UsableTweezers,
+    /// Qubits keep their tweezers if these have gates in the new Layout, the remaining tweezers
+    /// with gates are mapped to the lowest unmapped qubits. Replaces any mapping that is set.
+    OccupiedFirst,
+    /// The mapping is left unchanged.
+    NoMapping,
+}
+
+impl TrivialMappingPolicy {
+    /// Returns the policy with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - One of `all-tweezers`, `usable-tweezers`, `occupied-first` or `none`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(TrivialMappingPolicy)` - The policy with the given name.
+    /// * `None` - The name is unknown.
+    pub fn from_name(name: &str) -> Option<TrivialMappingPolicy> {
+        match name {
+            "all-tweezers" => Some(TrivialMappingPolicy::AllTweezers),
+            "usable-tweezers" => Some(TrivialMappingPolicy::UsableTweezers),
+            "occupied-first" => Some(TrivialMappingPolicy::OccupiedFirst),
+            "none" => Some(TrivialMappingPolicy::NoMapping),
+            _ => None,
+        }
+    }
+}
+
+/// Zone label of the tweezers two-qubit gates are allowed between in a zoned Layout.
+pub static INTERACTION_ZONE: &str = "interaction";
 
 /// Tweezer Device
 ///
-#[derive(Debug, PartialEq, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Default, Clone, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct TweezerDevice {
     /// Mapping from qubit to tweezer.
     pub qubit_to_tweezer: Option<HashMap<usize, usize>>,
-    /// Register of Layouts.
-    pub layout_register: Option<HashMap<String, TweezerLayoutInfo>>,
+    /// Register of Layouts, None for an EmulatorDevice.
+    ///
+    /// Layouts of devices created with [TweezerDevice::from_api] are only moved into the register
+    /// when they are first used, read them with [TweezerDevice::layouts] and
+    /// [TweezerDevice::layout_info].
+    pub(crate) layout_register: Option<HashMap<String, TweezerLayoutInfo>>,
     /// Current Layout.
     pub current_layout: Option<String>,
     /// The specific PhaseShiftedControlledZ relation to use.
@@ -83,11 +206,416 @@ pub struct TweezerDevice {
     pub(crate) seed: Option<usize>,
     /// Whether to allow PragmaActiveReset operations on the device.
     pub allow_reset: bool,
+    /// Whether setting a two-qubit gate time for a tweezer pair also sets it for the reversed pair.
+    #[serde(default)]
+    pub symmetric_two_qubit_gate_times: bool,
+    /// Native gates gate times and error rates can be set for, see [TweezerDevice::set_native_gates].
+    #[serde(default)]
+    pub native_gates: NativeGates,
+    /// Maximal number of qubits the hardware supports, None if the capacity is not limited.
+    #[serde(default)]
+    pub max_qubits: Option<usize>,
     /// Device name.
     pub device_name: String,
     /// Available gates (EmulatorDevice).
     #[serde(default)]
     pub available_gates: Option<Vec<String>>,
+    /// Allowed two-qubit edges (EmulatorDevice), None for all-to-all connectivity.
+    #[serde(default)]
+    pub allowed_edges: Option<Vec<(usize, usize)>>,
+    /// Global noise parameters of a noisy emulator (EmulatorDevice), None for an ideal emulator.
+    #[serde(default)]
+    pub emulator_noise: Option<EmulatorNoiseParameters>,
+    /// Noise parameters of the tweezers, used by the simulator.
+    #[serde(default)]
+    pub tweezer_noise: HashMap<usize, TweezerNoiseParameters>,
+    /// Stochastic loading of the tweezers at the start of each shot, used by the simulator.
+    #[serde(default)]
+    pub array_preparation: ArrayPreparation,
+    /// Probabilities of losing atoms during shifts and measurements, used by the simulator.
+    #[serde(default)]
+    pub atom_loss: AtomLoss,
+    /// Custom phi-theta relations the phase relations of the device can refer to by name.
+    #[serde(default)]
+    pub phi_theta_relations: HashMap<String, PhiThetaRelation>,
+    /// Cache of the gate time lookups, see [TweezerDevice::set_gate_time_cache_capacity].
+    #[serde(skip)]
+    pub(crate) gate_time_cache: GateTimeCache,
+    /// Cache of dense two-qubit gate time tables of the current layout, built on the first lookup.
+    #[serde(skip)]
+    pub(crate) dense_gate_times: DenseGateTimes,
+    /// Layouts downloaded with [TweezerDevice::from_api] that have not been loaded yet.
+    #[serde(skip)]
+    pub(crate) serialized_layouts: HashMap<String, SerializedLayout>,
+}
+
+/// Layout kept in serialized form until it is first accessed.
+///
+/// This only saves the deserialization of Layouts that are never used. A read-only access keeps
+/// the JSON representation next to the deserialized Layout, so it does not save memory.
+#[derive(Debug, Clone)]
+pub(crate) struct SerializedLayout {
+    /// The JSON representation of the Layout.
+    json: String,
+    /// The Layout, once deserialized by a read-only access.
+    info: OnceLock<TweezerLayoutInfo>,
+}
+
+impl SerializedLayout {
+    /// Returns the Layout, deserializing it on the first call.
+    fn info(&self) -> Result<&TweezerLayoutInfo, RoqoqoBackendError> {
+        if let Some(info) = self.info.get() {
+            return Ok(info);
+        }
+        let info = serde_json::from_str(&self.json).map_err(serialized_layout_error)?;
+        Ok(self.info.get_or_init(|| info))
+    }
+
+    /// Returns the owned Layout, deserializing it if it has not been accessed yet.
+    fn into_info(self) -> Result<TweezerLayoutInfo, RoqoqoBackendError> {
+        match self.info.into_inner() {
+            Some(info) => Ok(info),
+            None => serde_json::from_str(&self.json).map_err(serialized_layout_error),
+        }
+    }
+}
+
+fn serialized_layout_error(err: serde_json::Error) -> RoqoqoBackendError {
+    RoqoqoBackendError::GenericError {
+        msg: format!(
+            "Could not deserialize the Layout of the TweezerDevice: {}",
+            err
+        ),
+    }
+}
+
+/// Serialized form of the TweezerDevice, generic over the representation of the Layouts.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TweezerDeviceSerialize<R> {
+    qubit_to_tweezer: Option<HashMap<usize, usize>>,
+    layout_register: Option<R>,
+    current_layout: Option<String>,
+    controlled_z_phase_relation: String,
+    controlled_phase_phase_relation: String,
+    default_layout: Option<String>,
+    seed: Option<usize>,
+    allow_reset: bool,
+    #[serde(default)]
+    symmetric_two_qubit_gate_times: bool,
+    #[serde(default)]
+    native_gates: NativeGates,
+    #[serde(default)]
+    max_qubits: Option<usize>,
+    device_name: String,
+    #[serde(default)]
+    available_gates: Option<Vec<String>>,
+    #[serde(default)]
+    allowed_edges: Option<Vec<(usize, usize)>>,
+    #[serde(default)]
+    emulator_noise: Option<EmulatorNoiseParameters>,
+    #[serde(default)]
+    tweezer_noise: HashMap<usize, TweezerNoiseParameters>,
+    #[serde(default)]
+    array_preparation: ArrayPreparation,
+    #[serde(default)]
+    atom_loss: AtomLoss,
+    #[serde(default)]
+    phi_theta_relations: HashMap<String, PhiThetaRelation>,
+}
+
+impl serde::Serialize for TweezerDevice {
+    // Layouts that have not been loaded yet are serialized like the loaded ones.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TweezerDeviceSerialize {
+            qubit_to_tweezer: self.qubit_to_tweezer.clone(),
+            layout_register: self.layouts().map_err(serde::ser::Error::custom)?,
+            current_layout: self.current_layout.clone(),
+            controlled_z_phase_relation: self.controlled_z_phase_relation.clone(),
+            controlled_phase_phase_relation: self.controlled_phase_phase_relation.clone(),
+            default_layout: self.default_layout.clone(),
+            seed: self.seed,
+            allow_reset: self.allow_reset,
+            symmetric_two_qubit_gate_times: self.symmetric_two_qubit_gate_times,
+            native_gates: self.native_gates.clone(),
+            max_qubits: self.max_qubits,
+            device_name: self.device_name.clone(),
+            available_gates: self.available_gates.clone(),
+            allowed_edges: self.allowed_edges.clone(),
+            emulator_noise: self.emulator_noise,
+            tweezer_noise: self.tweezer_noise.clone(),
+            array_preparation: self.array_preparation.clone(),
+            atom_loss: self.atom_loss,
+            phi_theta_relations: self.phi_theta_relations.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl PartialEq for TweezerDevice {
+    // Layouts that have not been loaded yet are compared like the loaded ones,
+    // the gate time lookup caches are ignored.
+    fn eq(&self, other: &Self) -> bool {
+        let TweezerDevice {
+            qubit_to_tweezer,
+            layout_register: _,
+            current_layout,
+            controlled_z_phase_relation,
+            controlled_phase_phase_relation,
+            default_layout,
+            seed,
+            allow_reset,
+            symmetric_two_qubit_gate_times,
+            native_gates,
+            max_qubits,
+            device_name,
+            available_gates,
+            allowed_edges,
+            emulator_noise,
+            tweezer_noise,
+            array_preparation,
+            atom_loss,
+            phi_theta_relations,
+            gate_time_cache: _,
+            dense_gate_times: _,
+            serialized_layouts: _,
+        } = self;
+        let layouts_eq = match (self.layouts(), other.layouts()) {
+            (Ok(layouts), Ok(other_layouts)) => layouts == other_layouts,
+            _ => false,
+        };
+        layouts_eq
+            && *qubit_to_tweezer == other.qubit_to_tweezer
+            && *current_layout == other.current_layout
+            && *controlled_z_phase_relation == other.controlled_z_phase_relation
+            && *controlled_phase_phase_relation == other.controlled_phase_phase_relation
+            && *default_layout == other.default_layout
+            && *seed == other.seed
+            && *allow_reset == other.allow_reset
+            && *symmetric_two_qubit_gate_times == other.symmetric_two_qubit_gate_times
+            && *native_gates == other.native_gates
+            && *max_qubits == other.max_qubits
+            && *device_name == other.device_name
+            && *available_gates == other.available_gates
+            && *allowed_edges == other.allowed_edges
+            && *emulator_noise == other.emulator_noise
+            && *tweezer_noise == other.tweezer_noise
+            && *array_preparation == other.array_preparation
+            && *atom_loss == other.atom_loss
+            && *phi_theta_relations == other.phi_theta_relations
+    }
+}
+
+/// Noise parameters of a qubit in a tweezer.
+///
+/// Unset parameters do not contribute to the decoherence rates of the qubit.
+#[derive(Debug, Default, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct TweezerNoiseParameters {
+    /// Relaxation time T1.
+    pub t1: Option<f64>,
+    /// Dephasing time T2.
+    pub t2: Option<f64>,
+    /// Depolarising rate.
+    pub depolarising_rate: Option<f64>,
+}
+
+impl TweezerNoiseParameters {
+    /// Returns the decoherence rates matrix in the (sigma^+, sigma^-, sigma^z) Lindblad basis.
+    ///
+    /// The damping rate is given by `1/T1`, the pure dephasing rate by `(1/T2 - 1/(2 T1)) / 2`
+    /// and the depolarising rate `r` contributes `r/2`, `r/2` and `r/4` to the diagonal.
+    ///
+    /// # Returns
+    ///
+    /// * `Array2<f64>` - The 3x3 decoherence rates matrix.
+    pub fn decoherence_rates(&self) -> Array2<f64> {
+        let mut rates: Array2<f64> = Array2::zeros((3, 3));
+        let damping = self.t1.map(|t1| 1.0 / t1).unwrap_or(0.0);
+        rates[[1, 1]] += damping;
+        if let Some(t2) = self.t2 {
+            rates[[2, 2]] += ((1.0 / t2 - damping / 2.0) / 2.0).max(0.0);
+        }
+        if let Some(rate) = self.depolarising_rate {
+            rates[[0, 0]] += rate / 2.0;
+            rates[[1, 1]] += rate / 2.0;
+            rates[[2, 2]] += rate / 4.0;
+        }
+        rates
+    }
+}
+
+/// Stochastic loading of the tweezers of a [TweezerDevice] at the start of each shot.
+///
+/// Used by the simulator, which samples the occupied tweezers of every shot. Operations acting
+/// on a qubit in an empty tweezer are skipped and its measurement returns `false`.
+#[derive(Debug, Default, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct ArrayPreparation {
+    /// Probability that a tweezer is loaded with an atom, tweezers without a probability are always loaded.
+    #[serde(default)]
+    pub loading_probabilities: HashMap<usize, f64>,
+    /// Whether the loaded atoms are rearranged before each shot, filling the tweezers of the
+    /// qubits of the circuit in ascending order with the atoms of all tweezers of the Layout.
+    #[serde(default)]
+    pub rearrangement: bool,
+}
+
+/// Probabilities of losing the atom of a qubit during the operations of a shot.
+///
+/// Used by the simulator, which samples the lost qubits of every shot. A lost qubit is
+/// deactivated from the operation it is lost in: it is reset to `|0>`, subsequent operations
+/// acting on it are skipped and its measurements return `false`.
+#[derive(Debug, Default, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct AtomLoss {
+    /// Probability that the atom of a qubit is lost when its tweezer is shifted.
+    #[serde(default)]
+    pub shift_loss_probability: f64,
+    /// Probability that the atom of a qubit is lost when the qubit is measured.
+    #[serde(default)]
+    pub measurement_loss_probability: f64,
+}
+
+/// A phi-theta relation given by its coefficients, see [crate::phi_theta_relation].
+///
+/// Relations are registered on a [TweezerDevice] with [TweezerDevice::add_phi_theta_relation]
+/// and serialized with the device, so that updated interaction models can be shipped as data.
+/// As for the builtin relations, theta is first mapped into the interval [0, 2 pi].
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub enum PhiThetaRelation {
+    /// phi = sum_k coefficients\[k\] * theta^k
+    Polynomial {
+        /// The coefficients, starting with the constant one.
+        coefficients: Vec<f64>,
+    },
+    /// phi = offset + scale * ln(quadratic * theta^2 * exp(exponent * theta) + linear * theta + constant),
+    /// the form of the "DefaultRelation".
+    Logarithmic {
+        /// The offset of phi.
+        offset: f64,
+        /// The factor of the logarithm.
+        scale: f64,
+        /// The factor of the quadratic term.
+        quadratic: f64,
+        /// The factor of theta in the exponential of the quadratic term.
+        exponent: f64,
+        /// The factor of the linear term.
+        linear: f64,
+        /// The constant term.
+        constant: f64,
+    },
+}
+
+impl PhiThetaRelation {
+    /// Returns phi for the given theta.
+    ///
+    /// # Arguments
+    ///
+    /// * `theta` - The theta angle.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The phi angle.
+    /// * `None` - The relation is not defined for theta.
+    pub fn phi(&self, mut theta: f64) -> Option<f64> {
+        while theta < 0.0 {
+            theta += 2.0 * std::f64::consts::PI;
+        }
+        while theta > 2.0 * std::f64::consts::PI {
+            theta -= 2.0 * std::f64::consts::PI
+        }
+        let phi = match self {
+            PhiThetaRelation::Polynomial { coefficients } => coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |phi, coefficient| phi * theta + coefficient),
+            PhiThetaRelation::Logarithmic {
+                offset,
+                scale,
+                quadratic,
+                exponent,
+                linear,
+                constant,
+            } => {
+                offset
+                    + scale
+                        * f64::ln(
+                            quadratic * theta * theta * f64::exp(exponent * theta)
+                                + linear * theta
+                                + constant,
+                        )
+            }
+        };
+        phi.is_finite().then_some(phi)
+    }
+}
+
+/// Options of the drawing of a [TweezerDevice] created by [TweezerDevice::draw_with_options].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DrawOptions {
+    /// Whether to label the tweezers with their index.
+    pub show_tweezer_labels: bool,
+    /// Whether to label the tweezers with the index of the qubit they hold.
+    pub show_qubit_labels: bool,
+    /// Whether to add a legend explaining the labels, the edges and the shift arrows.
+    pub show_legend: bool,
+    /// The resolution of the image in dots per inch, overriding the pixels per point if set.
+    pub dpi: Option<f32>,
+}
+
+impl Default for DrawOptions {
+    fn default() -> Self {
+        DrawOptions {
+            show_tweezer_labels: true,
+            show_qubit_labels: true,
+            show_legend: false,
+            dpi: None,
+        }
+    }
+}
+
+/// Imports and page setup of the typst documents of the drawings.
+const DRAWING_HEADER: &str = r#"#import "@preview/fletcher:0.5.0" as fletcher: diagram, node, edge
+#set page(width: auto, height: auto, margin: 5mm, fill: white)
+#show math.equation: set text(font: "Fira Math")
+
+"#;
+
+/// Output formats of the drawing of a [TweezerDevice] created by [TweezerDevice::draw_as].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DrawFormat {
+    /// Raster image in the PNG format.
+    Png,
+    /// Vector graphic in the SVG format.
+    Svg,
+    /// Vector graphic in the PDF format.
+    Pdf,
+}
+
+impl DrawFormat {
+    /// Returns the format matching the extension of a file path, case-insensitive.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(DrawFormat)` - The format of a `.png`, `.svg` or `.pdf` file.
+    /// * `None` - The path has no or another extension.
+    pub fn from_path(path: &str) -> Option<DrawFormat> {
+        let extension = std::path::Path::new(path)
+            .extension()?
+            .to_str()?
+            .to_lowercase();
+        match extension.as_str() {
+            "png" => Some(DrawFormat::Png),
+            "svg" => Some(DrawFormat::Svg),
+            "pdf" => Some(DrawFormat::Pdf),
+            _ => None,
+        }
+    }
 }
 
 /// Tweezers information relative to a Layout
@@ -114,9 +642,35 @@ pub struct TweezerLayoutInfo {
     /// Specifies how many tweezers per row are present. Dynamic layout switching is only allowed between layouts
     /// having the same number of tweezers per row.
     pub tweezers_per_row: Option<Vec<usize>>,
+    /// Optional (x, y) coordinates of the tweezers, used for distance-based gate times and drawing.
+    pub tweezer_coordinates: HashMap<usize, (f64, f64)>,
+    /// Maps a single-qubit gate name to a tweezer -> error rate mapping
+    pub tweezer_single_qubit_gate_errors: HashMap<String, HashMap<usize, f64>>,
+    /// Maps a two-qubit gate name to a (tweezer, tweezer) -> error rate mapping
+    pub tweezer_two_qubit_gate_errors: HashMap<String, HashMap<(usize, usize), f64>>,
+    /// Maps a three-qubit gate name to a (tweezer, tweezer, tweezer) -> error rate mapping
+    pub tweezer_three_qubit_gate_errors: HashMap<String, HashMap<(usize, usize, usize), f64>>,
+    /// Maps a tweezer to the label of the zone it is placed in, e.g. "storage" or "interaction".
+    pub tweezer_zones: HashMap<usize, String>,
+    /// Maps an allowed shift (start tweezer, end tweezer) to its transport time
+    pub tweezer_shift_times: HashMap<(usize, usize), f64>,
+    /// Transport time per tweezer passed, used for the allowed shifts without a transport time.
+    pub shift_time_per_site: Option<f64>,
+    /// Maps a (tweezer, tweezer) pair to the sampled (theta, gate time) curve of the
+    /// PhaseShiftedControlledPhase gate, sorted by theta.
+    pub controlled_phase_time_curves: HashMap<(usize, usize), Vec<(f64, f64)>>,
+    /// Tweezer pairs that can not be driven simultaneously, stored as (smaller, larger) tweezer.
+    pub blockaded_tweezer_pairs: HashSet<(usize, usize)>,
+    /// Blockade radius, tweezers with coordinates at most this distance apart can not be driven simultaneously.
+    pub blockade_radius: Option<f64>,
+    /// Gate time of the global rotation PragmaGlobalRotateXY acting on all qubits simultaneously.
+    pub global_rotation_time: Option<f64>,
+    /// Time to switch the device into this Layout with a PragmaSwitchDeviceLayout.
+    pub layout_switch_time: Option<f64>,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 struct TweezerLayoutInfoSerialize {
     /// Maps a single-qubit gate name to a tweezer -> time mapping
     tweezer_single_qubit_gate_times: Vec<(String, SingleTweezerTimes)>,
@@ -130,11 +684,60 @@ struct TweezerLayoutInfoSerialize {
     allowed_tweezer_shifts: Vec<(usize, Vec<Vec<usize>>)>,
     /// Specifies how many tweezers per row are present.
     tweezers_per_row: Option<Vec<usize>>,
+    /// The (x, y) coordinates of the tweezers
+    #[serde(default)]
+    tweezer_coordinates: Vec<(usize, (f64, f64))>,
+    /// Maps a single-qubit gate name to a tweezer -> error rate mapping
+    #[serde(default)]
+    tweezer_single_qubit_gate_errors: Vec<(String, SingleTweezerTimes)>,
+    /// Maps a two-qubit gate name to a (tweezer, tweezer) -> error rate mapping
+    #[serde(default)]
+    tweezer_two_qubit_gate_errors: Vec<(String, TwoTweezersTimes)>,
+    /// Maps a three-qubit gate name to a (tweezer, tweezer, tweezer) -> error rate mapping
+    #[serde(default)]
+    tweezer_three_qubit_gate_errors: Vec<(String, ThreeTweezersTimes)>,
+    /// Maps a tweezer to the label of its zone
+    #[serde(default)]
+    tweezer_zones: Vec<(usize, String)>,
+    /// Maps an allowed shift (start tweezer, end tweezer) to its transport time
+    #[serde(default)]
+    tweezer_shift_times: TwoTweezersTimes,
+    /// Transport time per tweezer passed
+    #[serde(default)]
+    shift_time_per_site: Option<f64>,
+    /// Maps a (tweezer, tweezer) pair to the sampled (theta, gate time) curve
+    #[serde(default)]
+    controlled_phase_time_curves: TwoTweezersCurves,
+    /// Tweezer pairs that can not be driven simultaneously
+    #[serde(default)]
+    blockaded_tweezer_pairs: Vec<(usize, usize)>,
+    /// Blockade radius
+    #[serde(default)]
+    blockade_radius: Option<f64>,
+    /// Gate time of the global rotation
+    #[serde(default)]
+    global_rotation_time: Option<f64>,
+    /// Time to switch into the Layout
+    #[serde(default)]
+    layout_switch_time: Option<f64>,
 }
 type SingleTweezerTimes = Vec<(usize, f64)>;
 type TwoTweezersTimes = Vec<((usize, usize), f64)>;
 type ThreeTweezersTimes = Vec<((usize, usize, usize), f64)>;
 type MultiTweezersTimes = Vec<(Vec<usize>, f64)>;
+type TwoTweezersCurves = Vec<((usize, usize), Vec<(f64, f64)>)>;
+
+// The schema describes the serialized form of the Layout.
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for TweezerLayoutInfo {
+    fn schema_name() -> String {
+        "TweezerLayoutInfo".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <TweezerLayoutInfoSerialize>::json_schema(gen)
+    }
+}
 
 impl From<TweezerLayoutInfoSerialize> for TweezerLayoutInfo {
     fn from(info: TweezerLayoutInfoSerialize) -> Self {
@@ -161,6 +764,34 @@ impl From<TweezerLayoutInfoSerialize> for TweezerLayoutInfo {
         let allowed_tweezer_shifts: HashMap<usize, Vec<Vec<usize>>> =
             info.allowed_tweezer_shifts.into_iter().collect();
         let tweezers_per_row = info.tweezers_per_row;
+        let tweezer_coordinates: HashMap<usize, (f64, f64)> =
+            info.tweezer_coordinates.into_iter().collect();
+        let tweezer_single_qubit_gate_errors: HashMap<String, HashMap<usize, f64>> = info
+            .tweezer_single_qubit_gate_errors
+            .into_iter()
+            .map(|(k, v)| (k, v.into_iter().collect()))
+            .collect();
+        let tweezer_two_qubit_gate_errors: HashMap<String, HashMap<(usize, usize), f64>> = info
+            .tweezer_two_qubit_gate_errors
+            .into_iter()
+            .map(|(k, v)| (k, v.into_iter().collect()))
+            .collect();
+        let tweezer_three_qubit_gate_errors: HashMap<String, HashMap<(usize, usize, usize), f64>> =
+            info.tweezer_three_qubit_gate_errors
+                .into_iter()
+                .map(|(k, v)| (k, v.into_iter().collect()))
+                .collect();
+        let tweezer_zones: HashMap<usize, String> = info.tweezer_zones.into_iter().collect();
+        let tweezer_shift_times: HashMap<(usize, usize), f64> =
+            info.tweezer_shift_times.into_iter().collect();
+        let shift_time_per_site = info.shift_time_per_site;
+        let controlled_phase_time_curves: HashMap<(usize, usize), Vec<(f64, f64)>> =
+            info.controlled_phase_time_curves.into_iter().collect();
+        let blockaded_tweezer_pairs: HashSet<(usize, usize)> =
+            info.blockaded_tweezer_pairs.into_iter().collect();
+        let blockade_radius = info.blockade_radius;
+        let global_rotation_time = info.global_rotation_time;
+        let layout_switch_time = info.layout_switch_time;
 
         Self {
             tweezer_single_qubit_gate_times,
@@ -169,35 +800,86 @@ impl From<TweezerLayoutInfoSerialize> for TweezerLayoutInfo {
             tweezer_multi_qubit_gate_times,
             allowed_tweezer_shifts,
             tweezers_per_row,
+            tweezer_coordinates,
+            tweezer_single_qubit_gate_errors,
+            tweezer_two_qubit_gate_errors,
+            tweezer_three_qubit_gate_errors,
+            tweezer_zones,
+            tweezer_shift_times,
+            shift_time_per_site,
+            controlled_phase_time_curves,
+            blockaded_tweezer_pairs,
+            blockade_radius,
+            global_rotation_time,
+            layout_switch_time,
         }
     }
 }
 
 impl From<TweezerLayoutInfo> for TweezerLayoutInfoSerialize {
+    // The entries are sorted, giving the canonical serialization patched by
+    // [TweezerDevice::apply_update].
     fn from(info: TweezerLayoutInfo) -> Self {
-        let tweezer_single_qubit_gate_times: Vec<(String, SingleTweezerTimes)> = info
+        let mut tweezer_single_qubit_gate_times: Vec<(String, SingleTweezerTimes)> = info
             .tweezer_single_qubit_gate_times
             .into_iter()
-            .map(|(k, v)| (k, v.into_iter().collect()))
+            .map(|(k, v)| (k, sorted_by_key(v.into_iter().collect())))
             .collect();
-        let tweezer_two_qubit_gate_times: Vec<(String, TwoTweezersTimes)> = info
+        tweezer_single_qubit_gate_times.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut tweezer_two_qubit_gate_times: Vec<(String, TwoTweezersTimes)> = info
             .tweezer_two_qubit_gate_times
             .into_iter()
-            .map(|(k, v)| (k, v.into_iter().collect()))
+            .map(|(k, v)| (k, sorted_by_key(v.into_iter().collect())))
             .collect();
-        let tweezer_three_qubit_gate_times: Vec<(String, ThreeTweezersTimes)> = info
+        tweezer_two_qubit_gate_times.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut tweezer_three_qubit_gate_times: Vec<(String, ThreeTweezersTimes)> = info
             .tweezer_three_qubit_gate_times
             .into_iter()
-            .map(|(k, v)| (k, v.into_iter().collect()))
+            .map(|(k, v)| (k, sorted_by_key(v.into_iter().collect())))
             .collect();
-        let tweezer_multi_qubit_gate_times: Vec<(String, MultiTweezersTimes)> = info
+        tweezer_three_qubit_gate_times.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut tweezer_multi_qubit_gate_times: Vec<(String, MultiTweezersTimes)> = info
             .tweezer_multi_qubit_gate_times
             .into_iter()
-            .map(|(k, v)| (k, v.into_iter().collect()))
+            .map(|(k, v)| (k, sorted_by_key(v.into_iter().collect())))
             .collect();
+        tweezer_multi_qubit_gate_times.sort_by(|a, b| a.0.cmp(&b.0));
         let allowed_tweezer_shifts: Vec<(usize, Vec<Vec<usize>>)> =
-            info.allowed_tweezer_shifts.into_iter().collect();
+            sorted_by_key(info.allowed_tweezer_shifts.into_iter().collect());
         let tweezers_per_row = info.tweezers_per_row;
+        let tweezer_coordinates: Vec<(usize, (f64, f64))> =
+            sorted_by_key(info.tweezer_coordinates.into_iter().collect());
+        let tweezer_single_qubit_gate_errors: Vec<(String, SingleTweezerTimes)> = sorted_by_key(
+            info.tweezer_single_qubit_gate_errors
+                .into_iter()
+                .map(|(k, v)| (k, sorted_by_key(v.into_iter().collect())))
+                .collect(),
+        );
+        let tweezer_two_qubit_gate_errors: Vec<(String, TwoTweezersTimes)> = sorted_by_key(
+            info.tweezer_two_qubit_gate_errors
+                .into_iter()
+                .map(|(k, v)| (k, sorted_by_key(v.into_iter().collect())))
+                .collect(),
+        );
+        let tweezer_three_qubit_gate_errors: Vec<(String, ThreeTweezersTimes)> = sorted_by_key(
+            info.tweezer_three_qubit_gate_errors
+                .into_iter()
+                .map(|(k, v)| (k, sorted_by_key(v.into_iter().collect())))
+                .collect(),
+        );
+        let tweezer_zones: Vec<(usize, String)> =
+            sorted_by_key(info.tweezer_zones.into_iter().collect());
+        let tweezer_shift_times: TwoTweezersTimes =
+            sorted_by_key(info.tweezer_shift_times.into_iter().collect());
+        let shift_time_per_site = info.shift_time_per_site;
+        let controlled_phase_time_curves: TwoTweezersCurves =
+            sorted_by_key(info.controlled_phase_time_curves.into_iter().collect());
+        let mut blockaded_tweezer_pairs: Vec<(usize, usize)> =
+            info.blockaded_tweezer_pairs.into_iter().collect();
+        blockaded_tweezer_pairs.sort();
+        let blockade_radius = info.blockade_radius;
+        let global_rotation_time = info.global_rotation_time;
+        let layout_switch_time = info.layout_switch_time;
 
         Self {
             tweezer_single_qubit_gate_times,
@@ -206,328 +888,3013 @@ impl From<TweezerLayoutInfo> for TweezerLayoutInfoSerialize {
             tweezer_multi_qubit_gate_times,
             allowed_tweezer_shifts,
             tweezers_per_row,
+            tweezer_coordinates,
+            tweezer_single_qubit_gate_errors,
+            tweezer_two_qubit_gate_errors,
+            tweezer_three_qubit_gate_errors,
+            tweezer_zones,
+            tweezer_shift_times,
+            shift_time_per_site,
+            controlled_phase_time_curves,
+            blockaded_tweezer_pairs,
+            blockade_radius,
+            global_rotation_time,
+            layout_switch_time,
         }
     }
 }
 
-impl TweezerDevice {
-    /// Creates a new TweezerDevice instance.
-    ///
-    /// # Arguments
-    ///
-    /// * `seed` - Optional seed, for simulation purposes.
-    /// * `controlled_z_phase_relation` - The relation to use for the PhaseShiftedControlledZ gate.
-    ///                                   It can be hardcoded to a specific value if a float is passed in as String.
-    /// * `controlled_phase_phase_relation` - The relation to use for the PhaseShiftedControlledPhase gate.
-    ///
-    /// # Returns
-    ///
-    /// * `TweezerDevice` - The new TweezerDevice instance.
-    pub fn new(
-        seed: Option<usize>,
-        controlled_z_phase_relation: Option<String>,
-        controlled_phase_phase_relation: Option<String>,
-    ) -> Self {
-        let layout_register: Option<HashMap<String, TweezerLayoutInfo>> = Some(HashMap::new());
-        let controlled_z_phase_relation =
-            controlled_z_phase_relation.unwrap_or_else(|| "DefaultRelation".to_string());
-        let controlled_phase_phase_relation =
-            controlled_phase_phase_relation.unwrap_or_else(|| "DefaultRelation".to_string());
+/// Returns whether two tweezers of a Layout can not be driven simultaneously.
+///
+/// A tweezer is always blockaded with itself. Two different tweezers are blockaded if the pair has
+/// been added explicitly or if both have coordinates within the blockade radius of the Layout.
+fn tweezers_blockaded(info: &TweezerLayoutInfo, tweezer0: usize, tweezer1: usize) -> bool {
+    if tweezer0 == tweezer1
+        || info
+            .blockaded_tweezer_pairs
+            .contains(&(tweezer0.min(tweezer1), tweezer0.max(tweezer1)))
+    {
+        return true;
+    }
+    match (
+        info.blockade_radius,
+        info.tweezer_coordinates.get(&tweezer0),
+        info.tweezer_coordinates.get(&tweezer1),
+    ) {
+        (Some(radius), Some((x0, y0)), Some((x1, y1))) => (x0 - x1).hypot(y0 - y1) <= radius,
+        _ => false,
+    }
+}
 
-        TweezerDevice {
-            qubit_to_tweezer: None,
-            layout_register,
-            current_layout: None,
-            controlled_z_phase_relation,
-            controlled_phase_phase_relation,
-            default_layout: None,
-            seed,
-            allow_reset: false,
-            device_name: String::from("qryd_tweezer_device"),
-            available_gates: None,
+/// Compiles a typst document and exports it as an SVG or PDF file.
+#[cfg(feature = "vector-drawing")]
+fn export_typst_vector(
+    typst_str: String,
+    format: DrawFormat,
+) -> Result<Vec<u8>, RoqoqoBackendError> {
+    let backend = TypstBackend::new(typst_str).map_err(|err| RoqoqoBackendError::GenericError {
+        msg: format!("Error during the compilation of the drawing: {}", err),
+    })?;
+    let mut tracer = Tracer::new();
+    let document = typst::compile(&backend, &mut tracer).map_err(|errors| {
+        RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Error during the compilation of the drawing: {}",
+                errors.iter().map(|error| error.message.as_str()).join(", ")
+            ),
         }
+    })?;
+    match format {
+        DrawFormat::Svg => Ok(typst_svg::svg_merged(&document, Abs::zero()).into_bytes()),
+        DrawFormat::Pdf => Ok(typst_pdf::pdf(&document, Smart::Auto, None)),
+        DrawFormat::Png => Err(RoqoqoBackendError::GenericError {
+            msg: "PNG is not a vector graphics format.".to_string(),
+        }),
     }
+}
 
-    /// Creates a new TweezerDevice instance containing populated tweezer data.
-    ///
-    /// This requires a valid QRYD_API_TOKEN. Visit `https://thequantumlaend.de/get-access/` to get one.
-    ///
-    /// # Arguments
-    ///
-    /// * `device_name` - The name of the device to instantiate. Defaults to "qryd_emulator".
-    /// * `access_token` - An access_token is required to access QRYD hardware and emulators.
-    ///                    The access_token can either be given as an argument here
-    ///                         or set via the environmental variable `$QRYD_API_TOKEN`.
-    /// * `mock_port` - The address of the Mock server, used for testing purposes.
-    /// * `seed` - Optionally overwrite seed value from downloaded device instance.
-    /// * `dev` - The boolean to set the dev header to.
-    /// * `api_version` - The version of the QRYD API to use. Defaults to "v1_1".
-    ///
-    /// # Returns
-    ///
-    /// * `TweezerDevice` - The new TweezerDevice instance with populated tweezer data.
-    ///
-    /// # Errors
-    ///
-    /// * `RoqoqoBackendError`
-    #[cfg(feature = "web-api")]
-    pub fn from_api(
-        device_name: Option<String>,
-        access_token: Option<String>,
-        mock_port: Option<String>,
-        seed: Option<usize>,
-        dev: Option<bool>,
-        api_version: Option<String>,
-    ) -> Result<Self, RoqoqoBackendError> {
-        // Preparing variables
-        let device_name_internal = device_name.unwrap_or_else(|| String::from("qryd_emulator"));
-        let api_version = api_version.unwrap_or_else(|| String::from("v1_1"));
-        let dev = dev.unwrap_or(false);
-        let hqs_env_var = env::var("QRYD_API_HQS").is_ok();
-        let access_token_internal: String = if mock_port.is_some() {
-            "".to_string()
-        } else {
-            match access_token {
-                Some(s) => s,
-                None => env::var("QRYD_API_TOKEN").map_err(|_| {
-                    RoqoqoBackendError::MissingAuthentication {
-                        msg: "QRYD access token is missing.".to_string(),
-                    }
-                })?,
-            }
-        };
+/// SVG and PDF output is only available with the `vector-drawing` feature.
+#[cfg(not(feature = "vector-drawing"))]
+fn export_typst_vector(
+    _typst_str: String,
+    format: DrawFormat,
+) -> Result<Vec<u8>, RoqoqoBackendError> {
+    Err(RoqoqoBackendError::GenericError {
+        msg: format!(
+            "Drawing in the {:?} format requires the vector-drawing feature of roqoqo-qryd.",
+            format
+        ),
+    })
+}
 
-        // Client setup
-        let client = if mock_port.is_some() {
-            reqwest::blocking::Client::builder().build().map_err(|x| {
-                RoqoqoBackendError::NetworkError {
-                    msg: format!("Could not create test client {:?}.", x),
+/// Returns the pixels per point of a drawing, derived from the DPI of the options if it is set.
+fn drawing_pixels_per_point(
+    pixels_per_point: Option<f32>,
+    options: &DrawOptions,
+) -> Result<Option<f32>, RoqoqoBackendError> {
+    // Typst measures in points of 1/72 inch
+    match options.dpi {
+        Some(dpi) if dpi.is_finite() && dpi > 0.0 => Ok(Some(dpi / 72.0)),
+        Some(dpi) => Err(RoqoqoBackendError::GenericError {
+            msg: format!("The DPI needs to be positive, got {}.", dpi),
+        }),
+        None => Ok(pixels_per_point),
+    }
+}
+
+/// Saves a drawing, as a vector graphic for `.svg` and `.pdf` paths and as an image otherwise.
+fn save_drawing(
+    image: &DynamicImage,
+    typst_str: String,
+    file_path: &str,
+) -> Result<(), RoqoqoBackendError> {
+    match DrawFormat::from_path(file_path) {
+        Some(format @ (DrawFormat::Svg | DrawFormat::Pdf)) => {
+            let bytes = export_typst_vector(typst_str, format)?;
+            std::fs::write(file_path, bytes).map_err(|x| RoqoqoBackendError::GenericError {
+                msg: format!("Error during image saving: {x:?}"),
+            })
+        }
+        _ => image
+            .save(file_path)
+            .map_err(|x| RoqoqoBackendError::GenericError {
+                msg: format!("Error during image saving: {x:?}"),
+            }),
+    }
+}
+
+/// Encodes drawings as an animated GIF repeating indefinitely.
+///
+/// Frames smaller than the largest frame are padded with a white background.
+fn frames_to_gif(
+    frames: &[DynamicImage],
+    frame_delay_ms: u32,
+) -> Result<Vec<u8>, RoqoqoBackendError> {
+    let gif_error = |x: image::ImageError| RoqoqoBackendError::GenericError {
+        msg: format!("Error during the generation of the GIF file: {x:?}"),
+    };
+    let width = frames.iter().map(DynamicImage::width).max().unwrap_or(0);
+    let height = frames.iter().map(DynamicImage::height).max().unwrap_or(0);
+    let mut buffer: Vec<u8> = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut buffer);
+        encoder.set_repeat(Repeat::Infinite).map_err(gif_error)?;
+        for frame in frames {
+            let mut canvas = RgbaImage::from_pixel(width, height, Rgba([255, 255, 255, 255]));
+            image::imageops::overlay(&mut canvas, &frame.to_rgba8(), 0, 0);
+            encoder
+                .encode_frame(Frame::from_parts(
+                    canvas,
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(frame_delay_ms, 1),
+                ))
+                .map_err(gif_error)?;
+        }
+    }
+    Ok(buffer)
+}
+
+/// Returns the gate time for theta, interpolated linearly between the samples of a sorted curve.
+///
+/// Theta is wrapped into [-pi, pi] and its absolute value used, outside of the sampled range
+/// the gate time of the closest sample is returned.
+fn interpolate_time_curve(curve: &[(f64, f64)], theta: f64) -> f64 {
+    let theta = ((theta + std::f64::consts::PI).rem_euclid(2.0 * std::f64::consts::PI)
+        - std::f64::consts::PI)
+        .abs();
+    let upper = curve.partition_point(|(sample_theta, _)| *sample_theta < theta);
+    if upper == 0 {
+        return curve[0].1;
+    }
+    if upper == curve.len() {
+        return curve[curve.len() - 1].1;
+    }
+    let (theta0, time0) = curve[upper - 1];
+    let (theta1, time1) = curve[upper];
+    time0 + (time1 - time0) * (theta - theta0) / (theta1 - theta0)
+}
+
+/// Returns the number of tweezers passed by an allowed shift, None if the shift is not allowed.
+///
+/// A qubit shifted into the n-th tweezer of a shift direction passes n tweezers. If the end
+/// tweezer appears in several directions, the shortest one is used.
+fn shift_sites(
+    allowed_tweezer_shifts: &HashMap<usize, Vec<Vec<usize>>>,
+    start: usize,
+    end: usize,
+) -> Option<usize> {
+    allowed_tweezer_shifts
+        .get(&start)?
+        .iter()
+        .filter_map(|direction| direction.iter().position(|tweezer| *tweezer == end))
+        .min()
+        .map(|position| position + 1)
+}
+
+/// Returns the tweezers with a gate time in a Layout.
+pub(crate) fn layout_tweezers(info: &TweezerLayoutInfo) -> BTreeSet<usize> {
+    let mut tweezers: BTreeSet<usize> = BTreeSet::new();
+    for times in info.tweezer_single_qubit_gate_times.values() {
+        tweezers.extend(times.keys());
+    }
+    for times in info.tweezer_two_qubit_gate_times.values() {
+        tweezers.extend(
+            times
+                .keys()
+                .flat_map(|(tweezer0, tweezer1)| [*tweezer0, *tweezer1]),
+        );
+    }
+    for times in info.tweezer_three_qubit_gate_times.values() {
+        tweezers.extend(
+            times
+                .keys()
+                .flat_map(|(tweezer0, tweezer1, tweezer2)| [*tweezer0, *tweezer1, *tweezer2]),
+        );
+    }
+    for times in info.tweezer_multi_qubit_gate_times.values() {
+        tweezers.extend(times.keys().flatten());
+    }
+    tweezers
+}
+
+/// Largest difference of the y coordinates of tweezers in the same row.
+const ROW_COORDINATE_TOLERANCE: f64 = 1e-9;
+
+/// Returns the tweezers per row of a Layout, inferred if they are not set.
+pub(crate) fn layout_tweezers_per_row(info: &TweezerLayoutInfo) -> Option<Vec<usize>> {
+    info.tweezers_per_row
+        .clone()
+        .or_else(|| inferred_tweezers_per_row(info))
+}
+
+/// Infers the tweezers per row of a Layout.
+///
+/// If all tweezers have coordinates, the rows are the tweezers with the same y coordinate,
+/// ordered by increasing y. Otherwise, if every tweezer can be shifted or shifted into, the rows
+/// are the groups of tweezers connected by shifts, ordered by their smallest tweezer.
+fn inferred_tweezers_per_row(info: &TweezerLayoutInfo) -> Option<Vec<usize>> {
+    let tweezers = layout_tweezers(info);
+    if tweezers.is_empty() {
+        return None;
+    }
+    if tweezers
+        .iter()
+        .all(|tweezer| info.tweezer_coordinates.contains_key(tweezer))
+    {
+        let mut rows: Vec<usize> = Vec::new();
+        let mut row_y = f64::NEG_INFINITY;
+        for y in tweezers
+            .iter()
+            .map(|tweezer| info.tweezer_coordinates[tweezer].1)
+            .sorted_by(f64::total_cmp)
+        {
+            match rows.last_mut() {
+                Some(row) if y - row_y <= ROW_COORDINATE_TOLERANCE => *row += 1,
+                _ => {
+                    rows.push(1);
+                    row_y = y;
                 }
-            })?
-        } else {
-            reqwest::blocking::Client::builder()
-                .https_only(true)
-                .build()
-                .map_err(|x| RoqoqoBackendError::NetworkError {
-                    msg: format!("Could not create https client {:?}.", x),
-                })?
-        };
+            }
+        }
+        return Some(rows);
+    }
 
-        // Response gathering
-        let resp = if let Some(port) = mock_port {
-            client
-                .get(format!("http://127.0.0.1:{}", port))
-                .body(device_name_internal.clone())
-                .send()
-                .map_err(|e| RoqoqoBackendError::NetworkError {
-                    msg: format!("{:?}", e),
-                })?
-        } else {
-            match (dev, hqs_env_var) {
-                (true, true) => client
-                    .get(format!(
-                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/devices/{}",
-                        api_version, device_name_internal
-                    ))
-                    .header("X-API-KEY", access_token_internal)
-                    .header("X-DEV", "?1")
-                    .header("X-HQS", "?1")
-                    .send()
-                    .map_err(|e| RoqoqoBackendError::NetworkError {
-                        msg: format!("{:?}", e),
-                    })?,
-                (true, false) => client
-                    .get(format!(
-                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/devices/{}",
-                        api_version, device_name_internal
-                    ))
-                    .header("X-API-KEY", access_token_internal)
-                    .header("X-DEV", "?1")
-                    .send()
-                    .map_err(|e| RoqoqoBackendError::NetworkError {
-                        msg: format!("{:?}", e),
-                    })?,
-                (false, true) => client
-                    .get(format!(
-                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/devices/{}",
-                        api_version, device_name_internal
-                    ))
-                    .header("X-API-KEY", access_token_internal)
-                    .header("X-HQS", "?1")
-                    .send()
-                    .map_err(|e| RoqoqoBackendError::NetworkError {
-                        msg: format!("{:?}", e),
-                    })?,
-                (false, false) => client
-                    .get(format!(
-                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/devices/{}",
-                        api_version, device_name_internal
-                    ))
-                    .header("X-API-KEY", access_token_internal)
-                    .send()
-                    .map_err(|e| RoqoqoBackendError::NetworkError {
-                        msg: format!("{:?}", e),
-                    })?,
+    let mut neighbours: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (start, directions) in info.allowed_tweezer_shifts.iter() {
+        for target in directions.iter().flatten() {
+            if tweezers.contains(start) && tweezers.contains(target) {
+                neighbours.entry(*start).or_default().push(*target);
+                neighbours.entry(*target).or_default().push(*start);
             }
-        };
+        }
+    }
+    if tweezers
+        .iter()
+        .any(|tweezer| !neighbours.contains_key(tweezer))
+    {
+        return None;
+    }
+    let mut visited: HashSet<usize> = HashSet::new();
+    let mut rows: Vec<usize> = Vec::new();
+    for tweezer in tweezers.iter() {
+        if !visited.insert(*tweezer) {
+            continue;
+        }
+        let mut row_size = 0;
+        let mut stack = vec![*tweezer];
+        while let Some(current) = stack.pop() {
+            row_size += 1;
+            for neighbour in neighbours[&current].iter() {
+                if visited.insert(*neighbour) {
+                    stack.push(*neighbour);
+                }
+            }
+        }
+        rows.push(row_size);
+    }
+    Some(rows)
+}
 
-        // Response handling
-        let status_code = resp.status();
-        if status_code == reqwest::StatusCode::OK {
-            let mut device = resp.json::<TweezerDevice>().unwrap();
-            if let Some(default) = device.default_layout.clone() {
-                device.switch_layout(&default, None).unwrap();
+/// Removes the entry of a gate, and the gate itself if it has no entries left.
+fn unset_gate_entry<K: Eq + Hash>(
+    gate_map: &mut HashMap<String, HashMap<K, f64>>,
+    hqslang: &str,
+    key: &K,
+) -> Option<f64> {
+    let entries = gate_map.get_mut(hqslang)?;
+    let removed = entries.remove(key);
+    if entries.is_empty() {
+        gate_map.remove(hqslang);
+    }
+    removed
+}
+
+/// Checks that a gate is one of the allowed native gates of its kind.
+///
+/// # Arguments
+///
+/// * `hqslang` - The hqslang name of the gate.
+/// * `allowed_gates` - The native gates of the kind of the gate.
+/// * `number_qubits` - The number of qubits of the kind in words, e.g. "single", for the error message.
+/// * `quantity` - The quantity that is set for the gate, e.g. "gate time", for the error message.
+fn check_gate_name(
+    hqslang: &str,
+    allowed_gates: &[String],
+    number_qubits: &str,
+    quantity: &str,
+) -> Result<(), RoqoqoBackendError> {
+    if allowed_gates.iter().any(|gate| gate == hqslang) {
+        return Ok(());
+    }
+    Err(RoqoqoBackendError::GenericError {
+        msg: format!(
+            "Error setting the {} of a {}-qubit gate. Gate {} is not supported.",
+            quantity, number_qubits, hqslang
+        ),
+    })
+}
+
+/// Checks that a gate error rate is a probability.
+fn check_gate_error(error: f64) -> Result<(), RoqoqoBackendError> {
+    if error.is_nan() || !(0.0..=1.0).contains(&error) {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "The gate error rate needs to be between 0 and 1, got {}.",
+                error
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that an atom loss probability is a probability.
+fn check_loss_probability(probability: f64) -> Result<(), RoqoqoBackendError> {
+    if probability.is_nan() || !(0.0..=1.0).contains(&probability) {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: format!(
+                "The atom loss probability needs to be between 0 and 1, got {}.",
+                probability
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Position of a tweezer in a square lattice with unit spacing.
+pub(crate) fn square_lattice_position(row: usize, column: usize) -> (f64, f64) {
+    (column as f64, row as f64)
+}
+
+/// Position of a tweezer in a triangular lattice with unit spacing, every second row is offset.
+pub(crate) fn triangular_lattice_position(row: usize, column: usize) -> (f64, f64) {
+    (
+        column as f64 + 0.5 * (row % 2) as f64,
+        row as f64 * 3.0_f64.sqrt() / 2.0,
+    )
+}
+
+/// Sorts a list of (key, value) pairs by the keys.
+fn sorted_by_key<K: Ord, V>(mut entries: Vec<(K, V)>) -> Vec<(K, V)> {
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
+}
+
+impl TweezerDevice {
+    /// Creates a new TweezerDevice instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Optional seed, for simulation purposes.
+    /// * `controlled_z_phase_relation` - The relation to use for the PhaseShiftedControlledZ gate.
+    ///                                   It can be hardcoded to a specific value if a float is passed in as String.
+    /// * `controlled_phase_phase_relation` - The relation to use for the PhaseShiftedControlledPhase gate.
+    ///
+    /// # Returns
+    ///
+    /// * `TweezerDevice` - The new TweezerDevice instance.
+    pub fn new(
+        seed: Option<usize>,
+        controlled_z_phase_relation: Option<String>,
+        controlled_phase_phase_relation: Option<String>,
+    ) -> Self {
+        let layout_register: Option<HashMap<String, TweezerLayoutInfo>> = Some(HashMap::new());
+        let controlled_z_phase_relation =
+            controlled_z_phase_relation.unwrap_or_else(|| "DefaultRelation".to_string());
+        let controlled_phase_phase_relation =
+            controlled_phase_phase_relation.unwrap_or_else(|| "DefaultRelation".to_string());
+
+        TweezerDevice {
+            qubit_to_tweezer: None,
+            layout_register,
+            current_layout: None,
+            controlled_z_phase_relation,
+            controlled_phase_phase_relation,
+            default_layout: None,
+            seed,
+            allow_reset: false,
+            symmetric_two_qubit_gate_times: false,
+            native_gates: NativeGates::default(),
+            max_qubits: None,
+            device_name: String::from("qryd_tweezer_device"),
+            available_gates: None,
+            allowed_edges: None,
+            emulator_noise: None,
+            tweezer_noise: HashMap::new(),
+            array_preparation: ArrayPreparation::default(),
+            atom_loss: AtomLoss::default(),
+            phi_theta_relations: HashMap::new(),
+            gate_time_cache: GateTimeCache::default(),
+            dense_gate_times: DenseGateTimes::default(),
+            serialized_layouts: HashMap::new(),
+        }
+    }
+
+    /// Creates a new TweezerDevice with a square lattice of tweezers.
+    ///
+    /// The device contains the Layout "default", which is set as the current and the default
+    /// Layout. The tweezers are numbered row by row and placed at the coordinates (column, row).
+    /// Single-qubit gates are available on all tweezers, two-qubit gates between horizontally and
+    /// vertically neighbouring tweezers. Qubits can be shifted along the rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows of the lattice.
+    /// * `columns` - The number of tweezers per row.
+    /// * `gate_times` - The hqslang names of the single- and two-qubit gates and their gate times.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TweezerDevice)` - The device with the lattice Layout.
+    /// * `Err(RoqoqoBackendError)` - The lattice is empty, no single-qubit gate is given or a gate is not supported.
+    pub fn square_lattice(
+        rows: usize,
+        columns: usize,
+        gate_times: &HashMap<String, f64>,
+    ) -> Result<Self, RoqoqoBackendError> {
+        Self::lattice(
+            rows,
+            columns,
+            NativeGates::default(),
+            gate_times,
+            square_lattice_position,
+        )
+    }
+
+    /// Creates a new TweezerDevice with a triangular lattice of tweezers.
+    ///
+    /// The device contains the Layout "default", which is set as the current and the default
+    /// Layout. The tweezers are numbered row by row, every second row is offset by half a
+    /// tweezer spacing so that each tweezer has up to six neighbours at the same distance.
+    /// Single-qubit gates are available on all tweezers, two-qubit gates between neighbouring
+    /// tweezers. Qubits can be shifted along the rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows of the lattice.
+    /// * `columns` - The number of tweezers per row.
+    /// * `gate_times` - The hqslang names of the single- and two-qubit gates and their gate times.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TweezerDevice)` - The device with the lattice Layout.
+    /// * `Err(RoqoqoBackendError)` - The lattice is empty, no single-qubit gate is given or a gate is not supported.
+    pub fn triangular_lattice(
+        rows: usize,
+        columns: usize,
+        gate_times: &HashMap<String, f64>,
+    ) -> Result<Self, RoqoqoBackendError> {
+        Self::lattice(
+            rows,
+            columns,
+            NativeGates::default(),
+            gate_times,
+            triangular_lattice_position,
+        )
+    }
+
+    /// Creates a device with a lattice Layout, the neighbouring tweezers are one unit apart.
+    pub(crate) fn lattice<F>(
+        rows: usize,
+        columns: usize,
+        native_gates: NativeGates,
+        gate_times: &HashMap<String, f64>,
+        position: F,
+    ) -> Result<Self, RoqoqoBackendError>
+    where
+        F: Fn(usize, usize) -> (f64, f64),
+    {
+        if rows == 0 || columns == 0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "A lattice needs at least one row and one column, got {} rows and {} columns.",
+                    rows, columns
+                ),
+            });
+        }
+        if !gate_times
+            .keys()
+            .any(|gate| native_gates.single_qubit_gates.contains(gate))
+        {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "The gate times need to contain at least one single-qubit gate.".to_string(),
+            });
+        }
+        let layout_name = Some("default".to_string());
+        let mut device = TweezerDevice::new(None, None, None);
+        device.set_native_gates(native_gates)?;
+        device.add_layout("default")?;
+        for (row, column) in iproduct!(0..rows, 0..columns) {
+            let (x, y) = position(row, column);
+            device.set_tweezer_coordinates(row * columns + column, x, y, layout_name.clone())?;
+        }
+        for (gate, gate_time) in gate_times.iter().sorted_by(|a, b| a.0.cmp(b.0)) {
+            if device.native_gates.single_qubit_gates.contains(gate) {
+                for tweezer in 0..rows * columns {
+                    device.set_tweezer_single_qubit_gate_time(
+                        gate,
+                        tweezer,
+                        *gate_time,
+                        layout_name.clone(),
+                    )?;
+                }
+            } else if device.native_gates.two_qubit_gates.contains(gate) {
+                // Small tolerance for the rounding of the triangular lattice coordinates
+                device.set_two_qubit_gate_times_from_distance(
+                    gate,
+                    1.0 + 1e-9,
+                    |_| *gate_time,
+                    layout_name.clone(),
+                )?;
+            } else {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Gate {} is not a supported single- or two-qubit gate of a lattice.",
+                        gate
+                    ),
+                });
             }
-            if let Some(new_seed) = seed {
-                device.seed = Some(new_seed);
+        }
+        device.set_tweezers_per_row(vec![columns; rows], layout_name.clone())?;
+        let row_shifts: Vec<Vec<usize>> = (0..rows)
+            .map(|row| (row * columns..(row + 1) * columns).collect())
+            .collect();
+        device.set_allowed_tweezer_shifts_from_rows(
+            &row_shifts
+                .iter()
+                .map(Vec::as_slice)
+                .collect::<Vec<&[usize]>>(),
+            layout_name,
+        )?;
+        device.set_default_layout("default")?;
+        Ok(device)
+    }
+
+    /// Creates a new TweezerDevice from a [FirstDevice] of the deprecated `qryd_devices` module.
+    ///
+    /// The current layout of the FirstDevice becomes the Layout "default", which is set as the
+    /// current and the default Layout. The tweezers are numbered row by row and placed at the
+    /// positions of the FirstDevice. The gate times are the ones of the FirstDevice: single-qubit
+    /// gates on all tweezers, two- and three-qubit gates between tweezers within the cutoff and
+    /// MultiQubitZZ on three or more tweezers of a row. Qubits can be shifted along the rows and
+    /// are mapped to the tweezers of their positions in the FirstDevice.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The FirstDevice to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TweezerDevice)` - The converted device.
+    /// * `Err(RoqoqoBackendError)` - The FirstDevice has no tweezers.
+    pub fn from_first_device(device: &FirstDevice) -> Result<Self, RoqoqoBackendError> {
+        let columns_per_row = device.columns_per_row();
+        let rows: Vec<Vec<(usize, usize)>> = columns_per_row
+            .iter()
+            .enumerate()
+            .map(|(row, columns)| (0..*columns).map(|column| (row, column)).collect())
+            .collect();
+        let positions: Vec<(usize, usize)> = rows.iter().flatten().copied().collect();
+        if positions.is_empty() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "The FirstDevice has no tweezers.".to_string(),
+            });
+        }
+        let tweezer_of: HashMap<(usize, usize), usize> = positions
+            .iter()
+            .enumerate()
+            .map(|(tweezer, position)| (*position, tweezer))
+            .collect();
+
+        let native_gates = NativeGates {
+            single_qubit_gates: AVAILABLE_GATES_HQSLANG
+                .iter()
+                .filter(|gate| first_device_single_qubit_gate_time(gate).is_some())
+                .map(|gate| gate.to_string())
+                .collect(),
+            two_qubit_gates: FIRST_DEVICE_TWO_QUBIT_GATES
+                .iter()
+                .map(|gate| gate.to_string())
+                .collect(),
+            three_qubit_gates: device
+                .available_three_qubit_gates()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            multi_qubit_gates: vec!["MultiQubitZZ".to_string()],
+        };
+        let layout_name = Some("default".to_string());
+        let mut tweezer_device = TweezerDevice::new(
+            None,
+            Some(device.controlled_z_phase_relation().to_string()),
+            Some(device.controlled_phase_phase_relation().to_string()),
+        );
+        tweezer_device.set_native_gates(native_gates.clone())?;
+        tweezer_device.add_layout("default")?;
+        for (tweezer, position) in positions.iter().enumerate() {
+            if let Some((x, y)) = device.tweezer_position(position) {
+                tweezer_device.set_tweezer_coordinates(tweezer, x, y, layout_name.clone())?;
+            }
+            for gate in native_gates.single_qubit_gates.iter() {
+                if let Some(gate_time) = first_device_single_qubit_gate_time(gate) {
+                    tweezer_device.set_tweezer_single_qubit_gate_time(
+                        gate,
+                        tweezer,
+                        gate_time,
+                        layout_name.clone(),
+                    )?;
+                }
+            }
+        }
+        for gate in native_gates.two_qubit_gates.iter() {
+            for ((tweezer0, position0), (tweezer1, position1)) in
+                iproduct!(positions.iter().enumerate(), positions.iter().enumerate())
+            {
+                if tweezer0 == tweezer1 {
+                    continue;
+                }
+                if let Some(gate_time) =
+                    device.tweezer_two_qubit_gate_time(gate, position0, position1)
+                {
+                    tweezer_device.set_tweezer_two_qubit_gate_time(
+                        gate,
+                        tweezer0,
+                        tweezer1,
+                        gate_time,
+                        layout_name.clone(),
+                    )?;
+                }
+            }
+        }
+        for gate in native_gates.three_qubit_gates.iter() {
+            for trio in positions.iter().permutations(3) {
+                if let Some(gate_time) =
+                    device.tweezer_three_qubit_gate_time(gate, trio[0], trio[1], trio[2])
+                {
+                    tweezer_device.set_tweezer_three_qubit_gate_time(
+                        gate,
+                        tweezer_of[trio[0]],
+                        tweezer_of[trio[1]],
+                        tweezer_of[trio[2]],
+                        gate_time,
+                        layout_name.clone(),
+                    )?;
+                }
+            }
+        }
+        let row_tweezers: Vec<Vec<usize>> = rows
+            .iter()
+            .map(|row| row.iter().map(|position| tweezer_of[position]).collect())
+            .collect();
+        for tweezers in row_tweezers.iter() {
+            for combination_length in 3..=tweezers.len() {
+                for combination in tweezers.iter().copied().combinations(combination_length) {
+                    tweezer_device.set_tweezer_multi_qubit_gate_time(
+                        "MultiQubitZZ",
+                        &combination,
+                        2e-5,
+                        layout_name.clone(),
+                    )?;
+                }
+            }
+        }
+        tweezer_device.set_tweezers_per_row(columns_per_row, layout_name.clone())?;
+        tweezer_device.set_allowed_tweezer_shifts_from_rows(
+            &row_tweezers
+                .iter()
+                .map(Vec::as_slice)
+                .collect::<Vec<&[usize]>>(),
+            layout_name,
+        )?;
+        tweezer_device.set_default_layout("default")?;
+        let qubit_to_tweezer: HashMap<usize, usize> = device
+            .qubit_positions()
+            .iter()
+            .filter_map(|(qubit, position)| {
+                tweezer_of.get(position).map(|tweezer| (*qubit, *tweezer))
+            })
+            .collect();
+        tweezer_device.switch_layout_with_mapping("default", qubit_to_tweezer)?;
+        Ok(tweezer_device)
+    }
+
+    /// Creates a new TweezerDevice from a calibration file.
+    ///
+    /// The calibration file is a JSON document, or a YAML document if the file extension is
+    /// `.yaml` or `.yml`, containing the gate times, gate fidelities, allowed shifts, tweezer
+    /// coordinates and tweezers per row of each Layout. The format is documented in the
+    /// `calibration` module. Every Layout of the file is added to the device, the default
+    /// Layout is set as the current Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the calibration file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TweezerDevice)` - The device described by the calibration file.
+    /// * `Err(RoqoqoBackendError)` - The file could not be read or parsed, or a field is invalid.
+    ///                               The error message names the invalid field.
+    pub fn from_calibration_file<P: AsRef<Path>>(path: P) -> Result<Self, RoqoqoBackendError> {
+        device_from_calibration_file(path.as_ref())
+    }
+
+    /// Loads a TweezerDevice from a file written by [TweezerDevice::to_file].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, the format is inferred from its extension.
+    /// * `format` - The format of the file, overrides the format inferred from the extension.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TweezerDevice)` - The device stored in the file.
+    /// * `Err(RoqoqoBackendError)` - The format could not be inferred or the file could not be read or deserialized.
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        format: Option<DeviceFileFormat>,
+    ) -> Result<Self, RoqoqoBackendError> {
+        let path = path.as_ref();
+        read_device_file(path, resolve_file_format(path, format)?)
+    }
+
+    /// Saves the TweezerDevice to a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, the format is inferred from its extension.
+    /// * `format` - The format of the file, overrides the format inferred from the extension.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The device has been saved.
+    /// * `Err(RoqoqoBackendError)` - The format could not be inferred or the device could not be serialized or written.
+    pub fn to_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        format: Option<DeviceFileFormat>,
+    ) -> Result<(), RoqoqoBackendError> {
+        let path = path.as_ref();
+        write_device_file(self, path, resolve_file_format(path, format)?)
+    }
+
+    /// Creates a new TweezerDevice instance containing populated tweezer data.
+    ///
+    /// This requires a valid QRYD_API_TOKEN. Visit `https://thequantumlaend.de/get-access/` to get one.
+    ///
+    /// # Arguments
+    ///
+    /// * `device_name` - The name of the device to instantiate. Defaults to "qryd_emulator".
+    /// * `access_token` - An access_token is required to access QRYD hardware and emulators.
+    ///                    The access_token can either be given as an argument here
+    ///                         or set via the environmental variable `$QRYD_API_TOKEN`.
+    /// * `mock_port` - The address of the Mock server, used for testing purposes.
+    /// * `seed` - Optionally overwrite seed value from downloaded device instance.
+    /// * `dev` - The boolean to set the dev header to.
+    /// * `api_version` - The version of the QRYD API to use. Defaults to "v1_1".
+    ///
+    /// # Returns
+    ///
+    /// * `TweezerDevice` - The new TweezerDevice instance with populated tweezer data.
+    ///                     Layouts other than the current and the default Layout are kept
+    ///                     serialized and only deserialized when they are first accessed.
+    ///
+    /// # Errors
+    ///
+    /// * `RoqoqoBackendError`
+    #[cfg(feature = "web-api")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "qryd_tweezer_device_from_api",
+            skip_all,
+            fields(device_name = ?device_name, api_version = ?api_version, status = tracing::field::Empty)
+        )
+    )]
+    pub fn from_api(
+        device_name: Option<String>,
+        access_token: Option<String>,
+        mock_port: Option<String>,
+        seed: Option<usize>,
+        dev: Option<bool>,
+        api_version: Option<String>,
+    ) -> Result<Self, RoqoqoBackendError> {
+        // Preparing variables
+        let device_name_internal = device_name.unwrap_or_else(|| String::from("qryd_emulator"));
+        let api_version = api_version.unwrap_or_else(|| String::from("v1_1"));
+        let dev = dev.unwrap_or(false);
+        let hqs_env_var = env::var("QRYD_API_HQS").is_ok();
+        let access_token_internal: String = if mock_port.is_some() {
+            "".to_string()
+        } else {
+            match access_token {
+                Some(s) => s,
+                None => env::var("QRYD_API_TOKEN").map_err(|_| {
+                    RoqoqoBackendError::MissingAuthentication {
+                        msg: "QRYD access token is missing.".to_string(),
+                    }
+                })?,
+            }
+        };
+
+        // Client setup
+        let client = if mock_port.is_some() {
+            reqwest::blocking::Client::builder().build().map_err(|x| {
+                RoqoqoBackendError::NetworkError {
+                    msg: format!("Could not create test client {:?}.", x),
+                }
+            })?
+        } else {
+            reqwest::blocking::Client::builder()
+                .https_only(true)
+                .build()
+                .map_err(|x| RoqoqoBackendError::NetworkError {
+                    msg: format!("Could not create https client {:?}.", x),
+                })?
+        };
+
+        // Response gathering
+        let resp = if let Some(port) = mock_port {
+            client
+                .get(format!("http://127.0.0.1:{}", port))
+                .body(device_name_internal.clone())
+                .send()
+                .map_err(|e| RoqoqoBackendError::NetworkError {
+                    msg: format!("{:?}", e),
+                })?
+        } else {
+            match (dev, hqs_env_var) {
+                (true, true) => client
+                    .get(format!(
+                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/devices/{}",
+                        api_version, device_name_internal
+                    ))
+                    .header("X-API-KEY", access_token_internal)
+                    .header("X-DEV", "?1")
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (true, false) => client
+                    .get(format!(
+                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/devices/{}",
+                        api_version, device_name_internal
+                    ))
+                    .header("X-API-KEY", access_token_internal)
+                    .header("X-DEV", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, true) => client
+                    .get(format!(
+                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/devices/{}",
+                        api_version, device_name_internal
+                    ))
+                    .header("X-API-KEY", access_token_internal)
+                    .header("X-HQS", "?1")
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+                (false, false) => client
+                    .get(format!(
+                        "https://api.qryddemo.itp3.uni-stuttgart.de/{}/devices/{}",
+                        api_version, device_name_internal
+                    ))
+                    .header("X-API-KEY", access_token_internal)
+                    .send()
+                    .map_err(|e| RoqoqoBackendError::NetworkError {
+                        msg: format!("{:?}", e),
+                    })?,
+            }
+        };
+
+        // Response handling
+        let status_code = resp.status();
+        #[cfg(feature = "tracing")]
+        {
+            tracing::Span::current().record("status", status_code.as_u16());
+            tracing::debug!(
+                status = status_code.as_u16(),
+                "QRyd device request completed"
+            );
+        }
+        if status_code == reqwest::StatusCode::OK {
+            let mut device = resp
+                .text()
+                .map_err(|e| RoqoqoBackendError::NetworkError {
+                    msg: format!("{:?}", e),
+                })
+                .and_then(|text| TweezerDevice::from_api_json(&text))?;
+            if let Some(default) = device.default_layout.clone() {
+                device.switch_layout(&default, None).unwrap();
+            }
+            if let Some(new_seed) = seed {
+                device.seed = Some(new_seed);
+            }
+            device.device_name = device_name_internal;
+            Ok(device)
+        } else {
+            Err(RoqoqoBackendError::NetworkError {
+                msg: format!(
+                    "Request to server failed with HTTP status code {:?}.",
+                    status_code
+                ),
+            })
+        }
+    }
+
+    /// Adds a new empty Layout to the device's register.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the new Layout to be added to the register.
+    pub fn add_layout(&mut self, name: &str) -> Result<(), RoqoqoBackendError> {
+        if let Some(int_register) = &self.layout_register {
+            if int_register.contains_key(name) || self.serialized_layouts.contains_key(name) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Error adding layout to TweezerDevice. Layout name {} is already in use in the Layout register.",
+                        name,
+                    ),
+                });
+            }
+            self.layout_register
+                .as_mut()
+                .unwrap()
+                .insert(name.to_string(), TweezerLayoutInfo::default());
+        }
+        Ok(())
+    }
+
+    /// Removes a Layout from the device's register.
+    ///
+    /// The current and the default Layout can not be removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the Layout to be removed from the register.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The Layout has been removed.
+    /// * `Err(RoqoqoBackendError)` - The Layout is not set or is the current or the default Layout.
+    pub fn remove_layout(&mut self, name: &str) -> Result<(), RoqoqoBackendError> {
+        if self.current_layout.as_deref() == Some(name) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error removing layout from TweezerDevice. Layout {} is the current Layout.",
+                    name
+                ),
+            });
+        }
+        if self.default_layout.as_deref() == Some(name) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error removing layout from TweezerDevice. Layout {} is the default Layout.",
+                    name
+                ),
+            });
+        }
+        let removed = self.serialized_layouts.remove(name).is_some()
+            || self
+                .layout_register
+                .as_mut()
+                .and_then(|layout_register| layout_register.remove(name))
+                .is_some();
+        if !removed {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error removing layout from TweezerDevice. Layout {} is not set.",
+                    name
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Renames a Layout of the device's register.
+    ///
+    /// The current and the default Layout are updated if they refer to the renamed Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_name` - The name of the Layout to be renamed.
+    /// * `new_name` - The new name of the Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The Layout has been renamed.
+    /// * `Err(RoqoqoBackendError)` - The Layout is not set or the new name is already in use.
+    pub fn rename_layout(
+        &mut self,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<(), RoqoqoBackendError> {
+        if !self.available_layouts().contains(&old_name) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error renaming layout of TweezerDevice. Layout {} is not set.",
+                    old_name
+                ),
+            });
+        }
+        if old_name == new_name {
+            return Ok(());
+        }
+        if self.available_layouts().contains(&new_name) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error renaming layout of TweezerDevice. Layout name {} is already in use in the Layout register.",
+                    new_name
+                ),
+            });
+        }
+        if let Some(layout) = self.serialized_layouts.remove(old_name) {
+            self.serialized_layouts.insert(new_name.to_string(), layout);
+        } else if let Some(layout_register) = self.layout_register.as_mut() {
+            if let Some(info) = layout_register.remove(old_name) {
+                layout_register.insert(new_name.to_string(), info);
+            }
+        }
+        for layout in [&mut self.current_layout, &mut self.default_layout] {
+            if layout.as_deref() == Some(old_name) {
+                *layout = Some(new_name.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds a deep copy of a Layout to the device's register under a new name.
+    ///
+    /// The copy can be changed without affecting the source Layout, e.g. to derive a
+    /// recalibrated variant of a Layout by updating some of its gate times.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The name of the Layout to copy.
+    /// * `new_name` - The name of the new Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The Layout has been copied.
+    /// * `Err(RoqoqoBackendError)` - The source Layout is not set, could not be deserialized or the new name is already in use.
+    pub fn duplicate_layout(
+        &mut self,
+        source: &str,
+        new_name: &str,
+    ) -> Result<(), RoqoqoBackendError> {
+        if self.available_layouts().contains(&new_name) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error duplicating layout of TweezerDevice. Layout name {} is already in use in the Layout register.",
+                    new_name
+                ),
+            });
+        }
+        let info =
+            self.layout_info(source)?
+                .cloned()
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Error duplicating layout of TweezerDevice. Layout {} is not set.",
+                        source
+                    ),
+                })?;
+        self.layout_register
+            .get_or_insert_with(HashMap::new)
+            .insert(new_name.to_string(), info);
+        Ok(())
+    }
+
+    /// Switch to a different pre-defined Layout.
+    ///
+    /// It is updated only if the given Layout name is present in the device's
+    /// Layout register. If the qubit -> tweezer mapping is empty, it is
+    /// trivially populated by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the new Layout.
+    /// * `with_trivial_map` - Whether the qubit -> tweezer mapping should be trivially populated. Defaults to true.
+    pub fn switch_layout(
+        &mut self,
+        name: &str,
+        with_trivial_map: Option<bool>,
+    ) -> Result<(), RoqoqoBackendError> {
+        let policy = if with_trivial_map.unwrap_or(true) {
+            TrivialMappingPolicy::AllTweezers
+        } else {
+            TrivialMappingPolicy::NoMapping
+        };
+        self.switch_layout_with_policy(name, policy)
+    }
+
+    /// Switch to a different pre-defined Layout, populating the qubit -> tweezer mapping with a policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the new Layout.
+    /// * `policy` - The policy populating the qubit -> tweezer mapping in the new Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The Layout has been switched.
+    /// * `Err(RoqoqoBackendError)` - The Layout is not set.
+    pub fn switch_layout_with_policy(
+        &mut self,
+        name: &str,
+        policy: TrivialMappingPolicy,
+    ) -> Result<(), RoqoqoBackendError> {
+        self.invalidate_gate_time_lookups();
+        self.load_layout(name)?;
+        if let Some(int_register) = &self.layout_register {
+            if !int_register.keys().contains(&name.to_string()) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Error switching layout of TweezerDevice. Layout {} is not set.",
+                        name
+                    ),
+                });
+            }
+            self.current_layout = Some(name.to_string());
+            self.apply_mapping_policy(policy)?;
+        }
+        Ok(())
+    }
+
+    /// Switch to a different pre-defined Layout with an explicit qubit -> tweezer mapping.
+    ///
+    /// The mapping replaces the current qubit -> tweezer mapping. It is validated against the
+    /// new Layout before switching, the device is left unchanged if the validation fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the new Layout.
+    /// * `qubit_to_tweezer` - The qubit -> tweezer mapping to use in the new Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The Layout and the mapping have been set.
+    /// * `Err(RoqoqoBackendError)` - The Layout is not set, a tweezer of the mapping is not present
+    ///                               in the Layout or several qubits are mapped to the same tweezer.
+    pub fn switch_layout_with_mapping(
+        &mut self,
+        name: &str,
+        qubit_to_tweezer: HashMap<usize, usize>,
+    ) -> Result<(), RoqoqoBackendError> {
+        self.load_layout(name)?;
+        let info = self
+            .layout_info(name)?
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error switching layout of TweezerDevice. Layout {} is not set.",
+                    name
+                ),
+            })?;
+        let tweezers = layout_tweezers(info);
+        for (qubit, tweezer) in qubit_to_tweezer.iter().sorted_by_key(|(qubit, _)| **qubit) {
+            if !tweezers.contains(tweezer) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Qubit {} is mapped to tweezer {} which is not present in Layout {}.",
+                        qubit, tweezer, name
+                    ),
+                });
+            }
+        }
+        if let Some(tweezer) = qubit_to_tweezer.values().duplicates().min() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("Several qubits are mapped to tweezer {}.", tweezer),
+            });
+        }
+        self.invalidate_gate_time_lookups();
+        self.current_layout = Some(name.to_string());
+        self.qubit_to_tweezer = Some(qubit_to_tweezer);
+        Ok(())
+    }
+
+    /// Returns a vector of all available Layout names.
+    ///
+    /// # Returns:
+    ///
+    /// * `Vec<&str>` - The vector of all available Layout names.
+    pub fn available_layouts(&self) -> Vec<&str> {
+        if let Some(int_register) = &self.layout_register {
+            return int_register
+                .keys()
+                .chain(self.serialized_layouts.keys())
+                .collect_vec()
+                .iter()
+                .map(|x| x.as_str())
+                .collect();
+        }
+        vec![]
+    }
+
+    /// Deserializes all Layouts that have not been accessed yet.
+    ///
+    /// Devices created with [TweezerDevice::from_api] only deserialize the current and the
+    /// default Layout, the other Layouts are deserialized when they are first used. Loading
+    /// them upfront moves the deserialization out of the first accesses and drops the kept
+    /// JSON representations.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All Layouts are in the Layout register.
+    /// * `Err(RoqoqoBackendError)` - A Layout could not be deserialized.
+    pub fn load_layouts(&mut self) -> Result<(), RoqoqoBackendError> {
+        let names: Vec<String> = self.serialized_layouts.keys().cloned().collect();
+        for name in names {
+            self.load_layout(&name)?;
+        }
+        Ok(())
+    }
+
+    /// Applies a partial update of the device, e.g. a calibration delta served by the WebAPI.
+    ///
+    /// The update is a JSON patch (RFC 6902) against the JSON serialization of the device.
+    /// In the serialization the gate times of a Layout are lists of `[name, [[tweezers, time], ...]]`
+    /// entries sorted by the gate name and the tweezers, so that e.g.
+    /// `{"op": "replace", "path": "/layout_register/default/tweezer_single_qubit_gate_times/0/1/0/1", "value": 0.5}`
+    /// replaces the time of the first tweezer of the first single-qubit gate.
+    /// The device is only changed if the whole patch could be applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `json_patch` - The JSON patch, an array of operations.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The device has been updated.
+    /// * `Err(RoqoqoBackendError)` - The patch is invalid, could not be applied or results in an invalid device.
+    pub fn apply_update(&mut self, json_patch: &str) -> Result<(), RoqoqoBackendError> {
+        let patch: serde_json::Value =
+            serde_json::from_str(json_patch).map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("The JSON patch could not be parsed: {}", err),
+            })?;
+        let mut document =
+            serde_json::to_value(&*self).map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("The TweezerDevice could not be serialized: {}", err),
+            })?;
+        apply_json_patch(&mut document, &patch)?;
+        let mut updated: TweezerDevice =
+            serde_json::from_value(document).map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("The patched TweezerDevice is invalid: {}", err),
+            })?;
+        updated.set_gate_time_cache_capacity(self.gate_time_cache_capacity());
+        *self = updated;
+        Ok(())
+    }
+
+    /// Modifies the qubit -> tweezer mapping of the device.
+    ///
+    /// If a qubit -> tweezer mapping is already present, it is overwritten.
+    /// Returns an error in the the tweezer does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The index of the qubit.
+    /// * `tweezer` - The index of the tweezer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<usize,usize>)` - The updated qubit -> tweezer mapping.
+    /// * `Err(RoqoqoBackendError)` - The tweezer does not exist or the qubit exceeds the hardware capacity.
+    pub fn add_qubit_tweezer_mapping(
+        &mut self,
+        qubit: usize,
+        tweezer: usize,
+    ) -> Result<HashMap<usize, usize>, RoqoqoBackendError> {
+        self.invalidate_gate_time_lookups();
+        if !self.is_tweezer_present(tweezer, None) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "The given tweezer is not present in the device Tweezer data.".to_string(),
+            });
+        }
+        if let Some(max_qubits) = self.max_qubits.filter(|max_qubits| qubit >= *max_qubits) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Qubit {} exceeds the hardware capacity of the device of {} qubits.",
+                    qubit, max_qubits
+                ),
+            });
+        }
+        if let Some(map) = &mut self.qubit_to_tweezer {
+            // Remove the previous qubit present in the tweezer
+            if let Some(qubit_to_remove) =
+                map.iter()
+                    .find_map(|(&qbt, &twz)| if twz == tweezer { Some(qbt) } else { None })
+            {
+                map.remove(&qubit_to_remove);
+            }
+            map.insert(qubit, tweezer);
+        } else {
+            self.qubit_to_tweezer = Some(HashMap::from([(qubit, tweezer)]));
+        }
+        Ok(self
+            .qubit_to_tweezer
+            .as_ref()
+            .expect("Internal error: qubit_to_tweezer mapping supposed to be Some().")
+            .clone())
+    }
+
+    /// Set the time of a single-qubit gate for a tweezer in a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a single-qubit gate.
+    /// * `tweezer` - The index of the tweezer.
+    /// * `gate_time` - The the gate time for the given gate.
+    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
+    pub fn set_tweezer_single_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer: usize,
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        self.invalidate_gate_time_lookups();
+        check_gate_name(
+            hqslang,
+            &self.native_gates.single_qubit_gates,
+            "single",
+            "gate time",
+        )?;
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.qubit_to_tweezer = None;
+
+        self.load_layout(&layout_name)?;
+        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
+            let sqt = &mut info.tweezer_single_qubit_gate_times;
+            if let Some(present_hm) = sqt.get_mut(hqslang) {
+                present_hm.insert(tweezer, gate_time);
+            } else {
+                let mut hm = HashMap::new();
+                hm.insert(tweezer, gate_time);
+                sqt.insert(hqslang.to_string(), hm);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the time of a two-qubit gate for a tweezer couple in a given Layout.
+    ///
+    /// If the device has symmetric two-qubit gate times, see
+    /// [TweezerDevice::set_symmetric_two_qubit_gate_times], the gate time is also set for the
+    /// reversed couple.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a two-qubit gate.
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    /// * `gate_time` - The the gate time for the given gate.
+    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
+    pub fn set_tweezer_two_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        self.invalidate_gate_time_lookups();
+        check_gate_name(
+            hqslang,
+            &self.native_gates.two_qubit_gates,
+            "two",
+            "gate time",
+        )?;
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.qubit_to_tweezer = None;
+
+        self.load_layout(&layout_name)?;
+        let symmetric = self.symmetric_two_qubit_gate_times;
+        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
+            let sqt = &mut info.tweezer_two_qubit_gate_times;
+            if let Some(present_hm) = sqt.get_mut(hqslang) {
+                present_hm.insert((tweezer0, tweezer1), gate_time);
+            } else {
+                let mut hm = HashMap::new();
+                hm.insert((tweezer0, tweezer1), gate_time);
+                sqt.insert(hqslang.to_string(), hm);
+            }
+            if symmetric {
+                sqt.get_mut(hqslang)
+                    .unwrap()
+                    .insert((tweezer1, tweezer0), gate_time);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the theta-dependent gate time curve of the PhaseShiftedControlledPhase gate for a tweezer pair in a given Layout.
+    ///
+    /// The gate time of a PhaseShiftedControlledPhase gate with the rotation angle theta is
+    /// interpolated linearly between the samples of the curve, see
+    /// [TweezerDevice::gate_time_controlled_phase]. Theta is wrapped into [-pi, pi] and only its
+    /// absolute value is used, so the samples are given for theta in [0, pi].
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    /// * `samples` - The (theta, gate time) samples of the curve.
+    /// * `layout_name` - The name of the Layout to set the curve in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The curve has been set.
+    /// * `Err(RoqoqoBackendError)` - The samples are empty, contain a theta twice, a theta outside of [0, pi]
+    ///                               or an invalid gate time, no Layout is given or the Layout does not exist.
+    pub fn set_controlled_phase_time_curve(
+        &mut self,
+        tweezer0: usize,
+        tweezer1: usize,
+        samples: &[(f64, f64)],
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        let invalid_samples = |reason: &str| {
+            RoqoqoBackendError::GenericError {
+            msg: format!(
+                "Error setting the PhaseShiftedControlledPhase gate time curve of tweezers {} and {}. {}",
+                tweezer0, tweezer1, reason
+            ),
+        }
+        };
+        if samples.is_empty() {
+            return Err(invalid_samples("The curve has no samples."));
+        }
+        if samples.iter().any(|(theta, gate_time)| {
+            theta.is_nan()
+                || !(0.0..=std::f64::consts::PI).contains(theta)
+                || !gate_time.is_finite()
+                || *gate_time < 0.0
+        }) {
+            return Err(invalid_samples(
+                "Theta needs to be in [0, pi] and the gate times non-negative.",
+            ));
+        }
+        let mut curve = samples.to_vec();
+        curve.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if curve.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(invalid_samples("The curve contains a theta twice."));
+        }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        info.controlled_phase_time_curves
+            .insert((tweezer0, tweezer1), curve);
+        Ok(())
+    }
+
+    /// Set the time of a three-qubit gate for a tweezer trio in a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a three-qubit gate.
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    /// * `tweezer2` - The index of the third tweezer.
+    /// * `gate_time` - The the gate time for the given gate.
+    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
+    pub fn set_tweezer_three_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        tweezer2: usize,
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        self.invalidate_gate_time_lookups();
+        check_gate_name(
+            hqslang,
+            &self.native_gates.three_qubit_gates,
+            "three",
+            "gate time",
+        )?;
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.qubit_to_tweezer = None;
+
+        self.load_layout(&layout_name)?;
+        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
+            let sqt = &mut info.tweezer_three_qubit_gate_times;
+            if let Some(present_hm) = sqt.get_mut(hqslang) {
+                present_hm.insert((tweezer0, tweezer1, tweezer2), gate_time);
+            } else {
+                let mut hm = HashMap::new();
+                hm.insert((tweezer0, tweezer1, tweezer2), gate_time);
+                sqt.insert(hqslang.to_string(), hm);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the time of a multi-qubit gate for a list of tweezers in a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a multi-qubit gate.
+    /// * `tweezers` - The list of tweezer indexes.
+    /// * `gate_time` - The the gate time for the given gate.
+    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
+    pub fn set_tweezer_multi_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezers: &[usize],
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        self.invalidate_gate_time_lookups();
+        check_gate_name(
+            hqslang,
+            &self.native_gates.multi_qubit_gates,
+            "multi",
+            "gate time",
+        )?;
+        if tweezers.len() < 2 || tweezers.iter().duplicates().next().is_some() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "A multi-qubit gate needs at least two distinct tweezers, got {:?}.",
+                    tweezers
+                ),
+            });
+        }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.qubit_to_tweezer = None;
+
+        self.load_layout(&layout_name)?;
+        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
+            let sqt = &mut info.tweezer_multi_qubit_gate_times;
+            if let Some(present_hm) = sqt.get_mut(hqslang) {
+                present_hm.insert(tweezers.to_vec(), gate_time);
+            } else {
+                let mut hm = HashMap::new();
+                hm.insert(tweezers.to_vec(), gate_time);
+                sqt.insert(hqslang.to_string(), hm);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the times of a single-qubit gate for many tweezers in a given Layout.
+    ///
+    /// Equivalent to calling [TweezerDevice::set_tweezer_single_qubit_gate_time] for every
+    /// entry, without looking up the Layout for each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a single-qubit gate.
+    /// * `gate_times` - The (tweezer, gate time) entries.
+    /// * `layout_name` - The name of the Layout to apply the gate times in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate times have been set.
+    /// * `Err(RoqoqoBackendError)` - The gate is not supported, no layout name is provided and no current layout is set, or the layout is not present.
+    pub fn set_tweezer_single_qubit_gate_times(
+        &mut self,
+        hqslang: &str,
+        gate_times: &[(usize, f64)],
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        check_gate_name(
+            hqslang,
+            &self.native_gates.single_qubit_gates,
+            "single",
+            "gate time",
+        )?;
+        self.layout_info_mut(layout_name)?
+            .tweezer_single_qubit_gate_times
+            .entry(hqslang.to_string())
+            .or_default()
+            .extend(gate_times.iter().copied());
+        Ok(())
+    }
+
+    /// Set the times of a two-qubit gate for many tweezer couples in a given Layout.
+    ///
+    /// Equivalent to calling [TweezerDevice::set_tweezer_two_qubit_gate_time] for every
+    /// entry, without looking up the Layout for each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a two-qubit gate.
+    /// * `gate_times` - The (tweezer0, tweezer1, gate time) entries.
+    /// * `layout_name` - The name of the Layout to apply the gate times in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate times have been set.
+    /// * `Err(RoqoqoBackendError)` - The gate is not supported, no layout name is provided and no current layout is set, or the layout is not present.
+    pub fn set_tweezer_two_qubit_gate_times(
+        &mut self,
+        hqslang: &str,
+        gate_times: &[(usize, usize, f64)],
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        check_gate_name(
+            hqslang,
+            &self.native_gates.two_qubit_gates,
+            "two",
+            "gate time",
+        )?;
+        let symmetric = self.symmetric_two_qubit_gate_times;
+        let times = self
+            .layout_info_mut(layout_name)?
+            .tweezer_two_qubit_gate_times
+            .entry(hqslang.to_string())
+            .or_default();
+        for (tweezer0, tweezer1, time) in gate_times {
+            times.insert((*tweezer0, *tweezer1), *time);
+            if symmetric {
+                times.insert((*tweezer1, *tweezer0), *time);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the times of a three-qubit gate for many tweezer trios in a given Layout.
+    ///
+    /// Equivalent to calling [TweezerDevice::set_tweezer_three_qubit_gate_time] for every
+    /// entry, without looking up the Layout for each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a three-qubit gate.
+    /// * `gate_times` - The (tweezer0, tweezer1, tweezer2, gate time) entries.
+    /// * `layout_name` - The name of the Layout to apply the gate times in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate times have been set.
+    /// * `Err(RoqoqoBackendError)` - The gate is not supported, no layout name is provided and no current layout is set, or the layout is not present.
+    pub fn set_tweezer_three_qubit_gate_times(
+        &mut self,
+        hqslang: &str,
+        gate_times: &[(usize, usize, usize, f64)],
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        check_gate_name(
+            hqslang,
+            &self.native_gates.three_qubit_gates,
+            "three",
+            "gate time",
+        )?;
+        self.layout_info_mut(layout_name)?
+            .tweezer_three_qubit_gate_times
+            .entry(hqslang.to_string())
+            .or_default()
+            .extend(
+                gate_times
+                    .iter()
+                    .map(|(tweezer0, tweezer1, tweezer2, time)| {
+                        ((*tweezer0, *tweezer1, *tweezer2), *time)
+                    }),
+            );
+        Ok(())
+    }
+
+    /// Set the error rate of a single-qubit gate for a tweezer in a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a single-qubit gate.
+    /// * `tweezer` - The index of the tweezer.
+    /// * `error` - The error rate of the gate, between 0 and 1.
+    /// * `layout_name` - The name of the Layout to set the error rate in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The error rate has been set.
+    /// * `Err(RoqoqoBackendError)` - The gate is not supported, the error rate is invalid or the Layout does not exist.
+    pub fn set_tweezer_single_qubit_gate_error(
+        &mut self,
+        hqslang: &str,
+        tweezer: usize,
+        error: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        check_gate_name(
+            hqslang,
+            &self.native_gates.single_qubit_gates,
+            "single",
+            "error rate",
+        )?;
+        check_gate_error(error)?;
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        info.tweezer_single_qubit_gate_errors
+            .entry(hqslang.to_string())
+            .or_default()
+            .insert(tweezer, error);
+        Ok(())
+    }
+
+    /// Set the error rate of a two-qubit gate for a tweezer couple in a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a two-qubit gate.
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    /// * `error` - The error rate of the gate, between 0 and 1.
+    /// * `layout_name` - The name of the Layout to set the error rate in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The error rate has been set.
+    /// * `Err(RoqoqoBackendError)` - The gate is not supported, the error rate is invalid or the Layout does not exist.
+    pub fn set_tweezer_two_qubit_gate_error(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        error: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        check_gate_name(
+            hqslang,
+            &self.native_gates.two_qubit_gates,
+            "two",
+            "error rate",
+        )?;
+        check_gate_error(error)?;
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        info.tweezer_two_qubit_gate_errors
+            .entry(hqslang.to_string())
+            .or_default()
+            .insert((tweezer0, tweezer1), error);
+        Ok(())
+    }
+
+    /// Set the error rate of a three-qubit gate for a tweezer trio in a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a three-qubit gate.
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    /// * `tweezer2` - The index of the third tweezer.
+    /// * `error` - The error rate of the gate, between 0 and 1.
+    /// * `layout_name` - The name of the Layout to set the error rate in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The error rate has been set.
+    /// * `Err(RoqoqoBackendError)` - The gate is not supported, the error rate is invalid or the Layout does not exist.
+    pub fn set_tweezer_three_qubit_gate_error(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        tweezer2: usize,
+        error: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        check_gate_name(
+            hqslang,
+            &self.native_gates.three_qubit_gates,
+            "three",
+            "error rate",
+        )?;
+        check_gate_error(error)?;
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        info.tweezer_three_qubit_gate_errors
+            .entry(hqslang.to_string())
+            .or_default()
+            .insert((tweezer0, tweezer1, tweezer2), error);
+        Ok(())
+    }
+
+    /// Returns the error rate of a single-qubit gate on a qubit in the current Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the gate.
+    /// * `qubit` - The qubit the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The error rate of the gate.
+    /// * `None` - No error rate is set for the gate on the tweezer of the qubit.
+    pub fn single_qubit_gate_error(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
+        let tweezer = self.get_tweezer_from_qubit(qubit).ok()?;
+        self.get_current_layout_info()
+            .ok()?
+            .tweezer_single_qubit_gate_errors
+            .get(hqslang)?
+            .get(&tweezer)
+            .copied()
+    }
+
+    /// Returns the error rate of a two-qubit gate on two qubits in the current Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the gate.
+    /// * `control` - The control qubit the gate acts on.
+    /// * `target` - The target qubit the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The error rate of the gate.
+    /// * `None` - No error rate is set for the gate on the tweezers of the qubits.
+    pub fn two_qubit_gate_error(
+        &self,
+        hqslang: &str,
+        control: &usize,
+        target: &usize,
+    ) -> Option<f64> {
+        let control = self.get_tweezer_from_qubit(control).ok()?;
+        let target = self.get_tweezer_from_qubit(target).ok()?;
+        self.get_current_layout_info()
+            .ok()?
+            .tweezer_two_qubit_gate_errors
+            .get(hqslang)?
+            .get(&(control, target))
+            .copied()
+    }
+
+    /// Returns the error rate of a three-qubit gate on three qubits in the current Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the gate.
+    /// * `control_0` - The first control qubit the gate acts on.
+    /// * `control_1` - The second control qubit the gate acts on.
+    /// * `target` - The target qubit the gate acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The error rate of the gate.
+    /// * `None` - No error rate is set for the gate on the tweezers of the qubits.
+    pub fn three_qubit_gate_error(
+        &self,
+        hqslang: &str,
+        control_0: &usize,
+        control_1: &usize,
+        target: &usize,
+    ) -> Option<f64> {
+        let control_0 = self.get_tweezer_from_qubit(control_0).ok()?;
+        let control_1 = self.get_tweezer_from_qubit(control_1).ok()?;
+        let target = self.get_tweezer_from_qubit(target).ok()?;
+        self.get_current_layout_info()
+            .ok()?
+            .tweezer_three_qubit_gate_errors
+            .get(hqslang)?
+            .get(&(control_0, control_1, target))
+            .copied()
+    }
+
+    /// Remove the time of a single-qubit gate for a tweezer in a given Layout.
+    ///
+    /// The error rate of the gate for the tweezer is removed as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a single-qubit gate.
+    /// * `tweezer` - The index of the tweezer.
+    /// * `layout_name` - The name of the Layout to remove the gate time from. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Option<f64>)` - The removed gate time, None if it was not set.
+    /// * `Err(RoqoqoBackendError)` - No layout name is provided and no current layout is set, or the layout is not present.
+    pub fn unset_tweezer_single_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer: usize,
+        layout_name: Option<String>,
+    ) -> Result<Option<f64>, RoqoqoBackendError> {
+        let info = self.layout_info_mut(layout_name)?;
+        unset_gate_entry(
+            &mut info.tweezer_single_qubit_gate_errors,
+            hqslang,
+            &tweezer,
+        );
+        Ok(unset_gate_entry(
+            &mut info.tweezer_single_qubit_gate_times,
+            hqslang,
+            &tweezer,
+        ))
+    }
+
+    /// Remove the time of a two-qubit gate for a tweezer couple in a given Layout.
+    ///
+    /// Only the given order of the tweezers is removed, unless the device has symmetric
+    /// two-qubit gate times. The error rate of the gate for the tweezers is removed as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a two-qubit gate.
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    /// * `layout_name` - The name of the Layout to remove the gate time from. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Option<f64>)` - The removed gate time, None if it was not set.
+    /// * `Err(RoqoqoBackendError)` - No layout name is provided and no current layout is set, or the layout is not present.
+    pub fn unset_tweezer_two_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        layout_name: Option<String>,
+    ) -> Result<Option<f64>, RoqoqoBackendError> {
+        let symmetric = self.symmetric_two_qubit_gate_times;
+        let info = self.layout_info_mut(layout_name)?;
+        let tweezers = (tweezer0, tweezer1);
+        unset_gate_entry(&mut info.tweezer_two_qubit_gate_errors, hqslang, &tweezers);
+        let gate_time =
+            unset_gate_entry(&mut info.tweezer_two_qubit_gate_times, hqslang, &tweezers);
+        if symmetric {
+            let reversed = (tweezer1, tweezer0);
+            unset_gate_entry(&mut info.tweezer_two_qubit_gate_errors, hqslang, &reversed);
+            unset_gate_entry(&mut info.tweezer_two_qubit_gate_times, hqslang, &reversed);
+        }
+        Ok(gate_time)
+    }
+
+    /// Remove the time of a three-qubit gate for a tweezer trio in a given Layout.
+    ///
+    /// The error rate of the gate for the tweezers is removed as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a three-qubit gate.
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    /// * `tweezer2` - The index of the third tweezer.
+    /// * `layout_name` - The name of the Layout to remove the gate time from. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Option<f64>)` - The removed gate time, None if it was not set.
+    /// * `Err(RoqoqoBackendError)` - No layout name is provided and no current layout is set, or the layout is not present.
+    pub fn unset_tweezer_three_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezer0: usize,
+        tweezer1: usize,
+        tweezer2: usize,
+        layout_name: Option<String>,
+    ) -> Result<Option<f64>, RoqoqoBackendError> {
+        let info = self.layout_info_mut(layout_name)?;
+        let tweezers = (tweezer0, tweezer1, tweezer2);
+        unset_gate_entry(
+            &mut info.tweezer_three_qubit_gate_errors,
+            hqslang,
+            &tweezers,
+        );
+        Ok(unset_gate_entry(
+            &mut info.tweezer_three_qubit_gate_times,
+            hqslang,
+            &tweezers,
+        ))
+    }
+
+    /// Remove the time of a multi-qubit gate for a list of tweezers in a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of a multi-qubit gate.
+    /// * `tweezers` - The list of tweezer indexes.
+    /// * `layout_name` - The name of the Layout to remove the gate time from. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Option<f64>)` - The removed gate time, None if it was not set.
+    /// * `Err(RoqoqoBackendError)` - No layout name is provided and no current layout is set, or the layout is not present.
+    pub fn unset_tweezer_multi_qubit_gate_time(
+        &mut self,
+        hqslang: &str,
+        tweezers: &[usize],
+        layout_name: Option<String>,
+    ) -> Result<Option<f64>, RoqoqoBackendError> {
+        let info = self.layout_info_mut(layout_name)?;
+        Ok(unset_gate_entry(
+            &mut info.tweezer_multi_qubit_gate_times,
+            hqslang,
+            &tweezers.to_vec(),
+        ))
+    }
+
+    /// Remove all gate times, error rates, shifts, coordinates and tweezers per row of a Layout.
+    ///
+    /// The Layout stays in the register as an empty Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the Layout to clear.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The Layout has been cleared.
+    /// * `Err(RoqoqoBackendError)` - The layout is not present.
+    pub fn clear_layout(&mut self, name: &str) -> Result<(), RoqoqoBackendError> {
+        let info = self.layout_info_mut(Some(name.to_string()))?;
+        *info = TweezerLayoutInfo::default();
+        Ok(())
+    }
+
+    /// Set the allowed Tweezer shifts of a specified Tweezer.
+    ///
+    /// The tweezer give the tweezer a qubit can be shifted out of. The values are lists
+    /// over the directions the qubit in the tweezer can be shifted into.
+    /// The items in the list give the allowed tweezers the qubit can be shifted into in order.
+    /// For a list 1,2,3 the qubit can be shifted into tweezer 1, into tweezer 2 if tweezer 1 is not occupied,
+    /// and into tweezer 3 if tweezer 1 and 2 are not occupied.
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer` - The index of the tweezer.
+    /// * `allowed_shifts` - The allowed Tweezer shifts.
+    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The allowed shifts have been set.
+    /// * `Err(RoqoqoBackendError)` - The given shifts are not valid.
+    pub fn set_allowed_tweezer_shifts(
+        &mut self,
+        tweezer: &usize,
+        allowed_shifts: &[&[usize]],
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+
+        // Check that all the involved tweezers exist
+        if !self.is_tweezer_present(*tweezer, Some(layout_name.clone()))
+            || allowed_shifts.iter().any(|s| {
+                s.iter()
+                    .any(|t| !self.is_tweezer_present(*t, Some(layout_name.clone())))
+            })
+        {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "The given tweezer, or shifts tweezers, are not present in the device Tweezer data."
+                    .to_string(),
+            });
+        }
+        // Check the input tweezer is not present in the input allowed shifts
+        if allowed_shifts
+            .iter()
+            .any(|shift_list| shift_list.contains(tweezer))
+        {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "The allowed shifts contain the given tweezer.".to_string(),
+            });
+        }
+        self.load_layout(&layout_name)?;
+        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
+            info.allowed_tweezer_shifts
+                .entry(*tweezer)
+                .or_insert_with(Vec::new)
+                .extend(allowed_shifts.iter().map(|&slice| slice.to_vec()));
+        }
+        Ok(())
+    }
+
+    /// Set the transport time of an allowed shift in a given Layout.
+    ///
+    /// The transport time takes precedence over the time per site set with
+    /// [TweezerDevice::set_shift_time_per_site].
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The tweezer the qubit is shifted out of.
+    /// * `end` - The tweezer the qubit is shifted into.
+    /// * `shift_time` - The transport time of the shift.
+    /// * `layout_name` - The name of the Layout to set the transport time in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The transport time has been set.
+    /// * `Err(RoqoqoBackendError)` - The transport time is negative or not finite, the shift is not allowed,
+    ///                               no Layout is given or the Layout does not exist.
+    pub fn set_tweezer_shift_time(
+        &mut self,
+        start: usize,
+        end: usize,
+        shift_time: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        if !shift_time.is_finite() || shift_time < 0.0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error setting the transport time of the shift from tweezer {} to tweezer {}. The time {} is negative or not finite.",
+                    start, end, shift_time
+                ),
+            });
+        }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        if shift_sites(&info.allowed_tweezer_shifts, start, end).is_none() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "The shift from tweezer {} to tweezer {} is not allowed in Layout {}.",
+                    start, end, layout_name
+                ),
+            });
+        }
+        info.tweezer_shift_times.insert((start, end), shift_time);
+        Ok(())
+    }
+
+    /// Set the transport time per tweezer passed during a shift in a given Layout.
+    ///
+    /// Shifting a qubit into the n-th tweezer of an allowed shift direction takes n times the
+    /// time per site, unless a transport time is set for the shift with
+    /// [TweezerDevice::set_tweezer_shift_time].
+    ///
+    /// # Arguments
+    ///
+    /// * `time_per_site` - The transport time per tweezer passed.
+    /// * `layout_name` - The name of the Layout to set the time in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The time per site has been set.
+    /// * `Err(RoqoqoBackendError)` - The time is negative or not finite, no Layout is given or the Layout does not exist.
+    pub fn set_shift_time_per_site(
+        &mut self,
+        time_per_site: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        if !time_per_site.is_finite() || time_per_site < 0.0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error setting the transport time per site. The time {} is negative or not finite.",
+                    time_per_site
+                ),
+            });
+        }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        info.shift_time_per_site = Some(time_per_site);
+        Ok(())
+    }
+
+    /// Returns the transport time of shifting a qubit between two tweezers in the current Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The tweezer the qubit is shifted out of.
+    /// * `end` - The tweezer the qubit is shifted into.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The transport time set for the shift, or the number of tweezers passed times the time per site.
+    /// * `None` - The shift is not allowed, has no transport time and no time per site is set, or no current Layout is set.
+    pub fn shift_time(&self, start: usize, end: usize) -> Option<f64> {
+        let info = self.get_current_layout_info().ok()?;
+        let sites = shift_sites(&info.allowed_tweezer_shifts, start, end)?;
+        info.tweezer_shift_times
+            .get(&(start, end))
+            .copied()
+            .or_else(|| {
+                info.shift_time_per_site
+                    .map(|time_per_site| sites as f64 * time_per_site)
+            })
+    }
+
+    /// Returns the duration of a PragmaShiftQubitsTweezers operation in the current Layout.
+    ///
+    /// A shift takes the duration given in the operation or else the transport time of the
+    /// device, see [TweezerDevice::shift_time]. Sequential shifts take the sum of the durations
+    /// of the shifts, parallel shifts the longest duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `pragma` - The PragmaShiftQubitsTweezers operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The duration of the operation.
+    /// * `None` - A shift has neither a duration in the operation nor a transport time in the device.
+    pub fn shift_duration(&self, pragma: &PragmaShiftQubitsTweezers) -> Option<f64> {
+        let durations: Vec<f64> = pragma
+            .shifts
+            .iter()
+            .enumerate()
+            .map(|(index, (start, end))| {
+                pragma
+                    .durations
+                    .as_ref()
+                    .and_then(|durations| durations.get(index).copied())
+                    .or_else(|| self.shift_time(*start, *end))
+            })
+            .collect::<Option<Vec<f64>>>()?;
+        if pragma.parallel {
+            Some(durations.into_iter().fold(0.0, f64::max))
+        } else {
+            Some(durations.into_iter().sum())
+        }
+    }
+
+    /// Set the gate time of the global rotation PragmaGlobalRotateXY in a given Layout.
+    ///
+    /// The global rotation is only available in the Layouts with a global rotation time.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate_time` - The gate time of the global rotation.
+    /// * `layout_name` - The name of the Layout to set the time in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The global rotation time has been set.
+    /// * `Err(RoqoqoBackendError)` - The time is negative or not finite, no Layout is given or the Layout does not exist.
+    pub fn set_global_rotation_time(
+        &mut self,
+        gate_time: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        if !gate_time.is_finite() || gate_time < 0.0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error setting the global rotation time. The time {} is negative or not finite.",
+                    gate_time
+                ),
+            });
+        }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        info.global_rotation_time = Some(gate_time);
+        Ok(())
+    }
+
+    /// Checks whether the global rotation PragmaGlobalRotateXY is available in the current Layout.
+    fn validate_global_rotation(&self) -> Result<(), RoqoqoBackendError> {
+        match self.get_current_layout_info()?.global_rotation_time {
+            Some(_) => Ok(()),
+            None => Err(RoqoqoBackendError::GenericError {
+                msg: "The global rotation is not available in the current Layout: no global rotation time is set.".to_string(),
+            }),
+        }
+    }
+
+    /// Returns the gate time of the global rotation PragmaGlobalRotateXY in the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The global rotation time of the current Layout.
+    /// * `None` - No global rotation time is set or no current Layout is set.
+    pub fn global_rotation_time(&self) -> Option<f64> {
+        self.get_current_layout_info()
+            .ok()
+            .and_then(|info| info.global_rotation_time)
+    }
+
+    /// Set the time to switch the device into a given Layout with a PragmaSwitchDeviceLayout.
+    ///
+    /// # Arguments
+    ///
+    /// * `switch_time` - The time to switch into the Layout.
+    /// * `layout_name` - The name of the Layout to set the time in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The layout switch time has been set.
+    /// * `Err(RoqoqoBackendError)` - The time is negative or not finite, no Layout is given or the Layout does not exist.
+    pub fn set_layout_switch_time(
+        &mut self,
+        switch_time: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        if !switch_time.is_finite() || switch_time < 0.0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error setting the layout switch time. The time {} is negative or not finite.",
+                    switch_time
+                ),
+            });
+        }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        info.layout_switch_time = Some(switch_time);
+        Ok(())
+    }
+
+    /// Returns the time to switch the device into the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The layout switch time of the current Layout.
+    /// * `None` - No layout switch time is set or no current Layout is set.
+    pub fn layout_switch_time(&self) -> Option<f64> {
+        self.get_current_layout_info()
+            .ok()
+            .and_then(|info| info.layout_switch_time)
+    }
+
+    /// Set the allowed Tweezer shifts from a list of tweezers.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_shifts` - A list of lists, each representing a row of tweezers.
+    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
+    pub fn set_allowed_tweezer_shifts_from_rows(
+        &mut self,
+        row_shifts: &[&[usize]],
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+
+        // Check that all the involved tweezers exist
+        if row_shifts.iter().any(|row| {
+            row.iter()
+                .any(|t| !self.is_tweezer_present(*t, Some(layout_name.clone())))
+        }) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "A given Tweezer is not present in the device Tweezer data.".to_string(),
+            });
+        }
+        // Check that there are no repetitions in the input shifts
+        for row in row_shifts.iter() {
+            if row.iter().duplicates().count() > 0 {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: "The given Tweezers contain repetitions.".to_string(),
+                });
+            }
+        }
+
+        self.load_layout(&layout_name)?;
+        let allowed_shifts = &mut self
+            .layout_register
+            .as_mut()
+            .unwrap()
+            .get_mut(&layout_name)
+            .unwrap()
+            .allowed_tweezer_shifts;
+
+        // For each row in the input..
+        row_shifts.iter().for_each(|row| {
+            // ... divide in left, mid (the tweezer) and right parts
+            for i in 0..row.len() {
+                let (left_slice, mid) = row.split_at(i);
+                let mid = mid.first().unwrap_or(&0);
+                let mut vec_left = left_slice.to_vec();
+                vec_left.reverse();
+                let vec_right = &row[i + 1..].to_vec();
+
+                // Insert the left and right side
+                let val = allowed_shifts.entry(*mid).or_default();
+                if !vec_left.is_empty() && !val.contains(&vec_left) {
+                    val.push(vec_left.to_vec());
+                }
+                if !vec_right.is_empty() && !val.contains(vec_right) {
+                    val.push(vec_right.to_vec());
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Set the tweezer per row value for a given Layout.
+    ///
+    /// This is needed for dynamically switching layouts during circuit execution.
+    /// Only switching between layouts having the same tweezer per row value is supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezers_per_row` - Vector containing the number of tweezers per row to set.
+    /// * `layout_name` - The name of the Layout to set the tweezer per row for. Defaults to the current Layout.
+    pub fn set_tweezers_per_row(
+        &mut self,
+        tweezers_per_row: Vec<usize>,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+
+        self.load_layout(&layout_name)?;
+        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
+            info.tweezers_per_row = Some(tweezers_per_row);
+        }
+
+        Ok(())
+    }
+
+    /// Infers the tweezers per row of a given Layout.
+    ///
+    /// If all tweezers of the Layout have coordinates, the rows are the tweezers with the same
+    /// y coordinate, ordered by increasing y. Otherwise, if every tweezer can be shifted or
+    /// shifted into, the rows are the groups of tweezers connected by allowed shifts, ordered
+    /// by their smallest tweezer. Dynamic layout switching uses the inferred tweezers per row
+    /// of Layouts without tweezers per row set with [TweezerDevice::set_tweezers_per_row].
+    ///
+    /// # Arguments
+    ///
+    /// * `layout_name` - The name of the Layout. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Vec<usize>))` - The inferred number of tweezers per row.
+    /// * `Ok(None)` - The Layout has neither coordinates nor shifts for all of its tweezers.
+    /// * `Err(RoqoqoBackendError)` - No Layout is given or the Layout does not exist.
+    pub fn infer_tweezers_per_row(
+        &self,
+        layout_name: Option<String>,
+    ) -> Result<Option<Vec<usize>>, RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        let info =
+            self.layout_info(&layout_name)?
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: "The given layout name is not present in the layout register.".to_string(),
+                })?;
+        Ok(inferred_tweezers_per_row(info))
+    }
+
+    /// Set the (x, y) coordinates of a tweezer in a given Layout.
+    ///
+    /// The coordinates are used by [TweezerDevice::set_two_qubit_gate_times_from_distance] and
+    /// by [TweezerDevice::draw], which places the tweezers at their true positions when all
+    /// tweezers of the Layout have coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer` - The index of the tweezer.
+    /// * `x` - The x coordinate of the tweezer.
+    /// * `y` - The y coordinate of the tweezer.
+    /// * `layout_name` - The name of the Layout to set the coordinates in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The coordinates have been set.
+    /// * `Err(RoqoqoBackendError)` - The coordinates are not finite or no Layout is given.
+    pub fn set_tweezer_coordinates(
+        &mut self,
+        tweezer: usize,
+        x: f64,
+        y: f64,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        if !x.is_finite() || !y.is_finite() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error setting the coordinates of tweezer {}. The coordinates ({}, {}) are not finite.",
+                    tweezer, x, y
+                ),
+            });
+        }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+
+        self.load_layout(&layout_name)?;
+        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
+            info.tweezer_coordinates.insert(tweezer, (x, y));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the (x, y) coordinates of the tweezers of a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout_name` - The name of the Layout. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<usize, (f64, f64)>)` - The coordinates of the tweezers that have coordinates.
+    /// * `Err(RoqoqoBackendError)` - No Layout is given or the Layout does not exist.
+    pub fn tweezer_coordinates(
+        &self,
+        layout_name: Option<String>,
+    ) -> Result<HashMap<usize, (f64, f64)>, RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.layout_info(&layout_name)?
+            .map(|info| info.tweezer_coordinates.clone())
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Layout {} is not present in the layout register.",
+                    layout_name
+                ),
+            })
+    }
+
+    /// Set the zone label of a tweezer in a given Layout.
+    ///
+    /// Zone labels describe the region of a zoned trap a tweezer is placed in, e.g. "storage",
+    /// "interaction" or "readout". In a Layout with zone labels two-qubit gates are only allowed
+    /// between tweezers labelled [INTERACTION_ZONE], see [TweezerDevice::check_zone_constraints].
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer` - The index of the tweezer.
+    /// * `zone` - The label of the zone of the tweezer.
+    /// * `layout_name` - The name of the Layout to set the zone in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The zone has been set.
+    /// * `Err(RoqoqoBackendError)` - The zone label is empty or no Layout is given.
+    pub fn set_tweezer_zone(
+        &mut self,
+        tweezer: usize,
+        zone: &str,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        if zone.is_empty() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error setting the zone of tweezer {}. The zone label is empty.",
+                    tweezer
+                ),
+            });
+        }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+
+        self.load_layout(&layout_name)?;
+        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
+            info.tweezer_zones.insert(tweezer, zone.to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the zone labels of the tweezers of a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout_name` - The name of the Layout. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<usize, String>)` - The zone labels of the tweezers that have a zone.
+    /// * `Err(RoqoqoBackendError)` - No Layout is given or the Layout does not exist.
+    pub fn tweezer_zones(
+        &self,
+        layout_name: Option<String>,
+    ) -> Result<HashMap<usize, String>, RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.layout_info(&layout_name)?
+            .map(|info| info.tweezer_zones.clone())
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Layout {} is not present in the layout register.",
+                    layout_name
+                ),
+            })
+    }
+
+    /// Returns the zone label of a tweezer in a given Layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer` - The index of the tweezer.
+    /// * `layout_name` - The name of the Layout. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Option<String>)` - The zone label of the tweezer, None if the tweezer has no zone.
+    /// * `Err(RoqoqoBackendError)` - No Layout is given or the Layout does not exist.
+    pub fn tweezer_zone(
+        &self,
+        tweezer: usize,
+        layout_name: Option<String>,
+    ) -> Result<Option<String>, RoqoqoBackendError> {
+        Ok(self.tweezer_zones(layout_name)?.remove(&tweezer))
+    }
+
+    /// Checks that the two-qubit gates of a given Layout are only defined within the interaction zone.
+    ///
+    /// Layouts without zone labels are not zoned and always pass the check. In a zoned Layout
+    /// both tweezers of every two-qubit gate need to be labelled [INTERACTION_ZONE].
+    ///
+    /// # Arguments
+    ///
+    /// * `layout_name` - The name of the Layout. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - All two-qubit gates are defined within the interaction zone.
+    /// * `Err(RoqoqoBackendError)` - A two-qubit gate is defined on a tweezer outside of the interaction zone,
+    ///                               no Layout is given or the Layout does not exist.
+    pub fn check_zone_constraints(
+        &self,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        let info =
+            self.layout_info(&layout_name)?
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Layout {} is not present in the layout register.",
+                        layout_name
+                    ),
+                })?;
+        if info.tweezer_zones.is_empty() {
+            return Ok(());
+        }
+        let in_interaction_zone = |tweezer: &usize| {
+            info.tweezer_zones
+                .get(tweezer)
+                .is_some_and(|zone| zone == INTERACTION_ZONE)
+        };
+        for (hqslang, times) in info
+            .tweezer_two_qubit_gate_times
+            .iter()
+            .sorted_by(|a, b| a.0.cmp(b.0))
+        {
+            for (tweezer0, tweezer1) in times.keys().sorted() {
+                if !in_interaction_zone(tweezer0) || !in_interaction_zone(tweezer1) {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Two-qubit gate {} between tweezers {} and {} in Layout {} is defined outside of the {} zone.",
+                            hqslang, tweezer0, tweezer1, layout_name, INTERACTION_ZONE
+                        ),
+                    });
+                }
             }
-            device.device_name = device_name_internal;
-            Ok(device)
-        } else {
-            Err(RoqoqoBackendError::NetworkError {
-                msg: format!(
-                    "Request to server failed with HTTP status code {:?}.",
-                    status_code
-                ),
-            })
         }
+        Ok(())
     }
 
-    /// Adds a new empty Layout to the device's register.
+    /// Add a pair of tweezers that can not be driven simultaneously in a given Layout.
+    ///
+    /// The constraint is symmetric, e.g. due to the Rydberg blockade or crosstalk between the
+    /// tweezers. See [TweezerDevice::can_execute_in_parallel].
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the new Layout to be added to the register.
-    pub fn add_layout(&mut self, name: &str) -> Result<(), RoqoqoBackendError> {
-        if let Some(int_register) = &self.layout_register {
-            if int_register.contains_key(name) {
-                return Err(RoqoqoBackendError::GenericError {
-                    msg: format!(
-                        "Error adding layout to TweezerDevice. Layout name {} is already in use in the Layout register.",
-                        name,
-                    ),
-                });
-            }
-            self.layout_register
-                .as_mut()
-                .unwrap()
-                .insert(name.to_string(), TweezerLayoutInfo::default());
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    /// * `layout_name` - The name of the Layout to add the pair to. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The pair has been added.
+    /// * `Err(RoqoqoBackendError)` - The tweezers are the same, no Layout is given or the Layout does not exist.
+    pub fn add_blockaded_tweezer_pair(
+        &mut self,
+        tweezer0: usize,
+        tweezer1: usize,
+        layout_name: Option<String>,
+    ) -> Result<(), RoqoqoBackendError> {
+        if tweezer0 == tweezer1 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error adding the blockaded tweezer pair ({}, {}). The tweezers are the same.",
+                    tweezer0, tweezer1
+                ),
+            });
         }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        info.blockaded_tweezer_pairs
+            .insert((tweezer0.min(tweezer1), tweezer0.max(tweezer1)));
         Ok(())
     }
 
-    /// Switch to a different pre-defined Layout.
+    /// Set the blockade radius of a given Layout.
     ///
-    /// It is updated only if the given Layout name is present in the device's
-    /// Layout register. If the qubit -> tweezer mapping is empty, it is
-    /// trivially populated by default.
+    /// Tweezers with coordinates at most the blockade radius apart can not be driven
+    /// simultaneously. Tweezers without coordinates are only constrained by the pairs added with
+    /// [TweezerDevice::add_blockaded_tweezer_pair].
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the new Layout.
-    /// * `with_trivial_map` - Whether the qubit -> tweezer mapping should be trivially populated. Defaults to true.
-    pub fn switch_layout(
+    /// * `radius` - The blockade radius, in the units of the tweezer coordinates.
+    /// * `layout_name` - The name of the Layout to set the radius in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The blockade radius has been set.
+    /// * `Err(RoqoqoBackendError)` - The radius is negative or not finite, no Layout is given or the Layout does not exist.
+    pub fn set_blockade_radius(
         &mut self,
-        name: &str,
-        with_trivial_map: Option<bool>,
+        radius: f64,
+        layout_name: Option<String>,
     ) -> Result<(), RoqoqoBackendError> {
-        if let Some(int_register) = &self.layout_register {
-            if !int_register.keys().contains(&name.to_string()) {
-                return Err(RoqoqoBackendError::GenericError {
-                    msg: format!(
-                        "Error switching layout of TweezerDevice. Layout {} is not set.",
-                        name
-                    ),
-                });
-            }
-            self.current_layout = Some(name.to_string());
-            if self.qubit_to_tweezer.is_none() && with_trivial_map.unwrap_or(true) {
-                self.qubit_to_tweezer = Some(self.new_trivial_mapping());
-            }
+        if !radius.is_finite() || radius < 0.0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error setting the blockade radius. The radius {} is negative or not finite.",
+                    radius
+                ),
+            });
         }
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        info.blockade_radius = Some(radius);
         Ok(())
     }
 
-    /// Returns a vector of all available Layout names.
+    /// Returns whether two tweezers of a given Layout can not be driven simultaneously.
     ///
-    /// # Returns:
+    /// # Arguments
     ///
-    /// * `Vec<&str>` - The vector of all available Layout names.
-    pub fn available_layouts(&self) -> Vec<&str> {
-        if let Some(int_register) = &self.layout_register {
-            return int_register
-                .keys()
-                .collect_vec()
-                .iter()
-                .map(|x| x.as_str())
-                .collect();
-        }
-        vec![]
+    /// * `tweezer0` - The index of the first tweezer.
+    /// * `tweezer1` - The index of the second tweezer.
+    /// * `layout_name` - The name of the Layout. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the tweezers are the same, have been added as a blockaded pair or are within the blockade radius.
+    /// * `Err(RoqoqoBackendError)` - No Layout is given or the Layout does not exist.
+    pub fn are_tweezers_blockaded(
+        &self,
+        tweezer0: usize,
+        tweezer1: usize,
+        layout_name: Option<String>,
+    ) -> Result<bool, RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        let info =
+            self.layout_info(&layout_name)?
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Layout {} is not present in the layout register.",
+                        layout_name
+                    ),
+                })?;
+        Ok(tweezers_blockaded(info, tweezer0, tweezer1))
     }
 
-    /// Modifies the qubit -> tweezer mapping of the device.
+    /// Returns whether operations can be executed simultaneously in the current Layout.
     ///
-    /// If a qubit -> tweezer mapping is already present, it is overwritten.
-    /// Returns an error in the the tweezer does not exist.
+    /// The qubits of the operations are mapped to their tweezers. The operations can be executed
+    /// in parallel if no tweezer of an operation is blockaded with a tweezer of another operation,
+    /// in particular if no two operations act on the same qubit. Operations acting on all qubits
+    /// can only be executed alone, operations acting on no qubit do not constrain the others.
     ///
     /// # Arguments
     ///
-    /// * `qubit` - The index of the qubit.
-    /// * `tweezer` - The index of the tweezer.
+    /// * `operations` - The operations to execute in parallel.
     ///
     /// # Returns
     ///
-    /// * `Ok(HashMap<usize,usize>)` - The updated qubit -> tweezer mapping.
-    /// * `Err(RoqoqoBackendError)` - The tweezer does not exist.
-    pub fn add_qubit_tweezer_mapping(
-        &mut self,
-        qubit: usize,
-        tweezer: usize,
-    ) -> Result<HashMap<usize, usize>, RoqoqoBackendError> {
-        if !self.is_tweezer_present(tweezer, None) {
-            return Err(RoqoqoBackendError::GenericError {
-                msg: "The given tweezer is not present in the device Tweezer data.".to_string(),
-            });
+    /// * `Ok(bool)` - Whether the operations can be executed in parallel.
+    /// * `Err(RoqoqoBackendError)` - No current Layout is set or a qubit is not mapped to a tweezer.
+    pub fn can_execute_in_parallel(
+        &self,
+        operations: &[Operation],
+    ) -> Result<bool, RoqoqoBackendError> {
+        let info = self.get_current_layout_info()?;
+        let mut operation_tweezers: Vec<Vec<usize>> = Vec::with_capacity(operations.len());
+        let mut global_operations = 0;
+        for operation in operations {
+            match operation.involved_qubits() {
+                InvolvedQubits::None => {}
+                InvolvedQubits::All => global_operations += 1,
+                InvolvedQubits::Set(qubits) => operation_tweezers.push(
+                    qubits
+                        .iter()
+                        .map(|qubit| self.get_tweezer_from_qubit(qubit))
+                        .collect::<Result<Vec<usize>, RoqoqoBackendError>>()?,
+                ),
+            }
         }
-        if let Some(map) = &mut self.qubit_to_tweezer {
-            // Remove the previous qubit present in the tweezer
-            if let Some(qubit_to_remove) =
-                map.iter()
-                    .find_map(|(&qbt, &twz)| if twz == tweezer { Some(qbt) } else { None })
-            {
-                map.remove(&qubit_to_remove);
+        if global_operations > 1 || (global_operations == 1 && !operation_tweezers.is_empty()) {
+            return Ok(false);
+        }
+        for (index, tweezers) in operation_tweezers.iter().enumerate() {
+            for other_tweezers in operation_tweezers[index + 1..].iter() {
+                if iproduct!(tweezers.iter(), other_tweezers.iter())
+                    .any(|(tweezer0, tweezer1)| tweezers_blockaded(info, *tweezer0, *tweezer1))
+                {
+                    return Ok(false);
+                }
             }
-            map.insert(qubit, tweezer);
-        } else {
-            self.qubit_to_tweezer = Some(HashMap::from([(qubit, tweezer)]));
         }
-        Ok(self
-            .qubit_to_tweezer
-            .as_ref()
-            .expect("Internal error: qubit_to_tweezer mapping supposed to be Some().")
-            .clone())
+        Ok(true)
     }
 
-    /// Set the time of a single-qubit gate for a tweezer in a given Layout.
+    /// Set the time of a two-qubit gate for all tweezer pairs within a distance in a given Layout.
+    ///
+    /// For every pair of tweezers with coordinates at most `max_distance` apart, the gate time
+    /// `time_fn(distance)` is set for both orders of the tweezers. The gate times of pairs further
+    /// apart and of tweezers without coordinates are left unchanged.
     ///
     /// # Arguments
     ///
-    /// * `hqslang` - The hqslang name of a single-qubit gate.
-    /// * `tweezer` - The index of the tweezer.
-    /// * `gate_time` - The the gate time for the given gate.
-    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
-    pub fn set_tweezer_single_qubit_gate_time(
+    /// * `hqslang` - The hqslang name of a two-qubit gate.
+    /// * `max_distance` - The maximal distance between two tweezers supporting the gate.
+    /// * `time_fn` - Returns the gate time for the distance between two tweezers.
+    /// * `layout_name` - The name of the Layout to set the gate times in. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The gate times have been set.
+    /// * `Err(RoqoqoBackendError)` - The gate is not supported, the maximal distance is negative,
+    ///                               `time_fn` returned an invalid gate time or the Layout does not exist.
+    pub fn set_two_qubit_gate_times_from_distance<F>(
         &mut self,
         hqslang: &str,
-        tweezer: usize,
-        gate_time: f64,
+        max_distance: f64,
+        time_fn: F,
         layout_name: Option<String>,
-    ) -> Result<(), RoqoqoBackendError> {
-        if !ALLOWED_NATIVE_SINGLE_QUBIT_GATES.contains(&hqslang) {
+    ) -> Result<(), RoqoqoBackendError>
+    where
+        F: Fn(f64) -> f64,
+    {
+        check_gate_name(
+            hqslang,
+            &self.native_gates.two_qubit_gates,
+            "two",
+            "gate time",
+        )?;
+        if max_distance.is_nan() || max_distance < 0.0 {
             return Err(RoqoqoBackendError::GenericError {
                 msg: format!(
-                    "Error setting the gate time of a single-qubit gate. Gate {} is not supported.",
-                    hqslang
+                    "The maximal distance needs to be non-negative, got {}.",
+                    max_distance
                 ),
             });
         }
@@ -536,323 +3903,546 @@ impl TweezerDevice {
             .ok_or_else(|| RoqoqoBackendError::GenericError {
                 msg: "No layout name provided and no current layout set.".to_string(),
             })?;
-        self.qubit_to_tweezer = None;
+        let coordinates = self.tweezer_coordinates(Some(layout_name.clone()))?;
+        let mut tweezers: Vec<usize> = coordinates.keys().copied().collect();
+        tweezers.sort_unstable();
+
+        // All gate times are computed first so that an invalid time leaves the Layout unchanged
+        let mut gate_times: Vec<((usize, usize), f64)> = Vec::new();
+        for (index, tweezer0) in tweezers.iter().enumerate() {
+            for tweezer1 in tweezers[index + 1..].iter() {
+                let (x0, y0) = coordinates[tweezer0];
+                let (x1, y1) = coordinates[tweezer1];
+                let distance = (x1 - x0).hypot(y1 - y0);
+                if distance > max_distance {
+                    continue;
+                }
+                let gate_time = time_fn(distance);
+                if !gate_time.is_finite() || gate_time < 0.0 {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Invalid gate time {} for tweezers {} and {} at distance {}.",
+                            gate_time, tweezer0, tweezer1, distance
+                        ),
+                    });
+                }
+                gate_times.push(((*tweezer0, *tweezer1), gate_time));
+            }
+        }
 
+        self.invalidate_gate_time_lookups();
+        self.qubit_to_tweezer = None;
+        self.load_layout(&layout_name)?;
         if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
-            let sqt = &mut info.tweezer_single_qubit_gate_times;
-            if let Some(present_hm) = sqt.get_mut(hqslang) {
-                present_hm.insert(tweezer, gate_time);
-            } else {
-                let mut hm = HashMap::new();
-                hm.insert(tweezer, gate_time);
-                sqt.insert(hqslang.to_string(), hm);
+            let times = info
+                .tweezer_two_qubit_gate_times
+                .entry(hqslang.to_string())
+                .or_default();
+            for ((tweezer0, tweezer1), gate_time) in gate_times {
+                times.insert((tweezer0, tweezer1), gate_time);
+                times.insert((tweezer1, tweezer0), gate_time);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set whether the device allows PragmaActiveReset operations or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_reset` - Whether the device should allow PragmaActiveReset operations or not.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The device now supports PragmaActiveReset.
+    /// * `Err(RoqoqoBackendError)` - The device isn't compatible with PragmaActiveReset.
+    pub fn set_allow_reset(&mut self, allow_reset: bool) -> Result<(), RoqoqoBackendError> {
+        self.allow_reset = allow_reset;
+        Ok(())
+    }
+
+    /// Set whether setting a two-qubit gate time for a tweezer pair also sets it for the reversed pair.
+    ///
+    /// Applies to [TweezerDevice::set_tweezer_two_qubit_gate_time],
+    /// [TweezerDevice::set_tweezer_two_qubit_gate_times] and
+    /// [TweezerDevice::unset_tweezer_two_qubit_gate_time]. Gate times set before are not changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `symmetric` - Whether the two-qubit gate times are set symmetrically.
+    pub fn set_symmetric_two_qubit_gate_times(&mut self, symmetric: bool) {
+        self.symmetric_two_qubit_gate_times = symmetric;
+    }
+
+    /// Get the native gates gate times and error rates can be set for.
+    ///
+    /// # Returns
+    ///
+    /// * `&NativeGates` - The native gates of the device.
+    pub fn get_native_gates(&self) -> &NativeGates {
+        &self.native_gates
+    }
+
+    /// Set the native gates gate times and error rates can be set for.
+    ///
+    /// Allows modeling devices supporting other native gates than the QRyd backend, e.g.
+    /// `SqrtPauliX`. The gates with gate times in a Layout of the device need to stay native.
+    ///
+    /// # Arguments
+    ///
+    /// * `native_gates` - The new native gates of the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The native gates have been set.
+    /// * `Err(RoqoqoBackendError)` - A gate with gate times in a Layout is not native anymore.
+    pub fn set_native_gates(
+        &mut self,
+        native_gates: NativeGates,
+    ) -> Result<(), RoqoqoBackendError> {
+        if let Some(layouts) = self.layouts()? {
+            for (name, info) in layouts.iter().sorted_by_key(|(name, _)| **name) {
+                let used_gates = [
+                    (
+                        info.tweezer_single_qubit_gate_times
+                            .keys()
+                            .collect::<Vec<&String>>(),
+                        &native_gates.single_qubit_gates,
+                        "single",
+                    ),
+                    (
+                        info.tweezer_two_qubit_gate_times.keys().collect(),
+                        &native_gates.two_qubit_gates,
+                        "two",
+                    ),
+                    (
+                        info.tweezer_three_qubit_gate_times.keys().collect(),
+                        &native_gates.three_qubit_gates,
+                        "three",
+                    ),
+                    (
+                        info.tweezer_multi_qubit_gate_times.keys().collect(),
+                        &native_gates.multi_qubit_gates,
+                        "multi",
+                    ),
+                ];
+                for (gates, allowed_gates, number_qubits) in used_gates {
+                    if let Some(gate) = gates
+                        .into_iter()
+                        .sorted()
+                        .find(|gate| !allowed_gates.contains(*gate))
+                    {
+                        return Err(RoqoqoBackendError::GenericError {
+                            msg: format!(
+                                "The {}-qubit gate {} has gate times in Layout {} but is not a native gate.",
+                                number_qubits, gate, name
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+        self.native_gates = native_gates;
+        Ok(())
+    }
+
+    /// Set the maximal number of qubits the hardware supports.
+    ///
+    /// Qubits with an index of at least the capacity can not be mapped to tweezers, and circuits
+    /// acting on them are rejected by the backends before they are run or posted.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_qubits` - The maximal number of qubits, None if the capacity is not limited.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The capacity has been set.
+    /// * `Err(RoqoqoBackendError)` - A qubit of the qubit -> tweezer mapping exceeds the capacity.
+    pub fn set_max_qubits(&mut self, max_qubits: Option<usize>) -> Result<(), RoqoqoBackendError> {
+        if let (Some(max_qubits), Some(qubit)) = (
+            max_qubits,
+            self.qubit_to_tweezer.iter().flat_map(HashMap::keys).max(),
+        ) {
+            if *qubit >= max_qubits {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Qubit {} is mapped to a tweezer, the hardware capacity of the device is {} qubits.",
+                        qubit, max_qubits
+                    ),
+                });
             }
         }
+        self.max_qubits = max_qubits;
         Ok(())
     }
 
-    /// Set the time of a two-qubit gate for a tweezer couple in a given Layout.
+    /// Checks that a circuit only acts on qubits within the hardware capacity of the device.
     ///
     /// # Arguments
     ///
-    /// * `hqslang` - The hqslang name of a two-qubit gate.
-    /// * `tweezer0` - The index of the first tweezer.
-    /// * `tweezer1` - The index of the second tweezer.
-    /// * `gate_time` - The the gate time for the given gate.
-    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
-    pub fn set_tweezer_two_qubit_gate_time(
-        &mut self,
-        hqslang: &str,
-        tweezer0: usize,
-        tweezer1: usize,
-        gate_time: f64,
-        layout_name: Option<String>,
-    ) -> Result<(), RoqoqoBackendError> {
-        if !ALLOWED_NATIVE_TWO_QUBIT_GATES.contains(&hqslang) {
-            return Err(RoqoqoBackendError::GenericError {
-                msg: format!(
-                    "Error setting the gate time of a two-qubit gate. Gate {} is not supported.",
-                    hqslang
-                ),
-            });
-        }
-        let layout_name = layout_name
-            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
-            .ok_or_else(|| RoqoqoBackendError::GenericError {
-                msg: "No layout name provided and no current layout set.".to_string(),
-            })?;
-        self.qubit_to_tweezer = None;
-
-        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
-            let sqt = &mut info.tweezer_two_qubit_gate_times;
-            if let Some(present_hm) = sqt.get_mut(hqslang) {
-                present_hm.insert((tweezer0, tweezer1), gate_time);
-            } else {
-                let mut hm = HashMap::new();
-                hm.insert((tweezer0, tweezer1), gate_time);
-                sqt.insert(hqslang.to_string(), hm);
+    /// * `circuit` - The circuit to check.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The circuit fits the capacity, or the capacity is not limited.
+    /// * `Err(RoqoqoBackendError)` - An operation acts on a qubit exceeding the capacity.
+    pub fn check_qubit_capacity(&self, circuit: &Circuit) -> Result<(), RoqoqoBackendError> {
+        let max_qubits = match self.max_qubits {
+            Some(max_qubits) => max_qubits,
+            None => return Ok(()),
+        };
+        for operation in circuit.iter() {
+            if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+                if let Some(qubit) = qubits.iter().find(|qubit| **qubit >= max_qubits) {
+                    return Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Operation {} acts on qubit {}, the hardware capacity of the device is {} qubits.",
+                            operation.hqslang(),
+                            qubit,
+                            max_qubits
+                        ),
+                    });
+                }
             }
         }
         Ok(())
     }
 
-    /// Set the time of a three-qubit gate for a tweezer trio in a given Layout.
+    /// Set the relaxation time T1 of the qubit in the given tweezer.
     ///
     /// # Arguments
     ///
-    /// * `hqslang` - The hqslang name of a three-qubit gate.
-    /// * `tweezer0` - The index of the first tweezer.
-    /// * `tweezer1` - The index of the second tweezer.
-    /// * `tweezer2` - The index of the third tweezer.
-    /// * `gate_time` - The the gate time for the given gate.
-    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
-    pub fn set_tweezer_three_qubit_gate_time(
-        &mut self,
-        hqslang: &str,
-        tweezer0: usize,
-        tweezer1: usize,
-        tweezer2: usize,
-        gate_time: f64,
-        layout_name: Option<String>,
-    ) -> Result<(), RoqoqoBackendError> {
-        if !ALLOWED_NATIVE_THREE_QUBIT_GATES.contains(&hqslang) {
+    /// * `tweezer` - The index of the tweezer.
+    /// * `t1` - The relaxation time.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The relaxation time has been set.
+    /// * `Err(RoqoqoBackendError)` - The relaxation time is not positive.
+    pub fn set_tweezer_t1(&mut self, tweezer: usize, t1: f64) -> Result<(), RoqoqoBackendError> {
+        if t1.is_nan() || t1 <= 0.0 {
             return Err(RoqoqoBackendError::GenericError {
-                msg: format!(
-                    "Error setting the gate time of a three-qubit gate. Gate {} is not supported.",
-                    hqslang
-                ),
+                msg: format!("The relaxation time T1 needs to be positive, got {}.", t1),
             });
         }
-        let layout_name = layout_name
-            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
-            .ok_or_else(|| RoqoqoBackendError::GenericError {
-                msg: "No layout name provided and no current layout set.".to_string(),
-            })?;
-        self.qubit_to_tweezer = None;
-
-        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
-            let sqt = &mut info.tweezer_three_qubit_gate_times;
-            if let Some(present_hm) = sqt.get_mut(hqslang) {
-                present_hm.insert((tweezer0, tweezer1, tweezer2), gate_time);
-            } else {
-                let mut hm = HashMap::new();
-                hm.insert((tweezer0, tweezer1, tweezer2), gate_time);
-                sqt.insert(hqslang.to_string(), hm);
-            }
-        }
+        self.tweezer_noise.entry(tweezer).or_default().t1 = Some(t1);
         Ok(())
     }
 
-    /// Set the time of a multi-qubit gate for a list of tweezers in a given Layout.
+    /// Set the dephasing time T2 of the qubit in the given tweezer.
     ///
     /// # Arguments
     ///
-    /// * `hqslang` - The hqslang name of a multi-qubit gate.
-    /// * `tweezers` - The list of tweezer indexes.
-    /// * `gate_time` - The the gate time for the given gate.
-    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
-    pub fn set_tweezer_multi_qubit_gate_time(
-        &mut self,
-        hqslang: &str,
-        tweezers: &[usize],
-        gate_time: f64,
-        layout_name: Option<String>,
-    ) -> Result<(), RoqoqoBackendError> {
-        if !ALLOWED_NATIVE_MULTI_QUBIT_GATES.contains(&hqslang) {
+    /// * `tweezer` - The index of the tweezer.
+    /// * `t2` - The dephasing time.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The dephasing time has been set.
+    /// * `Err(RoqoqoBackendError)` - The dephasing time is not positive.
+    pub fn set_tweezer_t2(&mut self, tweezer: usize, t2: f64) -> Result<(), RoqoqoBackendError> {
+        if t2.is_nan() || t2 <= 0.0 {
             return Err(RoqoqoBackendError::GenericError {
-                msg: format!(
-                    "Error setting the gate time of a multi-qubit gate. Gate {} is not supported.",
-                    hqslang
-                ),
+                msg: format!("The dephasing time T2 needs to be positive, got {}.", t2),
             });
         }
-        let layout_name = layout_name
-            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
-            .ok_or_else(|| RoqoqoBackendError::GenericError {
-                msg: "No layout name provided and no current layout set.".to_string(),
-            })?;
-        self.qubit_to_tweezer = None;
-
-        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
-            let sqt = &mut info.tweezer_multi_qubit_gate_times;
-            if let Some(present_hm) = sqt.get_mut(hqslang) {
-                present_hm.insert(tweezers.to_vec(), gate_time);
-            } else {
-                let mut hm = HashMap::new();
-                hm.insert(tweezers.to_vec(), gate_time);
-                sqt.insert(hqslang.to_string(), hm);
-            }
-        }
+        self.tweezer_noise.entry(tweezer).or_default().t2 = Some(t2);
         Ok(())
     }
 
-    /// Set the allowed Tweezer shifts of a specified Tweezer.
-    ///
-    /// The tweezer give the tweezer a qubit can be shifted out of. The values are lists
-    /// over the directions the qubit in the tweezer can be shifted into.
-    /// The items in the list give the allowed tweezers the qubit can be shifted into in order.
-    /// For a list 1,2,3 the qubit can be shifted into tweezer 1, into tweezer 2 if tweezer 1 is not occupied,
-    /// and into tweezer 3 if tweezer 1 and 2 are not occupied.
+    /// Set the depolarising rate of the qubit in the given tweezer.
     ///
     /// # Arguments
     ///
     /// * `tweezer` - The index of the tweezer.
-    /// * `allowed_shifts` - The allowed Tweezer shifts.
-    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
+    /// * `rate` - The depolarising rate.
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - The allowed shifts have been set.
-    /// * `Err(RoqoqoBackendError)` - The given shifts are not valid.
-    pub fn set_allowed_tweezer_shifts(
+    /// * `Ok(())` - The depolarising rate has been set.
+    /// * `Err(RoqoqoBackendError)` - The depolarising rate is negative.
+    pub fn set_tweezer_depolarising_rate(
         &mut self,
-        tweezer: &usize,
-        allowed_shifts: &[&[usize]],
-        layout_name: Option<String>,
+        tweezer: usize,
+        rate: f64,
     ) -> Result<(), RoqoqoBackendError> {
-        let layout_name = layout_name
-            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
-            .ok_or_else(|| RoqoqoBackendError::GenericError {
-                msg: "No layout name provided and no current layout set.".to_string(),
-            })?;
-
-        // Check that all the involved tweezers exist
-        if !self.is_tweezer_present(*tweezer, Some(layout_name.clone()))
-            || allowed_shifts.iter().any(|s| {
-                s.iter()
-                    .any(|t| !self.is_tweezer_present(*t, Some(layout_name.clone())))
-            })
-        {
-            return Err(RoqoqoBackendError::GenericError {
-                msg: "The given tweezer, or shifts tweezers, are not present in the device Tweezer data."
-                    .to_string(),
-            });
-        }
-        // Check the input tweezer is not present in the input allowed shifts
-        if allowed_shifts
-            .iter()
-            .any(|shift_list| shift_list.contains(tweezer))
-        {
+        if rate.is_nan() || rate < 0.0 {
             return Err(RoqoqoBackendError::GenericError {
-                msg: "The allowed shifts contain the given tweezer.".to_string(),
+                msg: format!("The depolarising rate cannot be negative, got {}.", rate),
             });
         }
-        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
-            info.allowed_tweezer_shifts
-                .entry(*tweezer)
-                .or_insert_with(Vec::new)
-                .extend(allowed_shifts.iter().map(|&slice| slice.to_vec()));
-        }
+        self.tweezer_noise
+            .entry(tweezer)
+            .or_default()
+            .depolarising_rate = Some(rate);
         Ok(())
     }
 
-    /// Set the allowed Tweezer shifts from a list of tweezers.
+    /// Get the noise parameters of the qubit in the given tweezer.
     ///
     /// # Arguments
     ///
-    /// * `row_shifts` - A list of lists, each representing a row of tweezers.
-    /// * `layout_name` - The name of the Layout to apply the gate time in. Defaults to the current Layout.
-    pub fn set_allowed_tweezer_shifts_from_rows(
+    /// * `tweezer` - The index of the tweezer.
+    ///
+    /// # Returns
+    ///
+    /// * `TweezerNoiseParameters` - The noise parameters, all unset if no noise has been set for the tweezer.
+    pub fn get_tweezer_noise(&self, tweezer: usize) -> TweezerNoiseParameters {
+        self.tweezer_noise
+            .get(&tweezer)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Remove all noise parameters from the device.
+    pub fn clear_noise(&mut self) {
+        self.tweezer_noise.clear();
+    }
+
+    /// Set the probability that the given tweezer is loaded with an atom at the start of a shot.
+    ///
+    /// The simulator samples the occupied tweezers of every shot from the loading probabilities,
+    /// tweezers without a loading probability are always loaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer` - The index of the tweezer.
+    /// * `probability` - The loading probability.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The loading probability has been set.
+    /// * `Err(RoqoqoBackendError)` - The loading probability is not between 0 and 1.
+    pub fn set_loading_probability(
         &mut self,
-        row_shifts: &[&[usize]],
-        layout_name: Option<String>,
+        tweezer: usize,
+        probability: f64,
     ) -> Result<(), RoqoqoBackendError> {
-        let layout_name = layout_name
-            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
-            .ok_or_else(|| RoqoqoBackendError::GenericError {
-                msg: "No layout name provided and no current layout set.".to_string(),
-            })?;
-
-        // Check that all the involved tweezers exist
-        if row_shifts.iter().any(|row| {
-            row.iter()
-                .any(|t| !self.is_tweezer_present(*t, Some(layout_name.clone())))
-        }) {
+        if probability.is_nan() || !(0.0..=1.0).contains(&probability) {
             return Err(RoqoqoBackendError::GenericError {
-                msg: "A given Tweezer is not present in the device Tweezer data.".to_string(),
+                msg: format!(
+                    "The loading probability needs to be between 0 and 1, got {}.",
+                    probability
+                ),
             });
         }
-        // Check that there are no repetitions in the input shifts
-        for row in row_shifts.iter() {
-            if row.iter().duplicates().count() > 0 {
-                return Err(RoqoqoBackendError::GenericError {
-                    msg: "The given Tweezers contain repetitions.".to_string(),
-                });
-            }
-        }
-
-        let allowed_shifts = &mut self
-            .layout_register
-            .as_mut()
-            .unwrap()
-            .get_mut(&layout_name)
-            .unwrap()
-            .allowed_tweezer_shifts;
+        self.array_preparation
+            .loading_probabilities
+            .insert(tweezer, probability);
+        Ok(())
+    }
 
-        // For each row in the input..
-        row_shifts.iter().for_each(|row| {
-            // ... divide in left, mid (the tweezer) and right parts
-            for i in 0..row.len() {
-                let (left_slice, mid) = row.split_at(i);
-                let mid = mid.first().unwrap_or(&0);
-                let mut vec_left = left_slice.to_vec();
-                vec_left.reverse();
-                let vec_right = &row[i + 1..].to_vec();
+    /// Get the probability that the given tweezer is loaded with an atom at the start of a shot.
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer` - The index of the tweezer.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The loading probability, 1.0 if no loading probability has been set.
+    pub fn loading_probability(&self, tweezer: usize) -> f64 {
+        self.array_preparation
+            .loading_probabilities
+            .get(&tweezer)
+            .copied()
+            .unwrap_or(1.0)
+    }
 
-                // Insert the left and right side
-                let val = allowed_shifts.entry(*mid).or_default();
-                if !vec_left.is_empty() && !val.contains(&vec_left) {
-                    val.push(vec_left.to_vec());
-                }
-                if !vec_right.is_empty() && !val.contains(vec_right) {
-                    val.push(vec_right.to_vec());
-                }
-            }
-        });
+    /// Set whether the loaded atoms are rearranged before each shot.
+    ///
+    /// With rearrangement the atoms loaded into any tweezer of the current Layout fill the
+    /// tweezers of the qubits of a circuit in ascending qubit order, so that a shot only misses
+    /// qubits if fewer atoms than qubits have been loaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `rearrangement` - Whether the loaded atoms are rearranged.
+    pub fn set_rearrangement(&mut self, rearrangement: bool) {
+        self.array_preparation.rearrangement = rearrangement;
+    }
 
+    /// Set the probability that the atom of a qubit is lost when its tweezer is shifted.
+    ///
+    /// The simulator samples the loss of every qubit shifted by a PragmaShiftQubitsTweezers, see
+    /// [AtomLoss].
+    ///
+    /// # Arguments
+    ///
+    /// * `probability` - The loss probability per shifted qubit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The shift loss probability has been set.
+    /// * `Err(RoqoqoBackendError)` - The loss probability is not between 0 and 1.
+    pub fn set_shift_loss_probability(
+        &mut self,
+        probability: f64,
+    ) -> Result<(), RoqoqoBackendError> {
+        check_loss_probability(probability)?;
+        self.atom_loss.shift_loss_probability = probability;
         Ok(())
     }
 
-    /// Set the tweezer per row value for a given Layout.
+    /// Get the probability that the atom of a qubit is lost when its tweezer is shifted.
     ///
-    /// This is needed for dynamically switching layouts during circuit execution.
-    /// Only switching between layouts having the same tweezer per row value is supported.
+    /// # Returns
+    ///
+    /// * `f64` - The loss probability per shifted qubit.
+    pub fn shift_loss_probability(&self) -> f64 {
+        self.atom_loss.shift_loss_probability
+    }
+
+    /// Set the probability that the atom of a qubit is lost when the qubit is measured.
+    ///
+    /// The simulator samples the loss of every qubit measured by a MeasureQubit, see [AtomLoss].
     ///
     /// # Arguments
     ///
-    /// * `tweezers_per_row` - Vector containing the number of tweezers per row to set.
-    /// * `layout_name` - The name of the Layout to set the tweezer per row for. Defaults to the current Layout.
-    pub fn set_tweezers_per_row(
+    /// * `probability` - The loss probability per measured qubit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The measurement loss probability has been set.
+    /// * `Err(RoqoqoBackendError)` - The loss probability is not between 0 and 1.
+    pub fn set_measurement_loss_probability(
         &mut self,
-        tweezers_per_row: Vec<usize>,
-        layout_name: Option<String>,
+        probability: f64,
     ) -> Result<(), RoqoqoBackendError> {
-        let layout_name = layout_name
-            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
-            .ok_or_else(|| RoqoqoBackendError::GenericError {
-                msg: "No layout name provided and no current layout set.".to_string(),
-            })?;
+        check_loss_probability(probability)?;
+        self.atom_loss.measurement_loss_probability = probability;
+        Ok(())
+    }
 
-        if let Some(info) = self.layout_register.as_mut().unwrap().get_mut(&layout_name) {
-            info.tweezers_per_row = Some(tweezers_per_row);
+    /// Get the probability that the atom of a qubit is lost when the qubit is measured.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The loss probability per measured qubit.
+    pub fn measurement_loss_probability(&self) -> f64 {
+        self.atom_loss.measurement_loss_probability
+    }
+
+    /// Returns true if atoms can be lost during shifts or measurements.
+    #[cfg(feature = "simulator")]
+    pub(crate) fn has_atom_loss(&self) -> bool {
+        self.atom_loss.shift_loss_probability > 0.0
+            || self.atom_loss.measurement_loss_probability > 0.0
+    }
+
+    /// Returns true if a tweezer has a loading probability below 1.
+    #[cfg(feature = "simulator")]
+    pub(crate) fn has_stochastic_loading(&self) -> bool {
+        self.array_preparation
+            .loading_probabilities
+            .values()
+            .any(|probability| *probability < 1.0)
+    }
+
+    /// Returns the tweezers of the current Layout, in ascending order.
+    #[cfg(feature = "simulator")]
+    pub(crate) fn current_layout_tweezers(&self) -> Vec<usize> {
+        match self.max_tweezer() {
+            Ok(Some(max_tweezer)) => (0..=max_tweezer)
+                .filter(|tweezer| self.is_tweezer_present(*tweezer, None))
+                .collect(),
+            _ => Vec::new(),
         }
+    }
 
-        Ok(())
+    /// Returns the capability manifest of the device as a compact JSON document.
+    ///
+    /// The manifest summarizes the gates, connectivity and limits of the current Layout, the
+    /// phase relations and the noise parameters for external schedulers and frontends. The
+    /// schema is documented in the [crate::capabilities] module and can be deserialized into a
+    /// [crate::CapabilityManifest].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The JSON capability manifest.
+    /// * `Err(RoqoqoBackendError)` - The current Layout could not be read or the manifest could not be serialized.
+    pub fn capability_manifest(&self) -> Result<String, RoqoqoBackendError> {
+        let manifest = capability_manifest(self)?;
+        serde_json::to_string(&manifest).map_err(|err| RoqoqoBackendError::GenericError {
+            msg: format!("Could not serialize the capability manifest: {}", err),
+        })
     }
 
-    /// Set whether the device allows PragmaActiveReset operations or not.
+    /// Turns the device into a GenericDevice, approximating the gates it can not represent.
     ///
-    /// # Arguments
+    /// The qubits of the GenericDevice are the qubits of the device, the gate times of the
+    /// current Layout are translated through the qubit -> tweezer mapping and gates on tweezers
+    /// not holding a qubit are skipped. GenericDevice has no three-qubit gate table, three-qubit
+    /// gate times are approximated by multi-qubit gate times on the same qubits.
     ///
-    /// * `allow_reset` - Whether the device should allow PragmaActiveReset operations or not.
+    /// # Returns
+    ///
+    /// * `Ok(GenericDevice)` - The device in generic representation.
+    /// * `Err(RoqoqoBackendError)` - No current Layout is set.
+    pub fn try_to_generic_device(&self) -> Result<GenericDevice, RoqoqoBackendError> {
+        self.build_generic_device(false)
+    }
+
+    /// Turns the device into a GenericDevice, failing if gate times of the current Layout would be lost.
+    ///
+    /// Unlike [TweezerDevice::try_to_generic_device] no gate is skipped or approximated.
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - The device now supports PragmaActiveReset.
-    /// * `Err(RoqoqoBackendError)` - The device isn't compatible with PragmaActiveReset.
-    pub fn set_allow_reset(&mut self, allow_reset: bool) -> Result<(), RoqoqoBackendError> {
-        self.allow_reset = allow_reset;
-        Ok(())
+    /// * `Ok(GenericDevice)` - The device in generic representation.
+    /// * `Err(RoqoqoBackendError)` - No current Layout is set, a gate is defined on a tweezer not holding a qubit
+    ///                               or a three-qubit gate is defined.
+    pub fn to_generic_device_checked(&self) -> Result<GenericDevice, RoqoqoBackendError> {
+        self.build_generic_device(true)
+    }
+
+    /// Checks the device for inconsistencies.
+    ///
+    /// All Layouts are checked for invalid gate times, two-qubit gates defined for only one order
+    /// of a tweezer pair, shifts starting from or leading into tweezers that are not present and
+    /// tweezers per row differing from those of the current Layout. The current and the default
+    /// Layout need to be present and the qubits need to be mapped to tweezers of the current Layout.
+    /// See [crate::DeviceIssue] for all checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The device is consistent.
+    /// * `Err(Vec<DeviceIssue>)` - All issues found, sorted by the Layout they are found in.
+    pub fn validate(&self) -> Result<(), Vec<DeviceIssue>> {
+        let issues = device_issues(self);
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Set the number of gate time lookups memoized by the device.
+    ///
+    /// The gate times are cached by the gate and the tweezers it acts on for the current layout,
+    /// the qubit to tweezer mapping is resolved on every lookup. The cache is emptied whenever
+    /// the gate times are changed or a gate time is looked up in a different layout, also after
+    /// changing the public `current_layout` field directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximal number of cached gate times, zero disables the cache.
+    pub fn set_gate_time_cache_capacity(&mut self, capacity: usize) {
+        self.gate_time_cache.set_capacity(capacity);
+    }
+
+    /// Returns the maximal number of cached gate times, zero if the cache is disabled.
+    pub fn gate_time_cache_capacity(&self) -> usize {
+        self.gate_time_cache.capacity()
+    }
+
+    /// Remove all cached gate times.
+    ///
+    /// Also discards the dense two-qubit gate time tables built for layouts where most tweezer
+    /// pairs have a gate time.
+    pub fn clear_gate_time_cache(&mut self) {
+        self.invalidate_gate_time_lookups();
     }
 
     /// Set the name of the default layout to use and switch to it.
@@ -866,13 +4456,32 @@ impl TweezerDevice {
     /// * `Ok(())` - The default layout has been set and switched to.
     /// * `Err(RoqoqoBackendError)` - The given layout name is not present in the layout register.
     pub fn set_default_layout(&mut self, layout: &str) -> Result<(), RoqoqoBackendError> {
-        if !self._extract_layout_register()?.contains_key(layout) {
+        self.set_default_layout_with_policy(layout, TrivialMappingPolicy::AllTweezers)
+    }
+
+    /// Set the name of the default layout to use and switch to it with a mapping policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The name of the layout to use.
+    /// * `policy` - The policy populating the qubit -> tweezer mapping in the layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The default layout has been set and switched to.
+    /// * `Err(RoqoqoBackendError)` - The given layout name is not present in the layout register.
+    pub fn set_default_layout_with_policy(
+        &mut self,
+        layout: &str,
+        policy: TrivialMappingPolicy,
+    ) -> Result<(), RoqoqoBackendError> {
+        if self.layout_info(layout)?.is_none() {
             return Err(RoqoqoBackendError::GenericError {
                 msg: "The given layout name is not present in the layout register.".to_string(),
             });
         }
         self.default_layout = Some(layout.to_string());
-        self.switch_layout(layout, None)?;
+        self.switch_layout_with_policy(layout, policy)?;
         Ok(())
     }
 
@@ -900,6 +4509,72 @@ impl TweezerDevice {
         }
     }
 
+    /// Returns whether a qubit is mapped to the given tweezer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer` - The index of the tweezer.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the tweezer holds a qubit according to the qubit -> tweezer mapping.
+    pub fn is_tweezer_occupied(&self, tweezer: usize) -> bool {
+        self.qubit_to_tweezer
+            .as_ref()
+            .is_some_and(|map| map.values().any(|occupied| *occupied == tweezer))
+    }
+
+    /// Returns the tweezers holding a qubit according to the qubit -> tweezer mapping.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<usize>` - The occupied tweezers, in ascending order.
+    pub fn occupied_tweezers(&self) -> Vec<usize> {
+        match &self.qubit_to_tweezer {
+            Some(map) => map
+                .values()
+                .copied()
+                .collect::<BTreeSet<usize>>()
+                .into_iter()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the tweezers of a Layout that do not hold a qubit.
+    ///
+    /// The tweezers of a Layout are the tweezers with a gate time. The occupation is given by
+    /// the qubit -> tweezer mapping of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout_name` - The name of the Layout. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<usize>)` - The free tweezers of the Layout, in ascending order.
+    /// * `Err(RoqoqoBackendError)` - No layout name is provided and no current layout is set, or the layout is not present.
+    pub fn free_tweezers(
+        &self,
+        layout_name: Option<String>,
+    ) -> Result<Vec<usize>, RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.clone())
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        let info =
+            self.layout_info(&layout_name)?
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: "The given layout name is not present in the layout register.".to_string(),
+                })?;
+        let occupied: HashSet<usize> = self.occupied_tweezers().into_iter().collect();
+        Ok(layout_tweezers(info)
+            .into_iter()
+            .filter(|tweezer| !occupied.contains(tweezer))
+            .collect())
+    }
+
     /// Get the names of the available gates in the given layout.
     ///
     /// # Arguments
@@ -921,7 +4596,7 @@ impl TweezerDevice {
             })?;
 
         let mut names: HashSet<&str> = HashSet::new();
-        if let Some(info) = self._extract_layout_register()?.get(&layout_name) {
+        if let Some(info) = self.layout_info(&layout_name)? {
             let sqg = &info.tweezer_single_qubit_gate_times;
             for name in sqg.keys().by_ref() {
                 names.insert(name);
@@ -942,35 +4617,334 @@ impl TweezerDevice {
                 names.insert(name);
             }
         }
-        Ok(names.into_iter().collect())
+        Ok(names.into_iter().collect())
+    }
+
+    /// Get the names of the gates available on a tweezer in the given layout.
+    ///
+    /// Returns the single-qubit gates defined on the tweezer and the two-, three- and multi-qubit
+    /// gates defined on at least one group of tweezers including it.
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer` - The index of the tweezer.
+    /// * `layout_name` - The name of the layout. Defaults to the current Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<&str>)` - The sorted names of the gates available on the tweezer.
+    /// * `Err(RoqoqoBackendError)` - No layout name provided and no current layout set, or the layout is not present.
+    pub fn get_available_gates_for_tweezer(
+        &self,
+        tweezer: usize,
+        layout_name: Option<String>,
+    ) -> Result<Vec<&str>, RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.clone())
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        let info =
+            self.layout_info(&layout_name)?
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: "The given layout name is not present in the layout register.".to_string(),
+                })?;
+
+        let mut names: BTreeSet<&str> = BTreeSet::new();
+        for (name, times) in info.tweezer_single_qubit_gate_times.iter() {
+            if times.contains_key(&tweezer) {
+                names.insert(name);
+            }
+        }
+        for (name, times) in info.tweezer_two_qubit_gate_times.iter() {
+            if times
+                .keys()
+                .any(|(t0, t1)| *t0 == tweezer || *t1 == tweezer)
+            {
+                names.insert(name);
+            }
+        }
+        for (name, times) in info.tweezer_three_qubit_gate_times.iter() {
+            if times
+                .keys()
+                .any(|(t0, t1, t2)| *t0 == tweezer || *t1 == tweezer || *t2 == tweezer)
+            {
+                names.insert(name);
+            }
+        }
+        for (name, times) in info.tweezer_multi_qubit_gate_times.iter() {
+            if times.keys().any(|tweezers| tweezers.contains(&tweezer)) {
+                names.insert(name);
+            }
+        }
+        Ok(names.into_iter().collect())
+    }
+
+    /// Deactivate the given qubit in the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubit` - The input qubit identifier.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<usize,usize>)` - The updated qubit -> tweezer mapping.
+    /// * `Err(RoqoqoBackendError)` - If the given qubit identifier is not present in the mapping.
+    pub fn deactivate_qubit(
+        &mut self,
+        qubit: usize,
+    ) -> Result<HashMap<usize, usize>, RoqoqoBackendError> {
+        self.invalidate_gate_time_lookups();
+        if let Some(map) = &mut self.qubit_to_tweezer {
+            if map.remove(&qubit).is_none() {
+                Err(RoqoqoBackendError::GenericError {
+                    msg: "The given qubit is not present in the Layout.".to_string(),
+                })
+            } else {
+                Ok(map.clone())
+            }
+        } else {
+            Err(RoqoqoBackendError::GenericError {
+                msg: "The device qubit -> tweezer mapping is empty.".to_string(),
+            })
+        }
+    }
+
+    /// Exchanges the qubits of two occupied tweezers of the current Layout.
+    ///
+    /// The qubit -> tweezer mapping is only changed if both tweezers hold a qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `tweezer_0` - The first tweezer of the exchange.
+    /// * `tweezer_1` - The second tweezer of the exchange.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The qubits of the tweezers have been exchanged.
+    /// * `Err(RoqoqoBackendError)` - The tweezers are the same, not present in the current Layout or do not hold a qubit.
+    pub fn swap_tweezers(
+        &mut self,
+        tweezer_0: usize,
+        tweezer_1: usize,
+    ) -> Result<(), RoqoqoBackendError> {
+        let (qubit_0, qubit_1) = self.tweezer_swap_qubits(tweezer_0, tweezer_1)?;
+        self.invalidate_gate_time_lookups();
+        if let Some(map) = &mut self.qubit_to_tweezer {
+            map.insert(qubit_0, tweezer_1);
+            map.insert(qubit_1, tweezer_0);
+        }
+        Ok(())
+    }
+
+    /// Returns the qubits held by two tweezers that are exchanged, see [TweezerDevice::swap_tweezers].
+    fn tweezer_swap_qubits(
+        &self,
+        tweezer_0: usize,
+        tweezer_1: usize,
+    ) -> Result<(usize, usize), RoqoqoBackendError> {
+        if tweezer_0 == tweezer_1 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("Tweezer {} can not be swapped with itself.", tweezer_0),
+            });
+        }
+        let map =
+            self.qubit_to_tweezer
+                .as_ref()
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: "The device qubit -> tweezer mapping is empty: no qubits to swap."
+                        .to_string(),
+                })?;
+        let tweezers = layout_tweezers(self.get_current_layout_info()?);
+        let held_qubit = |tweezer: usize| -> Result<usize, RoqoqoBackendError> {
+            if !tweezers.contains(&tweezer) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!("Tweezer {} is not present in the current Layout.", tweezer),
+                });
+            }
+            map.iter()
+                .find_map(|(qubit, mapped)| (*mapped == tweezer).then_some(*qubit))
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!("Tweezer {} does not hold a qubit.", tweezer),
+                })
+        };
+        Ok((held_qubit(tweezer_0)?, held_qubit(tweezer_1)?))
+    }
+
+    /// Resolves a PragmaMoveToZone operation into the shifts applied by the device.
+    ///
+    /// The qubits are moved in the given order. A qubit already in the zone is not moved, any
+    /// other qubit is shifted into the nearest free tweezer of the zone it can reach with one
+    /// allowed shift. The distance is taken from the tweezer coordinates of the current Layout if
+    /// both tweezers have coordinates, otherwise it is the number of tweezers passed. Tweezers
+    /// at the same distance are chosen by their index.
+    ///
+    /// # Arguments
+    ///
+    /// * `pragma` - The PragmaMoveToZone operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PragmaShiftQubitsTweezers)` - The sequential shifts moving the qubits into the zone.
+    /// * `Err(RoqoqoBackendError)` - A qubit is not mapped or listed twice, the zone is not present in the current Layout or no free tweezer of the zone can be reached.
+    pub fn resolve_zone_move(
+        &self,
+        pragma: &PragmaMoveToZone,
+    ) -> Result<PragmaShiftQubitsTweezers, RoqoqoBackendError> {
+        let mut qubit_to_tweezer =
+            self.qubit_to_tweezer
+                .clone()
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: "The device qubit -> tweezer mapping is empty: no qubits to move."
+                        .to_string(),
+                })?;
+        let info = self.get_current_layout_info()?;
+        let in_zone = |tweezer: &usize| {
+            info.tweezer_zones
+                .get(tweezer)
+                .is_some_and(|zone| zone == &pragma.zone)
+        };
+        if !info.tweezer_zones.keys().any(in_zone) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("Zone {} is not present in the current Layout.", pragma.zone),
+            });
+        }
+        let mut moved_qubits: HashSet<usize> = HashSet::new();
+        let mut shifts: Vec<(usize, usize)> = Vec::new();
+        for qubit in pragma.qubits.iter() {
+            if !moved_qubits.insert(*qubit) {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!("Qubit {} is moved more than once.", qubit),
+                });
+            }
+            let start =
+                *qubit_to_tweezer
+                    .get(qubit)
+                    .ok_or_else(|| RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Qubit {} is not present in the qubit -> tweezer mapping.",
+                            qubit
+                        ),
+                    })?;
+            if in_zone(&start) {
+                continue;
+            }
+            let directions: &[Vec<usize>] = info
+                .allowed_tweezer_shifts
+                .get(&start)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let mut nearest: Option<(f64, usize)> = None;
+            for direction in directions {
+                for (position, tweezer) in direction.iter().enumerate() {
+                    // The tweezers behind an occupied tweezer can not be reached
+                    if qubit_to_tweezer.values().any(|other| other == tweezer) {
+                        break;
+                    }
+                    // A shift follows the first direction containing its end tweezer
+                    if !in_zone(tweezer)
+                        || directions
+                            .iter()
+                            .find(|other| other.contains(tweezer))
+                            .is_some_and(|first| first != direction)
+                    {
+                        continue;
+                    }
+                    let distance = match (
+                        info.tweezer_coordinates.get(&start),
+                        info.tweezer_coordinates.get(tweezer),
+                    ) {
+                        (Some((x0, y0)), Some((x1, y1))) => (x0 - x1).hypot(y0 - y1),
+                        _ => (position + 1) as f64,
+                    };
+                    let is_nearest = match nearest {
+                        Some((nearest_distance, nearest_tweezer)) => {
+                            distance < nearest_distance
+                                || (distance == nearest_distance && *tweezer < nearest_tweezer)
+                        }
+                        None => true,
+                    };
+                    if is_nearest {
+                        nearest = Some((distance, *tweezer));
+                    }
+                }
+            }
+            let (_, end) = nearest.ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "No free tweezer of zone {} can be reached from tweezer {} holding qubit {}.",
+                    pragma.zone, start, qubit
+                ),
+            })?;
+            qubit_to_tweezer.insert(*qubit, end);
+            shifts.push((start, end));
+        }
+        Ok(PragmaShiftQubitsTweezers::new(shifts))
     }
 
-    /// Deactivate the given qubit in the device.
+    /// Registers a custom phi-theta relation the phase relations of the device can refer to.
+    ///
+    /// A relation registered under the name of an existing custom relation replaces it.
     ///
     /// # Arguments
     ///
-    /// * `qubit` - The input qubit identifier.
+    /// * `name` - The name of the relation.
+    /// * `relation` - The relation.
     ///
     /// # Returns
     ///
-    /// * `Ok(HashMap<usize,usize>)` - The updated qubit -> tweezer mapping.
-    /// * `Err(RoqoqoBackendError)` - If the given qubit identifier is not present in the mapping.
-    pub fn deactivate_qubit(
+    /// * `Ok(())` - The relation has been registered.
+    /// * `Err(RoqoqoBackendError)` - The name is empty, a number or the name of a builtin relation.
+    pub fn add_phi_theta_relation(
         &mut self,
-        qubit: usize,
-    ) -> Result<HashMap<usize, usize>, RoqoqoBackendError> {
-        if let Some(map) = &mut self.qubit_to_tweezer {
-            if map.remove(&qubit).is_none() {
-                Err(RoqoqoBackendError::GenericError {
-                    msg: "The given qubit is not present in the Layout.".to_string(),
-                })
-            } else {
-                Ok(map.clone())
-            }
-        } else {
-            Err(RoqoqoBackendError::GenericError {
-                msg: "The device qubit -> tweezer mapping is empty.".to_string(),
-            })
+        name: &str,
+        relation: PhiThetaRelation,
+    ) -> Result<(), RoqoqoBackendError> {
+        if name.is_empty()
+            || f64::from_str(name).is_ok()
+            || phi_theta_relation(name, std::f64::consts::PI).is_some()
+        {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Error adding the phi-theta relation {}. The name is empty, a number or the name of a builtin relation.",
+                    name
+                ),
+            });
+        }
+        self.invalidate_gate_time_lookups();
+        self.phi_theta_relations.insert(name.to_string(), relation);
+        Ok(())
+    }
+
+    /// Removes a custom phi-theta relation.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the relation.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(PhiThetaRelation)` - The removed relation.
+    /// * `None` - No custom relation with the name is registered.
+    pub fn remove_phi_theta_relation(&mut self, name: &str) -> Option<PhiThetaRelation> {
+        self.invalidate_gate_time_lookups();
+        self.phi_theta_relations.remove(name)
+    }
+
+    /// Returns phi for the given theta according to a custom or builtin phi-theta relation.
+    ///
+    /// # Arguments
+    ///
+    /// * `relation_name` - The name of the relation.
+    /// * `theta` - The theta angle.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The phi angle.
+    /// * `None` - The relation does not exist or is not defined for theta.
+    pub fn phi_theta_relation(&self, relation_name: &str, theta: f64) -> Option<f64> {
+        match self.phi_theta_relations.get(relation_name) {
+            Some(relation) => relation.phi(theta),
+            None => phi_theta_relation(relation_name, theta),
         }
     }
 
@@ -983,7 +4957,7 @@ impl TweezerDevice {
         if let Ok(phase_shift_value) = f64::from_str(&self.controlled_z_phase_relation) {
             Some(phase_shift_value)
         } else {
-            phi_theta_relation(&self.controlled_z_phase_relation, std::f64::consts::PI)
+            self.phi_theta_relation(&self.controlled_z_phase_relation, std::f64::consts::PI)
         }
     }
 
@@ -996,7 +4970,51 @@ impl TweezerDevice {
         if let Ok(phase_shift_value) = f64::from_str(&self.controlled_phase_phase_relation) {
             Some(phase_shift_value)
         } else {
-            phi_theta_relation(&self.controlled_phase_phase_relation, theta)
+            self.phi_theta_relation(&self.controlled_phase_phase_relation, theta)
+        }
+    }
+
+    /// Sets the phase relations of the PhaseShiftedControlledZ and PhaseShiftedControlledPhase gates.
+    ///
+    /// A relation is either a phase given as a number or the name of a builtin or registered
+    /// custom phi-theta relation. The device is left unchanged if one of the relations is not valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `cz_relation` - The relation to use for the PhaseShiftedControlledZ gate.
+    /// * `cp_relation` - The relation to use for the PhaseShiftedControlledPhase gate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The phase relations have been set.
+    /// * `Err(RoqoqoBackendError)` - A relation is neither a number nor a known phi-theta relation.
+    pub fn set_phase_relations(
+        &mut self,
+        cz_relation: &str,
+        cp_relation: &str,
+    ) -> Result<(), RoqoqoBackendError> {
+        self.validate_phase_relation(cz_relation)?;
+        self.validate_phase_relation(cp_relation)?;
+        self.invalidate_gate_time_lookups();
+        self.controlled_z_phase_relation = cz_relation.to_string();
+        self.controlled_phase_phase_relation = cp_relation.to_string();
+        Ok(())
+    }
+
+    /// Checks whether a phase relation is a number or a builtin or registered phi-theta relation.
+    fn validate_phase_relation(&self, relation: &str) -> Result<(), RoqoqoBackendError> {
+        if f64::from_str(relation).is_ok()
+            || self.phi_theta_relations.contains_key(relation)
+            || phi_theta_relation(relation, std::f64::consts::PI).is_some()
+        {
+            Ok(())
+        } else {
+            Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "The phase relation {} is neither a number nor a builtin or registered phi-theta relation.",
+                    relation
+                ),
+            })
         }
     }
 
@@ -1028,6 +5046,9 @@ impl TweezerDevice {
 
     /// Returns the gate time of a PhaseShiftedControlledPhase operation with the given qubits and phi and theta angles.
     ///
+    /// If a gate time curve is set for the tweezers of the qubits with
+    /// [TweezerDevice::set_controlled_phase_time_curve], the gate time is interpolated from it.
+    ///
     /// # Arguments
     ///
     /// * `control` - The control qubit the gate acts on
@@ -1052,7 +5073,13 @@ impl TweezerDevice {
         {
             if let Some(relation_phi) = self.phase_shift_controlled_phase(theta) {
                 if (relation_phi.abs() - phi.abs()).abs() < 0.0001 {
-                    return Some(1e-6);
+                    let curve = self.get_current_layout_info().ok().and_then(|info| {
+                        info.controlled_phase_time_curves.get(&(
+                            self.get_tweezer_from_qubit(control).ok()?,
+                            self.get_tweezer_from_qubit(target).ok()?,
+                        ))
+                    });
+                    return Some(curve.map_or(1e-6, |curve| interpolate_time_curve(curve, theta)));
                 }
             }
         }
@@ -1094,7 +5121,7 @@ impl TweezerDevice {
     ) -> Result<usize, RoqoqoBackendError> {
         let mut set_tweezer_indices: HashSet<usize> = HashSet::new();
         let tweezer_info = if let Some(layout_name) = layout_name {
-            if let Some(tw) = self._extract_layout_register()?.get(&layout_name) {
+            if let Some(tw) = self.layout_info(&layout_name)? {
                 tw
             } else {
                 return Err(RoqoqoBackendError::GenericError {
@@ -1133,15 +5160,109 @@ impl TweezerDevice {
         Ok(set_tweezer_indices.len())
     }
 
+    /// Builds the GenericDevice of the current Layout, failing instead of skipping or
+    /// approximating gates if `lossless` is set.
+    fn build_generic_device(&self, lossless: bool) -> Result<GenericDevice, RoqoqoBackendError> {
+        let info = self.get_current_layout_info()?;
+        let tweezer_to_qubit: HashMap<usize, usize> = self
+            .qubit_to_tweezer
+            .iter()
+            .flatten()
+            .map(|(qubit, tweezer)| (*tweezer, *qubit))
+            .collect();
+        let qubits = |hqslang: &str,
+                      tweezers: &[usize]|
+         -> Result<Option<Vec<usize>>, RoqoqoBackendError> {
+            match tweezers
+                .iter()
+                .map(|tweezer| tweezer_to_qubit.get(tweezer).copied())
+                .collect::<Option<Vec<usize>>>()
+            {
+                Some(qubits) => Ok(Some(qubits)),
+                None if lossless => Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Gate {} on tweezers {:?} can not be converted, not all tweezers hold a qubit.",
+                        hqslang, tweezers
+                    ),
+                }),
+                None => Ok(None),
+            }
+        };
+        let to_backend_error = |err: RoqoqoError| RoqoqoBackendError::GenericError {
+            msg: format!("Could not convert the device into a GenericDevice: {}", err),
+        };
+
+        let mut generic_device = GenericDevice::new(self.number_qubits());
+        for (hqslang, times) in info.tweezer_single_qubit_gate_times.iter() {
+            for (tweezer, time) in times.iter() {
+                if let Some(qubits) = qubits(hqslang, &[*tweezer])? {
+                    generic_device
+                        .set_single_qubit_gate_time(hqslang, qubits[0], *time)
+                        .map_err(to_backend_error)?;
+                }
+            }
+        }
+        for (hqslang, times) in info.tweezer_two_qubit_gate_times.iter() {
+            for ((tweezer0, tweezer1), time) in times.iter() {
+                if let Some(qubits) = qubits(hqslang, &[*tweezer0, *tweezer1])? {
+                    generic_device
+                        .set_two_qubit_gate_time(hqslang, qubits[0], qubits[1], *time)
+                        .map_err(to_backend_error)?;
+                }
+            }
+        }
+        for (hqslang, times) in info.tweezer_three_qubit_gate_times.iter() {
+            if lossless && !times.is_empty() {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "Three-qubit gate {} can not be represented by a GenericDevice.",
+                        hqslang
+                    ),
+                });
+            }
+            for ((tweezer0, tweezer1, tweezer2), time) in times.iter() {
+                if let Some(qubits) = qubits(hqslang, &[*tweezer0, *tweezer1, *tweezer2])? {
+                    generic_device
+                        .set_multi_qubit_gate_time(hqslang, qubits, *time)
+                        .map_err(to_backend_error)?;
+                }
+            }
+        }
+        for (hqslang, times) in info.tweezer_multi_qubit_gate_times.iter() {
+            for (tweezers, time) in times.iter() {
+                if let Some(qubits) = qubits(hqslang, tweezers)? {
+                    generic_device
+                        .set_multi_qubit_gate_time(hqslang, qubits, *time)
+                        .map_err(to_backend_error)?;
+                }
+            }
+        }
+        for qubit in 0..self.number_qubits() {
+            if let Some(rates) = self.qubit_decoherence_rates(&qubit) {
+                generic_device
+                    .set_qubit_decoherence_rates(qubit, rates)
+                    .map_err(to_backend_error)?;
+            }
+        }
+        Ok(generic_device)
+    }
+
+    /// Discards the cached gate times and the dense gate time tables.
+    fn invalidate_gate_time_lookups(&mut self) {
+        self.gate_time_cache.invalidate();
+        self.dense_gate_times.invalidate();
+    }
+
     #[inline]
-    fn get_current_layout_info(&self) -> Result<&TweezerLayoutInfo, RoqoqoBackendError> {
+    pub(crate) fn get_current_layout_info(&self) -> Result<&TweezerLayoutInfo, RoqoqoBackendError> {
         if let Some(current) = &self.current_layout {
-            Ok(self
-                .layout_register
-                .as_ref()
-                .unwrap()
-                .get(current)
-                .expect("Unexpectedly did not find current layout. Bug in roqoqo-qryd."))
+            self.layout_info(current)?
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "The current layout {} is not present in the layout register.",
+                        current
+                    ),
+                })
         } else {
             Err(RoqoqoBackendError::GenericError {
                 msg: "Tried to access current layout info but no current layout is set."
@@ -1156,10 +5277,9 @@ impl TweezerDevice {
             return true;
         }
         let tweezer_info = if let Some(x) = layout_name {
-            self.layout_register
-                .as_ref()
-                .unwrap()
-                .get(&x)
+            self.layout_info(&x)
+                .ok()
+                .flatten()
                 .expect("The specified layout does not exist.")
         } else {
             self.get_current_layout_info().unwrap()
@@ -1263,6 +5383,54 @@ impl TweezerDevice {
         }
     }
 
+    /// Populates the qubit -> tweezer mapping of the current Layout according to a policy.
+    fn apply_mapping_policy(
+        &mut self,
+        policy: TrivialMappingPolicy,
+    ) -> Result<(), RoqoqoBackendError> {
+        match policy {
+            TrivialMappingPolicy::AllTweezers => {
+                if self.qubit_to_tweezer.is_none() {
+                    self.qubit_to_tweezer = Some(self.new_trivial_mapping());
+                }
+            }
+            TrivialMappingPolicy::UsableTweezers => {
+                if self.qubit_to_tweezer.is_none() {
+                    let tweezers = layout_tweezers(self.get_current_layout_info()?);
+                    self.qubit_to_tweezer = Some(tweezers.into_iter().enumerate().collect());
+                }
+            }
+            TrivialMappingPolicy::OccupiedFirst => {
+                let tweezers = layout_tweezers(self.get_current_layout_info()?);
+                let mut mapping: HashMap<usize, usize> = self
+                    .qubit_to_tweezer
+                    .iter()
+                    .flatten()
+                    .filter(|(_, tweezer)| tweezers.contains(tweezer))
+                    .map(|(qubit, tweezer)| (*qubit, *tweezer))
+                    .collect();
+                let occupied: HashSet<usize> = mapping.values().copied().collect();
+                let mut qubit = 0;
+                for tweezer in tweezers
+                    .into_iter()
+                    .filter(|tweezer| !occupied.contains(tweezer))
+                {
+                    while mapping.contains_key(&qubit) {
+                        qubit += 1;
+                    }
+                    mapping.insert(qubit, tweezer);
+                }
+                self.qubit_to_tweezer = Some(mapping);
+            }
+            TrivialMappingPolicy::NoMapping => (),
+        }
+        // Qubits exceeding the hardware capacity are not mapped
+        if let (Some(max_qubits), Some(mapping)) = (self.max_qubits, &mut self.qubit_to_tweezer) {
+            mapping.retain(|qubit, _| *qubit < max_qubits);
+        }
+        Ok(())
+    }
+
     fn _extract_layout_register(
         &self,
     ) -> Result<&HashMap<String, TweezerLayoutInfo>, RoqoqoBackendError> {
@@ -1274,94 +5442,373 @@ impl TweezerDevice {
         }
     }
 
-    fn _are_all_shifts_valid(&mut self, pragma: &PragmaShiftQubitsTweezers) -> bool {
-        #[inline]
-        fn _is_tweezer_in_shift_lists(tweezer_id: &usize, shift_lists: &[Vec<usize>]) -> bool {
-            shift_lists.iter().any(|list| list.contains(tweezer_id))
+    /// Returns the mutable information of a Layout for changing its entries.
+    ///
+    /// Like the gate time setters, the gate time lookups are invalidated and the
+    /// qubit -> tweezer mapping is reset.
+    fn layout_info_mut(
+        &mut self,
+        layout_name: Option<String>,
+    ) -> Result<&mut TweezerLayoutInfo, RoqoqoBackendError> {
+        let layout_name = layout_name
+            .or_else(|| self.current_layout.as_ref().map(|s| s.to_string()))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "No layout name provided and no current layout set.".to_string(),
+            })?;
+        self.load_layout(&layout_name)?;
+        let info = self
+            .layout_register
+            .as_mut()
+            .and_then(|register| register.get_mut(&layout_name))
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: "The given layout name is not present in the layout register.".to_string(),
+            })?;
+        self.gate_time_cache.invalidate();
+        self.dense_gate_times.invalidate();
+        self.qubit_to_tweezer = None;
+        Ok(info)
+    }
+
+    /// Returns the information of a Layout, deserializing it if it has not been loaded yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the Layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(&TweezerLayoutInfo))` - The information of the Layout.
+    /// * `Ok(None)` - The Layout is not present in the Layout register.
+    /// * `Err(RoqoqoBackendError)` - The device has no Layout register or the Layout could not
+    ///                               be deserialized.
+    pub fn layout_info(
+        &self,
+        name: &str,
+    ) -> Result<Option<&TweezerLayoutInfo>, RoqoqoBackendError> {
+        if let Some(info) = self._extract_layout_register()?.get(name) {
+            return Ok(Some(info));
+        }
+        self.serialized_layouts
+            .get(name)
+            .map(SerializedLayout::info)
+            .transpose()
+    }
+
+    /// Returns all Layouts of the device, deserializing those that have not been loaded yet.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(HashMap<&str, &TweezerLayoutInfo>))` - The Layouts by name.
+    /// * `Ok(None)` - The device has no Layout register, e.g. an EmulatorDevice.
+    /// * `Err(RoqoqoBackendError)` - A Layout could not be deserialized.
+    pub fn layouts(&self) -> Result<Option<HashMap<&str, &TweezerLayoutInfo>>, RoqoqoBackendError> {
+        let layout_register = match &self.layout_register {
+            Some(layout_register) => layout_register,
+            None => return Ok(None),
+        };
+        let mut layouts: HashMap<&str, &TweezerLayoutInfo> = layout_register
+            .iter()
+            .map(|(name, info)| (name.as_str(), info))
+            .collect();
+        for (name, layout) in &self.serialized_layouts {
+            layouts.insert(name, layout.info()?);
         }
-        #[inline]
-        fn _is_tweezer_occupied(qbt_to_twz: &HashMap<usize, usize>, tweezer_id: &usize) -> bool {
-            qbt_to_twz.iter().any(|(_, twz)| twz == tweezer_id)
+        Ok(Some(layouts))
+    }
+
+    /// Moves a Layout that has not been loaded yet into the Layout register.
+    fn load_layout(&mut self, name: &str) -> Result<(), RoqoqoBackendError> {
+        if let Some(layout) = self.serialized_layouts.remove(name) {
+            let info = layout.into_info()?;
+            self.layout_register
+                .get_or_insert_with(HashMap::new)
+                .insert(name.to_string(), info);
         }
-        #[inline]
-        fn _is_path_free(
-            qbt_to_twz: &HashMap<usize, usize>,
-            end_tweezer: &usize,
-            shift_lists: &[Vec<usize>],
-        ) -> bool {
-            let correct_shift_list = shift_lists
-                .iter()
-                .find(|list| list.contains(end_tweezer))
-                .unwrap();
-            // Check the path up to the target tweezer
-            for el in correct_shift_list
+        Ok(())
+    }
+
+    /// Deserializes a device downloaded from the WebAPI.
+    ///
+    /// Only the current and the default Layout are deserialized, the other Layouts are kept
+    /// in their JSON form until they are first accessed.
+    #[cfg(feature = "web-api")]
+    pub(crate) fn from_api_json(json: &str) -> Result<Self, RoqoqoBackendError> {
+        let device: TweezerDeviceSerialize<HashMap<String, Box<RawValue>>> =
+            serde_json::from_str(json).map_err(|err| RoqoqoBackendError::GenericError {
+                msg: format!("Could not deserialize the TweezerDevice: {}", err),
+            })?;
+        let mut layout_register: Option<HashMap<String, TweezerLayoutInfo>> = None;
+        let mut serialized_layouts: HashMap<String, SerializedLayout> = HashMap::new();
+        if let Some(layouts) = device.layout_register {
+            let register = layout_register.get_or_insert_with(HashMap::new);
+            for (name, layout) in layouts {
+                let layout = SerializedLayout {
+                    json: layout.get().to_string(),
+                    info: OnceLock::new(),
+                };
+                if device.current_layout.as_ref() == Some(&name)
+                    || device.default_layout.as_ref() == Some(&name)
+                {
+                    register.insert(name, layout.into_info()?);
+                } else {
+                    serialized_layouts.insert(name, layout);
+                }
+            }
+        }
+        Ok(TweezerDevice {
+            qubit_to_tweezer: device.qubit_to_tweezer,
+            layout_register,
+            current_layout: device.current_layout,
+            controlled_z_phase_relation: device.controlled_z_phase_relation,
+            controlled_phase_phase_relation: device.controlled_phase_phase_relation,
+            default_layout: device.default_layout,
+            seed: device.seed,
+            allow_reset: device.allow_reset,
+            symmetric_two_qubit_gate_times: device.symmetric_two_qubit_gate_times,
+            native_gates: device.native_gates,
+            max_qubits: device.max_qubits,
+            device_name: device.device_name,
+            available_gates: device.available_gates,
+            allowed_edges: device.allowed_edges,
+            emulator_noise: device.emulator_noise,
+            tweezer_noise: device.tweezer_noise,
+            array_preparation: device.array_preparation,
+            atom_loss: device.atom_loss,
+            phi_theta_relations: device.phi_theta_relations,
+            gate_time_cache: GateTimeCache::default(),
+            dense_gate_times: DenseGateTimes::default(),
+            serialized_layouts,
+        })
+    }
+
+    /// Checks whether a PragmaShiftQubitsTweezers operation can be applied to the device.
+    ///
+    /// A shift is valid if the current Layout allows shifting a qubit out of the start tweezer
+    /// into the end tweezer, the start tweezer holds a qubit and the end tweezer, as well as all
+    /// tweezers passed on the way, are free. Sequential shifts are checked in order, each shift
+    /// sees the qubits moved by the previous ones. Parallel shifts all see the qubits before the
+    /// operation and the paths of two parallel shifts, from the start to the end tweezer, may not
+    /// share a tweezer. The durations of the shifts, if given, must be one non-negative duration
+    /// per shift. The device is not changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pragma` - The PragmaShiftQubitsTweezers operation to check.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The operation can be applied to the device.
+    /// * `Err(RoqoqoBackendError)` - Describes the first shift that is not valid and why.
+    pub fn validate_pragma(
+        &self,
+        pragma: &PragmaShiftQubitsTweezers,
+    ) -> Result<(), RoqoqoBackendError> {
+        if let Some(durations) = &pragma.durations {
+            if durations.len() != pragma.shifts.len() {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "The operation has {} shifts but {} durations.",
+                        pragma.shifts.len(),
+                        durations.len()
+                    ),
+                });
+            }
+            if let Some((index, duration)) = durations
                 .iter()
-                .take_while(|tw| *tw != end_tweezer)
+                .enumerate()
+                .find(|(_, duration)| !duration.is_finite() || **duration < 0.0)
             {
-                if _is_tweezer_occupied(qbt_to_twz, el) {
-                    return false;
-                }
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "The duration {} of shift {} is negative or not finite.",
+                        duration, index
+                    ),
+                });
             }
-            // Check the target tweezer itself
-            if _is_tweezer_occupied(qbt_to_twz, end_tweezer) {
-                return false;
-            }
-            true
-        }
-        // Temporary clone: pretending the shift of the qubits in order to understand
-        //  if the whole row can indeed be shifted or not
-        let mut tmp_qubit_to_tweezer = self.qubit_to_tweezer.clone();
-        // Checks for all shifts from pragma:
-        // - if the starting tweezer has any valid shifts associated with it in the device
-        // - if the ending tweezer is contained in the associated valid shifts
-        // - if the device in the starting tweezer position is already occupied
-        // - if any tweezer in between the starting and ending tweezers is free (ending included)
-        for (shift_start, shift_end) in &pragma.shifts {
-            match self
-                .get_current_layout_info()
-                .unwrap()
-                .allowed_tweezer_shifts
+        }
+        // Pretend the shifts on a copy of the mapping, a shift may free the path of a later one
+        let mut qubit_to_tweezer =
+            self.qubit_to_tweezer
+                .clone()
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: "The device qubit -> tweezer mapping is empty: no qubits to shift."
+                        .to_string(),
+                })?;
+        let allowed_tweezer_shifts = &self.get_current_layout_info()?.allowed_tweezer_shifts;
+        let mut parallel_paths: Vec<Vec<usize>> = Vec::with_capacity(pragma.shifts.len());
+        for (index, (shift_start, shift_end)) in pragma.shifts.iter().enumerate() {
+            let invalid_shift = |reason: String| RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Shift {} from tweezer {} to tweezer {} is not valid: {}.",
+                    index, shift_start, shift_end, reason
+                ),
+            };
+            let direction = allowed_tweezer_shifts
                 .get(shift_start)
+                .ok_or_else(|| {
+                    invalid_shift(format!(
+                        "no shifts are allowed out of tweezer {}",
+                        shift_start
+                    ))
+                })?
+                .iter()
+                .find(|direction| direction.contains(shift_end))
+                .ok_or_else(|| {
+                    invalid_shift(format!(
+                        "tweezer {} can not be reached from tweezer {}",
+                        shift_end, shift_start
+                    ))
+                })?;
+            let qubit = qubit_to_tweezer
+                .iter()
+                .find_map(|(qubit, tweezer)| (tweezer == shift_start).then_some(*qubit))
+                .ok_or_else(|| {
+                    invalid_shift(format!("tweezer {} does not hold a qubit", shift_start))
+                })?;
+            let path: Vec<usize> = direction
+                .iter()
+                .take_while(|tweezer| *tweezer != shift_end)
+                .chain(std::iter::once(shift_end))
+                .copied()
+                .collect();
+            if let Some(occupied) = path
+                .iter()
+                .find(|tweezer| qubit_to_tweezer.values().any(|other| other == *tweezer))
             {
-                Some(allowed_shifts) => {
-                    if !_is_tweezer_in_shift_lists(shift_end, allowed_shifts)
-                        || !_is_tweezer_occupied(
-                            tmp_qubit_to_tweezer.as_ref().expect(
-                                "Internal error: qubit_to_tweezer mapping supposed to be Some().",
-                            ),
-                            shift_start,
-                        )
-                        || !_is_path_free(
-                            tmp_qubit_to_tweezer.as_ref().expect(
-                                "Internal error: qubit_to_tweezer mapping supposed to be Some().",
-                            ),
-                            shift_end,
-                            allowed_shifts,
-                        )
-                    {
-                        return false;
-                    }
+                return Err(invalid_shift(format!(
+                    "tweezer {} on the way is occupied",
+                    occupied
+                )));
+            }
+            if pragma.parallel {
+                let path: Vec<usize> = std::iter::once(*shift_start).chain(path).collect();
+                if let Some(other) = parallel_paths
+                    .iter()
+                    .position(|other| other.iter().any(|tweezer| path.contains(tweezer)))
+                {
+                    return Err(invalid_shift(format!(
+                        "its path crosses the path of the parallel shift {}",
+                        other
+                    )));
+                }
+                parallel_paths.push(path);
+            } else {
+                qubit_to_tweezer.insert(qubit, *shift_end);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether a device-changing operation can be applied to the device.
+    ///
+    /// Dry run of [Device::change_device] giving the same result without changing the device.
+    /// Custom pragmas with a registered handler are applied to a copy of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `hqslang` - The hqslang name of the wrapped operation.
+    /// * `operation` - The wrapped operation serialized with bincode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The operation can be applied to the device.
+    /// * `Err(RoqoqoBackendError)` - The operation is not supported or not valid on the device.
+    pub fn validate_change_device(
+        &self,
+        hqslang: &str,
+        operation: &[u8],
+    ) -> Result<(), RoqoqoBackendError> {
+        let not_supported = || RoqoqoBackendError::GenericError {
+            msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+        };
+        match hqslang {
+            "PragmaSwitchDeviceLayout" => {
+                let pragma: PragmaSwitchDeviceLayout =
+                    deserialize(operation).map_err(|_| not_supported())?;
+                self.validate_layout_switch(pragma.new_layout())
+            }
+            "PragmaDeactivateQRydQubit" => {
+                let pragma: PragmaDeactivateQRydQubit =
+                    deserialize(operation).map_err(|_| not_supported())?;
+                match &self.qubit_to_tweezer {
+                    Some(map) if map.contains_key(&pragma.qubit) => Ok(()),
+                    Some(_) => Err(RoqoqoBackendError::GenericError {
+                        msg: "The given qubit is not present in the Layout.".to_string(),
+                    }),
+                    None => Err(RoqoqoBackendError::GenericError {
+                        msg: "The device qubit -> tweezer mapping is empty.".to_string(),
+                    }),
                 }
-                // If no shifts are allowed by the device for this tweezer, then it's not valid
-                None => return false,
             }
-            // "Faking" the movement of the qubit
-            if let Some((key, _)) = tmp_qubit_to_tweezer
-                .as_ref()
-                .unwrap()
-                .iter()
-                .find(|&(_, &value)| value == *shift_start)
-                .map(|(&key, &value)| (key, value))
-            {
-                tmp_qubit_to_tweezer.as_mut().unwrap().remove(&key);
-                tmp_qubit_to_tweezer
-                    .as_mut()
-                    .unwrap()
-                    .insert(key, *shift_end);
+            "PragmaShiftQubitsTweezers" => {
+                let pragma = PragmaShiftQubitsTweezers::from_bincode(operation)
+                    .map_err(|_| not_supported())?;
+                self.validate_pragma(&pragma)
+            }
+            "PragmaSwapTweezers" => {
+                let pragma: PragmaSwapTweezers =
+                    deserialize(operation).map_err(|_| not_supported())?;
+                self.tweezer_swap_qubits(pragma.tweezer_0, pragma.tweezer_1)
+                    .map(|_| ())
+            }
+            "PragmaMoveToZone" => {
+                let pragma: PragmaMoveToZone =
+                    deserialize(operation).map_err(|_| not_supported())?;
+                self.resolve_zone_move(&pragma).map(|_| ())
+            }
+            "PragmaGlobalRotateXY" => {
+                let _: PragmaGlobalRotateXY =
+                    deserialize(operation).map_err(|_| not_supported())?;
+                self.validate_global_rotation()
+            }
+            "PragmaSetPhaseRelation" => {
+                let pragma: PragmaSetPhaseRelation =
+                    deserialize(operation).map_err(|_| not_supported())?;
+                self.validate_phase_relation(&pragma.cz_relation)?;
+                self.validate_phase_relation(&pragma.cp_relation)
+            }
+            // The remaining builtin pragmas are rejected without changing the device
+            "PragmaChangeQRydLayout" | "PragmaShiftQRydQubit" => {
+                self.clone().change_device(hqslang, operation)
             }
+            _ => apply_registered_pragma(&mut self.clone(), hqslang, operation)
+                .unwrap_or_else(|| Err(not_supported())),
         }
+    }
 
-        true
+    /// Checks whether the device can switch from the current Layout to the given one.
+    fn validate_layout_switch(&self, new_layout: &str) -> Result<(), RoqoqoBackendError> {
+        let new_layout_tweezer_info =
+            self.layout_info(new_layout)?
+                .ok_or_else(|| RoqoqoBackendError::GenericError {
+                    msg: format!(
+                    "Error with dynamic layout switching of TweezerDevice. Layout {} is not set.",
+                    new_layout
+                ),
+                })?;
+        // Layouts without tweezers per row fall back to the inferred rows
+        match (
+            &layout_tweezers_per_row(self.get_current_layout_info()?),
+            &layout_tweezers_per_row(new_layout_tweezer_info),
+        ) {
+            (Some(current_tweezers_per_row), Some(new_tweezers_per_row)) => {
+                // Switch if the number of tweezers per row is the same
+                if current_tweezers_per_row == new_tweezers_per_row {
+                    Ok(())
+                } else {
+                    Err(RoqoqoBackendError::GenericError {
+                        msg: format!(
+                            "Error with dynamic layout switching of TweezerDevice. Current tweezers per row is {:?} but switching to a layout with {:?} tweezers per row.",
+                            current_tweezers_per_row,
+                            new_tweezers_per_row,
+                        ),
+                    })
+                }
+            }
+            _ => Err(RoqoqoBackendError::GenericError {
+                msg: "Error with dynamic layout switching of TweezerDevice. Tweezers per row info missing from current or new layout.".to_string()
+            }),
+        }
     }
 
     /// Returns the seed usized for the API.
@@ -1391,14 +5838,282 @@ impl TweezerDevice {
         draw_shifts: bool,
         file_save_path: &Option<String>,
     ) -> Result<DynamicImage, RoqoqoBackendError> {
+        self.draw_with_options(
+            pixels_per_point,
+            draw_shifts,
+            file_save_path,
+            &DrawOptions::default(),
+        )
+    }
+
+    /// Creates a graph representing a TweezerDevice with the given drawing options.
+    ///
+    /// If `file_save_path` ends in `.svg` or `.pdf` the drawing is saved as a vector graphic in
+    /// that format (requires the `vector-drawing` feature), otherwise the image is saved in the
+    /// format given by the extension.
+    ///
+    /// ## Arguments
+    ///
+    /// * `pixels_per_point` - The quality of the image, ignored if `options.dpi` is set.
+    /// * `draw_shifts` - Whether to draw the allowed shifts between the tweezers.
+    /// * `file_save_path` - Path to save the image to.
+    /// * `options` - The labels, legend and resolution of the drawing.
+    ///
+    /// ## Returns
+    ///
+    /// * Ok(DynamicImage) - The representation of the device.
+    /// * Err(RoqoqoBackendError) - if there is no layout, the DPI is not positive or an error occurred during the compilation.
+    ///
+    pub fn draw_with_options(
+        &self,
+        pixels_per_point: Option<f32>,
+        draw_shifts: bool,
+        file_save_path: &Option<String>,
+        options: &DrawOptions,
+    ) -> Result<DynamicImage, RoqoqoBackendError> {
+        let pixels_per_point = drawing_pixels_per_point(pixels_per_point, options)?;
+        let typst_str = self.typst_drawing(draw_shifts, options)?;
+        let image = render_typst_str(typst_str.clone(), pixels_per_point)?;
+        if let Some(file_path) = file_save_path {
+            save_drawing(&image, typst_str, file_path)?;
+        }
+        Ok(image)
+    }
+
+    /// Creates a graph representing the given Layout, used by devices without a Layout register.
+    ///
+    /// The qubit labels follow the qubit -> tweezer mapping of the device, shifts are not drawn.
+    pub(crate) fn draw_layout_info(
+        &self,
+        layout: &TweezerLayoutInfo,
+        pixels_per_point: Option<f32>,
+        file_save_path: &Option<String>,
+        options: &DrawOptions,
+    ) -> Result<DynamicImage, RoqoqoBackendError> {
+        let pixels_per_point = drawing_pixels_per_point(pixels_per_point, options)?;
+        let mut typst_str = DRAWING_HEADER.to_owned();
+        typst_str.push_str(&self.typst_diagram(layout, false, options, &HashSet::new())?);
+        if options.show_legend {
+            typst_str.push_str(&create_legend(options, false, false));
+        }
+        let image = render_typst_str(typst_str.clone(), pixels_per_point)?;
+        if let Some(file_path) = file_save_path {
+            save_drawing(&image, typst_str, file_path)?;
+        }
+        Ok(image)
+    }
+
+    /// Creates a graph showing several Layouts of a TweezerDevice next to each other.
+    ///
+    /// Tweezers whose available gates differ between the Layouts are highlighted, a tweezer
+    /// missing in a Layout has no gates there. The qubits are labeled according to the current
+    /// qubit -> tweezer mapping in every Layout. If `file_save_path` ends in `.svg` or `.pdf` the
+    /// drawing is saved as a vector graphic in that format.
+    ///
+    /// ## Arguments
+    ///
+    /// * `layout_names` - The names of the Layouts to draw, from left to right.
+    /// * `pixels_per_point` - The quality of the image, ignored if `options.dpi` is set.
+    /// * `draw_shifts` - Whether to draw the allowed shifts between the tweezers.
+    /// * `file_save_path` - Path to save the image to.
+    /// * `options` - The labels, legend and resolution of the drawing.
+    ///
+    /// ## Returns
+    ///
+    /// * Ok(DynamicImage) - The representation of the Layouts.
+    /// * Err(RoqoqoBackendError) - if no Layout is given, a Layout is not present, the DPI is not positive or an error occurred during the compilation.
+    ///
+    pub fn draw_layouts(
+        &self,
+        layout_names: &[&str],
+        pixels_per_point: Option<f32>,
+        draw_shifts: bool,
+        file_save_path: &Option<String>,
+        options: &DrawOptions,
+    ) -> Result<DynamicImage, RoqoqoBackendError> {
+        if layout_names.is_empty() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "No Layout given to draw.".to_string(),
+            });
+        }
+        let pixels_per_point = drawing_pixels_per_point(pixels_per_point, options)?;
+        let layouts = layout_names
+            .iter()
+            .map(|name| {
+                self.layout_info(name)?
+                    .ok_or_else(|| RoqoqoBackendError::GenericError {
+                        msg: format!("Layout {} is not present in the Layout register.", name),
+                    })
+            })
+            .collect::<Result<Vec<&TweezerLayoutInfo>, RoqoqoBackendError>>()?;
+
+        let tweezers: BTreeSet<usize> = layouts
+            .iter()
+            .flat_map(|info| layout_tweezers(info))
+            .collect();
+        let mut highlighted_tweezers: HashSet<usize> = HashSet::new();
+        for tweezer in tweezers {
+            let gate_sets = layout_names
+                .iter()
+                .map(|name| self.get_available_gates_for_tweezer(tweezer, Some(name.to_string())))
+                .collect::<Result<Vec<Vec<&str>>, RoqoqoBackendError>>()?;
+            if gate_sets.iter().any(|gates| *gates != gate_sets[0]) {
+                highlighted_tweezers.insert(tweezer);
+            }
+        }
+
+        let mut typst_str = DRAWING_HEADER.to_owned();
+        typst_str.push_str(&format!(
+            "#grid(\n columns: {},\n gutter: 10mm,\n align: center + horizon,\n",
+            layouts.len()
+        ));
+        for (name, info) in layout_names.iter().zip(layouts) {
+            typst_str.push_str(&format!(
+                " [#text(weight: \"bold\", {:?})\n\n{}],\n",
+                name,
+                self.typst_diagram(info, draw_shifts, options, &highlighted_tweezers)?
+            ));
+        }
+        typst_str.push(')');
+        if options.show_legend {
+            typst_str.push_str(&create_legend(options, draw_shifts, true));
+        }
+        let image = render_typst_str(typst_str.clone(), pixels_per_point)?;
+        if let Some(file_path) = file_save_path {
+            save_drawing(&image, typst_str, file_path)?;
+        }
+        Ok(image)
+    }
+
+    /// Creates a graph representing a TweezerDevice in the given output format.
+    ///
+    /// Vector graphics can be scaled to arbitrary resolution, e.g. for figures in papers and
+    /// documentation. The SVG and PDF formats require the `vector-drawing` feature.
+    ///
+    /// ## Arguments
+    ///
+    /// * `format` - The output format of the drawing.
+    /// * `pixels_per_point` - The quality of a PNG image, ignored if `options.dpi` is set or for vector graphics.
+    /// * `draw_shifts` - Whether to draw the allowed shifts between the tweezers.
+    /// * `options` - The labels, legend and resolution of the drawing.
+    ///
+    /// ## Returns
+    ///
+    /// * Ok(Vec<u8>) - The content of the PNG, SVG or PDF file.
+    /// * Err(RoqoqoBackendError) - if there is no layout, the DPI is not positive, an error occurred during the compilation or a vector format is requested without the `vector-drawing` feature.
+    ///
+    pub fn draw_as(
+        &self,
+        format: DrawFormat,
+        pixels_per_point: Option<f32>,
+        draw_shifts: bool,
+        options: &DrawOptions,
+    ) -> Result<Vec<u8>, RoqoqoBackendError> {
+        match format {
+            DrawFormat::Png => {
+                let image =
+                    self.draw_with_options(pixels_per_point, draw_shifts, &None, options)?;
+                let mut buffer = std::io::Cursor::new(Vec::new());
+                image
+                    .write_to(&mut buffer, image::ImageFormat::Png)
+                    .map_err(|x| RoqoqoBackendError::GenericError {
+                        msg: format!("Error during the generation of the Png file: {x:?}"),
+                    })?;
+                Ok(buffer.into_inner())
+            }
+            DrawFormat::Svg | DrawFormat::Pdf => {
+                export_typst_vector(self.typst_drawing(draw_shifts, options)?, format)
+            }
+        }
+    }
+
+    /// Creates one drawing of the TweezerDevice per device-changing step of a circuit.
+    ///
+    /// The first frame shows the device before the circuit. The PragmaChangeDevice operations of
+    /// the circuit, e.g. Layout switches, shifts and deactivations, are applied in order to a copy
+    /// of the device and each adds a frame. If `file_save_path` ends in `.gif` the frames are saved
+    /// as an animated GIF, otherwise every frame is saved with its index appended to the file name,
+    /// e.g. `shifts_0.png`, `shifts_1.png`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `circuit` - The circuit whose device-changing operations are drawn.
+    /// * `pixels_per_point` - The quality of the images, ignored if `options.dpi` is set.
+    /// * `draw_shifts` - Whether to draw the allowed shifts between the tweezers.
+    /// * `file_save_path` - Path to save the frames or the animated GIF to.
+    /// * `frame_delay_ms` - The time each frame of the animated GIF is shown, in milliseconds.
+    /// * `options` - The labels, legend and resolution of the drawings.
+    ///
+    /// ## Returns
+    ///
+    /// * Ok(Vec<DynamicImage>) - The drawings of the device before the circuit and after each device-changing operation.
+    /// * Err(RoqoqoBackendError) - if there is no layout, a device-changing operation is not valid on the device or an error occurred during the compilation or saving.
+    ///
+    pub fn draw_circuit_shifts(
+        &self,
+        circuit: &Circuit,
+        pixels_per_point: Option<f32>,
+        draw_shifts: bool,
+        file_save_path: &Option<String>,
+        frame_delay_ms: u32,
+        options: &DrawOptions,
+    ) -> Result<Vec<DynamicImage>, RoqoqoBackendError> {
+        let mut device = self.clone();
+        let mut frames =
+            vec![device.draw_with_options(pixels_per_point, draw_shifts, &None, options)?];
+        for operation in circuit.iter() {
+            if let Operation::PragmaChangeDevice(pragma) = operation {
+                device.change_device(&pragma.wrapped_hqslang, &pragma.wrapped_operation)?;
+                frames.push(device.draw_with_options(
+                    pixels_per_point,
+                    draw_shifts,
+                    &None,
+                    options,
+                )?);
+            }
+        }
+        if let Some(file_path) = file_save_path {
+            let save_error = |x: String| RoqoqoBackendError::GenericError {
+                msg: format!("Error during image saving: {x}"),
+            };
+            let path = std::path::Path::new(file_path);
+            let extension = path.extension().and_then(|ext| ext.to_str());
+            if extension.is_some_and(|ext| ext.eq_ignore_ascii_case("gif")) {
+                let gif = frames_to_gif(&frames, frame_delay_ms)?;
+                std::fs::write(path, gif).map_err(|x| save_error(format!("{x:?}")))?;
+            } else {
+                let stem = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .unwrap_or_default();
+                for (index, frame) in frames.iter().enumerate() {
+                    let frame_path = match extension {
+                        Some(ext) => path.with_file_name(format!("{}_{}.{}", stem, index, ext)),
+                        None => path.with_file_name(format!("{}_{}", stem, index)),
+                    };
+                    frame
+                        .save(frame_path)
+                        .map_err(|x| save_error(format!("{x:?}")))?;
+                }
+            }
+        }
+        Ok(frames)
+    }
+
+    /// Returns the typst document of the drawing of the current Layout.
+    fn typst_drawing(
+        &self,
+        draw_shifts: bool,
+        options: &DrawOptions,
+    ) -> Result<String, RoqoqoBackendError> {
         let layout = match &self.layout_register {
-            Some(x) => x.get(
+            Some(_) => self.layout_info(
                 &self
                     .current_layout
                     .clone()
                     .or_else(|| self.default_layout.clone())
                     .unwrap_or_default(),
-            ),
+            )?,
             None => {
                 return Err(RoqoqoBackendError::GenericError {
                     msg: "Draw method not available for EmulatorDevice.".to_owned(),
@@ -1410,7 +6125,29 @@ impl TweezerDevice {
                 msg: "No layout found for the device.".to_owned(),
             });
         }
-        let current_layout = layout.unwrap();
+        let mut typst_str = DRAWING_HEADER.to_owned();
+        typst_str.push_str(&self.typst_diagram(
+            layout.unwrap(),
+            draw_shifts,
+            options,
+            &HashSet::new(),
+        )?);
+        if options.show_legend {
+            typst_str.push_str(&create_legend(options, draw_shifts, false));
+        }
+        Ok(typst_str)
+    }
+
+    /// Returns the typst diagram of a Layout.
+    ///
+    /// The tweezers in `highlighted_tweezers` are filled with a color.
+    fn typst_diagram(
+        &self,
+        current_layout: &TweezerLayoutInfo,
+        draw_shifts: bool,
+        options: &DrawOptions,
+        highlighted_tweezers: &HashSet<usize>,
+    ) -> Result<String, RoqoqoBackendError> {
         let nb_tweezers = current_layout
             .tweezer_single_qubit_gate_times
             .values()
@@ -1426,6 +6163,7 @@ impl TweezerDevice {
                             .unwrap_or(&0_usize)
                     }),
             )
+            .chain(current_layout.tweezer_coordinates.keys())
             .max()
             .unwrap_or(&0_usize)
             .to_owned()
@@ -1435,8 +6173,11 @@ impl TweezerDevice {
         let nodes = create_nodes(
             nb_tweezers,
             current_layout.tweezers_per_row.clone(),
+            &current_layout.tweezer_coordinates,
             &mut tweezers_positions,
             &self.qubit_to_tweezer,
+            options,
+            highlighted_tweezers,
         )?;
         map_edges(
             current_layout.tweezer_two_qubit_gate_times.clone(),
@@ -1450,11 +6191,7 @@ impl TweezerDevice {
             )?
         }
         let edges = create_edges(&edges_map, &tweezers_positions)?;
-        let mut typst_str = r#"#import "@preview/fletcher:0.5.0" as fletcher: diagram, node, edge
-#set page(width: auto, height: auto, margin: 5mm, fill: white)
-#show math.equation: set text(font: "Fira Math")
-
-#diagram(
+        let mut typst_str = r#"#diagram(
  edge-stroke: 1pt,
  node-stroke: black,
 	crossing-thickness: 3,
@@ -1466,48 +6203,53 @@ impl TweezerDevice {
         typst_str.push_str("\n	{\n");
         typst_str.push_str(edges.as_str());
         typst_str.push_str("\n	}\n)");
-        let image = render_typst_str(typst_str, pixels_per_point)?;
-        if let Some(file_path) = file_save_path {
-            image
-                .save(file_path)
-                .map_err(|x| RoqoqoBackendError::GenericError {
-                    msg: format!("Error during image saving: {x:?}"),
-                })?;
-        }
-        Ok(image)
+        Ok(typst_str)
     }
 }
 
 impl Device for TweezerDevice {
     fn single_qubit_gate_time(&self, hqslang: &str, qubit: &usize) -> Option<f64> {
-        let tweezer_layout_info = self.get_current_layout_info();
         let mapped_qubit = self.get_tweezer_from_qubit(qubit).ok()?;
 
-        if let Some(hqslang_map) = tweezer_layout_info
-            .unwrap()
-            .tweezer_single_qubit_gate_times
-            .get(hqslang)
-        {
-            return hqslang_map.get(&mapped_qubit).copied();
-        }
-        None
+        let layout = self.current_layout.as_deref();
+        self.gate_time_cache
+            .get_or_compute(layout, hqslang, &[mapped_qubit], || {
+                if let Some(hqslang_map) = self
+                    .get_current_layout_info()
+                    .unwrap()
+                    .tweezer_single_qubit_gate_times
+                    .get(hqslang)
+                {
+                    return hqslang_map.get(&mapped_qubit).copied();
+                }
+                None
+            })
     }
 
     fn two_qubit_gate_time(&self, hqslang: &str, control: &usize, target: &usize) -> Option<f64> {
-        let tweezer_layout_info = self.get_current_layout_info();
         let mapped_control_qubit = self.get_tweezer_from_qubit(control).ok()?;
         let mapped_target_qubit = self.get_tweezer_from_qubit(target).ok()?;
 
-        if let Some(hqslang_map) = tweezer_layout_info
-            .unwrap()
-            .tweezer_two_qubit_gate_times
-            .get(hqslang)
-        {
-            return hqslang_map
-                .get(&(mapped_control_qubit, mapped_target_qubit))
-                .copied();
-        }
-        None
+        let layout = self.current_layout.as_deref();
+        let tweezers = [mapped_control_qubit, mapped_target_qubit];
+        self.gate_time_cache
+            .get_or_compute(layout, hqslang, &tweezers, || {
+                if let Some(hqslang_map) = self
+                    .get_current_layout_info()
+                    .unwrap()
+                    .tweezer_two_qubit_gate_times
+                    .get(hqslang)
+                {
+                    return self.dense_gate_times.two_qubit_gate_time(
+                        layout,
+                        hqslang,
+                        hqslang_map,
+                        mapped_control_qubit,
+                        mapped_target_qubit,
+                    );
+                }
+                None
+            })
     }
 
     fn three_qubit_gate_time(
@@ -1517,49 +6259,65 @@ impl Device for TweezerDevice {
         control_1: &usize,
         target: &usize,
     ) -> Option<f64> {
-        let tweezer_layout_info = self.get_current_layout_info();
         let mapped_control0_qubit = self.get_tweezer_from_qubit(control_0).ok()?;
         let mapped_control1_qubit = self.get_tweezer_from_qubit(control_1).ok()?;
         let mapped_target_qubit = self.get_tweezer_from_qubit(target).ok()?;
 
-        if let Some(hqslang_map) = tweezer_layout_info
-            .unwrap()
-            .tweezer_three_qubit_gate_times
-            .get(hqslang)
-        {
-            return hqslang_map
-                .get(&(
-                    mapped_control0_qubit,
-                    mapped_control1_qubit,
-                    mapped_target_qubit,
-                ))
-                .copied();
-        }
-        None
+        let layout = self.current_layout.as_deref();
+        let tweezers = [
+            mapped_control0_qubit,
+            mapped_control1_qubit,
+            mapped_target_qubit,
+        ];
+        self.gate_time_cache
+            .get_or_compute(layout, hqslang, &tweezers, || {
+                if let Some(hqslang_map) = self
+                    .get_current_layout_info()
+                    .unwrap()
+                    .tweezer_three_qubit_gate_times
+                    .get(hqslang)
+                {
+                    return hqslang_map
+                        .get(&(
+                            mapped_control0_qubit,
+                            mapped_control1_qubit,
+                            mapped_target_qubit,
+                        ))
+                        .copied();
+                }
+                None
+            })
     }
 
     fn multi_qubit_gate_time(&self, hqslang: &str, qubits: &[usize]) -> Option<f64> {
-        let tweezer_layout_info = self.get_current_layout_info();
         let mut mapped_qubits: Vec<usize> = Vec::new();
         for qubit in qubits {
             let mapped_qubit = self.get_tweezer_from_qubit(qubit).ok()?;
             mapped_qubits.push(mapped_qubit);
         }
 
-        if let Some(hqslang_map) = tweezer_layout_info
-            .unwrap()
-            .tweezer_multi_qubit_gate_times
-            .get(hqslang)
-        {
-            return hqslang_map.get(&mapped_qubits).copied();
-        }
-        None
+        let layout = self.current_layout.as_deref();
+        self.gate_time_cache
+            .get_or_compute(layout, hqslang, &mapped_qubits, || {
+                if let Some(hqslang_map) = self
+                    .get_current_layout_info()
+                    .unwrap()
+                    .tweezer_multi_qubit_gate_times
+                    .get(hqslang)
+                {
+                    return hqslang_map.get(&mapped_qubits).copied();
+                }
+                None
+            })
     }
 
-    #[allow(unused_variables)]
     fn qubit_decoherence_rates(&self, qubit: &usize) -> Option<Array2<f64>> {
-        // At the moment we hard-code a noise free model
-        Some(Array2::zeros((3, 3).to_owned()))
+        // Qubits without a tweezer or noise parameters are noise free
+        Some(
+            self.get_tweezer_from_qubit(qubit)
+                .map(|tweezer| self.get_tweezer_noise(tweezer).decoherence_rates())
+                .unwrap_or_else(|_| Array2::zeros((3, 3))),
+        )
     }
 
     fn number_qubits(&self) -> usize {
@@ -1598,6 +6356,7 @@ impl Device for TweezerDevice {
     }
 
     fn change_device(&mut self, hqslang: &str, operation: &[u8]) -> Result<(), RoqoqoBackendError> {
+        self.invalidate_gate_time_lookups();
         match hqslang {
             "PragmaChangeQRydLayout" => Err(RoqoqoBackendError::GenericError {
                 msg: "Operation not supported in TweezerDevice. Please use PragmaSwitchDeviceLayout.".to_string(),
@@ -1607,40 +6366,10 @@ impl Device for TweezerDevice {
                     deserialize(operation);
                 match de_change_layout {
                     Ok(pragma) => {
-                        // Check layout existance
-                        match self._extract_layout_register()?.get(pragma.new_layout()) {
-                            Some(new_layout_tweezer_info) => {
-                                // Check layout tweezers per row
-                                match (&self.get_current_layout_info()?.tweezers_per_row, &new_layout_tweezer_info.tweezers_per_row) {
-                                    (Some(current_tweezers_per_row), Some(new_tweezers_per_row)) => {
-                                        // Switch if the number of tweezers per row is the same
-                                        if current_tweezers_per_row == new_tweezers_per_row {
-                                            self.current_layout = Some(pragma.new_layout().to_string());
-                                            Ok(())
-                                        } else {
-                                            Err(RoqoqoBackendError::GenericError {
-                                                msg: format!(
-                                                    "Error with dynamic layout switching of TweezerDevice. Current tweezers per row is {:?} but switching to a layout with {:?} tweezers per row.",
-                                                    current_tweezers_per_row,
-                                                    new_tweezers_per_row,
-                                                ),
-                                            })
-                                        }
-                                    },
-                                    _ => Err(RoqoqoBackendError::GenericError {
-                                        msg: "Error with dynamic layout switching of TweezerDevice. Tweezers per row info missing from current or new layout.".to_string()
-                                    })
-                                }
-                            },
-                            None => {
-                                Err(RoqoqoBackendError::GenericError {
-                                    msg: format!(
-                                        "Error with dynamic layout switching of TweezerDevice. Layout {} is not set.",
-                                        pragma.new_layout()
-                                    ),
-                                })
-                            },
-                        }
+                        self.validate_layout_switch(pragma.new_layout())?;
+                        self.load_layout(pragma.new_layout())?;
+                        self.current_layout = Some(pragma.new_layout().to_string());
+                        Ok(())
                     },
                     Err(_) => Err(RoqoqoBackendError::GenericError {
                         msg: "Wrapped operation not supported in TweezerDevice".to_string(),
@@ -1664,10 +6393,7 @@ impl Device for TweezerDevice {
                 msg: "Operation not supported in TweezerDevice. Please use PragmaShiftQubitsTweezers.".to_string(),
             }),
             "PragmaShiftQubitsTweezers" => {
-                let de_shift_qubits_tweezers: Result<
-                    PragmaShiftQubitsTweezers,
-                    Box<bincode::ErrorKind>,
-                > = deserialize(operation);
+                let de_shift_qubits_tweezers = PragmaShiftQubitsTweezers::from_bincode(operation);
                 match de_shift_qubits_tweezers {
                     Ok(pragma) => {
                         // Check if the there are qubits to move
@@ -1677,7 +6403,7 @@ impl Device for TweezerDevice {
                             });
                         }
                         // Check if the shifts in the operation are valid on the device
-                        if !self._are_all_shifts_valid(&pragma) {
+                        if self.validate_pragma(&pragma).is_err() {
                             return Err(RoqoqoBackendError::GenericError {
                                 msg: "The PragmaShiftQubitsTweezers operation is not valid on this device."
                                     .to_string(),
@@ -1703,47 +6429,71 @@ impl Device for TweezerDevice {
                     }),
                 }
             },
-            _ => Err(RoqoqoBackendError::GenericError {
-                msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+            "PragmaSwapTweezers" => {
+                let de_swap_tweezers: Result<PragmaSwapTweezers, Box<bincode::ErrorKind>> =
+                    deserialize(operation);
+                match de_swap_tweezers {
+                    Ok(pragma) => self.swap_tweezers(pragma.tweezer_0, pragma.tweezer_1),
+                    Err(_) => Err(RoqoqoBackendError::GenericError {
+                        msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+                    }),
+                }
+            },
+            "PragmaMoveToZone" => {
+                let de_move_to_zone: Result<PragmaMoveToZone, Box<bincode::ErrorKind>> =
+                    deserialize(operation);
+                match de_move_to_zone {
+                    Ok(pragma) => {
+                        let shifts = self.resolve_zone_move(&pragma)?;
+                        if let Some(map) = &mut self.qubit_to_tweezer {
+                            for (shift_start, shift_end) in shifts.shifts {
+                                if let Some(qubit_to_move) = map.iter().find_map(|(&qbt, &twz)| {
+                                    (twz == shift_start).then_some(qbt)
+                                }) {
+                                    map.insert(qubit_to_move, shift_end);
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                    Err(_) => Err(RoqoqoBackendError::GenericError {
+                        msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+                    }),
+                }
+            },
+            // The global rotation does not change the device, it is only checked to be available
+            "PragmaGlobalRotateXY" => {
+                let de_global_rotation: Result<PragmaGlobalRotateXY, Box<bincode::ErrorKind>> =
+                    deserialize(operation);
+                match de_global_rotation {
+                    Ok(_) => self.validate_global_rotation(),
+                    Err(_) => Err(RoqoqoBackendError::GenericError {
+                        msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+                    }),
+                }
+            },
+            "PragmaSetPhaseRelation" => {
+                let de_set_phase_relation: Result<PragmaSetPhaseRelation, Box<bincode::ErrorKind>> =
+                    deserialize(operation);
+                match de_set_phase_relation {
+                    Ok(pragma) => self.set_phase_relations(&pragma.cz_relation, &pragma.cp_relation),
+                    Err(_) => Err(RoqoqoBackendError::GenericError {
+                        msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+                    }),
+                }
+            },
+            _ => apply_registered_pragma(self, hqslang, operation).unwrap_or_else(|| {
+                Err(RoqoqoBackendError::GenericError {
+                    msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+                })
             }),
         }
     }
 
     fn to_generic_device(&self) -> GenericDevice {
-        let mut new_generic_device = GenericDevice::new(self.number_qubits());
-        let tweezer_info = self.get_current_layout_info().unwrap();
-
-        for single_qubit_gate_struct in &tweezer_info.tweezer_single_qubit_gate_times {
-            let gate_name = single_qubit_gate_struct.0.clone();
-            for single_qubit_gate_info in single_qubit_gate_struct.1 {
-                new_generic_device
-                    .set_single_qubit_gate_time(
-                        gate_name.as_str(),
-                        *single_qubit_gate_info.0,
-                        *single_qubit_gate_info.1,
-                    )
-                    .unwrap();
-            }
-        }
-        for qubit in 0..self.number_qubits() {
-            new_generic_device
-                .set_qubit_decoherence_rates(qubit, self.qubit_decoherence_rates(&qubit).unwrap())
-                .unwrap();
-        }
-        for two_qubit_gate_struct in &tweezer_info.tweezer_two_qubit_gate_times {
-            let gate_name = two_qubit_gate_struct.0.clone();
-            for two_qubit_gate_info in two_qubit_gate_struct.1 {
-                new_generic_device
-                    .set_two_qubit_gate_time(
-                        gate_name.as_str(),
-                        two_qubit_gate_info.0 .0,
-                        two_qubit_gate_info.0 .1,
-                        *two_qubit_gate_info.1,
-                    )
-                    .unwrap();
-            }
-        }
-        new_generic_device
+        // Without a current Layout the device has no gates
+        self.try_to_generic_device()
+            .unwrap_or_else(|_| GenericDevice::new(self.number_qubits()))
     }
 }
 
@@ -1757,11 +6507,40 @@ enum ShiftType {
 fn create_nodes(
     nb_tweezers: usize,
     tweezers_per_row: Option<Vec<usize>>,
-    tweezers_positions: &mut Vec<(usize, usize)>,
+    tweezer_coordinates: &HashMap<usize, (f64, f64)>,
+    tweezers_positions: &mut Vec<(f64, f64)>,
     qubit_to_tweezer: &Option<HashMap<usize, usize>>,
+    options: &DrawOptions,
+    highlighted_tweezers: &HashSet<usize>,
 ) -> Result<String, RoqoqoBackendError> {
-    let mut nodes = "".to_owned();
-    if tweezers_per_row.is_some()
+    if (0..nb_tweezers).all(|tweezer| tweezer_coordinates.contains_key(&tweezer)) {
+        // True positions, scaled so that the closest tweezers are one grid unit apart
+        let coordinates: Vec<(f64, f64)> = (0..nb_tweezers)
+            .map(|tweezer| tweezer_coordinates[&tweezer])
+            .collect();
+        let mut scale = f64::INFINITY;
+        for (index, (x0, y0)) in coordinates.iter().enumerate() {
+            for (x1, y1) in coordinates[index + 1..].iter() {
+                let distance = (x1 - x0).hypot(y1 - y0);
+                if distance > 0.0 {
+                    scale = scale.min(distance);
+                }
+            }
+        }
+        if !scale.is_finite() {
+            scale = 1.0;
+        }
+        let (min_x, min_y) = coordinates
+            .iter()
+            .fold((f64::INFINITY, f64::INFINITY), |(min_x, min_y), (x, y)| {
+                (min_x.min(*x), min_y.min(*y))
+            });
+        tweezers_positions.extend(
+            coordinates
+                .iter()
+                .map(|(x, y)| ((x - min_x) / scale, (y - min_y) / scale)),
+        );
+    } else if tweezers_per_row.is_some()
         && tweezers_per_row
             .clone()
             .unwrap()
@@ -1773,21 +6552,7 @@ fn create_nodes(
         let mut x = 0;
         let mut y = 0;
         for tweezer in 0..nb_tweezers {
-            tweezers_positions.insert(tweezer, (x, y));
-            nodes.push_str(&format!(
-                "node(({x},{y}), ${tweezer}_t{}, shape: circle),\n",
-                qubit_to_tweezer
-                    .clone()
-                    .map(|qubit_map| {
-                        for (qubit, tweez) in qubit_map {
-                            if tweez == tweezer {
-                                return format!("|{qubit}_q$, radius: 2.3em");
-                            }
-                        }
-                        "$, radius: 1.3em".to_owned()
-                    })
-                    .unwrap_or("$, radius: 1.3em".to_owned())
-            ));
+            tweezers_positions.insert(tweezer, (x as f64, y as f64));
             x += 1;
             if x == nb_tweezers_per_row[y] {
                 x = 0;
@@ -1804,9 +6569,77 @@ fn create_nodes(
             },
         ));
     }
+    let mut nodes = "".to_owned();
+    for (tweezer, (x, y)) in tweezers_positions.iter().enumerate() {
+        let mut labels: Vec<String> = Vec::new();
+        if options.show_tweezer_labels {
+            labels.push(format!("{tweezer}_t"));
+        }
+        if options.show_qubit_labels {
+            if let Some((qubit, _)) = qubit_to_tweezer
+                .iter()
+                .flatten()
+                .find(|(_, &tweez)| tweez == tweezer)
+            {
+                labels.push(format!("{qubit}_q"));
+            }
+        }
+        let label = if labels.is_empty() {
+            "[]".to_owned()
+        } else {
+            format!("${}$", labels.join("|"))
+        };
+        let radius = if labels.len() > 1 { "2.3em" } else { "1.3em" };
+        let fill = if highlighted_tweezers.contains(&tweezer) {
+            ", fill: orange.lighten(40%)"
+        } else {
+            ""
+        };
+        nodes.push_str(&format!(
+            "node(({x},{y}), {label}, shape: circle, radius: {radius}{fill}),\n"
+        ));
+    }
     Ok(nodes)
 }
 
+/// Creates the legend below the diagram, one row per label or edge type.
+fn create_legend(options: &DrawOptions, draw_shifts: bool, show_highlight: bool) -> String {
+    let mut entries: Vec<(&str, &str)> = Vec::new();
+    if options.show_tweezer_labels {
+        entries.push(("$n_t$", "Tweezer n"));
+    }
+    if options.show_qubit_labels {
+        entries.push(("$n_q$", "Qubit n held by the tweezer"));
+    }
+    entries.push((
+        "#diagram(edge((0,0), (1,0)))",
+        "Two-qubit gate between the tweezers",
+    ));
+    if draw_shifts {
+        entries.push((
+            "#diagram(edge((0,0), (1,0), \"-|>\"))",
+            "Qubit can be shifted in the direction of the arrow",
+        ));
+        entries.push((
+            "#diagram(edge((0,0), (1,0), \"<|-|>\"))",
+            "Qubit can be shifted in both directions",
+        ));
+    }
+    if show_highlight {
+        entries.push((
+            "#diagram(node((0,0), [], shape: circle, radius: 0.6em, stroke: black, fill: orange.lighten(40%)))",
+            "Tweezer with different gates in the compared Layouts",
+        ));
+    }
+    let mut legend =
+        "\n#v(3mm)\n#grid(\n columns: 2,\n gutter: 3mm,\n align: horizon,\n".to_owned();
+    for (symbol, description) in entries {
+        legend.push_str(&format!(" [{symbol}], [{description}],\n"));
+    }
+    legend.push(')');
+    legend
+}
+
 fn map_edges(
     tweezer_two_qubit_gate_times: HashMap<String, HashMap<(usize, usize), f64>>,
     edges_map: &mut HashMap<(usize, usize), ShiftType>,
@@ -1827,7 +6660,7 @@ fn map_edges(
 
 fn create_edges(
     edges_map: &HashMap<(usize, usize), ShiftType>,
-    tweezers_positions: &[(usize, usize)],
+    tweezers_positions: &[(f64, f64)],
 ) -> Result<String, RoqoqoBackendError> {
     let mut edges = "".to_owned();
     for (&(qb1, qb2), shift_type) in edges_map.iter() {
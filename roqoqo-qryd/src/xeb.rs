@@ -0,0 +1,452 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cross-entropy benchmarking (XEB).
+//!
+//! Generates random circuits built from the native `RotateXY` and `ControlledPauliZ` gates
+//! of a device, respecting its connectivity, runs them on any [EvaluatingBackend] and
+//! estimates the linear XEB fidelity by comparing the measured bitstrings with the ideal
+//! output probabilities of the circuits.
+
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::devices::Device;
+use roqoqo::operations::{
+    ControlledPauliZ, DefinitionBit, MeasureQubit, OperateSingleQubit, OperateTwoQubit, Operation,
+    PragmaSetNumberOfMeasurements, RotateXY,
+};
+use roqoqo::{Circuit, RoqoqoBackendError};
+use std::collections::HashSet;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+/// Name of the readout register used by the XEB circuits.
+pub const XEB_READOUT: &str = "ro_xeb";
+
+/// Maximal number of qubits for which the ideal output probabilities are computed.
+pub const XEB_MAX_QUBITS: usize = 16;
+
+/// Seed used for the circuit generation when no seed is given.
+const DEFAULT_SEED: u64 = 42;
+
+/// Result of a cross-entropy benchmarking experiment.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct XEBResult {
+    /// The number of cycles of the benchmarked circuits.
+    pub depths: Vec<usize>,
+    /// The linear XEB fidelity of the circuits of the corresponding depth, normalized by the
+    /// value of an ideal device so that noiseless execution yields `1`.
+    pub fidelities: Vec<f64>,
+    /// The fidelity per cycle, fitted from `F(d) = A * f^d`. `None` if fewer than two
+    /// depths have a positive fidelity.
+    pub fidelity_per_cycle: Option<f64>,
+}
+
+/// Experiment builder for cross-entropy benchmarking.
+///
+/// Each cycle consists of a layer of random `RotateXY(pi/2, phi)` rotations on all qubits,
+/// with `phi` a multiple of `pi/4`, followed by `ControlledPauliZ` gates on a set of
+/// non-overlapping qubit pairs connected on the device. Consecutive cycles alternate through
+/// the sets of pairs so that every connection is used. A final rotation layer precedes
+/// the measurement.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct XEBExperiment {
+    /// The benchmarked qubits.
+    qubits: Vec<usize>,
+    /// The number of measurements performed for each circuit.
+    number_measurements: usize,
+    /// The number of cycles of each generated circuit.
+    depths: Vec<usize>,
+    /// The generated circuits without measurement.
+    circuits: Vec<Circuit>,
+}
+
+impl XEBExperiment {
+    /// Creates a new XEBExperiment for a device.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The device the circuits are generated for.
+    /// * `qubits` - The benchmarked qubits.
+    /// * `depths` - The positive numbers of cycles to benchmark.
+    /// * `number_circuits` - The number of random circuits for each depth.
+    /// * `number_measurements` - The number of measurements performed for each circuit.
+    /// * `seed` - The seed of the random circuit generation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new XEBExperiment.
+    /// * `Err(RoqoqoBackendError)` - Invalid arguments or the device does not support `RotateXY` on all qubits.
+    pub fn new<D: Device>(
+        device: &D,
+        qubits: Vec<usize>,
+        depths: Vec<usize>,
+        number_circuits: usize,
+        number_measurements: usize,
+        seed: Option<u64>,
+    ) -> Result<Self, RoqoqoBackendError> {
+        if qubits.is_empty() || qubits.len() > XEB_MAX_QUBITS {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "XEB requires between 1 and {} qubits, got {}.",
+                    XEB_MAX_QUBITS,
+                    qubits.len()
+                ),
+            });
+        }
+        if qubits.iter().collect::<HashSet<&usize>>().len() != qubits.len() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("The XEB qubits {:?} contain duplicates.", qubits),
+            });
+        }
+        if depths.is_empty() || depths.contains(&0) {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "XEB requires at least one depth and all depths need to be positive."
+                    .to_string(),
+            });
+        }
+        if number_circuits == 0 || number_measurements == 0 {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: "XEB requires at least one circuit per depth and one measurement per circuit."
+                    .to_string(),
+            });
+        }
+        if let Some(qubit) = qubits
+            .iter()
+            .find(|qubit| device.single_qubit_gate_time("RotateXY", qubit).is_none())
+        {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!("The device does not support RotateXY on qubit {}.", qubit),
+            });
+        }
+
+        let pair_layers = pair_layers(device, &qubits);
+        let mut rng = SplitMix64(seed.unwrap_or(DEFAULT_SEED));
+        let mut circuit_depths: Vec<usize> = Vec::new();
+        let mut circuits: Vec<Circuit> = Vec::new();
+        for depth in depths.iter() {
+            for _ in 0..number_circuits {
+                let mut circuit = Circuit::new();
+                for cycle in 0..*depth {
+                    add_rotation_layer(&mut circuit, &qubits, &mut rng);
+                    if !pair_layers.is_empty() {
+                        for (control, target) in pair_layers[cycle % pair_layers.len()].iter() {
+                            circuit += ControlledPauliZ::new(*control, *target);
+                        }
+                    }
+                }
+                add_rotation_layer(&mut circuit, &qubits, &mut rng);
+                circuit_depths.push(*depth);
+                circuits.push(circuit);
+            }
+        }
+        Ok(Self {
+            qubits,
+            number_measurements,
+            depths: circuit_depths,
+            circuits,
+        })
+    }
+
+    /// Returns the benchmarked qubits.
+    pub fn qubits(&self) -> &[usize] {
+        &self.qubits
+    }
+
+    /// Returns the number of measurements performed for each circuit.
+    pub fn number_measurements(&self) -> usize {
+        self.number_measurements
+    }
+
+    /// Returns the number of cycles of each circuit, in the order of [XEBExperiment::circuits].
+    pub fn depths(&self) -> &[usize] {
+        &self.depths
+    }
+
+    /// Returns the measurement circuits of the experiment.
+    ///
+    /// The i-th benchmarked qubit is measured into the i-th bit of the [XEB_READOUT] register.
+    pub fn circuits(&self) -> Vec<Circuit> {
+        self.circuits
+            .iter()
+            .map(|random_circuit| {
+                let mut circuit = Circuit::new();
+                circuit += DefinitionBit::new(XEB_READOUT.to_string(), self.qubits.len(), true);
+                for operation in random_circuit.iter() {
+                    circuit += operation.clone();
+                }
+                for (position, qubit) in self.qubits.iter().enumerate() {
+                    circuit += MeasureQubit::new(*qubit, XEB_READOUT.to_string(), position);
+                }
+                circuit += PragmaSetNumberOfMeasurements::new(
+                    self.number_measurements,
+                    XEB_READOUT.to_string(),
+                );
+                circuit
+            })
+            .collect()
+    }
+
+    /// Returns the ideal output probabilities of each circuit.
+    ///
+    /// Bit `i` of the basis state index corresponds to the i-th benchmarked qubit.
+    pub fn ideal_probabilities(&self) -> Vec<Vec<f64>> {
+        self.circuits
+            .iter()
+            .map(|circuit| self.simulate(circuit))
+            .collect()
+    }
+
+    /// Runs the experiment on a backend and estimates the fidelities.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend` - The backend the circuits are run on.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(XEBResult)` - The estimated fidelities.
+    /// * `Err(RoqoqoBackendError)` - Running a circuit or the analysis failed.
+    pub fn run<T: EvaluatingBackend>(&self, backend: &T) -> Result<XEBResult, RoqoqoBackendError> {
+        let mut registers: Vec<Vec<Vec<bool>>> = Vec::new();
+        for circuit in self.circuits().iter() {
+            let (bit_registers, _, _) = backend.run_circuit(circuit)?;
+            let register =
+                bit_registers
+                    .get(XEB_READOUT)
+                    .ok_or_else(|| RoqoqoBackendError::GenericError {
+                        msg: format!("Backend did not return the {} register.", XEB_READOUT),
+                    })?;
+            registers.push(register.clone());
+        }
+        self.analyse(&registers)
+    }
+
+    /// Estimates the fidelities from the measured bit registers.
+    ///
+    /// # Arguments
+    ///
+    /// * `registers` - The measured bit registers, one per circuit, in the order of
+    ///                 [XEBExperiment::circuits].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(XEBResult)` - The estimated fidelities.
+    /// * `Err(RoqoqoBackendError)` - The registers do not match the circuits of the experiment.
+    pub fn analyse(&self, registers: &[Vec<Vec<bool>>]) -> Result<XEBResult, RoqoqoBackendError> {
+        if registers.len() != self.circuits.len() {
+            return Err(RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Expected {} registers but {} were given.",
+                    self.circuits.len(),
+                    registers.len()
+                ),
+            });
+        }
+        // Per depth: sums of the measured and of the ideal linear XEB values
+        let mut depths: Vec<usize> = Vec::new();
+        let mut sums: Vec<(f64, f64)> = Vec::new();
+        for ((depth, probabilities), register) in self
+            .depths
+            .iter()
+            .zip(self.ideal_probabilities().iter())
+            .zip(registers.iter())
+        {
+            let measured = linear_xeb_fidelity(probabilities, register)?;
+            let ideal =
+                probabilities.len() as f64 * probabilities.iter().map(|p| p * p).sum::<f64>() - 1.0;
+            match depths.iter().position(|d| d == depth) {
+                Some(index) => {
+                    sums[index].0 += measured;
+                    sums[index].1 += ideal;
+                }
+                None => {
+                    depths.push(*depth);
+                    sums.push((measured, ideal));
+                }
+            }
+        }
+        let mut fidelities: Vec<f64> = Vec::new();
+        for (depth, (measured, ideal)) in depths.iter().zip(sums.iter()) {
+            if *ideal < 1e-9 {
+                return Err(RoqoqoBackendError::GenericError {
+                    msg: format!(
+                        "The ideal output distributions of depth {} are uniform and cannot be benchmarked.",
+                        depth
+                    ),
+                });
+            }
+            fidelities.push(measured / ideal);
+        }
+        let fidelity_per_cycle = fit_fidelity_per_cycle(&depths, &fidelities);
+        Ok(XEBResult {
+            depths,
+            fidelities,
+            fidelity_per_cycle,
+        })
+    }
+
+    /// Computes the output probabilities of a random circuit with a state vector simulation.
+    fn simulate(&self, circuit: &Circuit) -> Vec<f64> {
+        let mut state = vec![Complex64::new(0.0, 0.0); 1 << self.qubits.len()];
+        state[0] = Complex64::new(1.0, 0.0);
+        for operation in circuit.iter() {
+            match operation {
+                Operation::RotateXY(op) => {
+                    let theta = *op.theta().float().unwrap_or(&0.0);
+                    let phi = *op.phi().float().unwrap_or(&0.0);
+                    let cos = Complex64::new((theta / 2.0).cos(), 0.0);
+                    let sin = Complex64::new(0.0, -(theta / 2.0).sin());
+                    let off_diagonal_01 = sin * Complex64::from_polar(1.0, -phi);
+                    let off_diagonal_10 = sin * Complex64::from_polar(1.0, phi);
+                    let mask = 1 << self.position(*op.qubit());
+                    for index in (0..state.len()).filter(|index| index & mask == 0) {
+                        let (zero, one) = (state[index], state[index | mask]);
+                        state[index] = cos * zero + off_diagonal_01 * one;
+                        state[index | mask] = off_diagonal_10 * zero + cos * one;
+                    }
+                }
+                Operation::ControlledPauliZ(op) => {
+                    let mask =
+                        (1 << self.position(*op.control())) | (1 << self.position(*op.target()));
+                    for (index, amplitude) in state.iter_mut().enumerate() {
+                        if index & mask == mask {
+                            *amplitude = -*amplitude;
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        state.iter().map(|amplitude| amplitude.norm_sqr()).collect()
+    }
+
+    /// Returns the position of a qubit in the list of benchmarked qubits.
+    fn position(&self, qubit: usize) -> usize {
+        self.qubits.iter().position(|q| *q == qubit).unwrap_or(0)
+    }
+}
+
+/// Returns the linear XEB fidelity of a measured bit register.
+///
+/// The fidelity is `2^n * <p(x)> - 1`, where `<p(x)>` is the mean ideal probability of
+/// the measured bitstrings. Bit `i` of each shot corresponds to bit `i` of the basis state index.
+///
+/// # Arguments
+///
+/// * `ideal_probabilities` - The ideal output probabilities of the circuit.
+/// * `register` - The measured bit register, one entry per shot.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The linear XEB fidelity.
+/// * `Err(RoqoqoBackendError)` - The register is empty or does not match the probabilities.
+pub fn linear_xeb_fidelity(
+    ideal_probabilities: &[f64],
+    register: &[Vec<bool>],
+) -> Result<f64, RoqoqoBackendError> {
+    if register.is_empty() {
+        return Err(RoqoqoBackendError::GenericError {
+            msg: "Registers without measurements cannot be analysed.".to_string(),
+        });
+    }
+    let mut sum = 0.0;
+    for shot in register.iter() {
+        let index = shot
+            .iter()
+            .enumerate()
+            .fold(0, |index, (bit, value)| index | ((*value as usize) << bit));
+        sum += ideal_probabilities
+            .get(index)
+            .ok_or_else(|| RoqoqoBackendError::GenericError {
+                msg: format!(
+                    "Measured bitstring of length {} does not match {} ideal probabilities.",
+                    shot.len(),
+                    ideal_probabilities.len()
+                ),
+            })?;
+    }
+    Ok(ideal_probabilities.len() as f64 * sum / register.len() as f64 - 1.0)
+}
+
+/// Fits `F(d) = A * f^d` to the positive fidelities with a log-linear least squares fit.
+fn fit_fidelity_per_cycle(depths: &[usize], fidelities: &[f64]) -> Option<f64> {
+    let points: Vec<(f64, f64)> = depths
+        .iter()
+        .zip(fidelities.iter())
+        .filter(|(_, fidelity)| **fidelity > 0.0)
+        .map(|(depth, fidelity)| (*depth as f64, fidelity.ln()))
+        .collect();
+    if points.len() < 2 {
+        return None;
+    }
+    let number_points = points.len() as f64;
+    let mean_depth = points.iter().map(|(d, _)| d).sum::<f64>() / number_points;
+    let mean_log = points.iter().map(|(_, l)| l).sum::<f64>() / number_points;
+    let variance: f64 = points.iter().map(|(d, _)| (d - mean_depth).powi(2)).sum();
+    if variance == 0.0 {
+        return None;
+    }
+    let covariance: f64 = points
+        .iter()
+        .map(|(d, l)| (d - mean_depth) * (l - mean_log))
+        .sum();
+    Some((covariance / variance).exp())
+}
+
+/// Partitions the device connections between the qubits into layers of non-overlapping pairs.
+fn pair_layers<D: Device>(device: &D, qubits: &[usize]) -> Vec<Vec<(usize, usize)>> {
+    let mut layers: Vec<Vec<(usize, usize)>> = Vec::new();
+    for (index, control) in qubits.iter().enumerate() {
+        for target in qubits[index + 1..].iter() {
+            let connected = device
+                .two_qubit_gate_time("ControlledPauliZ", control, target)
+                .is_some()
+                || device
+                    .two_qubit_gate_time("ControlledPauliZ", target, control)
+                    .is_some();
+            if !connected {
+                continue;
+            }
+            let free_layer = layers.iter_mut().find(|layer| {
+                layer
+                    .iter()
+                    .all(|(a, b)| ![a, b].contains(&control) && ![a, b].contains(&target))
+            });
+            match free_layer {
+                Some(layer) => layer.push((*control, *target)),
+                None => layers.push(vec![(*control, *target)]),
+            }
+        }
+    }
+    layers
+}
+
+/// Adds a layer of random `RotateXY(pi/2, phi)` rotations with `phi` a multiple of `pi/4`.
+fn add_rotation_layer(circuit: &mut Circuit, qubits: &[usize], rng: &mut SplitMix64) {
+    for qubit in qubits.iter() {
+        let phi = FRAC_PI_4 * (rng.next() % 8) as f64;
+        *circuit += RotateXY::new(*qubit, FRAC_PI_2.into(), phi.into());
+    }
+}
+
+/// Minimal seedable pseudo random number generator for reproducible circuit generation.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
@@ -19,7 +19,11 @@ use roqoqo::Circuit;
 use roqoqo::QuantumProgram;
 use roqoqo::RoqoqoBackendError;
 use roqoqo_qryd::api_devices::{QRydAPIDevice, QrydEmuSquareDevice, QrydEmuTriangularDevice};
-use roqoqo_qryd::{APIBackend, QRydJobResult, QRydJobStatus, ResultCounts, TweezerDevice};
+use roqoqo_qryd::{
+    readout_registers, APIBackend, APIClientConfig, DevDiagnostics, JobProgress,
+    QRydAPICapabilities, QRydJobResult, QRydJobStatus, ReadoutRegister, ResultCounts,
+    TweezerDevice, SUPPORTED_OPERATIONS,
+};
 
 use qoqo_calculator::CalculatorFloat;
 
@@ -123,10 +127,12 @@ async fn async_api_backend() {
     let qryd_job_status_in_progress = QRydJobStatus {
         status: "in progress".to_string(),
         msg: "the job is still in progress".to_string(),
+        ..Default::default()
     };
     let qryd_job_status_completed = QRydJobStatus {
         status: "completed".to_string(),
         msg: "the job has been completed".to_string(),
+        ..Default::default()
     };
     let result_counts = ResultCounts {
         counts: HashMap::from([("0x1".to_string(), 100), ("0x4".to_string(), 20)]),
@@ -145,6 +151,7 @@ async fn async_api_backend() {
         fusion_generated_gates: 100,
         executed_single_qubit_gates: 50,
         executed_two_qubit_gates: 50,
+        dev_diagnostics: None,
     };
     let _mock_post = Mock::given(method("POST"))
         .respond_with(
@@ -469,6 +476,7 @@ async fn async_api_triangular() {
     let qryd_job_status_completed = QRydJobStatus {
         status: "completed".to_string(),
         msg: "the job has been completed".to_string(),
+        ..Default::default()
     };
     let result_counts = ResultCounts {
         counts: HashMap::from([("0x1".to_string(), 100), ("0x4".to_string(), 20)]),
@@ -487,6 +495,7 @@ async fn async_api_triangular() {
         fusion_generated_gates: 100,
         executed_single_qubit_gates: 50,
         executed_two_qubit_gates: 50,
+        dev_diagnostics: None,
     };
     let _mock_post = Mock::given(method("POST"))
         .respond_with(ResponseTemplate::new(201).insert_header(
@@ -640,14 +649,17 @@ async fn async_evaluating_backend() {
     let qryd_job_status_completed = QRydJobStatus {
         status: "completed".to_string(),
         msg: "the job has been completed".to_string(),
+        ..Default::default()
     };
     let qryd_job_status_error = QRydJobStatus {
         status: "error".to_string(),
         msg: "an error as occured".to_string(),
+        ..Default::default()
     };
     let qryd_job_status_cancelled = QRydJobStatus {
         status: "cancelled".to_string(),
         msg: "the job has been cancelled".to_string(),
+        ..Default::default()
     };
     let _mock_post = Mock::given(method("POST"))
         .respond_with(ResponseTemplate::new(201).insert_header(
@@ -681,6 +693,7 @@ async fn async_evaluating_backend() {
         fusion_generated_gates: 100,
         executed_single_qubit_gates: 50,
         executed_two_qubit_gates: 50,
+        dev_diagnostics: None,
     };
     let _mock_result0 = Mock::given(method("GET"))
         .and(path("/DummyLocation/result"))
@@ -790,6 +803,7 @@ async fn async_evaluating_backend() {
     let unknown_status = QRydJobStatus {
         status: "unknown".to_string(),
         msg: "".to_string(),
+        ..Default::default()
     };
     let _mock_status3 = Mock::given(method("GET"))
         .and(path("/DummyLocation/status"))
@@ -1215,7 +1229,7 @@ fn api_backend_errorcase5() {
         assert_eq!(
             job_loc2.unwrap_err(),
             RoqoqoBackendError::GenericError {
-                msg: "QRyd API Backend only supports posting ClassicalRegister QuantumPrograms"
+                msg: "Cheated measurements read out the simulated state and can not be executed by the QRyd WebAPI, use a PauliZProduct measurement instead"
                     .to_string()
             }
         );
@@ -1305,7 +1319,7 @@ async fn async_api_backend_errorcase5() {
     assert_eq!(
         job_loc2.unwrap_err(),
         RoqoqoBackendError::GenericError {
-            msg: "QRyd API Backend only supports posting ClassicalRegister QuantumPrograms"
+            msg: "Cheated measurements read out the simulated state and can not be executed by the QRyd WebAPI, use a PauliZProduct measurement instead"
                 .to_string()
         }
     );
@@ -1732,6 +1746,104 @@ fn test_unknown_device_error() {
     }
 }
 
+/// Test capability negotiation with the WebAPI
+#[tokio::test]
+async fn async_api_backend_capabilities() {
+    let server_wiremock = MockServer::start().await;
+    let capabilities = QRydAPICapabilities {
+        api_version: "v5_2".to_string(),
+        supported_operations: vec![
+            "DefinitionBit".to_string(),
+            "RotateX".to_string(),
+            "MeasureQubit".to_string(),
+            "PragmaSetNumberOfMeasurements".to_string(),
+        ],
+        supported_fields: vec!["dev".to_string(), "seed_simulator".to_string()],
+    };
+    let _mock_capabilities = Mock::given(method("GET"))
+        .and(path("/capabilities"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&capabilities))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_post = Mock::given(method("POST"))
+        .and(|request: &wiremock::Request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            let fields = body.as_object().unwrap();
+            fields.contains_key("program")
+                && fields.contains_key("seed_simulator")
+                && !fields.contains_key("fusion_max_qubits")
+                && !fields.contains_key("use_extended_set")
+        })
+        .respond_with(ResponseTemplate::new(201).insert_header("Location", "DummyLocation"))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let mut api_backend = APIBackend::new(
+        qryd_device,
+        None,
+        None,
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    assert!(api_backend.recorded_capabilities().is_none());
+
+    let (api_backend, fetched) = tokio::task::spawn_blocking(move || {
+        let fetched = api_backend.capabilities();
+        (api_backend, fetched)
+    })
+    .await
+    .unwrap();
+    assert_eq!(fetched.unwrap(), capabilities);
+    assert_eq!(api_backend.recorded_capabilities(), Some(&capabilities));
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(40, "ro".to_string());
+    let supported_program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit.clone()],
+        },
+        input_parameter_names: vec![],
+    };
+    let mut unsupported_circuit = circuit;
+    unsupported_circuit += operations::Hadamard::new(0);
+    let unsupported_program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![unsupported_circuit],
+        },
+        input_parameter_names: vec![],
+    };
+
+    let (supported, unsupported) = tokio::task::spawn_blocking(move || {
+        (
+            api_backend.post_job(supported_program),
+            api_backend.post_job(unsupported_program),
+        )
+    })
+    .await
+    .unwrap();
+    assert_eq!(supported.unwrap(), "DummyLocation");
+    assert_eq!(
+        unsupported.unwrap_err(),
+        RoqoqoBackendError::GenericError {
+            msg: "Operation Hadamard is not supported by QRydDemo Web API version v5_2."
+                .to_string()
+        }
+    );
+
+    server_wiremock.verify().await;
+}
+
 // /// Test downcovert_roqoqo_version function
 // #[test]
 // fn test_downconvert_roqoqo_version() {
@@ -1854,3 +1966,1066 @@ fn test_unknown_device_error() {
 //     let correct_downconverted_quantum_program = downconvert_roqoqo_version(program);
 //     assert!(correct_downconverted_quantum_program.is_ok());
 // }
+
+// Test the progress callback receiving queue information during synchronous execution (mocked)
+#[tokio::test]
+async fn async_api_backend_progress_callback() {
+    use roqoqo::backends::EvaluatingBackend;
+    use std::sync::{Arc, Mutex};
+
+    let server_wiremock = MockServer::start().await;
+    let qryd_job_status_queued = QRydJobStatus {
+        status: "pending".to_string(),
+        msg: "the job is waiting in the queue".to_string(),
+        queue_position: Some(4),
+        estimated_start_time: Some("2024-05-02T10:00:00Z".to_string()),
+        progress: None,
+    };
+    let qryd_job_status_completed = QRydJobStatus {
+        status: "completed".to_string(),
+        msg: "the job has been completed".to_string(),
+        progress: Some(1.0),
+        ..Default::default()
+    };
+    let qryd_job_result_completed = QRydJobResult {
+        data: ResultCounts {
+            counts: HashMap::from([("0x1".to_string(), 10)]),
+        },
+        ..Default::default()
+    };
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201).insert_header(
+            "Location",
+            &format!("{}/DummyLocation", server_wiremock.uri()),
+        ))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_status_queued = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_queued))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_status_completed = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_completed))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_result = Mock::given(method("GET"))
+        .and(path("/DummyLocation/result"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_result_completed))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let mut api_backend_new = APIBackend::new(
+        qryd_device,
+        None,
+        Some(20),
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    let polled: Arc<Mutex<Vec<JobProgress>>> = Arc::new(Mutex::new(Vec::new()));
+    let polled_cloned = polled.clone();
+    api_backend_new
+        .set_progress_callback(move |progress| polled_cloned.lock().unwrap().push(progress));
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+
+    let (bits, _, _) = tokio::task::spawn_blocking(move || api_backend_new.run_circuit(&circuit))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(bits.get("ro").unwrap().len(), 10);
+
+    let polled = polled.lock().unwrap().clone();
+    assert_eq!(polled.len(), 3);
+    assert_eq!(polled[0].status, qryd_job_status_queued);
+    assert_eq!(polled[1].status.queue_position, Some(4));
+    assert_eq!(polled[2].status.progress, Some(1.0));
+    for (index, progress) in polled.iter().enumerate() {
+        assert_eq!(progress.attempt, index + 1);
+        assert_eq!(progress.max_attempts, 20);
+        assert_eq!(
+            progress.job_location,
+            format!("{}/DummyLocation", server_wiremock.uri())
+        );
+    }
+    assert!(polled[0].elapsed <= polled[2].elapsed);
+
+    server_wiremock.verify().await;
+}
+
+// Test recording the WebAPI requests of a run and replaying them without network access
+#[tokio::test]
+async fn async_api_backend_record_replay() {
+    use roqoqo::backends::EvaluatingBackend;
+    use roqoqo_qryd::{APIFixtureExchange, APIFixtureMode};
+
+    let server_wiremock = MockServer::start().await;
+    let qryd_job_status_running = QRydJobStatus {
+        status: "running".to_string(),
+        msg: "the job is running".to_string(),
+        progress: Some(0.5),
+        ..Default::default()
+    };
+    let qryd_job_status_completed = QRydJobStatus {
+        status: "completed".to_string(),
+        msg: "the job has been completed".to_string(),
+        ..Default::default()
+    };
+    let qryd_job_result_completed = QRydJobResult {
+        data: ResultCounts {
+            counts: HashMap::from([("0x1".to_string(), 10)]),
+        },
+        ..Default::default()
+    };
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201).insert_header(
+            "Location",
+            &format!("{}/DummyLocation", server_wiremock.uri()),
+        ))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_status_running = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_running))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_status_completed = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_completed))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_result = Mock::given(method("GET"))
+        .and(path("/DummyLocation/result"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_result_completed))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+
+    let fixture_file = env::temp_dir().join(format!(
+        "roqoqo_qryd_fixtures_{}.json",
+        server_wiremock.address().port()
+    ));
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let mut recording_backend = APIBackend::new(
+        qryd_device.clone(),
+        None,
+        Some(20),
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(recording_backend.fixture_mode(), None);
+    recording_backend
+        .set_fixture_mode(APIFixtureMode::Record, &fixture_file)
+        .unwrap();
+    assert_eq!(
+        recording_backend.fixture_mode(),
+        Some(APIFixtureMode::Record)
+    );
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+
+    let circuit_cloned = circuit.clone();
+    let (recorded_bits, _, _) =
+        tokio::task::spawn_blocking(move || recording_backend.run_circuit(&circuit_cloned))
+            .await
+            .unwrap()
+            .unwrap();
+    server_wiremock.verify().await;
+
+    let exchanges: Vec<APIFixtureExchange> =
+        serde_json::from_str(&std::fs::read_to_string(&fixture_file).unwrap()).unwrap();
+    assert_eq!(exchanges.len(), 4);
+    assert_eq!(exchanges[0].method, "POST");
+    assert_eq!(exchanges[0].status, 201);
+    assert_eq!(
+        exchanges[0].location,
+        Some(format!("{}/DummyLocation", server_wiremock.uri()))
+    );
+    assert_eq!(exchanges[1].path, "/DummyLocation/status");
+    assert_eq!(exchanges[3].path, "/DummyLocation/result");
+
+    // The replaying backend points to a port without a server
+    let mut replaying_backend = APIBackend::new(
+        qryd_device.clone(),
+        None,
+        Some(20),
+        Some("1".to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    replaying_backend
+        .set_fixture_mode(APIFixtureMode::Replay, &fixture_file)
+        .unwrap();
+    let (replayed_bits, _, _) =
+        tokio::task::spawn_blocking(move || replaying_backend.run_circuit(&circuit))
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(replayed_bits, recorded_bits);
+
+    let mut replaying_backend = APIBackend::new(
+        qryd_device,
+        None,
+        Some(20),
+        Some("1".to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    replaying_backend
+        .set_fixture_mode(APIFixtureMode::Replay, &fixture_file)
+        .unwrap();
+    let job_location = format!("{}/DummyLocation", server_wiremock.uri());
+    let (mut replaying_backend, deleted) = tokio::task::spawn_blocking(move || {
+        let deleted = replaying_backend.delete_job(job_location);
+        (replaying_backend, deleted)
+    })
+    .await
+    .unwrap();
+    assert!(deleted.is_err());
+    replaying_backend.clear_fixture_mode();
+    assert_eq!(replaying_backend.fixture_mode(), None);
+    assert!(replaying_backend
+        .set_fixture_mode(
+            APIFixtureMode::Replay,
+            env::temp_dir().join("missing_fixtures.json")
+        )
+        .is_err());
+
+    std::fs::remove_file(&fixture_file).unwrap();
+}
+
+// Test running the same circuit once per simulator seed
+#[tokio::test]
+async fn async_api_backend_run_circuit_with_seeds() {
+    use wiremock::matchers::body_partial_json;
+
+    let server_wiremock = MockServer::start().await;
+    let qryd_job_status_completed = QRydJobStatus {
+        status: "completed".to_string(),
+        msg: "the job has been completed".to_string(),
+        ..Default::default()
+    };
+    for (seed, counts) in [(3_usize, "0x1"), (7_usize, "0x0")] {
+        let job_path = format!("/Job{}", seed);
+        let _mock_post = Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "seed_simulator": seed }),
+            ))
+            .respond_with(ResponseTemplate::new(201).insert_header(
+                "Location",
+                &format!("{}{}", server_wiremock.uri(), job_path),
+            ))
+            .expect(1)
+            .mount(&server_wiremock)
+            .await;
+        let _mock_status = Mock::given(method("GET"))
+            .and(path(format!("{}/status", job_path)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_completed))
+            .expect(1)
+            .mount(&server_wiremock)
+            .await;
+        let _mock_result = Mock::given(method("GET"))
+            .and(path(format!("{}/result", job_path)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&QRydJobResult {
+                data: ResultCounts {
+                    counts: HashMap::from([(counts.to_string(), 10)]),
+                },
+                ..Default::default()
+            }))
+            .expect(1)
+            .mount(&server_wiremock)
+            .await;
+    }
+
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let api_backend_new = APIBackend::new(
+        qryd_device,
+        None,
+        Some(20),
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+
+    let cloned_circuit = circuit.clone();
+    let cloned_backend = api_backend_new.clone();
+    let results = tokio::task::spawn_blocking(move || {
+        cloned_backend.run_circuit_with_seeds(&cloned_circuit, &[3, 7])
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[&3].0["ro"].len(), 10);
+    assert_eq!(results[&7].0["ro"].len(), 10);
+    assert!(results[&3].0["ro"].iter().all(|shot| shot[0] && !shot[1]));
+    assert!(results[&7].0["ro"].iter().all(|shot| !shot[0] && !shot[1]));
+    server_wiremock.verify().await;
+
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec![],
+    };
+    assert!(api_backend_new
+        .post_job_with_seeds(program.clone(), &[])
+        .is_err());
+    assert!(api_backend_new
+        .post_job_with_seeds(program, &[1, 1])
+        .is_err());
+}
+
+// Test converting the counts to a boolean matrix of the measured bits
+#[test]
+fn api_backend_counts_to_array() {
+    let counts = ResultCounts {
+        counts: HashMap::from([("0x1".to_string(), 3), ("0x6".to_string(), 2)]),
+    };
+    let bits = APIBackend::counts_to_array(&counts, 3).unwrap();
+    assert_eq!(bits.shape(), &[5, 3]);
+    for row in 0..3 {
+        assert_eq!(bits.row(row).to_vec(), vec![true, false, false]);
+    }
+    for row in 3..5 {
+        assert_eq!(bits.row(row).to_vec(), vec![false, true, true]);
+    }
+
+    let (registers, _, _) = APIBackend::counts_to_result(counts, "ro".to_string(), 3).unwrap();
+    let mut from_result = registers["ro"].clone();
+    let mut from_array: Vec<Vec<bool>> = bits.rows().into_iter().map(|r| r.to_vec()).collect();
+    from_result.sort();
+    from_array.sort();
+    assert_eq!(from_array, from_result);
+
+    let bits = APIBackend::counts_to_array(&ResultCounts::default(), 2).unwrap();
+    assert_eq!(bits.shape(), &[0, 2]);
+
+    let invalid_counts = ResultCounts {
+        counts: HashMap::from([("1x1".to_string(), 3)]),
+    };
+    assert!(APIBackend::counts_to_array(&invalid_counts, 2).is_err());
+}
+
+// Test posting PauliZProduct measurements and evaluating the expectation values locally
+#[tokio::test]
+async fn async_api_backend_post_measurement_jobs() {
+    let number_qubits = 6;
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), number_qubits, true);
+    circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+    circuit += operations::RotateX::new(2, std::f64::consts::PI.into());
+    circuit += operations::RotateX::new(4, std::f64::consts::PI.into());
+    for i in 0..number_qubits {
+        circuit += operations::MeasureQubit::new(i, "ro".to_string(), i);
+    }
+    circuit += operations::PragmaSetNumberOfMeasurements::new(40, "ro".to_string());
+
+    let mut input = PauliZProductInput::new(6, false);
+    let index = input
+        .add_pauliz_product("ro".to_string(), vec![0, 2, 4])
+        .unwrap();
+    input
+        .add_linear_exp_val("test".to_string(), HashMap::from([(index, 3.0)]))
+        .unwrap();
+    let program = QuantumProgram::PauliZProduct {
+        measurement: PauliZProduct {
+            input: input.clone(),
+            constant_circuit: None,
+            circuits: vec![circuit.clone()],
+        },
+        input_parameter_names: vec![],
+    };
+    let two_circuit_program = QuantumProgram::PauliZProduct {
+        measurement: PauliZProduct {
+            input,
+            constant_circuit: None,
+            circuits: vec![circuit.clone(), circuit],
+        },
+        input_parameter_names: vec![],
+    };
+
+    let server_wiremock = MockServer::start().await;
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201).insert_header(
+            "Location",
+            &format!("{}/DummyLocation", server_wiremock.uri()),
+        ))
+        .expect(3)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_status = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&QRydJobStatus {
+            status: "completed".to_string(),
+            msg: "the job has been completed".to_string(),
+            ..Default::default()
+        }))
+        .mount(&server_wiremock)
+        .await;
+    let _mock_result = Mock::given(method("GET"))
+        .and(path("/DummyLocation/result"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&QRydJobResult {
+            data: ResultCounts {
+                counts: HashMap::from([("0x15".to_string(), 40)]),
+            },
+            ..Default::default()
+        }))
+        .mount(&server_wiremock)
+        .await;
+
+    let api_backend_new = APIBackend::new(
+        qryd_device,
+        None,
+        Some(20),
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let api_backend_new_cloned = api_backend_new.clone();
+    let program_cloned = program.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        let job_location = api_backend_new_cloned.post_job(program_cloned.clone())?;
+        api_backend_new_cloned.evaluate_measurement_jobs(&program_cloned, &[job_location])
+    })
+    .await
+    .unwrap()
+    .unwrap()
+    .unwrap();
+    assert_eq!(result.get("test"), Some(&-3.0));
+
+    let api_backend_new_cloned = api_backend_new.clone();
+    let (single_post, result, wrong_locations) = tokio::task::spawn_blocking(move || {
+        let single_post = api_backend_new_cloned.post_job(two_circuit_program.clone());
+        let job_locations = api_backend_new_cloned
+            .post_measurement_jobs(two_circuit_program.clone())
+            .unwrap();
+        assert_eq!(job_locations.len(), 2);
+        let result =
+            api_backend_new_cloned.evaluate_measurement_jobs(&two_circuit_program, &job_locations);
+        let wrong_locations = api_backend_new_cloned
+            .evaluate_measurement_jobs(&two_circuit_program, &job_locations[..1]);
+        (single_post, result, wrong_locations)
+    })
+    .await
+    .unwrap();
+    assert!(single_post.is_err());
+    assert_eq!(result.unwrap().unwrap().get("test"), Some(&-3.0));
+    assert!(wrong_locations.is_err());
+
+    let classical_program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![],
+        },
+        input_parameter_names: vec![],
+    };
+    assert!(api_backend_new
+        .evaluate_measurement_jobs(&classical_program, &[])
+        .is_err());
+
+    server_wiremock.verify().await;
+}
+
+// Test that post_job accepts exactly the operations of SUPPORTED_OPERATIONS
+#[test]
+fn api_backend_supported_operations_consistency() {
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let api_backend_new = APIBackend::new(
+        QRydAPIDevice::from(&device),
+        None,
+        None,
+        Some("12345".to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    let program_with = |operation: operations::Operation| {
+        let mut circuit = Circuit::new();
+        circuit += operations::DefinitionBit::new("ro".to_string(), 3, true);
+        circuit.add_operation(operation);
+        circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+        QuantumProgram::ClassicalRegister {
+            measurement: ClassicalRegister {
+                constant_circuit: None,
+                circuits: vec![circuit],
+            },
+            input_parameter_names: vec![],
+        }
+    };
+
+    let mut expected: Vec<String> = SUPPORTED_OPERATIONS
+        .iter()
+        .filter(|name| **name != "PragmaActiveReset")
+        .map(|name| name.to_string())
+        .collect();
+    expected.sort();
+    assert_eq!(api_backend_new.supported_operations(), expected);
+
+    let error = api_backend_new
+        .post_job(program_with(operations::Toffoli::new(0, 1, 2).into()))
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Operation Toffoli is not supported by QRydDemo Web API backend."));
+    for name in SUPPORTED_OPERATIONS.iter() {
+        assert!(error.contains(name));
+    }
+
+    let error = api_backend_new
+        .post_job(program_with(operations::PragmaActiveReset::new(0).into()))
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("qryd_emulator"));
+}
+
+// Test extending and restricting the operations accepted by post_job
+#[tokio::test]
+async fn async_api_backend_operation_whitelist() {
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let server_wiremock = MockServer::start().await;
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201).insert_header(
+            "Location",
+            &format!("{}/DummyLocation", server_wiremock.uri()),
+        ))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let mut api_backend_new = APIBackend::new(
+        qryd_device,
+        None,
+        None,
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    let program_with = |operation: operations::Operation| {
+        let mut circuit = Circuit::new();
+        circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+        circuit.add_operation(operation);
+        circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+        circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+        QuantumProgram::ClassicalRegister {
+            measurement: ClassicalRegister {
+                constant_circuit: None,
+                circuits: vec![circuit],
+            },
+            input_parameter_names: vec![],
+        }
+    };
+    let damping = program_with(operations::PragmaDamping::new(0, 1.0.into(), 0.1.into()).into());
+    let rotation = program_with(operations::RotateX::new(0, 1.0.into()).into());
+
+    let supported = api_backend_new.supported_operations();
+    assert!(supported.contains(&"RotateX".to_string()));
+    assert!(!supported.contains(&"PragmaActiveReset".to_string()));
+    assert!(!supported.contains(&"PragmaDamping".to_string()));
+    assert!(api_backend_new.allow_operation("PragmaDamping").is_err());
+
+    api_backend_new.set_dev(true);
+    api_backend_new.allow_operation("PragmaDamping").unwrap();
+    assert!(api_backend_new
+        .supported_operations()
+        .contains(&"PragmaDamping".to_string()));
+    api_backend_new.disallow_operation("RotateX");
+    assert!(!api_backend_new
+        .supported_operations()
+        .contains(&"RotateX".to_string()));
+
+    let api_backend_new_cloned = api_backend_new.clone();
+    let (damping_result, rotation_result) = tokio::task::spawn_blocking(move || {
+        (
+            api_backend_new_cloned.post_job(damping),
+            api_backend_new_cloned.post_job(rotation),
+        )
+    })
+    .await
+    .unwrap();
+    assert!(damping_result.is_ok());
+    assert!(rotation_result.is_err());
+
+    api_backend_new.set_dev(false);
+    assert!(!api_backend_new
+        .supported_operations()
+        .contains(&"PragmaDamping".to_string()));
+    api_backend_new.set_dev(true);
+    api_backend_new.reset_supported_operations();
+    let supported = api_backend_new.supported_operations();
+    assert!(supported.contains(&"RotateX".to_string()));
+    assert!(!supported.contains(&"PragmaDamping".to_string()));
+
+    server_wiremock.verify().await;
+}
+
+// Test substituting symbolic parameters before posting a job
+#[tokio::test]
+async fn async_api_backend_post_job_with_parameters() {
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let server_wiremock = MockServer::start().await;
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201).insert_header(
+            "Location",
+            &format!("{}/DummyLocation", server_wiremock.uri()),
+        ))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let api_backend_new = APIBackend::new(
+        qryd_device,
+        None,
+        None,
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    constant_circuit += operations::RotateX::new(0, CalculatorFloat::from("theta"));
+    let mut circuit = Circuit::new();
+    circuit += operations::RotateZ::new(0, CalculatorFloat::from("2 * theta"));
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: Some(constant_circuit),
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec!["theta".to_string()],
+    };
+
+    let (unsubstituted, wrong_number, substituted) = tokio::task::spawn_blocking(move || {
+        (
+            api_backend_new.post_job(program.clone()),
+            api_backend_new.post_job_with_parameters(program.clone(), &[]),
+            api_backend_new.post_job_with_parameters(program, &[0.5]),
+        )
+    })
+    .await
+    .unwrap();
+    assert!(unsubstituted.is_err());
+    assert!(wrong_number.is_err());
+    assert_eq!(
+        substituted.unwrap(),
+        format!("{}/DummyLocation", server_wiremock.uri())
+    );
+
+    server_wiremock.verify().await;
+}
+
+// Test reusing the configured HTTP client for the requests of a backend
+#[tokio::test]
+async fn async_api_backend_client_config() {
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let server_wiremock = MockServer::start().await;
+    let uri = server_wiremock.uri();
+    let qryd_job_status_completed = QRydJobStatus {
+        status: "completed".to_string(),
+        msg: "the job has been completed".to_string(),
+        ..Default::default()
+    };
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(201).insert_header("Location", &format!("{}/DummyLocation", uri)),
+        )
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_status = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_completed))
+        .expect(3)
+        .mount(&server_wiremock)
+        .await;
+    let mut api_backend_new = APIBackend::new(
+        qryd_device,
+        None,
+        None,
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(api_backend_new.client_config(), &APIClientConfig::default());
+    let client_config = APIClientConfig {
+        pool_max_idle_per_host: Some(2),
+        pool_idle_timeout: Some(time::Duration::from_secs(30)),
+        tcp_keepalive: Some(time::Duration::from_secs(10)),
+        request_timeout: Some(time::Duration::from_secs(5)),
+        connect_timeout: Some(time::Duration::from_secs(1)),
+    };
+    api_backend_new.set_client_config(client_config.clone());
+    assert_eq!(api_backend_new.client_config(), &client_config);
+    let serialized = serde_json::to_string(&api_backend_new).unwrap();
+    let deserialized: APIBackend = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, api_backend_new);
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec![],
+    };
+
+    let statuses = tokio::task::spawn_blocking(move || {
+        let job_loc = api_backend_new.post_job(program).unwrap();
+        (0..3)
+            .map(|_| api_backend_new.get_job_status(job_loc.clone()))
+            .collect::<Vec<Result<QRydJobStatus, RoqoqoBackendError>>>()
+    })
+    .await
+    .unwrap();
+    for status in statuses {
+        assert_eq!(status.unwrap(), qryd_job_status_completed);
+    }
+
+    server_wiremock.verify().await;
+}
+
+// Test splitting the counts of a circuit with several readout registers
+#[test]
+fn api_backend_counts_to_result_registers() {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::DefinitionFloat::new("angles".to_string(), 1, false);
+    circuit += operations::DefinitionBit::new("flags".to_string(), 1, true);
+    let registers = readout_registers(&circuit).unwrap();
+    assert_eq!(
+        registers,
+        vec![
+            ReadoutRegister {
+                name: "ro".to_string(),
+                offset: 0,
+                length: 2
+            },
+            ReadoutRegister {
+                name: "flags".to_string(),
+                offset: 2,
+                length: 1
+            },
+        ]
+    );
+
+    let counts = ResultCounts {
+        counts: HashMap::from([("0x5".to_string(), 2), ("0x6".to_string(), 1)]),
+    };
+    let (bit_registers, float_registers, complex_registers) =
+        APIBackend::counts_to_result_registers(counts, &registers).unwrap();
+    assert!(float_registers.is_empty());
+    assert!(complex_registers.is_empty());
+    assert_eq!(bit_registers.len(), 2);
+    let mut ro = bit_registers["ro"].clone();
+    ro.sort();
+    assert_eq!(
+        ro,
+        vec![vec![false, true], vec![true, false], vec![true, false]]
+    );
+    assert_eq!(bit_registers["flags"], vec![vec![true]; 3]);
+
+    let duplicated = vec![registers[0].clone(), registers[0].clone()];
+    assert!(APIBackend::counts_to_result_registers(ResultCounts::default(), &duplicated).is_err());
+    let overlapping = vec![
+        registers[0].clone(),
+        ReadoutRegister {
+            name: "flags".to_string(),
+            offset: 1,
+            length: 1,
+        },
+    ];
+    assert!(APIBackend::counts_to_result_registers(ResultCounts::default(), &overlapping).is_err());
+
+    let mut twice_defined = circuit.clone();
+    twice_defined += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    assert!(readout_registers(&twice_defined).is_err());
+}
+
+// Test that the register bits are laid out in definition order, not in measurement order
+#[test]
+fn api_backend_counts_to_result_registers_definition_order() {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("flags".to_string(), 1, true);
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::PauliX::new(1);
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += operations::MeasureQubit::new(2, "flags".to_string(), 0);
+    let registers = readout_registers(&circuit).unwrap();
+    assert_eq!(
+        registers,
+        vec![
+            ReadoutRegister {
+                name: "flags".to_string(),
+                offset: 0,
+                length: 1
+            },
+            ReadoutRegister {
+                name: "ro".to_string(),
+                offset: 1,
+                length: 2
+            },
+        ]
+    );
+
+    // flags[0] = false, ro[0] = false, ro[1] = true
+    let counts = ResultCounts {
+        counts: HashMap::from([("0x4".to_string(), 3)]),
+    };
+    let (bit_registers, _, _) = APIBackend::counts_to_result_registers(counts, &registers).unwrap();
+    assert_eq!(bit_registers["flags"], vec![vec![false]; 3]);
+    assert_eq!(bit_registers["ro"], vec![vec![false, true]; 3]);
+}
+
+// Test retrieving the job result as untyped JSON, keeping unknown fields
+#[tokio::test]
+async fn async_api_backend_get_job_result_raw() {
+    let server_wiremock = MockServer::start().await;
+    let raw_result = serde_json::json!({
+        "data": { "counts": { "0x1": 10 } },
+        "time_taken": 0.5,
+        "precision": 1e-3,
+        "qubit_error_rates": [0.01, 0.02],
+    });
+    let _mock_result = Mock::given(method("GET"))
+        .and(path("/DummyLocation/result"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&raw_result))
+        .expect(2)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_missing = Mock::given(method("GET"))
+        .and(path("/MissingLocation/result"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let api_backend_new = APIBackend::new(
+        qryd_device,
+        None,
+        Some(20),
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let uri = server_wiremock.uri();
+    let (raw, typed, missing) = tokio::task::spawn_blocking(move || {
+        (
+            api_backend_new.get_job_result_raw(format!("{}/DummyLocation", uri)),
+            api_backend_new.get_job_result(format!("{}/DummyLocation", uri)),
+            api_backend_new.get_job_result_raw(format!("{}/MissingLocation", uri)),
+        )
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(raw.unwrap(), raw_result);
+    let typed = typed.unwrap();
+    assert_eq!(typed.data.counts.get("0x1"), Some(&10));
+    assert_eq!(typed.time_taken, 0.5);
+    let dev_diagnostics = typed.dev_diagnostics.unwrap();
+    assert_eq!(
+        dev_diagnostics.names(),
+        vec!["precision", "qubit_error_rates"]
+    );
+    assert_eq!(
+        dev_diagnostics.get("qubit_error_rates"),
+        Some(&serde_json::json!([0.01, 0.02]))
+    );
+    assert!(missing.is_err());
+    server_wiremock.verify().await;
+}
+
+// Test the diagnostic fields of job results returned by the develop version of the WebAPI
+#[test]
+fn api_backend_dev_diagnostics() {
+    let stable: QRydJobResult =
+        serde_json::from_str(r#"{"data": {"counts": {"0x1": 10}}, "num_qubits": 2}"#).unwrap();
+    assert_eq!(stable.dev_diagnostics, None);
+    let serialized = serde_json::to_value(&stable).unwrap();
+    assert!(serialized.get("dev_diagnostics").is_none());
+
+    let dev: QRydJobResult = serde_json::from_str(
+        r#"{"data": {"counts": {"0x1": 10}}, "num_qubits": 2, "compiled_depth": 12, "mapping": {"0": 3}}"#,
+    )
+    .unwrap();
+    assert_eq!(dev.num_qubits, 2);
+    let dev_diagnostics: &DevDiagnostics = dev.dev_diagnostics.as_ref().unwrap();
+    assert_eq!(dev_diagnostics.names(), vec!["compiled_depth", "mapping"]);
+    assert_eq!(
+        dev_diagnostics.get("compiled_depth"),
+        Some(&serde_json::json!(12))
+    );
+    assert_eq!(dev_diagnostics.get("missing"), None);
+
+    // The diagnostic fields are serialized at the top level of the result again
+    let serialized = serde_json::to_value(&dev).unwrap();
+    assert_eq!(serialized["compiled_depth"], serde_json::json!(12));
+    let deserialized: QRydJobResult = serde_json::from_value(serialized).unwrap();
+    assert_eq!(deserialized.dev_diagnostics, dev.dev_diagnostics);
+}
+
+// Test comparing the runs of a program on two WebAPI versions
+#[tokio::test]
+async fn async_api_backend_compare_api_versions() {
+    use roqoqo_qryd::compare_api_versions;
+
+    let qryd_job_status_completed = QRydJobStatus {
+        status: "completed".to_string(),
+        msg: "the job has been completed".to_string(),
+        ..Default::default()
+    };
+    let mut servers: Vec<MockServer> = Vec::new();
+    for (counts, expected_calls) in [
+        (HashMap::from([("0x1".to_string(), 10)]), 3),
+        (
+            HashMap::from([("0x01".to_string(), 5), ("0x0".to_string(), 5)]),
+            1,
+        ),
+    ] {
+        let server_wiremock = MockServer::start().await;
+        let _mock_post = Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(201).insert_header(
+                "Location",
+                &format!("{}/DummyLocation", server_wiremock.uri()),
+            ))
+            .expect(expected_calls)
+            .mount(&server_wiremock)
+            .await;
+        let _mock_status = Mock::given(method("GET"))
+            .and(path("/DummyLocation/status"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_completed))
+            .expect(expected_calls)
+            .mount(&server_wiremock)
+            .await;
+        let _mock_result = Mock::given(method("GET"))
+            .and(path("/DummyLocation/result"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&QRydJobResult {
+                data: ResultCounts { counts },
+                num_qubits: 2,
+                ..Default::default()
+            }))
+            .expect(expected_calls)
+            .mount(&server_wiremock)
+            .await;
+        servers.push(server_wiremock);
+    }
+
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let qryd_device: QRydAPIDevice = QRydAPIDevice::from(&device);
+    let reference = APIBackend::new(
+        qryd_device.clone(),
+        None,
+        Some(20),
+        Some(servers[0].address().port().to_string()),
+        None,
+        Some("v1_1".to_string()),
+    )
+    .unwrap();
+    let candidate = APIBackend::new(
+        qryd_device,
+        None,
+        Some(20),
+        Some(servers[1].address().port().to_string()),
+        Some(true),
+        None,
+    )
+    .unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec![],
+    };
+
+    let (differing, consistent, invalid) = tokio::task::spawn_blocking(move || {
+        (
+            compare_api_versions(&reference, &candidate, program.clone(), 0.1),
+            compare_api_versions(&reference, &reference, program.clone(), 0.0),
+            compare_api_versions(&reference, &candidate, program, -1.0),
+        )
+    })
+    .await
+    .unwrap();
+
+    let differing = differing.unwrap();
+    assert_eq!(differing.reference_version, "v1_1");
+    assert_eq!(differing.candidate_version, "v5_2");
+    assert_eq!(differing.reference_status, "completed");
+    assert_eq!(differing.candidate_status, "completed");
+    assert_eq!(differing.total_variation_distance, Some(0.5));
+    assert_eq!(differing.discrepancies.len(), 1);
+    assert!(!differing.is_consistent());
+
+    let consistent = consistent.unwrap();
+    assert_eq!(consistent.total_variation_distance, Some(0.0));
+    assert!(consistent.is_consistent());
+
+    assert!(invalid.is_err());
+    for server in servers {
+        server.verify().await;
+    }
+}
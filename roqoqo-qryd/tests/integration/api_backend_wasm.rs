@@ -0,0 +1,189 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use roqoqo::measurements::ClassicalRegister;
+use roqoqo::operations;
+use roqoqo::{Circuit, QuantumProgram};
+use roqoqo_qryd::api_backend_wasm::{AsyncAPIBackend, QRydJobResult, QRydJobStatus, ResultCounts};
+use roqoqo_qryd::api_devices::{QRydAPIDevice, QrydEmuSquareDevice};
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn program() -> QuantumProgram {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+    circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+    QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec![],
+    }
+}
+
+/// Test posting, polling and downloading a job with the AsyncAPIBackend
+#[tokio::test]
+async fn async_api_backend_wasm() {
+    let server_wiremock = MockServer::start().await;
+    let uri = server_wiremock.uri();
+    let qryd_job_status_completed = QRydJobStatus {
+        status: "completed".to_string(),
+        msg: "the job has been completed".to_string(),
+        ..Default::default()
+    };
+    let qryd_job_result_completed = QRydJobResult {
+        data: ResultCounts {
+            counts: HashMap::from([("0x1".to_string(), 10)]),
+        },
+        num_qubits: 2,
+        num_clbits: 2,
+        ..Default::default()
+    };
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(201).insert_header("Location", &format!("{}/DummyLocation", uri)),
+        )
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_status = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_completed))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_result = Mock::given(method("GET"))
+        .and(path("/DummyLocation/result"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_result_completed))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_delete = Mock::given(method("DELETE"))
+        .and(path("/DummyLocation"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let backend = AsyncAPIBackend::new(
+        QRydAPIDevice::from(&device),
+        None,
+        Some(3),
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(backend.timeout(), 3);
+
+    let job_location = backend.post_job(program()).await.unwrap();
+    assert_eq!(job_location, format!("{}/DummyLocation", uri));
+    let result = backend
+        .wait_for_result(job_location.clone(), Duration::from_millis(10))
+        .await
+        .unwrap();
+    assert_eq!(result.data.counts.get("0x1"), Some(&10));
+    assert_eq!(result.num_qubits, 2);
+    backend.delete_job(job_location).await.unwrap();
+
+    server_wiremock.verify().await;
+}
+
+/// Test error cases of the AsyncAPIBackend
+#[tokio::test]
+async fn async_api_backend_wasm_errors() {
+    let server_wiremock = MockServer::start().await;
+    let uri = server_wiremock.uri();
+    let qryd_job_status_in_progress = QRydJobStatus {
+        status: "in progress".to_string(),
+        ..Default::default()
+    };
+    let _mock_status = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&qryd_job_status_in_progress))
+        .expect(2)
+        .mount(&server_wiremock)
+        .await;
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&server_wiremock)
+        .await;
+
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let backend = AsyncAPIBackend::new(
+        QRydAPIDevice::from(&device),
+        None,
+        Some(2),
+        Some(server_wiremock.address().port().to_string()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // Only ClassicalRegister programs without parameters are supported
+    let parametrized = match program() {
+        QuantumProgram::ClassicalRegister { measurement, .. } => {
+            QuantumProgram::ClassicalRegister {
+                measurement,
+                input_parameter_names: vec!["theta".to_string()],
+            }
+        }
+        _ => unreachable!(),
+    };
+    assert!(backend.post_job(parametrized).await.is_err());
+
+    // Programs the WebAPI can not execute are rejected before posting
+    let unsupported = match program() {
+        QuantumProgram::ClassicalRegister {
+            mut measurement,
+            input_parameter_names,
+        } => {
+            measurement.circuits[0] += operations::Toffoli::new(0, 1, 2);
+            QuantumProgram::ClassicalRegister {
+                measurement,
+                input_parameter_names,
+            }
+        }
+        _ => unreachable!(),
+    };
+    let error = backend.post_job(unsupported).await.unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Operation Toffoli is not supported by QRydDemo Web API backend."));
+    let mut symbolic = Circuit::new();
+    symbolic += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    symbolic += operations::RotateX::new(0, "theta".into());
+    let symbolic = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![symbolic],
+        },
+        input_parameter_names: vec![],
+    };
+    assert!(backend.post_job(symbolic).await.is_err());
+
+    assert!(backend.post_job(program()).await.is_err());
+    assert!(backend
+        .wait_for_result(format!("{}/DummyLocation", uri), Duration::from_millis(10))
+        .await
+        .is_err());
+
+    server_wiremock.verify().await;
+}
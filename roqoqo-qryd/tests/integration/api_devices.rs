@@ -434,9 +434,9 @@ fn test_gatetimes_tweezer() {
     device
         .set_tweezer_three_qubit_gate_time("ControlledControlledPauliZ", 0, 1, 2, 0.34, None)
         .unwrap();
-    // device
-    //     .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2], 0.23, None)
-    //     .unwrap();
+    device
+        .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2], 0.23, None)
+        .unwrap();
     device.add_qubit_tweezer_mapping(0, 0).unwrap();
     device.add_qubit_tweezer_mapping(1, 1).unwrap();
     device.add_qubit_tweezer_mapping(2, 2).unwrap();
@@ -470,10 +470,10 @@ fn test_gatetimes_tweezer() {
         device.three_qubit_gate_time("ControlledControlledPauliZ", &0, &1, &2),
         apidevice.three_qubit_gate_time("ControlledControlledPauliZ", &0, &1, &2)
     );
-    // assert_eq!(
-    //     device.multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2]),
-    //     apidevice.multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2])
-    // );
+    assert_eq!(
+        device.multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2]),
+        apidevice.multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2])
+    );
 }
 
 // Test gatetime gate category
@@ -0,0 +1,110 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::{operations::*, Circuit};
+use roqoqo_qryd::{device_hash, BoundCircuit, TweezerDevice};
+use std::collections::HashMap;
+
+fn create_device() -> TweezerDevice {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..3 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.1, Some("default".into()))
+            .unwrap();
+    }
+    device
+        .set_tweezer_two_qubit_gate_time(
+            "PhaseShiftedControlledZ",
+            0,
+            1,
+            0.5,
+            Some("default".into()),
+        )
+        .unwrap();
+    device.switch_layout("default", None).unwrap();
+    device
+}
+
+/// Test binding a circuit to a TweezerDevice
+#[test]
+fn test_bind() {
+    let device = create_device();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += RotateX::new(2, 0.5.into());
+    circuit += PhaseShiftedControlledZ::new(0, 1, 0.2.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+
+    let bound = BoundCircuit::bind(circuit.clone(), &device).unwrap();
+    assert_eq!(bound.circuit(), &circuit);
+    assert_eq!(bound.device_hash(), device_hash(&device).unwrap());
+    assert_eq!(
+        bound.mapping(),
+        Some(&HashMap::from([(0, 0), (1, 1), (2, 2)]))
+    );
+    assert_eq!(bound.layout(), Some("default"));
+    assert!(bound.verify(&device).is_ok());
+    assert!(bound.verify(&device.clone()).is_ok());
+
+    // Any change of the device invalidates the binding
+    let mut changed = device.clone();
+    changed
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.2, None)
+        .unwrap();
+    assert!(bound.verify(&changed).is_err());
+    let mut remapped = device.clone();
+    remapped.add_qubit_tweezer_mapping(0, 2).unwrap();
+    assert!(bound.verify(&remapped).is_err());
+}
+
+/// Test binding circuits not supported by a TweezerDevice
+#[test]
+fn test_bind_unsupported() {
+    let device = create_device();
+
+    let mut circuit = Circuit::new();
+    circuit += PhaseShiftedControlledZ::new(1, 2, 0.2.into());
+    assert!(BoundCircuit::bind(circuit, &device).is_err());
+
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(3, 0.5.into());
+    assert!(BoundCircuit::bind(circuit, &device).is_err());
+
+    let mut circuit = Circuit::new();
+    circuit += RotateY::new(0, 0.5.into());
+    assert!(BoundCircuit::bind(circuit, &device).is_err());
+}
+
+/// Test running bound circuits on the SimulatorBackend
+#[cfg(feature = "simulator")]
+#[test]
+fn test_run_bound_circuit() {
+    use roqoqo_qryd::SimulatorBackend;
+
+    let device = create_device();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    let bound = BoundCircuit::bind(circuit, &device).unwrap();
+
+    let backend = SimulatorBackend::new(device.clone(), None);
+    let (bits, _, _) = backend.run_bound_circuit(&bound).unwrap();
+    assert_eq!(bits["ro"].len(), 10);
+    assert!(bits["ro"].iter().all(|measured| measured[0]));
+
+    let mut changed = device;
+    changed.set_allow_reset(true).unwrap();
+    let backend = SimulatorBackend::new(changed, None);
+    assert!(backend.run_bound_circuit(&bound).is_err());
+}
@@ -0,0 +1,223 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::devices::Device;
+use roqoqo::RoqoqoBackendError;
+use roqoqo_qryd::TweezerDevice;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+const CALIBRATION_JSON: &str = r#"{
+    "controlled_z_phase_relation": "2.13",
+    "default_layout": "triangle",
+    "layouts": {
+        "triangle": {
+            "single_qubit_gates": {
+                "RotateX": [
+                    {"tweezer": 0, "time": 0.1, "fidelity": 0.999},
+                    {"tweezer": 1, "time": 0.1},
+                    {"tweezer": 2, "time": 0.2}
+                ]
+            },
+            "two_qubit_gates": {
+                "PhaseShiftedControlledZ": [{"tweezers": [0, 1], "time": 0.5, "fidelity": 0.99}]
+            },
+            "three_qubit_gates": {
+                "ControlledControlledPauliZ": [{"tweezers": [0, 1, 2], "time": 1.0}]
+            },
+            "shifts": [{"tweezer": 0, "shifts": [[1, 2]]}],
+            "coordinates": [
+                {"tweezer": 0, "x": 0.0, "y": 0.0},
+                {"tweezer": 1, "x": 1.0, "y": 0.0},
+                {"tweezer": 2, "x": 0.5, "y": 0.8}
+            ],
+            "tweezers_per_row": [2, 1]
+        },
+        "line": {
+            "single_qubit_gates": {"RotateZ": [{"tweezer": 0, "time": 0.3}]}
+        }
+    }
+}"#;
+
+#[cfg(feature = "yaml")]
+const CALIBRATION_YAML: &str = "
+layouts:
+  default:
+    single_qubit_gates:
+      RotateX:
+        - {tweezer: 0, time: 0.1, fidelity: 0.999}
+        - {tweezer: 1, time: 0.1}
+    two_qubit_gates:
+      PhaseShiftedControlledZ:
+        - tweezers: [0, 1]
+          time: 0.5
+";
+
+/// Writes a calibration file to the temporary directory and returns its path.
+fn write_calibration(file_name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(file_name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn error_message(result: Result<TweezerDevice, RoqoqoBackendError>) -> String {
+    match result {
+        Err(RoqoqoBackendError::GenericError { msg }) => msg,
+        other => panic!("Expected a GenericError, got {:?}", other),
+    }
+}
+
+/// Test TweezerDevice::from_calibration_file with a JSON calibration file
+#[test]
+fn test_from_calibration_file_json() {
+    let path = write_calibration("qryd_calibration_test.json", CALIBRATION_JSON);
+    let device = TweezerDevice::from_calibration_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(device.current_layout, Some("triangle".to_string()));
+    assert_eq!(device.default_layout, Some("triangle".to_string()));
+    let mut layouts = device.available_layouts();
+    layouts.sort();
+    assert_eq!(layouts, vec!["line", "triangle"]);
+    assert_eq!(device.controlled_z_phase_relation, "2.13");
+    assert_eq!(device.controlled_phase_phase_relation, "DefaultRelation");
+
+    assert_eq!(device.single_qubit_gate_time("RotateX", &2), Some(0.2));
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1),
+        Some(0.5)
+    );
+    assert_eq!(
+        device.three_qubit_gate_time("ControlledControlledPauliZ", &0, &1, &2),
+        Some(1.0)
+    );
+    assert!((device.single_qubit_gate_error("RotateX", &0).unwrap() - 0.001).abs() < 1e-12);
+    assert_eq!(device.single_qubit_gate_error("RotateX", &1), None);
+    assert!(
+        (device
+            .two_qubit_gate_error("PhaseShiftedControlledZ", &0, &1)
+            .unwrap()
+            - 0.01)
+            .abs()
+            < 1e-12
+    );
+    assert_eq!(
+        device.tweezer_coordinates(None).unwrap(),
+        HashMap::from([(0, (0.0, 0.0)), (1, (1.0, 0.0)), (2, (0.5, 0.8))])
+    );
+
+    let info = device.layout_info("triangle").unwrap().unwrap();
+    assert_eq!(info.allowed_tweezer_shifts.get(&0), Some(&vec![vec![1, 2]]));
+    assert_eq!(info.tweezers_per_row, Some(vec![2, 1]));
+    assert_eq!(
+        device
+            .layout_info("line")
+            .unwrap()
+            .unwrap()
+            .tweezer_single_qubit_gate_times
+            .get("RotateZ")
+            .and_then(|times| times.get(&0)),
+        Some(&0.3)
+    );
+}
+
+/// Test TweezerDevice::from_calibration_file with a YAML calibration file
+#[cfg(feature = "yaml")]
+#[test]
+fn test_from_calibration_file_yaml() {
+    let path = write_calibration("qryd_calibration_test.yaml", CALIBRATION_YAML);
+    let device = TweezerDevice::from_calibration_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(device.current_layout, Some("default".to_string()));
+    assert_eq!(device.single_qubit_gate_time("RotateX", &1), Some(0.1));
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1),
+        Some(0.5)
+    );
+    assert!((device.single_qubit_gate_error("RotateX", &0).unwrap() - 0.001).abs() < 1e-12);
+}
+
+/// Test the error messages of TweezerDevice::from_calibration_file
+#[test]
+fn test_from_calibration_file_errors() {
+    assert!(error_message(TweezerDevice::from_calibration_file(
+        std::env::temp_dir().join("qryd_calibration_missing.json")
+    ))
+    .starts_with("Could not read the calibration file"));
+
+    let cases = [
+        ("[]", "at <root>: expected an object"),
+        ("{}", "at layouts: missing field"),
+        (
+            r#"{"layouts": {}, "seed": 1}"#,
+            "at <root>: unknown field \"seed\"",
+        ),
+        (r#"{"layouts": {}}"#, "at layouts: expected at least one Layout"),
+        (
+            r#"{"layouts": {"default": {"single_qubit_gates": {"RotateX": [{"tweezer": 0}]}}}}"#,
+            "at layouts.default.single_qubit_gates.RotateX[0].time: missing field",
+        ),
+        (
+            r#"{"layouts": {"default": {"single_qubit_gates": {"RotateX": [{"tweezer": -1, "time": 0.1}]}}}}"#,
+            "at layouts.default.single_qubit_gates.RotateX[0].tweezer: expected a non-negative integer",
+        ),
+        (
+            r#"{"layouts": {"default": {"single_qubit_gates": {"RotateX": [{"tweezer": 0, "time": -0.1}]}}}}"#,
+            "at layouts.default.single_qubit_gates.RotateX[0].time: expected a non-negative gate time",
+        ),
+        (
+            r#"{"layouts": {"default": {"single_qubit_gates": {"RotateX": [{"tweezer": 0, "time": 0.1, "fidelity": 1.5}]}}}}"#,
+            "at layouts.default.single_qubit_gates.RotateX[0].fidelity: expected a fidelity between 0 and 1",
+        ),
+        (
+            r#"{"layouts": {"default": {"two_qubit_gates": {"PhaseShiftedControlledZ": [{"tweezers": [0], "time": 0.1}]}}}}"#,
+            "at layouts.default.two_qubit_gates.PhaseShiftedControlledZ[0].tweezers: expected 2 tweezers",
+        ),
+        (
+            r#"{"layouts": {"default": {"two_qubit_gates": {"CNOT": [{"tweezers": [0, 1], "time": 0.1}]}}}}"#,
+            "at layouts.default.two_qubit_gates.CNOT[0]: Error setting the gate time of a two-qubit gate",
+        ),
+        (
+            r#"{"layouts": {"default": {"shifts": [{"tweezer": 0, "shifts": [[1]]}]}}}"#,
+            "at layouts.default.shifts[0]: The given tweezer, or shifts tweezers, are not present",
+        ),
+        (
+            r#"{"layouts": {"default": {"coordinates": [{"tweezer": 0, "x": "a", "y": 0.0}]}}}"#,
+            "at layouts.default.coordinates[0].x: expected a number",
+        ),
+        (
+            r#"{"layouts": {"default": {}}, "default_layout": "other"}"#,
+            "at default_layout: Layout other is not described in the file",
+        ),
+    ];
+    for (index, (content, expected)) in cases.iter().enumerate() {
+        let path = write_calibration(&format!("qryd_calibration_error_{}.json", index), content);
+        let msg = error_message(TweezerDevice::from_calibration_file(&path));
+        std::fs::remove_file(&path).unwrap();
+        assert!(
+            msg.starts_with("Invalid calibration file ") && msg.contains(expected),
+            "Unexpected error message for {}: {}",
+            content,
+            msg
+        );
+    }
+
+    let path = write_calibration("qryd_calibration_invalid.yml", "layouts: [");
+    #[cfg(feature = "yaml")]
+    assert!(error_message(TweezerDevice::from_calibration_file(&path))
+        .starts_with("Could not parse the calibration file as YAML"));
+    #[cfg(not(feature = "yaml"))]
+    assert!(error_message(TweezerDevice::from_calibration_file(&path))
+        .contains("requires the yaml feature"));
+    std::fs::remove_file(&path).unwrap();
+}
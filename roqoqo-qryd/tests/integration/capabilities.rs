@@ -0,0 +1,106 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo_qryd::{
+    CapabilityManifest, GateCapability, TweezerDevice, CAPABILITY_MANIFEST_SCHEMA_VERSION,
+};
+use std::collections::HashMap;
+
+/// Test the capability manifest of a lattice device
+#[test]
+fn test_capability_manifest() {
+    let gate_times = HashMap::from([
+        ("RotateX".to_string(), 0.1),
+        ("PhaseShiftedControlledZ".to_string(), 0.5),
+    ]);
+    let mut device = TweezerDevice::square_lattice(2, 2, &gate_times).unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 3, 0.3, None)
+        .unwrap();
+    // Setting a gate time resets the qubit -> tweezer mapping
+    device.switch_layout("default", None).unwrap();
+    device.add_layout("backup").unwrap();
+    device.set_tweezer_t1(1, 100.0).unwrap();
+
+    let json = device.capability_manifest().unwrap();
+    let manifest: CapabilityManifest = serde_json::from_str(&json).unwrap();
+    assert_eq!(manifest.schema_version, CAPABILITY_MANIFEST_SCHEMA_VERSION);
+    assert_eq!(manifest.current_layout, Some("default".to_string()));
+    assert_eq!(
+        manifest.layouts,
+        vec!["backup".to_string(), "default".to_string()]
+    );
+    assert_eq!(
+        manifest.gates.single_qubit,
+        vec![GateCapability {
+            name: "RotateX".to_string(),
+            number_entries: 4,
+            min_time: 0.1,
+            max_time: 0.3,
+        }]
+    );
+    assert_eq!(manifest.gates.two_qubit[0].number_entries, 8);
+    assert!(manifest.gates.three_qubit.is_empty());
+
+    assert_eq!(manifest.connectivity.number_tweezers, 4);
+    assert_eq!(manifest.connectivity.number_edges, 4);
+    assert_eq!(manifest.connectivity.max_degree, 2);
+    assert_eq!(manifest.connectivity.mean_degree, 2.0);
+
+    assert_eq!(manifest.limits.number_qubits, 4);
+    assert_eq!(manifest.limits.tweezers_per_row, Some(vec![2, 2]));
+    assert_eq!(manifest.limits.shiftable_tweezers, 4);
+    assert!(!manifest.limits.allow_reset);
+
+    assert_eq!(
+        manifest.phase_relations.controlled_z,
+        device.controlled_z_phase_relation
+    );
+    assert_eq!(
+        manifest.phase_relations.controlled_z_phase,
+        device.phase_shift_controlled_z()
+    );
+    let noise = manifest.noise.unwrap();
+    assert_eq!(noise.len(), 1);
+    assert_eq!(noise[0].tweezer, 1);
+    assert_eq!(noise[0].t1, Some(100.0));
+
+    // The manifest is compact and uses the documented field names
+    assert!(!json.contains('\n'));
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    for field in [
+        "schema_version",
+        "device_name",
+        "current_layout",
+        "layouts",
+        "gates",
+        "connectivity",
+        "limits",
+        "phase_relations",
+        "noise",
+    ] {
+        assert!(value.get(field).is_some(), "missing field {}", field);
+    }
+}
+
+/// Test the capability manifest of a device without a current layout
+#[test]
+fn test_capability_manifest_empty() {
+    let device = TweezerDevice::new(None, None, None);
+    let manifest: CapabilityManifest =
+        serde_json::from_str(&device.capability_manifest().unwrap()).unwrap();
+    assert_eq!(manifest.current_layout, None);
+    assert!(manifest.gates.single_qubit.is_empty());
+    assert_eq!(manifest.connectivity.number_edges, 0);
+    assert_eq!(manifest.limits.number_qubits, 0);
+    assert_eq!(manifest.noise, None);
+}
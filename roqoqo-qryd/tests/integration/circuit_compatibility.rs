@@ -0,0 +1,141 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::operations::{
+    MeasureQubit, PhaseShiftState0, PhaseShiftedControlledZ, RotateX, RotateY,
+};
+use roqoqo::Circuit;
+use roqoqo_qryd::{
+    check_circuit_compatibility, IncompatibilityReport, PragmaShiftQubitsTweezers,
+    PragmaSwitchDeviceLayout, TweezerDevice,
+};
+
+/// Creates a device with three tweezers, qubit 1 can be shifted from tweezer 1 to tweezer 2
+fn create_device() -> TweezerDevice {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..3 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateX",
+                tweezer,
+                0.1,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device
+        .set_tweezer_two_qubit_gate_time(
+            "PhaseShiftedControlledZ",
+            0,
+            1,
+            0.2,
+            Some("default".to_string()),
+        )
+        .unwrap();
+    device.switch_layout("default", Some(false)).unwrap();
+    device
+        .set_allowed_tweezer_shifts(&1, &[&[2]], None)
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 1).unwrap();
+    device
+}
+
+/// Test check_circuit_compatibility with a compatible circuit
+#[test]
+fn test_compatible_circuit() {
+    let device = create_device();
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, 1.0.into());
+    circuit += PhaseShiftedControlledZ::new(0, 1, 0.0.into());
+    circuit += PragmaShiftQubitsTweezers::new(vec![(1, 2)])
+        .to_pragma_change_device()
+        .unwrap();
+    circuit += RotateX::new(1, 1.0.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+
+    assert_eq!(check_circuit_compatibility(&circuit, &device), Ok(()));
+    assert_eq!(
+        check_circuit_compatibility(&Circuit::new(), &device),
+        Ok(())
+    );
+}
+
+/// Test check_circuit_compatibility reporting every incompatible operation
+#[test]
+fn test_incompatible_circuit() {
+    let device = create_device();
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, 1.0.into());
+    circuit += RotateY::new(0, 1.0.into());
+    circuit += PhaseShiftedControlledZ::new(0, 1, 0.0.into());
+    circuit += PragmaShiftQubitsTweezers::new(vec![(1, 2)])
+        .to_pragma_change_device()
+        .unwrap();
+    // Qubit 1 is in tweezer 2 after the shift
+    circuit += PhaseShiftedControlledZ::new(0, 1, 0.0.into());
+    circuit += RotateX::new(5, 1.0.into());
+    circuit += PragmaSwitchDeviceLayout::new("missing".to_string())
+        .to_pragma_change_device()
+        .unwrap();
+    circuit += PhaseShiftState0::new(0, 1.0.into());
+
+    let report =
+        |index: usize, hqslang: &str, qubits: Vec<usize>, reason: &str| IncompatibilityReport {
+            index,
+            hqslang: hqslang.to_string(),
+            qubits,
+            layout: Some("default".to_string()),
+            reason: reason.to_string(),
+        };
+    let reports = check_circuit_compatibility(&circuit, &device).unwrap_err();
+    assert_eq!(
+        reports,
+        vec![
+            report(
+                1,
+                "RotateY",
+                vec![0],
+                "the gate is not available on qubits [0]"
+            ),
+            report(
+                4,
+                "PhaseShiftedControlledZ",
+                vec![0, 1],
+                "the gate is not available on qubits [0, 1]"
+            ),
+            report(
+                5,
+                "RotateX",
+                vec![5],
+                "qubit 5 is not part of the device with 2 qubits"
+            ),
+            report(
+                6,
+                "PragmaChangeDevice",
+                vec![],
+                "Error with dynamic layout switching of TweezerDevice. Layout missing is not set."
+            ),
+            report(
+                7,
+                "PhaseShiftState0",
+                vec![0],
+                "the gate is not a native gate of the device"
+            ),
+        ]
+    );
+    assert_eq!(
+        reports[1].to_string(),
+        "Operation 4 (PhaseShiftedControlledZ) on qubits [0, 1]: the gate is not available on qubits [0, 1]"
+    );
+}
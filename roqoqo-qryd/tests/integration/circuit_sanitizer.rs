@@ -0,0 +1,81 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::operations;
+use roqoqo::Circuit;
+use roqoqo_qryd::{sanitize_for_api, SanitizationReport};
+
+/// Test that simulation-only pragmas are removed and reported
+#[test]
+fn test_sanitize_removes_simulation_pragmas() {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::DefinitionComplex::new("state".to_string(), 4, true);
+    circuit += operations::RotateX::new(0, 1.0.into());
+    circuit += operations::PragmaDamping::new(0, 0.1.into(), 0.01.into());
+    circuit += operations::ControlledPauliZ::new(0, 1);
+    circuit += operations::PragmaGetStateVector::new("state".to_string(), None);
+    circuit += operations::PragmaStopParallelBlock::new(vec![0, 1], 1.0.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+
+    let (sanitized, report) = sanitize_for_api(&circuit);
+
+    let mut expected = Circuit::new();
+    expected += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    expected += operations::DefinitionComplex::new("state".to_string(), 4, true);
+    expected += operations::RotateX::new(0, 1.0.into());
+    expected += operations::ControlledPauliZ::new(0, 1);
+    expected += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    expected += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+    assert_eq!(sanitized, expected);
+    assert_eq!(
+        report.removed,
+        vec![
+            "PragmaDamping".to_string(),
+            "PragmaGetStateVector".to_string(),
+            "PragmaStopParallelBlock".to_string()
+        ]
+    );
+    assert_eq!(report.unrolled_loops, 0);
+    assert!(!report.is_empty());
+}
+
+/// Test that loops are unrolled and circuits without simulation pragmas are unchanged
+#[test]
+fn test_sanitize_unrolls_loops() {
+    let mut body = Circuit::new();
+    body += operations::RotateX::new(0, 0.5.into());
+    body += operations::PragmaDephasing::new(0, 0.1.into(), 0.01.into());
+    let mut circuit = Circuit::new();
+    circuit += operations::PragmaLoop::new(3.0.into(), body.clone());
+    circuit += operations::PragmaLoop::new(CalculatorFloat::from("n"), body);
+
+    let (sanitized, report) = sanitize_for_api(&circuit);
+    assert_eq!(sanitized.len(), 4);
+    for operation in sanitized.iter().take(3) {
+        assert_eq!(
+            operation,
+            &operations::Operation::from(operations::RotateX::new(0, 0.5.into()))
+        );
+    }
+    assert_eq!(report.unrolled_loops, 1);
+    assert_eq!(report.removed, vec!["PragmaDephasing".to_string()]);
+
+    let mut clean = Circuit::new();
+    clean += operations::RotateZ::new(1, 0.2.into());
+    let (sanitized, report) = sanitize_for_api(&clean);
+    assert_eq!(sanitized, clean);
+    assert_eq!(report, SanitizationReport::default());
+    assert!(report.is_empty());
+}
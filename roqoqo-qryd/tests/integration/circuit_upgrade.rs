@@ -0,0 +1,118 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::operations::RotateX;
+use roqoqo::Circuit;
+use roqoqo_qryd::{
+    upgrade_circuit, PragmaChangeQRydLayout, PragmaShiftQRydQubit, PragmaShiftQubitsTweezers,
+    PragmaSwitchDeviceLayout, TweezerDevice,
+};
+use std::collections::HashMap;
+
+/// Creates a device with the Layouts "0" and "1" of two rows of two tweezers
+fn create_device() -> TweezerDevice {
+    let mut device = TweezerDevice::new(None, None, None);
+    for layout in ["0", "1"] {
+        device.add_layout(layout).unwrap();
+        for tweezer in 0..4 {
+            device
+                .set_tweezer_single_qubit_gate_time(
+                    "RotateX",
+                    tweezer,
+                    0.1,
+                    Some(layout.to_string()),
+                )
+                .unwrap();
+        }
+        device
+            .set_tweezers_per_row(vec![2, 2], Some(layout.to_string()))
+            .unwrap();
+    }
+    device
+        .set_allowed_tweezer_shifts(&0, &[&[1]], Some("1".to_string()))
+        .unwrap();
+    device
+        .set_allowed_tweezer_shifts(&1, &[&[0]], Some("1".to_string()))
+        .unwrap();
+    device.switch_layout("0", Some(false)).unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 2).unwrap();
+    device
+}
+
+/// Test upgrade_circuit converting the legacy pragmas for the device state at their position
+#[test]
+fn test_upgrade_circuit() {
+    let device = create_device();
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, 1.0.into());
+    circuit += PragmaChangeQRydLayout::new(1)
+        .to_pragma_change_device()
+        .unwrap();
+    circuit += PragmaShiftQRydQubit::new(HashMap::from([(0, (0, 1)), (1, (1, 0))]))
+        .to_pragma_change_device()
+        .unwrap();
+    // Qubit 0 is in tweezer 1 after the previous shift
+    circuit += PragmaShiftQRydQubit::new(HashMap::from([(0, (0, 0))]))
+        .to_pragma_change_device()
+        .unwrap();
+
+    let mut expected = Circuit::new();
+    expected += RotateX::new(0, 1.0.into());
+    expected += PragmaSwitchDeviceLayout::new("1".to_string())
+        .to_pragma_change_device()
+        .unwrap();
+    expected += PragmaShiftQubitsTweezers::new_parallel(vec![(0, 1)])
+        .to_pragma_change_device()
+        .unwrap();
+    expected += PragmaShiftQubitsTweezers::new_parallel(vec![(1, 0)])
+        .to_pragma_change_device()
+        .unwrap();
+
+    assert_eq!(upgrade_circuit(&circuit, &device), expected);
+    // The device itself is not changed
+    assert_eq!(device.current_layout, Some("0".to_string()));
+    assert_eq!(
+        device.qubit_to_tweezer,
+        Some(HashMap::from([(0, 0), (1, 2)]))
+    );
+}
+
+/// Test upgrade_circuit leaving the legacy pragmas that can not be converted unchanged
+#[test]
+fn test_upgrade_circuit_unconvertible() {
+    let device = create_device();
+    let mut circuit = Circuit::new();
+    // The Layout "5" does not exist
+    circuit += PragmaChangeQRydLayout::new(5)
+        .to_pragma_change_device()
+        .unwrap();
+    // The position is outside of the rows of the Layout
+    circuit += PragmaShiftQRydQubit::new(HashMap::from([(0, (0, 2))]))
+        .to_pragma_change_device()
+        .unwrap();
+    // Qubit 3 is not placed in a tweezer
+    circuit += PragmaShiftQRydQubit::new(HashMap::from([(3, (0, 1))]))
+        .to_pragma_change_device()
+        .unwrap();
+
+    assert_eq!(upgrade_circuit(&circuit, &device), circuit);
+
+    // Without a qubit -> tweezer mapping no shift can be converted
+    let mut device = create_device();
+    device.qubit_to_tweezer = None;
+    let mut circuit = Circuit::new();
+    circuit += PragmaShiftQRydQubit::new(HashMap::from([(0, (0, 1))]))
+        .to_pragma_change_device()
+        .unwrap();
+    assert_eq!(upgrade_circuit(&circuit, &device), circuit);
+}
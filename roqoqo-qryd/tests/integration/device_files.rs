@@ -0,0 +1,110 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo_qryd::{DeviceFileFormat, TweezerDevice};
+use std::collections::HashMap;
+
+fn test_device() -> TweezerDevice {
+    let gate_times = HashMap::from([
+        ("RotateX".to_string(), 0.1),
+        ("PhaseShiftedControlledZ".to_string(), 0.5),
+    ]);
+    let mut device = TweezerDevice::square_lattice(2, 2, &gate_times).unwrap();
+    device.add_qubit_tweezer_mapping(0, 1).unwrap();
+    device
+}
+
+/// Test the inference of the file format from the extension
+#[test]
+fn test_file_format_from_path() {
+    assert_eq!(
+        DeviceFileFormat::from_path("device.json"),
+        Some(DeviceFileFormat::Json)
+    );
+    assert_eq!(
+        DeviceFileFormat::from_path("snapshots/device.BIN"),
+        Some(DeviceFileFormat::Bincode)
+    );
+    assert_eq!(
+        DeviceFileFormat::from_path("device.bincode"),
+        Some(DeviceFileFormat::Bincode)
+    );
+    assert_eq!(
+        DeviceFileFormat::from_path("device.yaml"),
+        Some(DeviceFileFormat::Yaml)
+    );
+    assert_eq!(
+        DeviceFileFormat::from_path("device.yml"),
+        Some(DeviceFileFormat::Yaml)
+    );
+    assert_eq!(DeviceFileFormat::from_path("device.txt"), None);
+    assert_eq!(DeviceFileFormat::from_path("device"), None);
+}
+
+/// Test that a device saved in each format is loaded unchanged
+#[test]
+fn test_file_roundtrip() {
+    let device = test_device();
+    for file_name in [
+        "device_files_test.json",
+        "device_files_test.bin",
+        #[cfg(feature = "yaml")]
+        "device_files_test.yaml",
+    ] {
+        let path = std::env::temp_dir().join(file_name);
+        device.to_file(&path, None).unwrap();
+        assert_eq!(TweezerDevice::from_file(&path, None).unwrap(), device);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    // The explicit format overrides the extension
+    let path = std::env::temp_dir().join("device_files_test.snapshot");
+    assert!(device.to_file(&path, None).is_err());
+    device.to_file(&path, Some(DeviceFileFormat::Json)).unwrap();
+    assert!(TweezerDevice::from_file(&path, None).is_err());
+    assert!(TweezerDevice::from_file(&path, Some(DeviceFileFormat::Bincode)).is_err());
+    assert_eq!(
+        TweezerDevice::from_file(&path, Some(DeviceFileFormat::Json)).unwrap(),
+        device
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+/// Test YAML device files, which require the yaml feature
+#[test]
+fn test_yaml_file() {
+    let device = test_device();
+    let path = std::env::temp_dir().join("device_files_test_yaml.snapshot");
+    #[cfg(feature = "yaml")]
+    {
+        device.to_file(&path, Some(DeviceFileFormat::Yaml)).unwrap();
+        assert!(TweezerDevice::from_file(&path, Some(DeviceFileFormat::Json)).is_err());
+        assert_eq!(
+            TweezerDevice::from_file(&path, Some(DeviceFileFormat::Yaml)).unwrap(),
+            device
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+    #[cfg(not(feature = "yaml"))]
+    assert!(device
+        .to_file(&path, Some(DeviceFileFormat::Yaml))
+        .unwrap_err()
+        .to_string()
+        .contains("requires the yaml feature"));
+}
+
+/// Test the errors of missing files
+#[test]
+fn test_missing_file() {
+    let path = std::env::temp_dir().join("device_files_test_missing.json");
+    assert!(TweezerDevice::from_file(&path, None).is_err());
+}
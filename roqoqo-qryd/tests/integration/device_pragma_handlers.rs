@@ -0,0 +1,97 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{deserialize, serialize};
+use roqoqo::{devices::Device, RoqoqoBackendError};
+use roqoqo_qryd::{
+    register_device_pragma_handler, registered_device_pragmas, unregister_device_pragma_handler,
+    DevicePragmaHandler, EmulatorDevice, TweezerDevice,
+};
+
+/// Test handler setting the relaxation time of a tweezer.
+struct SetTweezerT1Handler {
+    hqslang: String,
+}
+
+impl DevicePragmaHandler for SetTweezerT1Handler {
+    fn hqslang(&self) -> &str {
+        &self.hqslang
+    }
+
+    fn apply(
+        &self,
+        device: &mut TweezerDevice,
+        operation: &[u8],
+    ) -> Result<(), RoqoqoBackendError> {
+        let (tweezer, t1): (usize, f64) =
+            deserialize(operation).map_err(|_| RoqoqoBackendError::GenericError {
+                msg: "Wrapped operation not supported in TweezerDevice".to_string(),
+            })?;
+        device.set_tweezer_t1(tweezer, t1)
+    }
+}
+
+/// Test that registered handlers are used by TweezerDevice change_device()
+#[test]
+fn test_tweezer_device_custom_pragma() {
+    let hqslang = "PragmaTestSetTweezerT1".to_string();
+    let mut device = TweezerDevice::new(None, None, None);
+    let operation = serialize(&(3_usize, 20.0_f64)).unwrap();
+
+    assert!(device.change_device(&hqslang, &operation).is_err());
+
+    register_device_pragma_handler(SetTweezerT1Handler {
+        hqslang: hqslang.clone(),
+    })
+    .unwrap();
+    assert!(registered_device_pragmas().contains(&hqslang));
+
+    device.change_device(&hqslang, &operation).unwrap();
+    assert_eq!(device.get_tweezer_noise(3).t1, Some(20.0));
+    assert!(device
+        .change_device(&hqslang, &serialize(&(3_usize, -1.0_f64)).unwrap())
+        .is_err());
+    assert!(device.change_device(&hqslang, &Vec::<u8>::new()).is_err());
+
+    assert!(unregister_device_pragma_handler(&hqslang));
+    assert!(!unregister_device_pragma_handler(&hqslang));
+    assert!(!registered_device_pragmas().contains(&hqslang));
+    assert!(device.change_device(&hqslang, &operation).is_err());
+}
+
+/// Test that registered handlers are used by EmulatorDevice change_device()
+#[test]
+fn test_emulator_device_custom_pragma() {
+    let hqslang = "PragmaTestEmulatorSetTweezerT1".to_string();
+    let mut device = EmulatorDevice::new(None, None, None);
+    register_device_pragma_handler(SetTweezerT1Handler {
+        hqslang: hqslang.clone(),
+    })
+    .unwrap();
+
+    device
+        .change_device(&hqslang, &serialize(&(1_usize, 5.0_f64)).unwrap())
+        .unwrap();
+    assert_eq!(device.internal.get_tweezer_noise(1).t1, Some(5.0));
+
+    assert!(unregister_device_pragma_handler(&hqslang));
+}
+
+/// Test that the natively handled pragmas can not be overridden
+#[test]
+fn test_builtin_pragma_not_overridden() {
+    let result = register_device_pragma_handler(SetTweezerT1Handler {
+        hqslang: "PragmaShiftQubitsTweezers".to_string(),
+    });
+    assert!(result.is_err());
+    assert!(!registered_device_pragmas().contains(&"PragmaShiftQubitsTweezers".to_string()));
+}
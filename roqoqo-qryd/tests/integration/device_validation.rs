@@ -0,0 +1,101 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo_qryd::{DeviceIssue, TweezerDevice};
+use std::collections::HashMap;
+
+/// Test that a consistent lattice device passes the validation
+#[test]
+fn test_validate_lattice() {
+    let gate_times = HashMap::from([
+        ("RotateX".to_string(), 0.1),
+        ("PhaseShiftedControlledZ".to_string(), 0.5),
+    ]);
+    let device = TweezerDevice::square_lattice(2, 2, &gate_times).unwrap();
+    assert_eq!(device.validate(), Ok(()));
+}
+
+/// Test the issues found by the validation of an inconsistent device
+#[test]
+fn test_validate_issues() {
+    let gate_times = HashMap::from([("RotateX".to_string(), 0.1)]);
+    let mut device = TweezerDevice::square_lattice(2, 2, &gate_times).unwrap();
+    let other = Some("other".to_string());
+    device.add_layout("other").unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.1, other.clone())
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, 0.2, other.clone())
+        .unwrap();
+    device.set_tweezers_per_row(vec![4], other).unwrap();
+    // Shifts and gate times bypassing the checks of the setters
+    device
+        .apply_update(
+            r#"[
+                {"op": "add", "path": "/layout_register/other/tweezer_single_qubit_gate_times/0/1/-", "value": [1, -0.1]},
+                {"op": "add", "path": "/layout_register/other/allowed_tweezer_shifts/-", "value": [0, [[1, 9]]]},
+                {"op": "add", "path": "/layout_register/other/allowed_tweezer_shifts/-", "value": [7, [[0]]]}
+            ]"#,
+        )
+        .unwrap();
+    device.qubit_to_tweezer = Some(HashMap::from([(0, 0), (1, 8)]));
+
+    let issues = device.validate().unwrap_err();
+    assert_eq!(
+        issues,
+        vec![
+            DeviceIssue::InvalidGateTime {
+                layout: "other".to_string(),
+                hqslang: "RotateX".to_string(),
+                tweezers: vec![1],
+                gate_time: -0.1,
+            },
+            DeviceIssue::AsymmetricTwoQubitGate {
+                layout: "other".to_string(),
+                hqslang: "PhaseShiftedControlledZ".to_string(),
+                tweezer0: 0,
+                tweezer1: 1,
+            },
+            DeviceIssue::OrphanShiftTarget {
+                layout: "other".to_string(),
+                start: 0,
+                target: 9,
+            },
+            DeviceIssue::MissingShiftStart {
+                layout: "other".to_string(),
+                tweezer: 7,
+            },
+            DeviceIssue::IncompatibleTweezersPerRow {
+                layout: "other".to_string(),
+                reference_layout: "default".to_string(),
+            },
+            DeviceIssue::MissingMappedTweezer {
+                qubit: 1,
+                tweezer: 8,
+            },
+        ]
+    );
+    assert_eq!(
+        issues[1].to_string(),
+        "Gate PhaseShiftedControlledZ in Layout other is defined between tweezers 0 and 1 but not between tweezers 1 and 0."
+    );
+
+    device.current_layout = Some("missing".to_string());
+    device.qubit_to_tweezer = None;
+    assert!(device
+        .validate()
+        .unwrap_err()
+        .contains(&DeviceIssue::MissingCurrentLayout {
+            layout: "missing".to_string()
+        }));
+}
@@ -18,10 +18,13 @@ use std::env;
 
 use roqoqo::devices::Device;
 
-use roqoqo_qryd::{phi_theta_relation, EmulatorDevice, PragmaDeactivateQRydQubit};
 use roqoqo_qryd::{
-    PragmaChangeQRydLayout, PragmaShiftQRydQubit, PragmaShiftQubitsTweezers,
-    PragmaSwitchDeviceLayout,
+    phi_theta_relation, DrawOptions, EmulatorDevice, EmulatorLayoutSpec, EmulatorNoiseParameters,
+    PragmaDeactivateQRydQubit,
+};
+use roqoqo_qryd::{
+    PragmaChangeQRydLayout, PragmaSetPhaseRelation, PragmaShiftQRydQubit,
+    PragmaShiftQubitsTweezers, PragmaSwitchDeviceLayout,
 };
 
 /// Test EmulatorDevice new()
@@ -31,7 +34,7 @@ fn test_new() {
 
     assert!(device.internal.current_layout.is_none());
     assert!(device.internal.qubit_to_tweezer.is_none());
-    assert!(device.internal.layout_register.is_none());
+    assert!(device.internal.layouts().unwrap().is_none());
     assert_eq!(device.internal.seed(), Some(2));
     assert_eq!(device.internal.qrydbackend(), "qryd_tweezer_device");
 
@@ -93,6 +96,267 @@ fn test_available_gate_names() {
     assert!(gates.contains(&"MultiQubitZZ"));
 }
 
+// Test EmulatorDevice remove_available_gate(), set_available_gates() and the arity queries
+#[test]
+fn test_available_gates_management() {
+    let mut device = EmulatorDevice::new(None, None, None);
+
+    device.add_available_gate("RotateX").unwrap();
+    device.add_available_gate("RotateX").unwrap();
+    assert_eq!(device.get_available_gates_names().unwrap(), vec!["RotateX"]);
+
+    assert!(device.remove_available_gate("SWAP").is_err());
+    device.remove_available_gate("RotateX").unwrap();
+    assert!(device.get_available_gates_names().unwrap().is_empty());
+
+    assert!(device.set_available_gates(&["RotateX", "error"]).is_err());
+    assert!(device.get_available_gates_names().unwrap().is_empty());
+
+    device
+        .set_available_gates(&[
+            "RotateZ",
+            "CNOT",
+            "Toffoli",
+            "MultiQubitZZ",
+            "RotateX",
+            "RotateZ",
+        ])
+        .unwrap();
+    assert_eq!(
+        device.get_available_gates_names().unwrap(),
+        vec!["RotateZ", "CNOT", "Toffoli", "MultiQubitZZ", "RotateX"]
+    );
+    assert_eq!(
+        device.get_available_single_qubit_gates(),
+        vec!["RotateZ", "RotateX"]
+    );
+    assert_eq!(device.get_available_two_qubit_gates(), vec!["CNOT"]);
+    assert_eq!(device.get_available_three_qubit_gates(), vec!["Toffoli"]);
+    assert_eq!(
+        device.get_available_multi_qubit_gates(),
+        vec!["MultiQubitZZ"]
+    );
+
+    device.remove_available_gate("CNOT").unwrap();
+    assert!(device.get_available_two_qubit_gates().is_empty());
+    assert_eq!(device.two_qubit_gate_time("CNOT", &0, &1), None);
+}
+
+/// Test EmulatorDevice set_allowed_edges() connectivity restriction
+#[test]
+fn test_allowed_edges() {
+    let mut device = EmulatorDevice::new(None, None, None);
+    device.add_available_gate("CNOT").unwrap();
+    for qubit in 0..3 {
+        device.add_qubit_tweezer_mapping(qubit, qubit).unwrap();
+    }
+
+    assert!(device.allowed_edges().is_none());
+    assert!(device.two_qubit_edges().is_empty());
+    assert_eq!(device.two_qubit_gate_time("CNOT", &0, &2), Some(1.0));
+
+    assert!(device.set_allowed_edges(Some(vec![(1, 1)])).is_err());
+    assert!(device.allowed_edges().is_none());
+
+    device
+        .set_allowed_edges(Some(vec![(1, 0), (1, 2), (0, 1)]))
+        .unwrap();
+    assert_eq!(device.allowed_edges(), Some(&[(0, 1), (1, 2)][..]));
+    assert_eq!(device.two_qubit_edges(), vec![(0, 1), (1, 2)]);
+    assert_eq!(device.two_qubit_gate_time("CNOT", &1, &0), Some(1.0));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &2, &1), Some(1.0));
+    assert_eq!(device.two_qubit_gate_time("CNOT", &0, &2), None);
+
+    let generic_device = device.to_generic_device();
+    assert!(generic_device.two_qubit_gates["CNOT"].contains_key(&(0, 1)));
+    assert!(!generic_device.two_qubit_gates["CNOT"].contains_key(&(0, 2)));
+
+    device.set_allowed_edges(None).unwrap();
+    assert_eq!(device.two_qubit_gate_time("CNOT", &0, &2), Some(1.0));
+}
+
+/// Test EmulatorDevice set_number_qubits() limit
+#[test]
+fn test_number_qubits_limit() {
+    let mut device = EmulatorDevice::new(None, None, None);
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(3, 3).unwrap();
+    assert_eq!(device.number_qubits(), 4);
+
+    assert!(device.set_number_qubits(Some(3)).is_err());
+    device.set_number_qubits(Some(6)).unwrap();
+    assert_eq!(device.number_qubits(), 6);
+    assert_eq!(device.internal.max_qubits, Some(6));
+    assert!(device.add_qubit_tweezer_mapping(6, 6).is_err());
+    assert!(device.add_qubit_tweezer_mapping(5, 5).is_ok());
+
+    device.set_number_qubits(None).unwrap();
+    assert_eq!(device.number_qubits(), 6);
+    device.deactivate_qubit(5).unwrap();
+    assert_eq!(device.number_qubits(), 4);
+}
+
+/// Test EmulatorDevice into_tweezer_device() conversion
+#[test]
+fn test_into_tweezer_device() {
+    let mut device = EmulatorDevice::new(Some(3), None, None);
+    device
+        .set_available_gates(&[
+            "RotateX",
+            "PhaseShiftedControlledZ",
+            "ControlledControlledPauliZ",
+        ])
+        .unwrap();
+    device.set_number_qubits(Some(3)).unwrap();
+
+    let square = device
+        .clone()
+        .into_tweezer_device(EmulatorLayoutSpec::Square {
+            rows: 2,
+            columns: 2,
+        })
+        .unwrap();
+    assert_eq!(square.current_layout, Some("default".to_string()));
+    assert_eq!(square.seed(), Some(3));
+    assert_eq!(square.max_qubits, Some(3));
+    assert_eq!(square.number_qubits(), 3);
+    assert_eq!(square.single_qubit_gate_time("RotateX", &3), None);
+    assert_eq!(square.single_qubit_gate_time("RotateX", &2), Some(1.0));
+    assert_eq!(
+        square.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1),
+        Some(1.0)
+    );
+    assert_eq!(
+        square.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &3),
+        None
+    );
+    assert_eq!(
+        square.three_qubit_gate_time("ControlledControlledPauliZ", &0, &1, &2),
+        None
+    );
+
+    let triangular = device
+        .clone()
+        .into_tweezer_device(EmulatorLayoutSpec::Triangular {
+            rows: 2,
+            columns: 2,
+        })
+        .unwrap();
+    assert_eq!(
+        triangular.three_qubit_gate_time("ControlledControlledPauliZ", &2, &0, &1),
+        Some(1.0)
+    );
+
+    device.add_qubit_tweezer_mapping(0, 7).unwrap();
+    assert!(device
+        .clone()
+        .into_tweezer_device(EmulatorLayoutSpec::Square {
+            rows: 2,
+            columns: 2,
+        })
+        .is_err());
+    let mapped = device
+        .into_tweezer_device(EmulatorLayoutSpec::Square {
+            rows: 2,
+            columns: 4,
+        })
+        .unwrap();
+    assert_eq!(mapped.get_tweezer_from_qubit(&0).unwrap(), 7);
+    assert!(mapped.get_tweezer_from_qubit(&1).is_err());
+
+    let empty = EmulatorDevice::new(None, None, None);
+    assert!(empty
+        .into_tweezer_device(EmulatorLayoutSpec::Square {
+            rows: 2,
+            columns: 2,
+        })
+        .is_err());
+}
+
+/// Test EmulatorDevice noise parameters
+#[test]
+fn test_noise_parameters() {
+    let mut device = EmulatorDevice::new(None, None, None);
+    assert!(!device.is_noisy());
+    assert!(device.noise_parameters().is_none());
+
+    let invalid = [
+        EmulatorNoiseParameters {
+            damping: Some(-1.0),
+            ..Default::default()
+        },
+        EmulatorNoiseParameters {
+            dephasing: Some(f64::INFINITY),
+            ..Default::default()
+        },
+        EmulatorNoiseParameters {
+            spam_error: Some(1.5),
+            ..Default::default()
+        },
+    ];
+    for noise in invalid {
+        assert!(device.set_noise_parameters(Some(noise)).is_err());
+    }
+    assert!(!device.is_noisy());
+
+    device
+        .set_noise_parameters(Some(EmulatorNoiseParameters {
+            damping: Some(0.01),
+            dephasing: None,
+            spam_error: Some(0.02),
+        }))
+        .unwrap();
+    assert!(device.is_noisy());
+    assert_eq!(device.damping(), Some(0.01));
+    assert_eq!(device.dephasing(), None);
+    assert_eq!(device.spam_error(), Some(0.02));
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: EmulatorDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, device);
+
+    let payload = r#"{"qubit_to_tweezer":null,"layout_register":null,"current_layout":null,"controlled_z_phase_relation":"DefaultRelation","controlled_phase_phase_relation":"DefaultRelation","default_layout":null,"seed":null,"allow_reset":false,"device_name":"qryd_emulator_noisy","available_gates":[],"emulator_noise":{"dephasing":0.5}}"#;
+    let noisy = EmulatorDevice {
+        internal: serde_json::from_str(payload).unwrap(),
+    };
+    assert!(noisy.is_noisy());
+    assert_eq!(noisy.dephasing(), Some(0.5));
+    assert_eq!(noisy.damping(), None);
+
+    device.set_noise_parameters(None).unwrap();
+    assert!(!device.is_noisy());
+}
+
+/// Test EmulatorDevice draw() and draw_with_options() methods
+#[test]
+fn test_draw() {
+    let mut device = EmulatorDevice::new(None, None, None);
+    assert!(device.draw(None, &None).is_err());
+
+    device.add_available_gate("RotateX").unwrap();
+    device.add_available_gate("CNOT").unwrap();
+    for qubit in 0..5 {
+        device.add_qubit_tweezer_mapping(qubit, qubit).unwrap();
+    }
+    let _image = device
+        .draw(None, &Some("emulator_graph_test.png".to_owned()))
+        .unwrap();
+    assert!(std::path::Path::new("emulator_graph_test.png").exists());
+    std::fs::remove_file("emulator_graph_test.png").unwrap();
+
+    device
+        .set_allowed_edges(Some(vec![(0, 1), (1, 2), (2, 3), (3, 4)]))
+        .unwrap();
+    let options = DrawOptions {
+        show_legend: true,
+        dpi: Some(144.0),
+        ..Default::default()
+    };
+    let low_resolution = device.draw(Some(1.0), &None).unwrap();
+    let image = device.draw_with_options(None, &None, &options).unwrap();
+    assert!(image.width() > low_resolution.width());
+}
+
 /// Test EmulatorDevice allow_reset field
 #[test]
 fn test_allow_reset() {
@@ -233,32 +497,58 @@ fn test_number_qubits() {
 #[test]
 fn test_change_device_errors() {
     let mut device = EmulatorDevice::new(None, None, None);
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 1).unwrap();
 
     let pragma_change = PragmaChangeQRydLayout::new(0);
-    let pragma_shift = PragmaSwitchDeviceLayout::new("error".to_string());
+    let pragma_switch = PragmaSwitchDeviceLayout::new("error".to_string());
     let hm: HashMap<usize, (usize, usize)> = [(0, (1, 2))].into_iter().collect();
     let pragma_old_s = PragmaShiftQRydQubit::new(hm);
+    let pragma_shift = PragmaShiftQubitsTweezers::new(vec![(1, 2)]);
 
-    assert!(device.change_device("Error", &Vec::<u8>::new()).is_err());
-    assert!(device
-        .change_device("PragmaChangeQRydLayout", &Vec::<u8>::new())
-        .is_err());
-    assert!(device
-        .change_device(
-            "PragmaChangeQRydLayout",
-            &serialize(&pragma_change).unwrap()
-        )
-        .is_err());
     assert!(device
-        .change_device(
+        .change_device("Error", &Vec::<u8>::new())
+        .unwrap_err()
+        .to_string()
+        .contains("Wrapped operation not supported in EmulatorDevice"));
+    for (hqslang, operation) in [
+        ("PragmaChangeQRydLayout", Vec::<u8>::new()),
+        ("PragmaChangeQRydLayout", serialize(&pragma_change).unwrap()),
+        (
             "PragmaSwitchDeviceLayout",
-            &serialize(&pragma_shift).unwrap()
-        )
-        .is_err());
-
+            serialize(&pragma_switch).unwrap(),
+        ),
+    ] {
+        assert!(device
+            .change_device(hqslang, &operation)
+            .unwrap_err()
+            .to_string()
+            .contains("The EmulatorDevice has a single layout with all-to-all connectivity"));
+    }
+    for (hqslang, operation) in [
+        ("PragmaShiftQRydQubit", serialize(&pragma_old_s).unwrap()),
+        (
+            "PragmaShiftQubitsTweezers",
+            serialize(&pragma_shift).unwrap(),
+        ),
+    ] {
+        assert!(device
+            .change_device(hqslang, &operation)
+            .unwrap_err()
+            .to_string()
+            .contains("qubits do not need to be shifted"));
+    }
     assert!(device
-        .change_device("PragmaShiftQRydQubit", &serialize(&pragma_old_s).unwrap())
-        .is_err());
+        .change_device("PragmaDeactivateQRydQubit", &Vec::<u8>::new())
+        .unwrap_err()
+        .to_string()
+        .contains("Wrapped operation not supported in EmulatorDevice"));
+
+    // The rejected pragmas leave the device unchanged
+    assert_eq!(
+        device.internal.qubit_to_tweezer,
+        Some(HashMap::from([(0, 0), (1, 1)]))
+    );
 }
 
 /// Test EmulatorDevice change_device() method
@@ -266,19 +556,16 @@ fn test_change_device_errors() {
 fn test_change_device() {
     let mut device = EmulatorDevice::new(None, None, None);
 
-    let pr_shift = PragmaShiftQubitsTweezers::new(vec![(2, 3)]);
+    let pr_deac = PragmaDeactivateQRydQubit::new(3);
     assert!(device
-        .change_device("PragmaShiftQubitsTweezers", &serialize(&pr_shift).unwrap())
-        .unwrap_err()
-        .to_string()
-        .contains("The device qubit -> tweezer mapping is empty"));
+        .change_device("PragmaDeactivateQRydQubit", &serialize(&pr_deac).unwrap())
+        .is_err());
 
     device.add_qubit_tweezer_mapping(0, 0).unwrap();
     device.add_qubit_tweezer_mapping(1, 1).unwrap();
     device.add_qubit_tweezer_mapping(2, 2).unwrap();
     device.add_qubit_tweezer_mapping(3, 3).unwrap();
 
-    let pr_deac = PragmaDeactivateQRydQubit::new(3);
     assert!(device
         .internal
         .qubit_to_tweezer
@@ -294,22 +581,20 @@ fn test_change_device() {
         .as_ref()
         .unwrap()
         .contains_key(&3));
+    assert!(device
+        .change_device("PragmaDeactivateQRydQubit", &serialize(&pr_deac).unwrap())
+        .is_err());
 
+    let pr_phase = PragmaSetPhaseRelation::new("2.13".to_string(), "2.15".to_string());
     assert!(device
-        .change_device("PragmaShiftQubitsTweezers", &serialize(&pr_shift).unwrap())
+        .change_device("PragmaSetPhaseRelation", &serialize(&pr_phase).unwrap())
         .is_ok());
-    assert_eq!(
-        device.internal.qubit_to_tweezer.as_ref().unwrap().get(&0),
-        Some(&0)
-    );
-    assert_eq!(
-        device.internal.qubit_to_tweezer.as_ref().unwrap().get(&1),
-        Some(&1)
-    );
-    assert_eq!(
-        device.internal.qubit_to_tweezer.as_ref().unwrap().get(&2),
-        Some(&3)
-    );
+    assert_eq!(device.phase_shift_controlled_z(), Some(2.13));
+    assert_eq!(device.phase_shift_controlled_phase(0.3), Some(2.15));
+    let pr_phase = PragmaSetPhaseRelation::new("Error".to_string(), "2.15".to_string());
+    assert!(device
+        .change_device("PragmaSetPhaseRelation", &serialize(&pr_phase).unwrap())
+        .is_err());
 }
 
 /// Test EmulatorDevice to_generic_device() method
@@ -387,3 +672,20 @@ fn test_from_api() {
         assert!(response.unwrap_err().to_string().contains("incompatible"));
     }
 }
+
+/// Test the JSON schema of EmulatorDevice against a serialized device
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_json_schema() {
+    let mut device = EmulatorDevice::new(None, None, None);
+    device
+        .set_noise_parameters(Some(EmulatorNoiseParameters::default()))
+        .unwrap();
+    device.add_available_gate("RotateX").unwrap();
+
+    let schema = schemars::schema_for!(EmulatorDevice);
+    let schema_checker = jsonschema::JSONSchema::compile(&serde_json::to_value(&schema).unwrap())
+        .expect("Compiling the schema failed");
+    let value = serde_json::to_value(&device).unwrap();
+    assert!(schema_checker.validate(&value).is_ok());
+}
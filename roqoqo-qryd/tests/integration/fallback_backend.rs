@@ -0,0 +1,179 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::operations;
+use roqoqo::{Circuit, RoqoqoBackendError};
+use roqoqo_qryd::api_devices::{QRydAPIDevice, QrydEmuSquareDevice};
+use roqoqo_qryd::{
+    APIBackend, ExecutionPath, FallbackBackend, FallbackPolicy, FallbackRunMetadata, QRydJobResult,
+    QRydJobStatus, ResultCounts, SimulatorBackend, TweezerDevice,
+};
+use std::collections::HashMap;
+use std::net::TcpListener;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, std::f64::consts::PI.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += operations::PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+    circuit
+}
+
+fn create_backend(port: String, policy: FallbackPolicy) -> FallbackBackend {
+    let api_device = QRydAPIDevice::from(&QrydEmuSquareDevice::new(None, None, None));
+    let api_backend = APIBackend::new(api_device, None, Some(2), Some(port), None, None).unwrap();
+    let device = TweezerDevice::square_lattice(
+        2,
+        2,
+        &HashMap::from([
+            ("RotateX".to_string(), 1e-6),
+            ("PhaseShiftedControlledZ".to_string(), 1e-6),
+        ]),
+    )
+    .unwrap();
+    FallbackBackend::new(api_backend, SimulatorBackend::new(device, None), policy)
+}
+
+/// Returns a local port no server is listening on.
+fn unreachable_port() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.local_addr().unwrap().port().to_string()
+}
+
+/// Test FallbackBackend simulating locally when the WebAPI is unreachable
+#[test]
+fn test_fallback_unreachable() {
+    let circuit = create_circuit();
+    let backend = create_backend(unreachable_port(), FallbackPolicy::OnUnreachable);
+
+    let ((bit_registers, _, _), metadata) = backend.run_circuit_with_metadata(&circuit).unwrap();
+    assert_eq!(metadata.path, ExecutionPath::Simulator);
+    assert!(metadata.api_error.is_some());
+    let measured = bit_registers.get("ro").unwrap();
+    assert_eq!(measured.len(), 10);
+    assert!(measured.iter().all(|shot| shot == &vec![true, false]));
+
+    // Runs through the EvaluatingBackend trait are recorded as well, also for clones
+    let cloned = backend.clone();
+    assert!(cloned.run_circuit(&circuit).is_ok());
+    assert_eq!(backend.run_metadata().len(), 2);
+    assert!(backend
+        .run_metadata()
+        .iter()
+        .all(|metadata| metadata.path == ExecutionPath::Simulator));
+    backend.clear_run_metadata();
+    assert!(cloned.run_metadata().is_empty());
+
+    // Without fallback the network error is returned
+    let backend = create_backend(unreachable_port(), FallbackPolicy::Never);
+    assert!(matches!(
+        backend.run_circuit(&circuit),
+        Err(RoqoqoBackendError::NetworkError { .. })
+    ));
+    assert!(backend.run_metadata().is_empty());
+}
+
+/// Test FallbackBackend with completed and failed jobs on the WebAPI
+#[tokio::test]
+async fn test_fallback_job_status() {
+    let circuit = create_circuit();
+    let server_wiremock = MockServer::start().await;
+    let port = server_wiremock.address().port().to_string();
+
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201).insert_header(
+            "Location",
+            &format!("{}/DummyLocation", server_wiremock.uri()),
+        ))
+        .mount(&server_wiremock)
+        .await;
+    let _mock_status = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&QRydJobStatus {
+            status: "completed".to_string(),
+            ..Default::default()
+        }))
+        .mount(&server_wiremock)
+        .await;
+    let _mock_result = Mock::given(method("GET"))
+        .and(path("/DummyLocation/result"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&QRydJobResult {
+            data: ResultCounts {
+                counts: HashMap::from([("0x1".to_string(), 10)]),
+            },
+            ..Default::default()
+        }))
+        .mount(&server_wiremock)
+        .await;
+
+    let backend = create_backend(port.clone(), FallbackPolicy::OnUnreachable);
+    let circuit_cloned = circuit.clone();
+    let ((bit_registers, _, _), metadata) =
+        tokio::task::spawn_blocking(move || backend.run_circuit_with_metadata(&circuit_cloned))
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(
+        metadata,
+        FallbackRunMetadata {
+            path: ExecutionPath::WebAPI,
+            api_error: None
+        }
+    );
+    assert_eq!(bit_registers.get("ro").unwrap().len(), 10);
+
+    server_wiremock.reset().await;
+    let _mock_post = Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201).insert_header(
+            "Location",
+            &format!("{}/DummyLocation", server_wiremock.uri()),
+        ))
+        .mount(&server_wiremock)
+        .await;
+    let _mock_status = Mock::given(method("GET"))
+        .and(path("/DummyLocation/status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&QRydJobStatus {
+            status: "error".to_string(),
+            ..Default::default()
+        }))
+        .mount(&server_wiremock)
+        .await;
+
+    // A failed job is only simulated locally with the OnAnyError policy
+    let backend = create_backend(port.clone(), FallbackPolicy::OnUnreachable);
+    let circuit_cloned = circuit.clone();
+    let result = tokio::task::spawn_blocking(move || backend.run_circuit(&circuit_cloned))
+        .await
+        .unwrap();
+    assert!(matches!(
+        result,
+        Err(RoqoqoBackendError::GenericError { .. })
+    ));
+
+    let backend = create_backend(port, FallbackPolicy::OnAnyError);
+    let circuit_cloned = circuit.clone();
+    let (_, metadata) =
+        tokio::task::spawn_blocking(move || backend.run_circuit_with_metadata(&circuit_cloned))
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(metadata.path, ExecutionPath::Simulator);
+    assert!(metadata
+        .api_error
+        .unwrap()
+        .contains("WebAPI returned an error status"));
+}
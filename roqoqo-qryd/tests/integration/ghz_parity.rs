@@ -0,0 +1,129 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo_qryd::ghz_parity::GHZParityExperiment;
+
+// Test the creation of a GHZParityExperiment
+#[test]
+fn test_new() {
+    let experiment = GHZParityExperiment::new(vec![1, 0, 2], 8, 100).unwrap();
+    assert_eq!(experiment.qubits(), &[1, 0, 2]);
+    assert_eq!(experiment.number_phases(), 8);
+    assert_eq!(experiment.number_measurements(), 100);
+    assert_eq!(experiment.phases().len(), 8);
+    assert_eq!(experiment.phases()[2], std::f64::consts::FRAC_PI_2);
+
+    assert!(GHZParityExperiment::new(vec![0], 8, 100).is_err());
+    assert!(GHZParityExperiment::new(vec![0, 0], 8, 100).is_err());
+    assert!(GHZParityExperiment::new(vec![0, 1, 2], 6, 100).is_err());
+    assert!(GHZParityExperiment::new(vec![0, 1], 5, 0).is_err());
+}
+
+// Test the generated circuits
+#[test]
+fn test_circuits() {
+    let experiment = GHZParityExperiment::new(vec![0, 1, 2], 7, 50).unwrap();
+    let preparation = experiment.preparation_circuit();
+    assert_eq!(preparation.count_occurences(&["ControlledPauliZ"]), 2);
+    assert_eq!(preparation.count_occurences(&["RotateXY"]), 5);
+
+    let circuits = experiment.circuits();
+    assert_eq!(circuits.len(), 8);
+    assert_eq!(circuits[0].count_occurences(&["RotateXY"]), 5);
+    assert_eq!(circuits[0].count_occurences(&["MeasureQubit"]), 3);
+    for circuit in circuits[1..].iter() {
+        assert_eq!(circuit.count_occurences(&["RotateXY"]), 8);
+        assert_eq!(circuit.count_occurences(&["MeasureQubit"]), 3);
+        assert_eq!(
+            circuit.count_occurences(&["PragmaSetNumberOfMeasurements"]),
+            1
+        );
+        assert_eq!(circuit.count_occurences(&["DefinitionBit"]), 1);
+    }
+}
+
+// Test the analysis of ideal GHZ data
+#[test]
+fn test_analyse() {
+    let experiment = GHZParityExperiment::new(vec![0, 1], 8, 2).unwrap();
+    let even = vec![vec![false, false], vec![true, true]];
+    let odd = vec![vec![true, false], vec![false, true]];
+    let mixed = vec![vec![false, false], vec![true, false]];
+    // parity(phi) = cos(2 phi) for phi = k pi / 4
+    let mut registers = vec![even.clone()];
+    for index in 0..8 {
+        registers.push(match index % 4 {
+            0 => even.clone(),
+            2 => odd.clone(),
+            _ => mixed.clone(),
+        });
+    }
+    let result = experiment.analyse(&registers).unwrap();
+    assert_eq!(result.populations, 1.0);
+    assert_eq!(
+        result.parities,
+        vec![1.0, 0.0, -1.0, 0.0, 1.0, 0.0, -1.0, 0.0]
+    );
+    assert!((result.parity_amplitude - 1.0).abs() < 1e-12);
+    assert!(result.parity_phase_offset.abs() < 1e-12);
+    assert!((result.fidelity - 1.0).abs() < 1e-12);
+
+    registers[0] = odd;
+    let result = experiment.analyse(&registers).unwrap();
+    assert_eq!(result.populations, 0.0);
+    assert!((result.fidelity - 0.5).abs() < 1e-12);
+
+    assert!(experiment.analyse(&registers[1..]).is_err());
+    registers[3] = vec![];
+    assert!(experiment.analyse(&registers).is_err());
+    registers[3] = vec![vec![true]];
+    assert!(experiment.analyse(&registers).is_err());
+}
+
+// Test running the experiment on the simulator
+#[cfg(feature = "simulator")]
+#[test]
+fn test_run_simulator() {
+    use roqoqo_qryd::{SimulatorBackend, TweezerDevice};
+
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..3 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateXY",
+                tweezer,
+                1.0,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    for (tweezer0, tweezer1) in [(0, 1), (1, 2)] {
+        device
+            .set_tweezer_two_qubit_gate_time(
+                "ControlledPauliZ",
+                tweezer0,
+                tweezer1,
+                1.0,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device.switch_layout("default", None).unwrap();
+    let backend = SimulatorBackend::new(device, Some(3));
+
+    let experiment = GHZParityExperiment::new(vec![0, 1, 2], 7, 200).unwrap();
+    let result = experiment.run(&backend).unwrap();
+    assert!((result.populations - 1.0).abs() < 1e-12);
+    assert!((result.parity_amplitude - 1.0).abs() < 0.2);
+    assert!((result.fidelity - 1.0).abs() < 0.1);
+}
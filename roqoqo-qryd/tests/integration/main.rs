@@ -30,8 +30,71 @@ mod simulator_backend;
 #[cfg(feature = "web-api")]
 mod api_backend;
 
+#[cfg(test)]
+#[cfg(feature = "web-api-wasm")]
+mod api_backend_wasm;
+
 mod api_devices;
 
+#[cfg(test)]
+mod result_statistics;
+
+#[cfg(test)]
+mod result_plotting;
+
+#[cfg(test)]
+mod tomography;
+
+#[cfg(test)]
+mod ghz_parity;
+
+#[cfg(test)]
+mod xeb;
+
+#[cfg(test)]
+mod noise_fitting;
+
+#[cfg(test)]
+mod device_pragma_handlers;
+
+#[cfg(test)]
+mod circuit_sanitizer;
+
+#[cfg(test)]
+mod parameter_substitution;
+
+#[cfg(test)]
+mod circuit_upgrade;
+
+#[cfg(test)]
+mod circuit_compatibility;
+
+#[cfg(test)]
+mod resource_estimation;
+
+#[cfg(test)]
+mod bound_circuit;
+
+#[cfg(test)]
+mod calibration;
+
+#[cfg(test)]
+mod capabilities;
+
+#[cfg(test)]
+mod device_validation;
+
+#[cfg(test)]
+mod device_files;
+
+#[cfg(test)]
+#[cfg(feature = "web-api")]
+mod scheduling;
+
+#[cfg(test)]
+#[cfg(all(feature = "web-api", feature = "simulator"))]
+mod fallback_backend;
+
 #[cfg(feature = "web-api")]
 #[test]
 fn test_device_from_api() {
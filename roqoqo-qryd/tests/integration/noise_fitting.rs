@@ -0,0 +1,158 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ndarray::array;
+use roqoqo::devices::Device;
+use roqoqo_qryd::noise_fitting::{
+    apply_depolarising_rate, fit_decay_time, fit_depolarising_rate, fit_t1, fit_t2, fit_xeb_noise,
+};
+use roqoqo_qryd::xeb::{XEBExperiment, XEBResult};
+use roqoqo_qryd::{TweezerDevice, TweezerNoiseParameters};
+
+// Returns a device with two connected tweezers, qubit 0 in tweezer 1 and qubit 1 in tweezer 0
+fn create_device() -> TweezerDevice {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..2 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateXY",
+                tweezer,
+                1.0,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device
+        .set_tweezer_two_qubit_gate_time("ControlledPauliZ", 0, 1, 2.0, Some("default".to_string()))
+        .unwrap();
+    device.switch_layout("default", None).unwrap();
+    device.add_qubit_tweezer_mapping(0, 1).unwrap();
+    device.add_qubit_tweezer_mapping(1, 0).unwrap();
+    device
+}
+
+// Test the noise parameters of the TweezerDevice
+#[test]
+fn test_tweezer_noise() {
+    let mut device = create_device();
+    assert_eq!(
+        device.get_tweezer_noise(1),
+        TweezerNoiseParameters::default()
+    );
+    assert_eq!(
+        device.qubit_decoherence_rates(&0).unwrap(),
+        array![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]
+    );
+
+    device.set_tweezer_t1(1, 2.0).unwrap();
+    device.set_tweezer_t2(1, 2.0).unwrap();
+    device.set_tweezer_depolarising_rate(1, 0.4).unwrap();
+    assert_eq!(
+        device.get_tweezer_noise(1),
+        TweezerNoiseParameters {
+            t1: Some(2.0),
+            t2: Some(2.0),
+            depolarising_rate: Some(0.4),
+        }
+    );
+    let rates = device.qubit_decoherence_rates(&0).unwrap();
+    let expected = array![[0.2, 0.0, 0.0], [0.0, 0.7, 0.0], [0.0, 0.0, 0.225]];
+    assert!(rates
+        .iter()
+        .zip(expected.iter())
+        .all(|(rate, expected)| (rate - expected).abs() < 1e-12));
+    assert_eq!(
+        device.qubit_decoherence_rates(&1).unwrap(),
+        array![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]
+    );
+
+    assert!(device.set_tweezer_t1(0, 0.0).is_err());
+    assert!(device.set_tweezer_t2(0, -1.0).is_err());
+    assert!(device.set_tweezer_depolarising_rate(0, -0.1).is_err());
+
+    let serialised = serde_json::to_string(&device).unwrap();
+    let deserialised: TweezerDevice = serde_json::from_str(&serialised).unwrap();
+    assert_eq!(device, deserialised);
+
+    device.clear_noise();
+    assert_eq!(
+        device.get_tweezer_noise(1),
+        TweezerNoiseParameters::default()
+    );
+}
+
+// Test fitting a decay time
+#[test]
+fn test_fit_decay_time() {
+    let times = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let signal: Vec<f64> = times.iter().map(|t: &f64| 0.9 * (-t / 2.5).exp()).collect();
+    let decay_time = fit_decay_time(&times, &signal).unwrap();
+    assert!((decay_time - 2.5).abs() < 1e-10);
+
+    assert!(fit_decay_time(&times, &signal[..3]).is_err());
+    assert!(fit_decay_time(&[1.0, 1.0], &[0.5, 0.4]).is_err());
+    assert!(fit_decay_time(&[0.0, 1.0], &[0.4, 0.5]).is_err());
+    assert!(fit_decay_time(&[0.0, 1.0], &[0.4, 0.0]).is_err());
+}
+
+// Test fitting T1 and T2 into the device
+#[test]
+fn test_fit_t1_t2() {
+    let mut device = create_device();
+    let times = vec![0.0, 1.0, 2.0, 3.0];
+    let populations: Vec<f64> = times.iter().map(|t: &f64| (-t / 10.0).exp()).collect();
+    let coherences: Vec<f64> = times.iter().map(|t: &f64| (-t / 5.0).exp()).collect();
+
+    let t1 = fit_t1(&mut device, 0, &times, &populations).unwrap();
+    let t2 = fit_t2(&mut device, 0, &times, &coherences).unwrap();
+    assert!((t1 - 10.0).abs() < 1e-10);
+    assert!((t2 - 5.0).abs() < 1e-10);
+    let noise = device.get_tweezer_noise(1);
+    assert_eq!(noise.t1, Some(t1));
+    assert_eq!(noise.t2, Some(t2));
+    assert_eq!(noise.depolarising_rate, None);
+
+    assert!(fit_t1(&mut device, 5, &times, &populations).is_err());
+}
+
+// Test fitting the depolarising rate from an XEB result
+#[test]
+fn test_fit_depolarising_rate() {
+    let mut device = create_device();
+    let experiment = XEBExperiment::new(&device, vec![0, 1], vec![1, 2], 2, 10, Some(1)).unwrap();
+    let result = XEBResult {
+        depths: vec![1, 2],
+        fidelities: vec![0.9, 0.81],
+        fidelity_per_cycle: Some(0.9),
+    };
+    // Each cycle: two RotateXY gates of time 1.0 and one ControlledPauliZ of time 2.0 on two qubits
+    let rate = fit_xeb_noise(&mut device, &experiment, &result).unwrap();
+    assert!((rate - (-(0.9_f64).ln() / 6.0)).abs() < 1e-10);
+    assert_eq!(device.get_tweezer_noise(0).depolarising_rate, Some(rate));
+    assert_eq!(device.get_tweezer_noise(1).depolarising_rate, Some(rate));
+
+    apply_depolarising_rate(&mut device, &[0], 0.5).unwrap();
+    assert_eq!(device.get_tweezer_noise(1).depolarising_rate, Some(0.5));
+    assert!(apply_depolarising_rate(&mut device, &[3], 0.5).is_err());
+
+    let no_fit = XEBResult {
+        fidelity_per_cycle: None,
+        ..result.clone()
+    };
+    assert!(fit_depolarising_rate(&device, &experiment, &no_fit).is_err());
+    let invalid = XEBResult {
+        fidelity_per_cycle: Some(1.2),
+        ..result
+    };
+    assert!(fit_depolarising_rate(&device, &experiment, &invalid).is_err());
+}
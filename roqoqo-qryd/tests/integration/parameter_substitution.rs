@@ -0,0 +1,208 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::{Calculator, CalculatorFloat};
+use roqoqo::measurements::ClassicalRegister;
+use roqoqo::operations::{self, Operate, Operation};
+use roqoqo::{Circuit, QuantumProgram};
+use roqoqo_qryd::{
+    is_operation_parametrized, remap_circuit_qubits, remap_operation_qubits,
+    substitute_circuit_parameters, substitute_operation_parameters, substitute_program_parameters,
+    PragmaDeactivateQRydQubit, PragmaGlobalRotateXY, PragmaMoveToZone, PragmaShiftQRydQubit,
+    PragmaShiftQubitsTweezers, PragmaSwapTweezers, PragmaSwitchDeviceLayout,
+};
+use std::collections::HashMap;
+
+fn global_rotation(theta: CalculatorFloat) -> Operation {
+    Operation::from(
+        PragmaGlobalRotateXY::new(theta, CalculatorFloat::from(0.5))
+            .to_pragma_change_device()
+            .unwrap(),
+    )
+}
+
+fn symbolic_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::RotateX::new(0, CalculatorFloat::from("theta"));
+    circuit.add_operation(global_rotation(CalculatorFloat::from("2 * theta")));
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit
+}
+
+fn substituted_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += operations::RotateX::new(0, CalculatorFloat::from(1.0));
+    circuit.add_operation(global_rotation(CalculatorFloat::from(2.0)));
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit
+}
+
+/// Test that the parameters of the wrapped QRyd pragmas are detected
+#[test]
+fn test_is_operation_parametrized() {
+    let symbolic = global_rotation(CalculatorFloat::from("theta"));
+    assert!(!symbolic.is_parametrized());
+    assert!(is_operation_parametrized(&symbolic));
+    assert!(!is_operation_parametrized(&global_rotation(
+        CalculatorFloat::from(1.0)
+    )));
+    assert!(!is_operation_parametrized(&Operation::from(
+        PragmaSwapTweezers::new(0, 1)
+            .to_pragma_change_device()
+            .unwrap()
+    )));
+    assert!(is_operation_parametrized(&Operation::from(
+        operations::RotateX::new(0, CalculatorFloat::from("theta"))
+    )));
+    assert!(!is_operation_parametrized(&Operation::from(
+        operations::PragmaChangeDevice {
+            wrapped_tags: vec![],
+            wrapped_hqslang: "CustomPragma".to_string(),
+            wrapped_operation: vec![],
+        }
+    )));
+}
+
+/// Test the parameter substitution of operations and circuits with wrapped QRyd pragmas
+#[test]
+fn test_substitute_circuit_parameters() {
+    let mut calculator = Calculator::new();
+    calculator.set_variable("theta", 1.0);
+
+    assert_eq!(
+        substitute_operation_parameters(
+            &global_rotation(CalculatorFloat::from("theta")),
+            &calculator
+        )
+        .unwrap(),
+        global_rotation(CalculatorFloat::from(1.0))
+    );
+    assert_eq!(
+        substitute_circuit_parameters(&symbolic_circuit(), &calculator).unwrap(),
+        substituted_circuit()
+    );
+    assert!(substitute_circuit_parameters(&symbolic_circuit(), &Calculator::new()).is_err());
+}
+
+/// Test the parameter substitution of QuantumPrograms with wrapped QRyd pragmas
+#[test]
+fn test_substitute_program_parameters() {
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: Some(symbolic_circuit()),
+            circuits: vec![symbolic_circuit()],
+        },
+        input_parameter_names: vec!["theta".to_string()],
+    };
+
+    assert_eq!(
+        substitute_program_parameters(program.clone(), &[1.0]).unwrap(),
+        QuantumProgram::ClassicalRegister {
+            measurement: ClassicalRegister {
+                constant_circuit: Some(substituted_circuit()),
+                circuits: vec![substituted_circuit()],
+            },
+            input_parameter_names: vec![],
+        }
+    );
+    assert!(substitute_program_parameters(program, &[1.0, 2.0]).is_err());
+}
+
+/// Test remap_operation_qubits remapping the qubits of the wrapped QRyd pragmas
+#[test]
+fn test_remap_operation_qubits() {
+    let mapping: HashMap<usize, usize> = HashMap::from([(0, 2), (2, 0)]);
+    let remap = |operation: Operation| remap_operation_qubits(&operation, &mapping).unwrap();
+
+    let deactivate = |qubit: usize| {
+        Operation::from(
+            PragmaDeactivateQRydQubit::new(qubit)
+                .to_pragma_change_device()
+                .unwrap(),
+        )
+    };
+    assert_eq!(remap(deactivate(0)), deactivate(2));
+
+    let shift = |qubit: usize| {
+        Operation::from(
+            PragmaShiftQRydQubit::new(HashMap::from([(qubit, (0, 1))]))
+                .to_pragma_change_device()
+                .unwrap(),
+        )
+    };
+    assert_eq!(remap(shift(2)), shift(0));
+
+    let move_to_zone = |qubits: Vec<usize>| {
+        Operation::from(
+            PragmaMoveToZone::new(qubits, "interaction".to_string())
+                .to_pragma_change_device()
+                .unwrap(),
+        )
+    };
+    assert_eq!(remap(move_to_zone(vec![0, 1])), move_to_zone(vec![2, 1]));
+
+    // Pragmas referring to tweezers or Layouts are unchanged
+    let tweezer_shift = Operation::from(
+        PragmaShiftQubitsTweezers::new(vec![(0, 1)])
+            .to_pragma_change_device()
+            .unwrap(),
+    );
+    assert_eq!(remap(tweezer_shift.clone()), tweezer_shift);
+    let swap = Operation::from(
+        PragmaSwapTweezers::new(0, 2)
+            .to_pragma_change_device()
+            .unwrap(),
+    );
+    assert_eq!(remap(swap.clone()), swap);
+    let switch = Operation::from(
+        PragmaSwitchDeviceLayout::new("default".to_string())
+            .to_pragma_change_device()
+            .unwrap(),
+    );
+    assert_eq!(remap(switch.clone()), switch);
+
+    // Other operations are remapped by roqoqo
+    assert_eq!(
+        remap(Operation::from(operations::RotateX::new(
+            0,
+            CalculatorFloat::from(1.0)
+        ))),
+        Operation::from(operations::RotateX::new(2, CalculatorFloat::from(1.0)))
+    );
+}
+
+/// Test remap_circuit_qubits remapping a circuit with wrapped QRyd pragmas
+#[test]
+fn test_remap_circuit_qubits() {
+    let mapping: HashMap<usize, usize> = HashMap::from([(0, 1), (1, 0)]);
+    let mut circuit = Circuit::new();
+    circuit += operations::CNOT::new(0, 1);
+    circuit += PragmaDeactivateQRydQubit::new(0)
+        .to_pragma_change_device()
+        .unwrap();
+    circuit += operations::PauliX::new(1);
+
+    let mut expected = Circuit::new();
+    expected += operations::CNOT::new(1, 0);
+    expected += PragmaDeactivateQRydQubit::new(1)
+        .to_pragma_change_device()
+        .unwrap();
+    expected += operations::PauliX::new(0);
+
+    assert_eq!(remap_circuit_qubits(&circuit, &mapping).unwrap(), expected);
+    assert_eq!(
+        remap_circuit_qubits(&Circuit::new(), &mapping).unwrap(),
+        Circuit::new()
+    );
+}
@@ -11,12 +11,16 @@
 // limitations under the License.
 
 use bincode::serialize;
-use qoqo_calculator::Calculator;
+use ndarray::array;
+use qoqo_calculator::{Calculator, CalculatorFloat};
 use roqoqo::operations::{InvolveQubits, InvolvedQubits, Operate, PragmaChangeDevice, Substitute};
+use roqoqo::RoqoqoBackendError;
 use roqoqo_qryd::pragma_operations::{
-    PragmaChangeQRydLayout, PragmaDeactivateQRydQubit, PragmaShiftQRydQubit,
-    PragmaShiftQubitsTweezers, PragmaSwitchDeviceLayout,
+    PragmaChangeQRydLayout, PragmaDeactivateQRydQubit, PragmaGlobalRotateXY, PragmaMoveToZone,
+    PragmaSetPhaseRelation, PragmaShiftQRydQubit, PragmaShiftQubitsTweezers, PragmaSwapTweezers,
+    PragmaSwitchDeviceLayout,
 };
+use roqoqo_qryd::FirstDevice;
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::HashMap;
 
@@ -306,6 +310,58 @@ fn pragma_shift_qryd_qubit_serde_compact() {
     );
 }
 
+/// Test PragmaShiftQRydQubit builder functions and validation against a FirstDevice
+#[test]
+fn pragma_shift_qryd_qubit_builder() {
+    let device = FirstDevice::new(
+        2,
+        3,
+        &[2, 2],
+        1.0,
+        array![[0.0, 1.0, 2.0], [0.0, 1.0, 2.0]],
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let pragma = PragmaShiftQRydQubit::from_device(&device);
+    assert_eq!(pragma.new_positions(), device.qubit_positions());
+    assert!(pragma.validate(&device).is_ok());
+
+    let shifted = pragma.clone().shift_row(0, 1).unwrap().move_qubit(2, 1, 2);
+    let mut new_positions: HashMap<usize, (usize, usize)> = HashMap::new();
+    new_positions.insert(0, (0, 1));
+    new_positions.insert(1, (0, 2));
+    new_positions.insert(2, (1, 2));
+    new_positions.insert(3, (1, 1));
+    assert_eq!(shifted, PragmaShiftQRydQubit::new(new_positions));
+    assert!(shifted.validate(&device).is_ok());
+
+    assert_eq!(
+        pragma.clone().shift_row(1, -1),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Shifting row 1 by -1 columns moves qubit 2 from column 0 to a negative column."
+                .to_string()
+        })
+    );
+    assert_eq!(
+        pragma.clone().shift_row(0, 2).unwrap().validate(&device),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "New position of qubit 1 is column 3 but row 0 only has 3 tweezers".to_string()
+        })
+    );
+    assert_eq!(
+        pragma.clone().move_qubit(0, 1, 2).validate(&device),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "New qubit positions has a mismatch in rows for qubit 0 old row 0 new row 1"
+                .to_string()
+        })
+    );
+    assert!(pragma.move_qubit(4, 0, 2).validate(&device).is_err());
+}
+
 /// Test PragmaDeactivateQRydQubit inputs and involved qubits
 #[test]
 fn pragma_deactivate_qryd_qubit_inputs_qubits() {
@@ -384,7 +440,7 @@ fn pragma_deactivate_qryd_qubit_operate_trait() {
 fn pragma_deactivate_qryd_qubit_substitute_trait() {
     let qubit = 0;
     let pragma = PragmaDeactivateQRydQubit::new(qubit);
-    let pragma_test = PragmaDeactivateQRydQubit::new(qubit);
+    let pragma_test = PragmaDeactivateQRydQubit::new(2);
 
     // (1) Substitute parameters function
     let mut substitution_dict: Calculator = Calculator::new();
@@ -396,8 +452,10 @@ fn pragma_deactivate_qryd_qubit_substitute_trait() {
     let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
     qubit_mapping_test.insert(0, 2);
     qubit_mapping_test.insert(2, 0);
-    let result = pragma_test.remap_qubits(&qubit_mapping_test);
-    assert!(result.is_err());
+    let result = pragma_test.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+    let result = pragma.remap_qubits(&HashMap::new()).unwrap();
+    assert_eq!(result, pragma);
 }
 
 /// Test PragmaDeactivateQRydQubit Serialization and Deserialization traits (readable)
@@ -481,7 +539,7 @@ fn pragma_shift_qryd_qubit_tweezer_simple_traits() {
     assert_eq!(
         format!("{:?}", pragma),
         format!(
-            "PragmaShiftQubitsTweezers {{ shifts: {:?} }}",
+            "PragmaShiftQubitsTweezers {{ shifts: {:?}, durations: None, parallel: false }}",
             shifts.clone()
         )
     );
@@ -496,6 +554,37 @@ fn pragma_shift_qryd_qubit_tweezer_simple_traits() {
     assert!(pragma == pragma_0);
     assert!(pragma_1 != pragma);
     assert!(pragma != pragma_1);
+    let timed =
+        PragmaShiftQubitsTweezers::with_durations(shifts.clone(), vec![f64::NAN, 1.0], false);
+    assert!(timed == timed.clone());
+    assert!(timed != pragma);
+    assert!(PragmaShiftQubitsTweezers::new_parallel(shifts.clone()) != pragma);
+}
+
+/// Test deserializing PragmaShiftQubitsTweezers serialized before durations and parallel were added
+#[test]
+fn pragma_shift_qryd_qubit_tweezer_legacy_bincode() {
+    #[derive(serde::Serialize)]
+    struct LegacyPragmaShiftQubitsTweezers {
+        shifts: Vec<(usize, usize)>,
+    }
+    let shifts: Vec<(usize, usize)> = vec![(0, 1), (3, 4)];
+    let legacy_bytes = serialize(&LegacyPragmaShiftQubitsTweezers {
+        shifts: shifts.clone(),
+    })
+    .unwrap();
+    assert!(bincode::deserialize::<PragmaShiftQubitsTweezers>(&legacy_bytes).is_err());
+    assert_eq!(
+        PragmaShiftQubitsTweezers::from_bincode(&legacy_bytes).unwrap(),
+        PragmaShiftQubitsTweezers::new(shifts.clone())
+    );
+
+    let timed = PragmaShiftQubitsTweezers::with_durations(shifts, vec![0.5, 1.0], true);
+    assert_eq!(
+        PragmaShiftQubitsTweezers::from_bincode(&serialize(&timed).unwrap()).unwrap(),
+        timed
+    );
+    assert!(PragmaShiftQubitsTweezers::from_bincode(&[1, 2]).is_err());
 }
 
 /// Test PragmaShiftQubitsTweezers Operate trait
@@ -520,8 +609,6 @@ fn pragma_shift_qryd_qubit_tweezer_operate_trait() {
 fn pragma_shift_qryd_qubit_tweezer_substitute_trait() {
     let shifts: Vec<(usize, usize)> = vec![(0, 1), (3, 4)];
     let pragma = PragmaShiftQubitsTweezers::new(shifts.clone());
-    let new_shifts: Vec<(usize, usize)> = vec![(0, 2), (3, 5)];
-    let pragma_test = PragmaShiftQubitsTweezers::new(new_shifts.clone());
     // (1) Substitute parameters function
     let mut substitution_dict: Calculator = Calculator::new();
     substitution_dict.set_variable("ro", 0.0);
@@ -532,7 +619,8 @@ fn pragma_shift_qryd_qubit_tweezer_substitute_trait() {
     let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
     qubit_mapping_test.insert(2, 1);
     qubit_mapping_test.insert(5, 4);
-    let result = pragma_test.remap_qubits(&qubit_mapping_test).unwrap();
+    // The shifts refer to tweezers and are not relabeled with the qubits
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
     assert_eq!(result, pragma);
 }
 
@@ -546,7 +634,7 @@ fn pragma_shift_qryd_qubit_tweezer_serde_readable() {
         &[
             Token::Struct {
                 name: "PragmaShiftQubitsTweezers",
-                len: 1,
+                len: 3,
             },
             Token::Str("shifts"),
             Token::Seq { len: Some(1) },
@@ -555,6 +643,10 @@ fn pragma_shift_qryd_qubit_tweezer_serde_readable() {
             Token::U64(1),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("durations"),
+            Token::None,
+            Token::Str("parallel"),
+            Token::Bool(false),
             Token::StructEnd,
         ],
     );
@@ -570,7 +662,7 @@ fn pragma_shift_qryd_qubit_tweezer_serde_compact() {
         &[
             Token::Struct {
                 name: "PragmaShiftQubitsTweezers",
-                len: 1,
+                len: 3,
             },
             Token::Str("shifts"),
             Token::Seq { len: Some(1) },
@@ -579,6 +671,10 @@ fn pragma_shift_qryd_qubit_tweezer_serde_compact() {
             Token::U64(1),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("durations"),
+            Token::None,
+            Token::Str("parallel"),
+            Token::Bool(false),
             Token::StructEnd,
         ],
     );
@@ -709,3 +805,509 @@ fn pragma_switch_device_layout_serde_compact() {
         ],
     );
 }
+
+/// Test PragmaSwapTweezers inputs and involved qubits
+#[test]
+fn pragma_swap_tweezers_inputs_qubits() {
+    let pragma = PragmaSwapTweezers::new(0, 1);
+
+    // Test inputs are correct
+    assert_eq!(pragma.tweezer_0, 0);
+    assert_eq!(pragma.tweezer_1, 1);
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+}
+
+/// Test PragmaSwapTweezers to_pragma_change_device function
+#[test]
+fn pragma_swap_tweezers_change() {
+    let pragma = PragmaSwapTweezers::new(0, 1);
+
+    // Test inputs are correct
+    let result = PragmaChangeDevice {
+        wrapped_tags: vec![
+            "Operation".to_string(),
+            "PragmaOperation".to_string(),
+            "PragmaSwapTweezers".to_string(),
+        ],
+        wrapped_hqslang: "PragmaSwapTweezers".to_string(),
+        wrapped_operation: serialize(&pragma).unwrap(),
+    };
+    assert_eq!(pragma.to_pragma_change_device().unwrap(), result);
+}
+
+/// Test PragmaSwapTweezers standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_swap_tweezers_simple_traits() {
+    let pragma = PragmaSwapTweezers::new(0, 1);
+
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaSwapTweezers { tweezer_0: 0, tweezer_1: 1 }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaSwapTweezers::new(0, 1);
+    let pragma_1 = PragmaSwapTweezers::new(1, 0);
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaSwapTweezers Operate trait
+#[test]
+fn pragma_swap_tweezers_operate_trait() {
+    let pragma = PragmaSwapTweezers::new(0, 1);
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSwapTweezers"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaSwapTweezers"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaSwapTweezers Substitute trait
+#[test]
+fn pragma_swap_tweezers_substitute_trait() {
+    let pragma = PragmaSwapTweezers::new(0, 1);
+
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("ro", 0.0);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    assert_eq!(result, pragma);
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    // The tweezers are not relabeled with the qubits
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaSwapTweezers Serialization and Deserialization traits (readable)
+#[test]
+fn pragma_swap_tweezers_serde_readable() {
+    let pragma_serialization = PragmaSwapTweezers::new(0, 1);
+
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaSwapTweezers",
+                len: 2,
+            },
+            Token::Str("tweezer_0"),
+            Token::U64(0),
+            Token::Str("tweezer_1"),
+            Token::U64(1),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaSwapTweezers Serialization and Deserialization traits (compact)
+#[test]
+fn pragma_swap_tweezers_serde_compact() {
+    let pragma_serialization = PragmaSwapTweezers::new(0, 1);
+
+    assert_tokens(
+        &pragma_serialization.compact(),
+        &[
+            Token::Struct {
+                name: "PragmaSwapTweezers",
+                len: 2,
+            },
+            Token::Str("tweezer_0"),
+            Token::U64(0),
+            Token::Str("tweezer_1"),
+            Token::U64(1),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaGlobalRotateXY inputs and involved qubits
+#[test]
+fn pragma_global_rotate_xy_inputs_qubits() {
+    let pragma = PragmaGlobalRotateXY::new(CalculatorFloat::from(1.0), CalculatorFloat::from(0.5));
+
+    // Test inputs are correct
+    assert_eq!(pragma.theta(), &CalculatorFloat::from(1.0));
+    assert_eq!(pragma.phi(), &CalculatorFloat::from(0.5));
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+}
+
+/// Test PragmaGlobalRotateXY to_pragma_change_device function
+#[test]
+fn pragma_global_rotate_xy_change() {
+    let pragma = PragmaGlobalRotateXY::new(CalculatorFloat::from(1.0), CalculatorFloat::from(0.5));
+
+    // Test inputs are correct
+    let result = PragmaChangeDevice {
+        wrapped_tags: vec![
+            "Operation".to_string(),
+            "PragmaOperation".to_string(),
+            "PragmaGlobalRotateXY".to_string(),
+        ],
+        wrapped_hqslang: "PragmaGlobalRotateXY".to_string(),
+        wrapped_operation: serialize(&pragma).unwrap(),
+    };
+    assert_eq!(pragma.to_pragma_change_device().unwrap(), result);
+}
+
+/// Test PragmaGlobalRotateXY standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_global_rotate_xy_simple_traits() {
+    let pragma = PragmaGlobalRotateXY::new(CalculatorFloat::from(1.0), CalculatorFloat::from(0.5));
+
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaGlobalRotateXY { theta: Float(1.0), phi: Float(0.5) }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 =
+        PragmaGlobalRotateXY::new(CalculatorFloat::from(1.0), CalculatorFloat::from(0.5));
+    let pragma_1 =
+        PragmaGlobalRotateXY::new(CalculatorFloat::from(0.5), CalculatorFloat::from(1.0));
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaGlobalRotateXY Operate trait
+#[test]
+fn pragma_global_rotate_xy_operate_trait() {
+    let pragma = PragmaGlobalRotateXY::new(CalculatorFloat::from(1.0), CalculatorFloat::from(0.5));
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaGlobalRotateXY"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaGlobalRotateXY"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+    let pragma_symbolic =
+        PragmaGlobalRotateXY::new(CalculatorFloat::from("theta"), CalculatorFloat::from(0.5));
+    assert!(pragma_symbolic.is_parametrized());
+}
+
+/// Test PragmaGlobalRotateXY Substitute trait
+#[test]
+fn pragma_global_rotate_xy_substitute_trait() {
+    let pragma =
+        PragmaGlobalRotateXY::new(CalculatorFloat::from("theta"), CalculatorFloat::from(0.5));
+
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("theta", 1.0);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    assert_eq!(
+        result,
+        PragmaGlobalRotateXY::new(CalculatorFloat::from(1.0), CalculatorFloat::from(0.5))
+    );
+    assert!(pragma.substitute_parameters(&Calculator::new()).is_err());
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaGlobalRotateXY Serialization and Deserialization traits (readable)
+#[test]
+fn pragma_global_rotate_xy_serde_readable() {
+    let pragma_serialization =
+        PragmaGlobalRotateXY::new(CalculatorFloat::from(1.0), CalculatorFloat::from(0.5));
+
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaGlobalRotateXY",
+                len: 2,
+            },
+            Token::Str("theta"),
+            Token::F64(1.0),
+            Token::Str("phi"),
+            Token::F64(0.5),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaMoveToZone inputs and involved qubits
+#[test]
+fn pragma_move_to_zone_inputs_qubits() {
+    let pragma = PragmaMoveToZone::new(vec![0, 1], "interaction".to_string());
+
+    // Test inputs are correct
+    assert_eq!(pragma.qubits, vec![0, 1]);
+    assert_eq!(pragma.zone, "interaction".to_string());
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+}
+
+/// Test PragmaMoveToZone to_pragma_change_device function
+#[test]
+fn pragma_move_to_zone_change() {
+    let pragma = PragmaMoveToZone::new(vec![0, 1], "interaction".to_string());
+
+    // Test inputs are correct
+    let result = PragmaChangeDevice {
+        wrapped_tags: vec![
+            "Operation".to_string(),
+            "PragmaOperation".to_string(),
+            "PragmaMoveToZone".to_string(),
+        ],
+        wrapped_hqslang: "PragmaMoveToZone".to_string(),
+        wrapped_operation: serialize(&pragma).unwrap(),
+    };
+    assert_eq!(pragma.to_pragma_change_device().unwrap(), result);
+}
+
+/// Test PragmaMoveToZone standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_move_to_zone_simple_traits() {
+    let pragma = PragmaMoveToZone::new(vec![0, 1], "interaction".to_string());
+
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaMoveToZone { qubits: [0, 1], zone: \"interaction\" }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaMoveToZone::new(vec![0, 1], "interaction".to_string());
+    let pragma_1 = PragmaMoveToZone::new(vec![0, 1], "storage".to_string());
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaMoveToZone Operate trait
+#[test]
+fn pragma_move_to_zone_operate_trait() {
+    let pragma = PragmaMoveToZone::new(vec![0, 1], "interaction".to_string());
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaMoveToZone"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaMoveToZone"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaMoveToZone Substitute trait
+#[test]
+fn pragma_move_to_zone_substitute_trait() {
+    let pragma = PragmaMoveToZone::new(vec![0, 1], "interaction".to_string());
+
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("ro", 0.0);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    assert_eq!(result, pragma);
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(
+        result,
+        PragmaMoveToZone::new(vec![2, 1], "interaction".to_string())
+    );
+}
+
+/// Test PragmaMoveToZone Serialization and Deserialization traits (readable)
+#[test]
+fn pragma_move_to_zone_serde_readable() {
+    let pragma_serialization = PragmaMoveToZone::new(vec![0], "interaction".to_string());
+
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaMoveToZone",
+                len: 2,
+            },
+            Token::Str("qubits"),
+            Token::Seq { len: Some(1) },
+            Token::U64(0),
+            Token::SeqEnd,
+            Token::Str("zone"),
+            Token::Str("interaction"),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test PragmaSetPhaseRelation inputs and involved qubits
+#[test]
+fn pragma_set_phase_relation_inputs_qubits() {
+    let pragma = PragmaSetPhaseRelation::new("DefaultRelation".to_string(), "0.5".to_string());
+
+    // Test inputs are correct
+    assert_eq!(pragma.cz_relation, "DefaultRelation".to_string());
+    assert_eq!(pragma.cp_relation, "0.5".to_string());
+
+    // Test InvolveQubits trait
+    assert_eq!(pragma.involved_qubits(), InvolvedQubits::All);
+}
+
+/// Test PragmaSetPhaseRelation to_pragma_change_device function
+#[test]
+fn pragma_set_phase_relation_change() {
+    let pragma = PragmaSetPhaseRelation::new("DefaultRelation".to_string(), "0.5".to_string());
+
+    // Test inputs are correct
+    let result = PragmaChangeDevice {
+        wrapped_tags: vec![
+            "Operation".to_string(),
+            "PragmaOperation".to_string(),
+            "PragmaSetPhaseRelation".to_string(),
+        ],
+        wrapped_hqslang: "PragmaSetPhaseRelation".to_string(),
+        wrapped_operation: serialize(&pragma).unwrap(),
+    };
+    assert_eq!(pragma.to_pragma_change_device().unwrap(), result);
+}
+
+/// Test PragmaSetPhaseRelation standard derived traits (Debug, Clone, PartialEq)
+#[test]
+fn pragma_set_phase_relation_simple_traits() {
+    let pragma = PragmaSetPhaseRelation::new("DefaultRelation".to_string(), "0.5".to_string());
+
+    // Test Debug trait
+    assert_eq!(
+        format!("{:?}", pragma),
+        "PragmaSetPhaseRelation { cz_relation: \"DefaultRelation\", cp_relation: \"0.5\" }"
+    );
+
+    // Test Clone trait
+    assert_eq!(pragma.clone(), pragma);
+
+    // Test PartialEq trait
+    let pragma_0 = PragmaSetPhaseRelation::new("DefaultRelation".to_string(), "0.5".to_string());
+    let pragma_1 = PragmaSetPhaseRelation::new("0.5".to_string(), "DefaultRelation".to_string());
+    assert!(pragma_0 == pragma);
+    assert!(pragma == pragma_0);
+    assert!(pragma_1 != pragma);
+    assert!(pragma != pragma_1);
+}
+
+/// Test PragmaSetPhaseRelation Operate trait
+#[test]
+fn pragma_set_phase_relation_operate_trait() {
+    let pragma = PragmaSetPhaseRelation::new("DefaultRelation".to_string(), "0.5".to_string());
+
+    // (1) Test tags function
+    let tags: &[&str; 3] = &["Operation", "PragmaOperation", "PragmaSetPhaseRelation"];
+    assert_eq!(pragma.tags(), tags);
+
+    // (2) Test hqslang function
+    assert_eq!(pragma.hqslang(), String::from("PragmaSetPhaseRelation"));
+
+    // (3) Test is_parametrized function
+    assert!(!pragma.is_parametrized());
+}
+
+/// Test PragmaSetPhaseRelation Substitute trait
+#[test]
+fn pragma_set_phase_relation_substitute_trait() {
+    let pragma = PragmaSetPhaseRelation::new("DefaultRelation".to_string(), "0.5".to_string());
+
+    // (1) Substitute parameters function
+    let mut substitution_dict: Calculator = Calculator::new();
+    substitution_dict.set_variable("ro", 0.0);
+    let result = pragma.substitute_parameters(&substitution_dict).unwrap();
+    assert_eq!(result, pragma);
+
+    // (2) Remap qubits function
+    let mut qubit_mapping_test: HashMap<usize, usize> = HashMap::new();
+    qubit_mapping_test.insert(0, 2);
+    qubit_mapping_test.insert(2, 0);
+    let result = pragma.remap_qubits(&qubit_mapping_test).unwrap();
+    assert_eq!(result, pragma);
+}
+
+/// Test PragmaSetPhaseRelation Serialization and Deserialization traits (readable)
+#[test]
+fn pragma_set_phase_relation_serde_readable() {
+    let pragma_serialization =
+        PragmaSetPhaseRelation::new("DefaultRelation".to_string(), "0.5".to_string());
+
+    assert_tokens(
+        &pragma_serialization.readable(),
+        &[
+            Token::Struct {
+                name: "PragmaSetPhaseRelation",
+                len: 2,
+            },
+            Token::Str("cz_relation"),
+            Token::Str("DefaultRelation"),
+            Token::Str("cp_relation"),
+            Token::Str("0.5"),
+            Token::StructEnd,
+        ],
+    );
+}
+
+/// Test the JSON schemas of the layout and shift pragmas against their serialized form
+#[cfg(feature = "json_schema")]
+#[test]
+fn pragma_json_schema() {
+    fn validate<T: serde::Serialize + schemars::JsonSchema>(pragma: &T) {
+        let schema = schemars::schema_for!(T);
+        let schema_checker =
+            jsonschema::JSONSchema::compile(&serde_json::to_value(&schema).unwrap())
+                .expect("Compiling the schema failed");
+        let value = serde_json::to_value(pragma).unwrap();
+        assert!(schema_checker.validate(&value).is_ok());
+    }
+
+    validate(&PragmaChangeQRydLayout::new(1));
+    let mut new_positions: HashMap<usize, (usize, usize)> = HashMap::new();
+    new_positions.insert(0, (0, 1));
+    validate(&PragmaShiftQRydQubit::new(new_positions));
+    validate(&PragmaSwitchDeviceLayout::new("Square".to_string()));
+    validate(&PragmaShiftQubitsTweezers::new(vec![(0, 1), (2, 3)]));
+
+    // A serialized pragma missing a field does not match the schema
+    let schema = schemars::schema_for!(PragmaSwitchDeviceLayout);
+    let schema_checker =
+        jsonschema::JSONSchema::compile(&serde_json::to_value(&schema).unwrap()).unwrap();
+    assert!(schema_checker.validate(&serde_json::json!({})).is_err());
+}
@@ -17,7 +17,7 @@ use roqoqo::RoqoqoBackendError;
 use roqoqo_qryd::{
     phi_theta_relation,
     pragma_operations::{PragmaChangeQRydLayout, PragmaShiftQRydQubit},
-    qryd_devices::{FirstDevice, QRydDevice},
+    qryd_devices::{DistanceGateModel, FirstDevice, QRydDevice},
 };
 // use serde_test::{assert_tokens, Configure, Token};
 use std::collections::HashMap;
@@ -473,6 +473,142 @@ fn test_change_qubit_positions() {
     assert!(qryd_device == QRydDevice::FirstDevice(device));
 }
 
+/// Test FirstDevice with a different number of tweezers in each row
+#[test]
+fn test_ragged_device() {
+    let device = FirstDevice::new_ragged(
+        &[2, 1],
+        vec![vec![(0.0, 0.0), (0.0, 1.0), (0.0, 2.0)], vec![(0.8, 0.5)]],
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(device.number_rows(), 2);
+    assert_eq!(device.number_columns(), 3);
+    assert_eq!(device.columns_per_row(), vec![3, 1]);
+    assert_eq!(device.number_qubits(), 3);
+    assert_eq!(device.tweezer_position(&(1, 0)), Some((0.8, 0.5)));
+    assert_eq!(device.tweezer_position(&(1, 1)), None);
+    assert_eq!(device.tweezer_position(&(2, 0)), None);
+
+    // Qubit 2 sits between qubits 0 and 1
+    assert!(device
+        .two_qubit_gate_time("PhaseShiftedControlledZ", &0, &2)
+        .is_some());
+    assert!(device
+        .two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1)
+        .is_some());
+
+    let mut device = device
+        .add_irregular_layout(
+            1,
+            vec![vec![(0.0, 0.0), (0.0, 1.0), (0.0, 5.0)], vec![(3.0, 0.0)]],
+        )
+        .unwrap();
+    device.switch_layout(&1).unwrap();
+    assert!(device
+        .two_qubit_gate_time("PhaseShiftedControlledZ", &0, &2)
+        .is_none());
+    let mut new_positions = device.qubit_positions().clone();
+    new_positions.insert(1, (0, 2));
+    device.change_qubit_positions(&new_positions).unwrap();
+    assert!(device
+        .two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1)
+        .is_none());
+
+    new_positions.insert(2, (1, 1));
+    assert_eq!(
+        device.change_qubit_positions(&new_positions),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "New position of qubit 2 is column 1 but row 1 only has 1 tweezers".to_string()
+        })
+    );
+    assert_eq!(
+        device.add_irregular_layout(2, vec![vec![(0.0, 0.0)], vec![(1.0, 0.0)]]),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Error adding layout to QRyd device new layout [3, 1] tweezers per row required"
+                .to_string()
+        })
+    );
+    assert_eq!(
+        device.add_irregular_layout(1, vec![vec![(0.0, 0.0); 3], vec![(1.0, 0.0)]]),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Error adding layout to QRyd device layout key 1 is already used".to_string()
+        })
+    );
+    assert_eq!(
+        FirstDevice::new_ragged(&[2], vec![vec![(0.0, 0.0)]], None, None, None, None),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Row 0 has 1 tweezers but 2 qubits have been specified".to_string()
+        })
+    );
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: FirstDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, device);
+}
+
+/// Test updating qubit positions, tweezer positions and the cutoff of an existing FirstDevice
+#[test]
+fn test_runtime_updates() {
+    let mut device = FirstDevice::new(
+        2,
+        3,
+        &[2, 1],
+        1.0,
+        array![[0.0, 1.0, 2.0], [0.0, 1.0, 2.0]],
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(device.cutoff(), 1.0);
+    assert_eq!(device.two_qubit_edges(), vec![(0, 1), (0, 2)]);
+
+    device.set_cutoff(1.5);
+    assert_eq!(device.cutoff(), 1.5);
+    assert_eq!(device.two_qubit_edges(), vec![(0, 1), (0, 2), (1, 2)]);
+    device.set_cutoff(0.5);
+    assert!(device.two_qubit_edges().is_empty());
+    device.set_cutoff(1.0);
+
+    device.set_qubit_position(1, 2).unwrap();
+    assert_eq!(device.qubit_positions().get(&1), Some(&(0, 2)));
+    assert_eq!(device.two_qubit_edges(), vec![(0, 2)]);
+    assert_eq!(
+        device.set_qubit_position(1, 0),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Tweezer (0, 0) is already occupied by qubit 0".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_qubit_position(1, 3),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "New position of qubit 1 is column 3 but row 0 only has 3 tweezers".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_qubit_position(5, 0),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Qubit 5 is not in the device".to_string()
+        })
+    );
+
+    device.set_tweezer_position(&(0, 2), (0.0, 0.5)).unwrap();
+    assert_eq!(device.tweezer_position(&(0, 2)), Some((0.0, 0.5)));
+    assert_eq!(device.tweezer_position(&(1, 1)), Some((1.0, 1.0)));
+    assert_eq!(device.two_qubit_edges(), vec![(0, 1), (0, 2)]);
+    assert_eq!(
+        device.set_tweezer_position(&(1, 3), (0.0, 0.0)),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Tweezer (1, 3) does not exist in the current layout".to_string()
+        })
+    );
+}
+
 #[test]
 fn test_change_qubit_positions_error_qubit() {
     let mut device = FirstDevice::new(
@@ -924,7 +1060,7 @@ fn test_traits_firstdevice() {
     let qubits = create_simple_qubit_positions(&[(0_usize, (0_usize, 0_usize))]);
     assert_eq!(
         format!("{:?}", device),
-        format!("FirstDevice {{ number_rows: 1, number_columns: 1, qubit_positions: {:?}, row_distance: 0.0, layout_register: {{0: [[0.0]], shape=[1, 1], strides=[1, 1], layout=CFcf (0xf), const ndim=2}}, current_layout: 0, cutoff: 1.0, controlled_z_phase_relation: \"DefaultRelation\", controlled_phase_phase_relation: \"DefaultRelation\", allow_ccz_gate: true, allow_ccp_gate: false }}", qubits) 
+        format!("FirstDevice {{ number_rows: 1, number_columns: 1, columns_per_row: None, qubit_positions: {:?}, row_distance: 0.0, layout_register: {{0: [[0.0]], shape=[1, 1], strides=[1, 1], layout=CFcf (0xf), const ndim=2}}, irregular_layout_register: {{}}, current_layout: 0, cutoff: 1.0, controlled_z_phase_relation: \"DefaultRelation\", controlled_phase_phase_relation: \"DefaultRelation\", allow_ccz_gate: true, allow_ccp_gate: false, distance_gate_model: None }}", qubits) 
     );
 }
 
@@ -952,7 +1088,7 @@ fn test_traits_qryddevice() {
     let qubits = create_simple_qubit_positions(&[(0_usize, (0_usize, 0_usize))]);
     assert_eq!(
         format!("{:?}", qryd_device),
-        format!("FirstDevice(FirstDevice {{ number_rows: 1, number_columns: 1, qubit_positions: {:?}, row_distance: 0.0, layout_register: {{0: [[0.0]], shape=[1, 1], strides=[1, 1], layout=CFcf (0xf), const ndim=2}}, current_layout: 0, cutoff: 1.0, controlled_z_phase_relation: \"DefaultRelation\", controlled_phase_phase_relation: \"DefaultRelation\", allow_ccz_gate: true, allow_ccp_gate: false }})", qubits) 
+        format!("FirstDevice(FirstDevice {{ number_rows: 1, number_columns: 1, columns_per_row: None, qubit_positions: {:?}, row_distance: 0.0, layout_register: {{0: [[0.0]], shape=[1, 1], strides=[1, 1], layout=CFcf (0xf), const ndim=2}}, irregular_layout_register: {{}}, current_layout: 0, cutoff: 1.0, controlled_z_phase_relation: \"DefaultRelation\", controlled_phase_phase_relation: \"DefaultRelation\", allow_ccz_gate: true, allow_ccp_gate: false, distance_gate_model: None }})", qubits) 
     );
 }
 
@@ -1131,6 +1267,181 @@ fn test_allowed_3qubit_gate() {
         .is_none());
 }
 
+/// Test three-qubit gate settings of FirstDevice
+#[test]
+fn test_three_qubit_gates() {
+    let mut device = FirstDevice::new(
+        2,
+        2,
+        &[2, 2],
+        1.0,
+        array![[0.0, 1.0], [0.0, 1.0]],
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    device.set_cutoff(1.5);
+    assert!(device.allow_ccz_gate());
+    assert!(!device.allow_ccp_gate());
+    assert_eq!(
+        device.available_three_qubit_gates(),
+        vec!["ControlledControlledPauliZ"]
+    );
+    assert!(device
+        .three_qubit_gate_time("ControlledControlledPauliZ", &0, &0, &1)
+        .is_none());
+    assert!(device
+        .three_qubit_gate_triples("ControlledControlledPhaseShift")
+        .is_empty());
+    let triples = device.three_qubit_gate_triples("ControlledControlledPauliZ");
+    // Every ordering of the four triples of a 2x2 grid with a cutoff above the diagonal
+    assert_eq!(triples.len(), 24);
+    assert_eq!(triples[0], (0, 1, 2));
+
+    device.set_allow_ccp_gate(true);
+    device.set_allow_ccz_gate(false);
+    assert!(!device.allow_ccz_gate());
+    assert!(device.allow_ccp_gate());
+    assert_eq!(
+        device.available_three_qubit_gates(),
+        vec!["ControlledControlledPhaseShift"]
+    );
+    assert!(device
+        .three_qubit_gate_time("ControlledControlledPauliZ", &0, &1, &2)
+        .is_none());
+    assert!(device
+        .three_qubit_gate_time("ControlledControlledPhaseShift", &0, &1, &2)
+        .is_some());
+
+    device.set_cutoff(1.0);
+    assert!(device
+        .three_qubit_gate_triples("ControlledControlledPhaseShift")
+        .is_empty());
+}
+
+/// Test the distance dependence of the two-qubit gates
+#[test]
+fn test_distance_gate_model() {
+    let mut device = FirstDevice::new(
+        1,
+        3,
+        &[2],
+        1.0,
+        array![[0.0, 0.5, 1.0]],
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(device.distance_gate_model(), None);
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1),
+        Some(5e-7)
+    );
+    assert_eq!(
+        device.two_qubit_gate_fidelity("PhaseShiftedControlledZ", &0, &1),
+        Some(1.0)
+    );
+    assert_eq!(
+        device.two_qubit_gate_fidelity("ControlledPauliZ", &0, &1),
+        None
+    );
+
+    let model = DistanceGateModel::VanDerWaals {
+        c6: 1.0,
+        phase: 1e-6,
+        decay_rate: 1e4,
+    };
+    device.set_distance_gate_model(Some(model.clone())).unwrap();
+    assert_eq!(device.distance_gate_model(), Some(&model));
+    let gate_time = device
+        .two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1)
+        .unwrap();
+    assert!((gate_time - 1.5625e-8).abs() < 1e-15);
+    let fidelity = device
+        .two_qubit_gate_fidelity("PhaseShiftedControlledZ", &0, &1)
+        .unwrap();
+    assert!((fidelity - (-1.5625e-4_f64).exp()).abs() < 1e-12);
+
+    // Moving the qubit apart slows down the gate
+    let pragma =
+        PragmaShiftQRydQubit::new(create_simple_qubit_positions(&[(0, (0, 0)), (1, (0, 2))]));
+    device
+        .change_device("PragmaShiftQRydQubit", &serialize(&pragma).unwrap())
+        .unwrap();
+    let gate_time = device
+        .two_qubit_gate_time("PhaseShiftedControlledPhase", &0, &1)
+        .unwrap();
+    assert!((gate_time - 1e-6).abs() < 1e-15);
+    let fidelity = device
+        .two_qubit_gate_fidelity("PhaseShiftedControlledPhase", &0, &1)
+        .unwrap();
+    assert!((fidelity - (-1e-2_f64).exp()).abs() < 1e-12);
+
+    // The cutoff still limits the available gates
+    device.set_cutoff(0.9);
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1),
+        None
+    );
+    device.set_cutoff(1.0);
+
+    device
+        .set_distance_gate_model(Some(DistanceGateModel::PowerLaw {
+            prefactor: 3e-6,
+            exponent: 3.0,
+            fidelity: 0.99,
+        }))
+        .unwrap();
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1),
+        Some(3e-6)
+    );
+    assert_eq!(
+        device.two_qubit_gate_fidelity("PhaseShiftedControlledZ", &0, &1),
+        Some(0.99)
+    );
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: FirstDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, device);
+
+    assert_eq!(
+        device.set_distance_gate_model(Some(DistanceGateModel::VanDerWaals {
+            c6: 0.0,
+            phase: 1.0,
+            decay_rate: 0.0,
+        })),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Invalid parameters of the distance gate model VanDerWaals { c6: 0.0, phase: 1.0, decay_rate: 0.0 }".to_string()
+        })
+    );
+    assert_eq!(
+        device.set_distance_gate_model(Some(DistanceGateModel::PowerLaw {
+            prefactor: 1e-6,
+            exponent: 2.0,
+            fidelity: 1.5,
+        })),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Invalid parameters of the distance gate model PowerLaw { prefactor: 1e-6, exponent: 2.0, fidelity: 1.5 }".to_string()
+        })
+    );
+
+    let mut qryd_device = QRydDevice::from(&device);
+    qryd_device.set_distance_gate_model(None).unwrap();
+    assert_eq!(
+        qryd_device.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1),
+        Some(2e-6)
+    );
+    assert_eq!(
+        qryd_device.two_qubit_gate_fidelity("PhaseShiftedControlledZ", &0, &1),
+        Some(1.0)
+    );
+}
+
 // /// Test FirstDevice Serialization and Deserialization traits (readable)
 // #[cfg(feature = "serialize")]
 // #[test]
@@ -0,0 +1,105 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::measurements::{
+    Cheated, CheatedInput, ClassicalRegister, PauliZProduct, PauliZProductInput,
+};
+use roqoqo::operations;
+use roqoqo::Circuit;
+use roqoqo::QuantumProgram;
+use roqoqo_qryd::{estimate_resources, QrydEmuSquareDevice};
+
+fn create_circuit(number_measurements: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, 1.0.into());
+    circuit += operations::Hadamard::new(1);
+    circuit += operations::CNOT::new(0, 1);
+    // Qubits 0 and 7 are not connected in the square device
+    circuit += operations::PhaseShiftedControlledZ::new(0, 7, 0.0.into());
+    circuit += operations::MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += operations::MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit +=
+        operations::PragmaSetNumberOfMeasurements::new(number_measurements, "ro".to_string());
+    circuit
+}
+
+/// Test counting the native gates and estimating the time of a ClassicalRegister program
+#[test]
+fn test_estimate_classical_register() {
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![create_circuit(100)],
+        },
+        input_parameter_names: vec![],
+    };
+    let estimate = estimate_resources(&device, &program).unwrap();
+
+    assert_eq!(estimate.number_qubits, 3);
+    assert_eq!(estimate.number_circuits, 1);
+    assert_eq!(estimate.number_shots, 100);
+    assert_eq!(estimate.native_gate_counts.get("RotateX"), Some(&1));
+    // Hadamard and the two Hadamards of the CNOT
+    assert_eq!(estimate.native_gate_counts.get("RotateY"), Some(&3));
+    assert_eq!(estimate.native_gate_counts.get("PauliZ"), Some(&3));
+    assert_eq!(estimate.native_gate_counts.get("RotateZ"), Some(&2));
+    assert_eq!(
+        estimate.native_gate_counts.get("PhaseShiftedControlledZ"),
+        Some(&2)
+    );
+    assert!(!estimate.native_gate_counts.contains_key("CNOT"));
+    assert_eq!(
+        estimate.gates_without_time,
+        vec!["PhaseShiftedControlledZ".to_string()]
+    );
+    assert!((estimate.estimated_time - 100.0 * 10e-6).abs() < 1e-12);
+}
+
+/// Test that the constant circuit is added to every circuit of a PauliZProduct program
+#[test]
+fn test_estimate_pauli_z_product() {
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += operations::RotateX::new(2, 1.0.into());
+    let program = QuantumProgram::PauliZProduct {
+        measurement: PauliZProduct {
+            constant_circuit: Some(constant_circuit),
+            circuits: vec![create_circuit(10), create_circuit(20)],
+            input: PauliZProductInput::new(2, false),
+        },
+        input_parameter_names: vec![],
+    };
+    let estimate = estimate_resources(&device, &program).unwrap();
+
+    assert_eq!(estimate.number_qubits, 4);
+    assert_eq!(estimate.number_circuits, 2);
+    assert_eq!(estimate.number_shots, 30);
+    assert_eq!(estimate.native_gate_counts.get("RotateX"), Some(&4));
+    assert!((estimate.estimated_time - 30.0 * 11e-6).abs() < 1e-12);
+}
+
+/// Test that programs not posted to the WebAPI are rejected
+#[test]
+fn test_estimate_unsupported_program() {
+    let device = QrydEmuSquareDevice::new(Some(2), None, None);
+    let program = QuantumProgram::Cheated {
+        measurement: Cheated {
+            constant_circuit: None,
+            circuits: vec![],
+            input: CheatedInput::new(2),
+        },
+        input_parameter_names: vec![],
+    };
+    assert!(estimate_resources(&device, &program).is_err());
+}
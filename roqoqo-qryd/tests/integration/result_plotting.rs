@@ -0,0 +1,59 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo_qryd::result_plotting::{bit_register_to_counts, counts_histogram, plot_counts};
+use std::collections::HashMap;
+
+// Test the conversion of a bit register into counts
+#[test]
+fn test_bit_register_to_counts() {
+    let register = vec![vec![true, false], vec![true, false], vec![false, true]];
+    let counts = bit_register_to_counts(&register);
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts.get("10"), Some(&2));
+    assert_eq!(counts.get("01"), Some(&1));
+    assert!(bit_register_to_counts(&[]).is_empty());
+}
+
+// Test the sorting of the histogram data
+#[test]
+fn test_counts_histogram() {
+    let counts: HashMap<String, u64> = HashMap::from([
+        ("0x10".to_string(), 4),
+        ("0x3".to_string(), 2),
+        ("0x1".to_string(), 7),
+    ]);
+    let histogram = counts_histogram(&counts);
+    assert_eq!(
+        histogram,
+        vec![
+            ("0x1".to_string(), 7),
+            ("0x3".to_string(), 2),
+            ("0x10".to_string(), 4)
+        ]
+    );
+}
+
+// Test rendering the counts histogram
+#[test]
+fn test_plot_counts() {
+    let counts: HashMap<String, u64> = HashMap::from([
+        ("00".to_string(), 40),
+        ("01".to_string(), 10),
+        ("11".to_string(), 50),
+    ]);
+    let _image = plot_counts(&counts, None, &Some("counts_test.png".to_owned())).unwrap();
+    assert!(std::path::Path::new("counts_test.png").exists());
+    std::fs::remove_file("counts_test.png").unwrap();
+
+    assert!(plot_counts(&HashMap::new(), None, &None).is_err());
+}
@@ -0,0 +1,106 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo_qryd::result_statistics::{
+    bit_probability, pauli_z_product_expectation, wilson_interval,
+};
+
+// Test the Wilson score interval against reference values
+#[test]
+fn test_wilson_interval() {
+    let (lower, upper) = wilson_interval(50, 100, None).unwrap();
+    assert!((lower - 0.4038).abs() < 1e-4);
+    assert!((upper - 0.5962).abs() < 1e-4);
+
+    let (lower, upper) = wilson_interval(0, 10, None).unwrap();
+    assert!(lower.abs() < 1e-12);
+    assert!(upper > 0.0 && upper < 0.35);
+
+    let (lower, upper) = wilson_interval(10, 10, Some(2.576)).unwrap();
+    assert!(lower > 0.5 && lower < 1.0);
+    assert!((upper - 1.0).abs() < 1e-12);
+
+    assert!(wilson_interval(0, 0, None).is_err());
+    assert!(wilson_interval(11, 10, None).is_err());
+}
+
+// Test the probability estimate of a single readout bit
+#[test]
+fn test_bit_probability() {
+    let register = vec![
+        vec![true, false],
+        vec![true, true],
+        vec![false, false],
+        vec![true, false],
+    ];
+    let estimate = bit_probability(&register, 0, None).unwrap();
+    assert_eq!(estimate.value, 0.75);
+    assert!((estimate.standard_error - (0.75_f64 * 0.25 / 4.0).sqrt()).abs() < 1e-12);
+    assert!(estimate.lower_bound < 0.75 && estimate.upper_bound > 0.75);
+    assert_eq!(estimate.number_shots, 4);
+
+    assert!(bit_probability(&register, 2, None).is_err());
+    assert!(bit_probability(&[], 0, None).is_err());
+}
+
+// Test the PauliZ product expectation value estimate
+#[test]
+fn test_pauli_z_product_expectation() {
+    let register = vec![
+        vec![true, true],
+        vec![false, false],
+        vec![true, false],
+        vec![false, false],
+    ];
+    let estimate = pauli_z_product_expectation(&register, &[0, 1], None).unwrap();
+    assert_eq!(estimate.value, 0.5);
+    assert!((estimate.standard_error - 2.0 * (0.75_f64 * 0.25 / 4.0).sqrt()).abs() < 1e-12);
+    assert!(estimate.lower_bound >= -1.0 && estimate.upper_bound <= 1.0);
+    assert!(estimate.lower_bound < 0.5 && estimate.upper_bound > 0.5);
+
+    let estimate = pauli_z_product_expectation(&register, &[], None).unwrap();
+    assert_eq!(estimate.value, 1.0);
+
+    assert!(pauli_z_product_expectation(&register, &[3], None).is_err());
+}
+
+// Test the estimates computed directly from WebAPI counts
+#[cfg(feature = "web-api")]
+#[test]
+fn test_estimates_from_counts() {
+    use roqoqo_qryd::api_backend::ResultCounts;
+    use roqoqo_qryd::result_statistics::{
+        bit_probability_from_counts, pauli_z_product_expectation_from_counts,
+    };
+    use std::collections::HashMap;
+
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    counts.insert("0x1".to_string(), 30);
+    counts.insert("0x3".to_string(), 20);
+    counts.insert("0x0".to_string(), 50);
+    let counts = ResultCounts { counts };
+
+    let estimate = bit_probability_from_counts(&counts, 0, None).unwrap();
+    assert_eq!(estimate.value, 0.5);
+    assert_eq!(estimate.number_shots, 100);
+    let estimate = bit_probability_from_counts(&counts, 1, None).unwrap();
+    assert_eq!(estimate.value, 0.2);
+
+    let estimate = pauli_z_product_expectation_from_counts(&counts, &[0, 1], None).unwrap();
+    assert!((estimate.value - 0.4).abs() < 1e-12);
+
+    let wrong_counts = ResultCounts {
+        counts: HashMap::from([("1".to_string(), 1)]),
+    };
+    assert!(bit_probability_from_counts(&wrong_counts, 0, None).is_err());
+    assert!(bit_probability_from_counts(&ResultCounts::default(), 0, None).is_err());
+}
@@ -0,0 +1,109 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::measurements::ClassicalRegister;
+use roqoqo::operations;
+use roqoqo::{Circuit, QuantumProgram};
+use roqoqo_qryd::{
+    APIBackend, FastestTurnaround, QRydAPIDevice, QrydEmuSquareDevice, QrydEmuTriangularDevice,
+    Scheduler, SchedulingCandidate, SchedulingPolicy, ShortestQueue, TurnaroundEstimate,
+};
+
+fn create_candidate(device: QRydAPIDevice, queue_length: usize) -> SchedulingCandidate {
+    let backend = APIBackend::new(device, None, None, Some("0".to_string()), None, None).unwrap();
+    let mut candidate = SchedulingCandidate::new(backend, 1.0);
+    candidate.queue_length = queue_length;
+    candidate.seconds_per_queued_job = 10.0;
+    candidate
+}
+
+fn create_program(control: usize, target: usize) -> QuantumProgram {
+    let mut circuit = Circuit::new();
+    circuit += operations::DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += operations::RotateX::new(0, 1.0.into());
+    circuit += operations::PhaseShiftedControlledZ::new(control, target, 0.0.into());
+    circuit += operations::PragmaSetNumberOfMeasurements::new(100, "ro".to_string());
+    QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![circuit],
+        },
+        input_parameter_names: vec![],
+    }
+}
+
+/// Test selecting the device with the fastest turnaround
+#[test]
+fn test_fastest_turnaround() {
+    let square = QRydAPIDevice::from(&QrydEmuSquareDevice::new(Some(2), None, None));
+    let triangular = QRydAPIDevice::from(&QrydEmuTriangularDevice::new(
+        Some(2),
+        None,
+        None,
+        None,
+        None,
+    ));
+    let scheduler = Scheduler::new(
+        vec![create_candidate(square, 5), create_candidate(triangular, 2)],
+        FastestTurnaround,
+    );
+    let program = create_program(0, 1);
+
+    let estimates = scheduler.estimate(&program).unwrap();
+    assert_eq!(estimates.len(), 2);
+    assert_eq!(estimates[0].queue_time, 50.0);
+    assert_eq!(estimates[1].queue_time, 20.0);
+    assert!(estimates.iter().all(TurnaroundEstimate::is_supported));
+    assert!(estimates[0].run_time > 0.0);
+    assert_eq!(
+        estimates[0].turnaround(),
+        estimates[0].queue_time + estimates[0].run_time
+    );
+
+    let selected = scheduler.select(&program, None).unwrap();
+    assert_eq!(selected.candidate, 1);
+    assert_eq!(selected.device_name, "qryd_emu_cloudcomp_triangle");
+    assert!(scheduler.select(&program, Some(30.0)).is_ok());
+    assert!(scheduler.select(&program, Some(10.0)).is_err());
+
+    // Devices without the gate times of the program are not selected
+    let program = create_program(0, 29);
+    let estimates = scheduler.estimate(&program).unwrap();
+    assert!(!estimates[0].is_supported());
+    assert!(!estimates[1].is_supported());
+    assert!(scheduler.select(&program, None).is_err());
+}
+
+/// Test the ShortestQueue policy and custom policies
+#[test]
+fn test_policies() {
+    let device = QRydAPIDevice::from(&QrydEmuSquareDevice::new(Some(2), None, None));
+    let mut slow = create_candidate(device.clone(), 1);
+    slow.seconds_per_time_unit = 1e6;
+    let scheduler = Scheduler::new(
+        vec![slow.clone(), create_candidate(device.clone(), 3)],
+        ShortestQueue,
+    );
+    let program = create_program(0, 1);
+    assert_eq!(scheduler.select(&program, None).unwrap().candidate, 0);
+    assert_eq!(scheduler.select(&program, Some(40.0)).unwrap().candidate, 1);
+
+    struct LastCandidate;
+    impl SchedulingPolicy for LastCandidate {
+        fn select(&self, estimates: &[TurnaroundEstimate], _: Option<f64>) -> Option<usize> {
+            estimates.last().map(|estimate| estimate.candidate + 1)
+        }
+    }
+    let scheduler = Scheduler::new(vec![slow, create_candidate(device, 3)], LastCandidate);
+    // Indices selected by a policy are checked
+    assert!(scheduler.select(&program, None).is_err());
+}
@@ -10,10 +10,16 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use roqoqo::measurements::{ClassicalRegister, PauliZProduct, PauliZProductInput};
 use roqoqo::prelude::*;
-use roqoqo::{operations::*, Circuit};
-use roqoqo_qryd::{SimulatorBackend, TweezerDevice};
+use roqoqo::{operations::*, Circuit, QuantumProgram, RoqoqoBackendError};
+use roqoqo_qryd::{
+    device_events, DeviceEvent, DeviceEventKind, PragmaDeactivateQRydQubit, PragmaGlobalRotateXY,
+    PragmaShiftQubitsTweezers, PragmaSwitchDeviceLayout, SimulatorBackend, TweezerDevice,
+    EVENTS_REGISTER,
+};
 use roqoqo_test::prepare_monte_carlo_gate_test;
+use std::collections::HashMap;
 
 /// Test SimulatorBackend initialization with TweezerDevice.
 #[test]
@@ -76,7 +82,7 @@ fn test_simple_traits() {
     assert_eq!(
         format!("{:?}", backend_tw),
         format!(
-            "SimulatorBackend {{ device: {:?}, number_qubits: 0 }}",
+            "SimulatorBackend {{ device: {:?}, number_qubits: 0, noisy: false, number_threads: 1, sample_terminal_measurements: true, density_matrix: false, memory_limit: None, transport_noise: false }}",
             device_tw
         )
     );
@@ -205,3 +211,766 @@ fn test_full_simple_gate() {
         assert!((val - measured_exp_vals.get(key).unwrap()).abs() < 1.0);
     }
 }
+
+/// Test stochastic loading of the tweezers
+#[test]
+fn test_stochastic_loading() {
+    let gate_times = HashMap::from([("RotateX".to_string(), 1.0)]);
+    let mut device = TweezerDevice::square_lattice(1, 3, &gate_times).unwrap();
+    device.set_loading_probability(0, 0.0).unwrap();
+    device.set_loading_probability(1, 1.0).unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += RotateX::new(1, std::f64::consts::PI.into());
+    circuit += RotateX::new(2, std::f64::consts::PI.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+
+    // The qubit in the empty tweezer 0 is never flipped
+    let backend = SimulatorBackend::new(device.clone(), None);
+    let (bits, _, _, statistics) = backend
+        .run_circuit_with_preparation_statistics(&circuit)
+        .unwrap();
+    let ro = bits.get("ro").unwrap();
+    assert_eq!(ro.len(), 10);
+    for shot in ro.iter() {
+        assert_eq!(shot, &vec![false, true, true]);
+    }
+    assert_eq!(statistics.number_shots, 10);
+    assert_eq!(statistics.fully_loaded_shots, 0);
+    assert_eq!(statistics.qubit_occupation.get(&0), None);
+    assert_eq!(statistics.qubit_occupation.get(&1), Some(&10));
+    assert_eq!(statistics.mean_loaded_atoms, 2.0);
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bits.get("ro").unwrap(), ro);
+
+    // With rearrangement the two loaded atoms occupy the first two qubits
+    device.set_rearrangement(true);
+    let backend = SimulatorBackend::new(device, None);
+    let (bits, _, _, statistics) = backend
+        .run_circuit_with_preparation_statistics(&circuit)
+        .unwrap();
+    for shot in bits.get("ro").unwrap().iter() {
+        assert_eq!(shot, &vec![true, true, false]);
+    }
+    assert_eq!(statistics.qubit_occupation.get(&0), Some(&10));
+    assert_eq!(statistics.qubit_occupation.get(&2), None);
+}
+
+/// Test the loss of atoms during measurements and shifts
+#[test]
+fn test_atom_loss() {
+    let gate_times = HashMap::from([("RotateX".to_string(), 1.0)]);
+    let mut device = TweezerDevice::square_lattice(1, 2, &gate_times).unwrap();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 1);
+    circuit += PragmaSetNumberOfMeasurements::new(5, "ro".to_string());
+
+    let backend = SimulatorBackend::new(device.clone(), None);
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bits["ro"], vec![vec![true, true]; 5]);
+
+    // The atom is lost after the first measurement, so the second measurement returns false
+    device.set_measurement_loss_probability(1.0).unwrap();
+    let backend = SimulatorBackend::new(device, None);
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bits["ro"], vec![vec![true, false]; 5]);
+    let loss = DeviceEvent {
+        kind: DeviceEventKind::AtomLoss,
+        qubit: 0,
+    };
+    assert_eq!(device_events(&bits).unwrap(), vec![vec![loss]; 5]);
+
+    // The atom is lost during the shift, so the measurement returns false
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("test").unwrap();
+    for tweezer in 0..2 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 1.0, Some("test".to_string()))
+            .unwrap();
+    }
+    device
+        .set_allowed_tweezer_shifts(&0, &[&[1]], Some("test".to_string()))
+        .unwrap();
+    device.switch_layout("test", Some(false)).unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += PragmaShiftQubitsTweezers::new(vec![(0, 1)])
+        .to_pragma_change_device()
+        .unwrap();
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PragmaSetNumberOfMeasurements::new(3, "ro".to_string());
+
+    let backend = SimulatorBackend::new(device.clone(), Some(1));
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bits["ro"], vec![vec![true]; 3]);
+
+    device.set_shift_loss_probability(1.0).unwrap();
+    let backend = SimulatorBackend::new(device, Some(1));
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bits["ro"], vec![vec![false]; 3]);
+    assert_eq!(device_events(&bits).unwrap(), vec![vec![loss]; 3]);
+}
+
+/// Test the device events recorded during a simulation with stochastic loading
+#[test]
+fn test_device_events() {
+    let gate_times = HashMap::from([("RotateX".to_string(), 1.0)]);
+    let mut device = TweezerDevice::square_lattice(1, 3, &gate_times).unwrap();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 5, None);
+
+    // Without stochastic loading no events are recorded
+    let backend = SimulatorBackend::new(device.clone(), None);
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert!(!bits.contains_key(EVENTS_REGISTER));
+    assert!(device_events(&bits).unwrap().is_empty());
+
+    device.set_loading_probability(2, 0.0).unwrap();
+    let backend = SimulatorBackend::new(device, None);
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    let events = bits.get(EVENTS_REGISTER).unwrap();
+    assert_eq!(events.len(), bits.get("ro").unwrap().len());
+    assert_eq!(events[0].len(), 9);
+    let expected = vec![DeviceEvent {
+        kind: DeviceEventKind::AtomLoss,
+        qubit: 2,
+    }];
+    for shot_events in device_events(&bits).unwrap() {
+        assert_eq!(shot_events, expected);
+    }
+
+    let invalid = HashMap::from([(EVENTS_REGISTER.to_string(), vec![vec![false; 4]])]);
+    assert!(device_events(&invalid).is_err());
+}
+
+/// Test that the sampled loading is reproducible with the seed of the device
+#[test]
+fn test_stochastic_loading_seed() {
+    let mut device = TweezerDevice::new(Some(11), None, None);
+    device.add_layout("test").unwrap();
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 1.0, Some("test".to_string()))
+            .unwrap();
+        device.set_loading_probability(tweezer, 0.5).unwrap();
+    }
+    device.switch_layout("test", None).unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 4, true);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 50, None);
+
+    let backend = SimulatorBackend::new(device, None);
+    let (_, _, _, statistics) = backend
+        .run_circuit_with_preparation_statistics(&circuit)
+        .unwrap();
+    let (_, _, _, statistics_repeated) = backend
+        .run_circuit_with_preparation_statistics(&circuit)
+        .unwrap();
+    assert_eq!(statistics, statistics_repeated);
+    assert_eq!(statistics.number_shots, 50);
+    assert!(statistics.fully_loaded_shots < 50);
+    assert!(statistics.mean_loaded_atoms > 0.0 && statistics.mean_loaded_atoms < 4.0);
+}
+
+/// Test that the shots of different loading groups are sampled from one random stream per run
+#[test]
+fn test_stochastic_loading_seed_groups() {
+    let mut device = TweezerDevice::new(Some(11), None, None);
+    device.add_layout("test").unwrap();
+    for tweezer in 0..2 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 1.0, Some("test".to_string()))
+            .unwrap();
+    }
+    device.switch_layout("test", None).unwrap();
+    device.set_loading_probability(1, 0.5).unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::FRAC_PI_2.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 200, None);
+
+    let backend = SimulatorBackend::new(device, None);
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    let (bits_repeated, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bits, bits_repeated);
+
+    // The registers are grouped by the empty qubits, the fully loaded shots come first
+    let loaded_shots = device_events(&bits)
+        .unwrap()
+        .iter()
+        .filter(|events| events.is_empty())
+        .count();
+    assert!(loaded_shots > 0 && loaded_shots < 200);
+    let first_qubit: Vec<bool> = bits["ro"].iter().map(|shot| shot[0]).collect();
+    let (loaded, empty) = first_qubit.split_at(loaded_shots);
+    let common = loaded.len().min(empty.len());
+    assert_ne!(loaded[..common], empty[..common]);
+}
+
+/// Test that a global rotation acts on every active qubit
+#[test]
+fn test_global_rotation() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("test").unwrap();
+    for tweezer in 0..3 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 1.0, Some("test".to_string()))
+            .unwrap();
+    }
+    device.switch_layout("test", None).unwrap();
+    for qubit in 0..3 {
+        device.add_qubit_tweezer_mapping(qubit, qubit).unwrap();
+    }
+
+    // A rotation by pi around the x axis flips all active qubits, the deactivated qubit 2 is
+    // only flipped by the first rotation
+    let flip = PragmaGlobalRotateXY::new(std::f64::consts::PI.into(), 0.0.into())
+        .to_pragma_change_device()
+        .unwrap();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += flip.clone();
+    circuit += PragmaDeactivateQRydQubit::new(2)
+        .to_pragma_change_device()
+        .unwrap();
+    circuit += flip;
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+
+    let backend = SimulatorBackend::new(device.clone(), None);
+    assert_eq!(
+        backend.run_circuit(&circuit).unwrap_err(),
+        RoqoqoBackendError::GenericError {
+            msg: "The global rotation is not available in the current Layout: no global rotation time is set.".to_string()
+        }
+    );
+
+    device.set_global_rotation_time(1.0, None).unwrap();
+    let backend = SimulatorBackend::new(device, None);
+    let (bit_registers, _, _) = backend.run_circuit(&circuit).unwrap();
+    let output = bit_registers.get("ro").unwrap();
+    assert_eq!(output.len(), 10);
+    for shot in output {
+        assert_eq!(shot, &vec![false, false, true]);
+    }
+}
+
+/// Test that the simulator does not exceed the hardware capacity of the device
+#[test]
+fn test_max_qubits() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("test").unwrap();
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 1.0, Some("test".to_string()))
+            .unwrap();
+    }
+    device.switch_layout("test", None).unwrap();
+    device.set_max_qubits(Some(4)).unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 4, true);
+    circuit += RotateX::new(3, 1.0.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    assert!(SimulatorBackend::new(device.clone(), None)
+        .run_circuit(&circuit)
+        .is_ok());
+
+    let backend = SimulatorBackend::new(device, Some(5));
+    assert_eq!(
+        backend.run_circuit(&circuit).unwrap_err(),
+        RoqoqoBackendError::GenericError {
+            msg: "The simulator uses 5 qubits, the hardware capacity of the device is 4 qubits."
+                .to_string()
+        }
+    );
+}
+
+/// Test running a parametric program for a grid of parameters
+#[test]
+fn test_run_template() {
+    let gate_times = HashMap::from([("RotateX".to_string(), 1.0)]);
+    let device = TweezerDevice::square_lattice(1, 2, &gate_times).unwrap();
+    let backend = SimulatorBackend::new(device, None);
+
+    let mut constant_circuit = Circuit::new();
+    constant_circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    constant_circuit += RotateX::new(1, std::f64::consts::PI.into());
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, "theta".into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 20, None);
+
+    let mut input = PauliZProductInput::new(2, false);
+    let z0 = input.add_pauliz_product("ro".to_string(), vec![0]).unwrap();
+    let z1 = input.add_pauliz_product("ro".to_string(), vec![1]).unwrap();
+    input
+        .add_linear_exp_val("z0".to_string(), HashMap::from([(z0, 1.0)]))
+        .unwrap();
+    input
+        .add_linear_exp_val("z1".to_string(), HashMap::from([(z1, 1.0)]))
+        .unwrap();
+    let program = QuantumProgram::PauliZProduct {
+        measurement: PauliZProduct {
+            constant_circuit: Some(constant_circuit),
+            circuits: vec![circuit],
+            input,
+        },
+        input_parameter_names: vec!["theta".to_string()],
+    };
+
+    let results = backend
+        .run_template(
+            &program,
+            &[vec![0.0], vec![std::f64::consts::PI], vec![0.0]],
+        )
+        .unwrap();
+    assert_eq!(results.len(), 3);
+    let expectation_values: Vec<(f64, f64)> = results
+        .iter()
+        .map(|result| {
+            let result = result.as_ref().unwrap();
+            (result["z0"], result["z1"])
+        })
+        .collect();
+    assert_eq!(
+        expectation_values,
+        vec![(1.0, -1.0), (-1.0, -1.0), (1.0, -1.0)]
+    );
+    assert_eq!(
+        results[1],
+        program
+            .run(backend.clone(), &[std::f64::consts::PI])
+            .unwrap()
+    );
+    assert!(backend.run_template(&program, &[]).unwrap().is_empty());
+
+    // Wrong number of parameters
+    assert!(backend.run_template(&program, &[vec![0.0, 1.0]]).is_err());
+    // Programs without expectation values
+    let classical_program = QuantumProgram::ClassicalRegister {
+        measurement: ClassicalRegister {
+            constant_circuit: None,
+            circuits: vec![],
+        },
+        input_parameter_names: vec![],
+    };
+    assert!(backend.run_template(&classical_program, &[vec![]]).is_err());
+}
+
+/// Test that a noisy backend applies the decoherence of the tweezers after every gate
+#[test]
+fn test_noisy_simulation() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("test").unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 1.0, Some("test".to_string()))
+        .unwrap();
+    device.switch_layout("test", None).unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+
+    let mut flip = Circuit::new();
+    flip += DefinitionComplex::new("rho".to_string(), 4, true);
+    flip += RotateX::new(0, std::f64::consts::PI.into());
+    flip += PragmaGetDensityMatrix::new("rho".to_string(), None);
+    let mut superposition = Circuit::new();
+    superposition += DefinitionComplex::new("rho".to_string(), 4, true);
+    superposition += RotateX::new(0, std::f64::consts::FRAC_PI_2.into());
+    superposition += PragmaGetDensityMatrix::new("rho".to_string(), None);
+
+    // Without noise parameters or with noise disabled the simulation is ideal
+    let mut backend = SimulatorBackend::new(device.clone(), None);
+    backend.set_noisy(true);
+    let (_, _, complex_registers) = backend.run_circuit(&flip).unwrap();
+    assert!((complex_registers["rho"][0][3].re - 1.0).abs() < 1e-10);
+
+    device.set_tweezer_t1(0, 2.0).unwrap();
+    let mut backend = SimulatorBackend::new(device.clone(), None);
+    let (_, _, complex_registers) = backend.run_circuit(&flip).unwrap();
+    assert!((complex_registers["rho"][0][3].re - 1.0).abs() < 1e-10);
+
+    // The excited state decays with 1/T1 during the gate time of 1.0
+    backend.set_noisy(true);
+    let (_, _, complex_registers) = backend.run_circuit(&flip).unwrap();
+    assert!((complex_registers["rho"][0][3].re - (-0.5_f64).exp()).abs() < 1e-10);
+
+    // The coherences decay with 1/T2 during the gate time of 1.0
+    device.clear_noise();
+    device.set_tweezer_t2(0, 2.0).unwrap();
+    let mut backend = SimulatorBackend::new(device, None);
+    backend.set_noisy(true);
+    let (_, _, complex_registers) = backend.run_circuit(&superposition).unwrap();
+    assert!((complex_registers["rho"][0][0].re - 0.5).abs() < 1e-10);
+    assert!((complex_registers["rho"][0][1].norm() - 0.5 * (-0.5_f64).exp()).abs() < 1e-10);
+}
+
+/// Test the transport time and the decoherence during shifts and layout switches
+#[test]
+fn test_transport_noise() {
+    let mut device = TweezerDevice::new(None, None, None);
+    for layout in ["test", "other"] {
+        device.add_layout(layout).unwrap();
+        for tweezer in 0..2 {
+            device
+                .set_tweezer_single_qubit_gate_time(
+                    "RotateX",
+                    tweezer,
+                    1.0,
+                    Some(layout.to_string()),
+                )
+                .unwrap();
+        }
+        device
+            .set_tweezers_per_row(vec![2], Some(layout.to_string()))
+            .unwrap();
+    }
+    device
+        .set_allowed_tweezer_shifts(&0, &[&[1]], Some("test".to_string()))
+        .unwrap();
+    device
+        .set_tweezer_shift_time(0, 1, 1.0, Some("test".to_string()))
+        .unwrap();
+    device
+        .set_layout_switch_time(2.0, Some("other".to_string()))
+        .unwrap();
+    device.switch_layout("test", Some(false)).unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.set_tweezer_t1(0, 2.0).unwrap();
+    device.set_tweezer_t1(1, 2.0).unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("rho".to_string(), 4, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += PragmaShiftQubitsTweezers::new(vec![(0, 1)])
+        .to_pragma_change_device()
+        .unwrap();
+    circuit += PragmaGetDensityMatrix::new("rho".to_string(), None);
+    let mut switched_circuit = circuit.clone();
+    switched_circuit += DefinitionComplex::new("switched_rho".to_string(), 4, true);
+    switched_circuit += PragmaSwitchDeviceLayout::new("other".to_string())
+        .to_pragma_change_device()
+        .unwrap();
+    switched_circuit += PragmaGetDensityMatrix::new("switched_rho".to_string(), None);
+
+    let mut backend = SimulatorBackend::new(device, Some(1));
+    assert_eq!(backend.transport_time(&circuit).unwrap(), 1.0);
+    assert_eq!(backend.transport_time(&switched_circuit).unwrap(), 3.0);
+
+    // Without transport noise the shift is ideal
+    let (_, _, complex_registers) = backend.run_circuit(&circuit).unwrap();
+    assert!((complex_registers["rho"][0][3].re - 1.0).abs() < 1e-10);
+
+    // The excited state decays with 1/T1 during the shift time of 1.0 and the switch time of 2.0
+    backend.set_transport_noise(true);
+    let (_, _, complex_registers) = backend.run_circuit(&switched_circuit).unwrap();
+    assert!((complex_registers["rho"][0][3].re - (-0.5_f64).exp()).abs() < 1e-10);
+    assert!((complex_registers["switched_rho"][0][3].re - (-1.5_f64).exp()).abs() < 1e-10);
+}
+
+/// Test that the shots are split between the threads of the backend
+#[test]
+fn test_parallel_shots() {
+    let gate_times = HashMap::from([("RotateX".to_string(), 1.0)]);
+    let device = TweezerDevice::square_lattice(1, 2, &gate_times).unwrap();
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+
+    for number_threads in [0, 1, 3, 20] {
+        // Sampling the terminal measurements would simulate the circuit only once
+        let mut backend = SimulatorBackend::new(device.clone(), None);
+        backend.set_sample_terminal_measurements(false);
+        backend.set_number_threads(number_threads);
+        let (bit_registers, _, _) = backend.run_circuit(&circuit).unwrap();
+        let output = bit_registers.get("ro").unwrap();
+        assert_eq!(output.len(), 10);
+        for shot in output {
+            assert_eq!(shot, &vec![true, false]);
+        }
+    }
+}
+
+/// Test that the shots of circuits with terminal measurements are sampled from one simulation
+#[test]
+fn test_sample_terminal_measurements() {
+    let mut device = TweezerDevice::new(Some(5), None, None);
+    device.add_layout("test").unwrap();
+    for tweezer in 0..2 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 1.0, Some("test".to_string()))
+            .unwrap();
+    }
+    device.switch_layout("test", None).unwrap();
+    for qubit in 0..2 {
+        device.add_qubit_tweezer_mapping(qubit, qubit).unwrap();
+    }
+    let backend = SimulatorBackend::new(device, None);
+    let mut sequential_backend = backend.clone();
+    sequential_backend.set_sample_terminal_measurements(false);
+
+    // Terminal measurements
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += PragmaSetNumberOfMeasurements::new(10, "ro".to_string());
+    let expected = HashMap::from([("ro".to_string(), vec![vec![true, false]; 10])]);
+    assert_eq!(backend.run_circuit(&circuit).unwrap().0, expected);
+    assert_eq!(
+        sequential_backend.run_circuit(&circuit).unwrap().0,
+        expected
+    );
+
+    // A gate after a measurement of its qubit is simulated once per shot
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 1);
+    circuit += PragmaSetNumberOfMeasurements::new(5, "ro".to_string());
+    let expected = HashMap::from([("ro".to_string(), vec![vec![true, false]; 5])]);
+    assert_eq!(backend.run_circuit(&circuit).unwrap().0, expected);
+
+    // The qubit mapping of a repeated measurement is applied to the sampled bits
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit +=
+        PragmaRepeatedMeasurement::new("ro".to_string(), 3, Some(HashMap::from([(0, 1), (1, 0)])));
+    let expected = HashMap::from([("ro".to_string(), vec![vec![false, true]; 3])]);
+    assert_eq!(backend.run_circuit(&circuit).unwrap().0, expected);
+
+    // The sampled shots are reproducible with the seed of the device
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::FRAC_PI_2.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 100, None);
+    let (bits, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(backend.run_circuit(&circuit).unwrap().0, bits);
+    let ro = bits.get("ro").unwrap();
+    assert_eq!(ro.len(), 100);
+    assert!(ro.iter().any(|shot| shot[0]));
+    assert!(ro.iter().any(|shot| !shot[0]));
+    assert!(ro.iter().all(|shot| !shot[1]));
+}
+
+/// Test the density matrix mode of the backend
+#[test]
+fn test_density_matrix() {
+    let gate_times = HashMap::from([("RotateX".to_string(), 1.0)]);
+    let device = TweezerDevice::square_lattice(1, 2, &gate_times).unwrap();
+    let backend = SimulatorBackend::new_density_matrix(device.clone(), None);
+    assert!(backend.density_matrix);
+    assert!(!SimulatorBackend::new(device, None).density_matrix);
+
+    // Noise channels are applied exactly
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("rho".to_string(), 16, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += PragmaDamping::new(0, 1.0.into(), 0.5.into());
+    circuit += PragmaGetDensityMatrix::new("rho".to_string(), None);
+    let (_, _, complex_registers) = backend.run_circuit(&circuit).unwrap();
+    let rho = &complex_registers["rho"][0];
+    assert!((rho[5].re - (-0.5_f64).exp()).abs() < 1e-10);
+    assert!((rho[0].re - (1.0 - (-0.5_f64).exp())).abs() < 1e-10);
+
+    // Sampled and repeated shots are drawn from the density matrix
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    let expected = HashMap::from([("ro".to_string(), vec![vec![true, false]; 10])]);
+    assert_eq!(backend.run_circuit(&circuit).unwrap().0, expected);
+    let mut sequential_backend = backend.clone();
+    sequential_backend.set_sample_terminal_measurements(false);
+    assert_eq!(
+        sequential_backend.run_circuit(&circuit).unwrap().0,
+        expected
+    );
+}
+
+/// Test the memory limit for the quantum register of a simulation
+#[test]
+fn test_memory_limit() {
+    let gate_times = HashMap::from([("RotateX".to_string(), 1.0)]);
+    let device = TweezerDevice::square_lattice(1, 2, &gate_times).unwrap();
+    let mut backend = SimulatorBackend::new(device, None);
+    assert_eq!(
+        SimulatorBackend::uses_gpu(),
+        cfg!(feature = "simulator-gpu")
+    );
+    assert_eq!(backend.required_memory(false), Some(64));
+    assert_eq!(backend.required_memory(true), Some(256));
+    backend.number_qubits = 64;
+    assert_eq!(backend.required_memory(false), None);
+    backend.number_qubits = 2;
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    backend.set_memory_limit(Some(64));
+    assert!(backend.run_circuit(&circuit).is_ok());
+
+    // A noise operation requires a density matrix
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += PragmaDamping::new(0, 1.0.into(), 0.5.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    #[cfg(not(feature = "simulator-gpu"))]
+    assert_eq!(
+        backend.run_circuit(&circuit).unwrap_err(),
+        RoqoqoBackendError::GenericError {
+            msg: "The density matrix of 2 qubits requires 256 bytes, the memory limit of the simulator is 64 bytes.".to_string()
+        }
+    );
+    #[cfg(feature = "simulator-gpu")]
+    assert!(backend
+        .run_circuit(&circuit)
+        .unwrap_err()
+        .to_string()
+        .contains("need a build without the simulator-gpu feature"));
+    backend.set_memory_limit(None);
+    assert!(backend.run_circuit(&circuit).is_ok());
+}
+
+/// Test reading out the state vector and density matrix during a simulation
+#[test]
+fn test_state_readout() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("test").unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 1.0, Some("test".to_string()))
+        .unwrap();
+    device.switch_layout("test", None).unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    let backend = SimulatorBackend::new(device, None);
+
+    let mut flip = Circuit::new();
+    flip += RotateX::new(0, std::f64::consts::PI.into());
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("psi".to_string(), 2, true);
+    circuit += DefinitionComplex::new("flipped_psi".to_string(), 2, true);
+    circuit += DefinitionComplex::new("rho".to_string(), 4, true);
+    circuit += RotateX::new(0, std::f64::consts::FRAC_PI_2.into());
+    circuit += PragmaGetStateVector::new("psi".to_string(), None);
+    circuit += PragmaGetStateVector::new("flipped_psi".to_string(), Some(flip.clone()));
+    circuit += PragmaGetDensityMatrix::new("rho".to_string(), Some(flip));
+    let (_, _, complex_registers) = backend.run_circuit(&circuit).unwrap();
+
+    let psi = &complex_registers["psi"][0];
+    assert!((psi[0].norm_sqr() - 0.5).abs() < 1e-10);
+    assert!((psi[1].norm_sqr() - 0.5).abs() < 1e-10);
+    // The readout circuit acts only on a copy of the state
+    let flipped_psi = &complex_registers["flipped_psi"][0];
+    assert!((flipped_psi[0].norm_sqr() - 0.5).abs() < 1e-10);
+    let rho = &complex_registers["rho"][0];
+    assert!((rho[0].re - 0.5).abs() < 1e-10);
+    assert!((rho[3].re - 0.5).abs() < 1e-10);
+
+    // The gates of the readout circuit are checked against the device
+    let mut unavailable = Circuit::new();
+    unavailable += RotateY::new(0, 1.0.into());
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("psi".to_string(), 2, true);
+    circuit += PragmaGetStateVector::new("psi".to_string(), Some(unavailable));
+    assert_eq!(
+        backend.run_circuit(&circuit).unwrap_err(),
+        RoqoqoBackendError::GenericError {
+            msg: "The circuit of the PragmaGetStateVector is not compatible with the device: Operation 0 (RotateY) on qubits [0]: the gate is not available on qubits [0]".to_string()
+        }
+    );
+}
+
+/// Test mid-circuit measurements with classically conditioned operations and active resets
+#[test]
+fn test_classical_feedback() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("test").unwrap();
+    for tweezer in 0..2 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 1.0, Some("test".to_string()))
+            .unwrap();
+    }
+    device.switch_layout("test", None).unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 1).unwrap();
+    let backend = SimulatorBackend::new(device.clone(), None);
+
+    // Qubit 1 is flipped if the measurement of qubit 0 returns true
+    let mut feedback = Circuit::new();
+    feedback += RotateX::new(1, std::f64::consts::PI.into());
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PragmaConditional::new("ro".to_string(), 0, feedback);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    circuit += PragmaSetNumberOfMeasurements::new(5, "ro".to_string());
+    let (bit_registers, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bit_registers["ro"], vec![vec![true, true]; 5]);
+
+    // The gates of the conditional circuit are checked against the device
+    let mut unavailable = Circuit::new();
+    unavailable += RotateY::new(1, 1.0.into());
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PragmaConditional::new("ro".to_string(), 0, unavailable);
+    assert_eq!(
+        backend.run_circuit(&circuit).unwrap_err(),
+        RoqoqoBackendError::GenericError {
+            msg: "The circuit of the PragmaConditional is not compatible with the device: Operation 0 (RotateY) on qubits [1]: the gate is not available on qubits [1]".to_string()
+        }
+    );
+
+    // The device can not be changed conditionally
+    let mut deactivation = Circuit::new();
+    deactivation += PragmaDeactivateQRydQubit::new(1)
+        .to_pragma_change_device()
+        .unwrap();
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    circuit += PragmaConditional::new("ro".to_string(), 0, deactivation);
+    assert_eq!(
+        backend.run_circuit(&circuit).unwrap_err(),
+        RoqoqoBackendError::GenericError {
+            msg: "The circuit of the PragmaConditional contains a PragmaDeactivateQRydQubit, the device can not be changed conditionally.".to_string()
+        }
+    );
+
+    // A PragmaActiveReset is only supported if the device allows resets
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += RotateX::new(0, std::f64::consts::PI.into());
+    circuit += PragmaActiveReset::new(0);
+    circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    assert_eq!(
+        backend.run_circuit(&circuit).unwrap_err(),
+        RoqoqoBackendError::GenericError {
+            msg: "The device does not allow resets, PragmaActiveReset is not supported."
+                .to_string()
+        }
+    );
+    device.set_allow_reset(true).unwrap();
+    let backend = SimulatorBackend::new(device, None);
+    let (bit_registers, _, _) = backend.run_circuit(&circuit).unwrap();
+    assert_eq!(bit_registers["ro"], vec![vec![false]]);
+}
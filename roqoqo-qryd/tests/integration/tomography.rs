@@ -0,0 +1,170 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_complex::Complex64;
+use roqoqo::Circuit;
+use roqoqo_qryd::tomography::{
+    ReconstructionMethod, StateTomography, TomographyBasis, TOMOGRAPHY_READOUT,
+};
+
+// Returns a register where half of the shots measured 0 and half measured 1
+fn balanced_register(shots: usize) -> Vec<Vec<bool>> {
+    (0..shots).map(|shot| vec![shot % 2 == 1]).collect()
+}
+
+// Test the creation of a StateTomography experiment
+#[test]
+fn test_new() {
+    let tomography = StateTomography::new(vec![2, 0], 100).unwrap();
+    assert_eq!(tomography.qubits(), &[2, 0]);
+    assert_eq!(tomography.number_measurements(), 100);
+
+    assert!(StateTomography::new(vec![], 100).is_err());
+    assert!(StateTomography::new(vec![1, 1], 100).is_err());
+    assert!(StateTomography::new(vec![1], 0).is_err());
+}
+
+// Test the generated measurement settings and circuits
+#[test]
+fn test_measurement_circuits() {
+    let tomography = StateTomography::new(vec![3, 1], 10).unwrap();
+    let settings = tomography.measurement_settings();
+    assert_eq!(settings.len(), 9);
+    assert_eq!(settings[0], vec![TomographyBasis::X, TomographyBasis::X]);
+    assert_eq!(settings[1], vec![TomographyBasis::Y, TomographyBasis::X]);
+    assert_eq!(settings[8], vec![TomographyBasis::Z, TomographyBasis::Z]);
+
+    let circuits = tomography.measurement_circuits(&Circuit::new());
+    assert_eq!(circuits.len(), 9);
+    // Z-Z setting only contains the measurements
+    assert_eq!(circuits[8].count_occurences(&["RotateX", "RotateXY"]), 0);
+    assert_eq!(circuits[8].count_occurences(&["MeasureQubit"]), 2);
+    assert_eq!(circuits[0].count_occurences(&["RotateXY"]), 2);
+    assert_eq!(circuits[1].count_occurences(&["RotateX"]), 1);
+    assert_eq!(
+        circuits[4].count_occurences(&["PragmaSetNumberOfMeasurements"]),
+        1
+    );
+    assert_eq!(circuits[4].definitions().len(), 1);
+    assert!(format!("{:?}", circuits[4]).contains(TOMOGRAPHY_READOUT));
+}
+
+// Test the reconstruction of the |0> state from ideal data
+#[test]
+fn test_reconstruct_single_qubit() {
+    let tomography = StateTomography::new(vec![0], 100).unwrap();
+    // Settings are ordered X, Y, Z
+    let registers = vec![
+        balanced_register(100),
+        balanced_register(100),
+        vec![vec![false]; 100],
+    ];
+    for method in [
+        ReconstructionMethod::LinearInversion,
+        ReconstructionMethod::MaximumLikelihood,
+    ] {
+        let density_matrix = tomography.reconstruct(&registers, method).unwrap();
+        assert_eq!(density_matrix.shape(), &[2, 2]);
+        assert!((density_matrix[[0, 0]] - Complex64::new(1.0, 0.0)).norm() < 1e-6);
+        assert!(density_matrix[[1, 1]].norm() < 1e-6);
+        assert!(density_matrix[[0, 1]].norm() < 1e-6);
+    }
+
+    // |+> state
+    let registers = vec![
+        vec![vec![false]; 100],
+        balanced_register(100),
+        balanced_register(100),
+    ];
+    let density_matrix = tomography
+        .reconstruct(&registers, ReconstructionMethod::LinearInversion)
+        .unwrap();
+    for element in density_matrix.iter() {
+        assert!((element - Complex64::new(0.5, 0.0)).norm() < 1e-12);
+    }
+}
+
+// Test the reconstruction of a two-qubit product state from ideal data
+#[test]
+fn test_reconstruct_two_qubits() {
+    let tomography = StateTomography::new(vec![0, 1], 100).unwrap();
+    // State |10>: qubit 0 in |0>, qubit 1 in |1>
+    let registers: Vec<Vec<Vec<bool>>> = tomography
+        .measurement_settings()
+        .iter()
+        .map(|setting| {
+            (0..100)
+                .map(|shot| {
+                    vec![
+                        match setting[0] {
+                            TomographyBasis::Z => false,
+                            _ => shot % 2 == 1,
+                        },
+                        match setting[1] {
+                            TomographyBasis::Z => true,
+                            _ => (shot / 2) % 2 == 1,
+                        },
+                    ]
+                })
+                .collect()
+        })
+        .collect();
+    let density_matrix = tomography
+        .reconstruct(&registers, ReconstructionMethod::LinearInversion)
+        .unwrap();
+    assert_eq!(density_matrix.shape(), &[4, 4]);
+    // Basis index 2 corresponds to qubit 1 in |1> and qubit 0 in |0>
+    assert!((density_matrix[[2, 2]] - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+    let trace: Complex64 = density_matrix.diag().sum();
+    assert!((trace - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+
+    assert!(tomography
+        .reconstruct(&registers[0..3], ReconstructionMethod::LinearInversion)
+        .is_err());
+    let mut short_registers = registers.clone();
+    short_registers[0] = vec![vec![true]];
+    assert!(tomography
+        .reconstruct(&short_registers, ReconstructionMethod::MaximumLikelihood)
+        .is_err());
+}
+
+// Test running the tomography on the simulator
+#[cfg(feature = "simulator")]
+#[test]
+fn test_run_simulator() {
+    use roqoqo::operations::RotateX;
+    use roqoqo_qryd::{SimulatorBackend, TweezerDevice};
+
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for gate in ["RotateX", "RotateXY"] {
+        device
+            .set_tweezer_single_qubit_gate_time(gate, 0, 1.0, Some("default".to_string()))
+            .unwrap();
+    }
+    device.switch_layout("default", None).unwrap();
+    let backend = SimulatorBackend::new(device, Some(1));
+
+    let mut preparation = Circuit::new();
+    preparation += RotateX::new(0, std::f64::consts::PI.into());
+
+    let tomography = StateTomography::new(vec![0], 200).unwrap();
+    let density_matrix = tomography
+        .run(
+            &backend,
+            &preparation,
+            ReconstructionMethod::MaximumLikelihood,
+        )
+        .unwrap();
+    assert!((density_matrix[[1, 1]] - Complex64::new(1.0, 0.0)).norm() < 0.05);
+    assert!(density_matrix[[0, 1]].norm() < 0.2);
+}
@@ -11,14 +11,20 @@
 // limitations under the License.
 
 use bincode::serialize;
-use ndarray::Array2;
+use itertools::iproduct;
+use ndarray::{array, Array2};
 use std::collections::HashMap;
 use std::env;
 
-use roqoqo::{devices::Device, RoqoqoBackendError};
+use roqoqo::{
+    devices::{Device, GenericDevice},
+    operations, RoqoqoBackendError,
+};
 use roqoqo_qryd::{
-    phi_theta_relation, PragmaChangeQRydLayout, PragmaShiftQRydQubit, PragmaShiftQubitsTweezers,
-    PragmaSwitchDeviceLayout, TweezerDevice,
+    phi_theta_relation, DrawFormat, DrawOptions, FirstDevice, NativeGates, PhiThetaRelation,
+    PragmaChangeQRydLayout, PragmaDeactivateQRydQubit, PragmaGlobalRotateXY, PragmaMoveToZone,
+    PragmaSetPhaseRelation, PragmaShiftQRydQubit, PragmaShiftQubitsTweezers, PragmaSwapTweezers,
+    PragmaSwitchDeviceLayout, TrivialMappingPolicy, TweezerDevice, TweezerLayoutInfo,
 };
 
 #[cfg(feature = "web-api")]
@@ -33,8 +39,8 @@ fn test_new() {
 
     assert!(device.current_layout.is_none());
     assert!(device.qubit_to_tweezer.is_none());
-    assert!(device.layout_register.is_some());
-    assert_eq!(device.layout_register.as_ref().unwrap().len(), 0);
+    assert!(device.layouts().unwrap().is_some());
+    assert_eq!(device.layouts().unwrap().unwrap().len(), 0);
     assert_eq!(device.seed(), Some(2));
     assert_eq!(device.qrydbackend(), "qryd_tweezer_device");
 
@@ -60,17 +66,9 @@ fn test_layouts() {
 
     assert!(device.add_layout("Test").is_err());
 
-    assert_eq!(device.layout_register.as_ref().unwrap().len(), 2);
-    assert!(device
-        .layout_register
-        .as_ref()
-        .unwrap()
-        .contains_key("default"));
-    assert!(device
-        .layout_register
-        .as_ref()
-        .unwrap()
-        .contains_key("Test"));
+    assert_eq!(device.layouts().unwrap().unwrap().len(), 2);
+    assert!(device.layout_info("default").unwrap().is_some());
+    assert!(device.layout_info("Test").unwrap().is_some());
 
     device
         .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.23, None)
@@ -106,31 +104,20 @@ fn test_layouts() {
             Some("Test".to_string()),
         )
         .unwrap();
-    // TODO: Add this back in when the backend supports multi-qubit gates
-    // device
-    //     .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2, 3], 0.13, None)
-    //     .unwrap();
-    // device
-    //     .set_tweezer_multi_qubit_gate_time(
-    //         "MultiQubitZZ",
-    //         &[0, 1, 2, 3],
-    //         0.13,
-    //         Some("Test".to_string()),
-    //     )
-    //     .unwrap();
-
-    let default_layout = device
-        .layout_register
-        .as_ref()
-        .unwrap()
-        .get("default")
+    device
+        .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2, 3], 0.13, None)
         .unwrap();
-    let test_layout = device
-        .layout_register
-        .as_ref()
-        .unwrap()
-        .get("Test")
+    device
+        .set_tweezer_multi_qubit_gate_time(
+            "MultiQubitZZ",
+            &[0, 1, 2, 3],
+            0.13,
+            Some("Test".to_string()),
+        )
         .unwrap();
+
+    let default_layout = device.layout_info("default").unwrap().unwrap();
+    let test_layout = device.layout_info("Test").unwrap().unwrap();
     assert!(default_layout
         .tweezer_single_qubit_gate_times
         .contains_key("RotateX"));
@@ -207,25 +194,24 @@ fn test_layouts() {
         0.34
     );
 
-    // TODO: Add this back in when the backend supports multi-qubit gates
-    // assert_eq!(
-    //     *default_layout
-    //         .tweezer_multi_qubit_gate_times
-    //         .get("MultiQubitZZ")
-    //         .unwrap()
-    //         .get(&[0, 1, 2, 3].to_vec())
-    //         .unwrap(),
-    //     0.13
-    // );
-    // assert_eq!(
-    //     *test_layout
-    //         .tweezer_multi_qubit_gate_times
-    //         .get("MultiQubitZZ")
-    //         .unwrap()
-    //         .get(&[0, 1, 2, 3].to_vec())
-    //         .unwrap(),
-    //     0.13
-    // );
+    assert_eq!(
+        *default_layout
+            .tweezer_multi_qubit_gate_times
+            .get("MultiQubitZZ")
+            .unwrap()
+            .get(&[0, 1, 2, 3].to_vec())
+            .unwrap(),
+        0.13
+    );
+    assert_eq!(
+        *test_layout
+            .tweezer_multi_qubit_gate_times
+            .get("MultiQubitZZ")
+            .unwrap()
+            .get(&[0, 1, 2, 3].to_vec())
+            .unwrap(),
+        0.13
+    );
 
     assert_eq!(device.current_layout, Some("default".to_string()));
     assert!(device.qubit_to_tweezer.is_none());
@@ -233,7 +219,7 @@ fn test_layouts() {
     device.switch_layout("Test", None).unwrap();
     assert_eq!(device.current_layout, Some("Test".to_string()));
     assert!(device.qubit_to_tweezer.is_some());
-    assert_eq!(device.qubit_to_tweezer.clone().unwrap().len(), 3);
+    assert_eq!(device.qubit_to_tweezer.clone().unwrap().len(), 4);
 
     assert!(device.switch_layout("Error", None).is_err());
 
@@ -272,6 +258,421 @@ fn test_layouts() {
     );
 }
 
+/// Test TweezerDevice remove_layout() and rename_layout()
+#[test]
+fn test_remove_rename_layout() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.add_layout("old").unwrap();
+    device.add_layout("obsolete").unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.1, Some("default".to_string()))
+        .unwrap();
+    device.set_default_layout("default").unwrap();
+
+    assert!(device.remove_layout("default").is_err());
+    device.remove_layout("obsolete").unwrap();
+    assert!(!device.available_layouts().contains(&"obsolete"));
+    assert!(device.remove_layout("obsolete").is_err());
+
+    assert!(device.rename_layout("default", "old").is_err());
+    assert!(device.rename_layout("missing", "new").is_err());
+    device.rename_layout("default", "calibrated").unwrap();
+    assert_eq!(device.current_layout, Some("calibrated".to_string()));
+    assert_eq!(device.default_layout, Some("calibrated".to_string()));
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.1));
+    assert!(!device.available_layouts().contains(&"default"));
+
+    device.switch_layout("old", None).unwrap();
+    assert!(device.remove_layout("old").is_err());
+    device.rename_layout("old", "new").unwrap();
+    assert_eq!(device.current_layout, Some("new".to_string()));
+    assert!(device.remove_layout("calibrated").is_err());
+}
+
+/// Test TweezerDevice switch_layout_with_mapping()
+#[test]
+fn test_switch_layout_with_mapping() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("dense").unwrap();
+    device.add_layout("sparse").unwrap();
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.1, Some("dense".to_string()))
+            .unwrap();
+    }
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 1, 0.2, Some("sparse".to_string()))
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 3, 0.2, Some("sparse".to_string()))
+        .unwrap();
+    device.switch_layout("dense", None).unwrap();
+    assert_eq!(device.number_qubits(), 4);
+
+    assert_eq!(
+        device.switch_layout_with_mapping("sparse", HashMap::from([(0, 1), (1, 2)])),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Qubit 1 is mapped to tweezer 2 which is not present in Layout sparse."
+                .to_string()
+        })
+    );
+    assert_eq!(
+        device.switch_layout_with_mapping("sparse", HashMap::from([(0, 3), (1, 3)])),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Several qubits are mapped to tweezer 3.".to_string()
+        })
+    );
+    assert!(device
+        .switch_layout_with_mapping("unknown", HashMap::new())
+        .is_err());
+    assert_eq!(device.current_layout, Some("dense".to_string()));
+    assert_eq!(device.number_qubits(), 4);
+
+    device
+        .switch_layout_with_mapping("sparse", HashMap::from([(0, 3), (1, 1)]))
+        .unwrap();
+    assert_eq!(device.current_layout, Some("sparse".to_string()));
+    assert_eq!(
+        device.qubit_to_tweezer,
+        Some(HashMap::from([(0, 3), (1, 1)]))
+    );
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.2));
+    assert!(device.validate().is_ok());
+}
+
+/// Test TweezerDevice switch_layout_with_policy() and set_default_layout_with_policy()
+#[test]
+fn test_trivial_mapping_policies() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("sparse").unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 2, 0.1, Some("sparse".to_string()))
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 5, 0.1, Some("sparse".to_string()))
+        .unwrap();
+
+    device.switch_layout("sparse", None).unwrap();
+    assert_eq!(device.number_qubits(), 6);
+
+    device.qubit_to_tweezer = None;
+    device
+        .switch_layout_with_policy("sparse", TrivialMappingPolicy::NoMapping)
+        .unwrap();
+    assert!(device.qubit_to_tweezer.is_none());
+    device
+        .switch_layout_with_policy("sparse", TrivialMappingPolicy::UsableTweezers)
+        .unwrap();
+    assert_eq!(
+        device.qubit_to_tweezer,
+        Some(HashMap::from([(0, 2), (1, 5)]))
+    );
+
+    // Qubits on tweezers without gates lose their tweezers
+    device.qubit_to_tweezer = Some(HashMap::from([(3, 5), (0, 1)]));
+    device
+        .switch_layout_with_policy("sparse", TrivialMappingPolicy::OccupiedFirst)
+        .unwrap();
+    assert_eq!(
+        device.qubit_to_tweezer,
+        Some(HashMap::from([(0, 2), (3, 5)]))
+    );
+
+    device.qubit_to_tweezer = None;
+    device
+        .set_default_layout_with_policy("sparse", TrivialMappingPolicy::UsableTweezers)
+        .unwrap();
+    assert_eq!(device.default_layout, Some("sparse".to_string()));
+    assert_eq!(device.number_qubits(), 2);
+    assert!(device
+        .switch_layout_with_policy("unknown", TrivialMappingPolicy::UsableTweezers)
+        .is_err());
+
+    assert_eq!(
+        TrivialMappingPolicy::from_name("occupied-first"),
+        Some(TrivialMappingPolicy::OccupiedFirst)
+    );
+    assert_eq!(
+        TrivialMappingPolicy::from_name("none"),
+        Some(TrivialMappingPolicy::NoMapping)
+    );
+    assert_eq!(TrivialMappingPolicy::from_name("unknown"), None);
+}
+
+/// Test TweezerDevice duplicate_layout()
+#[test]
+fn test_duplicate_layout() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("triangle").unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.1, Some("triangle".to_string()))
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_time(
+            "PhaseShiftedControlledZ",
+            0,
+            1,
+            0.2,
+            Some("triangle".to_string()),
+        )
+        .unwrap();
+
+    assert!(device.duplicate_layout("missing", "copy").is_err());
+    assert!(device.duplicate_layout("triangle", "triangle").is_err());
+    device.duplicate_layout("triangle", "calibrated").unwrap();
+    assert!(device.available_layouts().contains(&"calibrated"));
+    assert!(device.duplicate_layout("triangle", "calibrated").is_err());
+
+    // Changing the copy does not change the source Layout
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.3, Some("calibrated".to_string()))
+        .unwrap();
+    device.switch_layout("triangle", None).unwrap();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.1));
+    device.switch_layout("calibrated", None).unwrap();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.3));
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1),
+        Some(0.2)
+    );
+}
+
+/// Test TweezerDevice unset_tweezer_*_qubit_gate_time() and clear_layout()
+#[test]
+fn test_unset_gate_times() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.1, None)
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 1, 0.1, None)
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_error("RotateX", 1, 0.01, None)
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, 0.5, None)
+        .unwrap();
+    device
+        .set_tweezer_three_qubit_gate_time("ControlledControlledPauliZ", 0, 1, 2, 0.8, None)
+        .unwrap();
+    device.switch_layout("default", None).unwrap();
+
+    assert_eq!(
+        device
+            .unset_tweezer_single_qubit_gate_time("RotateX", 1, None)
+            .unwrap(),
+        Some(0.1)
+    );
+    assert_eq!(
+        device
+            .unset_tweezer_single_qubit_gate_time("RotateX", 1, None)
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        device
+            .unset_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 1, 0, None)
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        device
+            .unset_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, None)
+            .unwrap(),
+        Some(0.5)
+    );
+    assert_eq!(
+        device
+            .unset_tweezer_three_qubit_gate_time("ControlledControlledPauliZ", 0, 1, 2, None)
+            .unwrap(),
+        Some(0.8)
+    );
+    assert_eq!(
+        device
+            .unset_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0, 1], None)
+            .unwrap(),
+        None
+    );
+    assert!(device
+        .unset_tweezer_single_qubit_gate_time("RotateX", 0, Some("missing".to_string()))
+        .is_err());
+
+    let layout = device.layout_info("default").unwrap().unwrap();
+    assert_eq!(
+        layout.tweezer_single_qubit_gate_times["RotateX"],
+        HashMap::from([(0, 0.1)])
+    );
+    assert!(layout.tweezer_single_qubit_gate_errors.is_empty());
+    assert!(layout.tweezer_two_qubit_gate_times.is_empty());
+    assert!(layout.tweezer_three_qubit_gate_times.is_empty());
+
+    // The qubit -> tweezer mapping is reset as by the setters
+    assert!(device.qubit_to_tweezer.is_none());
+    device.switch_layout("default", None).unwrap();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.1));
+    assert_eq!(device.single_qubit_gate_time("RotateX", &1), None);
+
+    device.clear_layout("default").unwrap();
+    assert_eq!(
+        device.layout_info("default").unwrap().unwrap(),
+        &TweezerLayoutInfo::default()
+    );
+    assert!(device.clear_layout("missing").is_err());
+}
+
+/// Test TweezerDevice bulk gate time setters
+#[test]
+fn test_bulk_gate_time_setters() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+
+    let single: Vec<(usize, f64)> = (0..200).map(|tweezer| (tweezer, 0.1)).collect();
+    device
+        .set_tweezer_single_qubit_gate_times("RotateX", &single, None)
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_times(
+            "PhaseShiftedControlledZ",
+            &[(0, 1, 0.5), (1, 0, 0.6)],
+            None,
+        )
+        .unwrap();
+    device
+        .set_tweezer_three_qubit_gate_times(
+            "ControlledControlledPauliZ",
+            &[(0, 1, 2, 0.8)],
+            Some("default".to_string()),
+        )
+        .unwrap();
+
+    let mut reference = TweezerDevice::new(None, None, None);
+    reference.add_layout("default").unwrap();
+    reference.current_layout = Some("default".to_string());
+    for tweezer in 0..200 {
+        reference
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.1, None)
+            .unwrap();
+    }
+    reference
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, 0.5, None)
+        .unwrap();
+    reference
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 1, 0, 0.6, None)
+        .unwrap();
+    reference
+        .set_tweezer_three_qubit_gate_time("ControlledControlledPauliZ", 0, 1, 2, 0.8, None)
+        .unwrap();
+    assert_eq!(device.layouts().unwrap(), reference.layouts().unwrap());
+
+    assert!(device
+        .set_tweezer_single_qubit_gate_times("CNOT", &[(0, 0.1)], None)
+        .is_err());
+    assert!(device
+        .set_tweezer_two_qubit_gate_times("RotateX", &[(0, 1, 0.1)], None)
+        .is_err());
+    assert!(device
+        .set_tweezer_three_qubit_gate_times("CNOT", &[(0, 1, 2, 0.1)], None)
+        .is_err());
+    assert!(device
+        .set_tweezer_single_qubit_gate_times("RotateX", &[(0, 0.1)], Some("missing".to_string()))
+        .is_err());
+}
+
+/// Test TweezerDevice occupied_tweezers(), free_tweezers() and is_tweezer_occupied()
+#[test]
+fn test_occupied_free_tweezers() {
+    let mut device = TweezerDevice::new(None, None, None);
+    assert!(device.occupied_tweezers().is_empty());
+    assert!(device.free_tweezers(None).is_err());
+
+    device.add_layout("default").unwrap();
+    device.add_layout("other").unwrap();
+    device.current_layout = Some("default".to_string());
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.1, None)
+            .unwrap();
+    }
+    device
+        .set_tweezer_two_qubit_gate_time(
+            "PhaseShiftedControlledZ",
+            3,
+            4,
+            0.5,
+            Some("other".to_string()),
+        )
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 3).unwrap();
+    device.add_qubit_tweezer_mapping(1, 1).unwrap();
+
+    assert_eq!(device.occupied_tweezers(), vec![1, 3]);
+    assert!(device.is_tweezer_occupied(3));
+    assert!(!device.is_tweezer_occupied(0));
+    assert_eq!(device.free_tweezers(None).unwrap(), vec![0, 2]);
+    assert_eq!(
+        device.free_tweezers(Some("other".to_string())).unwrap(),
+        vec![4]
+    );
+    assert!(device.free_tweezers(Some("missing".to_string())).is_err());
+}
+
+/// Test TweezerDevice max_qubits capacity
+#[test]
+fn test_max_qubits() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateX",
+                tweezer,
+                0.1,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device.set_max_qubits(Some(2)).unwrap();
+    assert_eq!(device.max_qubits, Some(2));
+
+    // The trivial mapping is limited to the capacity
+    device.switch_layout("default", None).unwrap();
+    assert_eq!(device.number_qubits(), 2);
+    assert_eq!(
+        device.add_qubit_tweezer_mapping(2, 3),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Qubit 2 exceeds the hardware capacity of the device of 2 qubits.".to_string()
+        })
+    );
+    device.add_qubit_tweezer_mapping(1, 3).unwrap();
+    assert!(device.set_max_qubits(Some(1)).is_err());
+    assert_eq!(device.max_qubits, Some(2));
+
+    let mut circuit = roqoqo::Circuit::new();
+    circuit += operations::RotateX::new(1, 1.0.into());
+    assert!(device.check_qubit_capacity(&circuit).is_ok());
+    circuit += operations::RotateX::new(2, 1.0.into());
+    assert_eq!(
+        device.check_qubit_capacity(&circuit),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Operation RotateX acts on qubit 2, the hardware capacity of the device is 2 qubits."
+                .to_string()
+        })
+    );
+    device.set_max_qubits(None).unwrap();
+    assert!(device.check_qubit_capacity(&circuit).is_ok());
+
+    // The capacity is serialized with the device
+    device.set_max_qubits(Some(3)).unwrap();
+    let deserialized: TweezerDevice =
+        serde_json::from_str(&serde_json::to_string(&device).unwrap()).unwrap();
+    assert_eq!(deserialized.max_qubits, Some(3));
+}
+
 // Test TweezerDevice add_qubit_tweezer_mapping(), get_tweezer_from_qubit() methods
 #[test]
 fn test_qubit_tweezer_mapping() {
@@ -333,10 +734,8 @@ fn test_allowed_tweezer_shifts_from_rows() {
         .is_ok());
 
     let saved_shifts = &device
-        .layout_register
-        .as_ref()
+        .layout_info("triangle")
         .unwrap()
-        .get("triangle")
         .unwrap()
         .allowed_tweezer_shifts;
     assert!(!saved_shifts.is_empty());
@@ -367,10 +766,8 @@ fn test_allowed_tweezer_shifts_from_rows() {
     assert!(adding_to_already_present_key.is_ok());
 
     let saved_shifts = &device
-        .layout_register
-        .as_ref()
+        .layout_info("triangle")
         .unwrap()
-        .get("triangle")
         .unwrap()
         .allowed_tweezer_shifts;
     assert!(saved_shifts.get(&0).unwrap().contains(&vec![1, 2]));
@@ -416,10 +813,8 @@ fn test_allowed_tweezer_shifts() {
         .is_ok());
 
     let saved_shifts = &device
-        .layout_register
-        .as_ref()
+        .layout_info("OtherLayout")
         .unwrap()
-        .get("OtherLayout")
         .unwrap()
         .allowed_tweezer_shifts;
     assert!(!saved_shifts.is_empty());
@@ -464,10 +859,8 @@ fn test_allowed_tweezer_shifts() {
         .is_ok());
 
     let saved_shifts = &device
-        .layout_register
-        .as_ref()
+        .layout_info("OtherLayout")
         .unwrap()
-        .get("OtherLayout")
         .unwrap()
         .allowed_tweezer_shifts;
     assert!(saved_shifts.contains_key(&0));
@@ -475,47 +868,466 @@ fn test_allowed_tweezer_shifts() {
     assert!(saved_shifts.get(&0).unwrap().contains(&vec![4]));
 }
 
-/// Test TweezerDevice deactivate_qubit()
+/// Test TweezerDevice shift transport times
 #[test]
-fn test_deactivate_qubit() {
+fn test_shift_times() {
     let mut device = TweezerDevice::new(None, None, None);
     device.add_layout("default").unwrap();
-    device.current_layout = Some("default".to_string());
-
-    assert!(device.deactivate_qubit(0).is_err());
-
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateX",
+                tweezer,
+                0.1,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device.switch_layout("default", None).unwrap();
     device
-        .set_tweezer_single_qubit_gate_time("RotateX", 1, 0.1, None)
+        .set_allowed_tweezer_shifts(&0, &[&[1, 2, 3]], None)
         .unwrap();
-    device.add_qubit_tweezer_mapping(0, 1).unwrap();
 
-    assert!(device.deactivate_qubit(0).is_ok());
-    assert!(device.deactivate_qubit(0).is_err());
+    // Shifts are instantaneous until a transport time is set
+    assert_eq!(device.shift_time(0, 2), None);
+    device.set_shift_time_per_site(0.5, None).unwrap();
+    assert_eq!(device.shift_time(0, 1), Some(0.5));
+    assert_eq!(device.shift_time(0, 3), Some(1.5));
+    assert_eq!(device.shift_time(1, 0), None);
+
+    device.set_tweezer_shift_time(0, 3, 1.0, None).unwrap();
+    assert_eq!(device.shift_time(0, 3), Some(1.0));
+    assert_eq!(device.shift_time(0, 2), Some(1.0));
+
+    assert!(device.set_tweezer_shift_time(1, 0, 1.0, None).is_err());
+    assert!(device.set_tweezer_shift_time(0, 1, -1.0, None).is_err());
+    assert!(device.set_shift_time_per_site(f64::NAN, None).is_err());
+    assert!(device
+        .set_shift_time_per_site(1.0, Some("error".to_string()))
+        .is_err());
+
+    // The transport times are serialized with the layout
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
 }
 
-/// Test TweezerDevice ..._qubit_gate_time() methods
+/// Test the durations and the parallel execution of PragmaShiftQubitsTweezers
 #[test]
-fn test_qubit_times() {
+fn test_parallel_shifts() {
     let mut device = TweezerDevice::new(None, None, None);
     device.add_layout("default").unwrap();
-    device.current_layout = Some("default".to_string());
-
-    assert!(device.single_qubit_gate_time("RotateX", &0).is_none());
-
-    // Testing missing qubits
-    assert!(device.single_qubit_gate_time("RotateX", &5).is_none());
-    assert!(device
-        .two_qubit_gate_time("PhaseShiftedControlledPhase", &0, &7)
-        .is_none());
-    assert!(device
-        .three_qubit_gate_time("ControlledControlledPhaseShift", &12, &1, &3)
-        .is_none());
-    assert!(device
-        .multi_qubit_gate_time("MultiQubitZZ", &[6, 2, 3, 4])
-        .is_none());
-
+    for tweezer in 0..6 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateX",
+                tweezer,
+                0.1,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device.switch_layout("default", Some(false)).unwrap();
     device
-        .set_tweezer_single_qubit_gate_time("RotateX", 1, 0.23, None)
+        .set_allowed_tweezer_shifts(&0, &[&[1, 2]], None)
+        .unwrap();
+    device
+        .set_allowed_tweezer_shifts(&4, &[&[1]], None)
+        .unwrap();
+    device
+        .set_allowed_tweezer_shifts(&3, &[&[5]], None)
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 4).unwrap();
+    device.add_qubit_tweezer_mapping(2, 3).unwrap();
+
+    let parallel = PragmaShiftQubitsTweezers::new_parallel(vec![(0, 2), (3, 5)]);
+    let sequential = PragmaShiftQubitsTweezers::new(vec![(0, 2), (3, 5)]);
+    assert!(device.validate_pragma(&parallel).is_ok());
+    assert_eq!(device.shift_duration(&parallel), None);
+    device.set_shift_time_per_site(0.5, None).unwrap();
+    assert_eq!(device.shift_duration(&parallel), Some(1.0));
+    assert_eq!(device.shift_duration(&sequential), Some(1.5));
+    let parallel_with_durations =
+        PragmaShiftQubitsTweezers::with_durations(vec![(0, 2), (3, 5)], vec![0.25, 0.5], true);
+    assert_eq!(device.shift_duration(&parallel_with_durations), Some(0.5));
+    let sequential_with_durations =
+        PragmaShiftQubitsTweezers::with_durations(vec![(0, 2), (3, 5)], vec![0.25, 0.5], false);
+    assert_eq!(
+        device.shift_duration(&sequential_with_durations),
+        Some(0.75)
+    );
+    assert_eq!(
+        device.shift_duration(&PragmaShiftQubitsTweezers::new_parallel(vec![])),
+        Some(0.0)
+    );
+
+    // The paths of parallel shifts may not cross
+    let crossing = PragmaShiftQubitsTweezers::new_parallel(vec![(0, 2), (4, 1)]);
+    assert_eq!(
+        device.validate_pragma(&crossing),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Shift 1 from tweezer 4 to tweezer 1 is not valid: its path crosses the path of the parallel shift 0."
+                .to_string(),
+        })
+    );
+    let not_crossing = PragmaShiftQubitsTweezers::new(vec![(0, 2), (4, 1)]);
+    assert!(device.validate_pragma(&not_crossing).is_ok());
+
+    assert_eq!(
+        device.validate_pragma(&PragmaShiftQubitsTweezers::with_durations(
+            vec![(0, 2)],
+            vec![0.1, 0.2],
+            true
+        )),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "The operation has 1 shifts but 2 durations.".to_string(),
+        })
+    );
+    assert_eq!(
+        device.validate_pragma(&PragmaShiftQubitsTweezers::with_durations(
+            vec![(0, 2)],
+            vec![-1.0],
+            true
+        )),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "The duration -1 of shift 0 is negative or not finite.".to_string(),
+        })
+    );
+    assert!(device
+        .change_device("PragmaShiftQubitsTweezers", &serialize(&crossing).unwrap())
+        .is_err());
+
+    device
+        .change_device(
+            "PragmaShiftQubitsTweezers",
+            &serialize(&parallel_with_durations).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(
+        device.qubit_to_tweezer,
+        Some(HashMap::from([(0, 2), (1, 4), (2, 5)]))
+    );
+}
+
+/// Test TweezerDevice swap_tweezers()
+#[test]
+fn test_swap_tweezers() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateX",
+                tweezer,
+                0.1,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device.switch_layout("default", Some(false)).unwrap();
+
+    assert_eq!(
+        device.swap_tweezers(0, 1),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "The device qubit -> tweezer mapping is empty: no qubits to swap.".to_string(),
+        })
+    );
+
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 3).unwrap();
+
+    assert_eq!(
+        device.swap_tweezers(0, 0),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Tweezer 0 can not be swapped with itself.".to_string(),
+        })
+    );
+    assert_eq!(
+        device.swap_tweezers(0, 7),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Tweezer 7 is not present in the current Layout.".to_string(),
+        })
+    );
+    assert_eq!(
+        device.swap_tweezers(2, 3),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Tweezer 2 does not hold a qubit.".to_string(),
+        })
+    );
+
+    let pragma = PragmaSwapTweezers::new(0, 3);
+    assert!(device
+        .validate_change_device("PragmaSwapTweezers", &serialize(&pragma).unwrap())
+        .is_ok());
+    assert_eq!(
+        device.qubit_to_tweezer,
+        Some(HashMap::from([(0, 0), (1, 3)]))
+    );
+    assert!(device
+        .validate_change_device(
+            "PragmaSwapTweezers",
+            &serialize(&PragmaSwapTweezers::new(1, 3)).unwrap()
+        )
+        .is_err());
+
+    device
+        .change_device("PragmaSwapTweezers", &serialize(&pragma).unwrap())
+        .unwrap();
+    assert_eq!(
+        device.qubit_to_tweezer,
+        Some(HashMap::from([(0, 3), (1, 0)]))
+    );
+    device.swap_tweezers(3, 0).unwrap();
+    assert_eq!(
+        device.qubit_to_tweezer,
+        Some(HashMap::from([(0, 0), (1, 3)]))
+    );
+}
+
+/// Test TweezerDevice resolve_zone_move() and the PragmaMoveToZone operation
+#[test]
+fn test_move_to_zone() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..6 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateX",
+                tweezer,
+                0.1,
+                Some("default".to_string()),
+            )
+            .unwrap();
+        let zone = if tweezer < 3 {
+            "storage"
+        } else {
+            "interaction"
+        };
+        device
+            .set_tweezer_zone(tweezer, zone, Some("default".to_string()))
+            .unwrap();
+    }
+    device.switch_layout("default", Some(false)).unwrap();
+    device
+        .set_allowed_tweezer_shifts(&0, &[&[3, 4, 5]], None)
+        .unwrap();
+    device
+        .set_allowed_tweezer_shifts(&1, &[&[4], &[5]], None)
+        .unwrap();
+    device
+        .set_allowed_tweezer_shifts(&2, &[&[3]], None)
+        .unwrap();
+
+    let pragma = PragmaMoveToZone::new(vec![0, 1], "interaction".to_string());
+    assert_eq!(
+        device.resolve_zone_move(&pragma),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "The device qubit -> tweezer mapping is empty: no qubits to move.".to_string(),
+        })
+    );
+    for qubit in 0..3 {
+        device.add_qubit_tweezer_mapping(qubit, qubit).unwrap();
+    }
+
+    // Without coordinates the nearest tweezer is the one passing the fewest tweezers
+    assert_eq!(
+        device.resolve_zone_move(&pragma).unwrap(),
+        PragmaShiftQubitsTweezers::new(vec![(0, 3), (1, 4)])
+    );
+    // The only tweezer of the zone qubit 2 can reach is taken by qubit 0
+    assert_eq!(
+        device.resolve_zone_move(&PragmaMoveToZone::new(
+            vec![0, 1, 2],
+            "interaction".to_string()
+        )),
+        Err(RoqoqoBackendError::GenericError {
+            msg:
+                "No free tweezer of zone interaction can be reached from tweezer 2 holding qubit 2."
+                    .to_string(),
+        })
+    );
+    assert_eq!(
+        device.resolve_zone_move(&PragmaMoveToZone::new(vec![0], "readout".to_string())),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Zone readout is not present in the current Layout.".to_string(),
+        })
+    );
+    assert_eq!(
+        device.resolve_zone_move(&PragmaMoveToZone::new(vec![7], "interaction".to_string())),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Qubit 7 is not present in the qubit -> tweezer mapping.".to_string(),
+        })
+    );
+    assert_eq!(
+        device.resolve_zone_move(&PragmaMoveToZone::new(
+            vec![0, 0],
+            "interaction".to_string()
+        )),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Qubit 0 is moved more than once.".to_string(),
+        })
+    );
+
+    // With coordinates the nearest tweezer is the closest one
+    device.set_tweezer_coordinates(1, 0.0, 0.0, None).unwrap();
+    device.set_tweezer_coordinates(4, 5.0, 0.0, None).unwrap();
+    device.set_tweezer_coordinates(5, 1.0, 0.0, None).unwrap();
+    assert_eq!(
+        device.resolve_zone_move(&pragma).unwrap(),
+        PragmaShiftQubitsTweezers::new(vec![(0, 3), (1, 5)])
+    );
+
+    assert!(device
+        .validate_change_device("PragmaMoveToZone", &serialize(&pragma).unwrap())
+        .is_ok());
+    device
+        .change_device("PragmaMoveToZone", &serialize(&pragma).unwrap())
+        .unwrap();
+    assert_eq!(
+        device.qubit_to_tweezer,
+        Some(HashMap::from([(0, 3), (1, 5), (2, 2)]))
+    );
+    // Qubits already in the zone are not moved
+    assert_eq!(
+        device.resolve_zone_move(&pragma).unwrap(),
+        PragmaShiftQubitsTweezers::new(vec![])
+    );
+    assert!(device
+        .validate_change_device(
+            "PragmaMoveToZone",
+            &serialize(&PragmaMoveToZone::new(vec![2], "interaction".to_string())).unwrap()
+        )
+        .is_err());
+}
+
+/// Test TweezerDevice set_global_rotation_time() and the PragmaGlobalRotateXY operation
+#[test]
+fn test_global_rotation() {
+    let mut device = TweezerDevice::new(None, None, None);
+    assert!(device.set_global_rotation_time(1.0, None).is_err());
+    device.add_layout("default").unwrap();
+    device.add_layout("other").unwrap();
+    device.switch_layout("default", None).unwrap();
+    assert_eq!(device.global_rotation_time(), None);
+
+    let pragma = PragmaGlobalRotateXY::new(1.0.into(), 0.0.into());
+    let error = Err(RoqoqoBackendError::GenericError {
+        msg: "The global rotation is not available in the current Layout: no global rotation time is set."
+            .to_string(),
+    });
+    assert_eq!(
+        device.validate_change_device("PragmaGlobalRotateXY", &serialize(&pragma).unwrap()),
+        error
+    );
+    assert_eq!(
+        device.change_device("PragmaGlobalRotateXY", &serialize(&pragma).unwrap()),
+        error
+    );
+
+    assert_eq!(
+        device.set_global_rotation_time(-1.0, None),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Error setting the global rotation time. The time -1 is negative or not finite."
+                .to_string(),
+        })
+    );
+    assert!(device
+        .set_global_rotation_time(1.0, Some("missing".to_string()))
+        .is_err());
+    device
+        .set_global_rotation_time(0.5, Some("other".to_string()))
+        .unwrap();
+    assert_eq!(device.global_rotation_time(), None);
+    device.set_global_rotation_time(0.25, None).unwrap();
+    assert_eq!(device.global_rotation_time(), Some(0.25));
+
+    let device_before = device.clone();
+    assert!(device
+        .validate_change_device("PragmaGlobalRotateXY", &serialize(&pragma).unwrap())
+        .is_ok());
+    device
+        .change_device("PragmaGlobalRotateXY", &serialize(&pragma).unwrap())
+        .unwrap();
+    assert_eq!(device, device_before);
+
+    device.switch_layout("other", None).unwrap();
+    assert_eq!(device.global_rotation_time(), Some(0.5));
+}
+
+/// Test TweezerDevice set_layout_switch_time() and layout_switch_time()
+#[test]
+fn test_layout_switch_time() {
+    let mut device = TweezerDevice::new(None, None, None);
+    assert!(device.set_layout_switch_time(1.0, None).is_err());
+    device.add_layout("default").unwrap();
+    device.add_layout("other").unwrap();
+    device.switch_layout("default", None).unwrap();
+    assert_eq!(device.layout_switch_time(), None);
+
+    assert_eq!(
+        device.set_layout_switch_time(f64::NAN, None),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Error setting the layout switch time. The time NaN is negative or not finite."
+                .to_string(),
+        })
+    );
+    assert!(device
+        .set_layout_switch_time(1.0, Some("missing".to_string()))
+        .is_err());
+    device
+        .set_layout_switch_time(2.0, Some("other".to_string()))
+        .unwrap();
+    assert_eq!(device.layout_switch_time(), None);
+    device.switch_layout("other", None).unwrap();
+    assert_eq!(device.layout_switch_time(), Some(2.0));
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized.layout_switch_time(), Some(2.0));
+}
+
+/// Test TweezerDevice deactivate_qubit()
+#[test]
+fn test_deactivate_qubit() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+
+    assert!(device.deactivate_qubit(0).is_err());
+
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 1, 0.1, None)
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 1).unwrap();
+
+    assert!(device.deactivate_qubit(0).is_ok());
+    assert!(device.deactivate_qubit(0).is_err());
+}
+
+/// Test TweezerDevice ..._qubit_gate_time() methods
+#[test]
+fn test_qubit_times() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+
+    assert!(device.single_qubit_gate_time("RotateX", &0).is_none());
+
+    // Testing missing qubits
+    assert!(device.single_qubit_gate_time("RotateX", &5).is_none());
+    assert!(device
+        .two_qubit_gate_time("PhaseShiftedControlledPhase", &0, &7)
+        .is_none());
+    assert!(device
+        .three_qubit_gate_time("ControlledControlledPhaseShift", &12, &1, &3)
+        .is_none());
+    assert!(device
+        .multi_qubit_gate_time("MultiQubitZZ", &[6, 2, 3, 4])
+        .is_none());
+
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 1, 0.23, None)
         .unwrap();
     device
         .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledPhase", 0, 1, 0.45, None)
@@ -523,15 +1335,13 @@ fn test_qubit_times() {
     device
         .set_tweezer_three_qubit_gate_time("ControlledControlledPhaseShift", 0, 1, 2, 0.65, None)
         .unwrap();
-    // TODO: Add this back in when the backend supports multi-qubit gates
-    // device
-    //     .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2, 3], 0.34, None)
-    //     .unwrap();
+    device
+        .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2, 3], 0.34, None)
+        .unwrap();
 
     device.add_qubit_tweezer_mapping(0, 1).unwrap();
     device.add_qubit_tweezer_mapping(1, 2).unwrap();
-    // TODO: Add this back in when the backend supports multi-qubit gates
-    // device.add_qubit_tweezer_mapping(2, 3).unwrap();
+    device.add_qubit_tweezer_mapping(2, 3).unwrap();
     device.add_qubit_tweezer_mapping(3, 0).unwrap();
 
     assert!(device.single_qubit_gate_time("RotateX", &0).is_some());
@@ -543,18 +1353,757 @@ fn test_qubit_times() {
         0.45
     );
     assert_eq!(
-        device
-            .three_qubit_gate_time("ControlledControlledPhaseShift", &3, &0, &1)
-            .unwrap(),
-        0.65
+        device
+            .three_qubit_gate_time("ControlledControlledPhaseShift", &3, &0, &1)
+            .unwrap(),
+        0.65
+    );
+    assert_eq!(
+        device
+            .multi_qubit_gate_time("MultiQubitZZ", &[3, 0, 1, 2])
+            .unwrap(),
+        0.34
+    );
+}
+
+/// Test TweezerDevice gate time cache
+#[test]
+fn test_gate_time_cache() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.1, None)
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 1, 0.2, None)
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 2, 0.3, None)
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledPhase", 0, 1, 0.45, None)
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 1).unwrap();
+    device.add_qubit_tweezer_mapping(2, 2).unwrap();
+
+    assert_eq!(device.gate_time_cache_capacity(), 0);
+    device.set_gate_time_cache_capacity(2);
+    assert_eq!(device.gate_time_cache_capacity(), 2);
+    let uncached = device.clone();
+
+    // More distinct lookups than the capacity
+    for _ in 0..3 {
+        assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.1));
+        assert_eq!(device.single_qubit_gate_time("RotateX", &1), Some(0.2));
+        assert_eq!(device.single_qubit_gate_time("RotateX", &2), Some(0.3));
+        assert_eq!(
+            device.two_qubit_gate_time("PhaseShiftedControlledPhase", &0, &1),
+            Some(0.45)
+        );
+        assert!(device
+            .two_qubit_gate_time("PhaseShiftedControlledPhase", &1, &0)
+            .is_none());
+    }
+    assert_eq!(device, uncached);
+
+    // Changes through the device methods empty the cache
+    device.add_qubit_tweezer_mapping(0, 2).unwrap();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.3));
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 2, 0.35, None)
+        .unwrap();
+    // Setting a gate time resets the mapping
+    device.add_qubit_tweezer_mapping(0, 2).unwrap();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.35));
+
+    // Direct changes of the public fields are returned by the following lookups
+    device.qubit_to_tweezer = Some(HashMap::from([(0, 1)]));
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.2));
+    device.add_layout("other").unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 1, 0.5, Some("other".to_string()))
+        .unwrap();
+    device.qubit_to_tweezer = Some(HashMap::from([(0, 1)]));
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.2));
+    device.current_layout = Some("other".to_string());
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.5));
+    device.current_layout = Some("default".to_string());
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.2));
+    device.clear_gate_time_cache();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.2));
+
+    device.set_gate_time_cache_capacity(0);
+    device.qubit_to_tweezer = Some(HashMap::from([(0, 0)]));
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.1));
+}
+
+/// Test TweezerDevice apply_update() method
+#[test]
+fn test_apply_update() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateZ", 0, 0.3, Some("default".to_string()))
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 1, 0.2, Some("default".to_string()))
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.1, Some("default".to_string()))
+        .unwrap();
+    device.set_default_layout("default").unwrap();
+    let original = device.clone();
+
+    device.apply_update("[]").unwrap();
+    assert_eq!(device, original);
+
+    // The gate times are sorted by gate name and tweezer in the serialization
+    device
+        .apply_update(
+            r#"[
+                {"op": "test", "path": "/layout_register/default/tweezer_single_qubit_gate_times/0/0", "value": "RotateX"},
+                {"op": "replace", "path": "/layout_register/default/tweezer_single_qubit_gate_times/0/1/1/1", "value": 0.25},
+                {"op": "add", "path": "/layout_register/default/tweezer_single_qubit_gate_times/1/1/-", "value": [1, 0.35]},
+                {"op": "copy", "from": "/device_name", "path": "/default_layout"},
+                {"op": "replace", "path": "/default_layout", "value": "default"}
+            ]"#,
+        )
+        .unwrap();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.1));
+    assert_eq!(device.single_qubit_gate_time("RotateX", &1), Some(0.25));
+    assert_eq!(device.single_qubit_gate_time("RotateZ", &1), Some(0.35));
+    assert_eq!(device.default_layout, Some("default".to_string()));
+    let updated = device.clone();
+
+    // Failing patches leave the device unchanged
+    assert!(device.apply_update("not a patch").is_err());
+    assert!(device
+        .apply_update(r#"{"op": "remove", "path": "/seed"}"#)
+        .is_err());
+    assert!(device
+        .apply_update(
+            r#"[
+                {"op": "remove", "path": "/layout_register/default/tweezer_single_qubit_gate_times/1"},
+                {"op": "test", "path": "/allow_reset", "value": true}
+            ]"#
+        )
+        .is_err());
+    assert!(device
+        .apply_update(r#"[{"op": "replace", "path": "/unknown", "value": 1}]"#)
+        .is_err());
+    assert!(device
+        .apply_update(r#"[{"op": "replace", "path": "/allow_reset", "value": "yes"}]"#)
+        .is_err());
+    assert_eq!(device, updated);
+
+    device
+        .apply_update(
+            r#"[{"op": "move", "from": "/layout_register/default", "path": "/layout_register/calibrated"},
+                {"op": "replace", "path": "/current_layout", "value": "calibrated"},
+                {"op": "replace", "path": "/default_layout", "value": "calibrated"}]"#,
+        )
+        .unwrap();
+    assert_eq!(device.available_layouts(), vec!["calibrated"]);
+    assert_eq!(device.single_qubit_gate_time("RotateX", &1), Some(0.25));
+}
+
+/// Test TweezerDevice tweezer coordinates and distance-based two-qubit gate times
+#[test]
+fn test_two_qubit_gate_times_from_distance() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    assert!(device.tweezer_coordinates(None).unwrap().is_empty());
+
+    // Four tweezers on a line with a spacing of 2.0
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_coordinates(tweezer, 2.0 * tweezer as f64, 0.0, None)
+            .unwrap();
+    }
+    assert_eq!(
+        device.tweezer_coordinates(Some("default".to_string())),
+        Ok(HashMap::from([
+            (0, (0.0, 0.0)),
+            (1, (2.0, 0.0)),
+            (2, (4.0, 0.0)),
+            (3, (6.0, 0.0))
+        ]))
+    );
+    assert!(device
+        .set_tweezer_coordinates(4, f64::NAN, 0.0, None)
+        .is_err());
+    assert!(device
+        .tweezer_coordinates(Some("error".to_string()))
+        .is_err());
+
+    device
+        .set_two_qubit_gate_times_from_distance(
+            "PhaseShiftedControlledZ",
+            4.0,
+            |distance| 0.1 * distance,
+            None,
+        )
+        .unwrap();
+    let gate_times = device
+        .layout_info("default")
+        .unwrap()
+        .unwrap()
+        .tweezer_two_qubit_gate_times
+        .get("PhaseShiftedControlledZ")
+        .unwrap()
+        .clone();
+    assert_eq!(gate_times.len(), 10);
+    assert_eq!(gate_times.get(&(0, 1)), Some(&0.2));
+    assert_eq!(gate_times.get(&(1, 0)), Some(&0.2));
+    assert_eq!(gate_times.get(&(3, 1)), Some(&0.4));
+    assert_eq!(gate_times.get(&(0, 3)), None);
+
+    // Invalid arguments leave the gate times unchanged
+    assert!(device
+        .set_two_qubit_gate_times_from_distance("CNOT", 4.0, |_| 1.0, None)
+        .is_err());
+    assert!(device
+        .set_two_qubit_gate_times_from_distance("PhaseShiftedControlledZ", -1.0, |_| 1.0, None)
+        .is_err());
+    assert!(device
+        .set_two_qubit_gate_times_from_distance("PhaseShiftedControlledZ", 8.0, |_| -1.0, None)
+        .is_err());
+    assert_eq!(
+        device
+            .layout_info("default")
+            .unwrap()
+            .unwrap()
+            .tweezer_two_qubit_gate_times
+            .get("PhaseShiftedControlledZ"),
+        Some(&gate_times)
+    );
+
+    // The coordinates are serialized with the layout
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
+}
+
+/// Test TweezerDevice blockade constraints and parallel execution
+#[test]
+fn test_blockade_constraints() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    assert!(device.add_blockaded_tweezer_pair(0, 1, None).is_err());
+    assert!(device.set_blockade_radius(1.0, None).is_err());
+    device.current_layout = Some("default".to_string());
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.1, None)
+            .unwrap();
+        device
+            .set_tweezer_coordinates(tweezer, 2.0 * tweezer as f64, 0.0, None)
+            .unwrap();
+    }
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, 0.2, None)
+        .unwrap();
+    // Setting gate times resets the mapping, the qubits are mapped afterwards
+    for tweezer in 0..4 {
+        device.add_qubit_tweezer_mapping(tweezer, tweezer).unwrap();
+    }
+
+    let rotate = |qubit: usize| -> operations::Operation {
+        operations::RotateX::new(qubit, 1.0.into()).into()
+    };
+    let cz: operations::Operation =
+        operations::PhaseShiftedControlledZ::new(0, 1, 0.5.into()).into();
+
+    // Without constraints only operations on the same qubits can not run in parallel
+    assert!(device.can_execute_in_parallel(&[]).unwrap());
+    assert!(device
+        .can_execute_in_parallel(&[rotate(0), rotate(1), rotate(2)])
+        .unwrap());
+    assert!(!device
+        .can_execute_in_parallel(&[cz.clone(), rotate(1)])
+        .unwrap());
+    assert!(device.are_tweezers_blockaded(2, 2, None).unwrap());
+    assert!(!device.are_tweezers_blockaded(2, 3, None).unwrap());
+
+    // Explicit pairs are symmetric
+    assert!(device.add_blockaded_tweezer_pair(3, 3, None).is_err());
+    device.add_blockaded_tweezer_pair(3, 2, None).unwrap();
+    assert!(device.are_tweezers_blockaded(2, 3, None).unwrap());
+    assert!(!device
+        .can_execute_in_parallel(&[rotate(2), rotate(3)])
+        .unwrap());
+    assert!(device
+        .can_execute_in_parallel(&[cz.clone(), rotate(3)])
+        .unwrap());
+
+    // The blockade radius uses the tweezer coordinates
+    assert!(device.set_blockade_radius(-1.0, None).is_err());
+    device.set_blockade_radius(2.0, None).unwrap();
+    assert!(device.are_tweezers_blockaded(1, 2, None).unwrap());
+    assert!(!device.are_tweezers_blockaded(0, 2, None).unwrap());
+    assert!(!device
+        .can_execute_in_parallel(&[cz.clone(), rotate(2)])
+        .unwrap());
+    assert!(device
+        .can_execute_in_parallel(&[rotate(0), rotate(2)])
+        .unwrap());
+
+    // Operations on all qubits run alone, operations on no qubit do not constrain
+    let global: operations::Operation =
+        operations::PragmaRepeatedMeasurement::new("ro".to_string(), 10, None).into();
+    let phase: operations::Operation = operations::PragmaGlobalPhase::new(0.1.into()).into();
+    assert!(device
+        .can_execute_in_parallel(&[global.clone(), phase.clone()])
+        .unwrap());
+    assert!(!device
+        .can_execute_in_parallel(&[global, rotate(0)])
+        .unwrap());
+    assert!(device.can_execute_in_parallel(&[phase, rotate(0)]).unwrap());
+
+    // Qubits without a tweezer
+    assert!(device.can_execute_in_parallel(&[rotate(5)]).is_err());
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
+}
+
+/// Test TweezerDevice zone labels and zone constraints
+#[test]
+fn test_tweezer_zones() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    assert!(device.tweezer_zones(None).unwrap().is_empty());
+    assert!(device.tweezer_zones(Some("error".to_string())).is_err());
+
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, 0.2, None)
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 1, 2, 0.2, None)
+        .unwrap();
+    // Layouts without zones are not constrained
+    assert!(device.check_zone_constraints(None).is_ok());
+
+    device.set_tweezer_zone(0, "interaction", None).unwrap();
+    device.set_tweezer_zone(1, "interaction", None).unwrap();
+    device.set_tweezer_zone(2, "storage", None).unwrap();
+    assert!(device.set_tweezer_zone(3, "", None).is_err());
+    assert_eq!(
+        device.tweezer_zone(2, None).unwrap(),
+        Some("storage".to_string())
+    );
+    assert_eq!(device.tweezer_zone(3, None).unwrap(), None);
+    assert_eq!(device.tweezer_zones(None).unwrap().len(), 3);
+    assert!(device.check_zone_constraints(None).is_err());
+
+    device.set_tweezer_zone(2, "interaction", None).unwrap();
+    assert!(device.check_zone_constraints(None).is_ok());
+    assert!(device
+        .check_zone_constraints(Some("error".to_string()))
+        .is_err());
+
+    // The zones are serialized with the layout
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
+}
+
+/// Test TweezerDevice square_lattice() and triangular_lattice() constructors
+#[test]
+fn test_lattices() {
+    let gate_times = HashMap::from([
+        ("RotateX".to_string(), 0.1),
+        ("RotateZ".to_string(), 0.05),
+        ("PhaseShiftedControlledZ".to_string(), 0.5),
+    ]);
+
+    let device = TweezerDevice::square_lattice(3, 4, &gate_times).unwrap();
+    assert_eq!(device.current_layout, Some("default".to_string()));
+    assert_eq!(device.default_layout, Some("default".to_string()));
+    assert_eq!(device.number_qubits(), 12);
+    assert_eq!(device.tweezer_coordinates(None).unwrap()[&6], (2.0, 1.0));
+    assert_eq!(device.single_qubit_gate_time("RotateZ", &11), Some(0.05));
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &5, &6),
+        Some(0.5)
+    );
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &5, &1),
+        Some(0.5)
+    );
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &5, &0),
+        None
+    );
+    // Edges between horizontal and vertical neighbours, in both orders
+    assert_eq!(device.two_qubit_edges().len(), 34);
+    let layout = device.layout_info("default").unwrap().unwrap();
+    assert_eq!(layout.tweezers_per_row, Some(vec![4, 4, 4]));
+    assert_eq!(
+        layout.allowed_tweezer_shifts.get(&5),
+        Some(&vec![vec![4], vec![6, 7]])
+    );
+
+    // Every second row is offset, tweezers of neighbouring rows are diagonal neighbours
+    let device = TweezerDevice::triangular_lattice(3, 4, &gate_times).unwrap();
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &1, &5),
+        Some(0.5)
+    );
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &1, &4),
+        Some(0.5)
+    );
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &5, &9),
+        Some(0.5)
+    );
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &5, &10),
+        Some(0.5)
+    );
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &1, &6),
+        None
+    );
+
+    assert!(TweezerDevice::square_lattice(0, 4, &gate_times).is_err());
+    assert!(TweezerDevice::square_lattice(
+        2,
+        2,
+        &HashMap::from([("PhaseShiftedControlledZ".to_string(), 0.5)])
+    )
+    .is_err());
+    assert!(TweezerDevice::triangular_lattice(
+        2,
+        2,
+        &HashMap::from([("RotateX".to_string(), 0.1), ("CNOT".to_string(), 0.5)])
+    )
+    .is_err());
+}
+
+/// Test TweezerDevice from_first_device() converter
+#[test]
+fn test_from_first_device() {
+    let mut first_device = FirstDevice::new(
+        2,
+        3,
+        &[3, 2],
+        1.0,
+        array![[0.0, 1.0, 2.0], [0.0, 1.0, 2.0]],
+        None,
+        None,
+        Some(true),
+        Some(true),
+    )
+    .unwrap();
+    first_device.set_cutoff(1.5);
+    first_device.set_qubit_position(3, 2).unwrap();
+
+    let device = TweezerDevice::from_first_device(&first_device).unwrap();
+    assert_eq!(device.current_layout, Some("default".to_string()));
+    assert_eq!(device.default_layout, Some("default".to_string()));
+    assert_eq!(
+        device.controlled_z_phase_relation,
+        first_device.controlled_z_phase_relation()
+    );
+    assert_eq!(device.get_tweezer_from_qubit(&3).unwrap(), 5);
+    assert_eq!(device.get_tweezer_from_qubit(&4).unwrap(), 4);
+    assert_eq!(device.tweezer_coordinates(None).unwrap()[&5], (1.0, 2.0));
+    let layout = device.layout_info("default").unwrap().unwrap();
+    assert_eq!(layout.tweezers_per_row, Some(vec![3, 3]));
+    assert_eq!(
+        layout.allowed_tweezer_shifts.get(&1),
+        Some(&vec![vec![0], vec![2]])
+    );
+
+    // The gate times of the qubits are the ones of the FirstDevice
+    for gate in ["RotateX", "RotateY", "PauliZ", "Hadamard"] {
+        assert_eq!(
+            device.single_qubit_gate_time(gate, &3),
+            first_device.single_qubit_gate_time(gate, &3)
+        );
+    }
+    for (control, target) in iproduct!(0..5, 0..5) {
+        for gate in ["PhaseShiftedControlledZ", "PhaseShiftedControlledPhase"] {
+            if control != target {
+                assert_eq!(
+                    device.two_qubit_gate_time(gate, &control, &target),
+                    first_device.two_qubit_gate_time(gate, &control, &target)
+                );
+            }
+        }
+    }
+    for (control_0, control_1, target) in iproduct!(0..5, 0..5, 0..5) {
+        for gate in [
+            "ControlledControlledPauliZ",
+            "ControlledControlledPhaseShift",
+        ] {
+            assert_eq!(
+                device.three_qubit_gate_time(gate, &control_0, &control_1, &target),
+                first_device.three_qubit_gate_time(gate, &control_0, &control_1, &target)
+            );
+        }
+    }
+    assert_eq!(
+        device.multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2]),
+        Some(2e-5)
+    );
+    assert_eq!(
+        device.multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 3]),
+        None
+    );
+
+    let ragged_device = FirstDevice::new_ragged(
+        &[1, 1],
+        vec![vec![(0.0, 0.0), (0.0, 1.0)], vec![(1.0, 0.5)]],
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    let device = TweezerDevice::from_first_device(&ragged_device).unwrap();
+    let layout = device.layout_info("default").unwrap().unwrap();
+    assert_eq!(layout.tweezers_per_row, Some(vec![2, 1]));
+    assert_eq!(device.get_tweezer_from_qubit(&1).unwrap(), 2);
+    assert_eq!(device.tweezer_coordinates(None).unwrap()[&2], (1.0, 0.5));
+}
+
+/// Test TweezerDevice per-tweezer gate error rates
+#[test]
+fn test_gate_errors() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    for tweezer in 0..3 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.1, None)
+            .unwrap();
+    }
+    device.add_qubit_tweezer_mapping(0, 2).unwrap();
+    device.add_qubit_tweezer_mapping(1, 0).unwrap();
+    device.add_qubit_tweezer_mapping(2, 1).unwrap();
+
+    device
+        .set_tweezer_single_qubit_gate_error("RotateX", 2, 0.001, None)
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_error("PhaseShiftedControlledZ", 2, 0, 0.01, None)
+        .unwrap();
+    device
+        .set_tweezer_three_qubit_gate_error(
+            "ControlledControlledPauliZ",
+            2,
+            0,
+            1,
+            0.05,
+            Some("default".to_string()),
+        )
+        .unwrap();
+
+    assert_eq!(device.single_qubit_gate_error("RotateX", &0), Some(0.001));
+    assert_eq!(device.single_qubit_gate_error("RotateX", &1), None);
+    assert_eq!(device.single_qubit_gate_error("RotateZ", &0), None);
+    assert_eq!(device.single_qubit_gate_error("RotateX", &5), None);
+    assert_eq!(
+        device.two_qubit_gate_error("PhaseShiftedControlledZ", &0, &1),
+        Some(0.01)
+    );
+    assert_eq!(
+        device.two_qubit_gate_error("PhaseShiftedControlledZ", &1, &0),
+        None
+    );
+    assert_eq!(
+        device.three_qubit_gate_error("ControlledControlledPauliZ", &0, &1, &2),
+        Some(0.05)
+    );
+    assert_eq!(
+        device.three_qubit_gate_error("ControlledControlledPauliZ", &2, &1, &0),
+        None
+    );
+
+    // Error rates do not change the gate times
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.1));
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1),
+        None
+    );
+
+    assert!(device
+        .set_tweezer_single_qubit_gate_error("CNOT", 0, 0.01, None)
+        .is_err());
+    assert!(device
+        .set_tweezer_two_qubit_gate_error("RotateX", 0, 1, 0.01, None)
+        .is_err());
+    assert!(device
+        .set_tweezer_three_qubit_gate_error("PhaseShiftedControlledZ", 0, 1, 2, 0.01, None)
+        .is_err());
+    assert!(device
+        .set_tweezer_single_qubit_gate_error("RotateX", 0, 1.5, None)
+        .is_err());
+    assert!(device
+        .set_tweezer_single_qubit_gate_error("RotateX", 0, -0.1, None)
+        .is_err());
+    assert!(device
+        .set_tweezer_single_qubit_gate_error("RotateX", 0, f64::NAN, None)
+        .is_err());
+    assert!(device
+        .set_tweezer_single_qubit_gate_error("RotateX", 0, 0.01, Some("error".to_string()))
+        .is_err());
+
+    // The error rates are serialized with the layout
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
+    assert_eq!(
+        deserialized.two_qubit_gate_error("PhaseShiftedControlledZ", &0, &1),
+        Some(0.01)
+    );
+}
+
+/// Test TweezerDevice per-tweezer loading probabilities
+#[test]
+fn test_loading_probability() {
+    let mut device = TweezerDevice::new(None, None, None);
+    assert_eq!(device.loading_probability(0), 1.0);
+
+    device.set_loading_probability(0, 0.6).unwrap();
+    device.set_rearrangement(true);
+    assert_eq!(device.loading_probability(0), 0.6);
+    assert_eq!(device.loading_probability(1), 1.0);
+    assert!(device.array_preparation.rearrangement);
+
+    assert!(device.set_loading_probability(1, 1.5).is_err());
+    assert!(device.set_loading_probability(1, -0.1).is_err());
+    assert!(device.set_loading_probability(1, f64::NAN).is_err());
+    assert_eq!(device.loading_probability(1), 1.0);
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
+    assert_eq!(deserialized.loading_probability(0), 0.6);
+}
+
+/// Test TweezerDevice atom loss probabilities
+#[test]
+fn test_atom_loss_probabilities() {
+    let mut device = TweezerDevice::new(None, None, None);
+    assert_eq!(device.shift_loss_probability(), 0.0);
+    assert_eq!(device.measurement_loss_probability(), 0.0);
+
+    device.set_shift_loss_probability(0.1).unwrap();
+    device.set_measurement_loss_probability(0.2).unwrap();
+    assert_eq!(device.shift_loss_probability(), 0.1);
+    assert_eq!(device.measurement_loss_probability(), 0.2);
+
+    assert_eq!(
+        device.set_shift_loss_probability(1.5),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "The atom loss probability needs to be between 0 and 1, got 1.5.".to_string()
+        })
+    );
+    assert!(device.set_measurement_loss_probability(-0.1).is_err());
+    assert!(device.set_measurement_loss_probability(f64::NAN).is_err());
+    assert_eq!(device.measurement_loss_probability(), 0.2);
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
+    assert_eq!(deserialized.shift_loss_probability(), 0.1);
+}
+
+/// Test TweezerDevice two-qubit gate times of densely and sparsely connected layouts
+#[test]
+fn test_dense_two_qubit_gate_times() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("dense").unwrap();
+    device.add_layout("sparse").unwrap();
+    device.current_layout = Some("dense".to_string());
+    // All-to-all connectivity of 12 tweezers, stored densely
+    for (tweezer0, tweezer1) in iproduct!(0..12, 0..12) {
+        if tweezer0 != tweezer1 {
+            device
+                .set_tweezer_two_qubit_gate_time(
+                    "PhaseShiftedControlledPhase",
+                    tweezer0,
+                    tweezer1,
+                    (tweezer0 * 12 + tweezer1) as f64,
+                    Some("dense".to_string()),
+                )
+                .unwrap();
+        }
+    }
+    // Chain of 80 tweezers, stored sparsely
+    for tweezer in 0..80 {
+        device
+            .set_tweezer_two_qubit_gate_time(
+                "PhaseShiftedControlledPhase",
+                tweezer,
+                tweezer + 1,
+                tweezer as f64,
+                Some("sparse".to_string()),
+            )
+            .unwrap();
+    }
+    device.switch_layout("dense", None).unwrap();
+    let unused = device.clone();
+
+    for (qubit0, qubit1) in iproduct!(0..12, 0..12) {
+        let expected = if qubit0 == qubit1 {
+            None
+        } else {
+            Some((qubit0 * 12 + qubit1) as f64)
+        };
+        assert_eq!(
+            device.two_qubit_gate_time("PhaseShiftedControlledPhase", &qubit0, &qubit1),
+            expected
+        );
+    }
+    assert!(device
+        .two_qubit_gate_time("PhaseShiftedControlledZ", &0, &1)
+        .is_none());
+    assert_eq!(device, unused);
+
+    // Changes of the gate times are returned by the following lookups
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledPhase", 0, 1, 0.5, None)
+        .unwrap();
+    device.switch_layout("dense", None).unwrap();
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledPhase", &0, &1),
+        Some(0.5)
+    );
+    // A direct change of the current layout does not use the tables of the previous layout
+    device.current_layout = Some("sparse".to_string());
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledPhase", &3, &4),
+        Some(3.0)
+    );
+    device.current_layout = Some("dense".to_string());
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledPhase", &3, &4),
+        Some(40.0)
+    );
+
+    device.qubit_to_tweezer = None;
+    device.switch_layout("sparse", None).unwrap();
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledPhase", &3, &4),
+        Some(3.0)
+    );
+    assert!(device
+        .two_qubit_gate_time("PhaseShiftedControlledPhase", &4, &3)
+        .is_none());
+    assert_eq!(
+        device.two_qubit_gate_time("PhaseShiftedControlledPhase", &79, &80),
+        Some(79.0)
     );
-    // TODO: Add this back in when the backend supports multi-qubit gates
-    // assert_eq!(
-    //     device
-    //         .multi_qubit_gate_time("MultiQubitZZ", &[3, 0, 1, 2])
-    //         .unwrap(),
-    //     0.34
-    // );
 }
 
 /// Test TweezerDevice number_qubits() method
@@ -608,12 +2157,11 @@ fn test_number_tweezer_positions() {
     device
         .set_tweezer_three_qubit_gate_time("ControlledControlledPhaseShift", 2, 9, 13, 0.34, None)
         .unwrap();
-    // TODO: Add this back in when the backend supports multi-qubit gates
-    // device
-    //     .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[1, 12, 5], 0.34, None)
-    //     .unwrap();
+    device
+        .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[1, 12, 5], 0.34, None)
+        .unwrap();
 
-    assert_eq!(device.number_tweezer_positions(None), Ok(6));
+    assert_eq!(device.number_tweezer_positions(None), Ok(8));
 }
 
 /// Test TweezerDevice to_generic_device() method
@@ -687,6 +2235,69 @@ fn test_to_generic_device() {
     );
 }
 
+/// Test TweezerDevice try_to_generic_device() and to_generic_device_checked() methods
+#[test]
+fn test_to_generic_device_checked() {
+    let mut device = TweezerDevice::new(None, None, None);
+    assert!(device.try_to_generic_device().is_err());
+    assert_eq!(device.to_generic_device(), GenericDevice::new(0));
+
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.1, None)
+            .unwrap();
+    }
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 1, 2, 0.2, None)
+        .unwrap();
+    // Qubits are mapped to tweezers in reverse order, tweezer 3 holds no qubit
+    let map_qubits = |device: &mut TweezerDevice| {
+        device.add_qubit_tweezer_mapping(0, 2).unwrap();
+        device.add_qubit_tweezer_mapping(1, 1).unwrap();
+        device.add_qubit_tweezer_mapping(2, 0).unwrap();
+    };
+    map_qubits(&mut device);
+
+    // Gates on tweezers without a qubit are skipped
+    assert!(device.to_generic_device_checked().is_err());
+    let generic_device = device.try_to_generic_device().unwrap();
+    assert_eq!(generic_device, device.to_generic_device());
+    assert_eq!(generic_device.number_qubits(), 3);
+    assert_eq!(
+        generic_device.single_qubit_gate_time("RotateX", &0),
+        Some(0.1)
+    );
+    assert_eq!(
+        generic_device.two_qubit_gate_time("PhaseShiftedControlledZ", &1, &0),
+        Some(0.2)
+    );
+    assert_eq!(
+        generic_device.two_qubit_gate_time("PhaseShiftedControlledZ", &1, &2),
+        None
+    );
+
+    device.add_qubit_tweezer_mapping(3, 3).unwrap();
+    let generic_device = device.to_generic_device_checked().unwrap();
+    assert_eq!(
+        generic_device.single_qubit_gate_time("RotateX", &3),
+        Some(0.1)
+    );
+
+    // Three-qubit gates are approximated by multi-qubit gates
+    device
+        .set_tweezer_three_qubit_gate_time("ControlledControlledPauliZ", 0, 1, 2, 0.3, None)
+        .unwrap();
+    map_qubits(&mut device);
+    assert!(device.to_generic_device_checked().is_err());
+    let generic_device = device.try_to_generic_device().unwrap();
+    assert_eq!(
+        generic_device.multi_qubit_gate_time("ControlledControlledPauliZ", &[2, 1, 0]),
+        Some(0.3)
+    );
+}
+
 /// Test TweezerDevice change_device() method
 #[test]
 fn test_change_device() {
@@ -883,6 +2494,65 @@ fn test_change_device_switch() {
     );
 }
 
+/// Test TweezerDevice infer_tweezers_per_row() method
+#[test]
+fn test_infer_tweezers_per_row() {
+    let mut device = TweezerDevice::new(None, None, None);
+    let coordinates = Some("coordinates".to_string());
+    let shifts = Some("shifts".to_string());
+    device.add_layout("coordinates").unwrap();
+    device.add_layout("shifts").unwrap();
+    assert!(device.infer_tweezers_per_row(None).is_err());
+    assert_eq!(device.infer_tweezers_per_row(shifts.clone()), Ok(None));
+
+    for tweezer in 0..5 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.1, coordinates.clone())
+            .unwrap();
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.1, shifts.clone())
+            .unwrap();
+    }
+    for (tweezer, (x, y)) in [(0.0, 1.0), (1.0, 1.0), (0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]
+        .into_iter()
+        .enumerate()
+    {
+        device
+            .set_tweezer_coordinates(tweezer, x, y, coordinates.clone())
+            .unwrap();
+    }
+    assert_eq!(
+        device.infer_tweezers_per_row(coordinates.clone()),
+        Ok(Some(vec![3, 2]))
+    );
+
+    device
+        .set_allowed_tweezer_shifts_from_rows(&[&[0, 1, 2], &[3]], shifts.clone())
+        .unwrap();
+    // Tweezer 4 can not be shifted
+    assert_eq!(device.infer_tweezers_per_row(shifts.clone()), Ok(None));
+    device
+        .set_allowed_tweezer_shifts_from_rows(&[&[0, 1, 2], &[3, 4]], shifts.clone())
+        .unwrap();
+    assert_eq!(
+        device.infer_tweezers_per_row(shifts.clone()),
+        Ok(Some(vec![3, 2]))
+    );
+
+    // Layouts without tweezers per row are switched using the inferred rows
+    device.switch_layout("shifts", None).unwrap();
+    let pragma = PragmaSwitchDeviceLayout::new("coordinates".to_string());
+    device
+        .change_device("PragmaSwitchDeviceLayout", &serialize(&pragma).unwrap())
+        .unwrap();
+    assert_eq!(device.current_layout, Some("coordinates".to_string()));
+    device.set_tweezers_per_row(vec![5], coordinates).unwrap();
+    let pragma = PragmaSwitchDeviceLayout::new("shifts".to_string());
+    assert!(device
+        .change_device("PragmaSwitchDeviceLayout", &serialize(&pragma).unwrap())
+        .is_err());
+}
+
 /// Test TweezerDevice allow_reset field
 #[test]
 fn test_allow_reset() {
@@ -992,6 +2662,103 @@ fn test_change_device_shift() {
     assert!(err4.is_err());
 }
 
+/// Test TweezerDevice validate_pragma() and validate_change_device() methods
+#[test]
+fn test_validate_pragma() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.add_layout("other").unwrap();
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateX",
+                tweezer,
+                0.1,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device
+        .set_tweezers_per_row(vec![4], Some("default".to_string()))
+        .unwrap();
+    device
+        .set_tweezers_per_row(vec![2, 2], Some("other".to_string()))
+        .unwrap();
+    device.switch_layout("default", Some(false)).unwrap();
+    let pragma = PragmaShiftQubitsTweezers::new(vec![(0, 2)]);
+    assert_eq!(
+        device.validate_pragma(&pragma),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "The device qubit -> tweezer mapping is empty: no qubits to shift.".to_string(),
+        })
+    );
+
+    device
+        .set_allowed_tweezer_shifts(&0, &[&[1, 2, 3]], None)
+        .unwrap();
+    device
+        .set_allowed_tweezer_shifts(&1, &[&[2, 3]], None)
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 1).unwrap();
+    let original = device.clone();
+
+    assert_eq!(
+        device.validate_pragma(&pragma),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Shift 0 from tweezer 0 to tweezer 2 is not valid: tweezer 1 on the way is occupied."
+                .to_string(),
+        })
+    );
+    // The first shift frees the way of the second one
+    let pragma = PragmaShiftQubitsTweezers::new(vec![(1, 3), (0, 2)]);
+    assert!(device.validate_pragma(&pragma).is_ok());
+    let pragma = PragmaShiftQubitsTweezers::new(vec![(1, 0)]);
+    assert_eq!(
+        device.validate_pragma(&pragma),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Shift 0 from tweezer 1 to tweezer 0 is not valid: tweezer 0 can not be reached from tweezer 1."
+                .to_string(),
+        })
+    );
+    let pragma = PragmaShiftQubitsTweezers::new(vec![(2, 3)]);
+    assert_eq!(
+        device.validate_pragma(&pragma),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "Shift 0 from tweezer 2 to tweezer 3 is not valid: no shifts are allowed out of tweezer 2."
+                .to_string(),
+        })
+    );
+
+    let pragma = PragmaShiftQubitsTweezers::new(vec![(1, 2)]);
+    assert!(device
+        .validate_change_device("PragmaShiftQubitsTweezers", &serialize(&pragma).unwrap())
+        .is_ok());
+    // Bincode of an operation serialized before durations and parallel were added: only the shifts
+    let legacy_bytes = serialize(&vec![(1_usize, 2_usize)]).unwrap();
+    assert!(device
+        .validate_change_device("PragmaShiftQubitsTweezers", &legacy_bytes)
+        .is_ok());
+    let mut shifted = device.clone();
+    shifted
+        .change_device("PragmaShiftQubitsTweezers", &legacy_bytes)
+        .unwrap();
+    assert_eq!(shifted.qubit_to_tweezer.as_ref().unwrap().get(&1), Some(&2));
+    let pragma = PragmaDeactivateQRydQubit::new(2);
+    assert!(device
+        .validate_change_device("PragmaDeactivateQRydQubit", &serialize(&pragma).unwrap())
+        .is_err());
+    let pragma = PragmaSwitchDeviceLayout::new("other".to_string());
+    assert!(device
+        .validate_change_device("PragmaSwitchDeviceLayout", &serialize(&pragma).unwrap())
+        .is_err());
+    assert!(device
+        .validate_change_device("PragmaUnknown", &serialize(&pragma).unwrap())
+        .is_err());
+    // Validating does not change the device
+    assert_eq!(device, original);
+}
+
 /// Test TweezerDevice change_device() method with PragmaShiftQubitsTweezers (whole row)
 #[test]
 fn test_change_device_shift_row() {
@@ -1142,6 +2909,81 @@ async fn asnyc_test_from_api() {
     wiremock_server.verify().await;
 }
 
+/// Test the Layouts of a TweezerDevice downloaded with from_api() that are loaded on access
+#[tokio::test]
+#[cfg(feature = "web-api")]
+async fn async_test_from_api_serialized_layouts() {
+    let mut returned_device = TweezerDevice::new(None, None, None);
+    for (layout, gate_time) in [("default", 0.23), ("other", 0.34), ("unused", 0.45)] {
+        returned_device.add_layout(layout).unwrap();
+        returned_device
+            .set_tweezer_single_qubit_gate_time("RotateX", 0, gate_time, Some(layout.to_string()))
+            .unwrap();
+        returned_device
+            .set_tweezer_single_qubit_gate_time("RotateZ", 1, gate_time, Some(layout.to_string()))
+            .unwrap();
+        returned_device
+            .set_tweezers_per_row(vec![2], Some(layout.to_string()))
+            .unwrap();
+    }
+    returned_device.set_default_layout("default").unwrap();
+    returned_device.device_name = "qryd_emulator".to_string();
+    let wiremock_server = MockServer::start().await;
+    let port = wiremock_server.address().port().to_string();
+    let _mock = Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&returned_device))
+        .expect(1)
+        .mount(&wiremock_server)
+        .await;
+
+    let mut device = tokio::task::spawn_blocking(move || {
+        TweezerDevice::from_api(None, None, Some(port), None, None, None)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    wiremock_server.verify().await;
+
+    // Layouts that have not been loaded yet behave like loaded ones
+    assert_eq!(device, returned_device);
+    assert_eq!(device.layouts().unwrap().unwrap().len(), 3);
+    let mut layouts = device.available_layouts();
+    layouts.sort();
+    assert_eq!(layouts, vec!["default", "other", "unused"]);
+    let mut gates = device
+        .get_available_gates_names(Some("unused".to_string()))
+        .unwrap();
+    gates.sort();
+    assert_eq!(gates, vec!["RotateX", "RotateZ"]);
+    assert_eq!(
+        device
+            .number_tweezer_positions(Some("unused".to_string()))
+            .unwrap(),
+        2
+    );
+    assert!(device.add_layout("unused").is_err());
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, returned_device);
+    // The public current layout can refer to a Layout that has not been loaded yet
+    let mut direct = device.clone();
+    direct.current_layout = Some("other".to_string());
+    direct.qubit_to_tweezer = Some(HashMap::from([(0, 0), (1, 1)]));
+    assert_eq!(direct.single_qubit_gate_time("RotateX", &0), Some(0.34));
+
+    device.switch_layout("other", None).unwrap();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.34));
+    device
+        .change_device(
+            "PragmaSwitchDeviceLayout",
+            &serialize(&PragmaSwitchDeviceLayout::new("unused".to_string())).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(device.single_qubit_gate_time("RotateX", &0), Some(0.45));
+    device.load_layouts().unwrap();
+    assert_eq!(device.layouts().unwrap().unwrap().len(), 3);
+}
+
 #[test]
 #[cfg(feature = "web-api")]
 fn test_from_api() {
@@ -1220,33 +3062,278 @@ fn test_phi_theta_relation() {
         .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, 0.23, None)
         .unwrap();
     device
-        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledPhase", 0, 1, 0.23, None)
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledPhase", 0, 1, 0.23, None)
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 1).unwrap();
+
+    assert!(device
+        .gate_time_controlled_z(&0, &1, device.phase_shift_controlled_z().unwrap())
+        .is_some());
+    assert!(device
+        .gate_time_controlled_z(&0, &7, device.phase_shift_controlled_z().unwrap())
+        .is_none());
+    assert!(device
+        .gate_time_controlled_phase(
+            &0,
+            &1,
+            device.phase_shift_controlled_phase(0.1).unwrap(),
+            0.1
+        )
+        .is_some());
+    assert!(device
+        .gate_time_controlled_phase(
+            &0,
+            &7,
+            device.phase_shift_controlled_phase(0.1).unwrap(),
+            0.1
+        )
+        .is_none());
+}
+
+/// Test TweezerDevice custom phi-theta relations
+#[test]
+fn test_custom_phi_theta_relations() {
+    let mut device = TweezerDevice::new(
+        None,
+        Some("LinearRelation".to_string()),
+        Some("LinearRelation".to_string()),
+    );
+    assert_eq!(device.phase_shift_controlled_z(), None);
+
+    device
+        .add_phi_theta_relation(
+            "LinearRelation",
+            PhiThetaRelation::Polynomial {
+                coefficients: vec![0.5, 2.0],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        device.phase_shift_controlled_z(),
+        Some(0.5 + 2.0 * std::f64::consts::PI)
+    );
+    assert_eq!(device.phase_shift_controlled_phase(0.25), Some(1.0));
+    assert_eq!(
+        device.phase_shift_controlled_phase(-0.25),
+        Some(0.5 + 2.0 * (2.0 * std::f64::consts::PI - 0.25))
+    );
+
+    // The logarithmic form reproduces the DefaultRelation
+    device
+        .add_phi_theta_relation(
+            "LogarithmicRelation",
+            PhiThetaRelation::Logarithmic {
+                offset: 5.11382,
+                scale: -0.32933,
+                quadratic: 1.63085,
+                exponent: 2.0,
+                linear: 1.0,
+                constant: 0.02889,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        device.phi_theta_relation("LogarithmicRelation", 1.2),
+        phi_theta_relation("DefaultRelation", 1.2)
+    );
+    assert_eq!(
+        device.phi_theta_relation("DefaultRelation", 1.2),
+        phi_theta_relation("DefaultRelation", 1.2)
+    );
+
+    let relation = PhiThetaRelation::Polynomial {
+        coefficients: vec![1.0],
+    };
+    assert!(device
+        .add_phi_theta_relation("DefaultRelation", relation.clone())
+        .is_err());
+    assert!(device
+        .add_phi_theta_relation("1.5", relation.clone())
+        .is_err());
+    assert!(device.add_phi_theta_relation("", relation).is_err());
+
+    // The relations are serialized with the device
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
+
+    assert!(device.remove_phi_theta_relation("LinearRelation").is_some());
+    assert!(device.remove_phi_theta_relation("LinearRelation").is_none());
+    assert_eq!(device.phase_shift_controlled_z(), None);
+}
+
+/// Test TweezerDevice set_phase_relations() and the PragmaSetPhaseRelation operation
+#[test]
+fn test_set_phase_relation() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+
+    device
+        .set_phase_relations("2.13", "DefaultRelation")
+        .unwrap();
+    assert_eq!(device.phase_shift_controlled_z(), Some(2.13));
+    assert_eq!(
+        device.phase_shift_controlled_phase(1.2),
+        phi_theta_relation("DefaultRelation", 1.2)
+    );
+
+    // The device is left unchanged if a relation is not valid
+    assert_eq!(
+        device.set_phase_relations("DefaultRelation", "LinearRelation"),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "The phase relation LinearRelation is neither a number nor a builtin or registered phi-theta relation.".to_string(),
+        })
+    );
+    assert_eq!(device.phase_shift_controlled_z(), Some(2.13));
+
+    device
+        .add_phi_theta_relation(
+            "LinearRelation",
+            PhiThetaRelation::Polynomial {
+                coefficients: vec![0.5, 2.0],
+            },
+        )
+        .unwrap();
+    let pragma =
+        PragmaSetPhaseRelation::new("DefaultRelation".to_string(), "LinearRelation".to_string());
+    assert!(device
+        .validate_change_device("PragmaSetPhaseRelation", &serialize(&pragma).unwrap())
+        .is_ok());
+    device
+        .change_device("PragmaSetPhaseRelation", &serialize(&pragma).unwrap())
+        .unwrap();
+    assert_eq!(
+        device.phase_shift_controlled_z(),
+        phi_theta_relation("DefaultRelation", std::f64::consts::PI)
+    );
+    assert_eq!(device.phase_shift_controlled_phase(0.25), Some(1.0));
+
+    let pragma = PragmaSetPhaseRelation::new("2.13".to_string(), "UnknownRelation".to_string());
+    assert!(device
+        .validate_change_device("PragmaSetPhaseRelation", &serialize(&pragma).unwrap())
+        .is_err());
+    assert!(device
+        .change_device("PragmaSetPhaseRelation", &serialize(&pragma).unwrap())
+        .is_err());
+    assert_eq!(device.phase_shift_controlled_phase(0.25), Some(1.0));
+}
+
+/// Test TweezerDevice theta-dependent PhaseShiftedControlledPhase gate times
+#[test]
+fn test_controlled_phase_time_curve() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledPhase", 0, 1, 0.23, None)
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 1).unwrap();
+    let phi = |theta: f64| device.phase_shift_controlled_phase(theta).unwrap();
+
+    // Without a curve the gate time is fixed
+    assert_eq!(
+        device.gate_time_controlled_phase(&0, &1, phi(0.5), 0.5),
+        Some(1e-6)
+    );
+
+    device
+        .set_controlled_phase_time_curve(0, 1, &[(std::f64::consts::PI, 2.0), (0.0, 1.0)], None)
+        .unwrap();
+    let phi = |theta: f64| device.phase_shift_controlled_phase(theta).unwrap();
+    let half_pi = std::f64::consts::FRAC_PI_2;
+    for theta in [half_pi, -half_pi] {
+        let gate_time = device
+            .gate_time_controlled_phase(&0, &1, phi(theta), theta)
+            .unwrap();
+        assert!((gate_time - 1.5).abs() < 1e-12);
+    }
+    assert_eq!(
+        device.gate_time_controlled_phase(&0, &1, phi(0.0), 0.0),
+        Some(1.0)
+    );
+    // Wrong phi or qubits without the gate
+    assert_eq!(
+        device.gate_time_controlled_phase(&0, &1, 0.1, half_pi),
+        None
+    );
+    assert_eq!(
+        device.gate_time_controlled_phase(&1, &0, phi(half_pi), half_pi),
+        None
+    );
+
+    assert!(device
+        .set_controlled_phase_time_curve(0, 1, &[], None)
+        .is_err());
+    assert!(device
+        .set_controlled_phase_time_curve(0, 1, &[(4.0, 1.0)], None)
+        .is_err());
+    assert!(device
+        .set_controlled_phase_time_curve(0, 1, &[(0.5, 1.0), (0.5, 2.0)], None)
+        .is_err());
+    assert!(device
+        .set_controlled_phase_time_curve(0, 1, &[(0.5, -1.0)], None)
+        .is_err());
+
+    // The curves are serialized with the layout
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
+}
+
+/// Test that two-qubit gate times are set for both orientations of the tweezer pair
+#[test]
+fn test_symmetric_two_qubit_gate_times() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    let times = |device: &TweezerDevice| {
+        device
+            .layout_info("default")
+            .unwrap()
+            .unwrap()
+            .tweezer_two_qubit_gate_times
+            .get("PhaseShiftedControlledZ")
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    // Off by default
+    assert!(!device.symmetric_two_qubit_gate_times);
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, 0.23, None)
+        .unwrap();
+    assert_eq!(times(&device).get(&(0, 1)), Some(&0.23));
+    assert_eq!(times(&device).get(&(1, 0)), None);
+
+    device.set_symmetric_two_qubit_gate_times(true);
+    assert!(device.symmetric_two_qubit_gate_times);
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 1, 2, 0.34, None)
         .unwrap();
-    device.add_qubit_tweezer_mapping(0, 0).unwrap();
-    device.add_qubit_tweezer_mapping(1, 1).unwrap();
-
-    assert!(device
-        .gate_time_controlled_z(&0, &1, device.phase_shift_controlled_z().unwrap())
-        .is_some());
-    assert!(device
-        .gate_time_controlled_z(&0, &7, device.phase_shift_controlled_z().unwrap())
-        .is_none());
-    assert!(device
-        .gate_time_controlled_phase(
-            &0,
-            &1,
-            device.phase_shift_controlled_phase(0.1).unwrap(),
-            0.1
-        )
-        .is_some());
-    assert!(device
-        .gate_time_controlled_phase(
-            &0,
-            &7,
-            device.phase_shift_controlled_phase(0.1).unwrap(),
-            0.1
+    assert_eq!(times(&device).get(&(1, 2)), Some(&0.34));
+    assert_eq!(times(&device).get(&(2, 1)), Some(&0.34));
+    device
+        .set_tweezer_two_qubit_gate_times(
+            "PhaseShiftedControlledZ",
+            &[(2, 3, 0.45), (3, 4, 0.56)],
+            None,
         )
-        .is_none());
+        .unwrap();
+    assert_eq!(times(&device).get(&(3, 2)), Some(&0.45));
+    assert_eq!(times(&device).get(&(4, 3)), Some(&0.56));
+
+    device
+        .unset_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 1, 2, None)
+        .unwrap();
+    assert_eq!(times(&device).get(&(1, 2)), None);
+    assert_eq!(times(&device).get(&(2, 1)), None);
+
+    let serialized = serde_json::to_string(&device).unwrap();
+    let deserialized: TweezerDevice = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(device, deserialized);
 }
 
 // Test TweezerDevice two_tweezer_edges() method
@@ -1422,6 +3509,176 @@ fn test_available_gate_names() {
     );
 }
 
+#[test]
+fn test_native_gates() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    assert_eq!(device.get_native_gates(), &NativeGates::default());
+    assert!(device.get_native_gates().contains("RotateX"));
+    assert!(!device.get_native_gates().contains("SqrtPauliX"));
+    assert!(device
+        .set_tweezer_single_qubit_gate_time("SqrtPauliX", 0, 0.1, None)
+        .is_err());
+
+    let mut native_gates = NativeGates::default();
+    native_gates
+        .single_qubit_gates
+        .push("SqrtPauliX".to_string());
+    native_gates.two_qubit_gates.push("CNOT".to_string());
+    device.set_native_gates(native_gates.clone()).unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("SqrtPauliX", 0, 0.1, None)
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_error("SqrtPauliX", 0, 0.01, None)
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_time("CNOT", 0, 1, 0.3, None)
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    assert_eq!(device.single_qubit_gate_time("SqrtPauliX", &0), Some(0.1));
+    assert!(device
+        .set_tweezer_three_qubit_gate_time("CNOT", 0, 1, 2, 0.3, None)
+        .is_err());
+
+    // Gates with gate times need to stay native
+    let error = device.set_native_gates(NativeGates::default()).unwrap_err();
+    assert_eq!(
+        error,
+        RoqoqoBackendError::GenericError {
+            msg: "The single-qubit gate SqrtPauliX has gate times in Layout default but is not a native gate."
+                .to_string()
+        }
+    );
+    assert_eq!(device.get_native_gates(), &native_gates);
+
+    // The native gates are serialized with the device
+    let deserialized: TweezerDevice =
+        serde_json::from_str(&serde_json::to_string(&device).unwrap()).unwrap();
+    assert_eq!(deserialized.get_native_gates(), &native_gates);
+    assert_eq!(deserialized, device);
+}
+
+/// Test TweezerDevice MultiQubitZZ native gate
+#[test]
+fn test_multi_qubit_native_gates() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    assert!(device.get_native_gates().contains("MultiQubitZZ"));
+
+    assert!(device
+        .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0], 0.5, None)
+        .is_err());
+    assert_eq!(
+        device.set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 0], 0.5, None),
+        Err(RoqoqoBackendError::GenericError {
+            msg: "A multi-qubit gate needs at least two distinct tweezers, got [0, 1, 0]."
+                .to_string()
+        })
+    );
+
+    device
+        .set_tweezer_multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2], 0.5, None)
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 2).unwrap();
+    device.add_qubit_tweezer_mapping(1, 1).unwrap();
+    device.add_qubit_tweezer_mapping(2, 0).unwrap();
+    assert_eq!(
+        device.multi_qubit_gate_time("MultiQubitZZ", &[2, 1, 0]),
+        Some(0.5)
+    );
+    assert_eq!(
+        device.multi_qubit_gate_time("MultiQubitZZ", &[0, 1, 2]),
+        None
+    );
+
+    let generic_device = device.try_to_generic_device().unwrap();
+    assert_eq!(
+        generic_device.multi_qubit_gate_time("MultiQubitZZ", &[2, 1, 0]),
+        Some(0.5)
+    );
+}
+
+#[test]
+fn test_available_gates_for_tweezer() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("layout_name").unwrap();
+
+    assert!(device
+        .get_available_gates_for_tweezer(0, None)
+        .unwrap_err()
+        .to_string()
+        .contains("No layout name provided and no current layout set."));
+    assert!(device
+        .get_available_gates_for_tweezer(0, Some("unknown".to_string()))
+        .is_err());
+
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 1.0, Some("layout_name".to_string()))
+        .unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time(
+            "PhaseShiftState1",
+            1,
+            1.0,
+            Some("layout_name".to_string()),
+        )
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_time(
+            "PhaseShiftedControlledZ",
+            1,
+            2,
+            1.0,
+            Some("layout_name".to_string()),
+        )
+        .unwrap();
+    device
+        .set_tweezer_three_qubit_gate_time(
+            "ControlledControlledPauliZ",
+            0,
+            2,
+            3,
+            1.0,
+            Some("layout_name".to_string()),
+        )
+        .unwrap();
+
+    let layout_name = Some("layout_name".to_string());
+    assert_eq!(
+        device
+            .get_available_gates_for_tweezer(0, layout_name.clone())
+            .unwrap(),
+        vec!["ControlledControlledPauliZ", "RotateX"]
+    );
+    assert_eq!(
+        device
+            .get_available_gates_for_tweezer(1, layout_name.clone())
+            .unwrap(),
+        vec!["PhaseShiftState1", "PhaseShiftedControlledZ"]
+    );
+    assert_eq!(
+        device
+            .get_available_gates_for_tweezer(2, layout_name.clone())
+            .unwrap(),
+        vec!["ControlledControlledPauliZ", "PhaseShiftedControlledZ"]
+    );
+    assert_eq!(
+        device
+            .get_available_gates_for_tweezer(4, layout_name)
+            .unwrap(),
+        Vec::<&str>::new()
+    );
+
+    device.switch_layout("layout_name", None).unwrap();
+    assert_eq!(
+        device.get_available_gates_for_tweezer(3, None).unwrap(),
+        vec!["ControlledControlledPauliZ"]
+    );
+}
+
 #[test]
 fn test_render_device() {
     let mut device = TweezerDevice::new(None, None, None);
@@ -1451,4 +3708,271 @@ fn test_render_device() {
         .unwrap();
     assert!(std::path::Path::new("graph_test.png").exists());
     std::fs::remove_file("graph_test.png").unwrap();
+
+    let options = DrawOptions {
+        show_tweezer_labels: false,
+        show_legend: true,
+        dpi: Some(144.0),
+        ..Default::default()
+    };
+    let low_resolution = device.draw(Some(1.0), true, &None).unwrap();
+    let image = device
+        .draw_with_options(Some(1.0), true, &None, &options)
+        .unwrap();
+    assert!(image.width() > low_resolution.width());
+    assert!(device
+        .draw_with_options(
+            None,
+            false,
+            &None,
+            &DrawOptions {
+                dpi: Some(0.0),
+                ..Default::default()
+            }
+        )
+        .is_err());
+}
+
+#[test]
+fn test_render_device_vector_formats() {
+    assert_eq!(DrawFormat::from_path("device.svg"), Some(DrawFormat::Svg));
+    assert_eq!(
+        DrawFormat::from_path("figures/device.PDF"),
+        Some(DrawFormat::Pdf)
+    );
+    assert_eq!(DrawFormat::from_path("device.png"), Some(DrawFormat::Png));
+    assert_eq!(DrawFormat::from_path("device.jpg"), None);
+    assert_eq!(DrawFormat::from_path("device"), None);
+
+    let mut device = TweezerDevice::new(None, None, None);
+    assert!(device
+        .draw_as(DrawFormat::Svg, None, false, &DrawOptions::default())
+        .is_err());
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.23, None)
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, 0.34, None)
+        .unwrap();
+    device
+        .set_tweezers_per_row(vec![2], Some("default".to_string()))
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+
+    #[cfg(feature = "vector-drawing")]
+    {
+        let svg = device
+            .draw_as(DrawFormat::Svg, None, true, &DrawOptions::default())
+            .unwrap();
+        assert!(String::from_utf8(svg).unwrap().contains("<svg"));
+        let pdf = device
+            .draw_as(DrawFormat::Pdf, None, true, &DrawOptions::default())
+            .unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+    #[cfg(not(feature = "vector-drawing"))]
+    assert!(device
+        .draw_as(DrawFormat::Svg, None, true, &DrawOptions::default())
+        .unwrap_err()
+        .to_string()
+        .contains("vector-drawing feature"));
+    let png = device
+        .draw_as(DrawFormat::Png, Some(1.0), true, &DrawOptions::default())
+        .unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+
+    #[cfg(feature = "vector-drawing")]
+    {
+        let _image = device
+            .draw(None, false, &Some("graph_test.svg".to_owned()))
+            .unwrap();
+        let saved = std::fs::read_to_string("graph_test.svg").unwrap();
+        assert!(saved.contains("<svg"));
+        std::fs::remove_file("graph_test.svg").unwrap();
+    }
+}
+
+#[test]
+fn test_draw_layouts() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.add_layout("triangle").unwrap();
+    device.current_layout = Some("default".to_string());
+    for layout in ["default", "triangle"] {
+        device
+            .set_tweezers_per_row(vec![2, 2], Some(layout.to_string()))
+            .unwrap();
+        for tweezer in 0..4 {
+            device
+                .set_tweezer_single_qubit_gate_time(
+                    "RotateX",
+                    tweezer,
+                    0.23,
+                    Some(layout.to_string()),
+                )
+                .unwrap();
+        }
+    }
+    device
+        .set_tweezer_two_qubit_gate_time(
+            "PhaseShiftedControlledZ",
+            0,
+            1,
+            0.34,
+            Some("triangle".to_string()),
+        )
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+
+    let single = device
+        .draw_layouts(
+            &["default"],
+            Some(1.0),
+            false,
+            &None,
+            &DrawOptions::default(),
+        )
+        .unwrap();
+    let image = device
+        .draw_layouts(
+            &["default", "triangle"],
+            Some(1.0),
+            true,
+            &Some("layouts_test.svg".to_owned()),
+            &DrawOptions {
+                show_legend: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert!(image.width() > single.width());
+    assert!(std::fs::read_to_string("layouts_test.svg")
+        .unwrap()
+        .contains("<svg"));
+    std::fs::remove_file("layouts_test.svg").unwrap();
+
+    assert!(device
+        .draw_layouts(&[], None, false, &None, &DrawOptions::default())
+        .is_err());
+    assert!(device
+        .draw_layouts(
+            &["default", "missing"],
+            None,
+            false,
+            &None,
+            &DrawOptions::default()
+        )
+        .is_err());
+}
+
+#[test]
+fn test_draw_circuit_shifts() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.current_layout = Some("default".to_string());
+    for tweezer in 0..4 {
+        device
+            .set_tweezer_single_qubit_gate_time("RotateX", tweezer, 0.23, None)
+            .unwrap();
+    }
+    device
+        .set_tweezers_per_row(vec![2, 2], Some("default".to_string()))
+        .unwrap();
+    device
+        .set_allowed_tweezer_shifts(&0, &[&[1]], Some("default".to_string()))
+        .unwrap();
+    device.add_qubit_tweezer_mapping(0, 0).unwrap();
+    device.add_qubit_tweezer_mapping(1, 2).unwrap();
+
+    let mut circuit = roqoqo::Circuit::new();
+    circuit += operations::RotateX::new(0, 1.0.into());
+    circuit += PragmaShiftQubitsTweezers::new(vec![(0, 1)])
+        .to_pragma_change_device()
+        .unwrap();
+    circuit += PragmaDeactivateQRydQubit::new(1)
+        .to_pragma_change_device()
+        .unwrap();
+
+    let frames = device
+        .draw_circuit_shifts(
+            &circuit,
+            Some(1.0),
+            true,
+            &None,
+            500,
+            &DrawOptions::default(),
+        )
+        .unwrap();
+    assert_eq!(frames.len(), 3);
+    // The drawn device is not changed
+    assert_eq!(device.qubit_to_tweezer.as_ref().unwrap().len(), 2);
+
+    device
+        .draw_circuit_shifts(
+            &circuit,
+            Some(1.0),
+            false,
+            &Some("shifts_test.gif".to_owned()),
+            500,
+            &DrawOptions::default(),
+        )
+        .unwrap();
+    assert!(std::fs::read("shifts_test.gif")
+        .unwrap()
+        .starts_with(b"GIF89a"));
+    std::fs::remove_file("shifts_test.gif").unwrap();
+
+    device
+        .draw_circuit_shifts(
+            &circuit,
+            Some(1.0),
+            false,
+            &Some("shifts_test.png".to_owned()),
+            500,
+            &DrawOptions::default(),
+        )
+        .unwrap();
+    for index in 0..3 {
+        let frame_path = format!("shifts_test_{}.png", index);
+        assert!(std::path::Path::new(&frame_path).exists());
+        std::fs::remove_file(frame_path).unwrap();
+    }
+
+    let mut invalid_circuit = roqoqo::Circuit::new();
+    invalid_circuit += PragmaShiftQubitsTweezers::new(vec![(2, 3)])
+        .to_pragma_change_device()
+        .unwrap();
+    assert!(device
+        .draw_circuit_shifts(
+            &invalid_circuit,
+            None,
+            false,
+            &None,
+            500,
+            &DrawOptions::default()
+        )
+        .is_err());
+}
+
+/// Test the JSON schema of TweezerDevice against a serialized device
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_json_schema() {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    device.switch_layout("default", None).unwrap();
+    device
+        .set_tweezer_single_qubit_gate_time("RotateX", 0, 0.23, None)
+        .unwrap();
+    device
+        .set_tweezer_two_qubit_gate_time("PhaseShiftedControlledZ", 0, 1, 0.5, None)
+        .unwrap();
+
+    let schema = schemars::schema_for!(TweezerDevice);
+    let schema_checker = jsonschema::JSONSchema::compile(&serde_json::to_value(&schema).unwrap())
+        .expect("Compiling the schema failed");
+    let value = serde_json::to_value(&device).unwrap();
+    assert!(schema_checker.validate(&value).is_ok());
 }
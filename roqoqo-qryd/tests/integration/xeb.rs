@@ -0,0 +1,143 @@
+// Copyright © 2021-2024 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo_qryd::xeb::{linear_xeb_fidelity, XEBExperiment};
+use roqoqo_qryd::TweezerDevice;
+
+// Returns a device with two connected tweezers supporting RotateXY and ControlledPauliZ
+fn create_device() -> TweezerDevice {
+    let mut device = TweezerDevice::new(None, None, None);
+    device.add_layout("default").unwrap();
+    for tweezer in 0..2 {
+        device
+            .set_tweezer_single_qubit_gate_time(
+                "RotateXY",
+                tweezer,
+                1.0,
+                Some("default".to_string()),
+            )
+            .unwrap();
+    }
+    device
+        .set_tweezer_two_qubit_gate_time("ControlledPauliZ", 0, 1, 1.0, Some("default".to_string()))
+        .unwrap();
+    device.switch_layout("default", None).unwrap();
+    device
+}
+
+// Test the creation of an XEBExperiment
+#[test]
+fn test_new() {
+    let device = create_device();
+    let experiment = XEBExperiment::new(&device, vec![0, 1], vec![1, 3], 4, 100, Some(1)).unwrap();
+    assert_eq!(experiment.qubits(), &[0, 1]);
+    assert_eq!(experiment.number_measurements(), 100);
+    assert_eq!(experiment.depths(), &[1, 1, 1, 1, 3, 3, 3, 3]);
+    assert_eq!(
+        experiment,
+        XEBExperiment::new(&device, vec![0, 1], vec![1, 3], 4, 100, Some(1)).unwrap()
+    );
+    assert_ne!(
+        experiment,
+        XEBExperiment::new(&device, vec![0, 1], vec![1, 3], 4, 100, Some(2)).unwrap()
+    );
+
+    assert!(XEBExperiment::new(&device, vec![], vec![1], 4, 100, None).is_err());
+    assert!(XEBExperiment::new(&device, vec![0, 0], vec![1], 4, 100, None).is_err());
+    assert!(XEBExperiment::new(&device, vec![0, 5], vec![1], 4, 100, None).is_err());
+    assert!(XEBExperiment::new(&device, vec![0, 1], vec![0], 4, 100, None).is_err());
+    assert!(XEBExperiment::new(&device, vec![0, 1], vec![1], 0, 100, None).is_err());
+    assert!(XEBExperiment::new(&device, vec![0, 1], vec![1], 4, 0, None).is_err());
+}
+
+// Test the generated circuits and their ideal probabilities
+#[test]
+fn test_circuits() {
+    let device = create_device();
+    let experiment = XEBExperiment::new(&device, vec![0, 1], vec![1, 3], 2, 100, None).unwrap();
+    let circuits = experiment.circuits();
+    assert_eq!(circuits.len(), 4);
+    for (circuit, depth) in circuits.iter().zip(experiment.depths().iter()) {
+        assert_eq!(circuit.count_occurences(&["RotateXY"]), 2 * (depth + 1));
+        assert_eq!(circuit.count_occurences(&["ControlledPauliZ"]), *depth);
+        assert_eq!(circuit.count_occurences(&["MeasureQubit"]), 2);
+        assert_eq!(
+            circuit.count_occurences(&["PragmaSetNumberOfMeasurements"]),
+            1
+        );
+    }
+    for probabilities in experiment.ideal_probabilities().iter() {
+        assert_eq!(probabilities.len(), 4);
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+}
+
+// Test the linear XEB fidelity of single registers
+#[test]
+fn test_linear_xeb_fidelity() {
+    let uniform = vec![0.25; 4];
+    let register = vec![vec![false, true], vec![true, true]];
+    assert!(linear_xeb_fidelity(&uniform, &register).unwrap().abs() < 1e-12);
+
+    let peaked = vec![0.0, 0.0, 1.0, 0.0];
+    assert_eq!(
+        linear_xeb_fidelity(&peaked, &[vec![false, true]]).unwrap(),
+        3.0
+    );
+    assert_eq!(
+        linear_xeb_fidelity(&peaked, &[vec![true, false]]).unwrap(),
+        -1.0
+    );
+
+    assert!(linear_xeb_fidelity(&peaked, &[]).is_err());
+    assert!(linear_xeb_fidelity(&peaked, &[vec![true, true, true]]).is_err());
+}
+
+// Test the analysis of measured registers
+#[test]
+fn test_analyse() {
+    let device = create_device();
+    let experiment = XEBExperiment::new(&device, vec![0, 1], vec![2, 4], 3, 10, None).unwrap();
+    let registers: Vec<Vec<Vec<bool>>> = experiment
+        .ideal_probabilities()
+        .iter()
+        .map(|probabilities| {
+            // Sample the most likely bitstring of each circuit
+            let index = (0..probabilities.len())
+                .max_by(|a, b| probabilities[*a].total_cmp(&probabilities[*b]))
+                .unwrap();
+            vec![vec![index & 1 == 1, index & 2 == 2]; 10]
+        })
+        .collect();
+    let result = experiment.analyse(&registers).unwrap();
+    assert_eq!(result.depths, vec![2, 4]);
+    assert_eq!(result.fidelities.len(), 2);
+    assert!(result.fidelities.iter().all(|fidelity| *fidelity > 0.0));
+
+    assert!(experiment.analyse(&registers[1..]).is_err());
+}
+
+// Test running the experiment on the simulator
+#[cfg(feature = "simulator")]
+#[test]
+fn test_run_simulator() {
+    use roqoqo_qryd::SimulatorBackend;
+
+    let device = create_device();
+    let backend = SimulatorBackend::new(device.clone(), None);
+    let experiment = XEBExperiment::new(&device, vec![0, 1], vec![1, 2], 5, 1000, None).unwrap();
+    let result = experiment.run(&backend).unwrap();
+    for fidelity in result.fidelities.iter() {
+        assert!((fidelity - 1.0).abs() < 0.25);
+    }
+    assert!(result.fidelity_per_cycle.is_some());
+}